@@ -0,0 +1,240 @@
+import { App, Mesh } from '@lib';
+import type { BoundingBox } from '@lib';
+
+/**
+ * 混合场景示例
+ *
+ * - Splat 扫描场景作为环境（?ply=<url> 指定，默认 /scene.ply）
+ * - 网格小球使用 MeshRenderer 的方向光 + 环境光 + 方向光阴影（setMeshShadowConfig）
+ * - 简单刚体物理：重力 + 球体碰撞 + 盒体碰撞代理（splat 的轴对齐包围盒）
+ * - OrbitControls 相机控制，加载后自动指向场景
+ *
+ * 绘制顺序与深度：
+ * splat 先绘制，开启深度输出（setSplatDepthWrite）后不透明的 splat 写入深度，
+ * 网格随后绘制并做深度测试，因此位于 splat 表面之后的网格会被遮挡。
+ * 场景中心的标记柱贯穿 splat 表面，可以同时看到它在表面前方和后方的部分；
+ * "splat 深度" 开关用于对比关闭深度输出时网格始终叠加在 splat 之上的效果。
+ * 半透明的 splat 边缘不写入深度，网格与 splat 的交界处会有轻微的硬边。
+ *
+ * 阴影：网格渲染阴影贴图，网格之间互相投射阴影；splat 写入深度后，
+ * 由深度缓冲重建位置并与阴影贴图比较，splat 表面也接收小球和标记柱的阴影。
+ * "阴影" 开关用于对比。
+ *
+ * 碰撞代理只是 splat 的包围盒：小球在盒子的底面和内壁反弹，不贴合扫描的实际表面，
+ * 在起伏的地面上小球可能悬空或陷入 splat。
+ *
+ * 已知限制：splat 不投射阴影（只有网格投射），半透明的 splat 边缘不接收阴影，
+ * splat 也不参与网格光照。
+ */
+
+interface RigidBody {
+  mesh: Mesh;
+  position: [number, number, number];
+  velocity: [number, number, number];
+  radius: number;
+}
+
+// 物理参数
+const GRAVITY = -9.8;
+const RESTITUTION = 0.6;
+const FRICTION = 0.98;
+const MAX_BODIES = 64;
+// splat 深度输出的不透明度阈值
+const SPLAT_DEPTH_ALPHA = 0.5;
+
+class HybridDemo {
+  private app!: App;
+  private bodies: RigidBody[] = [];
+  // 盒体碰撞代理：splat 环境的轴对齐包围盒（无 splat 时使用默认地面）
+  private proxy: BoundingBox = {
+    min: [-5, 0, -5],
+    max: [5, 10, 5],
+    center: [0, 5, 0],
+    radius: 8.66,
+  };
+
+  async init(): Promise<void> {
+    const canvas = document.getElementById('canvas') as HTMLCanvasElement;
+
+    if (!navigator.gpu) {
+      document.getElementById('error')!.style.display = 'block';
+      throw new Error('WebGPU 不受支持');
+    }
+
+    this.app = new App(canvas);
+    await this.app.init();
+    this.app.getRenderer().setClearColorHex('#1a1a26');
+
+    // 光照设置：斜上方的方向光 + 适中的环境光，与扫描场景的整体亮度接近
+    const meshRenderer = this.app.getMeshRenderer();
+    meshRenderer.setLightDirection(0.4, 1.0, 0.3);
+    meshRenderer.setAmbientIntensity(0.35);
+
+    // 加载 splat 环境
+    const url = new URLSearchParams(location.search).get('ply') ?? '/scene.ply';
+    document.getElementById('scene-url')!.textContent = url;
+    try {
+      const count = await this.app.addPLY(url);
+      document.getElementById('splat-count')!.textContent = count.toString();
+      const box = this.app.getSplatBoundingBox();
+      if (box) {
+        this.proxy = box;
+      }
+    } catch (error) {
+      console.warn('加载 splat 环境失败，使用默认地面', error);
+    }
+
+    // 不透明的 splat 写入深度，网格与 splat 互相遮挡
+    this.app.setSplatDepthWrite(SPLAT_DEPTH_ALPHA);
+    // 网格投射阴影，写入深度的 splat 接收阴影
+    this.app.setMeshShadowConfig({ enabled: true });
+    this.addMarker();
+
+    this.app.frameCurrentModel(false);
+    this.app.onUpdate((dt) => this.step(dt));
+
+    document.getElementById('btn-drop')!.addEventListener('click', () => this.dropBall());
+    document.getElementById('btn-reset')!.addEventListener('click', () => this.reset());
+    document.getElementById('btn-frame')!.addEventListener('click', () => this.app.frameCurrentModel());
+    const depthToggle = document.getElementById('splat-depth') as HTMLInputElement;
+    depthToggle.addEventListener('change', () => {
+      this.app.setSplatDepthWrite(depthToggle.checked ? SPLAT_DEPTH_ALPHA : 0);
+    });
+    const shadowToggle = document.getElementById('shadows') as HTMLInputElement;
+    shadowToggle.addEventListener('change', () => {
+      this.app.setMeshShadowConfig({ enabled: shadowToggle.checked });
+    });
+
+    this.app.start();
+  }
+
+  /**
+   * 在场景中心放置一根竖直的标记柱，高度覆盖整个包围盒，穿过 splat 表面
+   */
+  private addMarker(): void {
+    const { min, max, center } = this.proxy;
+    const width = Math.max(Math.min(max[0] - min[0], max[2] - min[2]) * 0.04, 0.05);
+    const mesh = this.app.addTestCube();
+    mesh.setScale(width, max[1] - min[1], width);
+    mesh.setPosition(center[0], center[1], center[2]);
+    this.app.setMeshColor(this.app.getMeshCount() - 1, 1.0, 0.45, 0.1);
+  }
+
+  /**
+   * 在场景上方随机位置投放一个小球
+   */
+  private dropBall(): void {
+    if (this.bodies.length >= MAX_BODIES) return;
+
+    const { min, max } = this.proxy;
+    const size = Math.max(max[0] - min[0], max[2] - min[2]);
+    const radius = Math.max(size * 0.02, 0.05);
+
+    const mesh = this.app.addTestSphere();
+    mesh.setScale(radius * 2, radius * 2, radius * 2);
+    const index = this.app.getMeshCount() - 1;
+    this.app.setMeshColor(index, Math.random(), Math.random() * 0.5 + 0.5, Math.random());
+
+    const position: [number, number, number] = [
+      min[0] + radius + Math.random() * (max[0] - min[0] - radius * 2),
+      max[1] - radius,
+      min[2] + radius + Math.random() * (max[2] - min[2] - radius * 2),
+    ];
+    mesh.setPosition(position[0], position[1], position[2]);
+
+    this.bodies.push({ mesh, position, velocity: [0, 0, 0], radius });
+    document.getElementById('body-count')!.textContent = this.bodies.length.toString();
+  }
+
+  private reset(): void {
+    for (const body of this.bodies) {
+      this.app.getMeshRenderer().removeMesh(body.mesh);
+    }
+    this.bodies = [];
+    document.getElementById('body-count')!.textContent = '0';
+  }
+
+  /**
+   * 物理步进：半隐式欧拉 + 盒体代理约束 + 球体间弹性碰撞
+   */
+  private step(dt: number): void {
+    const { min, max } = this.proxy;
+
+    for (const body of this.bodies) {
+      const p = body.position;
+      const v = body.velocity;
+      v[1] += GRAVITY * dt;
+      p[0] += v[0] * dt;
+      p[1] += v[1] * dt;
+      p[2] += v[2] * dt;
+
+      // 碰撞代理（轴对齐包围盒内壁）
+      for (let axis = 0; axis < 3; axis++) {
+        const lo = min[axis] + body.radius;
+        const hi = max[axis] - body.radius;
+        if (p[axis] < lo) {
+          p[axis] = lo;
+          v[axis] = -v[axis] * RESTITUTION;
+          if (axis === 1) {
+            v[0] *= FRICTION;
+            v[2] *= FRICTION;
+          }
+        } else if (p[axis] > hi) {
+          p[axis] = hi;
+          v[axis] = -v[axis] * RESTITUTION;
+        }
+      }
+    }
+
+    // 球体间碰撞
+    for (let i = 0; i < this.bodies.length; i++) {
+      for (let j = i + 1; j < this.bodies.length; j++) {
+        this.resolveContact(this.bodies[i], this.bodies[j]);
+      }
+    }
+
+    for (const body of this.bodies) {
+      body.mesh.setPosition(body.position[0], body.position[1], body.position[2]);
+    }
+  }
+
+  private resolveContact(a: RigidBody, b: RigidBody): void {
+    const dx = b.position[0] - a.position[0];
+    const dy = b.position[1] - a.position[1];
+    const dz = b.position[2] - a.position[2];
+    const dist = Math.sqrt(dx * dx + dy * dy + dz * dz);
+    const minDist = a.radius + b.radius;
+    if (dist >= minDist || dist < 1e-6) return;
+
+    const nx = dx / dist;
+    const ny = dy / dist;
+    const nz = dz / dist;
+
+    // 分离
+    const overlap = (minDist - dist) * 0.5;
+    a.position[0] -= nx * overlap;
+    a.position[1] -= ny * overlap;
+    a.position[2] -= nz * overlap;
+    b.position[0] += nx * overlap;
+    b.position[1] += ny * overlap;
+    b.position[2] += nz * overlap;
+
+    // 沿法线交换速度分量（等质量）
+    const relVel =
+      (b.velocity[0] - a.velocity[0]) * nx +
+      (b.velocity[1] - a.velocity[1]) * ny +
+      (b.velocity[2] - a.velocity[2]) * nz;
+    if (relVel > 0) return;
+
+    const impulse = -(1 + RESTITUTION) * relVel * 0.5;
+    a.velocity[0] -= impulse * nx;
+    a.velocity[1] -= impulse * ny;
+    a.velocity[2] -= impulse * nz;
+    b.velocity[0] += impulse * nx;
+    b.velocity[1] += impulse * ny;
+    b.velocity[2] += impulse * nz;
+  }
+}
+
+const demo = new HybridDemo();
+demo.init().catch(console.error);
@@ -0,0 +1,221 @@
+import { App, SplatRasterMode, SplatPointMode } from '@lib';
+import type { GPUResourceSnapshot, IGSSplatRenderer } from '@lib';
+
+/**
+ * 浸泡测试：GPU 资源泄漏检测
+ *
+ * 循环执行：加载主模型 + 在图层中创建若干实体（隔一轮合并一次）、切换光栅化 / 点模式 / 摆动 /
+ * indirect 绘制、调整画布大小，然后全部销毁并恢复配置，清空 buffer 对象池后与基线比较
+ * 存活的 GPU buffer / 纹理数量。第一轮作为预热（创建共享资源和管线缓存），之后记录基线。
+ *
+ * URL 参数：
+ * - minutes：运行时长（分钟），默认 60
+ * - splats：每个实体的最大 splat 数量，默认 20000
+ * - entities：每轮图层中的实体数量，默认 3
+ * - stopOnLeak：检测到泄漏时停止（1 / 0），默认 1
+ */
+
+const params = new URLSearchParams(location.search);
+const DURATION_MS = Number(params.get('minutes') ?? 60) * 60 * 1000;
+const MAX_SPLATS = Number(params.get('splats') ?? 20000);
+const ENTITY_COUNT = Number(params.get('entities') ?? 3);
+const STOP_ON_LEAK = params.get('stopOnLeak') !== '0';
+// 每个阶段等待的帧数（让排序、回读和延迟销毁完成）
+const SETTLE_FRAMES = 10;
+
+class SoakTest {
+  private app!: App;
+  private canvas!: HTMLCanvasElement;
+  private baseline: GPUResourceSnapshot | null = null;
+  private cycles = 0;
+  private failures = 0;
+  private running = true;
+  private startTime = 0;
+
+  async init(): Promise<void> {
+    this.canvas = document.getElementById('canvas') as HTMLCanvasElement;
+
+    if (!navigator.gpu) {
+      document.getElementById('error')!.style.display = 'block';
+      throw new Error('WebGPU 不受支持');
+    }
+
+    this.app = new App(this.canvas);
+    this.app.setGPUResourceTracking(true);
+    await this.app.init();
+    this.app.getRenderer().setClearColorHex('#1a1a26');
+    this.app.start();
+
+    document.getElementById('btn-stop')!.addEventListener('click', () => {
+      this.running = false;
+    });
+
+    this.startTime = performance.now();
+    await this.run();
+  }
+
+  private async run(): Promise<void> {
+    // 预热：第一轮创建的共享资源（管线、上传区、共享纹理）不算泄漏
+    await this.cycle();
+    await this.waitFrames(SETTLE_FRAMES);
+    this.app.getGPUResourceCounts(true);
+    this.baseline = this.app.getGPUResourceTracker()!.snapshot();
+    console.info('[soak] 基线', this.baseline.counts);
+
+    while (this.running && performance.now() - this.startTime < DURATION_MS) {
+      await this.cycle();
+      await this.waitFrames(SETTLE_FRAMES);
+      this.cycles++;
+      if (!this.check() && STOP_ON_LEAK) {
+        break;
+      }
+    }
+    this.running = false;
+    console.info(`[soak] 结束：${this.cycles} 轮，${this.failures} 次失败`);
+  }
+
+  /**
+   * 一轮：创建 -> 切换配置 -> 调整大小 -> 销毁
+   */
+  private async cycle(): Promise<void> {
+    const app = this.app;
+
+    await app.addPLY(createRandomPLY(randomCount()));
+    const layer = app.createSplatLayer(`soak-${this.cycles}`);
+    const entities: IGSSplatRenderer[] = [];
+    for (let i = 0; i < ENTITY_COUNT; i++) {
+      const entity = await app.addPLYToLayer(layer, createRandomPLY(randomCount()));
+      entity.setPosition((i - ENTITY_COUNT / 2) * 4, 0, 0);
+      entities.push(entity);
+    }
+    await this.waitFrames(SETTLE_FRAMES);
+
+    // 合并需要 CPU 端数据（移动端纹理打包渲染器不支持）
+    if (this.cycles % 2 === 1 && entities.every((entity) => entity.getSplats?.())) {
+      app.mergeSplatEntities(entities);
+      await this.waitFrames(SETTLE_FRAMES);
+    }
+
+    const rasterModes = [SplatRasterMode.Tile, SplatRasterMode.Stochastic, SplatRasterMode.Quad];
+    for (const mode of rasterModes) {
+      app.setSplatRasterMode(mode);
+      await this.waitFrames(SETTLE_FRAMES);
+    }
+    app.setSplatPointMode(SplatPointMode.Centers);
+    app.setSplatSway({ enabled: true });
+    app.setIndirectDraw(!app.isIndirectDraw());
+    await this.waitFrames(SETTLE_FRAMES);
+
+    this.canvas.style.width = `${50 + Math.random() * 50}%`;
+    this.canvas.style.height = `${50 + Math.random() * 50}%`;
+    await this.waitFrames(SETTLE_FRAMES);
+
+    // 恢复配置并销毁
+    app.setSplatPointMode(SplatPointMode.Off);
+    app.setSplatSway({ enabled: false });
+    app.setIndirectDraw(null);
+    this.canvas.style.width = '';
+    this.canvas.style.height = '';
+    layer.destroy();
+    app.clearSplats();
+  }
+
+  /**
+   * 与基线比较，返回是否通过
+   */
+  private check(): boolean {
+    const counts = this.app.getGPUResourceCounts(true)!;
+    const leaks = this.app.getGPUResourceTracker()!.diff(this.baseline!);
+    const base = this.baseline!.counts;
+
+    document.getElementById('elapsed')!.textContent = formatDuration(performance.now() - this.startTime);
+    document.getElementById('cycles')!.textContent = this.cycles.toString();
+    document.getElementById('buffers')!.textContent =
+      `${counts.buffers} / 基线 ${base.buffers}（${(counts.bufferBytes / 1048576).toFixed(1)} MB）`;
+    document.getElementById('textures')!.textContent =
+      `${counts.textures} / 基线 ${base.textures}（${(counts.textureBytes / 1048576).toFixed(1)} MB）`;
+
+    if (leaks.length === 0) {
+      return true;
+    }
+    this.failures++;
+    document.getElementById('failures')!.textContent = this.failures.toString();
+    document.getElementById('failures')!.className = 'fail';
+    const report = leaks
+      .map((leak) => `${leak.kind} "${leak.label || '(无标签)'}" +${leak.count}（${leak.bytes} 字节）`)
+      .join('\n');
+    document.getElementById('leaks')!.textContent = report;
+    console.error(`[soak] 第 ${this.cycles} 轮资源未回到基线：\n${report}`);
+    return false;
+  }
+
+  private async waitFrames(count: number): Promise<void> {
+    for (let i = 0; i < count; i++) {
+      await new Promise<void>((resolve) => requestAnimationFrame(() => resolve()));
+    }
+  }
+}
+
+function randomCount(): number {
+  return Math.max(1, Math.floor(MAX_SPLATS * (0.25 + Math.random() * 0.75)));
+}
+
+/**
+ * 生成随机 splat 的二进制 PLY（标准 3DGS 属性，球内随机分布）
+ */
+function createRandomPLY(count: number): ArrayBuffer {
+  const properties = [
+    'x', 'y', 'z',
+    'f_dc_0', 'f_dc_1', 'f_dc_2',
+    'opacity',
+    'scale_0', 'scale_1', 'scale_2',
+    'rot_0', 'rot_1', 'rot_2', 'rot_3',
+  ];
+  const header = [
+    'ply',
+    'format binary_little_endian 1.0',
+    `element vertex ${count}`,
+    ...properties.map((name) => `property float ${name}`),
+    'end_header',
+    '',
+  ].join('\n');
+  const headerBytes = new TextEncoder().encode(header);
+  const buffer = new ArrayBuffer(headerBytes.length + count * properties.length * 4);
+  new Uint8Array(buffer).set(headerBytes);
+  // 头部长度不一定是 4 的倍数，先写入对齐的数组再整体复制
+  const data = new Float32Array(count * properties.length);
+
+  for (let i = 0; i < count; i++) {
+    const o = i * properties.length;
+    const r = Math.cbrt(Math.random()) * 2;
+    const theta = Math.random() * Math.PI * 2;
+    const phi = Math.acos(2 * Math.random() - 1);
+    data[o] = r * Math.sin(phi) * Math.cos(theta);
+    data[o + 1] = r * Math.cos(phi);
+    data[o + 2] = r * Math.sin(phi) * Math.sin(theta);
+    data[o + 3] = Math.random() * 2 - 1;
+    data[o + 4] = Math.random() * 2 - 1;
+    data[o + 5] = Math.random() * 2 - 1;
+    data[o + 6] = Math.random() * 4 - 1;
+    for (let k = 0; k < 3; k++) {
+      data[o + 7 + k] = Math.log(0.01 + Math.random() * 0.04);
+    }
+    data[o + 10] = 1;
+    data[o + 11] = Math.random() - 0.5;
+    data[o + 12] = Math.random() - 0.5;
+    data[o + 13] = Math.random() - 0.5;
+  }
+  new Uint8Array(buffer).set(new Uint8Array(data.buffer), headerBytes.length);
+  return buffer;
+}
+
+function formatDuration(ms: number): string {
+  const seconds = Math.floor(ms / 1000);
+  const h = Math.floor(seconds / 3600);
+  const m = Math.floor((seconds % 3600) / 60);
+  const s = seconds % 60;
+  return `${h}:${m.toString().padStart(2, '0')}:${s.toString().padStart(2, '0')}`;
+}
+
+const soak = new SoakTest();
+soak.init().catch(console.error);
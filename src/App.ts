@@ -9,30 +9,112 @@
  * 
  * 场景管理委托给 SceneManager
  * Gizmo 交互委托给 GizmoManager
+ * Splat 指针事件委托给 SplatPicking
  */
 
 import { Renderer } from "./core/Renderer";
+import { frameCaptureToRGBA8, type FrameCapture } from "./core/FrameCapture";
+import { encodePNG, downloadBlob } from "./utils/png";
+import { clientToCanvasPixels } from "./utils/canvas";
+import { FramePreview } from "./core/FramePreview";
+import { SplitViewComparison } from "./core/SplitViewComparison";
+import type { SplitViewComparisonConfig } from "./core/SplitViewComparison";
+import { loadTextureFromBlob, loadTextureFromURL } from "./utils/texture";
+import type { GPUResourceTracker, GPUResourceCounts } from "./core/GPUResourceTracker";
 import { Camera } from "./core/Camera";
-import { OrbitControls } from "./core/OrbitControls";
+import { OrbitControls, OrbitControlsConfig } from "./core/OrbitControls";
+import { FlyControls, FlyControlsConfig } from "./core/FlyControls";
+import { CameraPath, CameraPathSample } from "./core/CameraPath";
+import type { FrameSequenceSink } from "./core/FrameSequenceExport";
+import { RenderOnDemand, RenderOnDemandConfig } from "./core/RenderOnDemand";
+import { DynamicResolution, DynamicResolutionConfig } from "./core/DynamicResolution";
 import { MeshRenderer } from "./mesh/MeshRenderer";
+import type { MeshShadowConfig } from "./mesh/MeshRenderer";
+import { GridRenderer, GridConfig } from "./core/GridRenderer";
+import { transformBoundingBox } from "./utils/geometry";
 import { GLBLoader } from "./loaders/GLBLoader";
 import { OBJLoader } from "./loaders/OBJLoader";
 import { Mesh } from "./mesh/Mesh";
 import { GSSplatRenderer } from "./gs/GSSplatRenderer";
+import { SplatBufferPool } from "./gs/SplatBufferPool";
+import { SplatPickTargets } from "./gs/SplatPickTargets";
+import { GSSplatRendererGroup } from "./gs/GSSplatRendererGroup";
+import { getMaxSplatsPerSegment } from "./gs/SplatSegments";
+import { SplatMemoryBudget, SplatMemoryDowngrade } from "./gs/SplatMemoryBudget";
+import type { SplatMemoryReport } from "./gs/SplatMemoryBudget";
+import type { UploadSchedulerStats } from "./core/UploadScheduler";
+import { TemporalJitter } from "./core/TemporalJitter";
+import { SplatDiagnostics, SPLAT_DIAGNOSTICS } from "./core/SplatDiagnostics";
+import { getSplatPlatformDefaults, resetSplatPlatformDefaults, setSplatPlatformDefaults } from "./core/PlatformDefaults";
+import type { SplatPlatform, SplatPlatformDefaults } from "./core/PlatformDefaults";
 import { GSSplatRendererMobile } from "./gs/GSSplatRendererMobile";
-import type { IGSSplatRenderer } from "./gs/IGSSplatRenderer";
-import type { BoundingBox } from "./types";
+import type { IGSSplatRenderer, SplatEntityPickResult } from "./gs/IGSSplatRenderer";
+import type { PackModeConfig } from "./gs/TextureCompressor";
+import type { TransferFunctionStop } from "./gs/TransferFunction";
+import type { SplatLodConfig } from "./gs/SplatLod";
+import type { SplatPointSizeConfig } from "./gs/SplatPoints";
+import type { SplatCentersConfig } from "./gs/SplatCentersFilter";
+import type { SplatSortCacheStats } from "./gs/SplatSortCache";
+import type { SplatPVSConfig } from "./gs/SplatPVS";
+import type { SplatSwayConfig } from "./gs/SplatSway";
+import type { SplatColorAdjustment } from "./gs/SplatColorAdjust";
+import type { SplatAppearanceData } from "./gs/SplatAppearance";
+import type { SplatOpacityEdit } from "./gs/SplatOpacityEdit";
+import type { SplatCropBox } from "./gs/SplatCropBox";
+import type { SplatGroups, SplatGroupInfo } from "./gs/SplatGroups";
+import type { SplatMirrorPlane } from "./gs/SplatMirror";
+import type { SplatFloaterConfig } from "./gs/SplatFloaters";
+import type { SplatAttributePredicate } from "./gs/SplatAttributeFilter";
+import { SPLAT_FEATURE_SH } from "./gs/SplatShaderFeatures";
+import {
+  createSplatRenderLayers,
+  encodeSplatRenderLayersEXR,
+  encodeSplatRenderLayerFiles,
+} from "./gs/SplatRenderLayers";
+import type { SplatRenderLayers, SplatRenderLayerFiles } from "./gs/SplatRenderLayers";
+import type { SplatDirtyRanges } from "./gs/SplatDirtyRanges";
+import type { SplatExternalBuffer, SplatExternalBuffers } from "./gs/SplatExternalBuffers";
+import { TrainingMetrics } from "./gs/TrainingMetrics";
+import type { TrainingSplatChannel } from "./gs/TrainingSplatChannel";
+import { RemoteTrainingClient } from "./net/RemoteTrainingClient";
+import type { RemoteTrainingClientConfig, RemoteTrainingHandlers } from "./net/RemoteTrainingClient";
+import { loadColmapCameras, loadNerfTransforms } from "./loaders/CameraDatasetLoader";
+import type { TrainingCamera } from "./loaders/CameraDatasetLoader";
+import type { CameraFrustumConfig } from "./core/CameraFrustumRenderer";
+import type { TrainingMetricsCallback, TrainingMetricsEventType, TrainingMetricsSample } from "./gs/TrainingMetrics";
+import type { CompactSplatData } from "./gs/PLYLoaderMobile";
+import type { SplatImportOptions } from "./gs/SplatImport";
+import { DepthConfig, resolveDepthConfig } from "./core/DepthConvention";
+import type { SplatLabels } from "./gs/SplatLabels";
+import type { SplatSelection, SelectionOp } from "./gs/SplatSelection";
+import type { SplatEditHistory, SplatTransformState } from "./gs/SplatEditHistory";
+import { GaussianSplats } from "./gs/GaussianSplats";
+import type { SplatPrecision, SplatStatistics } from "./gs/SplatStatistics";
+import type { BoundingBox, Vec3Tuple, SplatVisMode, Colormap, SplatRasterMode, SplatBlendMode, SplatPointMode, SplatPickResult, SplatDepthQueryMode, SplatDepthResult } from "./types";
+import { SplatDepthKeyMode } from "./types";
 import { deserializeSplat } from "./gs/SplatLoader";
 import { SceneManager } from "./scene/SceneManager";
+import type { SplatLayer } from "./scene/SplatLayer";
+import { registerSplatEntities, applySplatRegistration } from "./gs/SplatRegistration";
+import type { SplatRegistrationConfig, SplatRegistrationResult } from "./gs/SplatRegistration";
+import { mergeSplatData, getSplatMergeItem, splitSplatEntityData } from "./gs/SplatMerge";
 import { 
   GizmoManager, 
   SplatTransformProxy, 
   MeshGroupProxy, 
   SplatBoundingBoxProvider 
 } from "./interaction/GizmoManager";
+import { SplatPicking } from "./interaction/SplatPicking";
+import { SplatHoverHighlight } from "./interaction/SplatHoverHighlight";
+import type { SplatHoverHighlightConfig } from "./interaction/SplatHoverHighlight";
+import { SplatBrushSelection } from "./interaction/SplatBrushSelection";
+import type { SplatBrushConfig } from "./interaction/SplatBrushSelection";
+import { SplatLassoSelection } from "./interaction/SplatLassoSelection";
+import type { SplatLassoConfig } from "./interaction/SplatLassoSelection";
+import type { SplatPointerEventType, SplatPointerCallback } from "./interaction/SplatPicking";
 import { TransformableObject, GizmoMode } from "./core/gizmo/TransformGizmoV2";
 import { BoundingBoxProvider } from "./core/BoundingBoxRenderer";
-import { isMobileDevice } from "./utils";
+import type { TransformIssue } from "./utils";
 
 // 重新导出代理类以保持向后兼容
 export { SplatTransformProxy, MeshGroupProxy, SplatBoundingBoxProvider };
@@ -44,6 +126,37 @@ export { SplatTransformProxy, MeshGroupProxy, SplatBoundingBoxProvider };
  */
 export type ProgressCallback = (progress: number, stage: 'download' | 'parse' | 'upload') => void;
 
+/**
+ * 截图请求
+ */
+export interface SplatScreenshotRequest {
+  /** 下载的文件名，省略时只返回 PNG Blob */
+  path?: string;
+  /** 输出分辨率 [宽, 高]（像素），默认为画布尺寸；可以大于画布（不超过设备的最大纹理尺寸） */
+  resolution?: [number, number];
+  /** 背景透明（PNG alpha 为 0），默认使用背景颜色 */
+  transparentBackground?: boolean;
+  /** 视点，默认为当前相机 */
+  view?: Pick<OffscreenRenderView, 'position' | 'target' | 'up' | 'fovY'>;
+}
+
+/**
+ * 离屏渲染的视点
+ */
+export interface OffscreenRenderView {
+  position: [number, number, number];
+  target: [number, number, number];
+  /** 默认沿用当前相机的上方向 */
+  up?: [number, number, number];
+  /** 垂直视场角（弧度），默认沿用当前相机 */
+  fovY?: number;
+  /** 输出尺寸（像素） */
+  width: number;
+  height: number;
+  /** 背景透明（alpha 为 0），默认使用背景颜色 */
+  transparentBackground?: boolean;
+}
+
 /**
  * App - 统一调度入口
  */
@@ -52,20 +165,73 @@ export class App {
   private renderer!: Renderer;
   private camera!: Camera;
   private controls!: OrbitControls;
+  // 飞行控制器（第一次切换到飞行模式时创建）
+  private flyControls: FlyControls | null = null;
+  // 按需渲染（init 时创建）
+  private renderOnDemand!: RenderOnDemand;
+  private renderSignature: number[] = [];
+  private dynamicResolution: DynamicResolution = new DynamicResolution();
+  // 在 worker 中解析 PLY
+  private plyWorkerParsing: boolean = true;
+  private signatureRenderer: IGSSplatRenderer | null = null;
+  // 正在播放的相机路径（播放期间暂停相机控制）
+  private cameraPathPlayback: {
+    path: CameraPath;
+    time: number;
+    speed: number;
+    controlsEnabled: boolean;
+    onEnd?: () => void;
+  } | null = null;
   private meshRenderer!: MeshRenderer;
+  private gridRenderer!: GridRenderer;
   private glbLoader!: GLBLoader;
   private objLoader!: OBJLoader;
 
   // 子系统管理器
   private sceneManager!: SceneManager;
   private gizmoManager!: GizmoManager;
+  private splatPicking!: SplatPicking;
+  private splatHover!: SplatHoverHighlight;
+  private splatBrush!: SplatBrushSelection;
+  private splatLasso!: SplatLassoSelection;
+  private comparison!: SplitViewComparison;
+  private memoryBudget!: SplatMemoryBudget;
+  // TAA 亚像素抖动，null 表示关闭
+  private temporalJitter: TemporalJitter | null = null;
+  // 帧统计诊断（未启用时为 null）
+  private diagnostics: SplatDiagnostics | null = null;
+  private diagnosticsReadbackInterval: number = 0;
+  private diagnosticsRenderers: WeakSet<IGSSplatRenderer> = new WeakSet();
+  private diagnosticsSortStats: WeakMap<IGSSplatRenderer, SplatSortCacheStats> = new WeakMap();
 
   private isRunning: boolean = false;
   private animationId: number = 0;
 
+  // 每帧更新回调（物理、动画等），在相机矩阵更新前调用
+  private updateCallbacks: Array<(deltaTime: number) => void> = [];
+  private framePreviews: Set<FramePreview> = new Set();
+  // 训练指标，SH 阶数变化时可以同步预览的 SH 模式
+  private trainingMetrics: TrainingMetrics = new TrainingMetrics();
+  private followTrainingSHDegree: boolean = true;
+  // 训练数据双缓冲通道，每帧取得最新发布的数据
+  private trainingChannel: TrainingSplatChannel | null = null;
+  private remoteTraining: RemoteTrainingClient | null = null;
+  private lastFrameTime: number = 0;
+
   // 是否使用移动端渲染器
   private useMobileRenderer: boolean = false;
 
+  // 移动端纹理打包配置（对之后加载的模型生效）
+  private packModeConfig: Partial<PackModeConfig> = {};
+
+  // 深度约定和 HDR 输出（需在 init 之前设置）
+  private depthConfig: Partial<DepthConfig> = {};
+  private hdrOutput: boolean = false;
+  // 是否使用 indirect 绘制，null 表示按适配器自动选择
+  private indirectDraw: boolean | null = null;
+  // 是否统计 GPU 资源（需在 init 之前设置）
+  private gpuResourceTracking: boolean = false;
+
   // 绑定的事件处理函数
   private boundOnResize: () => void;
 
@@ -79,15 +245,27 @@ export class App {
    */
   async init(): Promise<void> {
     // 初始化渲染器
-    this.renderer = new Renderer(this.canvas);
+    this.renderer = new Renderer(this.canvas, {
+      depth: this.depthConfig,
+      hdr: this.hdrOutput,
+      indirectDraw: this.indirectDraw ?? undefined,
+      trackResources: this.gpuResourceTracking,
+    });
     await this.renderer.init();
 
     // 初始化相机
     this.camera = new Camera();
+    this.camera.depthConfig = this.renderer.depthConfig;
+    this.camera.updateMatrix();
     this.camera.setAspect(this.renderer.getAspectRatio());
 
-    // 初始化控制器
+    // 初始化控制器，双击（鼠标或触摸）平滑对焦到点击处的 splat 表面并拉近
     this.controls = new OrbitControls(this.camera, this.canvas);
+    this.renderOnDemand = new RenderOnDemand(this.canvas);
+    this.controls.onDoubleTap = (clientX, clientY) => {
+      const pixel = clientToCanvasPixels(this.canvas, clientX, clientY);
+      if (pixel) void this.focusSplatAt(pixel[0], pixel[1], true, this.controls.focusZoom);
+    };
 
     // 初始化网格渲染器
     this.meshRenderer = new MeshRenderer(this.renderer, this.camera);
@@ -107,6 +285,65 @@ export class App {
       this.controls
     );
 
+    // 初始化地面网格（默认隐藏，间距和高度跟随 splat 的世界空间包围盒）
+    this.gridRenderer = new GridRenderer(this.renderer, this.camera);
+    this.gridRenderer.setProvider({
+      getBoundingBox: () => {
+        const gsRenderer = this.sceneManager.getGSRenderer();
+        const box = gsRenderer?.getBoundingBox();
+        return gsRenderer && box ? transformBoundingBox(box, gsRenderer.getModelMatrix()) : null;
+      },
+    });
+
+    // 每帧上传预算取平台默认配置
+    this.renderer.uploadScheduler.setBudget(getSplatPlatformDefaults().uploadBudget);
+
+    // 初始化显存预算（默认不限制），当前 splat 渲染器作为一个实体参与预算
+    this.memoryBudget = new SplatMemoryBudget(this.renderer.device);
+    this.memoryBudget.register({
+      getGPUMemoryUsage: () => this.sceneManager.getGSRenderer()?.getGPUMemoryUsage?.() ?? 0,
+      downgradeMemory: (step) => step === SplatMemoryDowngrade.Pack
+        ? this.packSplatRenderer()
+        : this.sceneManager.getGSRenderer()?.downgradeMemory?.(step) ?? 0,
+      restoreMemory: (bytes) => this.sceneManager.getGSRenderer()?.restoreMemory?.(bytes) ?? 0,
+    }, "splats");
+
+    // 初始化分屏对比，在拾取之前注册指针事件，拖动分割线时先暂停拾取和相机控制
+    let pausedInput: { controls: boolean; picking: boolean } | null = null;
+    this.comparison = new SplitViewComparison(this.renderer, this.canvas, (dragging) => {
+      if (dragging) {
+        pausedInput = { controls: this.controls.enabled, picking: this.splatPicking.enabled };
+        this.controls.enabled = false;
+        this.splatPicking.enabled = false;
+      } else if (pausedInput) {
+        this.controls.enabled = pausedInput.controls;
+        this.splatPicking.enabled = pausedInput.picking;
+        pausedInput = null;
+      }
+    });
+
+    // 初始化 splat 拾取
+    this.splatPicking = new SplatPicking(this.canvas, () => this.sceneManager.getGSRenderer());
+    this.splatHover = new SplatHoverHighlight(this.canvas, () => this.sceneManager.getGSRenderer());
+    this.splatBrush = new SplatBrushSelection(
+      this.canvas,
+      () => this.sceneManager.getGSRenderer(),
+      (edit, label) => this.sceneManager.editSplatSelection(edit, label),
+    );
+    this.splatLasso = new SplatLassoSelection(
+      this.canvas,
+      () => this.sceneManager.getGSRenderer(),
+      (edit, label) => this.sceneManager.editSplatSelection(edit, label),
+    );
+
+    // 训练的 SH 阶数提升时同步预览
+    this.trainingMetrics.on("update", (sample) => {
+      const degree = sample?.shDegree;
+      if (this.followTrainingSHDegree && degree !== undefined && degree !== this.getSHMode()) {
+        this.setSHMode(Math.min(Math.max(Math.round(degree), 0), 3) as 0 | 1 | 2 | 3);
+      }
+    });
+
     // 监听窗口大小变化
     window.addEventListener("resize", this.boundOnResize);
   }
@@ -154,9 +391,11 @@ export class App {
     urlOrBuffer: string | ArrayBuffer,
     onProgress?: ProgressCallback,
     isLocalFile: boolean = false,
+    importOptions: SplatImportOptions = {},
   ): Promise<number> {
     try {
-      const isMobile = isMobileDevice();
+      const defaults = getSplatPlatformDefaults();
+      const packMode = { ...defaults.packMode, ...this.packModeConfig };
       let buffer: ArrayBuffer;
 
       // 下载阶段 (0-50%)
@@ -183,37 +422,51 @@ export class App {
 
       let gsRenderer: IGSSplatRenderer;
 
-      if (isMobile) {
-        gsRenderer = new GSSplatRendererMobile(this.renderer, this.camera);
+      if (defaults.pack) {
+        gsRenderer = new GSSplatRendererMobile(this.renderer, this.camera, packMode);
         this.useMobileRenderer = true;
 
         const compactData = await this.parsePLYBuffer(buffer, {
           maxSplats: Infinity,
-          loadSH: false,
+          ...importOptions,
+          // 打包配置存储 SH 时才读取 SH 系数
+          loadSH: packMode.sh !== 'none',
           onProgress: parseProgressCallback,
-        });
+        }, typeof urlOrBuffer === 'string');
 
         if (onProgress) onProgress(90, 'upload');
         gsRenderer.setCompactData(compactData);
         if (onProgress) onProgress(100, 'upload');
 
+        this.applyPlatformDefaults(gsRenderer);
         this.sceneManager.setGSRenderer(gsRenderer);
         return compactData.count;
       } else {
-        // 桌面端使用优化的 V2 渲染器
-        gsRenderer = new GSSplatRenderer(this.renderer, this.camera);
         this.useMobileRenderer = false;
 
         const compactData = await this.parsePLYBuffer(buffer, {
           maxSplats: Infinity,
-          loadSH: true,
+          ...importOptions,
+          loadSH: defaults.loadSH,
           onProgress: parseProgressCallback,
-        });
+        }, typeof urlOrBuffer === 'string');
+
+        // 桌面端使用优化的 V2 渲染器，超过单个渲染器上限时自动分段；
+        // 显存预算放不下存储缓冲区布局 (256 bytes/splat) 时直接使用纹理打包格式
+        if (!this.memoryBudget.reserve(compactData.count * 256)) {
+          gsRenderer = new GSSplatRendererMobile(this.renderer, this.camera, packMode);
+          this.useMobileRenderer = true;
+        } else {
+          gsRenderer = compactData.count > getMaxSplatsPerSegment(this.renderer.device)
+            ? new GSSplatRendererGroup(this.renderer, this.camera)
+            : new GSSplatRenderer(this.renderer, this.camera);
+        }
 
         if (onProgress) onProgress(90, 'upload');
         gsRenderer.setCompactData(compactData);
         if (onProgress) onProgress(100, 'upload');
 
+        this.applyPlatformDefaults(gsRenderer);
         this.sceneManager.setGSRenderer(gsRenderer);
         return compactData.count;
       }
@@ -229,6 +482,7 @@ export class App {
     urlOrBuffer: string | ArrayBuffer,
     onProgress?: ProgressCallback,
     isLocalFile: boolean = false,
+    importOptions: SplatImportOptions = {},
   ): Promise<number> {
     try {
       let buffer: ArrayBuffer;
@@ -247,13 +501,21 @@ export class App {
       }
 
       if (onProgress) onProgress(50, 'parse');
-      const splats = deserializeSplat(buffer);
+      const splats = deserializeSplat(buffer, importOptions);
       if (onProgress) onProgress(90, 'parse');
 
       if (onProgress) onProgress(90, 'upload');
-      // 使用优化的 V2 渲染器
-      const gsRenderer = new GSSplatRenderer(this.renderer, this.camera);
-      gsRenderer.setData(splats);
+      // 使用优化的 V2 渲染器，超过单个渲染器上限时自动分段
+      let gsRenderer: IGSSplatRenderer;
+      if (splats.length > getMaxSplatsPerSegment(this.renderer.device)) {
+        gsRenderer = new GSSplatRendererGroup(this.renderer, this.camera);
+        gsRenderer.setCompactData(GaussianSplats.fromSplatCPU(splats).getData());
+      } else {
+        const singleRenderer = new GSSplatRenderer(this.renderer, this.camera);
+        singleRenderer.setData(splats);
+        gsRenderer = singleRenderer;
+      }
+      this.applyPlatformDefaults(gsRenderer);
       this.sceneManager.setGSRenderer(gsRenderer);
       this.useMobileRenderer = false;
       if (onProgress) onProgress(100, 'upload');
@@ -264,20 +526,53 @@ export class App {
     }
   }
 
+  /**
+   * 加载 PLY 文件到图层（作为独立实体，不替换当前 splat 模型）
+   * @returns 新建的渲染器，可用于单独设置变换或从图层移除
+   */
+  async addPLYToLayer(
+    layer: SplatLayer,
+    urlOrBuffer: string | ArrayBuffer,
+    onProgress?: ProgressCallback,
+    importOptions: SplatImportOptions = {},
+  ): Promise<IGSSplatRenderer> {
+    const defaults = getSplatPlatformDefaults();
+    const buffer = typeof urlOrBuffer === 'string'
+      ? await this.fetchWithProgress(urlOrBuffer, (progress) => onProgress?.(progress * 0.5, 'download'))
+      : urlOrBuffer;
+
+    const compactData = await this.parsePLYBuffer(buffer, {
+      maxSplats: Infinity,
+      ...importOptions,
+      loadSH: defaults.pack ? { ...defaults.packMode, ...this.packModeConfig }.sh !== 'none' : defaults.loadSH,
+      onProgress: (loaded, total) => onProgress?.(50 + (loaded / total) * 40, 'parse'),
+    }, typeof urlOrBuffer === 'string');
+
+    onProgress?.(90, 'upload');
+    const gsRenderer = this.createSplatEntity(compactData);
+    layer.addEntity(gsRenderer);
+    // 加载可能在按需渲染空闲时完成
+    this.requestRender();
+    onProgress?.(100, 'upload');
+    return gsRenderer;
+  }
+
   /**
    * 添加测试立方体
    */
-  addTestCube(): void {
+  addTestCube(): Mesh {
     const { mesh, material } = this.glbLoader.createTestCube();
     this.meshRenderer.addMesh(mesh, material);
+    return mesh;
   }
 
   /**
    * 添加测试球体
    */
-  addTestSphere(): void {
+  addTestSphere(): Mesh {
     const { mesh, material } = this.glbLoader.createTestSphere();
     this.meshRenderer.addMesh(mesh, material);
+    return mesh;
   }
 
   // ============================================
@@ -290,9 +585,62 @@ export class App {
   start(): void {
     if (this.isRunning) return;
     this.isRunning = true;
+    this.lastFrameTime = performance.now();
     this.animate();
   }
 
+  /**
+   * 开关按需渲染：相机静止、数据没有变化时跳过 GPU 工作（渲染循环和更新回调照常运行），
+   * 用于嵌入页面和电池供电的设备。App 的变换 / 显示设置方法会自动请求渲染，
+   * 直接修改渲染器、图层等对象之后调用 requestRender
+   */
+  setRenderOnDemand(enabled: boolean): void {
+    this.renderOnDemand.setEnabled(enabled);
+  }
+
+  isRenderOnDemand(): boolean {
+    return this.renderOnDemand.isEnabled();
+  }
+
+  setRenderOnDemandConfig(config: Partial<RenderOnDemandConfig>): void {
+    this.renderOnDemand.setConfig(config);
+  }
+
+  getRenderOnDemandConfig(): RenderOnDemandConfig {
+    return this.renderOnDemand.getConfig();
+  }
+
+  /**
+   * 请求重新渲染（按需渲染时，在修改设置或数据之后调用；未开启按需渲染时无影响）
+   */
+  requestRender(): void {
+    this.renderOnDemand.requestRender();
+  }
+
+  /**
+   * 设置动态分辨率：相机快速旋转 / 移动时按 motionScale 降低渲染分辨率，停下后恢复，保持弱 GPU 上的交互流畅
+   */
+  setDynamicResolutionConfig(config: Partial<DynamicResolutionConfig>): void {
+    this.dynamicResolution.setConfig(config);
+  }
+
+  getDynamicResolutionConfig(): DynamicResolutionConfig {
+    return this.dynamicResolution.getConfig();
+  }
+
+  /**
+   * 注册每帧更新回调
+   * @param callback 参数为距上一帧的秒数
+   * @returns 取消注册函数
+   */
+  onUpdate(callback: (deltaTime: number) => void): () => void {
+    this.updateCallbacks.push(callback);
+    return () => {
+      const index = this.updateCallbacks.indexOf(callback);
+      if (index >= 0) this.updateCallbacks.splice(index, 1);
+    };
+  }
+
   /**
    * 停止渲染循环
    */
@@ -311,26 +659,204 @@ export class App {
   }
 
   private render(): void {
+    const now = performance.now();
+    // 限制最大步长，避免切回标签页后的巨大跳变
+    const deltaTime = Math.min((now - this.lastFrameTime) / 1000, 0.1);
+    this.lastFrameTime = now;
+    for (const callback of this.updateCallbacks) {
+      callback(deltaTime);
+    }
+    this.controls.tick(deltaTime);
+    this.flyControls?.tick(deltaTime);
+    this.advanceCameraPath(deltaTime);
+    // 相机快速运动时降低渲染分辨率，停下后恢复
+    this.renderer.setResolutionScale(this.dynamicResolution.update(this.camera, deltaTime, now));
+
     this.camera.setAspect(this.renderer.getAspectRatio());
     this.camera.updateMatrix();
+    // 按需渲染：画面没有变化时跳过本帧的 GPU 工作
+    if (!this.renderOnDemand.shouldRender(this.getRenderSignature(), now)) {
+      return;
+    }
+    this.temporalJitter?.apply(this.camera, this.renderer.width, this.renderer.height);
+    this.renderScene();
+    this.renderOnDemand.frameRendered(now);
+    if (this.renderer.uploadScheduler.hasDeferred()) {
+      this.renderOnDemand.requestRender();
+    }
+
+    if (this.diagnostics) {
+      this.updateDiagnostics(this.diagnostics, deltaTime);
+    }
+  }
 
-    const pass = this.renderer.beginFrame();
+  /**
+   * 按需渲染比较的状态：相机（不含抖动）、渲染尺寸和场景数据（实体数量、编辑 / 选择 / 分组版本）
+   * 变换和显示设置没有版本，由对应的 setter 调用 requestRender
+   */
+  private getRenderSignature(): number[] {
+    const gsRenderer = this.sceneManager.getGSRenderer();
+    if (gsRenderer !== this.signatureRenderer) {
+      this.signatureRenderer = gsRenderer;
+      this.renderOnDemand.requestRender();
+    }
+    const signature = this.renderSignature;
+    signature.length = 0;
+    signature.push(...this.camera.viewMatrix, ...this.camera.unjitteredProjectionMatrix);
+    signature.push(
+      this.renderer.width,
+      this.renderer.height,
+      gsRenderer?.getSplatCount() ?? -1,
+      this.trainingChannel?.getGeneration() ?? -1,
+      this.meshRenderer.getMeshCount(),
+      this.sceneManager.getSplatRootLayer().getEntityCount(),
+      this.sceneManager.getSplats()?.getVersion() ?? -1,
+      this.sceneManager.getSplatSelection()?.getVersion() ?? -1,
+      this.sceneManager.getSplatGroups()?.getVersion() ?? -1,
+    );
+    return signature;
+  }
+
+  /**
+   * 用当前相机矩阵绘制并提交一帧
+   */
+  private renderScene(): void {
+
+    // 训练器发布了新数据时交给渲染器（只上传修改的区间）
+    const trainingUpdate = this.trainingChannel?.consume();
+    if (trainingUpdate) {
+      this.sceneManager.updateSplatData(trainingUpdate.data, trainingUpdate.dirty);
+    }
+
+    // 超出显存预算时降级，有余量时恢复逐出的数据
+    this.memoryBudget.enforce();
+
+    // 网格阴影贴图在主渲染通道之前提交
+    this.meshRenderer.renderShadowMap();
+
+    let pass = this.renderer.beginFrame();
+
+    // 地面网格在 splat 之前绘制，splat 混合在其上
+    this.gridRenderer.render(pass);
 
     // 渲染 3D Gaussian Splatting
     const gsRenderer = this.sceneManager.getGSRenderer();
     if (gsRenderer) {
       gsRenderer.render(pass);
     }
+    this.sceneManager.renderSplatLayers(pass, this.camera.position);
+
+    // 写入了深度的 splat 接收网格阴影（深度只读的通道中采样深度缓冲）
+    if (this.meshRenderer.isShadowActive()) {
+      pass = this.renderer.continueFrame(true);
+      this.meshRenderer.renderShadowReceiver(pass, this.renderer.depthTexture);
+      pass = this.renderer.continueFrame();
+    }
 
     // 渲染网格
     this.meshRenderer.render(pass);
 
+    // 分屏对比的参考图像覆盖在场景之上，Gizmo 仍然可见
+    this.comparison.render(pass);
+
     // 渲染 Gizmo
     this.gizmoManager.render(pass);
 
     this.renderer.endFrame();
   }
 
+  /**
+   * 从指定视点渲染一帧到离屏纹理并回读（RGBA f32，画布编码值），画布和当前相机不受影响
+   * 不依赖渲染循环，画布不在页面中（没有窗口）时也可以使用，适合对验证视角计算 PSNR / SSIM、批量生成缩略图。
+   * 不执行 onUpdate 回调和时间抗锯齿抖动；多次调用按顺序等待即可
+   */
+  async renderView(view: OffscreenRenderView): Promise<FrameCapture> {
+    const camera = this.camera;
+    const saved = {
+      position: camera.position.slice(),
+      target: camera.target.slice(),
+      up: camera.up.slice(),
+      fov: camera.fov,
+      jitter: camera.jitter.slice(),
+    };
+
+    camera.position.set(view.position);
+    camera.target.set(view.target);
+    if (view.up) camera.up.set(view.up);
+    if (view.fovY !== undefined) camera.fov = view.fovY;
+    camera.clearJitter();
+
+    const clearColor = this.renderer.getClearColor();
+    if (view.transparentBackground) {
+      this.renderer.setClearColor(0, 0, 0, 0);
+    }
+    this.renderer.setRenderTarget({ width: view.width, height: view.height });
+    const capture = this.renderer.captureNextFrame();
+    try {
+      camera.setAspect(this.renderer.getAspectRatio());
+      camera.updateMatrix();
+      this.renderScene();
+    } finally {
+      this.renderer.setRenderTarget(null);
+      this.renderer.setClearColor(clearColor.r, clearColor.g, clearColor.b, clearColor.a);
+      camera.position.set(saved.position);
+      camera.target.set(saved.target);
+      camera.up.set(saved.up);
+      camera.fov = saved.fov;
+      camera.jitter.set(saved.jitter);
+      camera.setAspect(this.renderer.getAspectRatio());
+      camera.updateMatrix();
+    }
+    return capture;
+  }
+
+  /**
+   * 截图：以任意分辨率离屏渲染当前（或指定）视点并编码为 PNG，指定 path 时同时下载
+   */
+  async captureScreenshot(request: SplatScreenshotRequest = {}): Promise<Blob> {
+    const [width, height] = request.resolution ?? [this.renderer.width, this.renderer.height];
+    const maxSize = this.renderer.device.limits.maxTextureDimension2D;
+    if (width > maxSize || height > maxSize) {
+      throw new Error(`[App] 截图分辨率 ${width}x${height} 超过设备最大纹理尺寸 ${maxSize}`);
+    }
+    const { position, target } = this.camera;
+    const view = request.view ?? {
+      position: [position[0], position[1], position[2]],
+      target: [target[0], target[1], target[2]],
+    };
+
+    const capture = await this.renderView({ ...view, width, height, transparentBackground: request.transparentBackground });
+    const blob = await encodePNG(frameCaptureToRGBA8(capture), capture.width, capture.height);
+    if (request.path) {
+      downloadBlob(blob, request.path);
+    }
+    return blob;
+  }
+
+  /**
+   * 从训练相机的视点离屏渲染（默认使用相机记录的图像尺寸），用于和对应的训练 / 验证图像比较
+   * @param scale 输出尺寸相对相机图像尺寸的比例
+   */
+  renderTrainingCameraView(camera: TrainingCamera | number, scale: number = 1): Promise<FrameCapture> {
+    const source = typeof camera === 'number' ? this.getTrainingCameras()[camera] : camera;
+    if (!source) {
+      return Promise.reject(new Error(`[App] 训练相机不存在: ${camera}`));
+    }
+    const m = source.cameraToWorld;
+    const [x, y, z] = source.position;
+    // 数据集没有记录图像尺寸时按画布高度和相机宽高比
+    const height = source.height > 0 ? source.height : this.renderer.height;
+    const width = source.width > 0 ? source.width : height * source.aspect;
+    return this.renderView({
+      position: [x, y, z],
+      target: [x - m[8], y - m[9], z - m[10]],
+      up: [m[4], m[5], m[6]],
+      fovY: source.fovY,
+      width: Math.max(1, Math.round(width * scale)),
+      height: Math.max(1, Math.round(height * scale)),
+    });
+  }
+
   private onResize(): void {
     this.camera.setAspect(this.renderer.getAspectRatio());
     this.camera.updateMatrix();
@@ -365,6 +891,99 @@ export class App {
     return this.sceneManager.getSplatCount();
   }
 
+  /**
+   * 获取 Splat 数据访问器（逐 splat 读取 / 修改，修改后下一帧自动上传）
+   */
+  getSplats(): GaussianSplats | null {
+    return this.sceneManager.getSplats();
+  }
+
+  /**
+   * 整批更新 Splat 数据（训练等场景），只上传 dirty 中的区间
+   */
+  updateSplatData(data: CompactSplatData, dirty?: SplatDirtyRanges): void {
+    this.sceneManager.updateSplatData(data, dirty);
+  }
+
+  /**
+   * 连接训练数据双缓冲通道（null 断开）：每帧渲染前取得训练器最新发布的数据，
+   * 训练器写入后台数据时渲染器上传前台数据，互不干扰。当前模型的数据由通道接管，
+   * 没有 splat 模型时按通道的数据创建
+   */
+  setTrainingSplatChannel(channel: TrainingSplatChannel | null): void {
+    this.trainingChannel = channel;
+    if (channel && !this.sceneManager.getGSRenderer()) {
+      const update = channel.consume();
+      if (update) {
+        const gsRenderer = this.createSplatEntity(update.data);
+        this.sceneManager.setGSRenderer(gsRenderer);
+        this.useMobileRenderer = gsRenderer instanceof GSSplatRendererMobile;
+      }
+    }
+  }
+
+  getTrainingSplatChannel(): TrainingSplatChannel | null {
+    return this.trainingChannel;
+  }
+
+  /**
+   * 连接远程训练服务（WebSocket）：splat 数据和增量写入训练通道，预览图像显示为分屏对比的参考图像，
+   * 训练指标写入 getTrainingMetrics()。再次调用时先断开之前的连接
+   * @param handlers 额外的回调（连接状态、错误等），与内置处理一起调用
+   */
+  connectRemoteTraining(
+    url: string,
+    handlers: RemoteTrainingHandlers = {},
+    config?: Partial<RemoteTrainingClientConfig>,
+  ): RemoteTrainingClient {
+    this.disconnectRemoteTraining();
+    this.remoteTraining = new RemoteTrainingClient(url, {
+      ...handlers,
+      onChannel: (channel) => {
+        this.setTrainingSplatChannel(channel);
+        handlers.onChannel?.(channel);
+      },
+      onFrame: (pixels, width, height) => {
+        this.updateComparisonPixels(pixels, width, height);
+        handlers.onFrame?.(pixels, width, height);
+      },
+      onMetrics: (sample) => {
+        this.updateTrainingMetrics(sample);
+        handlers.onMetrics?.(sample);
+      },
+    }, config);
+    return this.remoteTraining;
+  }
+
+  /**
+   * 断开远程训练连接（保留已收到的数据）
+   */
+  disconnectRemoteTraining(): void {
+    if (!this.remoteTraining) return;
+    if (this.trainingChannel === this.remoteTraining.getChannel()) {
+      this.trainingChannel = null;
+    }
+    this.remoteTraining.close();
+    this.remoteTraining = null;
+  }
+
+  /**
+   * 使用外部 GPU buffer（同一 device 上的训练循环等）作为当前 splat 模型，不经过 CPU
+   * 外部数据更新后调用 updateExternalSplatBuffers 重新打包
+   */
+  loadExternalSplatBuffers(buffers: SplatExternalBuffers): IGSSplatRenderer {
+    const gsRenderer = new GSSplatRenderer(this.renderer, this.camera);
+    gsRenderer.setExternalBuffers(buffers);
+    this.applyPlatformDefaults(gsRenderer);
+    this.sceneManager.setGSRenderer(gsRenderer);
+    this.useMobileRenderer = false;
+    return gsRenderer;
+  }
+
+  updateExternalSplatBuffers(): boolean {
+    return this.sceneManager.updateExternalSplatBuffers();
+  }
+
   clearSplats(): void {
     this.sceneManager.clearSplats();
     this.useMobileRenderer = false;
@@ -376,6 +995,7 @@ export class App {
 
   setSplatPosition(x: number, y: number, z: number): void {
     this.sceneManager.setSplatPosition(x, y, z);
+    this.requestRender();
   }
 
   getSplatPosition(): [number, number, number] | null {
@@ -384,6 +1004,7 @@ export class App {
 
   setSplatRotation(x: number, y: number, z: number): void {
     this.sceneManager.setSplatRotation(x, y, z);
+    this.requestRender();
   }
 
   getSplatRotation(): [number, number, number] | null {
@@ -392,181 +1013,1815 @@ export class App {
 
   setSplatScale(x: number, y: number, z: number): void {
     this.sceneManager.setSplatScale(x, y, z);
+    this.requestRender();
   }
 
   getSplatScale(): [number, number, number] | null {
     return this.sceneManager.getSplatScale();
   }
 
-  // ============================================
-  // SH 模式
-  // ============================================
+  /**
+   * 设置是否自动修正 Splat 的非有限值 / 剪切变换（默认只输出警告；零缩放始终只警告）
+   */
+  setSplatTransformAutoCorrect(enabled: boolean): void {
+    this.sceneManager.setSplatTransformAutoCorrect(enabled);
+    this.requestRender();
+  }
 
-  setSHMode(mode: 0 | 1 | 2 | 3): void {
-    this.sceneManager.setSHMode(mode);
+  getSplatTransformIssues(): TransformIssue[] {
+    return this.sceneManager.getSplatTransformIssues();
   }
 
-  getSHMode(): number {
-    return this.sceneManager.getSHMode();
+  /**
+   * 设置 Splat 变换（可撤销）
+   */
+  setSplatTransform(transform: Partial<SplatTransformState>): void {
+    this.sceneManager.setSplatTransform(transform);
+    this.requestRender();
   }
 
-  // ============================================
-  // Bounding Box
-  // ============================================
+  /**
+   * 开始连续的变换编辑（如 gizmo 拖动），commitSplatTransformEdit 时记录为一步
+   */
+  beginSplatTransformEdit(): void {
+    this.sceneManager.beginSplatTransformEdit();
+  }
 
-  getSplatBoundingBox(): BoundingBox | null {
-    return this.sceneManager.getSplatBoundingBox();
+  commitSplatTransformEdit(): boolean {
+    const committed = this.sceneManager.commitSplatTransformEdit();
+    this.requestRender();
+    return committed;
   }
 
-  getMeshRangeBoundingBox(startIndex: number, count: number): BoundingBox | null {
-    return this.sceneManager.getMeshRangeBoundingBox(startIndex, count);
+  setSplatExposure(exposure: number): void {
+    this.sceneManager.setSplatExposure(exposure);
+    this.requestRender();
   }
 
-  // ============================================
-  // 材质颜色
-  // ============================================
+  getSplatExposure(): number {
+    return this.sceneManager.getSplatExposure();
+  }
 
-  getMeshColor(index: number): [number, number, number, number] | null {
-    return this.sceneManager.getMeshColor(index);
+  setSplatBlendMode(mode: SplatBlendMode): void {
+    this.sceneManager.setSplatBlendMode(mode);
+    this.requestRender();
   }
 
-  setMeshColor(index: number, r: number, g: number, b: number, a: number = 1): boolean {
-    return this.sceneManager.setMeshColor(index, r, g, b, a);
+  getSplatBlendMode(): SplatBlendMode {
+    return this.sceneManager.getSplatBlendMode();
   }
 
-  setMeshRangeColor(startIndex: number, count: number, r: number, g: number, b: number, a: number = 1): number {
-    return this.sceneManager.setMeshRangeColor(startIndex, count, r, g, b, a);
+  setSplatChunkedCulling(enabled: boolean): void {
+    this.sceneManager.setSplatChunkedCulling(enabled);
   }
 
-  // ============================================
-  // 相机控制
-  // ============================================
+  isSplatChunkedCulling(): boolean {
+    return this.sceneManager.isSplatChunkedCulling();
+  }
 
-  frameCurrentModel(animate: boolean = true): boolean {
-    const bbox = this.sceneManager.getSceneBoundingBox();
-    if (!bbox) {
-      return false;
-    }
+  buildSplatPVS(config?: Partial<SplatPVSConfig>): Promise<void> {
+    return this.sceneManager.buildSplatPVS(config);
+  }
 
-    this.controls.frameModel(bbox.center, bbox.radius, animate);
-    return true;
+  clearSplatPVS(): void {
+    this.sceneManager.clearSplatPVS();
   }
 
-  // ============================================
-  // Gizmo（委托给 GizmoManager）
-  // ============================================
+  setSplatOcclusionCulling(enabled: boolean): void {
+    this.sceneManager.setSplatOcclusionCulling(enabled);
+  }
 
-  getTransformGizmo() {
-    return this.gizmoManager.getTransformGizmo();
+  isSplatOcclusionCulling(): boolean {
+    return this.sceneManager.isSplatOcclusionCulling();
   }
 
-  getViewportGizmo() {
-    return this.gizmoManager.getViewportGizmo();
+  setSplatSortAmortization(interval: number): void {
+    this.sceneManager.setSplatSortAmortization(interval);
   }
 
-  getBoundingBoxRenderer() {
-    return this.gizmoManager.getBoundingBoxRenderer();
+  getSplatSortAmortization(): number {
+    return this.sceneManager.getSplatSortAmortization();
   }
 
-  setGizmoMode(mode: GizmoMode): void {
-    this.gizmoManager.setGizmoMode(mode);
+  /**
+   * 设置 Splat 摆动动画（风吹植被，仅桌面端），例如 { enabled: true, amplitude: 0.05, heightRange: 2 }
+   */
+  setSplatSway(config: Partial<SplatSwayConfig>): void {
+    this.sceneManager.setSplatSway(config);
   }
 
-  setGizmoTarget(object: TransformableObject | null): void {
-    this.gizmoManager.setGizmoTarget(object);
+  getSplatSway(): SplatSwayConfig {
+    return this.sceneManager.getSplatSway();
   }
 
-  setSelectionBoundingBox(box: BoundingBox | null): void {
-    this.gizmoManager.setSelectionBoundingBox(box);
+  setSplatSortReuse(enabled: boolean): void {
+    this.sceneManager.setSplatSortReuse(enabled);
   }
 
-  setSelectionBoundingBoxProvider(provider: BoundingBoxProvider | null): void {
-    this.gizmoManager.setSelectionBoundingBoxProvider(provider);
+  isSplatSortReuse(): boolean {
+    return this.sceneManager.isSplatSortReuse();
   }
 
-  clearSelectionBoundingBox(): void {
-    this.gizmoManager.clearSelectionBoundingBox();
+  getSplatSortReuseStats(): SplatSortCacheStats | null {
+    return this.sceneManager.getSplatSortReuseStats();
   }
 
-  /**
-   * 创建 Mesh 组的变换代理
-   */
-  createMeshGroupProxy(startIndex: number, count: number): MeshGroupProxy | null {
-    const meshes = this.sceneManager.getMeshRange(startIndex, count);
-    return this.gizmoManager.createMeshGroupProxy(meshes);
+  setSplatDepthKeyMode(mode: SplatDepthKeyMode): void {
+    this.sceneManager.setSplatDepthKeyMode(mode);
   }
 
-  /**
-   * 获取 Splat 的变换代理
-   */
-  getSplatTransformProxy(): SplatTransformProxy | null {
-    const gsRenderer = this.sceneManager.getGSRenderer();
-    if (!gsRenderer) return null;
-    return this.gizmoManager.createSplatTransformProxy(gsRenderer);
+  getSplatDepthKeyMode(): SplatDepthKeyMode {
+    return this.sceneManager.getSplatDepthKeyMode();
   }
 
-  /**
-   * 创建 Splat 包围盒提供者
-   */
-  createSplatBoundingBoxProvider(): SplatBoundingBoxProvider | null {
-    const gsRenderer = this.sceneManager.getGSRenderer();
-    if (!gsRenderer) return null;
-    return this.gizmoManager.createSplatBoundingBoxProvider(gsRenderer);
+  setSplatPassValidation(enabled: boolean): void {
+    this.sceneManager.setSplatPassValidation(enabled);
   }
 
-  // ============================================
-  // 子系统访问
-  // ============================================
+  isSplatPassValidation(): boolean {
+    return this.sceneManager.isSplatPassValidation();
+  }
 
-  getRenderer(): Renderer {
-    return this.renderer;
+  getSplatPassReport(): string {
+    return this.sceneManager.getSplatPassReport();
   }
 
-  getCamera(): Camera {
-    return this.camera;
+  setSplatCameraInsideFade(enabled: boolean): void {
+    this.sceneManager.setSplatCameraInsideFade(enabled);
+    this.requestRender();
   }
 
-  getControls(): OrbitControls {
-    return this.controls;
+  isSplatCameraInsideFade(): boolean {
+    return this.sceneManager.isSplatCameraInsideFade();
   }
 
-  getMeshRenderer(): MeshRenderer {
-    return this.meshRenderer;
+  setSplatCameraInsideCull(enabled: boolean): void {
+    this.sceneManager.setSplatCameraInsideCull(enabled);
+    this.requestRender();
   }
 
-  getGSRenderer(): GSSplatRenderer | undefined {
-    const renderer = this.sceneManager.getGSRenderer();
-    if (renderer && !this.useMobileRenderer) {
-      return renderer as GSSplatRenderer;
-    }
-    return undefined;
+  isSplatCameraInsideCull(): boolean {
+    return this.sceneManager.isSplatCameraInsideCull();
   }
 
-  getGSRendererMobile(): GSSplatRendererMobile | undefined {
-    const renderer = this.sceneManager.getGSRenderer();
-    if (renderer && this.useMobileRenderer) {
-      return renderer as GSSplatRendererMobile;
-    }
-    return undefined;
+  setSplatNearFadeDistance(distance: number): void {
+    this.sceneManager.setSplatNearFadeDistance(distance);
+    this.requestRender();
   }
 
-  isUsingMobileRenderer(): boolean {
-    return this.useMobileRenderer;
+  getSplatNearFadeDistance(): number {
+    return this.sceneManager.getSplatNearFadeDistance();
+  }
+
+  setSplatLod(config: Partial<SplatLodConfig> | null): void {
+    this.sceneManager.setSplatLod(config);
+    this.requestRender();
+  }
+
+  getSplatLod(): SplatLodConfig | null {
+    return this.sceneManager.getSplatLod();
   }
 
   // ============================================
-  // 内部方法
+  // Splat 拾取
   // ============================================
 
-  private async fetchWithProgress(
-    url: string,
-    onProgress?: (progress: number) => void
-  ): Promise<ArrayBuffer> {
-    const response = await fetch(url);
-    if (!response.ok) {
-      throw new Error(`无法加载文件: ${url}`);
-    }
-
+  /**
+   * 拾取画布像素 (x, y) 下最近的 splat
+   */
+  pickSplat(x: number, y: number): Promise<SplatPickResult | null> {
+    return this.sceneManager.pickSplat(x, y);
+  }
+
+  /**
+   * 在当前模型和所有可见图层实体中拾取画布像素 (x, y) 下最近的 splat，返回 (实体, splat 索引)
+   */
+  async pickSplatEntity(x: number, y: number): Promise<SplatEntityPickResult | null> {
+    return (await this.sceneManager.pickSplatEntities(x, y, this.camera.position))[0] ?? null;
+  }
+
+  /**
+   * 拾取画布像素 (x, y) 下每个实体的命中，按到相机的距离由近到远排序（移动端渲染器的实体不参与拾取）
+   */
+  pickSplatEntities(x: number, y: number): Promise<SplatEntityPickResult[]> {
+    return this.sceneManager.pickSplatEntities(x, y, this.camera.position);
+  }
+
+  /**
+   * 查询画布像素 (x, y) 处的 splat 深度和世界坐标（默认按不透明度混合，用于测量）
+   */
+  getSplatDepthAt(x: number, y: number, mode: SplatDepthQueryMode = "blended"): Promise<SplatDepthResult | null> {
+    return this.sceneManager.getSplatDepthAt(x, y, mode);
+  }
+
+  /**
+   * 在另一个画布中持续显示当前视图（按比例缩放，同一 GPUDevice），用于 UI 面板中的预览
+   * 不再需要时调用预览对象的 destroy；App 销毁时一并销毁
+   */
+  createFramePreview(canvas: HTMLCanvasElement): FramePreview {
+    const preview = new FramePreview(this.renderer, canvas);
+    this.framePreviews.add(preview);
+    return preview;
+  }
+
+  /**
+   * 设置分屏对比的参考图像（URL、图片文件或同一设备上的纹理），null 关闭对比
+   * 分割线一侧显示实时渲染，另一侧显示参考图像（拉伸到整个画布），用于评估训练 / 重建质量
+   * 传入的纹理不会被销毁，从 URL / 文件加载的纹理在替换时释放
+   * @returns 是否加载成功
+   */
+  async setComparisonImage(source: string | Blob | GPUTexture | null): Promise<boolean> {
+    if (source === null || source instanceof GPUTexture) {
+      this.comparison.setImage(source);
+      return true;
+    }
+    const texture = typeof source === "string"
+      ? await loadTextureFromURL(this.renderer.device, source)
+      : await loadTextureFromBlob(this.renderer.device, source);
+    if (!texture) return false;
+    this.comparison.setImage(texture, true);
+    return true;
+  }
+
+  /**
+   * 从 RGBA8 像素数组更新参考图像（训练器每次迭代的预览等），尺寸不变时复用同一个纹理，
+   * 直接从传入的数组上传，不解码、不复制
+   */
+  updateComparisonPixels(data: ArrayBufferView, width: number, height: number, bytesPerRow?: number): void {
+    this.comparison.writeImage(data, width, height, bytesPerRow);
+  }
+
+  /**
+   * 从同一设备上的 GPU buffer（RGBA8，COPY_SRC 用途，每行 256 字节对齐）更新参考图像，不经过 CPU
+   * 参考图像需要保持为训练器自己的纹理时，直接把纹理传给 setComparisonImage
+   */
+  updateComparisonFromBuffer(buffer: GPUBuffer, width: number, height: number, bytesPerRow: number, offset?: number): void {
+    this.comparison.copyImageFromBuffer(buffer, width, height, bytesPerRow, offset);
+  }
+
+  /**
+   * 开关分屏对比（保留参考图像）
+   */
+  setComparisonEnabled(enabled: boolean): void {
+    this.comparison.enabled = enabled;
+  }
+
+  isComparisonEnabled(): boolean {
+    return this.comparison.enabled && this.comparison.getImage() !== null;
+  }
+
+  /**
+   * 设置分割线位置（0~1，从左 / 上量起），也可以在画布上拖动分割线
+   */
+  setComparisonDivider(position: number): void {
+    this.comparison.setDivider(position);
+  }
+
+  getComparisonDivider(): number {
+    return this.comparison.getDivider();
+  }
+
+  setComparisonConfig(config: Partial<SplitViewComparisonConfig>): void {
+    this.comparison.setConfig(config);
+  }
+
+  getComparisonConfig(): SplitViewComparisonConfig {
+    return this.comparison.getConfig();
+  }
+
+  /**
+   * 订阅每帧画面纹理（可采样，格式与画布相同），用于自定义的 UI 显示
+   * @returns 取消订阅函数
+   */
+  onFrameTexture(callback: (texture: GPUTexture) => void): () => void {
+    return this.renderer.onFrameTexture(callback);
+  }
+
+  /**
+   * 渲染一帧并回读当前视图的图层：颜色（画布内容）、splat 深度和 splat ID
+   * 深度和 ID 只包含主模型，移动端渲染器不支持时为空
+   */
+  async captureRenderLayers(): Promise<SplatRenderLayers> {
+    const capture = this.renderer.captureNextFrame();
+    this.renderOnDemand.requestRender();
+    this.render();
+    // 拾取 pass 在调用时同步提交，使用刚渲染的这一帧的相机
+    const idDepth = this.sceneManager.readSplatIdDepth();
+    return createSplatRenderLayers(await capture, await idDepth);
+  }
+
+  /**
+   * 导出当前视图为多通道 EXR（R/G/B/A、Z、SplatID）
+   */
+  async exportRenderLayersEXR(): Promise<Blob> {
+    const layers = await this.captureRenderLayers();
+    return new Blob([encodeSplatRenderLayersEXR(layers)], { type: 'image/x-exr' });
+  }
+
+  /**
+   * 导出当前视图为分开的文件：颜色 PNG、ID PNG 和深度 EXR
+   */
+  async exportRenderLayerFiles(): Promise<SplatRenderLayerFiles> {
+    return encodeSplatRenderLayerFiles(await this.captureRenderLayers());
+  }
+
+  /**
+   * 监听 splat 指针事件（over/out/click/dragstart/drag/dragend）
+   * @returns 取消监听函数
+   */
+  onSplatPointer(type: SplatPointerEventType, callback: SplatPointerCallback): () => void {
+    return this.splatPicking.on(type, callback);
+  }
+
+  /**
+   * 获取 splat 拾取管理器
+   */
+  getSplatPicking(): SplatPicking {
+    return this.splatPicking;
+  }
+
+  /**
+   * 开关悬停高亮：按固定间隔拾取光标下的 splat 并在 shader 中提亮（可选整个分组）
+   */
+  setSplatHoverHighlightEnabled(enabled: boolean): void {
+    this.splatHover.enabled = enabled;
+  }
+
+  isSplatHoverHighlightEnabled(): boolean {
+    return this.splatHover.enabled;
+  }
+
+  setSplatHoverHighlightConfig(config: Partial<SplatHoverHighlightConfig>): void {
+    this.splatHover.setConfig(config);
+  }
+
+  getSplatHoverHighlightConfig(): SplatHoverHighlightConfig {
+    return this.splatHover.getConfig();
+  }
+
+  /**
+   * 开关画笔选择：启用时左键拖动在光标半径内选择 splat（按住 Alt 移除），
+   * 同时暂停相机控制和 splat 指针事件；每一笔可撤销
+   */
+  setSplatBrushEnabled(enabled: boolean): void {
+    this.splatBrush.enabled = enabled;
+    if (enabled) this.splatLasso.enabled = false;
+    this.splatPicking.enabled = !enabled;
+    this.controls.enabled = !enabled;
+  }
+
+  isSplatBrushEnabled(): boolean {
+    return this.splatBrush.enabled;
+  }
+
+  setSplatBrushConfig(config: Partial<SplatBrushConfig>): void {
+    this.splatBrush.setConfig(config);
+  }
+
+  getSplatBrushConfig(): SplatBrushConfig {
+    return this.splatBrush.getConfig();
+  }
+
+  /**
+   * 开关套索选择：启用时左键拖动绘制多边形，抬起时选择其中的 splat（Shift 加入，Alt 移除），
+   * 同时暂停相机控制和 splat 指针事件；每次套索可撤销
+   */
+  setSplatLassoEnabled(enabled: boolean): void {
+    this.splatLasso.enabled = enabled;
+    if (enabled) this.splatBrush.enabled = false;
+    this.splatPicking.enabled = !enabled;
+    this.controls.enabled = !enabled;
+  }
+
+  isSplatLassoEnabled(): boolean {
+    return this.splatLasso.enabled;
+  }
+
+  setSplatLassoConfig(config: Partial<SplatLassoConfig>): void {
+    this.splatLasso.setConfig(config);
+  }
+
+  /**
+   * 获取绘制中的套索路径（CSS 像素，相对视口），用于绘制叠加层
+   */
+  getSplatLassoPath(): ReadonlyArray<readonly [number, number]> {
+    return this.splatLasso.getPath();
+  }
+
+  /**
+   * 选择中心投影落在屏幕多边形（画布像素）内的 splat（可撤销）
+   * @returns 操作后的选中数量
+   */
+  selectSplatsInPolygon(points: ReadonlyArray<readonly [number, number]>, op?: SelectionOp): Promise<number> {
+    return this.sceneManager.selectSplatsInPolygon(points, op);
+  }
+
+  // ============================================
+  // SH 模式
+  // ============================================
+
+  setSHMode(mode: 0 | 1 | 2 | 3): void {
+    this.sceneManager.setSHMode(mode);
+    this.requestRender();
+  }
+
+  getSHMode(): number {
+    return this.sceneManager.getSHMode();
+  }
+
+  setSHDistances(distances: Vec3Tuple | null): void {
+    this.sceneManager.setSHDistances(distances);
+    this.requestRender();
+  }
+
+  getSHDistances(): Vec3Tuple {
+    return this.sceneManager.getSHDistances();
+  }
+
+  isSplatSurfel(): boolean {
+    return this.sceneManager.isSplatSurfel();
+  }
+
+  // ============================================
+  // 语义标签 / 可视化 / 选择
+  // ============================================
+
+  setSplatLabels(ids: Uint16Array | Uint32Array | null): void {
+    this.sceneManager.setSplatLabels(ids);
+  }
+
+  getSplatLabels(): SplatLabels | null {
+    return this.sceneManager.getSplatLabels();
+  }
+
+  setSplatVisMode(mode: SplatVisMode | string): void {
+    this.sceneManager.setSplatVisMode(mode);
+    this.requestRender();
+  }
+
+  getSplatVisMode(): SplatVisMode {
+    return this.sceneManager.getSplatVisMode();
+  }
+
+  getSplatSelection(): SplatSelection | null {
+    return this.sceneManager.getSplatSelection();
+  }
+
+  /**
+   * 按属性阈值选择 splat（可撤销），谓词之间为“与”
+   * 例如 app.selectSplatsByAttributes([{ attribute: "opacity", op: "<", value: 0.05 }])
+   * @param gpu 使用 GPU 筛选（大场景更快，渲染器不支持时回退到 CPU）
+   */
+  selectSplatsByAttributes(
+    predicates: ReadonlyArray<SplatAttributePredicate>,
+    op?: SelectionOp,
+    gpu?: boolean,
+  ): Promise<number> {
+    return this.sceneManager.selectSplatsByAttributes(predicates, op, gpu);
+  }
+
+  selectSplatsByLabel(label: number | number[], op?: SelectionOp): number {
+    return this.sceneManager.selectSplatsByLabel(label, op);
+  }
+
+  editSplatSelection(edit: (selection: SplatSelection) => void, label?: string): number {
+    return this.sceneManager.editSplatSelection(edit, label);
+  }
+
+  /**
+   * 扩张选择（可撤销）
+   * @param distance 与已选 splat 中心的最大距离（模型空间）
+   */
+  growSplatSelection(distance: number): number {
+    return this.sceneManager.growSplatSelection(distance);
+  }
+
+  /**
+   * 收缩选择：去掉边界 splat（可撤销）
+   * @param distance 边界判断距离（模型空间）
+   */
+  shrinkSplatSelection(distance: number): number {
+    return this.sceneManager.shrinkSplatSelection(distance);
+  }
+
+  clearSplatSelection(): void {
+    this.sceneManager.clearSplatSelection();
+  }
+
+  deleteSplats(indices: ArrayLike<number>): number {
+    return this.sceneManager.deleteSplats(indices);
+  }
+
+  /**
+   * 删除选中的 splat（可撤销），compact 为 true 时立即物理移除并重建 buffer
+   */
+  deleteSelectedSplats(compact: boolean = false): number {
+    return this.sceneManager.deleteSelectedSplats(compact);
+  }
+
+  /**
+   * 选中符合漂浮物特征的 splat（超大且透明 / 孤立的大 splat，可撤销）
+   * @param config cameraPositions 为世界空间坐标（例如训练相机位置）
+   */
+  selectSplatFloaters(config?: Partial<SplatFloaterConfig>, op?: SelectionOp): number {
+    return this.sceneManager.selectSplatFloaters(config, op);
+  }
+
+  /**
+   * 一键删除漂浮物（可撤销）
+   */
+  removeSplatFloaters(config?: Partial<SplatFloaterConfig>): number {
+    return this.sceneManager.removeSplatFloaters(config);
+  }
+
+  recolorSplats(indices: ArrayLike<number>, color: Vec3Tuple | ((index: number, color: Vec3Tuple) => Vec3Tuple)): number {
+    return this.sceneManager.recolorSplats(indices, color);
+  }
+
+  recolorSelectedSplats(color: Vec3Tuple | ((index: number, color: Vec3Tuple) => Vec3Tuple)): number {
+    return this.sceneManager.recolorSelectedSplats(color);
+  }
+
+  /**
+   * 实时预览选中 splat 的颜色调整（色相 / 饱和度 / 亮度 / 着色），null 关闭预览
+   */
+  previewSplatColorAdjustment(adjustment: SplatColorAdjustment | null): void {
+    this.sceneManager.previewSplatColorAdjustment(adjustment);
+  }
+
+  /**
+   * 把颜色调整写入选中 splat（可撤销），不传参数时提交当前预览
+   */
+  applySplatColorAdjustment(adjustment?: SplatColorAdjustment): number {
+    return this.sceneManager.applySplatColorAdjustment(adjustment);
+  }
+
+  setSplatOpacities(indices: ArrayLike<number>, opacity: number | ((index: number, opacity: number) => number)): number {
+    return this.sceneManager.setSplatOpacities(indices, opacity);
+  }
+
+  /**
+   * 实时预览选中 splat 的不透明度编辑（缩放或设置），null 关闭预览
+   */
+  previewSplatOpacityEdit(edit: SplatOpacityEdit | null): void {
+    this.sceneManager.previewSplatOpacityEdit(edit);
+  }
+
+  /**
+   * 把不透明度编辑写入选中 splat（可撤销），不传参数时提交当前预览
+   */
+  applySplatOpacityEdit(edit?: SplatOpacityEdit): number {
+    return this.sceneManager.applySplatOpacityEdit(edit);
+  }
+
+  /**
+   * 设置软裁剪盒（拖动手柄时直接修改盒子，下一帧生效），null 关闭
+   * 例如 SplatCropBox.fromBoundingBox(app.getSplatBoundingBox()!)
+   */
+  setSplatCropBox(cropBox: SplatCropBox | null): void {
+    this.sceneManager.setSplatCropBox(cropBox);
+    this.requestRender();
+  }
+
+  getSplatCropBox(): SplatCropBox | null {
+    return this.sceneManager.getSplatCropBox();
+  }
+
+  /**
+   * 获取 splat 分组（编辑图层）
+   * @param create 没有分组时创建
+   */
+  getSplatGroups(create?: boolean): SplatGroups | null {
+    return this.sceneManager.getSplatGroups(create);
+  }
+
+  getSplatGroupInfos(): SplatGroupInfo[] {
+    return this.sceneManager.getSplatGroupInfos();
+  }
+
+  /**
+   * 创建分组，返回分组 id（最多 15 个）
+   */
+  createSplatGroup(name: string, tint?: Vec3Tuple | null): number | null {
+    return this.sceneManager.createSplatGroup(name, tint);
+  }
+
+  deleteSplatGroup(id: number): boolean {
+    return this.sceneManager.deleteSplatGroup(id);
+  }
+
+  /**
+   * 把选中的 splat 分配到分组（id 为 0 时移出分组）
+   */
+  assignSelectionToSplatGroup(id: number): number {
+    return this.sceneManager.assignSelectionToSplatGroup(id);
+  }
+
+  selectSplatGroup(id: number, op?: SelectionOp): number {
+    return this.sceneManager.selectSplatGroup(id, op);
+  }
+
+  setSplatGroupVisible(id: number, visible: boolean): void {
+    this.sceneManager.setSplatGroupVisible(id, visible);
+    this.requestRender();
+  }
+
+  setSplatGroupLocked(id: number, locked: boolean): void {
+    this.sceneManager.setSplatGroupLocked(id, locked);
+  }
+
+  /**
+   * 只显示该分组，null 取消
+   */
+  soloSplatGroup(id: number | null): void {
+    this.sceneManager.soloSplatGroup(id);
+    this.requestRender();
+  }
+
+  setSplatGroupTint(id: number, tint: Vec3Tuple | null, strength?: number): void {
+    this.sceneManager.setSplatGroupTint(id, tint, strength);
+    this.requestRender();
+  }
+
+  /**
+   * 硬裁剪：删除被裁掉的 splat（可撤销），不传参数时使用当前的软裁剪盒
+   */
+  applySplatCropBox(cropBox?: SplatCropBox): number {
+    return this.sceneManager.applySplatCropBox(cropBox);
+  }
+
+  /**
+   * 加载第二套外观：同一几何的另一次采集（例如夜晚），splat 数量和顺序必须与当前模型一致，
+   * 只使用其中的颜色和 SH。之后用 setSplatAppearanceBlend 在两套外观之间过渡
+   * @param importOptions 与加载当前模型时相同的导入选项（保证 splat 一一对应）
+   * @returns splat 数量
+   */
+  async loadSplatAppearance(
+    urlOrBuffer: string | ArrayBuffer,
+    onProgress?: ProgressCallback,
+    importOptions: SplatImportOptions = {},
+  ): Promise<number> {
+    const buffer = typeof urlOrBuffer === 'string'
+      ? await this.fetchWithProgress(urlOrBuffer, (progress) => onProgress?.(progress * 0.5, 'download'))
+      : urlOrBuffer;
+    const data = await this.parsePLYBuffer(buffer, {
+      maxSplats: Infinity,
+      ...importOptions,
+      loadSH: !this.useMobileRenderer && getSplatPlatformDefaults().loadSH,
+      onProgress: (loaded, total) => onProgress?.(50 + (loaded / total) * 40, 'parse'),
+    }, typeof urlOrBuffer === 'string');
+    this.sceneManager.setSplatSecondaryAppearance(data);
+    onProgress?.(100, 'upload');
+    return data.count;
+  }
+
+  setSplatSecondaryAppearance(data: SplatAppearanceData | null): void {
+    this.sceneManager.setSplatSecondaryAppearance(data);
+  }
+
+  hasSplatSecondaryAppearance(): boolean {
+    return this.sceneManager.hasSplatSecondaryAppearance();
+  }
+
+  /**
+   * 设置两套外观的混合权重（0 为原始外观，1 为第二套外观），用于昼夜过渡
+   */
+  setSplatAppearanceBlend(t: number): void {
+    this.sceneManager.setSplatAppearanceBlend(t);
+    this.requestRender();
+  }
+
+  getSplatAppearanceBlend(): number {
+    return this.sceneManager.getSplatAppearanceBlend();
+  }
+
+  duplicateSelectedSplats(offset?: Vec3Tuple): number {
+    return this.sceneManager.duplicateSelectedSplats(offset);
+  }
+
+  /**
+   * 复制选中的 splat 并按平面镜像（修复只拍到一半的对称物体，可撤销）
+   * 例如 app.mirrorSelectedSplats({ normal: [1, 0, 0], point: [0, 0, 0] })
+   * @param plane 镜像平面（模型空间）
+   */
+  mirrorSelectedSplats(plane: SplatMirrorPlane): number {
+    return this.sceneManager.mirrorSelectedSplats(plane);
+  }
+
+  getDeletedSplatCount(): number {
+    return this.sceneManager.getDeletedSplatCount();
+  }
+
+  compactDeletedSplats(): number {
+    return this.sceneManager.compactDeletedSplats();
+  }
+
+  setSplatCompactionThreshold(fraction: number): void {
+    this.sceneManager.setSplatCompactionThreshold(fraction);
+  }
+
+  getSplatCompactionThreshold(): number {
+    return this.sceneManager.getSplatCompactionThreshold();
+  }
+
+  setSplatScalarAttribute(values: Float32Array | null, range?: [number, number]): void {
+    this.sceneManager.setSplatScalarAttribute(values, range);
+  }
+
+  getSplatScalarAttribute(): Float32Array | null {
+    return this.sceneManager.getSplatScalarAttribute();
+  }
+
+  /**
+   * 使用外部 GPU buffer（同一设备，每个 splat 一个 f32）作为标量属性，
+   * 训练器写入梯度大小或致密化得分后，配合 SplatVisMode.Scalar 查看哪些 splat 将被分裂 / 剪枝
+   */
+  setSplatScalarBuffer(source: SplatExternalBuffer | null, range?: [number, number]): void {
+    this.sceneManager.setSplatScalarBuffer(source, range);
+  }
+
+  /**
+   * 获取训练指标记录（历史样本和曲线）
+   */
+  getTrainingMetrics(): TrainingMetrics {
+    return this.trainingMetrics;
+  }
+
+  /**
+   * 写入一次迭代的训练指标（迭代次数、loss、PSNR、splat 数量、SH 阶数），通知 onTrainingMetrics 的监听者
+   * 未提供的字段沿用上一次的值
+   */
+  updateTrainingMetrics(sample: TrainingMetricsSample): TrainingMetricsSample {
+    return this.trainingMetrics.update(sample);
+  }
+
+  /**
+   * 监听训练指标（update 写入新样本，reset 清空历史），用于在 UI 中绘制曲线
+   * @returns 取消监听函数
+   */
+  onTrainingMetrics(callback: TrainingMetricsCallback, type: TrainingMetricsEventType = "update"): () => void {
+    return this.trainingMetrics.on(type, callback);
+  }
+
+  /**
+   * 训练指标中的 SH 阶数变化时是否同步预览的 SH 模式（默认开启）
+   */
+  setFollowTrainingSHDegree(enabled: boolean): void {
+    this.followTrainingSHDegree = enabled;
+  }
+
+  isFollowingTrainingSHDegree(): boolean {
+    return this.followTrainingSHDegree;
+  }
+
+  /**
+   * 按当前标量数据调整映射范围（外部 buffer 需要 COPY_SRC 用途）
+   * @param clip 两端各忽略的比例，用于排除长尾的极端值
+   */
+  fitSplatScalarRange(clip: number = 0): Promise<[number, number] | null> {
+    return this.sceneManager.fitSplatScalarRange(clip);
+  }
+
+  setSplatScalarRange(min: number, max: number): void {
+    this.sceneManager.setSplatScalarRange(min, max);
+    this.requestRender();
+  }
+
+  getSplatScalarRange(): [number, number] | null {
+    return this.sceneManager.getSplatScalarRange();
+  }
+
+  setSplatColormap(colormap: Colormap): void {
+    this.sceneManager.setSplatColormap(colormap);
+    this.requestRender();
+  }
+
+  getSplatColormap(): Colormap {
+    return this.sceneManager.getSplatColormap();
+  }
+
+  setSplatTransferFunction(transferFunction: TransferFunctionStop[] | Uint8Array | null): void {
+    this.sceneManager.setSplatTransferFunction(transferFunction);
+    this.requestRender();
+  }
+
+  setSplatVolumeOpacity(scale: number): void {
+    this.sceneManager.setSplatVolumeOpacity(scale);
+    this.requestRender();
+  }
+
+  getSplatVolumeOpacity(): number {
+    return this.sceneManager.getSplatVolumeOpacity();
+  }
+
+  setSplatRasterMode(mode: SplatRasterMode): void {
+    this.sceneManager.setSplatRasterMode(mode);
+    this.requestRender();
+  }
+
+  getSplatRasterMode(): SplatRasterMode {
+    return this.sceneManager.getSplatRasterMode();
+  }
+
+  setSplatMaxPerPixel(count: number): void {
+    this.sceneManager.setSplatMaxPerPixel(count);
+    this.requestRender();
+  }
+
+  getSplatMaxPerPixel(): number {
+    return this.sceneManager.getSplatMaxPerPixel();
+  }
+
+  setSplatDepthWrite(alphaThreshold: number): void {
+    this.sceneManager.setSplatDepthWrite(alphaThreshold);
+    this.requestRender();
+  }
+
+  getSplatDepthWrite(): number {
+    return this.sceneManager.getSplatDepthWrite();
+  }
+
+  setSplatPointMode(mode: SplatPointMode): void {
+    this.sceneManager.setSplatPointMode(mode);
+    this.requestRender();
+  }
+
+  getSplatPointMode(): SplatPointMode {
+    return this.sceneManager.getSplatPointMode();
+  }
+
+  setSplatPointSize(config: Partial<SplatPointSizeConfig>): void {
+    this.sceneManager.setSplatPointSize(config);
+    this.requestRender();
+  }
+
+  getSplatPointSize(): SplatPointSizeConfig {
+    return this.sceneManager.getSplatPointSize();
+  }
+
+  setSplatCentersConfig(config: Partial<SplatCentersConfig>): void {
+    this.sceneManager.setSplatCentersConfig(config);
+    this.requestRender();
+  }
+
+  getSplatCentersConfig(): SplatCentersConfig {
+    return this.sceneManager.getSplatCentersConfig();
+  }
+
+  setSplatCentersCursor(cursor: [number, number] | null): void {
+    this.sceneManager.setSplatCentersCursor(cursor);
+  }
+
+  // ============================================
+  // 撤销 / 重做
+  // ============================================
+
+  /**
+   * 获取 splat 编辑历史（可调整内存上限、查询可撤销的步骤）
+   */
+  getSplatEditHistory(): SplatEditHistory {
+    return this.sceneManager.getSplatEditHistory();
+  }
+
+  undo(): boolean {
+    const applied = this.sceneManager.undoSplatEdit();
+    this.requestRender();
+    return applied;
+  }
+
+  redo(): boolean {
+    const applied = this.sceneManager.redoSplatEdit();
+    this.requestRender();
+    return applied;
+  }
+
+  canUndo(): boolean {
+    return this.sceneManager.canUndoSplatEdit();
+  }
+
+  canRedo(): boolean {
+    return this.sceneManager.canRedoSplatEdit();
+  }
+
+  // ============================================
+  // 地面网格
+  // ============================================
+
+  setGridEnabled(enabled: boolean): void {
+    this.gridRenderer.setEnabled(enabled);
+    this.requestRender();
+  }
+
+  isGridEnabled(): boolean {
+    return this.gridRenderer.isEnabled();
+  }
+
+  setGridConfig(config: Partial<GridConfig>): void {
+    this.gridRenderer.setConfig(config);
+    this.requestRender();
+  }
+
+  getGridConfig(): GridConfig {
+    return this.gridRenderer.getConfig();
+  }
+
+  /**
+   * 获取当前网格的 [细线间距, 粗线间距]（场景单位）
+   */
+  getGridSpacing(): [number, number] {
+    return this.gridRenderer.getSpacing();
+  }
+
+  // ============================================
+  // 显存预算
+  // ============================================
+
+  /**
+   * 设置 splat 显存预算（字节），Infinity 或 0 表示不限制
+   */
+  setSplatMemoryBudget(bytes: number): void {
+    this.memoryBudget.setBudget(bytes);
+  }
+
+  getSplatMemoryBudget(): number {
+    return this.memoryBudget.getBudget();
+  }
+
+  getSplatMemoryReport(): SplatMemoryReport {
+    return this.memoryBudget.getReport();
+  }
+
+  getSplatMemoryBudgetManager(): SplatMemoryBudget {
+    return this.memoryBudget;
+  }
+
+  // ============================================
+  // 分帧上传
+  // ============================================
+
+  /**
+   * 设置每帧 splat 编辑的上传预算（字节），0 表示不限制
+   * 训练 / 流式更新超出预算的部分留到后续帧上传，避免单帧卡顿
+   */
+  setUploadBudget(bytesPerFrame: number): void {
+    this.renderer.uploadScheduler.setBudget(bytesPerFrame);
+  }
+
+  getUploadBudget(): number {
+    return this.renderer.uploadScheduler.getBudget();
+  }
+
+  getUploadStats(): UploadSchedulerStats {
+    return this.renderer.uploadScheduler.getStats();
+  }
+
+  // ============================================
+  // 诊断
+  // ============================================
+
+  /**
+   * 设置是否收集帧统计（帧时间、可见 / 总 splat 数、排序跳过、显存、上传量）
+   * @param readbackInterval 可见数量回读间隔（排序次数），0 不回读
+   * @param historyLength 每项保留的历史帧数
+   */
+  setDiagnosticsEnabled(enabled: boolean, readbackInterval: number = 30, historyLength: number = 120): void {
+    for (const gsRenderer of this.sceneManager.getSplatRenderers()) {
+      gsRenderer.setVisibleCountReadback?.(enabled ? readbackInterval : 0);
+    }
+    this.diagnosticsRenderers = new WeakSet();
+    this.diagnosticsSortStats = new WeakMap();
+    this.diagnosticsReadbackInterval = enabled ? readbackInterval : 0;
+    this.diagnostics = enabled ? new SplatDiagnostics(historyLength) : null;
+  }
+
+  /**
+   * 获取帧统计，未启用时返回 null
+   */
+  getDiagnostics(): SplatDiagnostics | null {
+    return this.diagnostics;
+  }
+
+  // ============================================
+  // TAA 抖动
+  // ============================================
+
+  /**
+   * 设置是否给相机投影加 TAA 亚像素抖动（供外部 TAA pass 使用）
+   * splat 与网格使用同一个抖动后的投影，排序不受抖动影响
+   * @param sampleCount 抖动序列长度（帧）
+   */
+  setTemporalJitter(enabled: boolean, sampleCount: number = 8): void {
+    if (!enabled) {
+      this.temporalJitter = null;
+      this.camera.clearJitter();
+      this.camera.updateMatrix();
+      return;
+    }
+    if (this.temporalJitter) {
+      this.temporalJitter.setSampleCount(sampleCount);
+    } else {
+      this.temporalJitter = new TemporalJitter(sampleCount);
+    }
+  }
+
+  /**
+   * 获取抖动序列（偏移、上一帧 viewProjection、历史有效性），未启用时返回 null
+   */
+  getTemporalJitter(): TemporalJitter | null {
+    return this.temporalJitter;
+  }
+
+  // ============================================
+  // Splat 图层
+  // ============================================
+
+  /**
+   * 获取根图层
+   */
+  getSplatRootLayer(): SplatLayer {
+    return this.sceneManager.getSplatRootLayer();
+  }
+
+  /**
+   * 创建图层（默认挂在根图层下），用 addPLYToLayer 或 layer.addEntity 添加实体
+   */
+  createSplatLayer(name: string, parent?: SplatLayer): SplatLayer {
+    return this.sceneManager.createSplatLayer(name, parent);
+  }
+
+  /**
+   * 自动配准两个重叠的扫描：计算把 source 对齐到 target 的刚体变换并写回 source 的位置 / 旋转
+   * 在 CPU 上同步计算，大场景可能耗时数百毫秒；fitness 过低时可视为配准失败并恢复原变换
+   */
+  alignSplatEntities(
+    source: IGSSplatRenderer,
+    target: IGSSplatRenderer,
+    config?: Partial<SplatRegistrationConfig>,
+  ): SplatRegistrationResult {
+    const result = registerSplatEntities(source, target, config);
+    applySplatRegistration(source, result.transform);
+    return result;
+  }
+
+  /**
+   * 合并多个实体为一个：各实体的世界变换烘焙进数据（已删除的 splat 丢弃），源实体被销毁
+   * 需要实体提供 CPU 端数据（桌面端渲染器）；不能混合 3DGS 和 2DGS
+   * @param layer 合并结果所在的图层；不传时源实体包含当前模型则替换当前模型，否则放入第一个实体所在的图层
+   * @returns 合并后的实体
+   */
+  mergeSplatEntities(renderers: ReadonlyArray<IGSSplatRenderer>, layer?: SplatLayer): IGSSplatRenderer {
+    if (renderers.length === 0) {
+      throw new Error('[App] 没有可合并的实体');
+    }
+    const main = this.sceneManager.getGSRenderer();
+    const target = layer
+      ?? (renderers.some((r) => r === main) ? null : this.sceneManager.findSplatEntityLayer(renderers[0]) ?? this.getSplatRootLayer());
+    const parentMatrix = target?.getWorldMatrix();
+    const data = mergeSplatData(renderers.map((r) => getSplatMergeItem(r, parentMatrix)));
+    const merged = this.createSplatEntity(data);
+    this.sceneManager.replaceSplatEntities(renderers, merged, target);
+    return merged;
+  }
+
+  /**
+   * 按选择集拆分实体：选中的 splat 成为同一图层中的新实体（当前模型拆分时放入根图层），
+   * 继承原实体的变换，原实体保留其余 splat（已删除的 splat 丢弃）
+   * @returns 新实体，没有选中或全部选中时返回 null
+   */
+  splitSplatEntity(renderer?: IGSSplatRenderer): IGSSplatRenderer | null {
+    const source = renderer ?? this.sceneManager.getGSRenderer();
+    if (!source) {
+      return null;
+    }
+    const split = splitSplatEntityData(source);
+    if (!split) {
+      return null;
+    }
+    const created = this.createSplatEntity(split.selected);
+    this.sceneManager.splitSplatEntity(source, split.rest, created);
+    return created;
+  }
+
+  // ============================================
+  // Bounding Box
+  // ============================================
+
+  getSplatBoundingBox(): BoundingBox | null {
+    return this.sceneManager.getSplatBoundingBox();
+  }
+
+  getSplatOrigin(): Vec3Tuple {
+    return this.sceneManager.getSplatOrigin();
+  }
+
+  getSplatStatistics(precision?: SplatPrecision): SplatStatistics | null {
+    return this.sceneManager.getSplatStatistics(precision);
+  }
+
+  getMeshRangeBoundingBox(startIndex: number, count: number): BoundingBox | null {
+    return this.sceneManager.getMeshRangeBoundingBox(startIndex, count);
+  }
+
+  // ============================================
+  // 材质颜色
+  // ============================================
+
+  getMeshColor(index: number): [number, number, number, number] | null {
+    return this.sceneManager.getMeshColor(index);
+  }
+
+  setMeshColor(index: number, r: number, g: number, b: number, a: number = 1): boolean {
+    const changed = this.sceneManager.setMeshColor(index, r, g, b, a);
+    this.requestRender();
+    return changed;
+  }
+
+  setMeshRangeColor(startIndex: number, count: number, r: number, g: number, b: number, a: number = 1): number {
+    const changed = this.sceneManager.setMeshRangeColor(startIndex, count, r, g, b, a);
+    this.requestRender();
+    return changed;
+  }
+
+  /**
+   * 设置网格的方向光阴影：网格互相投射阴影，开启 splat 深度输出（setSplatDepthWrite）时 splat 也接收阴影
+   */
+  setMeshShadowConfig(config: Partial<MeshShadowConfig>): void {
+    this.meshRenderer.setShadowConfig(config);
+    this.requestRender();
+  }
+
+  getMeshShadowConfig(): MeshShadowConfig {
+    return this.meshRenderer.getShadowConfig();
+  }
+
+  // ============================================
+  // 相机控制
+  // ============================================
+
+  frameCurrentModel(animate: boolean = true): boolean {
+    const bbox = this.sceneManager.getSceneBoundingBox();
+    if (!bbox) {
+      return false;
+    }
+
+    this.controls.frameModel(bbox.center, bbox.radius, animate);
+    return true;
+  }
+
+  /**
+   * 按场景包围盒取景，并把相机约束在场景附近（目标点限制在包围盒内，最大距离为包围球半径的 maxDistanceFactor 倍）
+   */
+  frameAndConstrainCurrentModel(animate: boolean = true, maxDistanceFactor: number = 10): boolean {
+    const bbox = this.sceneManager.getSceneBoundingBox();
+    if (!bbox) {
+      return false;
+    }
+
+    this.controls.frameBounds(bbox.min, bbox.max, animate, true, maxDistanceFactor);
+    return true;
+  }
+
+  /**
+   * 设置相机控制器的惯性和约束（距离、角度范围、目标点范围）
+   */
+  setCameraControlsConfig(config: Partial<OrbitControlsConfig>): void {
+    this.controls.setConfig(config);
+  }
+
+  getCameraControlsConfig(): OrbitControlsConfig {
+    return this.controls.getConfig();
+  }
+
+  /**
+   * 切换相机控制方式：orbit 绕目标点旋转，fly 第一人称飞行（WASD 移动、拖动转向，无碰撞）
+   * 切换时保持当前相机位姿；第一次进入飞行模式时按场景大小选择移动速度
+   */
+  setCameraMode(mode: 'orbit' | 'fly'): void {
+    if (mode === this.getCameraMode()) return;
+    if (mode === 'fly') {
+      this.controls.setAttached(false);
+      this.getOrCreateFlyControls().setAttached(true);
+    } else {
+      this.flyControls?.setAttached(false);
+      this.controls.setAttached(true);
+      const { position, target } = this.camera;
+      this.controls.setPose([position[0], position[1], position[2]], [target[0], target[1], target[2]]);
+    }
+  }
+
+  getCameraMode(): 'orbit' | 'fly' {
+    return this.flyControls?.isAttached() ? 'fly' : 'orbit';
+  }
+
+  setFlyControlsConfig(config: Partial<FlyControlsConfig>): void {
+    this.getOrCreateFlyControls().setConfig(config);
+  }
+
+  getFlyControlsConfig(): FlyControlsConfig | null {
+    return this.flyControls?.getConfig() ?? null;
+  }
+
+  private getOrCreateFlyControls(): FlyControls {
+    if (!this.flyControls) {
+      // 相机控制暂停（gizmo 拖动、画笔 / 套索选择等）沿用轨道控制器的 enabled
+      this.flyControls = new FlyControls(this.camera, this.canvas, () => this.controls.enabled);
+      const bbox = this.sceneManager.getSceneBoundingBox();
+      if (bbox) {
+        this.flyControls.setConfig({ speed: Math.max(bbox.radius * 0.2, 1e-3) });
+      }
+    }
+    return this.flyControls;
+  }
+
+  // ============================================
+  // 相机路径
+  // ============================================
+
+  /**
+   * 把当前相机位姿记录为路径的关键帧
+   * @param time 关键帧时间（秒），默认为最后一个关键帧之后 2 秒（第一个关键帧为 0）
+   * @returns 关键帧索引
+   */
+  recordCameraKeyframe(path: CameraPath, time?: number): number {
+    const keyframes = path.getKeyframes();
+    const { position, target } = this.camera;
+    return path.addKeyframe({
+      time: time ?? (keyframes.length > 0 ? keyframes[keyframes.length - 1].time + 2 : 0),
+      position: [position[0], position[1], position[2]],
+      target: [target[0], target[1], target[2]],
+      fov: this.camera.fov,
+    });
+  }
+
+  /**
+   * 在渲染循环中播放相机路径（按帧间隔推进，播放期间暂停相机控制）
+   * 非循环路径播放到末尾时停止并调用 onEnd
+   */
+  playCameraPath(path: CameraPath, options: { speed?: number; startTime?: number; onEnd?: () => void } = {}): void {
+    this.stopCameraPath();
+    if (path.getKeyframes().length === 0) return;
+    this.cameraPathPlayback = {
+      path,
+      time: options.startTime ?? 0,
+      speed: options.speed ?? 1,
+      controlsEnabled: this.controls.enabled,
+      onEnd: options.onEnd,
+    };
+    this.controls.enabled = false;
+    this.applyCameraPathSample(path.evaluate(this.cameraPathPlayback.time));
+  }
+
+  /**
+   * 停止播放，相机停在当前位置并恢复相机控制
+   */
+  stopCameraPath(): void {
+    const playback = this.cameraPathPlayback;
+    if (!playback) return;
+    this.cameraPathPlayback = null;
+    this.controls.enabled = playback.controlsEnabled;
+    // 轨道控制器的球坐标从相机位姿同步
+    const { position, target } = this.camera;
+    this.controls.setPose([position[0], position[1], position[2]], [target[0], target[1], target[2]]);
+  }
+
+  isPlayingCameraPath(): boolean {
+    return this.cameraPathPlayback !== null;
+  }
+
+  /**
+   * 当前播放时间（秒），没有播放时为 null
+   */
+  getCameraPathTime(): number | null {
+    return this.cameraPathPlayback?.time ?? null;
+  }
+
+  private advanceCameraPath(deltaTime: number): void {
+    const playback = this.cameraPathPlayback;
+    if (!playback) return;
+    playback.time += deltaTime * playback.speed;
+    this.applyCameraPathSample(playback.path.evaluate(playback.time));
+    if (!playback.path.loop && playback.time >= playback.path.getDuration()) {
+      this.stopCameraPath();
+      playback.onEnd?.();
+    }
+  }
+
+  private applyCameraPathSample(sample: CameraPathSample | null): void {
+    if (!sample) return;
+    this.camera.position.set(sample.position);
+    this.camera.target.set(sample.target);
+    this.camera.fov = sample.fov;
+    this.camera.updateMatrix();
+  }
+
+  /**
+   * 按固定帧率离屏渲染整条路径（与渲染循环无关），逐帧回调
+   * @param onFrame 返回 Promise 时等待其完成后再渲染下一帧（例如交给视频编码器）
+   */
+  async renderCameraPath(
+    path: CameraPath,
+    options: { fps: number; width: number; height: number; transparentBackground?: boolean },
+    onFrame: (capture: FrameCapture, index: number, time: number) => void | Promise<void>,
+  ): Promise<number> {
+    const frameCount = this.getCameraPathFrameCount(path, options.fps);
+    for (let index = 0; index < frameCount; index++) {
+      const time = index / options.fps;
+      const sample = path.evaluate(time)!;
+      const capture = await this.renderView({
+        position: sample.position,
+        target: sample.target,
+        fovY: sample.fov,
+        width: options.width,
+        height: options.height,
+        transparentBackground: options.transparentBackground,
+      });
+      await onFrame(capture, index, time);
+    }
+    return frameCount;
+  }
+
+  // 按帧率渲染路径的帧数：循环路径一个周期（不含与第一帧重复的末帧），否则包含首尾
+  private getCameraPathFrameCount(path: CameraPath, fps: number): number {
+    if (path.getKeyframes().length === 0 || fps <= 0) return 0;
+    const duration = path.getDuration();
+    return path.loop ? Math.max(1, Math.round(duration * fps)) : Math.floor(duration * fps + 1e-6) + 1;
+  }
+
+  /**
+   * 导出相机路径的帧序列：按固定帧率逐帧离屏渲染并写入输出端（PNG 序列或自定义的编码器）
+   * 导出期间关闭分摊排序和遮挡剔除（两者依赖之前的帧），并使用 Stable64 深度键（深度相同的 splat 按索引排序，
+   * 不受剔除 pass 输出顺序影响），同一路径和设置的输出可以复现
+   * @returns 导出的帧数
+   */
+  async exportCameraPath(
+    path: CameraPath,
+    options: { fps: number; width: number; height: number; transparentBackground?: boolean },
+    sink: FrameSequenceSink,
+    onProgress?: (done: number, total: number) => void,
+  ): Promise<number> {
+    const amortization = this.sceneManager.getSplatSortAmortization();
+    const occlusion = this.sceneManager.isSplatOcclusionCulling();
+    const depthKeyMode = this.sceneManager.getSplatDepthKeyMode();
+    this.sceneManager.setSplatSortAmortization(0);
+    this.sceneManager.setSplatOcclusionCulling(false);
+    this.sceneManager.setSplatDepthKeyMode(SplatDepthKeyMode.Stable64);
+    const total = this.getCameraPathFrameCount(path, options.fps);
+    try {
+      return await this.renderCameraPath(path, options, async (capture, index, time) => {
+        await sink.writeFrame({ capture, index, time });
+        onProgress?.(index + 1, total);
+      });
+    } finally {
+      this.sceneManager.setSplatSortAmortization(amortization);
+      this.sceneManager.setSplatOcclusionCulling(occlusion);
+      this.sceneManager.setSplatDepthKeyMode(depthKeyMode);
+      await sink.close?.();
+    }
+  }
+
+  // ============================================
+  // 训练相机
+  // ============================================
+
+  /**
+   * 加载 NeRF / Blender 格式的 transforms.json 并显示相机视锥
+   */
+  async loadNerfTransforms(source: string | Blob): Promise<TrainingCamera[]> {
+    const cameras = await loadNerfTransforms(source);
+    this.setTrainingCameras(cameras);
+    return cameras;
+  }
+
+  /**
+   * 加载 COLMAP 的 cameras / images 文件（.txt 或 .bin）并显示相机视锥
+   */
+  async loadColmapCameras(cameras: string | Blob, images: string | Blob): Promise<TrainingCamera[]> {
+    const result = await loadColmapCameras(cameras, images);
+    this.setTrainingCameras(result);
+    return result;
+  }
+
+  /**
+   * 设置显示的训练相机（空数组清除）
+   */
+  setTrainingCameras(cameras: TrainingCamera[]): void {
+    this.gizmoManager.getCameraFrustumRenderer().setCameras(cameras);
+  }
+
+  getTrainingCameras(): TrainingCamera[] {
+    return this.gizmoManager.getCameraFrustumRenderer().getCameras();
+  }
+
+  setTrainingCamerasVisible(visible: boolean): void {
+    this.gizmoManager.getCameraFrustumRenderer().setVisible(visible);
+  }
+
+  setTrainingCameraFrustumConfig(config: Partial<CameraFrustumConfig>): void {
+    this.gizmoManager.getCameraFrustumRenderer().setConfig(config);
+  }
+
+  /**
+   * 切换到训练相机的视角（位置、朝向、上方向和垂直视场角）并高亮其视锥
+   * 旋转中心放在视线上离场景中心最近的点；画布宽高比与图像一致时与训练图像逐像素对齐
+   */
+  viewFromTrainingCamera(index: number): boolean {
+    const frustums = this.gizmoManager.getCameraFrustumRenderer();
+    const camera = frustums.getCameras()[index];
+    if (!camera) return false;
+
+    const m = camera.cameraToWorld;
+    const position = camera.position;
+    const forward: [number, number, number] = [-m[8], -m[9], -m[10]];
+    const center = this.sceneManager.getSceneBoundingBox()?.center ?? [0, 0, 0];
+    let distance = (center[0] - position[0]) * forward[0]
+      + (center[1] - position[1]) * forward[1]
+      + (center[2] - position[2]) * forward[2];
+    if (!(distance > 1e-3)) {
+      distance = frustums.getFrustumSize() * 10;
+    }
+
+    this.camera.up[0] = m[4];
+    this.camera.up[1] = m[5];
+    this.camera.up[2] = m[6];
+    this.camera.fov = camera.fovY;
+    this.controls.setPose(
+      [position[0], position[1], position[2]],
+      [position[0] + forward[0] * distance, position[1] + forward[1] * distance, position[2] + forward[2] * distance],
+    );
+    frustums.setHighlighted(index);
+    return true;
+  }
+
+  /**
+   * 把相机旋转中心移到画布像素 (x, y) 处看到的 splat 表面（混合深度）
+   * @param animate 平滑过渡
+   * @param zoom 指定时相机到对焦点的距离为当前视线距离的 zoom 倍（小于 1 拉近），省略时保持轨道距离
+   * @returns 该像素没有 splat 时返回 false
+   */
+  async focusSplatAt(x: number, y: number, animate: boolean = false, zoom?: number): Promise<boolean> {
+    const hit = await this.sceneManager.getSplatDepthAt(x, y);
+    if (!hit) {
+      return false;
+    }
+    if (zoom === undefined) {
+      this.controls.focusOn(hit.worldPosition, animate);
+      return true;
+    }
+    const [px, py, pz] = hit.worldPosition;
+    const { position } = this.camera;
+    // 相机沿原来的角度退到距对焦点 (视线距离 × zoom) 处
+    const distance = Math.hypot(px - position[0], py - position[1], pz - position[2]) * zoom;
+    this.controls.focusOn(hit.worldPosition, animate, distance > 0 ? distance : undefined);
+    return true;
+  }
+
+  // ============================================
+  // Gizmo（委托给 GizmoManager）
+  // ============================================
+
+  getTransformGizmo() {
+    return this.gizmoManager.getTransformGizmo();
+  }
+
+  getViewportGizmo() {
+    return this.gizmoManager.getViewportGizmo();
+  }
+
+  getBoundingBoxRenderer() {
+    return this.gizmoManager.getBoundingBoxRenderer();
+  }
+
+  getCameraFrustumRenderer() {
+    return this.gizmoManager.getCameraFrustumRenderer();
+  }
+
+  setGizmoMode(mode: GizmoMode): void {
+    this.gizmoManager.setGizmoMode(mode);
+  }
+
+  setGizmoTarget(object: TransformableObject | null): void {
+    this.gizmoManager.setGizmoTarget(object);
+  }
+
+  setSelectionBoundingBox(box: BoundingBox | null): void {
+    this.gizmoManager.setSelectionBoundingBox(box);
+  }
+
+  setSelectionBoundingBoxProvider(provider: BoundingBoxProvider | null): void {
+    this.gizmoManager.setSelectionBoundingBoxProvider(provider);
+  }
+
+  clearSelectionBoundingBox(): void {
+    this.gizmoManager.clearSelectionBoundingBox();
+  }
+
+  /**
+   * 创建 Mesh 组的变换代理
+   */
+  createMeshGroupProxy(startIndex: number, count: number): MeshGroupProxy | null {
+    const meshes = this.sceneManager.getMeshRange(startIndex, count);
+    return this.gizmoManager.createMeshGroupProxy(meshes);
+  }
+
+  /**
+   * 获取 Splat 的变换代理
+   */
+  getSplatTransformProxy(): SplatTransformProxy | null {
+    const gsRenderer = this.sceneManager.getGSRenderer();
+    if (!gsRenderer) return null;
+    return this.gizmoManager.createSplatTransformProxy(gsRenderer);
+  }
+
+  /**
+   * 创建 Splat 包围盒提供者
+   */
+  createSplatBoundingBoxProvider(): SplatBoundingBoxProvider | null {
+    const gsRenderer = this.sceneManager.getGSRenderer();
+    if (!gsRenderer) return null;
+    return this.gizmoManager.createSplatBoundingBoxProvider(gsRenderer);
+  }
+
+  // ============================================
+  // 子系统访问
+  // ============================================
+
+  getRenderer(): Renderer {
+    return this.renderer;
+  }
+
+  getCamera(): Camera {
+    return this.camera;
+  }
+
+  getControls(): OrbitControls {
+    return this.controls;
+  }
+
+  getMeshRenderer(): MeshRenderer {
+    return this.meshRenderer;
+  }
+
+  getGridRenderer(): GridRenderer {
+    return this.gridRenderer;
+  }
+
+  getGSRenderer(): GSSplatRenderer | undefined {
+    const renderer = this.sceneManager.getGSRenderer();
+    if (renderer instanceof GSSplatRenderer) {
+      return renderer;
+    }
+    return undefined;
+  }
+
+  getGSRendererMobile(): GSSplatRendererMobile | undefined {
+    const renderer = this.sceneManager.getGSRenderer();
+    if (renderer && this.useMobileRenderer) {
+      return renderer as GSSplatRendererMobile;
+    }
+    return undefined;
+  }
+
+  isUsingMobileRenderer(): boolean {
+    return this.useMobileRenderer;
+  }
+
+  /**
+   * 设置移动端纹理打包配置（在内存和精度之间权衡，对之后加载的 PLY 生效）
+   */
+  setPackModeConfig(config: Partial<PackModeConfig>): void {
+    this.packModeConfig = { ...this.packModeConfig, ...config };
+  }
+
+  getPackModeConfig(): Partial<PackModeConfig> {
+    return { ...this.packModeConfig };
+  }
+
+  /**
+   * 设置平台默认配置（平台名使用预设，部分字段覆盖当前配置）
+   * 对之后加载的模型生效；上传预算立即生效。setPackModeConfig 的设置优先于默认打包编码
+   */
+  setPlatformDefaults(platformOrOverrides: SplatPlatform | Partial<SplatPlatformDefaults>): void {
+    setSplatPlatformDefaults(platformOrOverrides);
+    this.renderer?.uploadScheduler.setBudget(getSplatPlatformDefaults().uploadBudget);
+  }
+
+  getPlatformDefaults(): SplatPlatformDefaults {
+    return getSplatPlatformDefaults();
+  }
+
+  /**
+   * 开关低配性能模式（面向核显，目标 30 fps）
+   * 半分辨率画布、SH 只用 1 阶、限制 splat 屏幕尺寸、更激进的像素剔除、移动时分摊排序；
+   * 立即作用于已加载的模型，关闭时恢复自动检测的平台默认配置。打包编码只对之后加载的模型生效
+   */
+  setPotatoMode(enabled: boolean): void {
+    if (enabled) {
+      setSplatPlatformDefaults("potato");
+    } else {
+      resetSplatPlatformDefaults();
+    }
+    for (const gsRenderer of this.sceneManager?.getSplatRenderers() ?? []) {
+      this.applyPlatformDefaults(gsRenderer);
+    }
+    this.renderer?.uploadScheduler.setBudget(getSplatPlatformDefaults().uploadBudget);
+    this.renderer?.updateCanvasSize();
+  }
+
+  isPotatoMode(): boolean {
+    return getSplatPlatformDefaults().platform === "potato";
+  }
+
+  /**
+   * 设置深度约定（reverse-Z / 无限远平面），必须在 init 之前调用
+   */
+  setDepthConfig(config: Partial<DepthConfig>): void {
+    if (this.renderer) {
+      console.warn("setDepthConfig 需要在 init 之前调用，当前设置不会生效");
+      return;
+    }
+    this.depthConfig = { ...this.depthConfig, ...config };
+  }
+
+  getDepthConfig(): DepthConfig {
+    return this.renderer ? { ...this.renderer.depthConfig } : resolveDepthConfig(this.depthConfig);
+  }
+
+  /**
+   * 设置是否输出 HDR，必须在 init 之前调用
+   */
+  setHDROutput(enabled: boolean): void {
+    if (this.renderer) {
+      console.warn("setHDROutput 需要在 init 之前调用，当前设置不会生效");
+      return;
+    }
+    this.hdrOutput = enabled;
+  }
+
+  isHDROutput(): boolean {
+    return this.renderer ? this.renderer.hdr : this.hdrOutput;
+  }
+
+  /**
+   * 设置是否使用 indirect 绘制，null 表示按适配器自动选择（兼容模式适配器关闭）。
+   * 关闭时异步回读可见数量后直接绘制，可随时切换
+   */
+  setIndirectDraw(enabled: boolean | null): void {
+    this.indirectDraw = enabled;
+    this.renderer?.setIndirectDraw(enabled);
+  }
+
+  /**
+   * 当前是否使用 indirect 绘制（init 之前返回手动设置，未设置时为 true）
+   */
+  isIndirectDraw(): boolean {
+    return this.renderer ? this.renderer.indirectDraw : this.indirectDraw ?? true;
+  }
+
+  /**
+   * 设置是否统计存活的 GPU buffer / 纹理（泄漏检测，只用于调试），必须在 init 之前调用
+   */
+  setGPUResourceTracking(enabled: boolean): void {
+    if (this.renderer) {
+      console.warn("setGPUResourceTracking 需要在 init 之前调用，当前设置不会生效");
+      return;
+    }
+    this.gpuResourceTracking = enabled;
+  }
+
+  /**
+   * 获取 GPU 资源计数器，未启用时返回 null
+   */
+  getGPUResourceTracker(): GPUResourceTracker | null {
+    return this.renderer?.resourceTracker ?? null;
+  }
+
+  /**
+   * 获取存活的 GPU 资源数量，未启用统计时返回 null
+   * @param trimPools 是否先清空 buffer 对象池和共享的拾取目标（空闲资源也算存活，比较基线前应清空）
+   */
+  getGPUResourceCounts(trimPools: boolean = false): GPUResourceCounts | null {
+    const tracker = this.getGPUResourceTracker();
+    if (!tracker) {
+      return null;
+    }
+    if (trimPools) {
+      SplatBufferPool.get(this.renderer.device).clear();
+      SplatPickTargets.get(this.renderer.device).clear();
+    }
+    return tracker.getCounts();
+  }
+
+  // ============================================
+  // 内部方法
+  // ============================================
+
+  /**
+   * 显存降级：把存储缓冲区渲染器换成纹理打包的移动端渲染器（仅 L0，不支持拾取和编辑）
+   * @returns 释放的字节数
+   */
+  private packSplatRenderer(): number {
+    const current = this.sceneManager.getGSRenderer();
+    const data = current?.getSplats?.()?.getData();
+    if (!current || !data || current instanceof GSSplatRendererMobile) {
+      return 0;
+    }
+    const before = current.getGPUMemoryUsage?.() ?? 0;
+    const packMode = { ...getSplatPlatformDefaults().packMode, ...this.packModeConfig };
+    const packed = new GSSplatRendererMobile(this.renderer, this.camera, packMode);
+    packed.setCompactData(data);
+    packed.setPosition(...current.getPosition());
+    packed.setRotation(...current.getRotation());
+    packed.setScale(...current.getScale());
+    packed.setPivot(...current.getPivot());
+    packed.setExposure(current.getExposure?.() ?? 1);
+    if (current.getBlendMode) {
+      packed.setBlendMode(current.getBlendMode());
+    }
+    // 替换时销毁原渲染器
+    this.sceneManager.setGSRenderer(packed);
+    this.useMobileRenderer = true;
+    return before - packed.getGPUMemoryUsage();
+  }
+
+  /**
+   * 按平台默认配置初始化新建的 splat 渲染器
+   */
+  private updateDiagnostics(diagnostics: SplatDiagnostics, deltaTime: number): void {
+    diagnostics.add(SPLAT_DIAGNOSTICS.FRAME_TIME, deltaTime * 1000);
+    diagnostics.add(SPLAT_DIAGNOSTICS.FPS, deltaTime > 0 ? 1 / deltaTime : 0);
+
+    let total = 0;
+    let visible = 0;
+    let visibleKnown = false;
+    let gpuMemory = 0;
+    let sorts = 0;
+    let skips = 0;
+    for (const gsRenderer of this.sceneManager.getSplatRenderers()) {
+      // 之后加载的渲染器也开启回读
+      if (!this.diagnosticsRenderers.has(gsRenderer)) {
+        this.diagnosticsRenderers.add(gsRenderer);
+        gsRenderer.setVisibleCountReadback?.(this.diagnosticsReadbackInterval);
+      }
+      total += gsRenderer.getSplatCount();
+      gpuMemory += gsRenderer.getGPUMemoryUsage?.() ?? 0;
+      const count = gsRenderer.getVisibleCount?.() ?? -1;
+      if (count >= 0) {
+        visible += count;
+        visibleKnown = true;
+      }
+      const stats = gsRenderer.getSortReuseStats?.();
+      if (stats) {
+        const previous = this.diagnosticsSortStats.get(gsRenderer);
+        if (previous) {
+          sorts += stats.sortedFrames - previous.sortedFrames;
+          skips += stats.skippedFrames - previous.skippedFrames;
+        }
+        this.diagnosticsSortStats.set(gsRenderer, stats);
+      }
+    }
+
+    diagnostics.add(SPLAT_DIAGNOSTICS.TOTAL_SPLATS, total);
+    diagnostics.add(SPLAT_DIAGNOSTICS.VISIBLE_SPLATS, visibleKnown ? visible : -1);
+    diagnostics.add(SPLAT_DIAGNOSTICS.SORTS, sorts);
+    diagnostics.add(SPLAT_DIAGNOSTICS.SORT_SKIPS, skips);
+    diagnostics.add(SPLAT_DIAGNOSTICS.SORT_CACHE_HIT_RATE, sorts + skips > 0 ? skips / (sorts + skips) : -1);
+    diagnostics.add(SPLAT_DIAGNOSTICS.GPU_MEMORY, gpuMemory);
+    diagnostics.add(SPLAT_DIAGNOSTICS.UPLOAD_BYTES, this.renderer.uploadScheduler.getStats().lastFrameBytes);
+  }
+
+  // 按平台和数量创建图层实体的渲染器并上传数据
+  private createSplatEntity(compactData: CompactSplatData): IGSSplatRenderer {
+    const defaults = getSplatPlatformDefaults();
+    let gsRenderer: IGSSplatRenderer;
+    if (defaults.pack) {
+      gsRenderer = new GSSplatRendererMobile(this.renderer, this.camera, { ...defaults.packMode, ...this.packModeConfig });
+    } else if (compactData.count > getMaxSplatsPerSegment(this.renderer.device)) {
+      gsRenderer = new GSSplatRendererGroup(this.renderer, this.camera);
+    } else {
+      gsRenderer = new GSSplatRenderer(this.renderer, this.camera);
+    }
+    gsRenderer.setCompactData(compactData);
+    this.applyPlatformDefaults(gsRenderer);
+    return gsRenderer;
+  }
+
+  private applyPlatformDefaults(gsRenderer: IGSSplatRenderer): void {
+    const defaults = getSplatPlatformDefaults();
+    gsRenderer.setSHMode?.(defaults.shMode);
+    gsRenderer.setSortAmortization?.(defaults.sortAmortization);
+    gsRenderer.setPixelCullThreshold?.(defaults.pixelCullThreshold);
+    gsRenderer.setMaxSplatSize?.(defaults.maxSplatSize);
+  }
+
+  private async fetchWithProgress(
+    url: string,
+    onProgress?: (progress: number) => void
+  ): Promise<ArrayBuffer> {
+    const response = await fetch(url);
+    if (!response.ok) {
+      throw new Error(`无法加载文件: ${url}`);
+    }
+
     const contentLength = response.headers.get('content-length');
     if (!contentLength || !response.body) {
       const buffer = await response.arrayBuffer();
@@ -602,12 +2857,35 @@ export class App {
     return buffer;
   }
 
+  /**
+   * @param owned buffer 是否由 App 自己下载：只有这时才直接转移给 worker，调用方传入的 buffer 先复制一份，调用后仍然可用
+   */
   private async parsePLYBuffer(
     buffer: ArrayBuffer,
-    options: { maxSplats?: number; loadSH?: boolean; onProgress?: (loaded: number, total: number) => void }
+    options: import('./gs/PLYLoaderMobile').MobileLoadOptions,
+    owned: boolean,
   ): Promise<import('./gs/PLYLoaderMobile').CompactSplatData> {
+    // 构建时关闭 SH 时不读取 SH 系数
+    const parseOptions = { ...options, loadSH: SPLAT_FEATURE_SH && options.loadSH };
+    if (this.plyWorkerParsing) {
+      const { parsePLYBufferInWorker } = await import('./gs/PLYParseWorkerClient');
+      return parsePLYBufferInWorker(owned ? buffer : buffer.slice(0), parseOptions);
+    }
     const { parsePLYBuffer } = await import('./gs/PLYLoaderMobile');
-    return parsePLYBuffer(buffer, options);
+    return parsePLYBuffer(buffer, parseOptions);
+  }
+
+  /**
+   * 设置是否在 worker 中解析 PLY（默认开启，避免大文件解析冻结页面；环境不支持时自动回退到主线程）
+   * 下载的数据直接转移给 worker；传入 addPLY / addPLYToLayer / loadSplatAppearance 的 ArrayBuffer
+   * 会先复制再转移（解析期间多占用一份文件大小的内存），调用方的 buffer 不受影响
+   */
+  setPLYWorkerParsing(enabled: boolean): void {
+    this.plyWorkerParsing = enabled;
+  }
+
+  isPLYWorkerParsing(): boolean {
+    return this.plyWorkerParsing;
   }
 
   /**
@@ -616,14 +2894,30 @@ export class App {
   destroy(): void {
     this.stop();
     window.removeEventListener("resize", this.boundOnResize);
+    this.updateCallbacks = [];
+    this.framePreviews.forEach((preview) => preview.destroy());
+    this.disconnectRemoteTraining();
+    this.trainingMetrics.destroy();
 
     this.sceneManager.destroy();
+    // 拾取目标按设备共享，不随单个渲染器销毁
+    SplatPickTargets.get(this.renderer.device).clear();
     this.gizmoManager.destroy();
+    this.gridRenderer.destroy();
+    this.splatPicking.destroy();
+    this.splatHover.destroy();
+    this.comparison.destroy();
+    this.splatBrush.destroy();
+    this.splatLasso.destroy();
 
     if (this.meshRenderer) {
       this.meshRenderer.destroy();
     }
 
+    this.cameraPathPlayback = null;
+    this.renderOnDemand?.destroy();
+    this.flyControls?.destroy();
+    this.flyControls = null;
     if (this.controls) {
       this.controls.destroy();
     }
@@ -1,3 +1,5 @@
+import { DepthConfig, DEFAULT_DEPTH_CONFIG, writePerspective } from "./DepthConvention";
+
 /**
  * Camera - 相机矩阵计算
  * 只负责视图矩阵和投影矩阵
@@ -13,11 +15,17 @@ export class Camera {
   aspect: number = 1;
   near: number = 0.1;  // 增大近平面以提高深度精度 (参考实现使用 0.1)
   far: number = 1000;  // 减小远平面以提高深度精度
+  // 深度约定（应与 Renderer.depthConfig 一致）
+  depthConfig: DepthConfig = { ...DEFAULT_DEPTH_CONFIG };
 
-  // 矩阵
+  // 矩阵（projectionMatrix / viewProjectionMatrix 包含 TAA 抖动）
   viewMatrix: Float32Array = new Float32Array(16);
   projectionMatrix: Float32Array = new Float32Array(16);
   viewProjectionMatrix: Float32Array = new Float32Array(16);
+  // 不含抖动的投影矩阵（排序、LOD 等不应随抖动变化的计算使用）
+  unjitteredProjectionMatrix: Float32Array = new Float32Array(16);
+  // 亚像素抖动（NDC 偏移）
+  jitter: Float32Array = new Float32Array(2);
 
   constructor() {
     this.updateMatrix();
@@ -30,6 +38,21 @@ export class Camera {
     this.aspect = aspect;
   }
 
+  /**
+   * 设置亚像素抖动（像素偏移，下一次 updateMatrix 生效）
+   */
+  setJitter(x: number, y: number, width: number, height: number): void {
+    this.jitter[0] = (2 * x) / Math.max(width, 1);
+    this.jitter[1] = (-2 * y) / Math.max(height, 1);
+  }
+
+  /**
+   * 清除抖动
+   */
+  clearJitter(): void {
+    this.jitter.fill(0);
+  }
+
   /**
    * 更新视图和投影矩阵
    */
@@ -82,29 +105,11 @@ export class Camera {
    * 计算投影矩阵 (透视投影)
    */
   private updateProjectionMatrix(): void {
-    const f = 1.0 / Math.tan(this.fov / 2);
-    const rangeInv = 1 / (this.near - this.far);
-
-    // 列主序
-    this.projectionMatrix[0] = f / this.aspect;
-    this.projectionMatrix[1] = 0;
-    this.projectionMatrix[2] = 0;
-    this.projectionMatrix[3] = 0;
-
-    this.projectionMatrix[4] = 0;
-    this.projectionMatrix[5] = f;
-    this.projectionMatrix[6] = 0;
-    this.projectionMatrix[7] = 0;
-
-    this.projectionMatrix[8] = 0;
-    this.projectionMatrix[9] = 0;
-    this.projectionMatrix[10] = (this.near + this.far) * rangeInv;
-    this.projectionMatrix[11] = -1;
-
-    this.projectionMatrix[12] = 0;
-    this.projectionMatrix[13] = 0;
-    this.projectionMatrix[14] = this.near * this.far * rangeInv * 2;
-    this.projectionMatrix[15] = 0;
+    writePerspective(this.unjitteredProjectionMatrix, this.fov, this.aspect, this.near, this.far, this.depthConfig);
+    this.projectionMatrix.set(this.unjitteredProjectionMatrix);
+    // clip.w = -z，第三列的 x / y 分量在 NDC 中产生与深度无关的常量偏移
+    this.projectionMatrix[8] = -this.jitter[0];
+    this.projectionMatrix[9] = -this.jitter[1];
   }
 
   // ========== 向量/矩阵工具函数 ==========
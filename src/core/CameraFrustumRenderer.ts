@@ -0,0 +1,269 @@
+import { Renderer } from "./Renderer";
+import { Camera } from "./Camera";
+import { PipelineCache } from "./PipelineCache";
+import { getDepthCompare } from "./DepthConvention";
+import type { TrainingCamera } from "../loaders/CameraDatasetLoader";
+
+/**
+ * 相机视锥显示配置
+ */
+export interface CameraFrustumConfig {
+  /** 视锥深度（场景单位），0 表示按相机分布范围自动选择 */
+  size: number;
+  /** 线条颜色 */
+  color: [number, number, number];
+  /** 高亮相机的线条颜色 */
+  highlightColor: [number, number, number];
+}
+
+/** 默认视锥配置 */
+export const DEFAULT_CAMERA_FRUSTUM_CONFIG: CameraFrustumConfig = {
+  size: 0,
+  color: [1.0, 0.6, 0.1],
+  highlightColor: [0.2, 0.9, 1.0],
+};
+
+// 每个相机：顶点到 4 个角 + 底面 4 条边 + 上方三角形 3 条边
+const LINES_PER_CAMERA = 11;
+const FLOATS_PER_VERTEX = 6;
+
+const frustumShaderCode = /* wgsl */ `
+@group(0) @binding(0) var<uniform> viewProjection: mat4x4<f32>;
+
+struct VertexOutput {
+  @builtin(position) position: vec4<f32>,
+  @location(0) color: vec3<f32>,
+}
+
+@vertex
+fn vertexMain(@location(0) position: vec3<f32>, @location(1) color: vec3<f32>) -> VertexOutput {
+  var output: VertexOutput;
+  output.position = viewProjection * vec4<f32>(position, 1.0);
+  output.color = color;
+  return output;
+}
+
+@fragment
+fn fragmentMain(input: VertexOutput) -> @location(0) vec4<f32> {
+  return vec4<f32>(input.color, 1.0);
+}
+`;
+
+/**
+ * CameraFrustumRenderer - 训练相机视锥线框
+ *
+ * 每个相机绘制为一个视锥（按视场角和宽高比），底面上方的三角形标出相机的上方向。
+ * 顶点在相机列表或配置变化时重建，只做深度测试不写深度
+ */
+export class CameraFrustumRenderer {
+  private renderer: Renderer;
+  private camera: Camera;
+  private pipeline: GPURenderPipeline;
+  private uniformBuffer: GPUBuffer;
+  private bindGroup: GPUBindGroup;
+  private vertexBuffer: GPUBuffer | null = null;
+  private vertexCount: number = 0;
+  private dirty: boolean = false;
+
+  private cameras: TrainingCamera[] = [];
+  private highlighted: number | null = null;
+  private config: CameraFrustumConfig = { ...DEFAULT_CAMERA_FRUSTUM_CONFIG };
+  private visible: boolean = true;
+
+  constructor(renderer: Renderer, camera: Camera) {
+    this.renderer = renderer;
+    this.camera = camera;
+    const device = renderer.device;
+    const pipelines = PipelineCache.get(device);
+
+    const module = pipelines.getShaderModule({ code: frustumShaderCode, label: "camera-frustum-shader" });
+    const bindGroupLayout = pipelines.getBindGroupLayout({
+      label: "camera-frustum-layout",
+      entries: [{ binding: 0, visibility: GPUShaderStage.VERTEX, buffer: { type: "uniform" } }],
+    });
+    this.uniformBuffer = device.createBuffer({
+      size: 64,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+      label: "camera-frustum-uniforms",
+    });
+    this.bindGroup = device.createBindGroup({
+      layout: bindGroupLayout,
+      entries: [{ binding: 0, resource: { buffer: this.uniformBuffer } }],
+    });
+
+    this.pipeline = pipelines.getRenderPipeline({
+      layout: pipelines.getPipelineLayout([bindGroupLayout]),
+      vertex: {
+        module,
+        entryPoint: "vertexMain",
+        buffers: [{
+          arrayStride: FLOATS_PER_VERTEX * 4,
+          attributes: [
+            { shaderLocation: 0, offset: 0, format: "float32x3" },
+            { shaderLocation: 1, offset: 12, format: "float32x3" },
+          ],
+        }],
+      },
+      fragment: { module, entryPoint: "fragmentMain", targets: [{ format: renderer.format }] },
+      primitive: { topology: "line-list", cullMode: "none" },
+      depthStencil: {
+        format: renderer.depthFormat,
+        depthWriteEnabled: false,
+        depthCompare: getDepthCompare(renderer.depthConfig),
+      },
+      label: "camera-frustum-pipeline",
+    });
+  }
+
+  /**
+   * 设置要显示的相机（空数组清除）
+   */
+  setCameras(cameras: TrainingCamera[]): void {
+    this.cameras = cameras;
+    this.highlighted = null;
+    this.dirty = true;
+  }
+
+  getCameras(): TrainingCamera[] {
+    return this.cameras;
+  }
+
+  /**
+   * 高亮某个相机（索引），null 取消
+   */
+  setHighlighted(index: number | null): void {
+    this.highlighted = index;
+    this.dirty = true;
+  }
+
+  getHighlighted(): number | null {
+    return this.highlighted;
+  }
+
+  setVisible(visible: boolean): void {
+    this.visible = visible;
+  }
+
+  isVisible(): boolean {
+    return this.visible;
+  }
+
+  setConfig(config: Partial<CameraFrustumConfig>): void {
+    this.config = { ...this.config, ...config };
+    this.dirty = true;
+  }
+
+  getConfig(): CameraFrustumConfig {
+    return { ...this.config };
+  }
+
+  /**
+   * 视锥深度：未指定时取相机位置包围盒对角线的 5%
+   */
+  getFrustumSize(): number {
+    if (this.config.size > 0) return this.config.size;
+    const min = [Infinity, Infinity, Infinity];
+    const max = [-Infinity, -Infinity, -Infinity];
+    for (const camera of this.cameras) {
+      for (let k = 0; k < 3; k++) {
+        min[k] = Math.min(min[k], camera.position[k]);
+        max[k] = Math.max(max[k], camera.position[k]);
+      }
+    }
+    const diagonal = Math.hypot(max[0] - min[0], max[1] - min[1], max[2] - min[2]);
+    return diagonal > 0 ? diagonal * 0.05 : 0.1;
+  }
+
+  /**
+   * 生成顶点数据（位置 + 颜色）
+   */
+  private generateVertices(): Float32Array {
+    const vertices = new Float32Array(this.cameras.length * LINES_PER_CAMERA * 2 * FLOATS_PER_VERTEX);
+    const depth = this.getFrustumSize();
+    let offset = 0;
+
+    this.cameras.forEach((camera, index) => {
+      const [r, g, b] = index === this.highlighted ? this.config.highlightColor : this.config.color;
+      const m = camera.cameraToWorld;
+      // 相机空间点变换到世界空间
+      const toWorld = (x: number, y: number, z: number): [number, number, number] => [
+        m[0] * x + m[4] * y + m[8] * z + m[12],
+        m[1] * x + m[5] * y + m[9] * z + m[13],
+        m[2] * x + m[6] * y + m[10] * z + m[14],
+      ];
+      const addLine = (a: [number, number, number], c: [number, number, number]) => {
+        vertices.set([a[0], a[1], a[2], r, g, b, c[0], c[1], c[2], r, g, b], offset);
+        offset += FLOATS_PER_VERTEX * 2;
+      };
+
+      const halfY = Math.tan(camera.fovY / 2) * depth;
+      const halfX = halfY * camera.aspect;
+      const apex = toWorld(0, 0, 0);
+      const topLeft = toWorld(-halfX, halfY, -depth);
+      const topRight = toWorld(halfX, halfY, -depth);
+      const bottomRight = toWorld(halfX, -halfY, -depth);
+      const bottomLeft = toWorld(-halfX, -halfY, -depth);
+      const up = toWorld(0, halfY * 1.5, -depth);
+
+      addLine(apex, topLeft);
+      addLine(apex, topRight);
+      addLine(apex, bottomRight);
+      addLine(apex, bottomLeft);
+      addLine(topLeft, topRight);
+      addLine(topRight, bottomRight);
+      addLine(bottomRight, bottomLeft);
+      addLine(bottomLeft, topLeft);
+      // 上方向标记
+      const markLeft = toWorld(-halfX * 0.5, halfY * 1.1, -depth);
+      const markRight = toWorld(halfX * 0.5, halfY * 1.1, -depth);
+      addLine(markLeft, markRight);
+      addLine(markRight, up);
+      addLine(up, markLeft);
+    });
+
+    return vertices;
+  }
+
+  private updateVertexBuffer(): void {
+    this.dirty = false;
+    const vertices = this.generateVertices();
+    this.vertexCount = vertices.length / FLOATS_PER_VERTEX;
+    if (this.vertexCount === 0) return;
+    if (!this.vertexBuffer || this.vertexBuffer.size < vertices.byteLength) {
+      this.vertexBuffer?.destroy();
+      this.vertexBuffer = this.renderer.device.createBuffer({
+        size: vertices.byteLength,
+        usage: GPUBufferUsage.VERTEX | GPUBufferUsage.COPY_DST,
+        label: "camera-frustum-vertices",
+      });
+    }
+    this.renderer.device.queue.writeBuffer(this.vertexBuffer, 0, vertices);
+  }
+
+  /**
+   * 渲染视锥
+   */
+  render(pass: GPURenderPassEncoder): void {
+    if (!this.visible || this.cameras.length === 0) return;
+    if (this.dirty) {
+      this.updateVertexBuffer();
+    }
+    if (!this.vertexBuffer || this.vertexCount === 0) return;
+
+    this.renderer.device.queue.writeBuffer(this.uniformBuffer, 0, this.camera.viewProjectionMatrix);
+    pass.setPipeline(this.pipeline);
+    pass.setBindGroup(0, this.bindGroup);
+    pass.setVertexBuffer(0, this.vertexBuffer);
+    pass.draw(this.vertexCount);
+  }
+
+  /**
+   * 销毁资源
+   */
+  destroy(): void {
+    this.vertexBuffer?.destroy();
+    this.vertexBuffer = null;
+    this.uniformBuffer.destroy();
+    this.cameras = [];
+  }
+}
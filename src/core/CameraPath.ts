@@ -0,0 +1,177 @@
+import type { Vec3Tuple } from "../types";
+
+/**
+ * 相机路径关键帧
+ */
+export interface CameraKeyframe {
+  /** 时间（秒） */
+  time: number;
+  position: Vec3Tuple;
+  /** 注视点 */
+  target: Vec3Tuple;
+  /** 垂直视场角（弧度） */
+  fov: number;
+}
+
+/**
+ * 路径上某一时刻的相机位姿
+ */
+export interface CameraPathSample {
+  position: Vec3Tuple;
+  target: Vec3Tuple;
+  fov: number;
+}
+
+/**
+ * 三次 Hermite 插值
+ */
+function hermite(p0: number, m0: number, p1: number, m1: number, t: number, dt: number): number {
+  const t2 = t * t;
+  const t3 = t2 * t;
+  return (2 * t3 - 3 * t2 + 1) * p0
+    + (t3 - 2 * t2 + t) * dt * m0
+    + (-2 * t3 + 3 * t2) * p1
+    + (t3 - t2) * dt * m1;
+}
+
+/**
+ * CameraPath - 相机路径
+ *
+ * 关键帧按时间排序，位置、注视点和视场角分别用按时间参数化的 Catmull-Rom 样条（Hermite 形式，
+ * 切线取相邻关键帧的差商，首尾取单侧差商）插值，经过每个关键帧且速度连续。
+ * evaluate 只依赖时间，同一路径在任意帧率下结果相同，可以逐帧离线渲染导出视频
+ */
+export class CameraPath {
+  private keyframes: CameraKeyframe[] = [];
+
+  /** 循环播放时末尾回到第一个关键帧（首尾之间按 loopGap 秒过渡） */
+  loop: boolean = false;
+  loopGap: number = 1;
+
+  constructor(keyframes: CameraKeyframe[] = []) {
+    for (const keyframe of keyframes) {
+      this.addKeyframe(keyframe);
+    }
+  }
+
+  /**
+   * 添加关键帧（同一时间已有关键帧时替换）
+   * @returns 关键帧索引
+   */
+  addKeyframe(keyframe: CameraKeyframe): number {
+    const copy: CameraKeyframe = {
+      time: keyframe.time,
+      position: [...keyframe.position],
+      target: [...keyframe.target],
+      fov: keyframe.fov,
+    };
+    const existing = this.keyframes.findIndex((k) => k.time === copy.time);
+    if (existing >= 0) {
+      this.keyframes[existing] = copy;
+      return existing;
+    }
+    let index = this.keyframes.findIndex((k) => k.time > copy.time);
+    if (index < 0) index = this.keyframes.length;
+    this.keyframes.splice(index, 0, copy);
+    return index;
+  }
+
+  removeKeyframe(index: number): boolean {
+    if (index < 0 || index >= this.keyframes.length) return false;
+    this.keyframes.splice(index, 1);
+    return true;
+  }
+
+  getKeyframes(): ReadonlyArray<Readonly<CameraKeyframe>> {
+    return this.keyframes;
+  }
+
+  clear(): void {
+    this.keyframes = [];
+  }
+
+  /**
+   * 路径时长（秒）：循环时包含末尾回到开头的过渡
+   */
+  getDuration(): number {
+    const n = this.keyframes.length;
+    if (n === 0) return 0;
+    const span = this.keyframes[n - 1].time - this.keyframes[0].time;
+    return this.loop && n > 1 ? span + this.loopGap : span;
+  }
+
+  /**
+   * 计算时间 time（秒，从第一个关键帧起算）的相机位姿；非循环时超出范围取首尾关键帧，没有关键帧时返回 null
+   */
+  evaluate(time: number): CameraPathSample | null {
+    const frames = this.getControlFrames();
+    const n = frames.length;
+    if (n === 0) return null;
+    if (n === 1) return { position: [...frames[0].position], target: [...frames[0].target], fov: frames[0].fov };
+
+    const start = frames[0].time;
+    const duration = this.getDuration();
+    let t = time;
+    if (this.loop) {
+      t = ((t % duration) + duration) % duration;
+    } else {
+      t = Math.min(Math.max(t, 0), duration);
+    }
+    t += start;
+
+    // 所在区间 [i, i + 1]
+    let i = 0;
+    while (i < n - 2 && frames[i + 1].time <= t) i++;
+    const k0 = frames[i];
+    const k1 = frames[i + 1];
+    const dt = k1.time - k0.time;
+    const u = dt > 0 ? (t - k0.time) / dt : 0;
+
+    const tangent = (index: number, get: (k: CameraKeyframe) => number): number => {
+      const prev = frames[Math.max(index - 1, 0)];
+      const next = frames[Math.min(index + 1, n - 1)];
+      const span = next.time - prev.time;
+      return span > 0 ? (get(next) - get(prev)) / span : 0;
+    };
+    const interpolate = (get: (k: CameraKeyframe) => number): number =>
+      hermite(get(k0), tangent(i, get), get(k1), tangent(i + 1, get), u, dt);
+
+    const vec = (field: "position" | "target"): Vec3Tuple => [
+      interpolate((k) => k[field][0]),
+      interpolate((k) => k[field][1]),
+      interpolate((k) => k[field][2]),
+    ];
+    return {
+      position: vec("position"),
+      target: vec("target"),
+      // 样条可能过冲，限制在两端关键帧的范围内
+      fov: Math.min(Math.max(interpolate((k) => k.fov), Math.min(k0.fov, k1.fov)), Math.max(k0.fov, k1.fov)),
+    };
+  }
+
+  /**
+   * 参与插值的关键帧：循环时在两端各补一个环绕的关键帧，使首尾过渡也是平滑的
+   */
+  private getControlFrames(): CameraKeyframe[] {
+    const frames = this.keyframes;
+    const n = frames.length;
+    if (!this.loop || n < 2) return frames;
+    const period = this.getDuration();
+    const shift = (k: CameraKeyframe, offset: number): CameraKeyframe => ({ ...k, time: k.time + offset });
+    return [shift(frames[n - 1], -period), ...frames, shift(frames[0], period), shift(frames[1], period)];
+  }
+
+  /**
+   * 序列化为 JSON 对象（用于保存路径）
+   */
+  toJSON(): { loop: boolean; loopGap: number; keyframes: CameraKeyframe[] } {
+    return { loop: this.loop, loopGap: this.loopGap, keyframes: this.keyframes.map((k) => ({ ...k })) };
+  }
+
+  static fromJSON(json: { loop?: boolean; loopGap?: number; keyframes: CameraKeyframe[] }): CameraPath {
+    const path = new CameraPath(json.keyframes);
+    path.loop = json.loop ?? false;
+    path.loopGap = json.loopGap ?? 1;
+    return path;
+  }
+}
@@ -0,0 +1,138 @@
+import { describe, it, expect } from 'vitest';
+import {
+  DEFAULT_DEPTH_CONFIG,
+  resolveDepthConfig,
+  getDepthClearValue,
+  getDepthCompare,
+  getDepthFormat,
+  getNearDepth,
+  linearizeDepth,
+  unprojectPoint,
+  writePerspective,
+  type DepthConfig,
+} from './DepthConvention';
+
+const NEAR = 0.1;
+const FAR = 100;
+const FOV = Math.PI / 3;
+const ASPECT = 16 / 9;
+
+const CONFIGS: [string, DepthConfig][] = [
+  ['标准', { reverseZ: false, infiniteFar: false }],
+  ['标准 + 无限远', { reverseZ: false, infiniteFar: true }],
+  ['reverse-Z', { reverseZ: true, infiniteFar: false }],
+  ['reverse-Z + 无限远', { reverseZ: true, infiniteFar: true }],
+];
+
+/**
+ * 视图空间距离 distance（相机前方）处的 NDC 深度
+ */
+function projectDepth(projection: Float32Array, distance: number): number {
+  const z = -distance;
+  const clipZ = projection[10] * z + projection[14];
+  const clipW = projection[11] * z + projection[15];
+  return clipZ / clipW;
+}
+
+/**
+ * 按深度比较函数判断 a 是否通过（比 b 更近）
+ */
+function passes(compare: GPUCompareFunction, a: number, b: number): boolean {
+  switch (compare) {
+    case 'less': return a < b;
+    case 'less-equal': return a <= b;
+    case 'greater': return a > b;
+    case 'greater-equal': return a >= b;
+    default: throw new Error(`unexpected compare ${compare}`);
+  }
+}
+
+describe('resolveDepthConfig', () => {
+  it('默认为标准深度、有限远平面', () => {
+    expect(DEFAULT_DEPTH_CONFIG).toEqual({ reverseZ: false, infiniteFar: false });
+    expect(resolveDepthConfig()).toEqual(DEFAULT_DEPTH_CONFIG);
+  });
+
+  it('只覆盖提供的字段', () => {
+    expect(resolveDepthConfig({ reverseZ: true })).toEqual({ reverseZ: true, infiniteFar: false });
+    expect(resolveDepthConfig({ infiniteFar: true })).toEqual({ reverseZ: false, infiniteFar: true });
+  });
+
+  it('返回新对象，不修改默认配置', () => {
+    const config = resolveDepthConfig();
+    config.reverseZ = true;
+    expect(DEFAULT_DEPTH_CONFIG.reverseZ).toBe(false);
+  });
+});
+
+describe.each(CONFIGS)('深度约定: %s', (_name, config) => {
+  const projection = writePerspective(new Float32Array(16), FOV, ASPECT, NEAR, FAR, config);
+
+  it('投影矩阵的 xy 和 w 部分与约定无关', () => {
+    const f = 1 / Math.tan(FOV / 2);
+    expect(projection[0]).toBeCloseTo(f / ASPECT, 5);
+    expect(projection[5]).toBeCloseTo(f, 5);
+    expect(projection[11]).toBe(-1);
+    expect(projection[15]).toBe(0);
+  });
+
+  it('近平面映射到 getNearDepth', () => {
+    expect(projectDepth(projection, NEAR)).toBeCloseTo(getNearDepth(config), 5);
+  });
+
+  it('远处映射到清除值', () => {
+    const clear = getDepthClearValue(config);
+    if (config.infiniteFar) {
+      // 无限远平面：远平面不裁剪，极远处趋近清除值且不越界
+      const depth = projectDepth(projection, 1e7);
+      expect(depth).toBeCloseTo(clear, 5);
+      expect(depth).toBeGreaterThanOrEqual(0);
+      expect(depth).toBeLessThanOrEqual(1);
+    } else {
+      expect(projectDepth(projection, FAR)).toBeCloseTo(clear, 4);
+    }
+  });
+
+  it('可见范围内深度位于 [0, 1]', () => {
+    for (const distance of [NEAR, 0.5, 1, 10, 50, FAR]) {
+      const depth = projectDepth(projection, distance);
+      expect(depth).toBeGreaterThanOrEqual(-1e-6);
+      expect(depth).toBeLessThanOrEqual(1 + 1e-6);
+    }
+  });
+
+  it('比较函数让更近的片段通过', () => {
+    const near = projectDepth(projection, 1);
+    const far = projectDepth(projection, 10);
+    expect(passes(getDepthCompare(config), near, far)).toBe(true);
+    expect(passes(getDepthCompare(config), far, near)).toBe(false);
+    expect(passes(getDepthCompare(config), near, near)).toBe(false);
+    expect(passes(getDepthCompare(config, true), near, near)).toBe(true);
+    // 任何可见片段都能通过清除后的深度缓冲
+    expect(passes(getDepthCompare(config), far, getDepthClearValue(config))).toBe(true);
+  });
+
+  it('linearizeDepth 还原视图空间距离', () => {
+    for (const distance of [NEAR, 0.37, 1, 12.5, FAR]) {
+      const depth = projectDepth(projection, distance);
+      expect(linearizeDepth(projection, depth) / distance).toBeCloseTo(1, 3);
+    }
+  });
+
+  it('unprojectPoint 与投影互逆', () => {
+    // view 为单位矩阵时 view-projection 即投影矩阵
+    const point: [number, number, number] = [0.4, -0.25, -3];
+    const w = -point[2];
+    const ndcX = (projection[0] * point[0]) / w;
+    const ndcY = (projection[5] * point[1]) / w;
+    const depth = projectDepth(projection, w);
+    const [x, y, z] = unprojectPoint(projection, ndcX, ndcY, depth);
+    expect(x).toBeCloseTo(point[0], 3);
+    expect(y).toBeCloseTo(point[1], 3);
+    expect(z).toBeCloseTo(point[2], 3);
+  });
+
+  it('reverse-Z 使用 32 位浮点深度', () => {
+    expect(getDepthFormat(config)).toBe(config.reverseZ ? 'depth32float' : 'depth24plus');
+  });
+});
@@ -0,0 +1,195 @@
+/**
+ * DepthConvention - 深度约定
+ *
+ * 投影矩阵、深度缓冲清除值、深度比较函数和射线反投影
+ * 必须使用同一套约定，统一在这里计算，避免各处各自假设。
+ *
+ * WebGPU 的 NDC 深度范围为 [0, 1]：
+ * - 标准: 近平面 0，远平面 1，比较函数 less，清除为 1
+ * - Reverse-Z: 近平面 1，远平面 0，比较函数 greater，清除为 0（配合 depth32float 精度更均匀）
+ * - 无限远平面: 不裁剪远处，far 参数被忽略
+ *
+ * splat 的深度排序基于视图空间 z，与深度映射无关
+ */
+
+/**
+ * 深度配置
+ */
+export interface DepthConfig {
+  /** 是否使用 reverse-Z */
+  reverseZ: boolean;
+  /** 是否使用无限远平面 */
+  infiniteFar: boolean;
+}
+
+/**
+ * 默认深度配置：标准深度、有限远平面
+ */
+export const DEFAULT_DEPTH_CONFIG: DepthConfig = {
+  reverseZ: false,
+  infiniteFar: false,
+};
+
+/**
+ * 合并深度配置
+ */
+export function resolveDepthConfig(config: Partial<DepthConfig> = {}): DepthConfig {
+  return { ...DEFAULT_DEPTH_CONFIG, ...config };
+}
+
+/**
+ * 深度缓冲格式
+ */
+export function getDepthFormat(config: DepthConfig): GPUTextureFormat {
+  return config.reverseZ ? "depth32float" : "depth24plus";
+}
+
+/**
+ * 深度缓冲清除值（最远处）
+ */
+export function getDepthClearValue(config: DepthConfig): number {
+  return config.reverseZ ? 0.0 : 1.0;
+}
+
+/**
+ * "更近" 的深度比较函数
+ * @param orEqual 是否包含相等
+ */
+export function getDepthCompare(config: DepthConfig, orEqual: boolean = false): GPUCompareFunction {
+  if (config.reverseZ) {
+    return orEqual ? "greater-equal" : "greater";
+  }
+  return orEqual ? "less-equal" : "less";
+}
+
+/**
+ * 把标准深度 [0, 1]（0 为最近）映射到当前约定
+ */
+export function mapDepth(config: DepthConfig, depth: number): number {
+  return config.reverseZ ? 1 - depth : depth;
+}
+
+/**
+ * 近平面对应的 NDC 深度
+ */
+export function getNearDepth(config: DepthConfig): number {
+  return mapDepth(config, 0);
+}
+
+/**
+ * 写入透视投影矩阵（列主序，右手坐标系，相机看向 -Z）
+ */
+export function writePerspective(
+  out: Float32Array,
+  fov: number,
+  aspect: number,
+  near: number,
+  far: number,
+  config: DepthConfig,
+): Float32Array {
+  const f = 1.0 / Math.tan(fov / 2);
+
+  out.fill(0);
+  out[0] = f / aspect;
+  out[5] = f;
+  out[11] = -1;
+
+  // depth = (m10 * z + m14) / -z
+  if (config.reverseZ) {
+    if (config.infiniteFar) {
+      out[10] = 0;
+      out[14] = near;
+    } else {
+      out[10] = near / (far - near);
+      out[14] = (near * far) / (far - near);
+    }
+  } else if (config.infiniteFar) {
+    out[10] = -1;
+    out[14] = -near;
+  } else {
+    out[10] = far / (near - far);
+    out[14] = (near * far) / (near - far);
+  }
+
+  return out;
+}
+
+/**
+ * 把 NDC 深度转换为视图空间距离（相机前方 -z），适用于 writePerspective 生成的任意约定
+ * @param projection 列主序透视投影矩阵
+ */
+export function linearizeDepth(projection: ArrayLike<number>, depth: number): number {
+  // depth = (m10 * z + m14) / -z  =>  -z = m14 / (depth + m10)
+  return projection[14] / (depth + projection[10]);
+}
+
+/**
+ * 把 NDC 坐标反投影到世界空间
+ * @param viewProjection 列主序 view-projection 矩阵
+ * @param depth 与当前深度约定一致的 NDC 深度
+ */
+export function unprojectPoint(
+  viewProjection: ArrayLike<number>,
+  ndcX: number,
+  ndcY: number,
+  depth: number,
+): [number, number, number] {
+  const inv = invertMat4(viewProjection);
+  if (!inv) {
+    return [0, 0, 0];
+  }
+  const x = inv[0] * ndcX + inv[4] * ndcY + inv[8] * depth + inv[12];
+  const y = inv[1] * ndcX + inv[5] * ndcY + inv[9] * depth + inv[13];
+  const z = inv[2] * ndcX + inv[6] * ndcY + inv[10] * depth + inv[14];
+  const w = inv[3] * ndcX + inv[7] * ndcY + inv[11] * depth + inv[15];
+  const invW = Math.abs(w) > 1e-12 ? 1 / w : 0;
+  return [x * invW, y * invW, z * invW];
+}
+
+/**
+ * 4x4 矩阵求逆（列主序，双精度），不可逆时返回 null
+ */
+export function invertMat4(m: ArrayLike<number>): Float64Array | null {
+  const out = new Float64Array(16);
+  const a00 = m[0], a01 = m[1], a02 = m[2], a03 = m[3];
+  const a10 = m[4], a11 = m[5], a12 = m[6], a13 = m[7];
+  const a20 = m[8], a21 = m[9], a22 = m[10], a23 = m[11];
+  const a30 = m[12], a31 = m[13], a32 = m[14], a33 = m[15];
+
+  const b00 = a00 * a11 - a01 * a10;
+  const b01 = a00 * a12 - a02 * a10;
+  const b02 = a00 * a13 - a03 * a10;
+  const b03 = a01 * a12 - a02 * a11;
+  const b04 = a01 * a13 - a03 * a11;
+  const b05 = a02 * a13 - a03 * a12;
+  const b06 = a20 * a31 - a21 * a30;
+  const b07 = a20 * a32 - a22 * a30;
+  const b08 = a20 * a33 - a23 * a30;
+  const b09 = a21 * a32 - a22 * a31;
+  const b10 = a21 * a33 - a23 * a31;
+  const b11 = a22 * a33 - a23 * a32;
+
+  const det = b00 * b11 - b01 * b10 + b02 * b09 + b03 * b08 - b04 * b07 + b05 * b06;
+  if (Math.abs(det) < 1e-20) {
+    return null;
+  }
+  const invDet = 1 / det;
+
+  out[0] = (a11 * b11 - a12 * b10 + a13 * b09) * invDet;
+  out[1] = (a02 * b10 - a01 * b11 - a03 * b09) * invDet;
+  out[2] = (a31 * b05 - a32 * b04 + a33 * b03) * invDet;
+  out[3] = (a22 * b04 - a21 * b05 - a23 * b03) * invDet;
+  out[4] = (a12 * b08 - a10 * b11 - a13 * b07) * invDet;
+  out[5] = (a00 * b11 - a02 * b08 + a03 * b07) * invDet;
+  out[6] = (a32 * b02 - a30 * b05 - a33 * b01) * invDet;
+  out[7] = (a20 * b05 - a22 * b02 + a23 * b01) * invDet;
+  out[8] = (a10 * b10 - a11 * b08 + a13 * b06) * invDet;
+  out[9] = (a01 * b08 - a00 * b10 - a03 * b06) * invDet;
+  out[10] = (a30 * b04 - a31 * b02 + a33 * b00) * invDet;
+  out[11] = (a21 * b02 - a20 * b04 - a23 * b00) * invDet;
+  out[12] = (a11 * b07 - a10 * b09 - a12 * b06) * invDet;
+  out[13] = (a00 * b09 - a01 * b07 + a02 * b06) * invDet;
+  out[14] = (a31 * b01 - a30 * b03 - a32 * b00) * invDet;
+  out[15] = (a20 * b03 - a21 * b01 + a22 * b00) * invDet;
+  return out;
+}
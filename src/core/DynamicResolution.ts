@@ -0,0 +1,106 @@
+import { Camera } from "./Camera";
+
+/**
+ * 动态分辨率配置
+ */
+export interface DynamicResolutionConfig {
+  /** 是否启用 */
+  enabled: boolean;
+  /** 运动时的渲染分辨率比例（相对正常分辨率，0~1） */
+  motionScale: number;
+  /** 旋转速度阈值（弧度 / 秒），超过时降低分辨率 */
+  rotationThreshold: number;
+  /** 平移速度阈值（每秒移动的距离相对相机到目标点距离的比例） */
+  translationThreshold: number;
+  /** 低于阈值持续多久后恢复正常分辨率（毫秒） */
+  restoreDelay: number;
+}
+
+export const DEFAULT_DYNAMIC_RESOLUTION_CONFIG: DynamicResolutionConfig = {
+  enabled: false,
+  motionScale: 0.5,
+  rotationThreshold: 0.6,
+  translationThreshold: 0.3,
+  restoreDelay: 200,
+};
+
+/**
+ * DynamicResolution - 相机快速运动时降低渲染分辨率
+ *
+ * 每帧比较相机位置和视线方向与上一帧的差异得到旋转 / 平移速度（平移按相机到目标点的距离归一化，与场景尺度无关），
+ * 超过阈值时返回 motionScale，停下并持续 restoreDelay 后恢复 1。
+ * 只有两档比例，进入和恢复各改变一次画布尺寸，避免逐帧重建渲染目标
+ */
+export class DynamicResolution {
+  private config: DynamicResolutionConfig = { ...DEFAULT_DYNAMIC_RESOLUTION_CONFIG };
+  private lastPosition: Float32Array = new Float32Array(3);
+  private lastForward: Float32Array = new Float32Array(3);
+  private hasLast: boolean = false;
+  private scale: number = 1;
+  private lastMotionTime: number = -Infinity;
+  // 最近一帧的运动速度（用于诊断）
+  private rotationSpeed: number = 0;
+  private translationSpeed: number = 0;
+
+  setConfig(config: Partial<DynamicResolutionConfig>): void {
+    this.config = { ...this.config, ...config };
+    this.config.motionScale = Math.min(Math.max(this.config.motionScale, 0.1), 1);
+  }
+
+  getConfig(): DynamicResolutionConfig {
+    return { ...this.config };
+  }
+
+  /**
+   * 每帧调用，返回本帧的分辨率比例
+   * @param deltaTime 距上一帧的秒数
+   */
+  update(camera: Camera, deltaTime: number, now: number = performance.now()): number {
+    const { position, target } = camera;
+    const dx = target[0] - position[0];
+    const dy = target[1] - position[1];
+    const dz = target[2] - position[2];
+    const distance = Math.hypot(dx, dy, dz) || 1;
+    const forward = [dx / distance, dy / distance, dz / distance];
+
+    if (this.hasLast && deltaTime > 0) {
+      const cos = forward[0] * this.lastForward[0] + forward[1] * this.lastForward[1] + forward[2] * this.lastForward[2];
+      const moved = Math.hypot(
+        position[0] - this.lastPosition[0],
+        position[1] - this.lastPosition[1],
+        position[2] - this.lastPosition[2],
+      );
+      this.rotationSpeed = Math.acos(Math.min(1, Math.max(-1, cos))) / deltaTime;
+      this.translationSpeed = moved / distance / deltaTime;
+    }
+    this.lastPosition.set(position);
+    this.lastForward.set(forward);
+    this.hasLast = true;
+
+    if (!this.config.enabled) {
+      this.scale = 1;
+      return 1;
+    }
+    if (this.rotationSpeed > this.config.rotationThreshold || this.translationSpeed > this.config.translationThreshold) {
+      this.lastMotionTime = now;
+      this.scale = this.config.motionScale;
+    } else if (now - this.lastMotionTime >= this.config.restoreDelay) {
+      this.scale = 1;
+    }
+    return this.scale;
+  }
+
+  /**
+   * 当前分辨率比例
+   */
+  getScale(): number {
+    return this.scale;
+  }
+
+  /**
+   * 最近一帧的相机运动速度
+   */
+  getMotion(): { rotation: number; translation: number } {
+    return { rotation: this.rotationSpeed, translation: this.translationSpeed };
+  }
+}
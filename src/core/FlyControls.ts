@@ -0,0 +1,292 @@
+import { Camera } from "./Camera";
+
+/**
+ * 飞行控制器配置
+ */
+export interface FlyControlsConfig {
+  /** 移动速度（场景单位 / 秒） */
+  speed: number;
+  /** 按住 Shift 时的速度倍数 */
+  boostMultiplier: number;
+  /** 拖动视角的灵敏度（弧度 / 像素） */
+  lookSpeed: number;
+  /** 滚轮每一格调整速度的倍数 */
+  wheelSpeedFactor: number;
+}
+
+export const DEFAULT_FLY_CONTROLS_CONFIG: FlyControlsConfig = {
+  speed: 1,
+  boostMultiplier: 4,
+  lookSpeed: 0.003,
+  wheelSpeedFactor: 1.1,
+};
+
+// 视线与上方向的最小夹角（避免越过天顶翻转）
+const MIN_UP_ANGLE_COS = Math.cos(0.01);
+
+// 按键 -> [右, 上, 前] 方向
+const MOVE_KEYS: Record<string, [number, number, number]> = {
+  KeyW: [0, 0, 1],
+  ArrowUp: [0, 0, 1],
+  KeyS: [0, 0, -1],
+  ArrowDown: [0, 0, -1],
+  KeyD: [1, 0, 0],
+  ArrowRight: [1, 0, 0],
+  KeyA: [-1, 0, 0],
+  ArrowLeft: [-1, 0, 0],
+  KeyE: [0, 1, 0],
+  Space: [0, 1, 0],
+  KeyQ: [0, -1, 0],
+  KeyC: [0, -1, 0],
+};
+
+type Vec3 = [number, number, number];
+
+function normalize(v: Vec3): Vec3 {
+  const length = Math.hypot(v[0], v[1], v[2]) || 1;
+  return [v[0] / length, v[1] / length, v[2] / length];
+}
+
+function cross(a: Vec3, b: Vec3): Vec3 {
+  return [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]];
+}
+
+function dot(a: Vec3, b: Vec3): number {
+  return a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+}
+
+// 绕单位轴旋转（Rodrigues）
+function rotateAround(v: Vec3, axis: Vec3, angle: number): Vec3 {
+  const c = Math.cos(angle);
+  const s = Math.sin(angle);
+  const k = cross(axis, v);
+  const d = dot(axis, v) * (1 - c);
+  return [
+    v[0] * c + k[0] * s + axis[0] * d,
+    v[1] * c + k[1] * s + axis[1] * d,
+    v[2] * c + k[2] * s + axis[2] * d,
+  ];
+}
+
+// 焦点在输入框中时不响应移动按键
+function isEditableTarget(target: EventTarget | null): boolean {
+  const element = target as HTMLElement | null;
+  if (!element || !element.tagName) return false;
+  return element.isContentEditable || element.tagName === "INPUT" || element.tagName === "TEXTAREA" || element.tagName === "SELECT";
+}
+
+/**
+ * FlyControls - 第一人称飞行控制器
+ *
+ * WASD / 方向键前后左右，E / Space 上升，Q / C 下降（沿相机上方向），Shift 加速，
+ * 左键拖动（或单指拖动）转动视角，滚轮调整移动速度。不做碰撞检测，可以穿过场景，适合在大范围采集的环境中漫游。
+ * 朝向每帧从相机的 position / target 读取，其它代码修改相机位姿（训练相机视角、取景等）后直接生效；
+ * 移动时 target 随 position 平移，保持与位置的距离不变，切回轨道控制时以它为旋转中心
+ */
+export class FlyControls {
+  private camera: Camera;
+  private canvas: HTMLCanvasElement;
+  private config: FlyControlsConfig = { ...DEFAULT_FLY_CONTROLS_CONFIG };
+  // 外部暂停输入的条件（例如 gizmo 拖动时）
+  private isInputEnabled: () => boolean;
+
+  private pressedKeys: Set<string> = new Set();
+  private boost: boolean = false;
+  private isDragging: boolean = false;
+  private lastX: number = 0;
+  private lastY: number = 0;
+  private attached: boolean = false;
+
+  // 启用/禁用
+  enabled: boolean = true;
+
+  private boundOnKeyDown: (e: KeyboardEvent) => void;
+  private boundOnKeyUp: (e: KeyboardEvent) => void;
+  private boundOnBlur: () => void;
+  private boundOnPointerDown: (e: PointerEvent) => void;
+  private boundOnPointerMove: (e: PointerEvent) => void;
+  private boundOnPointerUp: () => void;
+  private boundOnWheel: (e: WheelEvent) => void;
+
+  /**
+   * @param isInputEnabled 额外的输入开关，返回 false 时忽略指针和按键输入
+   */
+  constructor(camera: Camera, canvas: HTMLCanvasElement, isInputEnabled: () => boolean = () => true) {
+    this.camera = camera;
+    this.canvas = canvas;
+    this.isInputEnabled = isInputEnabled;
+
+    this.boundOnKeyDown = this.onKeyDown.bind(this);
+    this.boundOnKeyUp = this.onKeyUp.bind(this);
+    this.boundOnBlur = () => this.releaseInput();
+    this.boundOnPointerDown = this.onPointerDown.bind(this);
+    this.boundOnPointerMove = this.onPointerMove.bind(this);
+    this.boundOnPointerUp = () => {
+      this.isDragging = false;
+    };
+    this.boundOnWheel = this.onWheel.bind(this);
+  }
+
+  /**
+   * 挂接 / 断开输入事件（切换相机控制器时调用）
+   */
+  setAttached(attached: boolean): void {
+    if (attached === this.attached) return;
+    this.attached = attached;
+    if (attached) {
+      window.addEventListener("keydown", this.boundOnKeyDown);
+      window.addEventListener("keyup", this.boundOnKeyUp);
+      window.addEventListener("blur", this.boundOnBlur);
+      this.canvas.addEventListener("pointerdown", this.boundOnPointerDown);
+      this.canvas.addEventListener("pointermove", this.boundOnPointerMove);
+      this.canvas.addEventListener("pointerup", this.boundOnPointerUp);
+      this.canvas.addEventListener("pointerleave", this.boundOnPointerUp);
+      this.canvas.addEventListener("wheel", this.boundOnWheel, { passive: false });
+    } else {
+      window.removeEventListener("keydown", this.boundOnKeyDown);
+      window.removeEventListener("keyup", this.boundOnKeyUp);
+      window.removeEventListener("blur", this.boundOnBlur);
+      this.canvas.removeEventListener("pointerdown", this.boundOnPointerDown);
+      this.canvas.removeEventListener("pointermove", this.boundOnPointerMove);
+      this.canvas.removeEventListener("pointerup", this.boundOnPointerUp);
+      this.canvas.removeEventListener("pointerleave", this.boundOnPointerUp);
+      this.canvas.removeEventListener("wheel", this.boundOnWheel);
+      this.releaseInput();
+    }
+  }
+
+  isAttached(): boolean {
+    return this.attached;
+  }
+
+  setConfig(config: Partial<FlyControlsConfig>): void {
+    this.config = { ...this.config, ...config };
+  }
+
+  getConfig(): FlyControlsConfig {
+    return { ...this.config };
+  }
+
+  private isActive(): boolean {
+    return this.attached && this.enabled && this.isInputEnabled();
+  }
+
+  private releaseInput(): void {
+    this.pressedKeys.clear();
+    this.boost = false;
+    this.isDragging = false;
+  }
+
+  private onKeyDown(e: KeyboardEvent): void {
+    if (isEditableTarget(e.target)) return;
+    this.boost = e.shiftKey;
+    if (!(e.code in MOVE_KEYS)) return;
+    if (!this.isActive()) return;
+    this.pressedKeys.add(e.code);
+    // 避免方向键 / 空格滚动页面
+    e.preventDefault();
+  }
+
+  private onKeyUp(e: KeyboardEvent): void {
+    this.boost = e.shiftKey;
+    this.pressedKeys.delete(e.code);
+  }
+
+  private onPointerDown(e: PointerEvent): void {
+    if (!this.isActive() || e.button !== 0) return;
+    this.isDragging = true;
+    this.lastX = e.clientX;
+    this.lastY = e.clientY;
+  }
+
+  private onPointerMove(e: PointerEvent): void {
+    if (!this.isDragging) return;
+    if (!this.isActive()) {
+      this.isDragging = false;
+      return;
+    }
+    const deltaX = e.clientX - this.lastX;
+    const deltaY = e.clientY - this.lastY;
+    this.lastX = e.clientX;
+    this.lastY = e.clientY;
+    this.look(-deltaX * this.config.lookSpeed, -deltaY * this.config.lookSpeed);
+  }
+
+  private onWheel(e: WheelEvent): void {
+    e.preventDefault();
+    if (!this.isActive() || e.deltaY === 0) return;
+    const factor = this.config.wheelSpeedFactor;
+    this.config.speed *= e.deltaY < 0 ? factor : 1 / factor;
+  }
+
+  /**
+   * 相机当前的单位视线方向、视线长度和单位上方向
+   */
+  private getFrame(): { forward: Vec3; distance: number; up: Vec3 } {
+    const { position, target, up } = this.camera;
+    const direction: Vec3 = [target[0] - position[0], target[1] - position[1], target[2] - position[2]];
+    const distance = Math.hypot(direction[0], direction[1], direction[2]);
+    return {
+      forward: distance > 0 ? normalize(direction) : [0, 0, -1],
+      distance: distance > 0 ? distance : 1,
+      up: normalize([up[0], up[1], up[2]]),
+    };
+  }
+
+  /**
+   * 转动视角：绕上方向偏航，绕右方向俯仰（不越过天顶 / 天底）
+   */
+  look(yaw: number, pitch: number): void {
+    const { forward, distance, up } = this.getFrame();
+    let direction = rotateAround(forward, up, yaw);
+    const right = normalize(cross(direction, up));
+    const pitched = rotateAround(direction, right, pitch);
+    if (Math.abs(dot(pitched, up)) < MIN_UP_ANGLE_COS) {
+      direction = pitched;
+    }
+    const { position, target } = this.camera;
+    for (let k = 0; k < 3; k++) {
+      target[k] = position[k] + direction[k] * distance;
+    }
+    this.camera.updateMatrix();
+  }
+
+  /**
+   * 每帧调用：按住的移动键按速度和帧间隔移动相机
+   * @param deltaTime 距上一帧的秒数
+   */
+  tick(deltaTime: number): void {
+    if (this.pressedKeys.size === 0) return;
+    if (!this.isActive()) {
+      this.pressedKeys.clear();
+      return;
+    }
+    const move: Vec3 = [0, 0, 0];
+    for (const code of this.pressedKeys) {
+      const axis = MOVE_KEYS[code];
+      move[0] += axis[0];
+      move[1] += axis[1];
+      move[2] += axis[2];
+    }
+    const length = Math.hypot(move[0], move[1], move[2]);
+    if (length === 0) return;
+
+    const { forward, up } = this.getFrame();
+    const right = normalize(cross(forward, up));
+    const step = (this.config.speed * (this.boost ? this.config.boostMultiplier : 1) * deltaTime) / length;
+    const { position, target } = this.camera;
+    for (let k = 0; k < 3; k++) {
+      const delta = (right[k] * move[0] + up[k] * move[1] + forward[k] * move[2]) * step;
+      position[k] += delta;
+      target[k] += delta;
+    }
+    this.camera.updateMatrix();
+  }
+
+  /**
+   * 销毁控制器
+   */
+  destroy(): void {
+    this.setAttached(false);
+  }
+}
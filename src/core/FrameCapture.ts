@@ -0,0 +1,93 @@
+/**
+ * FrameCapture - 画布帧回读
+ *
+ * 画布纹理在 endFrame 提交前复制到可映射 buffer，回读后统一转换为 RGBA f32：
+ * bgra8unorm / rgba8unorm 除以 255，rgba16float（HDR 画布）按半精度解码。
+ * 数值保持画布编码（sRGB 编码、预乘 alpha），线性化由使用方决定
+ */
+
+/**
+ * 回读的一帧
+ */
+export interface FrameCapture {
+  width: number;
+  height: number;
+  /** RGBA（画布编码值、预乘 alpha），逐行从上到下 */
+  pixels: Float32Array;
+}
+
+/**
+ * 每个像素的字节数
+ */
+export function getCaptureBytesPerPixel(format: GPUTextureFormat): number {
+  return format === "rgba16float" ? 8 : 4;
+}
+
+/**
+ * 把回读的行数据（按 bytesPerRow 对齐）解码为 RGBA f32
+ */
+export function decodeFrameCapture(
+  data: ArrayBuffer,
+  format: GPUTextureFormat,
+  width: number,
+  height: number,
+  bytesPerRow: number,
+): FrameCapture {
+  const pixels = new Float32Array(width * height * 4);
+  if (format === "rgba16float") {
+    const halves = new Uint16Array(data);
+    const rowStride = bytesPerRow / 2;
+    for (let y = 0; y < height; y++) {
+      for (let i = 0; i < width * 4; i++) {
+        pixels[y * width * 4 + i] = halfToFloat(halves[y * rowStride + i]);
+      }
+    }
+  } else {
+    const bytes = new Uint8Array(data);
+    const bgra = format === "bgra8unorm";
+    for (let y = 0; y < height; y++) {
+      for (let x = 0; x < width; x++) {
+        const src = y * bytesPerRow + x * 4;
+        const dst = (y * width + x) * 4;
+        pixels[dst] = bytes[src + (bgra ? 2 : 0)] / 255;
+        pixels[dst + 1] = bytes[src + 1] / 255;
+        pixels[dst + 2] = bytes[src + (bgra ? 0 : 2)] / 255;
+        pixels[dst + 3] = bytes[src + 3] / 255;
+      }
+    }
+  }
+  return { width, height, pixels };
+}
+
+/**
+ * 转换为非预乘的 RGBA8（用于 PNG 等图像文件），HDR 画布超出 [0, 1] 的值截断
+ */
+export function frameCaptureToRGBA8(capture: FrameCapture): Uint8ClampedArray {
+  const { pixels } = capture;
+  const result = new Uint8ClampedArray(pixels.length);
+  for (let i = 0; i < pixels.length; i += 4) {
+    const alpha = Math.min(1, Math.max(0, pixels[i + 3]));
+    result[i + 3] = Math.round(alpha * 255);
+    if (alpha <= 0) continue;
+    for (let c = 0; c < 3; c++) {
+      result[i + c] = Math.round((pixels[i + c] / alpha) * 255);
+    }
+  }
+  return result;
+}
+
+/**
+ * IEEE 754 半精度 -> 单精度
+ */
+export function halfToFloat(h: number): number {
+  const sign = h & 0x8000 ? -1 : 1;
+  const exponent = (h >> 10) & 0x1f;
+  const mantissa = h & 0x3ff;
+  if (exponent === 0) {
+    return sign * mantissa * 2 ** -24;
+  }
+  if (exponent === 0x1f) {
+    return mantissa ? NaN : sign * Infinity;
+  }
+  return sign * (1 + mantissa / 1024) * 2 ** (exponent - 15);
+}
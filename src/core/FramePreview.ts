@@ -0,0 +1,157 @@
+/**
+ * FramePreview - 在另一个画布中显示当前视图
+ *
+ * 订阅 Renderer 的每帧画面纹理，按比例缩放（留边）绘制到目标画布，
+ * 用于在 UI 面板中显示预览（例如与参考图像并排对比），而不是只能全屏显示。
+ * 目标画布使用同一个 GPUDevice，不经过 CPU 回读
+ */
+
+import type { Renderer } from "./Renderer";
+import { PipelineCache } from "./PipelineCache";
+
+const previewShader = /* wgsl */ `
+struct PreviewParams {
+  // 图像在目标画布 NDC 中的半尺寸
+  scale: vec2<f32>,
+  _pad: vec2<f32>,
+}
+
+@group(0) @binding(0) var<uniform> params: PreviewParams;
+@group(0) @binding(1) var frameTex: texture_2d<f32>;
+@group(0) @binding(2) var frameSampler: sampler;
+
+struct VertexOutput {
+  @builtin(position) position: vec4<f32>,
+  @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertexIndex: u32) -> VertexOutput {
+  let corner = vec2<f32>(f32(vertexIndex & 1u), f32(vertexIndex >> 1u));
+  var output: VertexOutput;
+  output.position = vec4<f32>((corner * 2.0 - 1.0) * params.scale, 0.0, 1.0);
+  output.uv = vec2<f32>(corner.x, 1.0 - corner.y);
+  return output;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+  return textureSample(frameTex, frameSampler, input.uv);
+}
+`;
+
+/**
+ * FramePreview - 视图预览面板
+ */
+export class FramePreview {
+  private renderer: Renderer;
+  private canvas: HTMLCanvasElement;
+  private context: GPUCanvasContext;
+  private format: GPUTextureFormat;
+  private pipeline: GPURenderPipeline;
+  private bindGroupLayout: GPUBindGroupLayout;
+  private paramsBuffer: GPUBuffer;
+  private sampler: GPUSampler;
+  private bindGroup: GPUBindGroup | null = null;
+  private boundTexture: GPUTexture | null = null;
+  private unsubscribe: () => void;
+  private destroyed: boolean = false;
+
+  /** 是否更新（暂停时保留最后一帧） */
+  enabled: boolean = true;
+
+  constructor(renderer: Renderer, canvas: HTMLCanvasElement) {
+    this.renderer = renderer;
+    this.canvas = canvas;
+    const device = renderer.device;
+
+    const context = canvas.getContext("webgpu") as GPUCanvasContext | null;
+    if (!context) {
+      throw new Error("无法获取预览画布的 WebGPU 上下文");
+    }
+    this.context = context;
+    this.format = navigator.gpu.getPreferredCanvasFormat();
+    this.context.configure({ device, format: this.format, alphaMode: "premultiplied" });
+
+    const pipelines = PipelineCache.get(device);
+    this.bindGroupLayout = pipelines.getBindGroupLayout({
+      label: "frame-preview-layout",
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.VERTEX, buffer: { type: "uniform" } },
+        { binding: 1, visibility: GPUShaderStage.FRAGMENT, texture: { sampleType: "float" } },
+        { binding: 2, visibility: GPUShaderStage.FRAGMENT, sampler: { type: "filtering" } },
+      ],
+    });
+    const module = pipelines.getShaderModule({ code: previewShader, label: "frame-preview-shader" });
+    this.pipeline = pipelines.getRenderPipeline({
+      layout: pipelines.getPipelineLayout([this.bindGroupLayout]),
+      vertex: { module, entryPoint: "vs_main", buffers: [] },
+      fragment: { module, entryPoint: "fs_main", targets: [{ format: this.format }] },
+      primitive: { topology: "triangle-strip" },
+      label: "frame-preview-pipeline",
+    });
+    this.paramsBuffer = device.createBuffer({
+      size: 16,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+      label: "frame-preview-params",
+    });
+    this.sampler = device.createSampler({ magFilter: "linear", minFilter: "linear" });
+
+    this.unsubscribe = renderer.onFrameTexture((texture) => {
+      if (this.enabled) this.draw(texture);
+    });
+  }
+
+  private draw(texture: GPUTexture): void {
+    const width = this.canvas.width;
+    const height = this.canvas.height;
+    if (width === 0 || height === 0 || texture.width === 0 || texture.height === 0) return;
+    const device = this.renderer.device;
+
+    if (this.boundTexture !== texture) {
+      this.bindGroup = device.createBindGroup({
+        layout: this.bindGroupLayout,
+        entries: [
+          { binding: 0, resource: { buffer: this.paramsBuffer } },
+          { binding: 1, resource: texture.createView() },
+          { binding: 2, resource: this.sampler },
+        ],
+        label: "frame-preview-bind-group",
+      });
+      this.boundTexture = texture;
+    }
+
+    // 保持宽高比，较长的一边占满画布
+    const ratio = (texture.width / texture.height) / (width / height);
+    const scale = ratio > 1 ? [1, 1 / ratio] : [ratio, 1];
+    device.queue.writeBuffer(this.paramsBuffer, 0, new Float32Array([scale[0], scale[1], 0, 0]));
+
+    const encoder = device.createCommandEncoder({ label: "frame-preview-encoder" });
+    const pass = encoder.beginRenderPass({
+      colorAttachments: [{
+        view: this.context.getCurrentTexture().createView(),
+        clearValue: { r: 0, g: 0, b: 0, a: 0 },
+        loadOp: "clear",
+        storeOp: "store",
+      }],
+    });
+    pass.setPipeline(this.pipeline);
+    pass.setBindGroup(0, this.bindGroup!);
+    pass.draw(4);
+    pass.end();
+    device.queue.submit([encoder.finish()]);
+  }
+
+  /**
+   * 销毁（取消订阅，释放资源），可以重复调用
+   */
+  destroy(): void {
+    if (this.destroyed) return;
+    this.destroyed = true;
+    this.unsubscribe();
+    this.paramsBuffer.destroy();
+    this.context.unconfigure();
+    this.bindGroup = null;
+    this.boundTexture = null;
+  }
+}
@@ -0,0 +1,95 @@
+/**
+ * FrameSequenceExport - 逐帧导出的输出端
+ *
+ * App.exportCameraPath 按固定帧率逐帧离屏渲染相机路径，把每一帧交给输出端（按顺序，等待写入完成后再渲染下一帧）。
+ * 内置 PNG 序列（回调写入 / 写入 File System Access 目录），也可以实现自己的输出端把帧交给视频编码器
+ * （例如 frameCaptureToRGBA8 后构造 WebCodecs VideoFrame）
+ */
+
+import { frameCaptureToRGBA8, type FrameCapture } from "./FrameCapture";
+import { encodePNG } from "../utils/png";
+
+/**
+ * 导出的一帧
+ */
+export interface FrameSequenceFrame {
+  capture: FrameCapture;
+  /** 帧序号（从 0 开始） */
+  index: number;
+  /** 路径时间（秒） */
+  time: number;
+}
+
+/**
+ * 帧序列输出端
+ */
+export interface FrameSequenceSink {
+  writeFrame(frame: FrameSequenceFrame): void | Promise<void>;
+  /** 全部帧写入后调用（中途出错时也会调用） */
+  close?(): void | Promise<void>;
+}
+
+/**
+ * PNG 序列的文件命名
+ */
+export interface PNGSequenceOptions {
+  /** 文件名前缀 */
+  prefix: string;
+  /** 序号位数（不足补 0） */
+  digits: number;
+}
+
+export const DEFAULT_PNG_SEQUENCE_OPTIONS: PNGSequenceOptions = {
+  prefix: "frame_",
+  digits: 5,
+};
+
+/**
+ * 帧序号对应的文件名，例如 frame_00012.png
+ */
+export function getSequenceFileName(index: number, options: Partial<PNGSequenceOptions> = {}): string {
+  const { prefix, digits } = { ...DEFAULT_PNG_SEQUENCE_OPTIONS, ...options };
+  return `${prefix}${String(index).padStart(digits, "0")}.png`;
+}
+
+/**
+ * PNG 序列：每帧编码为 PNG 后以文件名调用 write（例如上传到服务器、打包 zip）
+ */
+export function createPNGSequenceSink(
+  write: (fileName: string, blob: Blob) => void | Promise<void>,
+  options: Partial<PNGSequenceOptions> = {},
+): FrameSequenceSink {
+  return {
+    async writeFrame({ capture, index }) {
+      const blob = await encodePNG(frameCaptureToRGBA8(capture), capture.width, capture.height);
+      await write(getSequenceFileName(index, options), blob);
+    },
+  };
+}
+
+/**
+ * 可写入文件的目录（File System Access API 的 FileSystemDirectoryHandle）
+ */
+export interface WritableDirectory {
+  getFileHandle(name: string, options: { create: boolean }): Promise<{
+    createWritable(): Promise<{ write(data: Blob): Promise<void>; close(): Promise<void> }>;
+  }>;
+}
+
+/**
+ * PNG 序列写入目录（window.showDirectoryPicker 选择的目录），同名文件覆盖
+ */
+export function createDirectoryPNGSequenceSink(
+  directory: WritableDirectory,
+  options: Partial<PNGSequenceOptions> = {},
+): FrameSequenceSink {
+  return createPNGSequenceSink(async (fileName, blob) => {
+    const handle = await directory.getFileHandle(fileName, { create: true });
+    const writable = await handle.createWritable();
+    try {
+      await writable.write(blob);
+    } finally {
+      await writable.close();
+    }
+  }, options);
+}
@@ -0,0 +1,171 @@
+/**
+ * GPUResourceTracker - GPU buffer / 纹理的存活计数（泄漏检测）
+ *
+ * 包装设备的 createBuffer / createTexture 和返回对象的 destroy，记录尚未 destroy 的资源。
+ * 每个实体持有自己的 buffer，漏掉一次 destroy 就会泄漏显存：浸泡测试在反复创建 / 销毁实体、
+ * 调整窗口大小和切换配置之后比较计数是否回到基线。只在调试时启用（强引用所有存活资源），
+ * 必须在创建任何资源之前安装，之前创建的资源不被计数。
+ * 对象池（SplatBufferPool）中的空闲 buffer 也算存活，比较前先清空对象池
+ */
+
+/**
+ * 资源计数
+ */
+export interface GPUResourceCounts {
+  /** 存活的 buffer 数量 */
+  buffers: number;
+  /** 存活 buffer 的总字节数 */
+  bufferBytes: number;
+  /** 存活的纹理数量 */
+  textures: number;
+  /** 存活纹理的估算字节数（按每像素 4 字节、含 mip 和数组层） */
+  textureBytes: number;
+}
+
+/**
+ * 与基线相比增加的资源（按标签分组）
+ */
+export interface GPUResourceLeak {
+  kind: "buffer" | "texture";
+  label: string;
+  /** 比基线多出的数量 */
+  count: number;
+  /** 比基线多出的字节数 */
+  bytes: number;
+}
+
+/**
+ * 计数快照，用于和之后的状态比较
+ */
+export interface GPUResourceSnapshot {
+  counts: GPUResourceCounts;
+  // 按 kind + 标签分组的数量和字节数
+  groups: Map<string, { count: number; bytes: number }>;
+}
+
+interface TrackedResource {
+  kind: "buffer" | "texture";
+  label: string;
+  bytes: number;
+}
+
+/**
+ * GPU 资源计数器（每个设备一个）
+ */
+export class GPUResourceTracker {
+  private static trackers: WeakMap<GPUDevice, GPUResourceTracker> = new WeakMap();
+
+  private live: Map<GPUBuffer | GPUTexture, TrackedResource> = new Map();
+
+  /**
+   * 在设备上安装计数器（重复安装返回同一个实例）
+   */
+  static install(device: GPUDevice): GPUResourceTracker {
+    let tracker = GPUResourceTracker.trackers.get(device);
+    if (!tracker) {
+      tracker = new GPUResourceTracker(device);
+      GPUResourceTracker.trackers.set(device, tracker);
+    }
+    return tracker;
+  }
+
+  /**
+   * 获取设备上已安装的计数器，未安装时返回 null
+   */
+  static get(device: GPUDevice): GPUResourceTracker | null {
+    return GPUResourceTracker.trackers.get(device) ?? null;
+  }
+
+  private constructor(device: GPUDevice) {
+    const createBuffer = device.createBuffer.bind(device);
+    const createTexture = device.createTexture.bind(device);
+
+    device.createBuffer = (descriptor: GPUBufferDescriptor): GPUBuffer => {
+      const buffer = createBuffer(descriptor);
+      this.track(buffer, { kind: "buffer", label: descriptor.label ?? "", bytes: descriptor.size });
+      return buffer;
+    };
+    device.createTexture = (descriptor: GPUTextureDescriptor): GPUTexture => {
+      const texture = createTexture(descriptor);
+      this.track(texture, { kind: "texture", label: descriptor.label ?? "", bytes: estimateTextureBytes(texture) });
+      return texture;
+    };
+  }
+
+  /**
+   * 当前计数
+   */
+  getCounts(): GPUResourceCounts {
+    const counts: GPUResourceCounts = { buffers: 0, bufferBytes: 0, textures: 0, textureBytes: 0 };
+    for (const resource of this.live.values()) {
+      if (resource.kind === "buffer") {
+        counts.buffers++;
+        counts.bufferBytes += resource.bytes;
+      } else {
+        counts.textures++;
+        counts.textureBytes += resource.bytes;
+      }
+    }
+    return counts;
+  }
+
+  /**
+   * 记录当前状态作为基线
+   */
+  snapshot(): GPUResourceSnapshot {
+    return { counts: this.getCounts(), groups: this.group() };
+  }
+
+  /**
+   * 与基线相比增加的资源（按标签分组，多出的字节数从大到小），没有增加时返回空数组
+   */
+  diff(baseline: GPUResourceSnapshot): GPUResourceLeak[] {
+    const leaks: GPUResourceLeak[] = [];
+    for (const [key, current] of this.group()) {
+      const before = baseline.groups.get(key) ?? { count: 0, bytes: 0 };
+      if (current.count > before.count) {
+        const separator = key.indexOf(":");
+        leaks.push({
+          kind: key.slice(0, separator) as GPUResourceLeak["kind"],
+          label: key.slice(separator + 1),
+          count: current.count - before.count,
+          bytes: current.bytes - before.bytes,
+        });
+      }
+    }
+    return leaks.sort((a, b) => b.bytes - a.bytes);
+  }
+
+  private track(resource: GPUBuffer | GPUTexture, info: TrackedResource): void {
+    this.live.set(resource, info);
+    const destroy = resource.destroy.bind(resource);
+    resource.destroy = () => {
+      this.live.delete(resource);
+      destroy();
+    };
+  }
+
+  private group(): Map<string, { count: number; bytes: number }> {
+    const groups = new Map<string, { count: number; bytes: number }>();
+    for (const resource of this.live.values()) {
+      const key = `${resource.kind}:${resource.label}`;
+      const group = groups.get(key) ?? { count: 0, bytes: 0 };
+      group.count++;
+      group.bytes += resource.bytes;
+      groups.set(key, group);
+    }
+    return groups;
+  }
+}
+
+// 估算纹理大小：每像素 4 字节（深度 / 半精度格式也按 4 字节近似）
+function estimateTextureBytes(texture: GPUTexture): number {
+  let bytes = 0;
+  for (let level = 0; level < texture.mipLevelCount; level++) {
+    const width = Math.max(1, texture.width >> level);
+    const height = Math.max(1, texture.height >> level);
+    const depth = texture.dimension === "3d" ? Math.max(1, texture.depthOrArrayLayers >> level) : texture.depthOrArrayLayers;
+    bytes += width * height * depth * 4;
+  }
+  return bytes * texture.sampleCount;
+}
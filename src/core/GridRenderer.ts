@@ -0,0 +1,251 @@
+import { Renderer } from "./Renderer";
+import { Camera } from "./Camera";
+import { PipelineCache } from "./PipelineCache";
+import { getDepthCompare } from "./DepthConvention";
+import type { BoundingBoxProvider, Vec3Tuple } from "../types";
+
+/**
+ * 网格地面配置
+ */
+export interface GridConfig {
+  /** 每米对应的场景单位（厘米制数据为 100），用于把自动间距对齐到公制刻度 */
+  unitsPerMeter: number;
+  /** 细线间距（场景单位），0 表示根据包围盒自动选择 10 的整数次幂米 */
+  minorSpacing: number;
+  /** 每隔多少条细线画一条粗线 */
+  majorEvery: number;
+  /** 网格高度 (Y)，null 表示贴在包围盒底部 */
+  height: number | null;
+  /** 淡出距离（场景单位），0 表示根据包围盒自动选择 */
+  fadeDistance: number;
+  /** 细线颜色 RGBA */
+  minorColor: [number, number, number, number];
+  /** 粗线颜色 RGBA */
+  majorColor: [number, number, number, number];
+}
+
+/** 默认网格配置 */
+export const DEFAULT_GRID_CONFIG: GridConfig = {
+  unitsPerMeter: 1,
+  minorSpacing: 0,
+  majorEvery: 10,
+  height: null,
+  fadeDistance: 0,
+  minorColor: [0.5, 0.5, 0.5, 0.35],
+  majorColor: [0.7, 0.7, 0.7, 0.6],
+};
+
+const gridShaderCode = /* wgsl */ `
+struct Uniforms {
+  viewProjection: mat4x4<f32>,
+  // xyz: 相机位置, w: 网格高度
+  cameraHeight: vec4<f32>,
+  // x: 细线间距, y: 粗线间距, z: 淡出距离
+  spacing: vec4<f32>,
+  minorColor: vec4<f32>,
+  majorColor: vec4<f32>,
+}
+
+@group(0) @binding(0) var<uniform> uniforms: Uniforms;
+
+struct VertexOutput {
+  @builtin(position) position: vec4<f32>,
+  @location(0) worldXZ: vec2<f32>,
+}
+
+// 以相机为中心、边长 2 * 淡出距离的四边形，视觉上无限延伸
+@vertex
+fn vertexMain(@builtin(vertex_index) vertexIndex: u32) -> VertexOutput {
+  var corners = array<vec2<f32>, 6>(
+    vec2<f32>(-1.0, -1.0), vec2<f32>(1.0, -1.0), vec2<f32>(1.0, 1.0),
+    vec2<f32>(-1.0, -1.0), vec2<f32>(1.0, 1.0), vec2<f32>(-1.0, 1.0),
+  );
+  let extent = uniforms.spacing.z;
+  let xz = uniforms.cameraHeight.xz + corners[vertexIndex] * extent;
+  var output: VertexOutput;
+  output.position = uniforms.viewProjection * vec4<f32>(xz.x, uniforms.cameraHeight.w, xz.y, 1.0);
+  output.worldXZ = xz;
+  return output;
+}
+
+// 抗锯齿线条强度：距最近网格线的距离按屏幕导数归一化
+fn gridLine(coord: vec2<f32>) -> f32 {
+  let derivative = max(fwidth(coord), vec2<f32>(1e-6));
+  let grid = abs(fract(coord - 0.5) - 0.5) / derivative;
+  return 1.0 - min(min(grid.x, grid.y), 1.0);
+}
+
+@fragment
+fn fragmentMain(input: VertexOutput) -> @location(0) vec4<f32> {
+  let minor = gridLine(input.worldXZ / uniforms.spacing.x);
+  let major = gridLine(input.worldXZ / uniforms.spacing.y);
+  let distance = length(input.worldXZ - uniforms.cameraHeight.xz);
+  let fade = 1.0 - smoothstep(0.5, 1.0, distance / uniforms.spacing.z);
+  var color = uniforms.minorColor * minor;
+  if major * uniforms.majorColor.a >= color.a {
+    color = uniforms.majorColor * major;
+  }
+  let alpha = color.a * fade;
+  if alpha <= 0.001 { discard; }
+  return vec4<f32>(color.rgb, alpha);
+}
+`;
+
+/**
+ * GridRenderer - 地面网格 / 标尺
+ *
+ * 在 splat 下方绘制无限网格：细线间距按包围盒尺寸自动对齐到 10 的整数次幂米，
+ * 单位换算错误（如厘米数据按米显示）时网格与模型的比例一眼可见。
+ * 在 splat 之前绘制，只做深度测试不写深度
+ */
+export class GridRenderer {
+  private renderer: Renderer;
+  private camera: Camera;
+  private pipeline: GPURenderPipeline;
+  private uniformBuffer: GPUBuffer;
+  private bindGroup: GPUBindGroup;
+
+  private enabled: boolean = false;
+  private config: GridConfig = { ...DEFAULT_GRID_CONFIG };
+  private provider: BoundingBoxProvider | null = null;
+  // 最近一帧使用的 [细线间距, 粗线间距]
+  private spacing: [number, number] = [1, 10];
+
+  constructor(renderer: Renderer, camera: Camera) {
+    this.renderer = renderer;
+    this.camera = camera;
+    const device = renderer.device;
+    const pipelines = PipelineCache.get(device);
+
+    const module = pipelines.getShaderModule({ code: gridShaderCode, label: "grid-shader" });
+    const bindGroupLayout = pipelines.getBindGroupLayout({
+      label: "grid-layout",
+      entries: [{
+        binding: 0,
+        visibility: GPUShaderStage.VERTEX | GPUShaderStage.FRAGMENT,
+        buffer: { type: "uniform" },
+      }],
+    });
+
+    // viewProjection (64) + cameraHeight (16) + spacing (16) + minorColor (16) + majorColor (16)
+    this.uniformBuffer = device.createBuffer({
+      size: 128,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+      label: "grid-uniforms",
+    });
+    this.bindGroup = device.createBindGroup({
+      layout: bindGroupLayout,
+      entries: [{ binding: 0, resource: { buffer: this.uniformBuffer } }],
+    });
+
+    this.pipeline = pipelines.getRenderPipeline({
+      layout: pipelines.getPipelineLayout([bindGroupLayout]),
+      vertex: { module, entryPoint: "vertexMain" },
+      fragment: {
+        module,
+        entryPoint: "fragmentMain",
+        targets: [{
+          format: renderer.format,
+          blend: {
+            color: { srcFactor: "src-alpha", dstFactor: "one-minus-src-alpha", operation: "add" },
+            alpha: { srcFactor: "one", dstFactor: "one-minus-src-alpha", operation: "add" },
+          },
+        }],
+      },
+      primitive: { topology: "triangle-list", cullMode: "none" },
+      depthStencil: {
+        format: renderer.depthFormat,
+        depthWriteEnabled: false,
+        depthCompare: getDepthCompare(renderer.depthConfig),
+      },
+      label: "grid-pipeline",
+    });
+  }
+
+  /**
+   * 设置是否显示网格
+   */
+  setEnabled(enabled: boolean): void {
+    this.enabled = enabled;
+  }
+
+  /**
+   * 是否显示网格
+   */
+  isEnabled(): boolean {
+    return this.enabled;
+  }
+
+  /**
+   * 更新网格配置（未指定的字段保持不变）
+   */
+  setConfig(config: Partial<GridConfig>): void {
+    this.config = { ...this.config, ...config };
+  }
+
+  /**
+   * 获取网格配置
+   */
+  getConfig(): GridConfig {
+    return { ...this.config };
+  }
+
+  /**
+   * 设置用于自动间距和高度的世界空间包围盒提供者
+   */
+  setProvider(provider: BoundingBoxProvider | null): void {
+    this.provider = provider;
+  }
+
+  /**
+   * 获取最近一帧使用的 [细线间距, 粗线间距]（场景单位）
+   */
+  getSpacing(): [number, number] {
+    return [...this.spacing];
+  }
+
+  /**
+   * 渲染网格（在 splat 之前调用）
+   */
+  render(pass: GPURenderPassEncoder): void {
+    if (!this.enabled) {
+      return;
+    }
+    const config = this.config;
+    const box = this.provider?.getBoundingBox() ?? null;
+    const unit = config.unitsPerMeter > 0 ? config.unitsPerMeter : 1;
+
+    // 包围盒水平尺寸，决定自动间距和淡出距离
+    const extent = box ? Math.max(box.max[0] - box.min[0], box.max[2] - box.min[2], 1e-6) : 10 * unit;
+    let minor = config.minorSpacing;
+    if (minor <= 0) {
+      const meters = extent / unit / 10;
+      minor = 10 ** Math.floor(Math.log10(meters)) * unit;
+    }
+    const major = minor * Math.max(1, Math.round(config.majorEvery));
+    this.spacing = [minor, major];
+    const height = config.height ?? (box ? box.min[1] : 0);
+    const fadeDistance = config.fadeDistance > 0 ? config.fadeDistance : Math.max(extent * 2, major * 10);
+
+    const cameraPos: Vec3Tuple = [this.camera.position[0], this.camera.position[1], this.camera.position[2]];
+    const data = new Float32Array(32);
+    data.set(this.camera.viewProjectionMatrix, 0);
+    data.set([cameraPos[0], cameraPos[1], cameraPos[2], height], 16);
+    data.set([minor, major, fadeDistance, 0], 20);
+    data.set(config.minorColor, 24);
+    data.set(config.majorColor, 28);
+    this.renderer.uploads.write(this.uniformBuffer, 0, data);
+
+    pass.setPipeline(this.pipeline);
+    pass.setBindGroup(0, this.bindGroup);
+    pass.draw(6);
+  }
+
+  /**
+   * 销毁资源
+   */
+  destroy(): void {
+    this.uniformBuffer.destroy();
+    this.provider = null;
+  }
+}
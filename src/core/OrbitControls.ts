@@ -1,5 +1,37 @@
 import { Camera } from "./Camera";
 
+/**
+ * 轨道控制器的约束和惯性配置
+ */
+export interface OrbitControlsConfig {
+  /** 惯性：松开后速度每 1/60 秒保留的比例（0~1，0 关闭惯性），需要每帧调用 tick */
+  inertia: number;
+  minDistance: number;
+  maxDistance: number;
+  /** 垂直角范围（弧度，从 +Y 向下量） */
+  minPhi: number;
+  maxPhi: number;
+  /** 水平角范围（弧度），默认不限制 */
+  minTheta: number;
+  maxTheta: number;
+  /** 目标点（旋转中心）的活动范围，null 不限制 */
+  targetBounds: { min: [number, number, number]; max: [number, number, number] } | null;
+}
+
+export const DEFAULT_ORBIT_CONTROLS_CONFIG: OrbitControlsConfig = {
+  inertia: 0,
+  minDistance: 0.001,
+  maxDistance: Infinity,
+  minPhi: 0.01,
+  maxPhi: Math.PI - 0.01,
+  minTheta: -Infinity,
+  maxTheta: Infinity,
+  targetBounds: null,
+};
+
+// 速度低于该值时停止惯性
+const INERTIA_EPSILON = 1e-5;
+
 /**
  * OrbitControls - 轨道控制器
  * 只负责鼠标/触摸输入控制相机
@@ -14,10 +46,20 @@ export class OrbitControls {
   phi: number = Math.PI / 4; // 垂直角 (从Y轴向下)
 
   // 限制
-  minDistance: number = 0.001;
-  maxDistance: number = Infinity;
-  minPhi: number = 0.01;
-  maxPhi: number = Math.PI - 0.01;
+  minDistance: number = DEFAULT_ORBIT_CONTROLS_CONFIG.minDistance;
+  maxDistance: number = DEFAULT_ORBIT_CONTROLS_CONFIG.maxDistance;
+  minPhi: number = DEFAULT_ORBIT_CONTROLS_CONFIG.minPhi;
+  maxPhi: number = DEFAULT_ORBIT_CONTROLS_CONFIG.maxPhi;
+  minTheta: number = DEFAULT_ORBIT_CONTROLS_CONFIG.minTheta;
+  maxTheta: number = DEFAULT_ORBIT_CONTROLS_CONFIG.maxTheta;
+  targetBounds: OrbitControlsConfig["targetBounds"] = null;
+
+  // 惯性
+  inertia: number = DEFAULT_ORBIT_CONTROLS_CONFIG.inertia;
+  // 最近一次输入的每帧增量（松开后按惯性衰减继续应用）
+  private velocity = { theta: 0, phi: 0, pan: [0, 0, 0], zoom: 0 };
+  // 上一次 tick 之后是否有拖动输入（按住不动时不保留速度）
+  private movedSinceTick: boolean = false;
 
   // 灵敏度
   rotateSpeed: number = 0.005;
@@ -38,8 +80,21 @@ export class OrbitControls {
   private lastTouchDistance: number = 0;
   private lastTouchCenter: { x: number; y: number } = { x: 0, y: 0 };
 
+  // 双击（双指点按）检测：两次单指轻点的最大间隔（毫秒）和最大距离（CSS 像素）
+  doubleTapInterval: number = 300;
+  doubleTapDistance: number = 30;
+  /** 双击（鼠标左键双击或触摸双击）回调（视口坐标），用于对焦到点击位置 */
+  onDoubleTap: ((clientX: number, clientY: number) => void) | null = null;
+  /** 对焦时相机到对焦点的距离相对当前距离的比例（小于 1 时同时拉近） */
+  focusZoom: number = 0.7;
+  // 当前单指按下的位置和时间（移动或多指后取消）
+  private tapStart: { x: number; y: number; time: number } | null = null;
+  private lastTap: { x: number; y: number; time: number } | null = null;
+
   // 启用/禁用
   enabled: boolean = true;
+  // 是否挂接输入事件（切换到其它相机控制器时断开）
+  private attached: boolean = true;
 
   // 绑定的事件处理函数（用于移除监听器）
   private boundOnMouseDown: (e: MouseEvent) => void;
@@ -50,6 +105,7 @@ export class OrbitControls {
   private boundOnTouchMove: (e: TouchEvent) => void;
   private boundOnTouchEnd: (e: TouchEvent) => void;
   private boundOnContextMenu: (e: Event) => void;
+  private boundOnDoubleClick: (e: MouseEvent) => void;
 
   constructor(camera: Camera, canvas: HTMLCanvasElement) {
     this.camera = camera;
@@ -64,6 +120,9 @@ export class OrbitControls {
     this.boundOnTouchMove = this.onTouchMove.bind(this);
     this.boundOnTouchEnd = this.onTouchEnd.bind(this);
     this.boundOnContextMenu = (e: Event) => e.preventDefault();
+    this.boundOnDoubleClick = (e: MouseEvent) => {
+      if (this.enabled && e.button === 0) this.onDoubleTap?.(e.clientX, e.clientY);
+    };
 
     this.setupEventListeners();
     this.update();
@@ -78,6 +137,7 @@ export class OrbitControls {
     this.canvas.addEventListener("mousemove", this.boundOnMouseMove);
     this.canvas.addEventListener("mouseup", this.boundOnMouseUp);
     this.canvas.addEventListener("mouseleave", this.boundOnMouseUp);
+    this.canvas.addEventListener("dblclick", this.boundOnDoubleClick);
     this.canvas.addEventListener("wheel", this.boundOnWheel, {
       passive: false,
     });
@@ -103,6 +163,7 @@ export class OrbitControls {
     this.canvas.removeEventListener("mousemove", this.boundOnMouseMove);
     this.canvas.removeEventListener("mouseup", this.boundOnMouseUp);
     this.canvas.removeEventListener("mouseleave", this.boundOnMouseUp);
+    this.canvas.removeEventListener("dblclick", this.boundOnDoubleClick);
     this.canvas.removeEventListener("wheel", this.boundOnWheel);
     this.canvas.removeEventListener("touchstart", this.boundOnTouchStart);
     this.canvas.removeEventListener("touchmove", this.boundOnTouchMove);
@@ -110,11 +171,31 @@ export class OrbitControls {
     this.canvas.removeEventListener("contextmenu", this.boundOnContextMenu);
   }
 
+  /**
+   * 挂接 / 断开输入事件（切换到其它相机控制器时断开），断开时停止惯性
+   */
+  setAttached(attached: boolean): void {
+    if (attached === this.attached) return;
+    this.attached = attached;
+    if (attached) {
+      this.setupEventListeners();
+    } else {
+      this.removeEventListeners();
+      this.isDragging = false;
+      this.touchMode = "none";
+      this.stopInertia();
+    }
+  }
+
+  isAttached(): boolean {
+    return this.attached;
+  }
+
   /**
    * 销毁控制器
    */
   destroy(): void {
-    this.removeEventListeners();
+    this.setAttached(false);
   }
 
   private onMouseDown(e: MouseEvent): void {
@@ -132,27 +213,53 @@ export class OrbitControls {
     this.lastX = e.clientX;
     this.lastY = e.clientY;
 
-    // 左键旋转
+    // 左键旋转（向上拖动时相机向上）
     if (e.buttons === 1) {
-      this.theta -= deltaX * this.rotateSpeed;
-      this.phi -= deltaY * this.rotateSpeed; // 修复：向上拖动时相机向上
-      this.phi = Math.max(this.minPhi, Math.min(this.maxPhi, this.phi));
+      this.rotate(-deltaX * this.rotateSpeed, -deltaY * this.rotateSpeed);
     }
     // 右键平移（拖动场景模式：向上拖动场景向上移动）
     else if (e.buttons === 2) {
-      const panX = -deltaX * this.panSpeed * this.distance;
-      const panY = deltaY * this.panSpeed * this.distance;
+      this.pan(-deltaX * this.panSpeed * this.distance, deltaY * this.panSpeed * this.distance);
+    }
+
+    this.update();
+  }
 
-      // 计算平移向量
-      const sinTheta = Math.sin(this.theta);
-      const cosTheta = Math.cos(this.theta);
+  /**
+   * 旋转并记录速度
+   */
+  private rotate(deltaTheta: number, deltaPhi: number): void {
+    this.theta += deltaTheta;
+    this.phi += deltaPhi;
+    this.velocity.theta = deltaTheta;
+    this.velocity.phi = deltaPhi;
+    this.velocity.pan.fill(0);
+    this.velocity.zoom = 0;
+    this.movedSinceTick = true;
+  }
 
-      this.camera.target[0] += panX * cosTheta;
-      this.camera.target[2] += panX * sinTheta;
-      this.camera.target[1] += panY;
+  /**
+   * 在水平面（按水平角）和竖直方向平移目标点并记录速度
+   */
+  private pan(panX: number, panY: number): void {
+    const sinTheta = Math.sin(this.theta);
+    const cosTheta = Math.cos(this.theta);
+    const pan = [panX * cosTheta, panY, panX * sinTheta];
+    for (let k = 0; k < 3; k++) {
+      this.camera.target[k] += pan[k];
     }
+    this.velocity.pan = pan;
+    this.velocity.theta = 0;
+    this.velocity.phi = 0;
+    this.movedSinceTick = true;
+  }
 
-    this.update();
+  /**
+   * 按对数比例缩放距离并记录速度
+   */
+  private zoom(logScale: number): void {
+    this.distance *= Math.exp(logScale);
+    this.velocity.zoom = logScale;
   }
 
   private onMouseUp(): void {
@@ -162,11 +269,8 @@ export class OrbitControls {
   private onWheel(e: WheelEvent): void {
     e.preventDefault();
     if (!this.enabled) return;
-    this.distance += e.deltaY * this.zoomSpeed * this.distance;
-    this.distance = Math.max(
-      this.minDistance,
-      Math.min(this.maxDistance, this.distance),
-    );
+    // 与 distance *= (1 + deltaY * zoomSpeed) 一阶相同，对数形式便于惯性衰减
+    this.zoom(Math.log(Math.max(1e-3, 1 + e.deltaY * this.zoomSpeed)));
     this.update();
   }
 
@@ -176,12 +280,14 @@ export class OrbitControls {
     
     if (e.touches.length === 1) {
       // 单指：旋转模式
+      this.tapStart = { x: e.touches[0].clientX, y: e.touches[0].clientY, time: performance.now() };
       this.touchMode = 'rotate';
       this.isDragging = true;
       this.lastX = e.touches[0].clientX;
       this.lastY = e.touches[0].clientY;
     } else if (e.touches.length === 2) {
       // 双指：缩放+平移模式
+      this.tapStart = null;
       this.touchMode = 'zoom-pan';
       this.isDragging = true;
       this.lastTouchDistance = this.getTouchDistance(e.touches);
@@ -194,15 +300,19 @@ export class OrbitControls {
     if (!this.enabled || !this.isDragging) return;
 
     if (e.touches.length === 1 && this.touchMode === 'rotate') {
+      // 移动超出轻点范围时不再算作点按
+      if (this.tapStart) {
+        const moved = Math.hypot(e.touches[0].clientX - this.tapStart.x, e.touches[0].clientY - this.tapStart.y);
+        if (moved > this.doubleTapDistance / 3) this.tapStart = null;
+      }
+
       // 单指旋转
       const deltaX = e.touches[0].clientX - this.lastX;
       const deltaY = e.touches[0].clientY - this.lastY;
       this.lastX = e.touches[0].clientX;
       this.lastY = e.touches[0].clientY;
 
-      this.theta -= deltaX * this.rotateSpeed;
-      this.phi -= deltaY * this.rotateSpeed;
-      this.phi = Math.max(this.minPhi, Math.min(this.maxPhi, this.phi));
+      this.rotate(-deltaX * this.rotateSpeed, -deltaY * this.rotateSpeed);
 
       this.update();
     } else if (e.touches.length === 2) {
@@ -211,29 +321,14 @@ export class OrbitControls {
       const currentCenter = this.getTouchCenter(e.touches);
 
       // 缩放：基于双指距离变化
-      if (this.lastTouchDistance > 0) {
-        const scale = this.lastTouchDistance / currentDistance;
-        this.distance *= Math.pow(scale, this.touchZoomSpeed * 100);
-        this.distance = Math.max(
-          this.minDistance,
-          Math.min(this.maxDistance, this.distance)
-        );
-      }
-
-      // 平移：基于双指中心点移动
+      // 平移：基于双指中心点移动（考虑相机朝向）
       const deltaX = currentCenter.x - this.lastTouchCenter.x;
       const deltaY = currentCenter.y - this.lastTouchCenter.y;
+      this.pan(-deltaX * this.touchPanSpeed * this.distance, deltaY * this.touchPanSpeed * this.distance);
 
-      const panX = -deltaX * this.touchPanSpeed * this.distance;
-      const panY = deltaY * this.touchPanSpeed * this.distance;
-
-      // 计算平移向量（考虑相机朝向）
-      const sinTheta = Math.sin(this.theta);
-      const cosTheta = Math.cos(this.theta);
-
-      this.camera.target[0] += panX * cosTheta;
-      this.camera.target[2] += panX * sinTheta;
-      this.camera.target[1] += panY;
+      if (this.lastTouchDistance > 0 && currentDistance > 0) {
+        this.zoom(Math.log(this.lastTouchDistance / currentDistance) * this.touchZoomSpeed * 100);
+      }
 
       // 更新上一次的触摸状态
       this.lastTouchDistance = currentDistance;
@@ -244,6 +339,11 @@ export class OrbitControls {
   }
 
   private onTouchEnd(e: TouchEvent): void {
+    if (e.touches.length === 0 && this.tapStart) {
+      this.detectDoubleTap(this.tapStart);
+    }
+    this.tapStart = null;
+
     if (e.touches.length === 0) {
       // 所有手指离开
       this.isDragging = false;
@@ -257,6 +357,29 @@ export class OrbitControls {
     }
   }
 
+  /**
+   * 单指轻点结束时检查是否与上一次轻点构成双击
+   */
+  private detectDoubleTap(tap: { x: number; y: number; time: number }): void {
+    const now = performance.now();
+    // 按住过久不算轻点
+    if (now - tap.time > this.doubleTapInterval) {
+      this.lastTap = null;
+      return;
+    }
+    const last = this.lastTap;
+    if (
+      last
+      && tap.time - last.time <= this.doubleTapInterval
+      && Math.hypot(tap.x - last.x, tap.y - last.y) <= this.doubleTapDistance
+    ) {
+      this.lastTap = null;
+      if (this.enabled) this.onDoubleTap?.(tap.x, tap.y);
+      return;
+    }
+    this.lastTap = { x: tap.x, y: tap.y, time: now };
+  }
+
   /**
    * 计算双指之间的距离
    */
@@ -276,10 +399,105 @@ export class OrbitControls {
     };
   }
 
+  /**
+   * 每帧调用：松开拖动后按惯性继续旋转 / 平移 / 缩放
+   * @param deltaTime 距上一帧的秒数
+   */
+  tick(deltaTime: number): void {
+    const v = this.velocity;
+    if (!this.enabled || !this.attached || this.inertia <= 0) {
+      this.stopInertia();
+      return;
+    }
+    if (this.isDragging) {
+      // 按住不动时松开不应继续运动
+      if (!this.movedSinceTick) this.stopInertia();
+      this.movedSinceTick = false;
+      return;
+    }
+    const speed = Math.abs(v.theta) + Math.abs(v.phi) + Math.abs(v.zoom)
+      + (Math.abs(v.pan[0]) + Math.abs(v.pan[1]) + Math.abs(v.pan[2])) / Math.max(this.distance, 1e-6);
+    if (speed < INERTIA_EPSILON) {
+      this.stopInertia();
+      return;
+    }
+    // 速度按帧记录，换算到实际帧间隔
+    const frames = deltaTime * 60;
+    const decay = Math.pow(Math.min(this.inertia, 0.999), frames);
+    this.theta += v.theta * frames;
+    this.phi += v.phi * frames;
+    for (let k = 0; k < 3; k++) {
+      this.camera.target[k] += v.pan[k] * frames;
+      v.pan[k] *= decay;
+    }
+    this.distance *= Math.exp(v.zoom * frames);
+    v.theta *= decay;
+    v.phi *= decay;
+    v.zoom *= decay;
+    this.update();
+  }
+
+  /**
+   * 立即停止惯性运动
+   */
+  stopInertia(): void {
+    this.velocity.theta = 0;
+    this.velocity.phi = 0;
+    this.velocity.pan.fill(0);
+    this.velocity.zoom = 0;
+  }
+
+  /**
+   * 设置约束和惯性
+   */
+  setConfig(config: Partial<OrbitControlsConfig>): void {
+    const merged = { ...this.getConfig(), ...config };
+    this.inertia = Math.min(Math.max(merged.inertia, 0), 1);
+    this.minDistance = merged.minDistance;
+    this.maxDistance = merged.maxDistance;
+    this.minPhi = merged.minPhi;
+    this.maxPhi = merged.maxPhi;
+    this.minTheta = merged.minTheta;
+    this.maxTheta = merged.maxTheta;
+    this.targetBounds = merged.targetBounds;
+    this.update();
+  }
+
+  getConfig(): OrbitControlsConfig {
+    return {
+      inertia: this.inertia,
+      minDistance: this.minDistance,
+      maxDistance: this.maxDistance,
+      minPhi: this.minPhi,
+      maxPhi: this.maxPhi,
+      minTheta: this.minTheta,
+      maxTheta: this.maxTheta,
+      targetBounds: this.targetBounds,
+    };
+  }
+
+  /**
+   * 把球坐标和目标点限制在约束范围内
+   */
+  private applyConstraints(): void {
+    this.distance = Math.max(this.minDistance, Math.min(this.maxDistance, this.distance));
+    this.phi = Math.max(this.minPhi, Math.min(this.maxPhi, this.phi));
+    if (Number.isFinite(this.minTheta) || Number.isFinite(this.maxTheta)) {
+      this.theta = Math.max(this.minTheta, Math.min(this.maxTheta, this.theta));
+    }
+    const bounds = this.targetBounds;
+    if (bounds) {
+      for (let k = 0; k < 3; k++) {
+        this.camera.target[k] = Math.max(bounds.min[k], Math.min(bounds.max[k], this.camera.target[k]));
+      }
+    }
+  }
+
   /**
    * 根据球坐标更新相机位置
    */
   update(): void {
+    this.applyConstraints();
     const sinPhi = Math.sin(this.phi);
     const cosPhi = Math.cos(this.phi);
     const sinTheta = Math.sin(this.theta);
@@ -302,6 +520,7 @@ export class OrbitControls {
    * @param animate 是否动画过渡
    */
   setViewAxis(axis: string, positive: boolean, animate: boolean = true): void {
+    this.stopInertia();
     let targetTheta = this.theta;
     let targetPhi = this.phi;
 
@@ -371,12 +590,48 @@ export class OrbitControls {
    * @param z Z 坐标
    */
   setTarget(x: number, y: number, z: number): void {
+    this.stopInertia();
     this.camera.target[0] = x;
     this.camera.target[1] = y;
     this.camera.target[2] = z;
     this.update();
   }
 
+  /**
+   * 把目标点（旋转中心）移到 point，保持当前角度
+   * @param distance 相机到新目标点的距离，默认保持当前距离
+   */
+  focusOn(point: [number, number, number], animate: boolean = true, distance: number = this.distance): void {
+    const clamped = Math.max(this.minDistance, Math.min(this.maxDistance, distance));
+    if (animate) {
+      this.stopInertia();
+      this.animateToFrame(point, clamped);
+    } else {
+      this.distance = clamped;
+      this.setTarget(point[0], point[1], point[2]);
+    }
+  }
+
+  /**
+   * 设置相机位置和目标点（同步球坐标，垂直角按 minPhi / maxPhi 限制）
+   */
+  setPose(position: [number, number, number], target: [number, number, number]): void {
+    const dx = position[0] - target[0];
+    const dy = position[1] - target[1];
+    const dz = position[2] - target[2];
+    const distance = Math.hypot(dx, dy, dz);
+    this.stopInertia();
+    this.camera.target[0] = target[0];
+    this.camera.target[1] = target[1];
+    this.camera.target[2] = target[2];
+    if (distance > 0) {
+      this.distance = Math.min(Math.max(distance, this.minDistance), this.maxDistance);
+      this.phi = Math.min(Math.max(Math.acos(dy / distance), this.minPhi), this.maxPhi);
+      this.theta = Math.atan2(dx, dz);
+    }
+    this.update();
+  }
+
   /**
    * 获取当前目标点
    */
@@ -408,6 +663,7 @@ export class OrbitControls {
 
     // 确保距离不会太小
     const clampedDistance = Math.max(this.minDistance, targetDistance);
+    this.stopInertia();
 
     if (animate) {
       this.animateToFrame(center, clampedDistance);
@@ -464,4 +720,28 @@ export class OrbitControls {
 
     requestAnimationFrame(animate);
   }
+
+  /**
+   * 按包围盒自动取景（splat 场景通常有远处的漂浮点，传入裁剪后的包围盒效果更好）
+   * @param constrain 同时把目标点限制在包围盒内、最大距离限制为包围球半径的 maxDistanceFactor 倍
+   */
+  frameBounds(
+    min: [number, number, number],
+    max: [number, number, number],
+    animate: boolean = true,
+    constrain: boolean = false,
+    maxDistanceFactor: number = 10,
+  ): void {
+    const center: [number, number, number] = [
+      (min[0] + max[0]) / 2,
+      (min[1] + max[1]) / 2,
+      (min[2] + max[2]) / 2,
+    ];
+    const radius = Math.max(Math.hypot(max[0] - min[0], max[1] - min[1], max[2] - min[2]) / 2, 1e-6);
+    if (constrain) {
+      this.targetBounds = { min: [...min], max: [...max] };
+      this.maxDistance = Math.max(radius * maxDistanceFactor, this.minDistance);
+    }
+    this.frameModel(center, radius, animate);
+  }
 }
@@ -0,0 +1,135 @@
+/**
+ * PipelineCache - 按设备复用 shader module / bind group layout / 管线
+ *
+ * 只负责同一会话内的复用：重新加载模型时新建的排序器 / 渲染器直接拿到已编译的管线，
+ * 不再重复创建模块和编译管线。WebGPU 没有可持久化的管线缓存 API，冷启动的首帧编译不受影响
+ * （是否有磁盘缓存取决于浏览器）
+ */
+export class PipelineCache {
+  private static caches: WeakMap<GPUDevice, PipelineCache> = new WeakMap();
+
+  private device: GPUDevice;
+  // GPU 对象 -> 序号，用于把描述中引用的模块 / 布局序列化为缓存键
+  private objectIds: WeakMap<object, number> = new WeakMap();
+  private nextObjectId: number = 1;
+
+  private modules: Map<string, GPUShaderModule> = new Map();
+  private bindGroupLayouts: Map<string, GPUBindGroupLayout> = new Map();
+  private pipelineLayouts: Map<string, GPUPipelineLayout> = new Map();
+  private computePipelines: Map<string, GPUComputePipeline> = new Map();
+  private renderPipelines: Map<string, GPURenderPipeline> = new Map();
+  private hits: number = 0;
+  private misses: number = 0;
+
+  /**
+   * 获取设备的共享缓存
+   */
+  static get(device: GPUDevice): PipelineCache {
+    let cache = PipelineCache.caches.get(device);
+    if (!cache) {
+      cache = new PipelineCache(device);
+      PipelineCache.caches.set(device, cache);
+    }
+    return cache;
+  }
+
+  constructor(device: GPUDevice) {
+    this.device = device;
+  }
+
+  /**
+   * 获取 shader module（按源码缓存）
+   */
+  getShaderModule(descriptor: GPUShaderModuleDescriptor): GPUShaderModule {
+    return this.lookup(this.modules, descriptor.code, () => this.device.createShaderModule(descriptor));
+  }
+
+  /**
+   * 获取 bind group layout（相同条目复用同一对象，bind group 可以跨实例共享布局）
+   */
+  getBindGroupLayout(descriptor: GPUBindGroupLayoutDescriptor): GPUBindGroupLayout {
+    const key = this.key(descriptor.entries);
+    return this.lookup(this.bindGroupLayouts, key, () => this.device.createBindGroupLayout(descriptor));
+  }
+
+  /**
+   * 获取 pipeline layout
+   */
+  getPipelineLayout(bindGroupLayouts: GPUBindGroupLayout[]): GPUPipelineLayout {
+    const key = this.key(bindGroupLayouts);
+    return this.lookup(this.pipelineLayouts, key, () => this.device.createPipelineLayout({ bindGroupLayouts }));
+  }
+
+  /**
+   * 获取 compute 管线
+   */
+  getComputePipeline(descriptor: GPUComputePipelineDescriptor): GPUComputePipeline {
+    const key = this.key(descriptor);
+    return this.lookup(this.computePipelines, key, () => this.device.createComputePipeline(descriptor));
+  }
+
+  /**
+   * 获取 render 管线
+   */
+  getRenderPipeline(descriptor: GPURenderPipelineDescriptor): GPURenderPipeline {
+    const key = this.key(descriptor);
+    return this.lookup(this.renderPipelines, key, () => this.device.createRenderPipeline(descriptor));
+  }
+
+  /**
+   * 获取缓存统计
+   */
+  getStats(): { modules: number; pipelines: number; hits: number; misses: number } {
+    return {
+      modules: this.modules.size,
+      pipelines: this.computePipelines.size + this.renderPipelines.size,
+      hits: this.hits,
+      misses: this.misses,
+    };
+  }
+
+  /**
+   * 清空缓存（已创建的对象仍可被持有者继续使用）
+   */
+  clear(): void {
+    this.modules.clear();
+    this.bindGroupLayouts.clear();
+    this.pipelineLayouts.clear();
+    this.computePipelines.clear();
+    this.renderPipelines.clear();
+  }
+
+  private lookup<T extends object>(map: Map<string, T>, key: string, create: () => T): T {
+    let value = map.get(key);
+    if (value) {
+      this.hits++;
+      return value;
+    }
+    this.misses++;
+    value = create();
+    map.set(key, value);
+    return value;
+  }
+
+  /**
+   * 序列化描述为缓存键：普通对象 / 数组按内容，GPU 对象按序号，label 不参与
+   */
+  private key(value: unknown): string {
+    return JSON.stringify(value, (name, v) => {
+      if (name === "label") return undefined;
+      if (v !== null && typeof v === "object" && !Array.isArray(v) && Object.getPrototypeOf(v) !== Object.prototype) {
+        return `#${this.objectId(v)}`;
+      }
+      return v;
+    });
+  }
+
+  private objectId(object: object): number {
+    let id = this.objectIds.get(object);
+    if (id === undefined) {
+      id = this.nextObjectId++;
+      this.objectIds.set(object, id);
+    }
+    return id;
+  }
+}
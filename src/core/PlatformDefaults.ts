@@ -0,0 +1,151 @@
+/**
+ * PlatformDefaults - 按平台集中管理的 splat 默认配置
+ *
+ * 是否使用纹理打包、打包编码、是否加载 SH、画布像素比上限、分摊排序、上传预算等默认值
+ * 原本分散在加载和初始化代码里按 isMobileDevice 分支。这里按平台提供预设，
+ * App / Renderer 创建对象时统一从这里读取，针对某个部署目标调优只需改一处
+ */
+
+import { SHMode } from "../types";
+import type { PackModeConfig } from "../gs/TextureCompressor";
+import { isMobileDevice } from "../utils/device";
+
+/**
+ * 平台
+ * - desktop: 独显 / 桌面浏览器
+ * - mobile: 手机、平板
+ * - low-power: 集显、低端设备或嵌入式 WebView（需手动选择）
+ * - potato: 最低配置性能模式，集显目标 30 fps：半分辨率、SH L1、限制 splat 尺寸、
+ *   提高贡献剔除阈值、分摊排序（需手动选择）
+ */
+export type SplatPlatform = "desktop" | "mobile" | "low-power" | "potato";
+
+/**
+ * 平台默认配置
+ */
+export interface SplatPlatformDefaults {
+  platform: SplatPlatform;
+  /** 使用纹理打包的渲染器（约 40~52 bytes/splat，packMode.sh 为 none 时仅 L0） */
+  pack: boolean;
+  /** 纹理打包编码 */
+  packMode: PackModeConfig;
+  /** 加载 PLY 时是否读取 SH 系数 */
+  loadSH: boolean;
+  /** 新建渲染器的 SH 模式 */
+  shMode: SHMode;
+  /** 画布像素比上限 */
+  maxDevicePixelRatio: number;
+  /** 画布分辨率倍数（在像素比上限之后再乘，浏览器负责放大到显示尺寸） */
+  renderScale: number;
+  /** 投影尺寸小于该像素数的 splat 在排序前剔除 */
+  pixelCullThreshold: number;
+  /** splat 屏幕轴长上限（像素），0 不限制 */
+  maxSplatSize: number;
+  /** 移动时的分摊排序间隔，0 关闭 */
+  sortAmortization: number;
+  /** 每帧 splat 编辑的上传预算（字节），0 不限制 */
+  uploadBudget: number;
+}
+
+/** 各平台预设 */
+export const SPLAT_PLATFORM_PRESETS: Record<SplatPlatform, SplatPlatformDefaults> = {
+  desktop: {
+    platform: "desktop",
+    pack: false,
+    packMode: { rotation: "f32", position: "f32", sh: "none" },
+    loadSH: true,
+    shMode: SHMode.L0,
+    maxDevicePixelRatio: 3,
+    renderScale: 1,
+    pixelCullThreshold: 1,
+    maxSplatSize: 0,
+    sortAmortization: 0,
+    uploadBudget: 0,
+  },
+  mobile: {
+    platform: "mobile",
+    pack: true,
+    packMode: { rotation: "f32", position: "f32", sh: "none" },
+    loadSH: false,
+    shMode: SHMode.L0,
+    maxDevicePixelRatio: 1.5,
+    renderScale: 1,
+    pixelCullThreshold: 1,
+    maxSplatSize: 0,
+    sortAmortization: 0,
+    uploadBudget: 0,
+  },
+  "low-power": {
+    platform: "low-power",
+    pack: true,
+    packMode: { rotation: "snorm10", position: "unorm16", sh: "none" },
+    loadSH: false,
+    shMode: SHMode.L0,
+    maxDevicePixelRatio: 1,
+    renderScale: 1,
+    pixelCullThreshold: 1,
+    maxSplatSize: 0,
+    sortAmortization: 4,
+    uploadBudget: 8 * 1024 * 1024,
+  },
+  potato: {
+    platform: "potato",
+    pack: false,
+    packMode: { rotation: "f32", position: "f32", sh: "none" },
+    loadSH: true,
+    shMode: SHMode.L1,
+    maxDevicePixelRatio: 1,
+    renderScale: 0.5,
+    pixelCullThreshold: 3,
+    maxSplatSize: 256,
+    sortAmortization: 4,
+    uploadBudget: 8 * 1024 * 1024,
+  },
+};
+
+let current: SplatPlatformDefaults | null = null;
+
+/**
+ * 按设备检测平台（low-power 不会自动选中）
+ */
+export function detectSplatPlatform(): SplatPlatform {
+  return isMobileDevice() ? "mobile" : "desktop";
+}
+
+/**
+ * 获取当前平台默认配置（首次调用时按设备检测）
+ */
+export function getSplatPlatformDefaults(): SplatPlatformDefaults {
+  current ??= clonePreset(SPLAT_PLATFORM_PRESETS[detectSplatPlatform()]);
+  return clonePreset(current);
+}
+
+/**
+ * 设置平台默认配置：传入平台名使用对应预设，传入部分字段则覆盖当前配置
+ * （overrides.platform 存在时先切换到该预设再覆盖）。对之后创建的对象生效
+ */
+export function setSplatPlatformDefaults(platformOrOverrides: SplatPlatform | Partial<SplatPlatformDefaults>): void {
+  if (typeof platformOrOverrides === "string") {
+    current = clonePreset(SPLAT_PLATFORM_PRESETS[platformOrOverrides]);
+    return;
+  }
+  const base = platformOrOverrides.platform
+    ? clonePreset(SPLAT_PLATFORM_PRESETS[platformOrOverrides.platform])
+    : getSplatPlatformDefaults();
+  current = {
+    ...base,
+    ...platformOrOverrides,
+    packMode: { ...base.packMode, ...platformOrOverrides.packMode },
+  };
+}
+
+/**
+ * 恢复为按设备检测的预设
+ */
+export function resetSplatPlatformDefaults(): void {
+  current = null;
+}
+
+function clonePreset(preset: SplatPlatformDefaults): SplatPlatformDefaults {
+  return { ...preset, packMode: { ...preset.packMode } };
+}
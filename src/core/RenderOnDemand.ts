@@ -0,0 +1,135 @@
+/**
+ * RenderOnDemand - 按需渲染
+ *
+ * 查看器大部分时间画面是静止的，持续每帧排序和绘制只会消耗电量。开启后渲染循环仍然每帧运行
+ * （更新回调、控制器惯性、相机路径照常推进），但只有以下情况才提交 GPU 工作：
+ * - 状态签名变化：相机矩阵、渲染尺寸、splat 数据（渲染器、数量、训练数据代数）等
+ * - 页面上的输入事件（指针、按键、滚轮、表单修改），覆盖 UI 触发的设置变化
+ * - 分帧上传还有推迟的数据
+ * - 显式调用 requestRender（通过代码修改设置之后）
+ * 每次变化之后继续渲染 settleTime 毫秒，让时间抗锯齿累积、异步拾取（悬停高亮）等后续效果完成
+ */
+
+/**
+ * 按需渲染配置
+ */
+export interface RenderOnDemandConfig {
+  /** 最后一次变化之后继续渲染的时间（毫秒） */
+  settleTime: number;
+  /** 空闲时至少每隔多少毫秒渲染一帧（0 表示空闲时完全不渲染），兜底没有通知的变化 */
+  maxIdleInterval: number;
+}
+
+export const DEFAULT_RENDER_ON_DEMAND_CONFIG: RenderOnDemandConfig = {
+  settleTime: 500,
+  maxIdleInterval: 0,
+};
+
+// 唤醒渲染的页面事件（在 window 上捕获）
+const WAKE_EVENTS = ["pointerdown", "pointerup", "keydown", "keyup", "wheel", "input", "change"];
+
+/**
+ * RenderOnDemand - 判断每帧是否需要渲染
+ */
+export class RenderOnDemand {
+  private config: RenderOnDemandConfig = { ...DEFAULT_RENDER_ON_DEMAND_CONFIG };
+  private signature: Float64Array = new Float64Array(0);
+  // 在该时间之前持续渲染
+  private activeUntil: number = 0;
+  private lastRenderTime: number = -Infinity;
+  private canvas: HTMLCanvasElement;
+  private boundWake: () => void;
+  private enabled: boolean = false;
+
+  constructor(canvas: HTMLCanvasElement) {
+    this.canvas = canvas;
+    this.boundWake = () => this.requestRender();
+  }
+
+  setEnabled(enabled: boolean): void {
+    if (enabled === this.enabled) return;
+    this.enabled = enabled;
+    if (enabled) {
+      for (const type of WAKE_EVENTS) {
+        window.addEventListener(type, this.boundWake, { capture: true, passive: true });
+      }
+      // 画布内的指针移动（gizmo 悬停、拖动）
+      this.canvas.addEventListener("pointermove", this.boundWake, { passive: true });
+      this.requestRender();
+    } else {
+      for (const type of WAKE_EVENTS) {
+        window.removeEventListener(type, this.boundWake, { capture: true });
+      }
+      this.canvas.removeEventListener("pointermove", this.boundWake);
+    }
+  }
+
+  isEnabled(): boolean {
+    return this.enabled;
+  }
+
+  setConfig(config: Partial<RenderOnDemandConfig>): void {
+    this.config = { ...this.config, ...config };
+  }
+
+  getConfig(): RenderOnDemandConfig {
+    return { ...this.config };
+  }
+
+  /**
+   * 请求渲染（之后持续 settleTime 毫秒）
+   */
+  requestRender(): void {
+    this.activeUntil = Math.max(this.activeUntil, performance.now() + this.config.settleTime);
+  }
+
+  /**
+   * 每帧调用：比较状态签名，返回本帧是否需要渲染（关闭时总是返回 true）
+   * @param signature 影响画面的状态（数值数组，长度可以变化）
+   */
+  shouldRender(signature: ArrayLike<number>, now: number = performance.now()): boolean {
+    if (!this.enabled) return true;
+    if (!this.matchSignature(signature)) {
+      this.requestRender();
+    }
+    if (now < this.activeUntil) return true;
+    const idle = this.config.maxIdleInterval;
+    return idle > 0 && now - this.lastRenderTime >= idle;
+  }
+
+  /**
+   * 渲染一帧之后调用
+   */
+  frameRendered(now: number = performance.now()): void {
+    this.lastRenderTime = now;
+  }
+
+  /**
+   * 当前是否处于空闲（跳过渲染）
+   */
+  isIdle(now: number = performance.now()): boolean {
+    return this.enabled && now >= this.activeUntil;
+  }
+
+  // 与上一帧的签名比较并保存
+  private matchSignature(signature: ArrayLike<number>): boolean {
+    let same = this.signature.length === signature.length;
+    if (!same) {
+      this.signature = new Float64Array(signature.length);
+    }
+    for (let i = 0; i < signature.length; i++) {
+      // NaN 视为与自身相同
+      const value = signature[i];
+      const previous = this.signature[i];
+      if (value !== previous && !(Number.isNaN(value) && Number.isNaN(previous))) {
+        same = false;
+        this.signature[i] = value;
+      }
+    }
+    return same;
+  }
+
+  destroy(): void {
+    this.setEnabled(false);
+  }
+}
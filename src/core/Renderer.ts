@@ -1,4 +1,39 @@
 import { isMobileDevice, getRecommendedDPR } from "../utils";
+import {
+  DepthConfig,
+  resolveDepthConfig,
+  getDepthFormat,
+  getDepthClearValue,
+} from "./DepthConvention";
+import { UploadArena } from "./UploadArena";
+import { UploadScheduler } from "./UploadScheduler";
+import { getSplatPlatformDefaults } from "./PlatformDefaults";
+import { decodeFrameCapture, getCaptureBytesPerPixel } from "./FrameCapture";
+import type { FrameCapture } from "./FrameCapture";
+import { GPUResourceTracker } from "./GPUResourceTracker";
+
+/**
+ * 渲染器选项
+ */
+export interface RendererOptions {
+  /** 深度约定 */
+  depth?: Partial<DepthConfig>;
+  /** 是否输出 HDR（rgba16float 画布 + extended 色调映射，超过 1.0 的颜色不被截断） */
+  hdr?: boolean;
+  /** 是否使用 indirect 绘制，省略时按适配器自动选择 */
+  indirectDraw?: boolean;
+  /** 是否统计存活的 GPU buffer / 纹理（泄漏检测，只用于调试） */
+  trackResources?: boolean;
+}
+
+/**
+ * 适配器是否适合使用 indirect 绘制：兼容模式（OpenGL ES / D3D11 后端）的适配器
+ * 不保证 indirect 绘制的稳定性和性能，返回 false
+ */
+export function detectIndirectDrawSupport(adapter: GPUAdapter): boolean {
+  const compat = adapter as GPUAdapter & { featureLevel?: string; isCompatibilityMode?: boolean };
+  return compat.featureLevel !== "compatibility" && compat.isCompatibilityMode !== true;
+}
 
 /**
  * Renderer - WebGPU 初始化 + 帧提交
@@ -11,18 +46,48 @@ export class Renderer {
   private _format!: GPUTextureFormat;
   private _depthTexture!: GPUTexture;
   private _depthTextureView!: GPUTextureView;
+  private _uploads!: UploadArena;
+  private _uploadScheduler: UploadScheduler = new UploadScheduler();
   
   private commandEncoder!: GPUCommandEncoder;
   private renderPassEncoder!: GPURenderPassEncoder;
+  private colorTexture!: GPUTexture;
+
+  // 等待下一帧回读的请求
+  private pendingCaptures: { resolve: (capture: FrameCapture) => void; reject: (error: unknown) => void }[] = [];
+  // 每帧画面的可采样副本（有订阅者时才复制），尺寸或格式变化时重建
+  private frameTexture: GPUTexture | null = null;
+  private frameTextureListeners: Set<(texture: GPUTexture) => void> = new Set();
+  // 离屏渲染目标（设置后 beginFrame 绘制到该纹理而不是画布，宽高和宽高比也取该纹理）
+  private renderTarget: { color: GPUTexture; depth: GPUTexture; depthView: GPUTextureView } | null = null;
 
   // ResizeObserver 引用（用于清理）
   private resizeObserver: ResizeObserver | null = null;
+  // 动态分辨率比例（在平台默认的分辨率倍数之上）
+  private resolutionScale: number = 1;
 
   // 背景颜色
   private _clearColor: GPUColorDict = { r: 0.15, g: 0.15, b: 0.15, a: 1.0 };
 
-  constructor(canvas: HTMLCanvasElement) {
+  // 深度约定（创建后不可修改，管线依赖它）
+  readonly depthConfig: DepthConfig;
+
+  // 是否 HDR 输出
+  readonly hdr: boolean;
+
+  // indirect 绘制：手动设置（null 表示自动）和适配器检测结果
+  private indirectDrawOverride: boolean | null;
+  private adapterIndirectDraw: boolean = true;
+
+  // 是否统计 GPU 资源（设备创建后立即安装计数器）
+  private trackResources: boolean;
+
+  constructor(canvas: HTMLCanvasElement, options: RendererOptions = {}) {
     this.canvas = canvas;
+    this.depthConfig = resolveDepthConfig(options.depth);
+    this.hdr = options.hdr ?? false;
+    this.indirectDrawOverride = options.indirectDraw ?? null;
+    this.trackResources = options.trackResources ?? false;
   }
 
   /**
@@ -32,6 +97,13 @@ export class Renderer {
     this._clearColor = { r, g, b, a };
   }
 
+  /**
+   * 获取背景颜色
+   */
+  getClearColor(): GPUColorDict {
+    return { ...this._clearColor };
+  }
+
   /**
    * 通过十六进制设置背景颜色
    */
@@ -61,6 +133,27 @@ export class Renderer {
     return this._device;
   }
 
+  /**
+   * 每帧小块写入的共享上传区（endFrame 提交前自动 flush）
+   */
+  get uploads(): UploadArena {
+    return this._uploads;
+  }
+
+  /**
+   * 大块 buffer 更新的分帧上传预算（beginFrame 时重置额度）
+   */
+  get uploadScheduler(): UploadScheduler {
+    return this._uploadScheduler;
+  }
+
+  /**
+   * GPU 资源计数器，未启用 trackResources 时为 null
+   */
+  get resourceTracker(): GPUResourceTracker | null {
+    return this._device ? GPUResourceTracker.get(this._device) : null;
+  }
+
   get context(): GPUCanvasContext {
     return this._context;
   }
@@ -70,21 +163,86 @@ export class Renderer {
   }
 
   get depthFormat(): GPUTextureFormat {
-    return 'depth24plus';
+    return getDepthFormat(this.depthConfig);
+  }
+
+  /**
+   * 是否使用 indirect 绘制。关闭时 splat 渲染器异步回读可见数量（晚一帧），改用直接绘制
+   */
+  get indirectDraw(): boolean {
+    return this.indirectDrawOverride ?? this.adapterIndirectDraw;
+  }
+
+  /**
+   * 设置是否使用 indirect 绘制，null 表示按适配器自动选择（立即生效）
+   */
+  setIndirectDraw(enabled: boolean | null): void {
+    this.indirectDrawOverride = enabled;
+  }
+
+  /**
+   * 获取深度纹理（尺寸变化时会重建，不要长期持有）
+   */
+  get depthTexture(): GPUTexture {
+    return this.renderTarget?.depth ?? this._depthTexture;
   }
 
   /**
    * 获取渲染宽度（像素）
    */
   get width(): number {
-    return this.canvas.width;
+    return this.renderTarget?.color.width ?? this.canvas.width;
   }
 
   /**
    * 获取渲染高度（像素）
    */
   get height(): number {
-    return this.canvas.height;
+    return this.renderTarget?.color.height ?? this.canvas.height;
+  }
+
+  /**
+   * 设置离屏渲染目标尺寸（null 恢复绘制到画布）
+   * 设置后 beginFrame 绘制到同格式的离屏纹理（可回读、可采样），width / height / getAspectRatio 返回该尺寸，
+   * 画布尺寸和画布上已显示的内容不受影响。尺寸不变时复用已有纹理
+   */
+  setRenderTarget(size: { width: number; height: number } | null): void {
+    if (!size) {
+      this.destroyRenderTarget();
+      return;
+    }
+    const width = Math.max(1, Math.floor(size.width));
+    const height = Math.max(1, Math.floor(size.height));
+    if (this.renderTarget && this.renderTarget.color.width === width && this.renderTarget.color.height === height) {
+      return;
+    }
+    this.destroyRenderTarget();
+    const color = this._device.createTexture({
+      size: { width, height },
+      format: this._format,
+      usage: GPUTextureUsage.RENDER_ATTACHMENT | GPUTextureUsage.COPY_SRC | GPUTextureUsage.TEXTURE_BINDING,
+      label: 'offscreen-color',
+    });
+    const depth = this._device.createTexture({
+      size: { width, height },
+      format: this.depthFormat,
+      usage: GPUTextureUsage.RENDER_ATTACHMENT | GPUTextureUsage.TEXTURE_BINDING,
+      label: 'offscreen-depth',
+    });
+    this.renderTarget = { color, depth, depthView: depth.createView() };
+  }
+
+  /**
+   * 当前离屏渲染目标的颜色纹理（未设置时为 null）
+   */
+  getRenderTarget(): GPUTexture | null {
+    return this.renderTarget?.color ?? null;
+  }
+
+  private destroyRenderTarget(): void {
+    this.renderTarget?.color.destroy();
+    this.renderTarget?.depth.destroy();
+    this.renderTarget = null;
   }
 
   /**
@@ -105,16 +263,29 @@ export class Renderer {
     }
 
     // 获取设备，请求更高的缓冲区大小限制以支持大型模型
+    // subgroups 可用时启用，radix sort 使用 subgroup 加速的 downsweep
+    this.adapterIndirectDraw = detectIndirectDrawSupport(adapter);
     const adapterLimits = adapter.limits;
+    const requiredFeatures: GPUFeatureName[] = [];
+    if (adapter.features.has('subgroups')) {
+      requiredFeatures.push('subgroups' as GPUFeatureName);
+    }
     this._device = await adapter.requestDevice({
+      requiredFeatures,
       requiredLimits: {
         maxBufferSize: adapterLimits.maxBufferSize,
         maxStorageBufferBindingSize: adapterLimits.maxStorageBufferBindingSize,
+        // splat bind group 加上各 compute pass 自己的 buffer 会超过默认的 8 个
+        maxStorageBuffersPerShaderStage: adapterLimits.maxStorageBuffersPerShaderStage,
       },
     });
     this._device.lost.then((info) => {
       // GPU 设备丢失（静默处理）
     });
+    if (this.trackResources) {
+      GPUResourceTracker.install(this._device);
+    }
+    this._uploads = new UploadArena(this._device);
 
     // 配置 canvas 上下文
     this._context = this.canvas.getContext('webgpu') as GPUCanvasContext;
@@ -122,13 +293,17 @@ export class Renderer {
       throw new Error('无法获取 WebGPU 上下文');
     }
 
-    this._format = navigator.gpu.getPreferredCanvasFormat();
+    // HDR: 浮点画布 + extended 色调映射，由浏览器/显示器处理超过 1.0 的亮度
+    this._format = this.hdr ? 'rgba16float' : navigator.gpu.getPreferredCanvasFormat();
     this._context.configure({
       device: this._device,
       format: this._format,
+      // COPY_SRC: 导出时回读画布
+      usage: GPUTextureUsage.RENDER_ATTACHMENT | GPUTextureUsage.COPY_SRC,
       alphaMode: 'premultiplied',
       colorSpace: 'srgb',
-    });
+      ...(this.hdr ? { toneMapping: { mode: 'extended' } } : {}),
+    } as GPUCanvasConfiguration);
 
     // 创建深度纹理
     this.createDepthTexture();
@@ -151,7 +326,8 @@ export class Renderer {
         height: this.canvas.height,
       },
       format: this.depthFormat,
-      usage: GPUTextureUsage.RENDER_ATTACHMENT,
+      // TEXTURE_BINDING: splat 遮挡剔除从上一帧深度构建 Hi-Z
+      usage: GPUTextureUsage.RENDER_ATTACHMENT | GPUTextureUsage.TEXTURE_BINDING,
     });
     this._depthTextureView = this._depthTexture.createView();
   }
@@ -163,19 +339,48 @@ export class Renderer {
     this.resizeObserver = new ResizeObserver((entries) => {
       for (const entry of entries) {
         const { width, height } = entry.contentRect;
-        
-        // 使用工具函数获取推荐的 DPR
-        const dpr = getRecommendedDPR();
-        
-        this.canvas.width = Math.floor(width * dpr);
-        this.canvas.height = Math.floor(height * dpr);
-        
-        this.createDepthTexture();
+        this.resizeCanvas(width, height);
       }
     });
     this.resizeObserver.observe(this.canvas);
   }
 
+  /**
+   * 按当前平台默认配置（像素比上限、分辨率倍数）重新设置画布尺寸
+   */
+  updateCanvasSize(): void {
+    this.resizeCanvas(this.canvas.clientWidth, this.canvas.clientHeight);
+  }
+
+  /**
+   * 设置动态分辨率比例（0~1，相对正常的画布分辨率），画布按 CSS 尺寸拉伸显示
+   * 比例变化时重建画布和深度纹理，不要逐帧连续改变
+   */
+  setResolutionScale(scale: number): void {
+    const clamped = Math.min(Math.max(scale, 0.1), 1);
+    if (clamped === this.resolutionScale) return;
+    this.resolutionScale = clamped;
+    this.updateCanvasSize();
+  }
+
+  getResolutionScale(): number {
+    return this.resolutionScale;
+  }
+
+  private resizeCanvas(width: number, height: number): void {
+    // 像素比上限和分辨率倍数取平台默认配置
+    const defaults = getSplatPlatformDefaults();
+    const dpr = getRecommendedDPR(defaults.maxDevicePixelRatio) * defaults.renderScale * this.resolutionScale;
+    const canvasWidth = Math.max(1, Math.floor(width * dpr));
+    const canvasHeight = Math.max(1, Math.floor(height * dpr));
+    if (canvasWidth === this.canvas.width && canvasHeight === this.canvas.height && this._depthTexture) {
+      return;
+    }
+    this.canvas.width = canvasWidth;
+    this.canvas.height = canvasHeight;
+    this.createDepthTexture();
+  }
+
   /**
    * 销毁渲染器资源
    */
@@ -190,14 +395,19 @@ export class Renderer {
     if (this._depthTexture) {
       this._depthTexture.destroy();
     }
+    this.frameTexture?.destroy();
+    this.frameTexture = null;
+    this.frameTextureListeners.clear();
+    this.destroyRenderTarget();
   }
 
   /**
    * 开始帧 - 创建命令编码器和渲染通道
    */
   beginFrame(): GPURenderPassEncoder {
-    const colorTexture = this._context.getCurrentTexture();
-    const colorView = colorTexture.createView();
+    this._uploadScheduler.beginFrame();
+    this.colorTexture = this.renderTarget?.color ?? this._context.getCurrentTexture();
+    const colorView = this.colorTexture.createView();
 
     this.commandEncoder = this._device.createCommandEncoder();
     
@@ -211,8 +421,8 @@ export class Renderer {
         },
       ],
       depthStencilAttachment: {
-        view: this._depthTextureView,
-        depthClearValue: 1.0,
+        view: this.renderTarget?.depthView ?? this._depthTextureView,
+        depthClearValue: getDepthClearValue(this.depthConfig),
         depthLoadOp: 'clear',
         depthStoreOp: 'store',
       },
@@ -221,18 +431,123 @@ export class Renderer {
     return this.renderPassEncoder;
   }
 
+  /**
+   * 结束当前渲染通道并在同一帧中开始新通道（保留颜色和深度），用于帧中间需要采样深度的绘制
+   * @param depthReadOnly 深度只读，此时深度纹理可以同时作为纹理绑定
+   */
+  continueFrame(depthReadOnly: boolean = false): GPURenderPassEncoder {
+    this.renderPassEncoder.end();
+    const depthView = this.renderTarget?.depthView ?? this._depthTextureView;
+    this.renderPassEncoder = this.commandEncoder.beginRenderPass({
+      colorAttachments: [
+        {
+          view: this.colorTexture.createView(),
+          loadOp: 'load',
+          storeOp: 'store',
+        },
+      ],
+      depthStencilAttachment: depthReadOnly
+        ? { view: depthView, depthReadOnly: true }
+        : { view: depthView, depthLoadOp: 'load', depthStoreOp: 'store' },
+    });
+    return this.renderPassEncoder;
+  }
+
   /**
    * 结束帧 - 提交命令
    */
   endFrame(): void {
     this.renderPassEncoder.end();
+    const captures = this.pendingCaptures;
+    this.pendingCaptures = [];
+    const readback = captures.length > 0 ? this.copyColorTexture() : null;
+    const frameTexture = this.frameTextureListeners.size > 0 ? this.copyFrameTexture() : null;
+    this._uploads.flush();
     this._device.queue.submit([this.commandEncoder.finish()]);
+    if (frameTexture) {
+      this.frameTextureListeners.forEach((listener) => listener(frameTexture));
+    }
+    if (readback) {
+      readback.then(
+        (capture) => captures.forEach((c) => c.resolve(capture)),
+        (error) => captures.forEach((c) => c.reject(error)),
+      );
+    }
+  }
+
+  /**
+   * 回读下一帧提交的画布内容（RGBA f32，画布编码值）
+   */
+  captureNextFrame(): Promise<FrameCapture> {
+    return new Promise((resolve, reject) => this.pendingCaptures.push({ resolve, reject }));
+  }
+
+  /**
+   * 订阅每帧画面：有订阅者时每帧把画布内容复制到一张可采样的纹理（TEXTURE_BINDING，格式与画布相同），
+   * 提交后以该纹理调用回调，用于在 UI 面板 / 其它画布中显示当前视图。纹理随画布尺寸重建，不要长期持有
+   * @returns 取消订阅函数
+   */
+  onFrameTexture(callback: (texture: GPUTexture) => void): () => void {
+    this.frameTextureListeners.add(callback);
+    return () => {
+      this.frameTextureListeners.delete(callback);
+      if (this.frameTextureListeners.size === 0) {
+        this.frameTexture?.destroy();
+        this.frameTexture = null;
+      }
+    };
+  }
+
+  // 把本帧画布纹理复制到可采样的纹理（在提交前编码）
+  private copyFrameTexture(): GPUTexture {
+    const { width, height } = this.colorTexture;
+    let texture = this.frameTexture;
+    if (!texture || texture.width !== width || texture.height !== height || texture.format !== this._format) {
+      texture?.destroy();
+      texture = this._device.createTexture({
+        size: { width, height },
+        format: this._format,
+        usage: GPUTextureUsage.TEXTURE_BINDING | GPUTextureUsage.COPY_DST | GPUTextureUsage.COPY_SRC,
+        label: 'frame-texture',
+      });
+      this.frameTexture = texture;
+    }
+    this.commandEncoder.copyTextureToTexture({ texture: this.colorTexture }, { texture }, { width, height });
+    return texture;
+  }
+
+  // 把本帧画布纹理复制到可映射 buffer（在提交前编码），提交后映射解码
+  private async copyColorTexture(): Promise<FrameCapture> {
+    const width = this.colorTexture.width;
+    const height = this.colorTexture.height;
+    // copyTextureToBuffer 要求 bytesPerRow 256 对齐
+    const bytesPerRow = Math.ceil((width * getCaptureBytesPerPixel(this._format)) / 256) * 256;
+    const buffer = this._device.createBuffer({
+      size: bytesPerRow * height,
+      usage: GPUBufferUsage.COPY_DST | GPUBufferUsage.MAP_READ,
+      label: 'frame-capture',
+    });
+    this.commandEncoder.copyTextureToBuffer(
+      { texture: this.colorTexture },
+      { buffer, bytesPerRow },
+      { width, height },
+    );
+    // 等待 endFrame 提交后再映射
+    await Promise.resolve();
+    try {
+      await buffer.mapAsync(GPUMapMode.READ);
+      const capture = decodeFrameCapture(buffer.getMappedRange(), this._format, width, height, bytesPerRow);
+      buffer.unmap();
+      return capture;
+    } finally {
+      buffer.destroy();
+    }
   }
 
   /**
-   * 获取 canvas 宽高比
+   * 获取渲染宽高比（画布或离屏渲染目标）
    */
   getAspectRatio(): number {
-    return this.canvas.width / this.canvas.height;
+    return this.width / this.height;
   }
 }
@@ -0,0 +1,115 @@
+/**
+ * SplatDiagnostics - 帧统计诊断
+ *
+ * 每帧由 App 写入一组测量值（帧时间、可见 / 总 splat 数、排序跳过、显存、上传量），
+ * 每项保留最近若干帧的历史并提供平均值，宿主应用可直接读取用于统计面板，
+ * 不需要了解渲染器内部。可见数量通过 indirect buffer 异步回读，会延迟若干帧
+ */
+
+/**
+ * 诊断项名称
+ */
+export const SPLAT_DIAGNOSTICS = {
+  /** 帧时间（毫秒） */
+  FRAME_TIME: "frame_time",
+  /** 帧率 */
+  FPS: "fps",
+  /** 可见 splat 数量（异步回读） */
+  VISIBLE_SPLATS: "visible_splats",
+  /** splat 总数 */
+  TOTAL_SPLATS: "total_splats",
+  /** 本帧执行排序的实体数 */
+  SORTS: "sorts",
+  /** 本帧跳过排序（复用上一帧结果）的实体数 */
+  SORT_SKIPS: "sort_skips",
+  /** 排序复用命中率 (0~1) */
+  SORT_CACHE_HIT_RATE: "sort_cache_hit_rate",
+  /** splat 占用的显存（字节） */
+  GPU_MEMORY: "gpu_memory",
+  /** 本帧上传的字节数 */
+  UPLOAD_BYTES: "upload_bytes",
+} as const;
+
+/**
+ * 单个诊断项
+ */
+export interface SplatDiagnostic {
+  name: string;
+  /** 最近一次测量值 */
+  value: number;
+  /** 历史平均值 */
+  average: number;
+  /** 历史测量值（旧到新） */
+  history: number[];
+}
+
+/**
+ * 诊断项集合
+ */
+export class SplatDiagnostics {
+  private historyLength: number;
+  private entries: Map<string, number[]> = new Map();
+
+  /**
+   * @param historyLength 每项保留的历史帧数，默认 120
+   */
+  constructor(historyLength: number = 120) {
+    this.historyLength = Math.max(1, Math.floor(historyLength));
+  }
+
+  /**
+   * 记录一次测量（负值表示本帧没有测量结果，不记录）
+   */
+  add(name: string, value: number): void {
+    if (value < 0 || !Number.isFinite(value)) {
+      return;
+    }
+    let history = this.entries.get(name);
+    if (!history) {
+      history = [];
+      this.entries.set(name, history);
+    }
+    history.push(value);
+    if (history.length > this.historyLength) {
+      history.shift();
+    }
+  }
+
+  /**
+   * 获取诊断项，从未记录过时返回 null
+   */
+  get(name: string): SplatDiagnostic | null {
+    const history = this.entries.get(name);
+    if (!history || history.length === 0) {
+      return null;
+    }
+    return {
+      name,
+      value: history[history.length - 1],
+      average: history.reduce((sum, v) => sum + v, 0) / history.length,
+      history: [...history],
+    };
+  }
+
+  /**
+   * 获取诊断项的最近一次测量值
+   */
+  getValue(name: string): number | null {
+    const history = this.entries.get(name);
+    return history && history.length > 0 ? history[history.length - 1] : null;
+  }
+
+  /**
+   * 获取全部诊断项
+   */
+  getAll(): SplatDiagnostic[] {
+    return [...this.entries.keys()].map((name) => this.get(name)).filter((d): d is SplatDiagnostic => d !== null);
+  }
+
+  /**
+   * 清空历史
+   */
+  clear(): void {
+    this.entries.clear();
+  }
+}
@@ -0,0 +1,337 @@
+/**
+ * SplitViewComparison - 分屏对比参考图像
+ *
+ * 在主渲染通道中按分割线把画面分为两侧：一侧保留实时渲染，另一侧绘制参考图像（例如训练视角的真值照片），
+ * 用于逐像素检查重建质量。参考图像拉伸到整个画布，相机的视角和宽高比需要与图像一致。
+ * 分割线位置是 uniform，可以在画布上拖动（指针靠近分割线时按下）。
+ * 训练器每次迭代更新预览图像时，writeImage / copyImageFromBuffer 复用同一个纹理，
+ * 直接从调用方的数组或同一设备上的 GPU buffer 写入，不经过图像解码和额外的 CPU 复制
+ */
+
+import type { Renderer } from "./Renderer";
+import { PipelineCache } from "./PipelineCache";
+import { getCanvasPixelMapping } from "../utils/canvas";
+
+/**
+ * 分屏对比配置
+ */
+export interface SplitViewComparisonConfig {
+  /** 分割方向：vertical 为左右分屏，horizontal 为上下分屏 */
+  orientation: "vertical" | "horizontal";
+  /** 参考图像显示在分割线之前（左 / 上）一侧，默认在之后（右 / 下） */
+  swap: boolean;
+  /** 分割线宽度（CSS 像素），0 表示不绘制 */
+  lineWidth: number;
+  /** 分割线颜色 RGBA */
+  lineColor: [number, number, number, number];
+  /** 按下位置与分割线的距离小于该值（CSS 像素）时开始拖动 */
+  grabDistance: number;
+}
+
+export const DEFAULT_SPLIT_VIEW_COMPARISON_CONFIG: SplitViewComparisonConfig = {
+  orientation: "vertical",
+  swap: false,
+  lineWidth: 2,
+  lineColor: [1, 1, 1, 0.9],
+  grabDistance: 8,
+};
+
+const comparisonShader = /* wgsl */ `
+struct ComparisonParams {
+  // x: 分割线位置 (0~1), y: 方向 (0 左右, 1 上下), z: 参考图像在分割线之前, w: 分割线半宽（像素）
+  divider: vec4<f32>,
+  lineColor: vec4<f32>,
+  // xy: 画布尺寸（像素）
+  viewport: vec4<f32>,
+}
+
+@group(0) @binding(0) var<uniform> params: ComparisonParams;
+@group(0) @binding(1) var referenceTex: texture_2d<f32>;
+@group(0) @binding(2) var referenceSampler: sampler;
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertexIndex: u32) -> @builtin(position) vec4<f32> {
+  let corner = vec2<f32>(f32(vertexIndex & 1u), f32(vertexIndex >> 1u));
+  return vec4<f32>(corner * 2.0 - 1.0, 0.0, 1.0);
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+  let uv = position.xy / params.viewport.xy;
+  // textureSample 需要在统一控制流中调用，先采样再分支
+  let color = textureSample(referenceTex, referenceSampler, uv);
+  let horizontal = params.divider.y > 0.5;
+  let t = select(uv.x, uv.y, horizontal);
+  let size = select(params.viewport.x, params.viewport.y, horizontal);
+
+  if abs(t - params.divider.x) * size < params.divider.w {
+    return params.lineColor;
+  }
+  let before = t < params.divider.x;
+  if before != (params.divider.z > 0.5) {
+    discard;
+  }
+  return vec4<f32>(color.rgb, 1.0);
+}
+`;
+
+/**
+ * SplitViewComparison - 分屏对比
+ */
+export class SplitViewComparison {
+  private renderer: Renderer;
+  private canvas: HTMLCanvasElement;
+  private config: SplitViewComparisonConfig = { ...DEFAULT_SPLIT_VIEW_COMPARISON_CONFIG };
+  private pipeline: GPURenderPipeline;
+  private bindGroupLayout: GPUBindGroupLayout;
+  private paramsBuffer: GPUBuffer;
+  private sampler: GPUSampler;
+  private bindGroup: GPUBindGroup | null = null;
+
+  private image: GPUTexture | null = null;
+  // 由本对象创建的纹理，替换或销毁时释放
+  private ownsImage: boolean = false;
+  private divider: number = 0.5;
+  private dragging: boolean = false;
+  private onDraggingChange: (dragging: boolean) => void;
+
+  /** 是否绘制参考图像（没有图像时不绘制） */
+  enabled: boolean = true;
+
+  // 事件处理函数引用（用于移除监听器）
+  private boundOnPointerDown: (e: PointerEvent) => void;
+  private boundOnPointerMove: (e: PointerEvent) => void;
+  private boundOnPointerUp: (e: PointerEvent) => void;
+
+  /**
+   * @param onDraggingChange 开始 / 结束拖动分割线时调用（用于暂停相机控制等）
+   */
+  constructor(renderer: Renderer, canvas: HTMLCanvasElement, onDraggingChange: (dragging: boolean) => void = () => {}) {
+    this.renderer = renderer;
+    this.canvas = canvas;
+    this.onDraggingChange = onDraggingChange;
+    const device = renderer.device;
+
+    const pipelines = PipelineCache.get(device);
+    this.bindGroupLayout = pipelines.getBindGroupLayout({
+      label: "split-view-comparison-layout",
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.FRAGMENT, buffer: { type: "uniform" } },
+        { binding: 1, visibility: GPUShaderStage.FRAGMENT, texture: { sampleType: "float" } },
+        { binding: 2, visibility: GPUShaderStage.FRAGMENT, sampler: { type: "filtering" } },
+      ],
+    });
+    const module = pipelines.getShaderModule({ code: comparisonShader, label: "split-view-comparison-shader" });
+    this.pipeline = pipelines.getRenderPipeline({
+      layout: pipelines.getPipelineLayout([this.bindGroupLayout]),
+      vertex: { module, entryPoint: "vs_main", buffers: [] },
+      fragment: {
+        module,
+        entryPoint: "fs_main",
+        targets: [{
+          format: renderer.format,
+          blend: {
+            color: { srcFactor: "src-alpha", dstFactor: "one-minus-src-alpha", operation: "add" },
+            alpha: { srcFactor: "one", dstFactor: "one-minus-src-alpha", operation: "add" },
+          },
+        }],
+      },
+      primitive: { topology: "triangle-strip" },
+      // 覆盖在场景之上，不参与深度测试
+      depthStencil: {
+        format: renderer.depthFormat,
+        depthWriteEnabled: false,
+        depthCompare: "always",
+      },
+      label: "split-view-comparison-pipeline",
+    });
+    this.paramsBuffer = device.createBuffer({
+      size: 48,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+      label: "split-view-comparison-params",
+    });
+    this.sampler = device.createSampler({ magFilter: "linear", minFilter: "linear" });
+
+    this.boundOnPointerDown = this.onPointerDown.bind(this);
+    this.boundOnPointerMove = this.onPointerMove.bind(this);
+    this.boundOnPointerUp = this.onPointerUp.bind(this);
+    this.canvas.addEventListener("pointerdown", this.boundOnPointerDown);
+    this.canvas.addEventListener("pointermove", this.boundOnPointerMove);
+    this.canvas.addEventListener("pointerup", this.boundOnPointerUp);
+    this.canvas.addEventListener("pointercancel", this.boundOnPointerUp);
+  }
+
+  /**
+   * 设置参考图像，null 清除
+   * @param owned 为 true 时纹理由本对象管理，替换或销毁时释放
+   */
+  setImage(texture: GPUTexture | null, owned: boolean = false): void {
+    if (texture === this.image) {
+      this.ownsImage = owned;
+      return;
+    }
+    if (this.ownsImage) this.image?.destroy();
+    this.image = texture;
+    this.ownsImage = texture !== null && owned;
+    this.bindGroup = texture
+      ? this.renderer.device.createBindGroup({
+        layout: this.bindGroupLayout,
+        entries: [
+          { binding: 0, resource: { buffer: this.paramsBuffer } },
+          { binding: 1, resource: texture.createView() },
+          { binding: 2, resource: this.sampler },
+        ],
+        label: "split-view-comparison-bind-group",
+      })
+      : null;
+    if (!texture) this.endDrag();
+  }
+
+  getImage(): GPUTexture | null {
+    return this.image;
+  }
+
+  /**
+   * 尺寸不变时复用自有纹理，否则重新创建
+   */
+  private ensureOwnedImage(width: number, height: number): GPUTexture {
+    if (this.image && this.ownsImage && this.image.width === width && this.image.height === height) {
+      return this.image;
+    }
+    const texture = this.renderer.device.createTexture({
+      size: [width, height, 1],
+      format: "rgba8unorm",
+      usage: GPUTextureUsage.TEXTURE_BINDING | GPUTextureUsage.COPY_DST,
+      label: "split-view-comparison-image",
+    });
+    this.setImage(texture, true);
+    return texture;
+  }
+
+  /**
+   * 从 RGBA8 像素数组更新参考图像（直接从调用方的数组上传，不复制）
+   * @param bytesPerRow 每行字节数，默认 width * 4
+   */
+  writeImage(data: ArrayBufferView, width: number, height: number, bytesPerRow: number = width * 4): void {
+    const texture = this.ensureOwnedImage(width, height);
+    this.renderer.device.queue.writeTexture({ texture }, data, { bytesPerRow }, [width, height, 1]);
+  }
+
+  /**
+   * 从同一设备上的 GPU buffer（RGBA8，需要 COPY_SRC 用途）更新参考图像，不经过 CPU
+   * @param bytesPerRow 每行字节数，需要是 256 的倍数
+   */
+  copyImageFromBuffer(buffer: GPUBuffer, width: number, height: number, bytesPerRow: number, offset: number = 0): void {
+    if ((buffer.usage & GPUBufferUsage.COPY_SRC) === 0) {
+      throw new Error("[SplitViewComparison] 图像 buffer 需要 COPY_SRC 用途");
+    }
+    if (bytesPerRow % 256 !== 0 || bytesPerRow < width * 4) {
+      throw new Error(`[SplitViewComparison] bytesPerRow (${bytesPerRow}) 需要是 256 的倍数且不小于 width * 4`);
+    }
+    const texture = this.ensureOwnedImage(width, height);
+    const device = this.renderer.device;
+    const encoder = device.createCommandEncoder({ label: "split-view-comparison-copy" });
+    encoder.copyBufferToTexture({ buffer, offset, bytesPerRow }, { texture }, [width, height, 1]);
+    device.queue.submit([encoder.finish()]);
+  }
+
+  /**
+   * 设置分割线位置（0~1，沿分割方向从左 / 上量起）
+   */
+  setDivider(position: number): void {
+    this.divider = Math.min(Math.max(position, 0), 1);
+  }
+
+  getDivider(): number {
+    return this.divider;
+  }
+
+  setConfig(config: Partial<SplitViewComparisonConfig>): void {
+    this.config = { ...this.config, ...config };
+  }
+
+  getConfig(): SplitViewComparisonConfig {
+    return { ...this.config, lineColor: [...this.config.lineColor] };
+  }
+
+  private get active(): boolean {
+    return this.enabled && this.bindGroup !== null;
+  }
+
+  /**
+   * 在主渲染通道中绘制（场景之后、Gizmo 之前）
+   */
+  render(pass: GPURenderPassEncoder): void {
+    if (!this.active) return;
+    const { width, height } = this.renderer;
+    if (width === 0 || height === 0) return;
+
+    // 分割线宽度按 CSS 像素换算到画布像素
+    const pixelRatio = getCanvasPixelMapping(this.canvas)?.scaleX ?? 1;
+    const { orientation, swap, lineWidth, lineColor } = this.config;
+    this.renderer.device.queue.writeBuffer(this.paramsBuffer, 0, new Float32Array([
+      this.divider, orientation === "horizontal" ? 1 : 0, swap ? 1 : 0, (lineWidth * pixelRatio) / 2,
+      ...lineColor,
+      width, height, 0, 0,
+    ]));
+
+    pass.setPipeline(this.pipeline);
+    pass.setBindGroup(0, this.bindGroup!);
+    pass.draw(4);
+  }
+
+  /**
+   * 指针位置对应的分割线位置（未限制到 0~1）和每 CSS 像素对应的位置变化，画布不可见时为 null
+   */
+  private pointerToDivider(e: PointerEvent): { t: number; perPixel: number } | null {
+    const mapping = getCanvasPixelMapping(this.canvas);
+    if (!mapping) return null;
+    return this.config.orientation === "horizontal"
+      ? { t: ((e.clientY - mapping.top) * mapping.scaleY) / this.canvas.height, perPixel: mapping.scaleY / this.canvas.height }
+      : { t: ((e.clientX - mapping.left) * mapping.scaleX) / this.canvas.width, perPixel: mapping.scaleX / this.canvas.width };
+  }
+
+  private onPointerDown(e: PointerEvent): void {
+    if (!this.active || e.button !== 0) return;
+    const pointer = this.pointerToDivider(e);
+    if (!pointer || pointer.t < 0 || pointer.t > 1) return;
+    if (Math.abs(pointer.t - this.divider) > this.config.grabDistance * pointer.perPixel) return;
+
+    this.dragging = true;
+    this.canvas.setPointerCapture(e.pointerId);
+    this.onDraggingChange(true);
+  }
+
+  private onPointerMove(e: PointerEvent): void {
+    if (!this.dragging) return;
+    // 拖出画布时限制在边缘
+    const pointer = this.pointerToDivider(e);
+    if (pointer) this.setDivider(pointer.t);
+  }
+
+  private onPointerUp(e: PointerEvent): void {
+    if (!this.dragging) return;
+    if (this.canvas.hasPointerCapture(e.pointerId)) {
+      this.canvas.releasePointerCapture(e.pointerId);
+    }
+    this.endDrag();
+  }
+
+  private endDrag(): void {
+    if (!this.dragging) return;
+    this.dragging = false;
+    this.onDraggingChange(false);
+  }
+
+  /**
+   * 销毁（释放自有的参考图像纹理）
+   */
+  destroy(): void {
+    this.setImage(null);
+    this.canvas.removeEventListener("pointerdown", this.boundOnPointerDown);
+    this.canvas.removeEventListener("pointermove", this.boundOnPointerMove);
+    this.canvas.removeEventListener("pointerup", this.boundOnPointerUp);
+    this.canvas.removeEventListener("pointercancel", this.boundOnPointerUp);
+    this.paramsBuffer.destroy();
+  }
+}
@@ -0,0 +1,134 @@
+/**
+ * TemporalJitter - TAA 的亚像素抖动序列与历史有效性
+ *
+ * 每帧按 Halton(2, 3) 序列给相机投影加亚像素偏移，splat 和网格使用同一个抖动后的投影，
+ * 外部 TAA pass 累积时两者一致，不会出现 splat 单独闪烁或拖影。
+ * 排序、LOD 等使用不含抖动的投影（Camera.unjitteredProjectionMatrix），抖动不会让排序结果失效。
+ * 速度由深度重投影得到：记录上一帧不含抖动的 viewProjection，静止 splat 的速度只来自相机运动，
+ * 需要 splat 写入深度（GSSplatRenderer.setDepthWrite）。序列重置或视口尺寸变化时历史标记为无效
+ */
+
+import type { Camera } from "./Camera";
+
+/**
+ * Halton 序列第 index 项 (index 从 1 开始)
+ */
+export function halton(index: number, base: number): number {
+  let result = 0;
+  let f = 1;
+  let i = index;
+  while (i > 0) {
+    f /= base;
+    result += f * (i % base);
+    i = Math.floor(i / base);
+  }
+  return result;
+}
+
+/**
+ * 抖动序列
+ */
+export class TemporalJitter {
+  private sampleCount: number;
+  private index: number = 0;
+  private offset: [number, number] = [0, 0];
+  private width: number = 0;
+  private height: number = 0;
+  // 已累积的帧数，0 表示历史无效
+  private historyFrames: number = 0;
+  private viewProjection: Float32Array = new Float32Array(16);
+  private previousViewProjection: Float32Array = new Float32Array(16);
+
+  /**
+   * @param sampleCount 序列长度（帧），默认 8
+   */
+  constructor(sampleCount: number = 8) {
+    this.sampleCount = Math.max(1, Math.floor(sampleCount));
+  }
+
+  /**
+   * 设置序列长度（会重置序列）
+   */
+  setSampleCount(count: number): void {
+    this.sampleCount = Math.max(1, Math.floor(count));
+    this.reset();
+  }
+
+  getSampleCount(): number {
+    return this.sampleCount;
+  }
+
+  /**
+   * 重置序列，历史标记为无效
+   */
+  reset(): void {
+    this.index = 0;
+    this.historyFrames = 0;
+  }
+
+  /**
+   * 每帧调用一次：给相机设置下一个抖动并更新矩阵，记录上一帧的 viewProjection
+   */
+  apply(camera: Camera, width: number, height: number): void {
+    if (width !== this.width || height !== this.height) {
+      this.width = width;
+      this.height = height;
+      this.reset();
+    }
+    // 像素偏移在 [-0.5, 0.5) 内
+    const sample = (this.index % this.sampleCount) + 1;
+    this.offset = [halton(sample, 2) - 0.5, halton(sample, 3) - 0.5];
+    this.index = (this.index + 1) % this.sampleCount;
+
+    camera.setJitter(this.offset[0], this.offset[1], width, height);
+    camera.updateMatrix();
+
+    this.previousViewProjection.set(this.historyFrames > 0 ? this.viewProjection : this.computeViewProjection(camera));
+    this.viewProjection.set(this.computeViewProjection(camera));
+    this.historyFrames++;
+  }
+
+  /**
+   * 本帧的抖动（像素）
+   */
+  getOffset(): [number, number] {
+    return [...this.offset];
+  }
+
+  /**
+   * 上一帧的历史是否可用于累积（序列重置后的第一帧为 false）
+   */
+  isHistoryValid(): boolean {
+    return this.historyFrames > 1;
+  }
+
+  /**
+   * 上一帧不含抖动的 viewProjection（用于由深度重投影计算速度）
+   */
+  getPreviousViewProjection(): Float32Array {
+    return this.previousViewProjection;
+  }
+
+  /**
+   * 本帧不含抖动的 viewProjection
+   */
+  getViewProjection(): Float32Array {
+    return this.viewProjection;
+  }
+
+  private computeViewProjection(camera: Camera): Float32Array {
+    const out = new Float32Array(16);
+    const a = camera.unjitteredProjectionMatrix;
+    const b = camera.viewMatrix;
+    for (let i = 0; i < 4; i++) {
+      for (let j = 0; j < 4; j++) {
+        out[i * 4 + j] =
+          a[j] * b[i * 4] +
+          a[j + 4] * b[i * 4 + 1] +
+          a[j + 8] * b[i * 4 + 2] +
+          a[j + 12] * b[i * 4 + 3];
+      }
+    }
+    return out;
+  }
+}
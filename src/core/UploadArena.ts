@@ -0,0 +1,148 @@
+/**
+ * UploadArena - 每帧小块 buffer 写入的共享上传区
+ *
+ * uniform、剔除参数、选择状态等每帧写入先拷贝到同一块复用的 CPU 暂存区，
+ * 提交命令前统一 flush：目标 buffer 上首尾相接的写入合并为一次 queue.writeBuffer，
+ * 避免每次写入都创建临时 TypedArray 并由浏览器单独分配暂存内存。
+ * 写入方必须保证在引用这些数据的 queue.submit 之前调用 flush
+ */
+export class UploadArena {
+  private device: GPUDevice;
+  private scratch: ArrayBuffer;
+  private bytes: Uint8Array;
+  private _f32: Float32Array;
+  private _u32: Uint32Array;
+  private used: number = 0;
+
+  // 待上传区间（并列数组，避免每次写入创建对象）
+  private targets: GPUBuffer[] = [];
+  private targetOffsets: number[] = [];
+  private starts: number[] = [];
+  private sizes: number[] = [];
+
+  constructor(device: GPUDevice, initialSize: number = 4096) {
+    this.device = device;
+    this.scratch = new ArrayBuffer(initialSize);
+    this.bytes = new Uint8Array(this.scratch);
+    this._f32 = new Float32Array(this.scratch);
+    this._u32 = new Uint32Array(this.scratch);
+  }
+
+  /**
+   * 写入任意数据（按 4 字节对齐）
+   */
+  write(buffer: GPUBuffer, offset: number, data: ArrayBufferView | ArrayBuffer): void {
+    const src = data instanceof ArrayBuffer
+      ? new Uint8Array(data)
+      : new Uint8Array(data.buffer, data.byteOffset, data.byteLength);
+    const start = this.reserve(buffer, offset, src.byteLength);
+    this.bytes.set(src, start);
+  }
+
+  /**
+   * 写入最多 4 个 f32（一个 vec4，不创建临时数组）
+   */
+  writeF32(buffer: GPUBuffer, offset: number, x: number, y: number = 0, z: number = 0, w: number = 0): void {
+    const i = this.reserve(buffer, offset, 16) >> 2;
+    this._f32[i] = x;
+    this._f32[i + 1] = y;
+    this._f32[i + 2] = z;
+    this._f32[i + 3] = w;
+  }
+
+  /**
+   * 写入最多 4 个 u32（一个 vec4<u32>，不创建临时数组）
+   */
+  writeU32(buffer: GPUBuffer, offset: number, x: number, y: number = 0, z: number = 0, w: number = 0): void {
+    const i = this.reserve(buffer, offset, 16) >> 2;
+    this._u32[i] = x;
+    this._u32[i + 1] = y;
+    this._u32[i + 2] = z;
+    this._u32[i + 3] = w;
+  }
+
+  /**
+   * 预留 size 字节由调用方通过 f32 / u32 视图就地填写（混合类型的 vec4 等）
+   * @returns 区间起始的 4 字节字索引
+   */
+  stage(buffer: GPUBuffer, offset: number, size: number): number {
+    return this.reserve(buffer, offset, size) >> 2;
+  }
+
+  /**
+   * 暂存区的 f32 视图（暂存区扩容后会变化，需在 stage 之后获取）
+   */
+  get f32(): Float32Array {
+    return this._f32;
+  }
+
+  /**
+   * 暂存区的 u32 视图（暂存区扩容后会变化，需在 stage 之后获取）
+   */
+  get u32(): Uint32Array {
+    return this._u32;
+  }
+
+  /**
+   * 上传所有待写入区间并重置暂存区
+   */
+  flush(): void {
+    const queue = this.device.queue;
+    for (let i = 0; i < this.targets.length; i++) {
+      queue.writeBuffer(this.targets[i], this.targetOffsets[i], this.scratch, this.starts[i], this.sizes[i]);
+    }
+    this.targets.length = 0;
+    this.targetOffsets.length = 0;
+    this.starts.length = 0;
+    this.sizes.length = 0;
+    this.used = 0;
+  }
+
+  /**
+   * 是否有待上传的数据
+   */
+  hasPending(): boolean {
+    return this.targets.length > 0;
+  }
+
+  /**
+   * 在暂存区中分配区间并登记目标，返回字节起始位置
+   */
+  private reserve(buffer: GPUBuffer, offset: number, size: number): number {
+    const aligned = (size + 3) & ~3;
+    if (this.used + aligned > this.scratch.byteLength) {
+      this.grow(this.used + aligned);
+    }
+    const start = this.used;
+    this.used += aligned;
+
+    // 与上一次写入首尾相接时合并
+    const last = this.targets.length - 1;
+    if (
+      last >= 0 &&
+      this.targets[last] === buffer &&
+      this.targetOffsets[last] + this.sizes[last] === offset &&
+      this.starts[last] + this.sizes[last] === start
+    ) {
+      this.sizes[last] += aligned;
+    } else {
+      this.targets.push(buffer);
+      this.targetOffsets.push(offset);
+      this.starts.push(start);
+      this.sizes.push(aligned);
+    }
+    return start;
+  }
+
+  private grow(required: number): void {
+    let size = this.scratch.byteLength * 2;
+    while (size < required) size *= 2;
+    const scratch = new ArrayBuffer(size);
+    const bytes = new Uint8Array(scratch);
+    bytes.set(this.bytes.subarray(0, this.used));
+    this.scratch = scratch;
+    this.bytes = bytes;
+    this._f32 = new Float32Array(scratch);
+    this._u32 = new Uint32Array(scratch);
+  }
+}
@@ -0,0 +1,101 @@
+/**
+ * UploadScheduler - 大块 buffer 更新的分帧上传预算
+ *
+ * 训练或流式加载时每帧可能有数十 MB 的 splat 更新，一次性 queue.writeBuffer 会让该帧卡顿。
+ * 写入方在上传前按字节向调度器申请额度，超出本帧预算的部分保持脏标记，留到后续帧继续上传。
+ * 调度器不缓存数据：推迟的部分在真正上传时从 CPU 端取最新值，重复修改只上传一次
+ */
+
+/**
+ * 上传统计
+ */
+export interface UploadSchedulerStats {
+  /** 每帧预算（字节），0 表示不限制 */
+  budget: number;
+  /** 上一帧上传的字节数 */
+  lastFrameBytes: number;
+  /** 上一帧因预算推迟的字节数 */
+  lastFrameDeferredBytes: number;
+}
+
+/**
+ * 分帧上传调度器（每帧开始时由 Renderer.beginFrame 重置额度）
+ */
+export class UploadScheduler {
+  private budget: number = 0;
+  private remaining: number = Infinity;
+  private frameBytes: number = 0;
+  private frameDeferredBytes: number = 0;
+  private lastFrameBytes: number = 0;
+  private lastFrameDeferredBytes: number = 0;
+
+  /**
+   * 设置每帧上传预算（字节），0 表示不限制
+   */
+  setBudget(bytesPerFrame: number): void {
+    this.budget = Math.max(0, Math.floor(bytesPerFrame));
+    this.remaining = this.budget > 0 ? Math.max(0, this.budget - this.frameBytes) : Infinity;
+  }
+
+  /**
+   * 获取每帧上传预算（字节）
+   */
+  getBudget(): number {
+    return this.budget;
+  }
+
+  /**
+   * 开始新的一帧：记录上一帧统计并重置额度
+   */
+  beginFrame(): void {
+    this.lastFrameBytes = this.frameBytes;
+    this.lastFrameDeferredBytes = this.frameDeferredBytes;
+    this.frameBytes = 0;
+    this.frameDeferredBytes = 0;
+    this.remaining = this.budget > 0 ? this.budget : Infinity;
+  }
+
+  /**
+   * 申请上传 bytes 字节，返回本帧允许上传的字节数
+   * 不能整块上传时按 granularity 向下取整；本帧还没有上传过数据时至少允许一个 granularity，
+   * 保证单块大于预算的更新也能前进。未获批的部分计入推迟统计
+   */
+  request(bytes: number, granularity: number = 4): number {
+    let allowed = bytes;
+    if (bytes > this.remaining) {
+      allowed = Math.floor(this.remaining / granularity) * granularity;
+      if (allowed === 0 && this.frameBytes === 0) {
+        allowed = Math.min(bytes, granularity);
+      }
+    }
+    this.remaining = Math.max(0, this.remaining - allowed);
+    this.frameBytes += allowed;
+    this.frameDeferredBytes += bytes - allowed;
+    return allowed;
+  }
+
+  /**
+   * 本帧剩余额度（字节，不限制时为 Infinity）
+   */
+  getRemaining(): number {
+    return this.remaining;
+  }
+
+  /**
+   * 本帧是否有因预算推迟的数据（需要后续帧继续上传）
+   */
+  hasDeferred(): boolean {
+    return this.frameDeferredBytes > 0;
+  }
+
+  /**
+   * 获取上传统计
+   */
+  getStats(): UploadSchedulerStats {
+    return {
+      budget: this.budget,
+      lastFrameBytes: this.lastFrameBytes,
+      lastFrameDeferredBytes: this.lastFrameDeferredBytes,
+    };
+  }
+}
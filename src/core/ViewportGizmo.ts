@@ -1,5 +1,6 @@
 import { Camera } from "./Camera";
 import { Renderer } from "./Renderer";
+import { getDepthCompare, mapDepth } from "./DepthConvention";
 
 /**
  * Gizmo 轴配置
@@ -152,7 +153,7 @@ export class ViewportGizmo {
       depthStencil: {
         format: this.renderer.depthFormat,
         depthWriteEnabled: true,
-        depthCompare: "less",
+        depthCompare: getDepthCompare(this.renderer.depthConfig),
       },
     });
   }
@@ -468,8 +469,10 @@ export class ViewportGizmo {
     // 正交投影矩阵
     this.projMatrix[0] = 1 / s;
     this.projMatrix[5] = 1 / s;
-    this.projMatrix[10] = -1 / 10;
-    this.projMatrix[14] = 0;
+    // 标准约定下 depth = -z / 10，reverse-Z 下取 1 - depth
+    const reverse = this.renderer.depthConfig.reverseZ;
+    this.projMatrix[10] = reverse ? 1 / 10 : -1 / 10;
+    this.projMatrix[14] = mapDepth(this.renderer.depthConfig, 0);
     this.projMatrix[15] = 1;
   }
 
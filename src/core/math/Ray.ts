@@ -1,5 +1,6 @@
 import { Vec3 } from "./Vec3";
 import { Camera } from "../Camera";
+import { getNearDepth } from "../DepthConvention";
 
 /**
  * Ray - Ray utility class
@@ -36,9 +37,10 @@ export class Ray {
     // Create inverse view-projection matrix
     const invViewProj = Ray.invertMatrix(camera.viewProjectionMatrix);
 
-    // Transform NDC point to world space (near plane)
-    const nearPoint = Ray.transformPoint(invViewProj, ndcX, ndcY, -1);
-    const farPoint = Ray.transformPoint(invViewProj, ndcX, ndcY, 1);
+    // Transform NDC points to world space: near plane and a mid depth
+    // (the far plane may be at infinity, so only finite depths are unprojected)
+    const nearPoint = Ray.transformPoint(invViewProj, ndcX, ndcY, getNearDepth(camera.depthConfig));
+    const farPoint = Ray.transformPoint(invViewProj, ndcX, ndcY, 0.5);
 
     // Ray origin is camera position
     const origin = new Vec3(
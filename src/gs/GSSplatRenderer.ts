@@ -11,12 +11,69 @@
 
 import { Renderer } from "../core/Renderer";
 import { Camera } from "../core/Camera";
+import { PipelineCache } from "../core/PipelineCache";
+import { SplatBufferPool } from "./SplatBufferPool";
+import { SplatPickTargets } from "./SplatPickTargets";
 import { SplatCPU } from "./PLYLoader";
 import { GSSplatSorter } from "./GSSplatSorter";
+import { GSSplatTileRasterizer } from "./GSSplatTileRasterizer";
+import { computeTransformUniforms } from "./TransformUniforms";
+import { SplatOctree, buildSplatOctree } from "./SplatOctree";
+import { SplatPVS, SplatPVSConfig, buildSplatPVS } from "./SplatPVS";
+import { SplatLodConfig, DEFAULT_SPLAT_LOD_CONFIG } from "./SplatLod";
+import { TransferFunctionStop, DEFAULT_TRANSFER_FUNCTION, createTransferFunction } from "./TransferFunction";
+import { getDepthCompare, getDepthClearValue, linearizeDepth, unprojectPoint } from "../core/DepthConvention";
+import { halfToFloat } from "../core/FrameCapture";
 import { CompactSplatData, compactDataToGPUBuffer } from "./PLYLoaderMobile";
+import { SplatLabels } from "./SplatLabels";
+import { SplatSelection } from "./SplatSelection";
+import { GaussianSplats, SPLAT_DIRTY_CHUNK_SIZE } from "./GaussianSplats";
+import { SplatCompactor } from "./SplatCompactor";
+import { SplatMemoryDowngrade, getBuffersByteSize } from "./SplatMemoryBudget";
+import { computeSplatStatistics } from "./SplatStatistics";
+import type { SplatPrecision, SplatStatistics } from "./SplatStatistics";
+import { validateTransform, multiplyTransforms } from "../utils/transform";
+import { createBoundingBoxFromMinMax } from "../utils/geometry";
+import type { TransformIssue } from "../utils/transform";
 import type { BoundingBox, Vec3Tuple } from "../types";
-import { SHMode, RendererCapabilities } from "../types";
+import { SHMode, SplatVisMode, Colormap, SplatRasterMode, SplatBlendMode, SplatPointMode, SplatDepthKeyMode, RendererCapabilities } from "../types";
+import { getSplatBlendState, isOrderIndependentBlend } from "./SplatBlend";
+import { SplatPointSizeConfig, DEFAULT_SPLAT_POINT_SIZE_CONFIG, POINT_DENSITY_CELL_SIZE } from "./SplatPoints";
+import { SplatCentersFilter, SplatCentersConfig, DEFAULT_SPLAT_CENTERS_CONFIG, needsCentersFilter } from "./SplatCentersFilter";
+import type { SplatPickResult, SplatDepthQueryMode, SplatDepthResult } from "../types";
 import type { IGSSplatRenderer, IGSSplatRendererWithCapabilities } from "./IGSSplatRenderer";
+import { SplatPassTracker } from "./SplatPassTracker";
+import { SplatSortCache, SplatSortCacheStats } from "./SplatSortCache";
+import { SplatDirtyRanges } from "./SplatDirtyRanges";
+import { getDirectDrawInstanceCount } from "./SplatDirectDraw";
+import { SplatPolygonSelector } from "./SplatPolygonSelector";
+import { SplatAttributeSelector } from "./SplatAttributeFilter";
+import { SplatExternalPacker, validateExternalBuffer, validateExternalBuffers } from "./SplatExternalBuffers";
+import type { SplatExternalBuffer, SplatExternalBuffers } from "./SplatExternalBuffers";
+import type { SplatAttributePredicate } from "./SplatAttributeFilter";
+import { DEFAULT_SPLAT_SWAY_CONFIG, getSplatSwayUniforms, splatSwayShader } from "./SplatSway";
+import type { SplatSwayConfig } from "./SplatSway";
+import { getColorAdjustUniforms, splatColorAdjustShader } from "./SplatColorAdjust";
+import type { SplatColorAdjustment } from "./SplatColorAdjust";
+import { gatherSplatAppearance, packSplatAppearance, splatAppearanceShader } from "./SplatAppearance";
+import type { SplatAppearanceData } from "./SplatAppearance";
+import { getOpacityEditUniforms, splatOpacityEditShader } from "./SplatOpacityEdit";
+import type { SplatOpacityEdit } from "./SplatOpacityEdit";
+import { getCropBoxUniforms, splatCropBoxShader } from "./SplatCropBox";
+import type { SplatCropBox } from "./SplatCropBox";
+import { getSplatGroupHiddenMask, getSplatGroupUniforms, splatGroupsShader } from "./SplatGroups";
+import type { SplatGroups } from "./SplatGroups";
+import { createSplatIdDepth } from "./SplatRenderLayers";
+import type { SplatIdDepth } from "./SplatRenderLayers";
+import { splatShaderFeatureConstants } from "./SplatShaderFeatures";
+import { SPLAT_VIS_MODE_CUSTOM_BASE, SPLAT_VIS_MODE_HOOK, buildSplatVisModeShader, findSplatVisMode, resolveSplatVisMode } from "./SplatVisModes";
+
+/**
+ * LOD 重要性：不透明度 * 椭球截面积的近似，越重要的 splat 在低 LOD 下越晚被丢弃
+ */
+function splatImportance(opacity: number, sx: number, sy: number, sz: number): number {
+  return opacity * (sx * sy + sy * sz + sz * sx);
+}
 
 // 优化的 shader (内联)
 const gsOptimizedShader = /* wgsl */ `
@@ -25,15 +82,37 @@ const gsOptimizedShader = /* wgsl */ `
  * 参考 rfs-gsplat-render 实现，修复颜色和抗锯齿问题
  */
 
+${splatShaderFeatureConstants}
 const SQRT_8: f32 = 2.82842712475;
 const SH_C0: f32 = 0.28209479177387814;
 const SH_C1: f32 = 0.4886025119029199;
+const SH_C2 = array<f32, 5>(1.0925484305920792, -1.0925484305920792, 0.31539156525252005, -1.0925484305920792, 0.5462742152960396);
+const SH_C3 = array<f32, 7>(
+  -0.5900435899266435, 2.890611442640554, -0.4570457994644658, 0.3731763325901154,
+  -0.4570457994644658, 1.445305721320277, -0.5900435899266435,
+);
 // Normalized Gaussian 常量 (匹配 SuperSplat)
 const EXP_NEG4: f32 = 0.01831563888873418;
 const INV_ONE_MINUS_EXP_NEG4: f32 = 1.01865736036377408;
 // 低通滤波器 (正则化协方差矩阵)
 const LOW_PASS_FILTER: f32 = 0.3;
 const ALPHA_CULL_THRESHOLD: f32 = 0.00392156863;
+// 可视化模式 (与 SplatVisMode 对应)
+const VIS_MODE_LABEL: u32 = 1u;
+const VIS_MODE_SCALAR: u32 = 2u;
+const VIS_MODE_VOLUME: u32 = 3u;
+const VIS_MODE_CUSTOM: u32 = ${SPLAT_VIS_MODE_CUSTOM_BASE}u;
+// colormap (与 Colormap 对应)
+const COLORMAP_TURBO: u32 = 1u;
+// 2DGS surfel quad 覆盖的 σ 倍数
+const SURFEL_EXTENT: f32 = 3.0;
+// 相机到 splat 中心的马氏距离低于此值时完全淡出 (到 SQRT_8 时完全不透明)
+const CAMERA_INSIDE_FADE_START: f32 = 1.0;
+// 选中高亮颜色
+const SELECTION_COLOR: vec3<f32> = vec3<f32>(1.0, 0.6, 0.1);
+// 中心点叠加颜色
+const CENTER_COLOR: vec3<f32> = vec3<f32>(0.1, 0.85, 1.0);
+const PI: f32 = 3.14159265359;
 
 struct Uniforms {
   view: mat4x4<f32>,
@@ -42,11 +121,43 @@ struct Uniforms {
   cameraPos: vec3<f32>,
   _pad: f32,
   screenSize: vec2<f32>,
-  _pad2: vec2<f32>,
+  maxSplatSize: f32,    // splat 屏幕轴长上限（像素），0 不限制
+  _pad2: f32,
+  visMode: u32,
+  hasLabels: u32,
+  hasSelection: u32,
+  colormap: u32,
+  scalarRange: vec2<f32>,
+  hasScalar: u32,
+  frameSeed: u32,
+  exposure: f32,
+  volumeOpacity: f32,  // 体渲染模式的不透明度倍数
+  nearPlane: f32,
+  nearFade: f32,       // 近平面淡出距离，0 表示关闭
+  insideFade: u32,     // 相机位于 splat 内部时淡出
+  shDegree: u32,       // SH 阶数上限 (SHMode)
+  depthAlpha: f32,     // 深度输出的不透明度阈值
+  opacityScale: f32,   // 整体不透明度倍数（图层淡入淡出）
+  shDistances: vec4<f32>,  // xyz: 超过该距离依次去掉 L3 / L2 / L1，0 表示不限制
+  pointParams: vec4<f32>,  // 点显示 x: 最小直径, y: 最大直径 (像素), z: 目标覆盖率
+  pointFlags: vec4<u32>,   // 点显示 x: 密度格子边长 (像素，0 表示固定尺寸), y: 中心点叠加
+  visibleCount: u32,       // 本帧可见数量（排序器拷贝），直接绘制时剔除多出的实例
+  swayWind: vec4<f32>,     // 摆动 xyz: 风向, w: 幅度（0 表示关闭）
+  swayParams: vec4<f32>,   // 摆动 x: 频率, y: 时间, z: 起始高度, w: 高度范围
+  swayNoise: vec4<f32>,    // 摆动 x: 噪声空间频率
+  colorTint: vec4<f32>,    // 颜色调整预览 rgb: 着色, w: 是否启用
+  colorAdjust: vec4<f32>,  // 颜色调整预览 x: 色相旋转 (圈), y: 饱和度倍数, z: 亮度倍数
+  appearance: vec4<f32>,   // 外观混合 x: 第二套外观的权重 (0~1)，没有第二套外观时为 0
+  opacityEdit: vec4<f32>,  // 不透明度编辑预览 x: 启用, y: 模式 (0 缩放 / 1 设置), z: 值
+  cropBox: mat4x4<f32>,    // 裁剪盒：模型空间 -> 盒子单位空间
+  cropParams: vec4<f32>,   // 裁剪盒 x: 启用, y: 反转
+  groupTints: array<vec4<f32>, 16>,  // 分组着色 rgb: 颜色, w: 强度（按分组 id 索引）
+  groupParams: vec4<u32>,  // 分组 x: 隐藏分组的位掩码
+  hover: vec4<u32>,        // 悬停高亮 x: 模式 (0 关闭 / 1 splat / 2 分组), y: splat 索引, z: 分组 id, w: 强度 (f32 位)
 }
 
 struct Splat {
-  mean: vec3<f32>, _pad0: f32,
+  mean: vec3<f32>, emissive: f32,
   scale: vec3<f32>, _pad1: f32,
   rotation: vec4<f32>,
   colorDC: vec3<f32>,
@@ -60,12 +171,22 @@ struct Splat {
 @group(0) @binding(0) var<uniform> uniforms: Uniforms;
 @group(0) @binding(1) var<storage, read> splats: array<Splat>;
 @group(0) @binding(2) var<storage, read> sortedIndices: array<u32>;
+@group(0) @binding(3) var<storage, read> labels: array<u32>;
+@group(0) @binding(4) var<storage, read> selection: array<u32>;
+@group(0) @binding(5) var<storage, read> scalars: array<f32>;
+// 体渲染传递函数查找表 (N x 1, RGBA)
+@group(0) @binding(6) var transferFunctionTex: texture_2d<f32>;
+// 剔除 pass 统计的每个屏幕格子内的可见 splat 数量
+@group(0) @binding(7) var<storage, read> densityGrid: array<u32>;
+// 第二套外观（DC + SH），未设置时为占位 buffer
+@group(0) @binding(8) var<storage, read> appearanceB: array<f32>;
 
 struct VertexOutput {
   @builtin(position) position: vec4<f32>,
   @location(0) fragPos: vec2<f32>,
   @location(1) color: vec3<f32>,
   @location(2) opacity: f32,
+  @location(3) @interpolate(flat) splatIndex: u32,
 }
 
 const QUAD_POSITIONS = array<vec2<f32>, 4>(
@@ -102,6 +223,35 @@ fn computeCovariance3D(scale: vec3<f32>, rotation: vec4<f32>) -> mat3x3<f32> {
   return M * transpose(M);
 }
 
+// 3x3 矩阵求逆 (伴随矩阵)
+fn inverse3x3(m: mat3x3<f32>) -> mat3x3<f32> {
+  let c0 = cross(m[1], m[2]);
+  let c1 = cross(m[2], m[0]);
+  let c2 = cross(m[0], m[1]);
+  return transpose(mat3x3<f32>(c0, c1, c2)) * (1.0 / dot(m[0], c0));
+}
+
+// 相机 (视图空间原点) 到 splat 中心的马氏距离，< SQRT_8 时相机位于 splat 的 quad 覆盖范围内
+fn cameraMahalanobis(splat: Splat, viewPos: vec4<f32>, modelViewMat: mat4x4<f32>) -> f32 {
+  let m = mat3x3<f32>(modelViewMat[0].xyz, modelViewMat[1].xyz, modelViewMat[2].xyz);
+  let toCamera = inverse3x3(m) * -viewPos.xyz;
+  let R = quatToMat3(normalize(splat.rotation));
+  return length((transpose(R) * toCamera) / max(splat.scale, vec3<f32>(1e-6)));
+}
+
+// 相机贴近 splat 时的淡出系数 [0, 1]
+// 相机在大 splat 内部时其投影几乎覆盖全屏且随相机微动剧烈变化，导致整屏闪烁
+fn cameraProximityFade(splat: Splat, viewPos: vec4<f32>, modelViewMat: mat4x4<f32>) -> f32 {
+  var fade = 1.0;
+  if uniforms.nearFade > 0.0 {
+    fade = clamp((-viewPos.z - uniforms.nearPlane) / uniforms.nearFade, 0.0, 1.0);
+  }
+  if uniforms.insideFade != 0u && fade > 0.0 {
+    fade *= smoothstep(CAMERA_INSIDE_FADE_START, SQRT_8, cameraMahalanobis(splat, viewPos, modelViewMat));
+  }
+  return fade;
+}
+
 // 协方差投影 (匹配参考实现)
 // 注意: viewCenter 是 vec4，直接使用 .xyz (不除以 w)
 fn projectCovariance(cov3d: mat3x3<f32>, viewCenter: vec4<f32>, focal: vec2<f32>, modelViewMat: mat4x4<f32>) -> vec3<f32> {
@@ -115,7 +265,8 @@ fn projectCovariance(cov3d: mat3x3<f32>, viewCenter: vec4<f32>, focal: vec2<f32>
     vec3<f32>(-(focal.x * v.x) * s, -(focal.y * v.y) * s, 0.0)
   );
   
-  // 从 model-view 矩阵提取 3x3 旋转部分 (匹配参考实现)
+  // 从 model-view 矩阵提取完整 3x3 线性部分 (不是纯旋转)
+  // 包含模型的非均匀缩放和剪切，T Σ Tᵀ 对任意线性变换都成立
   let W = mat3x3<f32>(
     vec3<f32>(modelViewMat[0][0], modelViewMat[0][1], modelViewMat[0][2]),
     vec3<f32>(modelViewMat[1][0], modelViewMat[1][1], modelViewMat[1][2]),
@@ -165,7 +316,10 @@ fn computeExtentBasisAA(cov2dIn: vec3<f32>, opacity: f32, viewportSize: vec2<f32
   }
   
   // 使用基于视口的最大限制 (匹配 PlayCanvas)
-  let vmin = min(1024.0, min(viewportSize.x, viewportSize.y));
+  var vmin = min(1024.0, min(viewportSize.x, viewportSize.y));
+  if uniforms.maxSplatSize > 0.0 {
+    vmin = min(vmin, uniforms.maxSplatSize * 0.5);
+  }
   
   // 计算轴长度: l = 2.0 * min(sqrt(2.0 * lambda), vmin)
   // 这等价于 std_dev * sqrt(lambda)，因为 std_dev = sqrt(8) ≈ 2.83
@@ -192,19 +346,219 @@ fn computeExtentBasisAA(cov2dIn: vec3<f32>, opacity: f32, viewportSize: vec2<f32
   return result;
 }
 
-fn getModelScale3(model: mat4x4<f32>) -> vec3<f32> {
-  return vec3<f32>(length(model[0].xyz), length(model[1].xyz), length(model[2].xyz));
+// HSV -> RGB
+fn hsvToRgb(h: f32, s: f32, v: f32) -> vec3<f32> {
+  let k = vec3<f32>(1.0, 2.0 / 3.0, 1.0 / 3.0);
+  let p = abs(fract(vec3<f32>(h) + k) * 6.0 - 3.0);
+  return v * mix(vec3<f32>(1.0), clamp(p - 1.0, vec3<f32>(0.0), vec3<f32>(1.0)), s);
+}
+
+// 标签 -> 调色板颜色 (整数哈希打散色相，相邻 id 颜色差异明显)
+fn labelColor(label: u32) -> vec3<f32> {
+  var h = label * 747796405u + 2891336453u;
+  h = ((h >> ((h >> 28u) + 4u)) ^ h) * 277803737u;
+  h = (h >> 22u) ^ h;
+  let hue = f32(h & 0xFFFFu) / 65535.0;
+  return hsvToRgb(hue, 0.65, 0.95);
+}
+
+// viridis 多项式拟合
+fn viridis(t: f32) -> vec3<f32> {
+  let c0 = vec3<f32>(0.2777273272234177, 0.005407344544966578, 0.3340998053353061);
+  let c1 = vec3<f32>(0.1050930431085774, 1.404613529898575, 1.384590162594685);
+  let c2 = vec3<f32>(-0.3308618287255563, 0.214847559468213, 0.09509516302823659);
+  let c3 = vec3<f32>(-4.634230498983486, -5.799100973351585, -19.33244095627987);
+  let c4 = vec3<f32>(6.228269936347081, 14.17993336680509, 56.69055260068105);
+  let c5 = vec3<f32>(4.776384997670288, -13.74514537774601, -65.35303263337234);
+  let c6 = vec3<f32>(-5.435455855934631, 4.645852612178535, 26.3124352495832);
+  return c0 + t * (c1 + t * (c2 + t * (c3 + t * (c4 + t * (c5 + t * c6)))));
+}
+
+// turbo 多项式拟合
+fn turbo(t: f32) -> vec3<f32> {
+  let v4 = vec4<f32>(1.0, t, t * t, t * t * t);
+  let v2 = v4.zw * v4.z;
+  return vec3<f32>(
+    dot(v4, vec4<f32>(0.13572138, 4.61539260, -42.66032258, 132.13108234)) + dot(v2, vec2<f32>(-152.94239396, 59.28637943)),
+    dot(v4, vec4<f32>(0.09140261, 2.19418839, 4.84296658, -14.18503333)) + dot(v2, vec2<f32>(4.27729857, 2.82956604)),
+    dot(v4, vec4<f32>(0.10667330, 12.64194608, -60.58204836, 110.36276771)) + dot(v2, vec2<f32>(-89.90310912, 27.34824973))
+  );
+}
+
+// 标量按 scalarRange 归一化到 [0, 1]
+fn normalizeScalar(value: f32) -> f32 {
+  let span = uniforms.scalarRange.y - uniforms.scalarRange.x;
+  var t = 0.5;
+  if span > 0.0 {
+    t = (value - uniforms.scalarRange.x) / span;
+  }
+  return clamp(t, 0.0, 1.0);
+}
+
+// 标量 -> colormap 颜色
+fn scalarColor(value: f32) -> vec3<f32> {
+  let t = normalizeScalar(value);
+  if uniforms.colormap == COLORMAP_TURBO {
+    return turbo(t);
+  }
+  return viridis(t);
+}
+
+// 标量 -> 传递函数 RGBA (查找表线性插值)
+fn transferFunction(value: f32) -> vec4<f32> {
+  let size = textureDimensions(transferFunctionTex).x;
+  let x = normalizeScalar(value) * f32(size - 1u);
+  let i0 = u32(floor(x));
+  let i1 = min(i0 + 1u, size - 1u);
+  let c0 = textureLoad(transferFunctionTex, vec2<u32>(i0, 0u), 0);
+  let c1 = textureLoad(transferFunctionTex, vec2<u32>(i1, 0u), 0);
+  return mix(c0, c1, fract(x));
+}
+
+fn isVolumeMode() -> bool {
+  return uniforms.visMode == VIS_MODE_VOLUME && uniforms.hasScalar != 0u;
+}
+
+// 不透明度：体渲染模式下由传递函数决定，否则为 splat 原始不透明度，再乘整体倍数；被裁剪盒裁掉时为 0
+fn splatOpacity(splatIndex: u32, baseOpacity: f32) -> f32 {
+  if isSplatCropped(splats[splatIndex].mean) || isSplatGroupHidden(splatIndex) {
+    return 0.0;
+  }
+  if isVolumeMode() {
+    return clamp(transferFunction(scalars[splatIndex]).a * uniforms.volumeOpacity * uniforms.opacityScale, 0.0, 1.0);
+  }
+  return editSplatOpacity(splatIndex, baseOpacity) * uniforms.opacityScale;
+}
+
+// SH 系数按 (系数, 通道) 交错存储；直接从 storage buffer 按动态下标读取
+fn shCoeff1(i: u32, k: u32) -> vec3<f32> {
+  return blendAppearance(i, 3u + k * 3u, vec3<f32>(splats[i].sh1[k * 3u], splats[i].sh1[k * 3u + 1u], splats[i].sh1[k * 3u + 2u]));
+}
+
+fn shCoeff2(i: u32, k: u32) -> vec3<f32> {
+  return blendAppearance(i, 12u + k * 3u, vec3<f32>(splats[i].sh2[k * 3u], splats[i].sh2[k * 3u + 1u], splats[i].sh2[k * 3u + 2u]));
+}
+
+fn shCoeff3(i: u32, k: u32) -> vec3<f32> {
+  return blendAppearance(i, 27u + k * 3u, vec3<f32>(splats[i].sh3[k * 3u], splats[i].sh3[k * 3u + 1u], splats[i].sh3[k * 3u + 2u]));
+}
+
+// 远距离时去掉高阶 SH：在阈值前 10% 的距离内渐变为 0，避免跳变
+// 去掉第 k 阶时更高阶也一并去掉，因此第 band 阶的阈值取 1..band 阶中的最小值
+fn shBandWeight(band: u32, distance: f32) -> f32 {
+  if band > uniforms.shDegree { return 0.0; }
+  var limit = 0.0;
+  for (var k = 1u; k <= band; k++) {
+    let d = uniforms.shDistances[3u - k];
+    if d > 0.0 && (limit == 0.0 || d < limit) { limit = d; }
+  }
+  if limit == 0.0 { return 1.0; }
+  return 1.0 - smoothstep(limit * 0.9, limit, distance);
+}
+
+// 视角相关颜色：DC 颜色 + 按距离裁剪阶数的 SH
+// 方向在模型空间计算 (从相机指向 splat)，与训练时的 SH 坐标系一致
+fn shadedColor(splatIndex: u32, viewPos: vec4<f32>, modelViewMat: mat4x4<f32>) -> vec3<f32> {
+  var color = blendAppearance(splatIndex, 0u, splats[splatIndex].colorDC);
+  if !FEATURE_SH || uniforms.shDegree == 0u { return color; }
+
+  let distance = length(viewPos.xyz);
+  let w1 = shBandWeight(1u, distance);
+  if w1 <= 0.0 { return color; }
+
+  let m = mat3x3<f32>(modelViewMat[0].xyz, modelViewMat[1].xyz, modelViewMat[2].xyz);
+  let dir = normalize(inverse3x3(m) * viewPos.xyz);
+  let x = dir.x; let y = dir.y; let z = dir.z;
+
+  color += w1 * SH_C1 * (-y * shCoeff1(splatIndex, 0u) + z * shCoeff1(splatIndex, 1u) - x * shCoeff1(splatIndex, 2u));
+
+  let w2 = shBandWeight(2u, distance);
+  if w2 <= 0.0 { return color; }
+  let xx = x * x; let yy = y * y; let zz = z * z;
+  let xy = x * y; let yz = y * z; let xz = x * z;
+  color += w2 * (
+    SH_C2[0] * xy * shCoeff2(splatIndex, 0u) +
+    SH_C2[1] * yz * shCoeff2(splatIndex, 1u) +
+    SH_C2[2] * (2.0 * zz - xx - yy) * shCoeff2(splatIndex, 2u) +
+    SH_C2[3] * xz * shCoeff2(splatIndex, 3u) +
+    SH_C2[4] * (xx - yy) * shCoeff2(splatIndex, 4u)
+  );
+
+  let w3 = shBandWeight(3u, distance);
+  if w3 <= 0.0 { return color; }
+  color += w3 * (
+    SH_C3[0] * y * (3.0 * xx - yy) * shCoeff3(splatIndex, 0u) +
+    SH_C3[1] * xy * z * shCoeff3(splatIndex, 1u) +
+    SH_C3[2] * y * (4.0 * zz - xx - yy) * shCoeff3(splatIndex, 2u) +
+    SH_C3[3] * z * (2.0 * zz - 3.0 * xx - 3.0 * yy) * shCoeff3(splatIndex, 3u) +
+    SH_C3[4] * x * (4.0 * zz - xx - yy) * shCoeff3(splatIndex, 4u) +
+    SH_C3[5] * z * (xx - yy) * shCoeff3(splatIndex, 5u) +
+    SH_C3[6] * x * (xx - 3.0 * yy) * shCoeff3(splatIndex, 6u)
+  );
+  return color;
+}
+
+${splatSwayShader}
+${splatColorAdjustShader}
+${splatAppearanceShader}
+${splatOpacityEditShader}
+${splatCropBoxShader}
+${splatGroupsShader}
+
+// 悬停高亮：分组模式下高亮同组的全部 splat（未分组时只高亮该 splat）
+fn isSplatHovered(splatIndex: u32) -> bool {
+  let hover = uniforms.hover;
+  if hover.x == 0u { return false; }
+  if splatIndex == hover.y { return true; }
+  return hover.x == 2u && hover.z != 0u && splatGroup(splatIndex) == hover.z;
+}
+
+// 自定义可视化模式的颜色函数（SplatVisModes 注入时替换）
+${SPLAT_VIS_MODE_HOOK}
+
+// 最终颜色：原始颜色 / 标签 / 标量 / 传递函数 / 自定义模式，再叠加选中高亮
+// 颜色已在 CPU 端预处理为 (dc * SH_C0 + 0.5)，这是 3DGS 的标准颜色格式，在 sRGB 空间中
+fn splatColor(splatIndex: u32, baseColor: vec3<f32>) -> vec3<f32> {
+  let selected = FEATURE_EDITING && uniforms.hasSelection != 0u && (selection[splatIndex] & 1u) != 0u;
+  // 颜色调整预览：只替换选中 splat 的 DC 部分，不显示选中高亮
+  let previewing = uniforms.colorTint.w != 0.0;
+  var shaded = baseColor;
+  if previewing && selected {
+    let dc = blendAppearance(splatIndex, 0u, splats[splatIndex].colorDC);
+    shaded = baseColor - dc + adjustSplatColor(dc);
+  }
+  // 自发光 splat 按 (1 + emissive) 放大，HDR 输出时保留超过 1.0 的亮度
+  var color = shaded * (1.0 + splats[splatIndex].emissive) * uniforms.exposure;
+  if uniforms.visMode == VIS_MODE_LABEL && uniforms.hasLabels != 0u {
+    color = labelColor(labels[splatIndex]);
+  } else if uniforms.visMode == VIS_MODE_SCALAR && uniforms.hasScalar != 0u {
+    color = scalarColor(scalars[splatIndex]);
+  } else if isVolumeMode() {
+    color = transferFunction(scalars[splatIndex]).rgb * uniforms.exposure;
+  } else if uniforms.visMode >= VIS_MODE_CUSTOM {
+    color = customVisColor(splatIndex, color);
+  }
+  color = tintSplatGroup(splatIndex, color);
+  if isSplatHovered(splatIndex) {
+    color = mix(max(color, vec3<f32>(0.0)), vec3<f32>(1.0), bitcast<f32>(uniforms.hover.w));
+  }
+  if selected && !previewing {
+    color = mix(max(color, vec3<f32>(0.0)), SELECTION_COLOR, 0.5);
+  }
+  return color;
 }
 
 @vertex
 fn vs_main(@builtin(vertex_index) vertexIndex: u32, @builtin(instance_index) instanceIndex: u32) -> VertexOutput {
   var output: VertexOutput;
+  if instanceIndex >= uniforms.visibleCount { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
   let splatIndex = sortedIndices[instanceIndex];
-  let splat = splats[splatIndex];
+  let splat = animatedSplat(splatIndex);
   let quadPos = QUAD_POSITIONS[vertexIndex];
+  let opacity = splatOpacity(splatIndex, splat.opacity);
   
   // 透明度剔除
-  if splat.opacity < ALPHA_CULL_THRESHOLD { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
+  if opacity < ALPHA_CULL_THRESHOLD { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
   
   // 四元数有效性检查
   let quatNormSqr = dot(splat.rotation, splat.rotation);
@@ -241,12 +595,16 @@ fn vs_main(@builtin(vertex_index) vertexIndex: u32, @builtin(instance_index) ins
   // 计算 model-view 矩阵 (匹配参考实现)
   let modelViewMat = uniforms.view * uniforms.model;
   
+  // 相机贴近 / 位于 splat 内部时淡出并缩小
+  let proximity = cameraProximityFade(splat, viewPos, modelViewMat);
+  if opacity * proximity < ALPHA_CULL_THRESHOLD { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
+  
   // 投影协方差到 2D (传入 viewPos 作为 vec4，不除以 w)
   let cov2d = projectCovariance(cov3d, viewPos, focal, modelViewMat);
   
   // 计算范围基向量 (带抗锯齿)
-  let extentResult = computeExtentBasisAA(cov2d, splat.opacity, uniforms.screenSize);
-  let basis = extentResult.basis;
+  let extentResult = computeExtentBasisAA(cov2d, opacity * proximity, uniforms.screenSize);
+  let basis = extentResult.basis * proximity;
   let adjustedOpacity = extentResult.adjustedOpacity;
   
   if basis.x == 0.0 && basis.y == 0.0 && basis.z == 0.0 && basis.w == 0.0 {
@@ -282,23 +640,78 @@ fn vs_main(@builtin(vertex_index) vertexIndex: u32, @builtin(instance_index) ins
   // UV 输出 - 用 clipFactor 缩放以获得正确的 Gaussian 权重
   output.fragPos = quadPos * clipFactor;
   
-  // 颜色已在 CPU 端预处理为 (dc * SH_C0 + 0.5)，直接使用
-  // 这是 3DGS 的标准颜色格式，在 sRGB 空间中
-  output.color = splat.colorDC;
+  output.color = splatColor(splatIndex, shadedColor(splatIndex, viewPos, modelViewMat));
   output.opacity = adjustedOpacity;
+  output.splatIndex = splatIndex;
+  return output;
+}
+
+// ============================================
+// 2DGS (surfel) 变体
+// 在 splat 平面上绘制覆盖 3σ 的 quad，光栅化的透视校正插值
+// 即等价于像素射线与盘面求交，片段着色器直接计算 2D 高斯核
+// ============================================
+
+@vertex
+fn vs_main_2d(@builtin(vertex_index) vertexIndex: u32, @builtin(instance_index) instanceIndex: u32) -> VertexOutput {
+  var output: VertexOutput;
+  if instanceIndex >= uniforms.visibleCount { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
+  let splatIndex = sortedIndices[instanceIndex];
+  let splat = animatedSplat(splatIndex);
+  let quadPos = QUAD_POSITIONS[vertexIndex];
+  let opacity = splatOpacity(splatIndex, splat.opacity);
+
+  if opacity < ALPHA_CULL_THRESHOLD { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
+
+  let quatNormSqr = dot(splat.rotation, splat.rotation);
+  if quatNormSqr < 1e-6 { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
+
+  let modelViewMat = uniforms.view * uniforms.model;
+  let viewCenter = modelViewMat * vec4<f32>(splat.mean, 1.0);
+  if viewCenter.z >= 0.0 { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
+
+  let fadedOpacity = opacity * cameraProximityFade(splat, viewCenter, modelViewMat);
+  if fadedOpacity < ALPHA_CULL_THRESHOLD { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
+
+  // 盘面切向量 (旋转矩阵前两列 * 对应 scale)
+  let R = quatToMat3(splat.rotation * inverseSqrt(quatNormSqr));
+  let tu = R[0] * (splat.scale.x * SURFEL_EXTENT);
+  let tv = R[1] * (splat.scale.y * SURFEL_EXTENT);
+  let localPos = splat.mean + tu * quadPos.x + tv * quadPos.y;
+
+  output.position = uniforms.proj * (modelViewMat * vec4<f32>(localPos, 1.0));
+  output.fragPos = quadPos;
+  output.color = splatColor(splatIndex, shadedColor(splatIndex, viewCenter, modelViewMat));
+  output.opacity = fadedOpacity;
+  output.splatIndex = splatIndex;
   return output;
 }
 
+fn surfelOpacity(input: VertexOutput) -> f32 {
+  // 盘面坐标，单位为 σ
+  let uv = input.fragPos * SURFEL_EXTENT;
+  let weight = exp(-0.5 * dot(uv, uv));
+  return weight * input.opacity;
+}
+
 @fragment
-fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-  if input.opacity <= 0.0 { discard; }
+fn fs_main_2d(input: VertexOutput) -> @location(0) vec4<f32> {
+  let opacity = surfelOpacity(input);
+  if opacity < ALPHA_CULL_THRESHOLD { discard; }
+
+  let color = max(input.color, vec3<f32>(0.0));
+  return vec4<f32>(color * opacity, opacity);
+}
+
+fn gaussianOpacity(input: VertexOutput) -> f32 {
+  if input.opacity <= 0.0 { return 0.0; }
   
   // A = 到中心的平方距离，在 UV 空间中
   // 由于 clipCorner 优化，fragPos 在 [-clip, clip] 范围内
   let A = dot(input.fragPos, input.fragPos);
   
-  // 丢弃单位圆外的片段
-  if A > 1.0 { discard; }
+  // 单位圆外的片段
+  if A > 1.0 { return 0.0; }
   
   // Normalized Gaussian 衰减 (精确匹配 SuperSplat normExp)
   // 关键修复: 在 A=1 (边界) 时返回精确的 0.0，消除边缘雾化
@@ -307,7 +720,12 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
   let weight = (exp(-4.0 * A) - EXP_NEG4) * INV_ONE_MINUS_EXP_NEG4;
   
   // 组合 splat 透明度
-  let opacity = weight * input.opacity;
+  return weight * input.opacity;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+  let opacity = gaussianOpacity(input);
   
   // Alpha 阈值丢弃 (匹配 SuperSplat: if (alpha < 1.0 / 255.0) discard)
   if opacity < ALPHA_CULL_THRESHOLD { discard; }
@@ -319,6 +737,155 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
   // 这是 3DGS 渲染的标准混合模式
   return vec4<f32>(color * opacity, opacity);
 }
+
+// ============================================
+// 随机透明度 (hashed alpha test)
+// 以 opacity 为概率保留片段并写入深度，不需要排序；噪声随 frameSeed 逐帧变化
+// ============================================
+
+fn pcgHash(v: u32) -> u32 {
+  let state = v * 747796405u + 2891336453u;
+  let word = ((state >> ((state >> 28u) + 4u)) ^ state) * 277803737u;
+  return (word >> 22u) ^ word;
+}
+
+fn stochasticThreshold(position: vec4<f32>, splatIndex: u32) -> f32 {
+  let pixel = vec2<u32>(position.xy);
+  let h = pcgHash(pixel.x ^ pcgHash(pixel.y ^ pcgHash(splatIndex ^ pcgHash(uniforms.frameSeed))));
+  return f32(h) / 4294967296.0;
+}
+
+@fragment
+fn fs_main_stochastic(input: VertexOutput) -> @location(0) vec4<f32> {
+  let opacity = gaussianOpacity(input);
+  if opacity < ALPHA_CULL_THRESHOLD || opacity <= stochasticThreshold(input.position, input.splatIndex) { discard; }
+  return vec4<f32>(max(input.color, vec3<f32>(0.0)), 1.0);
+}
+
+@fragment
+fn fs_main_2d_stochastic(input: VertexOutput) -> @location(0) vec4<f32> {
+  let opacity = surfelOpacity(input);
+  if opacity < ALPHA_CULL_THRESHOLD || opacity <= stochasticThreshold(input.position, input.splatIndex) { discard; }
+  return vec4<f32>(max(input.color, vec3<f32>(0.0)), 1.0);
+}
+
+// ============================================
+// 深度输出 (景深等后处理)
+// 不透明度超过阈值的片段写入 splat 深度，颜色不输出；
+// 在混合绘制之前执行，使深度纹理中的 splat 与网格一样可用于计算弥散圆
+// ============================================
+
+@fragment
+fn fs_depth(input: VertexOutput) -> @location(0) vec4<f32> {
+  if gaussianOpacity(input) < max(uniforms.depthAlpha, ALPHA_CULL_THRESHOLD) { discard; }
+  return vec4<f32>(0.0);
+}
+
+@fragment
+fn fs_depth_2d(input: VertexOutput) -> @location(0) vec4<f32> {
+  if surfelOpacity(input) < max(uniforms.depthAlpha, ALPHA_CULL_THRESHOLD) { discard; }
+  return vec4<f32>(0.0);
+}
+
+// ============================================
+// 点显示 (Points / Centers)
+// 每个 splat 绘制为屏幕空间圆点，直径按所在格子的每像素点数选择：
+// 格子内点面积之和约为 coverage 倍格子面积，稀疏处放大、密集处缩小
+// ============================================
+
+fn pointSize(pixel: vec2<f32>) -> f32 {
+  let minSize = uniforms.pointParams.x;
+  let maxSize = uniforms.pointParams.y;
+  let cellSize = uniforms.pointFlags.x;
+  if cellSize == 0u { return maxSize; }
+  let columns = (u32(uniforms.screenSize.x) + cellSize - 1u) / cellSize;
+  let cell = vec2<u32>(clamp(pixel, vec2<f32>(0.0), uniforms.screenSize - 1.0)) / cellSize;
+  let count = max(densityGrid[cell.y * columns + cell.x], 1u);
+  let pointsPerPixel = f32(count) / f32(cellSize * cellSize);
+  return clamp(2.0 * sqrt(uniforms.pointParams.z / (PI * pointsPerPixel)), minSize, maxSize);
+}
+
+@vertex
+fn vs_points(@builtin(vertex_index) vertexIndex: u32, @builtin(instance_index) instanceIndex: u32) -> VertexOutput {
+  var output: VertexOutput;
+  if instanceIndex >= uniforms.visibleCount { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
+  let splatIndex = sortedIndices[instanceIndex];
+  let splat = animatedSplat(splatIndex);
+  if splatOpacity(splatIndex, splat.opacity) < ALPHA_CULL_THRESHOLD { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
+
+  let modelViewMat = uniforms.view * uniforms.model;
+  let viewPos = modelViewMat * vec4<f32>(splat.mean, 1.0);
+  if viewPos.z >= 0.0 { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
+  let clipPos = uniforms.proj * viewPos;
+  let ndcPos = clipPos / clipPos.w;
+
+  let pixel = vec2<f32>(ndcPos.x * 0.5 + 0.5, 0.5 - ndcPos.y * 0.5) * uniforms.screenSize;
+  let quadPos = QUAD_POSITIONS[vertexIndex];
+  output.position = vec4<f32>(ndcPos.xy + quadPos * pointSize(pixel) / uniforms.screenSize, ndcPos.z, 1.0);
+  output.fragPos = quadPos;
+  if uniforms.pointFlags.y != 0u {
+    // 叠加在高斯渲染之上，使用固定颜色以便区分
+    output.color = select(CENTER_COLOR, SELECTION_COLOR, uniforms.hasSelection != 0u && (selection[splatIndex] & 1u) != 0u);
+  } else {
+    output.color = splatColor(splatIndex, shadedColor(splatIndex, viewPos, modelViewMat));
+  }
+  output.opacity = 1.0;
+  output.splatIndex = splatIndex;
+  return output;
+}
+
+@fragment
+fn fs_points(input: VertexOutput) -> @location(0) vec4<f32> {
+  // 圆点边缘约 1 像素抗锯齿
+  let r = length(input.fragPos);
+  let alpha = 1.0 - smoothstep(1.0 - fwidth(r), 1.0, r);
+  if alpha < ALPHA_CULL_THRESHOLD { discard; }
+  return vec4<f32>(max(input.color, vec3<f32>(0.0)) * alpha, alpha);
+}
+
+// ============================================
+// 拾取 (pick)
+// 输出 splatIndex + 1 (0 表示未命中) 和 NDC 深度，深度测试保留最近的命中
+// ============================================
+
+const PICK_ALPHA_THRESHOLD: f32 = 0.1;
+
+struct PickOutput {
+  @location(0) id: u32,
+  @location(1) depth: f32,
+}
+
+@fragment
+fn fs_pick(input: VertexOutput) -> PickOutput {
+  if gaussianOpacity(input) < PICK_ALPHA_THRESHOLD { discard; }
+  return PickOutput(input.splatIndex + 1u, input.position.z);
+}
+
+@fragment
+fn fs_pick_2d(input: VertexOutput) -> PickOutput {
+  if surfelOpacity(input) < PICK_ALPHA_THRESHOLD { discard; }
+  return PickOutput(input.splatIndex + 1u, input.position.z);
+}
+
+// 混合深度：输出 (视图空间距离 * α, 0, 0, α)，按与主 pass 相同的顺序预乘混合，
+// 结果 r / a 即按不透明度加权的深度
+fn linearSplatDepth(depth: f32) -> f32 {
+  return uniforms.proj[3][2] / (depth + uniforms.proj[2][2]);
+}
+
+@fragment
+fn fs_pick_blended_depth(input: VertexOutput) -> @location(0) vec4<f32> {
+  let opacity = gaussianOpacity(input);
+  if opacity < ALPHA_CULL_THRESHOLD { discard; }
+  return vec4<f32>(linearSplatDepth(input.position.z) * opacity, 0.0, 0.0, opacity);
+}
+
+@fragment
+fn fs_pick_blended_depth_2d(input: VertexOutput) -> @location(0) vec4<f32> {
+  let opacity = surfelOpacity(input);
+  if opacity < ALPHA_CULL_THRESHOLD { discard; }
+  return vec4<f32>(linearSplatDepth(input.position.z) * opacity, 0.0, 0.0, opacity);
+}
 `;
 
 // 重新导出类型保持向后兼容
@@ -327,6 +894,28 @@ export type { BoundingBox };
 
 const SPLAT_BYTE_SIZE = 256;
 const SPLAT_FLOAT_COUNT = 64;
+// uniforms.visibleCount 的偏移（由排序器在 GPU 上写入，CPU 不写）
+const VISIBLE_COUNT_OFFSET = 336;
+const SWAY_OFFSET = 352;
+const COLOR_ADJUST_OFFSET = 400;
+const APPEARANCE_OFFSET = 432;
+const OPACITY_EDIT_OFFSET = 448;
+const CROP_BOX_OFFSET = 464;
+const GROUP_TINTS_OFFSET = 544;
+const GROUP_PARAMS_OFFSET = 800;
+const HOVER_OFFSET = 816;
+// 混合深度查询的最小累计不透明度，低于该值视为未命中
+const MIN_DEPTH_COVERAGE = 0.05;
+
+/** 悬停高亮的默认强度（向白色混合的比例） */
+export const DEFAULT_SPLAT_HOVER_STRENGTH = 0.35;
+
+// 悬停高亮状态
+interface SplatHover {
+  index: number;
+  group: boolean;
+  strength: number;
+}
 
 /**
  * GSSplatRendererV2 - 优化的渲染器
@@ -336,16 +925,98 @@ export class GSSplatRenderer implements IGSSplatRendererWithCapabilities {
   private camera: Camera;
 
   private pipeline!: GPURenderPipeline;
+  // 2DGS (surfel) 管线变体
+  private pipeline2DGS!: GPURenderPipeline;
+  private is2DGS: boolean = false;
+  // 随机透明度管线变体 (无需排序)
+  private pipelineStochastic!: GPURenderPipeline;
+  private pipeline2DGSStochastic!: GPURenderPipeline;
+  private frameSeed: number = 0;
+  // 混合模式及其管线变体 (key: blendMode:2d|3d)
+  private blendMode: SplatBlendMode = SplatBlendMode.Standard;
+  private variantPipelines: Map<string, GPURenderPipeline> = new Map();
+  // 点显示管线 (Points / Centers)
+  private pipelinePoints!: GPURenderPipeline;
+  // 深度输出管线（不透明度超过阈值的片段写入深度），0 表示关闭
+  private pipelineDepth!: GPURenderPipeline;
+  private pipeline2DGSDepth!: GPURenderPipeline;
+  private depthWriteAlpha: number = 0;
+  private pointMode: SplatPointMode = SplatPointMode.Off;
+  private pointSize: SplatPointSizeConfig = { ...DEFAULT_SPLAT_POINT_SIZE_CONFIG };
+  // 中心点叠加的子采样（按需创建过滤器），光标为 null 表示不在画布上
+  private centersConfig: SplatCentersConfig = { ...DEFAULT_SPLAT_CENTERS_CONFIG };
+  private centersCursor: [number, number] | null = null;
+  private centersFilter: SplatCentersFilter | null = null;
+  // 多边形（套索）选择（按需创建）
+  private polygonSelector: SplatPolygonSelector | null = null;
+  // 属性阈值筛选（按需创建）
+  private attributeSelector: SplatAttributeSelector | null = null;
+  // 外部 GPU buffer 打包器（数据来自 setExternalBuffers 时存在）
+  private externalPacker: SplatExternalPacker | null = null;
+  private centersBindGroup: GPUBindGroup | null = null;
+  // 选择包围盒（模型空间，选择版本变化后重新计算）
+  private selectionBounds: { min: Vec3Tuple; max: Vec3Tuple } | null = null;
+  private selectionBoundsVersion: number = -1;
+  // 已上传到 selectionBuffer 的选择版本，-1 表示需要上传
+  private selectionUploadVersion: number = -1;
+  private shaderModule!: GPUShaderModule;
+  private pipelineLayout!: GPUPipelineLayout;
+  // 拾取管线 (按需创建)
+  private pickPipeline: GPURenderPipeline | null = null;
+  private pickPipeline2DGS: GPURenderPipeline | null = null;
+  // 混合深度查询管线 (按需创建)；拾取和混合深度的渲染目标按设备共享（SplatPickTargets）
+  private blendedDepthPipeline: GPURenderPipeline | null = null;
+  private blendedDepthPipeline2DGS: GPURenderPipeline | null = null;
   private bindGroupLayout!: GPUBindGroupLayout;
   private uniformBuffer!: GPUBuffer;
 
   private splatBuffer: GPUBuffer | null = null;
   private splatCount: number = 0;
   private bindGroup: GPUBindGroup | null = null;
+  // bind group 中绑定的密度格子 buffer（排序器重建格子后需要重建 bind group）
+  private boundDensityGrid: GPUBuffer | null = null;
+  // CPU 端数据（逐 splat 编辑后按脏区间重新上传）
+  private splats: GaussianSplats | null = null;
 
   private sorter: GSSplatSorter | null = null;
+  // compute tile 光栅化（按需创建）
+  private rasterMode: SplatRasterMode = SplatRasterMode.Quad;
+  private tileRasterizer: GSSplatTileRasterizer | null = null;
+  // 每像素最多合成的 splat 数量（仅 tile 模式生效，0 表示不限制）
+  private maxSplatsPerPixel: number = 0;
   private shMode: SHMode = SHMode.L0;
+  // 超过该距离依次去掉 L3 / L2 / L1 SH，0 表示不限制
+  private shDistances: Vec3Tuple = [0, 0, 0];
   private boundingBox: BoundingBox | null = null;
+  // 位置相对的原点（f64）
+  private origin: Vec3Tuple = [0, 0, 0];
+
+  // 语义标签 / 选择 / 可视化
+  private labels: SplatLabels | null = null;
+  private labelBuffer: GPUBuffer | null = null;
+  private selection: SplatSelection | null = null;
+  private selectionBuffer: GPUBuffer | null = null;
+  // 已删除（不透明度置零、等待压缩）的 splat
+  private deleted: Uint8Array | null = null;
+  private deletedCount: number = 0;
+  private compactionThreshold: number = 0.25;
+  private compactor: SplatCompactor | null = null;
+  // 可选 storage buffer 未设置时绑定的占位 buffer
+  private placeholderBuffer!: GPUBuffer;
+  private visMode: SplatVisMode = SplatVisMode.Color;
+
+  // 标量属性可视化
+  private scalars: Float32Array | null = null;
+  private scalarBuffer: GPUBuffer | null = null;
+  // 外部标量 buffer（训练统计等，由调用方持有，不销毁），存在时 scalarBuffer 指向它
+  private externalScalar: SplatExternalBuffer | null = null;
+  private scalarRange: [number, number] = [0, 1];
+  private colormap: Colormap = Colormap.Viridis;
+  // 体渲染传递函数
+  private transferFunctionTexture!: GPUTexture;
+  private volumeOpacity: number = 1.0;
+  // 整体不透明度倍数
+  private opacityScale: number = 1.0;
 
   // Transform
   private position: Vec3Tuple = [0, 0, 0];
@@ -353,9 +1024,74 @@ export class GSSplatRenderer implements IGSSplatRendererWithCapabilities {
   private scale: Vec3Tuple = [1, 1, 1];
   private pivot: Vec3Tuple = [0, 0, 0];
   private modelMatrix: Float32Array = new Float32Array(16);
+  // 父级（图层）变换，模型矩阵 = parent * local
+  private parentMatrix: Float32Array | null = null;
+  // 变换检查（零缩放 / 剪切）
+  private autoCorrectTransform: boolean = false;
+  private transformIssues: TransformIssue[] = [];
+  // 相机相对渲染（消除远离原点时的 f32 抖动）
+  private cameraRelative: boolean = true;
+  // 曝光倍数（HDR 输出时颜色可超过 1.0）
+  private exposure: number = 1.0;
 
   // 剔除选项
   private pixelCullThreshold: number = 1.0;
+  // splat 屏幕轴长上限（像素），0 不限制
+  private maxSplatSize: number = 0;
+  // 摆动动画
+  private swayConfig: SplatSwayConfig = { ...DEFAULT_SPLAT_SWAY_CONFIG };
+  private swayStartTime: number = performance.now();
+  private swayUniforms: Float32Array = new Float32Array(12);
+  // 选中 splat 的颜色调整预览，null 表示关闭
+  private colorAdjustPreview: SplatColorAdjustment | null = null;
+  private opacityEditPreview: SplatOpacityEdit | null = null;
+  private opacityEditUniforms: Float32Array = new Float32Array(4);
+  // 软裁剪盒（每帧读取，拖动时直接修改盒子即可），null 表示不裁剪
+  private cropBox: SplatCropBox | null = null;
+  private cropBoxUniforms: Float32Array = new Float32Array(20);
+  // splat 分组（与选中状态打包上传），数据替换后清除
+  private groups: SplatGroups | null = null;
+  private groupUniforms: Float32Array = new Float32Array(64);
+  // 已上传到 selectionBuffer 的分组版本
+  private groupsUploadVersion: number = -1;
+  // 悬停高亮，null 表示关闭；数据替换后清除
+  private hover: SplatHover | null = null;
+  private hoverUniforms: Uint32Array = new Uint32Array(4);
+  private hoverUniformsF32: Float32Array = new Float32Array(this.hoverUniforms.buffer);
+  private colorAdjustUniforms: Float32Array = new Float32Array(8);
+  // 第二套外观（打包后的 DC + SH）及混合权重
+  private appearance: Float32Array | null = null;
+  private appearanceBuffer: GPUBuffer | null = null;
+  private appearanceBlend: number = 0;
+  // 八叉树分块剔除（加载时构建）
+  private octree: SplatOctree | null = null;
+  private chunkedCulling: boolean = true;
+  // 分块可见性预计算（八叉树重建后失效）
+  private pvs: SplatPVS | null = null;
+  // 可见数量回读间隔（排序次数，0 关闭）
+  private visibleCountInterval: number = 0;
+  // 不使用 indirect 绘制（按回读的可见数量直接绘制，跟随 Renderer.indirectDraw）
+  private directDraw: boolean = false;
+  // Hi-Z 遮挡剔除（使用上一帧深度）
+  private occlusionCulling: boolean = false;
+  // 相机贴近 / 位于 splat 内部时的处理
+  private cameraInsideFade: boolean = true;
+  private cameraInsideCull: boolean = false;
+  private nearFadeDistance: number = 0;
+  // LOD（依赖八叉树分块），null 表示关闭
+  private lodConfig: SplatLodConfig | null = null;
+  // 相机 / 模型移动时的分摊排序：每 n 帧一次全精度排序，其余帧 16 位排序，0 表示关闭
+  private sortAmortizationInterval: number = 0;
+  private motionFrameCount: number = 0;
+  private lastSortView: Float32Array = new Float32Array(16);
+  private lastSortModel: Float32Array = new Float32Array(16);
+  // 深度排序键模式
+  private depthKeyMode: SplatDepthKeyMode = SplatDepthKeyMode.Float;
+  // 调试：逐帧 pass / buffer 访问校验，null 表示关闭
+  private passTracker: SplatPassTracker | null = null;
+  // 排序结果复用：本实体的排序输入和数据都没有变化时跳过剔除和排序
+  private sortCache: SplatSortCache = new SplatSortCache();
+  private sortKey: Float64Array = new Float64Array(59);
 
   constructor(renderer: Renderer, camera: Camera) {
     this.renderer = renderer;
@@ -366,48 +1102,61 @@ export class GSSplatRenderer implements IGSSplatRendererWithCapabilities {
   }
 
   private createPipeline(): void {
-    const device = this.renderer.device;
+    const pipelines = PipelineCache.get(this.renderer.device);
 
-    const shaderModule = device.createShaderModule({
+    this.shaderModule = pipelines.getShaderModule({
       code: gsOptimizedShader,
     });
 
-    this.bindGroupLayout = device.createBindGroupLayout({
+    this.bindGroupLayout = pipelines.getBindGroupLayout({
       entries: [
-        { binding: 0, visibility: GPUShaderStage.VERTEX | GPUShaderStage.FRAGMENT, buffer: { type: "uniform" } },
-        { binding: 1, visibility: GPUShaderStage.VERTEX, buffer: { type: "read-only-storage" } },
-        { binding: 2, visibility: GPUShaderStage.VERTEX, buffer: { type: "read-only-storage" } },
+        { binding: 0, visibility: GPUShaderStage.VERTEX | GPUShaderStage.FRAGMENT | GPUShaderStage.COMPUTE, buffer: { type: "uniform" } },
+        { binding: 1, visibility: GPUShaderStage.VERTEX | GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 2, visibility: GPUShaderStage.VERTEX | GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 3, visibility: GPUShaderStage.VERTEX | GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 4, visibility: GPUShaderStage.VERTEX | GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 5, visibility: GPUShaderStage.VERTEX | GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 6, visibility: GPUShaderStage.VERTEX | GPUShaderStage.COMPUTE, texture: { sampleType: "float" } },
+        { binding: 7, visibility: GPUShaderStage.VERTEX | GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 8, visibility: GPUShaderStage.VERTEX | GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
       ],
     });
 
-    const pipelineLayout = device.createPipelineLayout({
-      bindGroupLayouts: [this.bindGroupLayout],
-    });
+    this.pipelineLayout = pipelines.getPipelineLayout([this.bindGroupLayout]);
 
-    this.pipeline = device.createRenderPipeline({
-      layout: pipelineLayout,
+    this.pipeline = this.createPipelineVariant("vs_main", "fs_main");
+    this.pipeline2DGS = this.createPipelineVariant("vs_main_2d", "fs_main_2d");
+    this.pipelineStochastic = this.createPipelineVariant("vs_main", "fs_main_stochastic", true);
+    this.pipeline2DGSStochastic = this.createPipelineVariant("vs_main_2d", "fs_main_2d_stochastic", true);
+    this.pipelinePoints = this.createPipelineVariant("vs_points", "fs_points");
+    this.pipelineDepth = this.createDepthPipeline("vs_main", "fs_depth");
+    this.pipeline2DGSDepth = this.createDepthPipeline("vs_main_2d", "fs_depth_2d");
+  }
+
+  /**
+   * 创建管线变体
+   * stochastic 变体不混合、写入深度，依赖深度测试代替排序
+   */
+  private createPipelineVariant(
+    vertexEntry: string,
+    fragmentEntry: string,
+    stochastic: boolean = false,
+    blendMode: SplatBlendMode = SplatBlendMode.Standard,
+    module: GPUShaderModule = this.shaderModule,
+  ): GPURenderPipeline {
+    return PipelineCache.get(this.renderer.device).getRenderPipeline({
+      layout: this.pipelineLayout,
       vertex: {
-        module: shaderModule,
-        entryPoint: "vs_main",
+        module,
+        entryPoint: vertexEntry,
         buffers: [],
       },
       fragment: {
-        module: shaderModule,
-        entryPoint: "fs_main",
+        module,
+        entryPoint: fragmentEntry,
         targets: [{
           format: this.renderer.format,
-          blend: {
-            color: {
-              srcFactor: "one",
-              dstFactor: "one-minus-src-alpha",
-              operation: "add",
-            },
-            alpha: {
-              srcFactor: "one",
-              dstFactor: "one-minus-src-alpha",
-              operation: "add",
-            },
-          },
+          blend: stochastic ? undefined : getSplatBlendState(blendMode),
         }],
       },
       primitive: {
@@ -415,18 +1164,112 @@ export class GSSplatRenderer implements IGSSplatRendererWithCapabilities {
       },
       depthStencil: {
         format: this.renderer.depthFormat,
-        depthWriteEnabled: false,
-        depthCompare: "always",
+        depthWriteEnabled: stochastic,
+        depthCompare: stochastic ? getDepthCompare(this.renderer.depthConfig) : "always",
+      },
+    });
+  }
+
+  /**
+   * 创建深度输出管线（不写颜色，按深度约定测试并写入深度）
+   */
+  private createDepthPipeline(vertexEntry: string, fragmentEntry: string): GPURenderPipeline {
+    return PipelineCache.get(this.renderer.device).getRenderPipeline({
+      layout: this.pipelineLayout,
+      vertex: {
+        module: this.shaderModule,
+        entryPoint: vertexEntry,
+        buffers: [],
+      },
+      fragment: {
+        module: this.shaderModule,
+        entryPoint: fragmentEntry,
+        targets: [{ format: this.renderer.format, writeMask: 0 }],
+      },
+      primitive: {
+        topology: "triangle-strip",
+      },
+      depthStencil: {
+        format: this.renderer.depthFormat,
+        depthWriteEnabled: true,
+        depthCompare: getDepthCompare(this.renderer.depthConfig),
       },
     });
   }
 
+  /**
+   * 获取当前混合模式和可视化模式的颜色管线
+   * 标准混合 + 内置可视化模式直接使用预建管线；非标准混合模式和自定义可视化模式按需创建并缓存
+   */
+  private getColorPipeline(kind: "quad" | "stochastic" | "points"): GPURenderPipeline {
+    const visMode = findSplatVisMode(this.visMode);
+    const blendMode = kind === "quad" ? this.blendMode : SplatBlendMode.Standard;
+    if (!visMode && blendMode === SplatBlendMode.Standard) {
+      if (kind === "points") return this.pipelinePoints;
+      if (kind === "stochastic") return this.is2DGS ? this.pipeline2DGSStochastic : this.pipelineStochastic;
+      return this.is2DGS ? this.pipeline2DGS : this.pipeline;
+    }
+    const key = `${visMode?.pipelineKey ?? ""}:${kind}:${blendMode}:${this.is2DGS ? "2d" : "3d"}`;
+    let pipeline = this.variantPipelines.get(key);
+    if (!pipeline) {
+      const module = visMode
+        ? PipelineCache.get(this.renderer.device).getShaderModule({ code: buildSplatVisModeShader(gsOptimizedShader, visMode) })
+        : this.shaderModule;
+      const suffix = this.is2DGS ? "_2d" : "";
+      pipeline = kind === "points"
+        ? this.createPipelineVariant("vs_points", "fs_points", false, blendMode, module)
+        : this.createPipelineVariant(
+          `vs_main${suffix}`,
+          kind === "stochastic" ? `fs_main${suffix}_stochastic` : `fs_main${suffix}`,
+          kind === "stochastic",
+          blendMode,
+          module,
+        );
+      this.variantPipelines.set(key, pipeline);
+    }
+    return pipeline;
+  }
+
   private createUniformBuffer(): void {
-    // view (64) + proj (64) + model (64) + cameraPos (12) + pad (4) + screenSize (8) + pad (8) = 224
+    // view (64) + proj (64) + model (64) + cameraPos (12) + pad (4) + screenSize (8) + maxSplatSize (4) + pad (4)
+    // + visMode/hasLabels/hasSelection/colormap (16) + scalarRange/hasScalar/frameSeed (16)
+    // + exposure/volumeOpacity/nearPlane/nearFade (16) + insideFade/shDegree/depthAlpha/opacityScale (16) + shDistances (16)
+    // + pointParams (16) + pointFlags (16) + visibleCount (4 + pad 12) + swayWind/swayParams/swayNoise (48)
+    // + colorTint/colorAdjust (32) + appearance (16) + opacityEdit (16) + cropBox (64) + cropParams (16)
+    // + groupTints (256) + groupParams (16) + hover (16) = 832
     this.uniformBuffer = this.renderer.device.createBuffer({
-      size: 224,
+      size: 832,
       usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+      label: "splat-uniforms",
     });
+
+    this.placeholderBuffer = this.renderer.device.createBuffer({
+      size: 16,
+      usage: GPUBufferUsage.STORAGE,
+    });
+
+    this.writeTransferFunction(createTransferFunction(DEFAULT_TRANSFER_FUNCTION));
+  }
+
+  /**
+   * 上传传递函数查找表（尺寸变化时重建纹理）
+   */
+  private writeTransferFunction(table: Uint8Array): void {
+    const width = table.length / 4;
+    if (!this.transferFunctionTexture || this.transferFunctionTexture.width !== width) {
+      this.transferFunctionTexture?.destroy();
+      this.transferFunctionTexture = this.renderer.device.createTexture({
+        size: { width, height: 1 },
+        format: "rgba8unorm",
+        usage: GPUTextureUsage.TEXTURE_BINDING | GPUTextureUsage.COPY_DST,
+      });
+    }
+    this.renderer.device.queue.writeTexture(
+      { texture: this.transferFunctionTexture },
+      table,
+      { bytesPerRow: width * 4 },
+      { width, height: 1 },
+    );
   }
 
   setPosition(x: number, y: number, z: number): void {
@@ -501,12 +1344,79 @@ export class GSSplatRenderer implements IGSSplatRendererWithCapabilities {
     this.modelMatrix[4] = rs01; this.modelMatrix[5] = rs11; this.modelMatrix[6] = rs21; this.modelMatrix[7] = 0;
     this.modelMatrix[8] = rs02; this.modelMatrix[9] = rs12; this.modelMatrix[10] = rs22; this.modelMatrix[11] = 0;
     this.modelMatrix[12] = finalTx; this.modelMatrix[13] = finalTy; this.modelMatrix[14] = finalTz; this.modelMatrix[15] = 1;
+
+    if (this.parentMatrix) {
+      multiplyTransforms(this.parentMatrix, this.modelMatrix, this.modelMatrix);
+    }
+
+    // 自身的 TRS 不会产生剪切，只在组合了父级矩阵时检查
+    this.transformIssues = validateTransform(
+      this.modelMatrix, "splat", this.autoCorrectTransform, this.transformIssues, this.parentMatrix !== null,
+    );
   }
 
   getModelMatrix(): Float32Array {
     return this.modelMatrix;
   }
 
+  /**
+   * 设置父级变换（列主序），null 表示没有父级
+   */
+  setParentMatrix(matrix: Float32Array | null): void {
+    this.parentMatrix = matrix ? new Float32Array(matrix) : null;
+    this.updateModelMatrix();
+  }
+
+  /**
+   * 获取父级变换
+   */
+  getParentMatrix(): Float32Array | null {
+    return this.parentMatrix;
+  }
+
+  /**
+   * 设置是否自动修正有问题的模型矩阵（非有限值、父级变换带来的剪切；零缩放只警告）
+   */
+  setTransformAutoCorrect(enabled: boolean): void {
+    this.autoCorrectTransform = enabled;
+    this.updateModelMatrix();
+  }
+
+  /**
+   * 获取当前模型矩阵检测到的问题（修正前）
+   */
+  getTransformIssues(): TransformIssue[] {
+    return [...this.transformIssues];
+  }
+
+  /**
+   * 设置是否使用相机相对渲染（默认开启）
+   */
+  setCameraRelative(enabled: boolean): void {
+    this.cameraRelative = enabled;
+  }
+
+  /**
+   * 是否使用相机相对渲染
+   */
+  isCameraRelative(): boolean {
+    return this.cameraRelative;
+  }
+
+  /**
+   * 设置曝光倍数
+   */
+  setExposure(exposure: number): void {
+    this.exposure = Math.max(0, exposure);
+  }
+
+  /**
+   * 获取曝光倍数
+   */
+  getExposure(): number {
+    return this.exposure;
+  }
+
   setSHMode(mode: SHMode): void {
     this.shMode = mode;
   }
@@ -515,60 +1425,497 @@ export class GSSplatRenderer implements IGSSplatRendererWithCapabilities {
     return this.shMode;
   }
 
+  /**
+   * 设置按距离降低 SH 阶数的阈值 [去掉 L3, 去掉 L2, 去掉 L1]（相机到 splat 的距离），0 表示不限制
+   * 室外大场景中远处 splat 的高阶 SH 几乎不可见，去掉可以显著减少顶点着色器计算
+   */
+  setSHDistances(distances: Vec3Tuple | null): void {
+    this.shDistances = distances ? [...distances] : [0, 0, 0];
+  }
+
+  getSHDistances(): Vec3Tuple {
+    return [...this.shDistances];
+  }
+
   setPixelCullThreshold(threshold: number): void {
     this.pixelCullThreshold = threshold;
   }
 
-  setData(splats: SplatCPU[]): void {
-    const device = this.renderer.device;
+  getPixelCullThreshold(): number {
+    return this.pixelCullThreshold;
+  }
 
-    if (this.splatBuffer) {
-      this.splatBuffer.destroy();
+  /**
+   * 设置 splat 屏幕轴长上限（像素），限制贴近相机的大 splat 的填充率开销，0 不限制
+   */
+  setMaxSplatSize(size: number): void {
+    this.maxSplatSize = Math.max(0, size);
+  }
+
+  getMaxSplatSize(): number {
+    return this.maxSplatSize;
+  }
+
+  /**
+   * 设置摆动动画（风吹植被），在顶点着色器中按时间偏移 splat 中心
+   */
+  setSway(config: Partial<SplatSwayConfig>): void {
+    const wasEnabled = this.swayConfig.enabled;
+    this.swayConfig = { ...this.swayConfig, ...config };
+    if (this.swayConfig.enabled && !wasEnabled) {
+      this.swayStartTime = performance.now();
     }
-    if (this.sorter) {
-      this.sorter.destroy();
-      this.sorter = null;
+  }
+
+  getSway(): SplatSwayConfig {
+    return { ...this.swayConfig, direction: [...this.swayConfig.direction] };
+  }
+
+  /**
+   * 设置第二套外观（同一几何的另一次采集，splat 一一对应，只使用颜色和 SH），null 清除
+   * 之后用 setAppearanceBlend 在两套外观之间插值；压缩删除时保留，替换数据后清除
+   */
+  setSecondaryAppearance(data: SplatAppearanceData | null): void {
+    if (data && data.count !== this.splatCount) {
+      throw new Error(`外观数量 (${data.count}) 与 splat 数量 (${this.splatCount}) 不一致`);
     }
+    this.setPackedAppearance(data && data.count > 0 ? packSplatAppearance(data) : null);
+  }
 
-    this.splatCount = splats.length;
+  /**
+   * 是否设置了第二套外观
+   */
+  hasSecondaryAppearance(): boolean {
+    return this.appearance !== null;
+  }
 
-    if (this.splatCount === 0) {
-      this.splatBuffer = null;
-      this.bindGroup = null;
-      this.boundingBox = null;
-      return;
+  /**
+   * 设置第二套外观的混合权重（0 为原始外观，1 为第二套外观）
+   */
+  setAppearanceBlend(t: number): void {
+    this.appearanceBlend = Math.min(1, Math.max(0, t));
+  }
+
+  getAppearanceBlend(): number {
+    return this.appearanceBlend;
+  }
+
+  private setPackedAppearance(packed: Float32Array | null): void {
+    if (this.appearanceBuffer) {
+      this.appearanceBuffer.destroy();
+      this.appearanceBuffer = null;
     }
+    this.appearance = packed;
+    if (packed) {
+      this.appearanceBuffer = this.renderer.device.createBuffer({
+        size: packed.byteLength,
+        usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+        label: "splat-appearance",
+      });
+      this.renderer.device.queue.writeBuffer(this.appearanceBuffer, 0, packed);
+    }
+    this.createBindGroup();
+  }
 
-    this.boundingBox = this.computeBoundingBox(splats);
+  /**
+   * 设置选中 splat 的颜色调整预览（只在 shader 中生效，不修改数据），null 关闭
+   * 预览期间选中的 splat 不显示高亮
+   */
+  setColorAdjustPreview(adjustment: SplatColorAdjustment | null): void {
+    this.colorAdjustPreview = adjustment ? { ...adjustment, tint: [...adjustment.tint] } : null;
+  }
 
-    const data = new Float32Array(this.splatCount * SPLAT_FLOAT_COUNT);
+  getColorAdjustPreview(): SplatColorAdjustment | null {
+    return this.colorAdjustPreview ? { ...this.colorAdjustPreview, tint: [...this.colorAdjustPreview.tint] } : null;
+  }
 
-    for (let i = 0; i < this.splatCount; i++) {
-      const splat = splats[i];
-      const offset = i * SPLAT_FLOAT_COUNT;
+  /**
+   * 设置选中 splat 的不透明度编辑预览（只在 shader 中生效，不修改数据），null 关闭
+   * 只影响绘制，剔除和排序仍按原不透明度
+   */
+  setOpacityEditPreview(edit: SplatOpacityEdit | null): void {
+    this.opacityEditPreview = edit ? { ...edit } : null;
+  }
 
-      data[offset + 0] = splat.mean[0];
-      data[offset + 1] = splat.mean[1];
-      data[offset + 2] = splat.mean[2];
-      data[offset + 3] = 0;
+  getOpacityEditPreview(): SplatOpacityEdit | null {
+    return this.opacityEditPreview ? { ...this.opacityEditPreview } : null;
+  }
 
-      data[offset + 4] = splat.scale[0];
-      data[offset + 5] = splat.scale[1];
-      data[offset + 6] = splat.scale[2];
-      data[offset + 7] = 0;
+  /**
+   * 设置软裁剪盒（只在 shader 中生效，不修改数据），null 关闭
+   * 保存的是引用，之后修改盒子在下一帧生效；只影响绘制和拾取，排序仍包含被裁掉的 splat
+   */
+  setCropBox(cropBox: SplatCropBox | null): void {
+    this.cropBox = cropBox;
+  }
 
-      data[offset + 8] = splat.rotation[0];
-      data[offset + 9] = splat.rotation[1];
-      data[offset + 10] = splat.rotation[2];
-      data[offset + 11] = splat.rotation[3];
+  getCropBox(): SplatCropBox | null {
+    return this.cropBox;
+  }
 
-      data[offset + 12] = splat.colorDC[0];
-      data[offset + 13] = splat.colorDC[1];
-      data[offset + 14] = splat.colorDC[2];
-      data[offset + 15] = splat.opacity;
+  /**
+   * 设置 splat 分组（数量必须与 splat 数量一致），null 清除
+   * 保存的是引用，之后修改分组在下一帧生效；替换数据后分组被清除，压缩时按相同顺序收集
+   */
+  setGroups(groups: SplatGroups | null): void {
+    if (groups && groups.count !== this.splatCount) {
+      throw new Error(`分组数量 (${groups.count}) 与 splat 数量 (${this.splatCount}) 不一致`);
+    }
+    this.groups = groups;
+    this.selectionUploadVersion = -1;
+  }
 
-      const shRest = splat.shRest;
-      for (let j = 0; j < 9; j++) {
+  getGroups(): SplatGroups | null {
+    return this.groups;
+  }
+
+  /**
+   * 设置悬停高亮的 splat，null 清除
+   * group 为 true 时高亮该 splat 所在分组的全部 splat；strength 为向白色混合的比例 (0~1)
+   */
+  setHoveredSplat(index: number | null, group: boolean = false, strength: number = DEFAULT_SPLAT_HOVER_STRENGTH): void {
+    this.hover = index === null || index < 0 || index >= this.splatCount
+      ? null
+      : { index, group, strength: Math.min(Math.max(strength, 0), 1) };
+  }
+
+  getHoveredSplat(): number | null {
+    return this.hover?.index ?? null;
+  }
+
+  // hover uniform：(模式, 索引, 分组 id, 强度位)
+  private getHoverUniforms(): Uint32Array {
+    const data = this.hoverUniforms;
+    const hover = this.hover;
+    if (!hover) {
+      data.fill(0);
+      return data;
+    }
+    data[0] = hover.group ? 2 : 1;
+    data[1] = hover.index;
+    data[2] = hover.group ? this.groups?.getGroupId(hover.index) ?? 0 : 0;
+    this.hoverUniformsF32[3] = hover.strength;
+    return data;
+  }
+
+  /**
+   * 设置是否启用八叉树分块剔除
+   * 启用时每帧先在 CPU 上剔除整个 chunk，GPU 只对可见 chunk 内的 splat 做逐点剔除和排序
+   */
+  setChunkedCulling(enabled: boolean): void {
+    this.chunkedCulling = enabled;
+    this.applyChunkedCulling();
+  }
+
+  /**
+   * 是否启用八叉树分块剔除
+   */
+  isChunkedCulling(): boolean {
+    return this.chunkedCulling;
+  }
+
+  /**
+   * 获取上一帧通过分块剔除的 splat 数量
+   */
+  getChunkVisibleCount(): number {
+    return this.sorter?.getChunkCulledCount() ?? 0;
+  }
+
+  /**
+   * 设置可见数量回读间隔（排序次数，0 关闭），用于诊断统计
+   */
+  setVisibleCountReadback(interval: number): void {
+    this.visibleCountInterval = Math.max(0, Math.floor(interval));
+    this.applyVisibleCountReadback();
+  }
+
+  // 直接绘制需要每次排序都回读可见数量
+  private applyVisibleCountReadback(): void {
+    this.sorter?.setVisibleCountReadback(this.directDraw ? 1 : this.visibleCountInterval);
+  }
+
+  /**
+   * 最近一次回读的可见 splat 数量（未开启回读或尚未读回时为 -1）
+   */
+  getVisibleCount(): number {
+    return this.sorter?.getVisibleCount() ?? -1;
+  }
+
+  /**
+   * 设置分块可见性预计算结果（null 关闭），需要启用分块剔除
+   */
+  setPVS(pvs: SplatPVS | null): void {
+    this.pvs = pvs;
+    this.sortCache.invalidate();
+  }
+
+  /**
+   * 获取分块可见性预计算结果（八叉树已重建时返回 null）
+   */
+  getPVS(): SplatPVS | null {
+    return this.pvs && this.pvs.octree === this.octree ? this.pvs : null;
+  }
+
+  /**
+   * 按当前八叉树预计算分块可见性并启用
+   */
+  async buildPVS(config: Partial<SplatPVSConfig> = {}): Promise<SplatPVS> {
+    if (!this.octree || !this.splats) {
+      throw new Error("[GSSplatRenderer] 没有八叉树分块，无法预计算可见性");
+    }
+    const pvs = await buildSplatPVS(this.octree, this.splats.getData(), config);
+    this.setPVS(pvs);
+    return pvs;
+  }
+
+  /**
+   * 关闭分块可见性预计算
+   */
+  clearPVS(): void {
+    this.setPVS(null);
+  }
+
+  private applyChunkedCulling(): void {
+    this.sorter?.setOctree(this.chunkedCulling ? this.octree : null);
+    this.sorter?.setLodConfig(this.lodConfig);
+    this.sortCache.markDataUpdated();
+  }
+
+  /**
+   * 设置 LOD 配置，null 关闭
+   * 按 chunk 投影尺寸截取其中最重要的一部分 splat，并受全局预算限制；需要启用分块剔除
+   */
+  setLodConfig(config: Partial<SplatLodConfig> | null): void {
+    this.lodConfig = config ? { ...DEFAULT_SPLAT_LOD_CONFIG, ...config } : null;
+    this.sorter?.setLodConfig(this.lodConfig);
+    this.sortCache.markDataUpdated();
+  }
+
+  /**
+   * 获取 LOD 配置，未启用时返回 null
+   */
+  getLodConfig(): SplatLodConfig | null {
+    return this.lodConfig ? { ...this.lodConfig } : null;
+  }
+
+  /**
+   * 设置是否启用遮挡剔除
+   * 排序前用上一帧的深度构建 Hi-Z，剔除被网格完全挡住的 splat；
+   * 深度晚一帧，相机快速移动时新露出的 splat 会延迟一帧出现
+   */
+  setOcclusionCulling(enabled: boolean): void {
+    this.occlusionCulling = enabled;
+  }
+
+  /**
+   * 是否启用遮挡剔除
+   */
+  isOcclusionCulling(): boolean {
+    return this.occlusionCulling;
+  }
+
+  /**
+   * 设置移动时的分摊排序
+   * 相机或模型移动期间只排序深度键的高 16 位（radix sort 4 个 pass 减为 2 个），
+   * 每 interval 帧做一次全精度排序；静止后立即恢复全精度。0 关闭
+   */
+  setSortAmortization(interval: number): void {
+    this.sortAmortizationInterval = Math.max(0, Math.floor(interval));
+    this.motionFrameCount = 0;
+  }
+
+  /**
+   * 获取分摊排序的全精度间隔（0 表示关闭）
+   */
+  getSortAmortization(): number {
+    return this.sortAmortizationInterval;
+  }
+
+  /**
+   * 设置深度排序键模式
+   * Stable64 额外按 splat 索引预排序（2~4 个 radix pass），消除远处深度键相同的 splat 逐帧交换顺序造成的闪烁
+   */
+  setDepthKeyMode(mode: SplatDepthKeyMode): void {
+    this.depthKeyMode = mode;
+  }
+
+  /**
+   * 获取深度排序键模式
+   */
+  getDepthKeyMode(): SplatDepthKeyMode {
+    return this.depthKeyMode;
+  }
+
+  /**
+   * 设置是否启用 pass 顺序校验（调试用）
+   * 记录每帧 cull / sort / draw / pick 对 splat buffer 的读写，顺序异常时 console.warn
+   */
+  setPassValidation(enabled: boolean): void {
+    this.passTracker = enabled ? (this.passTracker ?? new SplatPassTracker()) : null;
+    this.sorter?.setPassTracker(this.passTracker);
+  }
+
+  /**
+   * 是否启用 pass 顺序校验
+   */
+  isPassValidation(): boolean {
+    return this.passTracker !== null;
+  }
+
+  /**
+   * 获取上一帧的 pass 依赖报告（未启用校验时返回空字符串）
+   */
+  getPassReport(): string {
+    return this.passTracker?.report() ?? "";
+  }
+
+  /**
+   * 选择本帧的排序键精度：视图或模型矩阵变化时视为移动
+   */
+  private selectSortKeyBits(): 16 | 32 {
+    const view = this.camera.viewMatrix;
+    let moving = false;
+    for (let i = 0; i < 16; i++) {
+      if (view[i] !== this.lastSortView[i] || this.modelMatrix[i] !== this.lastSortModel[i]) {
+        moving = true;
+        break;
+      }
+    }
+    this.lastSortView.set(view);
+    this.lastSortModel.set(this.modelMatrix);
+
+    if (!moving || this.sortAmortizationInterval === 0) {
+      this.motionFrameCount = 0;
+      return 32;
+    }
+    return this.motionFrameCount++ % this.sortAmortizationInterval === 0 ? 32 : 16;
+  }
+
+  /**
+   * 收集影响剔除和排序结果的全部输入
+   */
+  private buildSortKey(cullOnly: boolean, keyBits: number, densityCellSize: number, occlusion: boolean): Float64Array {
+    const key = this.sortKey;
+    key.set(this.camera.viewMatrix, 0);
+    key.set(this.camera.unjitteredProjectionMatrix, 16);
+    key.set(this.modelMatrix, 32);
+    key[48] = this.renderer.width;
+    key[49] = this.renderer.height;
+    key[50] = this.camera.near;
+    key[51] = this.camera.far;
+    key[52] = this.pixelCullThreshold;
+    key[53] = this.cameraInsideCull ? 1 : 0;
+    key[54] = this.depthKeyMode;
+    key[55] = densityCellSize;
+    key[56] = cullOnly ? 1 : 0;
+    key[57] = keyBits;
+    key[58] = occlusion ? 1 : 0;
+    return key;
+  }
+
+  /**
+   * 设置是否复用排序结果：相机、变换、剔除参数和数据都没有变化时跳过本实体的剔除和排序
+   */
+  setSortReuse(enabled: boolean): void {
+    this.sortCache.setEnabled(enabled);
+  }
+
+  /**
+   * 是否复用排序结果
+   */
+  isSortReuse(): boolean {
+    return this.sortCache.isEnabled();
+  }
+
+  /**
+   * 获取排序复用统计
+   */
+  getSortReuseStats(): SplatSortCacheStats {
+    return this.sortCache.getStats();
+  }
+
+  /**
+   * 设置相机位于 splat 内部时是否淡出并缩小该 splat（消除整屏闪烁）
+   */
+  setCameraInsideFade(enabled: boolean): void {
+    this.cameraInsideFade = enabled;
+  }
+
+  isCameraInsideFade(): boolean {
+    return this.cameraInsideFade;
+  }
+
+  /**
+   * 设置是否剔除中心在近平面之后、且相机位于其内部的 splat
+   */
+  setCameraInsideCull(enabled: boolean): void {
+    this.cameraInsideCull = enabled;
+  }
+
+  isCameraInsideCull(): boolean {
+    return this.cameraInsideCull;
+  }
+
+  /**
+   * 设置近平面淡出距离：中心距近平面不足该距离的 splat 线性淡出，0 关闭
+   */
+  setNearFadeDistance(distance: number): void {
+    this.nearFadeDistance = Math.max(0, distance);
+  }
+
+  getNearFadeDistance(): number {
+    return this.nearFadeDistance;
+  }
+
+  setData(splats: SplatCPU[]): void {
+    const device = this.renderer.device;
+
+    this.releaseSplatData();
+
+    this.splatCount = splats.length;
+    this.is2DGS = false;
+    this.origin = [0, 0, 0];
+
+    if (this.splatCount === 0) {
+      this.splatBuffer = null;
+      this.bindGroup = null;
+      this.boundingBox = null;
+      this.octree = null;
+      this.splats = null;
+      return;
+    }
+    this.splats = GaussianSplats.fromSplatCPU(splats);
+
+    this.boundingBox = this.computeBoundingBox(splats);
+
+    const data = new Float32Array(this.splatCount * SPLAT_FLOAT_COUNT);
+
+    for (let i = 0; i < this.splatCount; i++) {
+      const splat = splats[i];
+      const offset = i * SPLAT_FLOAT_COUNT;
+
+      data[offset + 0] = splat.mean[0];
+      data[offset + 1] = splat.mean[1];
+      data[offset + 2] = splat.mean[2];
+      data[offset + 3] = splat.emissive ?? 0;
+
+      data[offset + 4] = splat.scale[0];
+      data[offset + 5] = splat.scale[1];
+      data[offset + 6] = splat.scale[2];
+      data[offset + 7] = 0;
+
+      data[offset + 8] = splat.rotation[0];
+      data[offset + 9] = splat.rotation[1];
+      data[offset + 10] = splat.rotation[2];
+      data[offset + 11] = splat.rotation[3];
+
+      data[offset + 12] = splat.colorDC[0];
+      data[offset + 13] = splat.colorDC[1];
+      data[offset + 14] = splat.colorDC[2];
+      data[offset + 15] = splat.opacity;
+
+      const shRest = splat.shRest;
+      for (let j = 0; j < 9; j++) {
         data[offset + 16 + j] = shRest ? shRest[j] : 0;
       }
       for (let j = 0; j < 15; j++) {
@@ -582,19 +1929,27 @@ export class GSSplatRenderer implements IGSSplatRendererWithCapabilities {
       data[offset + 63] = 0;
     }
 
-    this.splatBuffer = device.createBuffer({
-      size: data.byteLength,
-      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
-    });
+    this.splatBuffer = SplatBufferPool.get(device).acquire(
+      data.byteLength,
+      GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      "splats",
+    );
 
     device.queue.writeBuffer(this.splatBuffer, 0, data);
 
+    this.octree = this.buildOctree(this.splats.getData());
+
     this.sorter = new GSSplatSorter(
       device,
       this.splatCount,
       this.splatBuffer,
       this.uniformBuffer,
+      { uploads: this.renderer.uploads },
     );
+    this.sorter.setPassTracker(this.passTracker);
+    this.sorter.setVisibleCountTarget(this.uniformBuffer, VISIBLE_COUNT_OFFSET);
+    this.applyVisibleCountReadback();
+    this.applyChunkedCulling();
 
     this.sorter.setScreenSize(this.renderer.width, this.renderer.height);
     this.sorter.setCullingOptions({
@@ -603,54 +1958,172 @@ export class GSSplatRenderer implements IGSSplatRendererWithCapabilities {
       pixelThreshold: this.pixelCullThreshold,
     });
 
-    this.bindGroup = device.createBindGroup({
-      layout: this.bindGroupLayout,
-      entries: [
-        { binding: 0, resource: { buffer: this.uniformBuffer } },
-        { binding: 1, resource: { buffer: this.splatBuffer } },
-        { binding: 2, resource: { buffer: this.sorter.getIndicesBuffer() } },
-      ],
-    });
+    this.selection = new SplatSelection(this.splatCount);
+    this.createSelectionBuffer();
+    this.createBindGroup();
   }
 
   setCompactData(compactData: CompactSplatData): void {
     const device = this.renderer.device;
 
-    if (this.splatBuffer) {
-      this.splatBuffer.destroy();
-    }
-    if (this.sorter) {
-      this.sorter.destroy();
-      this.sorter = null;
-    }
+    this.releaseSplatData();
 
     this.splatCount = compactData.count;
+    this.is2DGS = compactData.is2DGS === true;
+    this.origin = compactData.origin ? [...compactData.origin] : [0, 0, 0];
 
     if (this.splatCount === 0) {
       this.splatBuffer = null;
       this.bindGroup = null;
       this.boundingBox = null;
+      this.octree = null;
+      this.splats = null;
       return;
     }
+    this.splats = new GaussianSplats(compactData);
 
     this.boundingBox = this.computeBoundingBoxFromCompact(compactData);
 
     const includeSH = compactData.shCoeffs !== undefined;
     const gpuData = compactDataToGPUBuffer(compactData, includeSH);
 
-    this.splatBuffer = device.createBuffer({
-      size: gpuData.byteLength,
-      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
-    });
+    this.splatBuffer = SplatBufferPool.get(device).acquire(
+      gpuData.byteLength,
+      GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      "splats",
+    );
 
     device.queue.writeBuffer(this.splatBuffer, 0, gpuData.buffer);
+    this.createSplatResources(compactData);
+  }
+
+  /**
+   * 整批更新 splat 数据（训练等每一步只修改部分 splat 的场景）
+   * 数量和 SH 布局不变时直接接管新数组（不复制），只把 dirty 中的区间按块上传（受每帧上传预算限制），
+   * 不重新分配 buffer，也不重建排序器；否则退回 setCompactData。之后调用方不应再修改 compactData
+   * @param dirty 被修改的 splat，省略时视为全部修改
+   */
+  updateCompactData(compactData: CompactSplatData, dirty?: SplatDirtyRanges): void {
+    const current = this.splats?.getData();
+    if (
+      !this.splats || !current || !this.splatBuffer ||
+      compactData.count !== this.splatCount ||
+      (compactData.shCoeffs === undefined) !== (current.shCoeffs === undefined) ||
+      (compactData.is2DGS === true) !== this.is2DGS
+    ) {
+      this.setCompactData(compactData);
+      return;
+    }
+    this.splats.replaceData(compactData, dirty ?? new SplatDirtyRanges().addRange(0, compactData.count, true));
+  }
+
+  /**
+   * 使用外部 GPU buffer 中的 splat 数据（例如同一 device 上的训练循环），跳过 CPU 打包和上传
+   * 数据由 compute pass 打包进 splat buffer，外部数据更新后调用 updateExternalBuffers 重新打包。
+   * 没有 CPU 端数据：getSplats、八叉树分块剔除、PVS、压缩等依赖 CPU 数据的功能不可用，
+   * 包围盒取 buffers.bounds（未提供时为 null）
+   */
+  setExternalBuffers(buffers: SplatExternalBuffers): void {
+    validateExternalBuffers(this.renderer.device, buffers);
+    this.releaseSplatData();
+
+    this.splatCount = buffers.count;
+    this.is2DGS = false;
+    this.origin = [0, 0, 0];
+    this.splats = null;
+    this.octree = null;
+    this.splatBuffer = null;
+    this.bindGroup = null;
+    this.boundingBox = null;
+    if (this.splatCount === 0) {
+      return;
+    }
+    this.boundingBox = buffers.bounds ? createBoundingBoxFromMinMax(buffers.bounds.min, buffers.bounds.max) : null;
+    this.splatBuffer = SplatBufferPool.get(this.renderer.device).acquire(
+      this.splatCount * SPLAT_BYTE_SIZE,
+      GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      "splats",
+    );
+    this.externalPacker = new SplatExternalPacker(this.renderer.device, buffers, this.splatBuffer);
+    this.externalPacker.pack();
+
+    this.createSorter();
+    this.selection = new SplatSelection(this.splatCount);
+    this.createSelectionBuffer();
+    this.createBindGroup();
+  }
+
+  /**
+   * 外部 buffer 的内容更新后重新打包（同一批 buffer；数量或 buffer 变化时重新调用 setExternalBuffers）
+   * @returns 当前数据不是来自外部 buffer 时返回 false
+   */
+  updateExternalBuffers(): boolean {
+    if (!this.externalPacker) {
+      return false;
+    }
+    this.externalPacker.pack();
+    this.sortCache.markDataUpdated();
+    return true;
+  }
+
+  /**
+   * 释放当前数据的 splat buffer、排序器和派生资源（替换数据前调用）
+   */
+  private releaseSplatData(): void {
+    if (this.splatBuffer) {
+      SplatBufferPool.get(this.renderer.device).release(this.splatBuffer);
+    }
+    if (this.sorter) {
+      this.sorter.destroy();
+      this.sorter = null;
+    }
+    this.destroyTileRasterizer();
+    this.destroyCentersFilter();
+    this.destroyPolygonSelector();
+    this.destroyAttributeSelector();
+    this.destroyAttributeBuffers();
+    this.destroyExternalPacker();
+  }
+
+  private destroyExternalPacker(): void {
+    if (this.externalPacker) {
+      this.externalPacker.destroy();
+      this.externalPacker = null;
+    }
+  }
+
+  /**
+   * splat buffer 就绪后创建排序器、八叉树、选择集和标签
+   */
+  private createSplatResources(compactData: CompactSplatData): void {
+    this.octree = this.buildOctree(compactData);
+    this.createSorter();
+
+    this.selection = new SplatSelection(this.splatCount);
+    this.createSelectionBuffer();
+    if (compactData.labels) {
+      this.setLabels(new SplatLabels(compactData.labels));
+    } else {
+      this.createBindGroup();
+    }
+  }
 
+  /**
+   * 为当前 splat buffer 创建排序器
+   */
+  private createSorter(): void {
+    const device = this.renderer.device;
     this.sorter = new GSSplatSorter(
       device,
       this.splatCount,
-      this.splatBuffer,
+      this.splatBuffer!,
       this.uniformBuffer,
+      { uploads: this.renderer.uploads },
     );
+    this.sorter.setPassTracker(this.passTracker);
+    this.sorter.setVisibleCountTarget(this.uniformBuffer, VISIBLE_COUNT_OFFSET);
+    this.applyVisibleCountReadback();
+    this.applyChunkedCulling();
 
     this.sorter.setScreenSize(this.renderer.width, this.renderer.height);
     this.sorter.setCullingOptions({
@@ -658,43 +2131,1141 @@ export class GSSplatRenderer implements IGSSplatRendererWithCapabilities {
       farPlane: this.camera.far,
       pixelThreshold: this.pixelCullThreshold,
     });
+  }
+
+  /**
+   * 按 LOD 重要性构建八叉树
+   */
+  private buildOctree(data: CompactSplatData): SplatOctree {
+    const importance = new Float32Array(data.count);
+    const s = data.scales;
+    for (let i = 0; i < data.count; i++) {
+      importance[i] = splatImportance(data.opacities[i], s[i * 3], s[i * 3 + 1], s[i * 3 + 2]);
+    }
+    return buildSplatOctree(data.positions, data.count, { importance });
+  }
+
+  /**
+   * 获取 CPU 端 splat 数据访问器（通过 getMut / iterMut 修改后下一帧自动上传）
+   */
+  getSplats(): GaussianSplats | null {
+    return this.splats;
+  }
+
+  /**
+   * 上传逐 splat 编辑的脏块（受每帧上传预算限制，force 时全部上传）；位置变化时重建包围盒和八叉树
+   */
+  private syncSplatEdits(force: boolean = false): void {
+    if (!this.splats || !this.splatBuffer || !this.splats.getDirtyRange()) {
+      return;
+    }
+    // 按每帧上传预算逐块上传，超出的块保持脏标记留到后续帧
+    const scheduler = this.renderer.uploadScheduler;
+    const chunkBytes = SPLAT_DIRTY_CHUNK_SIZE * SPLAT_BYTE_SIZE;
+    for (const [start, end] of this.splats.getDirtyRanges()) {
+      const rangeBytes = (end - start) * SPLAT_BYTE_SIZE;
+      const bytes = force ? rangeBytes : scheduler.request(rangeBytes, chunkBytes);
+      if (bytes === 0) continue;
+      const uploadEnd = start + bytes / SPLAT_BYTE_SIZE;
+      this.renderer.device.queue.writeBuffer(this.splatBuffer, start * SPLAT_BYTE_SIZE, this.splats.toGPUData(start, uploadEnd));
+      this.splats.markRangeClean(start, uploadEnd);
+      this.sortCache.markDataUpdated();
+    }
+    // 包围盒和八叉树按 CPU 端最新位置立即重建（尚未上传的块在上传前沿用旧位置剔除）
+    if (this.splats.isPositionsDirty()) {
+      const data = this.splats.getData();
+      this.boundingBox = this.computeBoundingBoxFromCompact(data);
+      this.octree = this.buildOctree(data);
+      this.applyChunkedCulling();
+      this.splats.markPositionsClean();
+    }
+  }
+
+  // ============================================
+  // 删除 / 压缩
+  // ============================================
+
+  /**
+   * 删除 splat：不透明度置零后不再绘制，删除比例达到压缩阈值时自动压缩
+   * @returns 本次新删除的数量
+   */
+  deleteSplats(indices: ArrayLike<number>): number {
+    if (!this.splats) {
+      return 0;
+    }
+    this.deleted ??= new Uint8Array(this.splatCount);
+    const opacities = this.splats.getDataMut().opacities;
+    let removed = 0;
+    for (let k = 0; k < indices.length; k++) {
+      const i = indices[k];
+      if (i < 0 || i >= this.splatCount || this.deleted[i]) continue;
+      this.deleted[i] = 1;
+      opacities[i] = 0;
+      this.splats.markDirty(i);
+      removed++;
+    }
+    this.deletedCount += removed;
+    if (this.compactionThreshold > 0 && this.deletedCount >= this.splatCount * this.compactionThreshold) {
+      this.compactDeleted();
+    }
+    return removed;
+  }
+
+  /**
+   * 删除当前选中的 splat 并清空选择
+   */
+  deleteSelected(): number {
+    if (!this.selection || this.selection.getSelectedCount() === 0) {
+      return 0;
+    }
+    const removed = this.deleteSplats(this.selection.getSelectedIndices());
+    this.selection?.clear();
+    return removed;
+  }
+
+  /**
+   * 恢复已删除（尚未压缩）的 splat
+   * @param opacities 与 indices 一一对应的删除前不透明度
+   * @returns 恢复的数量
+   */
+  restoreSplats(indices: ArrayLike<number>, opacities: ArrayLike<number>): number {
+    if (!this.splats || !this.deleted) {
+      return 0;
+    }
+    const data = this.splats.getDataMut().opacities;
+    let restored = 0;
+    for (let k = 0; k < indices.length; k++) {
+      const i = indices[k];
+      if (i < 0 || i >= this.splatCount || !this.deleted[i]) continue;
+      this.deleted[i] = 0;
+      data[i] = opacities[k];
+      this.splats.markDirty(i);
+      restored++;
+    }
+    this.deletedCount -= restored;
+    return restored;
+  }
+
+  /**
+   * 获取删除标记（只读使用，1 表示已删除；没有删除过时返回 null）
+   */
+  getDeletedMask(): Uint8Array | null {
+    return this.deleted;
+  }
+
+  /**
+   * 获取已删除但尚未压缩的 splat 数量
+   */
+  getDeletedCount(): number {
+    return this.deletedCount;
+  }
+
+  /**
+   * 设置自动压缩阈值（已删除数量占总数的比例），0 表示只在调用 compactDeleted 时压缩
+   */
+  setCompactionThreshold(fraction: number): void {
+    this.compactionThreshold = Math.max(0, Math.min(1, fraction));
+  }
+
+  /**
+   * 获取自动压缩阈值
+   */
+  getCompactionThreshold(): number {
+    return this.compactionThreshold;
+  }
+
+  /**
+   * 压缩已删除的 splat：在 GPU 上把保留的 splat 收集到新 buffer，
+   * CPU 端数据、标签、标量属性和选择集按相同顺序收集。压缩后 splat 索引会变化
+   * @returns 移除的数量
+   */
+  compactDeleted(): number {
+    if (!this.splats || !this.splatBuffer || !this.deleted || this.deletedCount === 0) {
+      return 0;
+    }
+    const removed = this.deletedCount;
+    const removal = this.splats.removeDeleted(this.deleted, this.selection);
+    const keep = removal.keep;
+    const data = removal.splats.getData();
+    data.labels = this.labels ? Uint32Array.from(keep, (i) => this.labels!.ids[i]) : undefined;
+    const scalars = this.scalars ? Float32Array.from(keep, (i) => this.scalars![i]) : null;
+    const scalarRange = this.scalarRange;
+    const selected = removal.selection?.getMask() ?? null;
+    const appearance = this.appearance ? gatherSplatAppearance(this.appearance, keep) : null;
+    const groups = this.groups?.gather(keep) ?? null;
+
+    if (keep.length === 0) {
+      this.setCompactData(data);
+      return removed;
+    }
+
+    // 先上传全部未同步的编辑（不受每帧预算限制），压缩直接读取 GPU 上的旧 buffer
+    this.syncSplatEdits(true);
+    const device = this.renderer.device;
+    const pool = SplatBufferPool.get(device);
+    const compacted = pool.acquire(keep.length * SPLAT_BYTE_SIZE, GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST, "splats");
+    this.compactor ??= new SplatCompactor(device);
+    this.compactor.compact(this.splatBuffer, compacted, keep);
+
+    this.sorter?.destroy();
+    this.sorter = null;
+    this.destroyTileRasterizer();
+    this.destroyCentersFilter();
+    this.destroyPolygonSelector();
+    this.destroyAttributeSelector();
+    this.destroyAttributeBuffers();
+    pool.release(this.splatBuffer);
+
+    this.splatBuffer = compacted;
+    this.splatCount = keep.length;
+    this.splats = removal.splats;
+    this.boundingBox = this.computeBoundingBoxFromCompact(data);
+    this.createSplatResources(data);
+    if (scalars) {
+      this.setScalarAttribute(scalars, scalarRange);
+    }
+    if (selected) {
+      this.selection?.selectWhere((i) => selected[i] !== 0);
+    }
+    if (appearance) {
+      this.setPackedAppearance(appearance);
+    }
+    if (groups) {
+      this.setGroups(groups);
+    }
+    return removed;
+  }
+
+  /**
+   * 创建 bind group（数据、标签或选择 buffer 变化后调用）
+   */
+  private createBindGroup(): void {
+    if (!this.splatBuffer || !this.sorter) {
+      this.bindGroup = null;
+      return;
+    }
 
-    this.bindGroup = device.createBindGroup({
+    this.bindGroup = this.createSplatBindGroup(this.splatBuffer, this.sorter.getIndicesBuffer());
+    this.boundDensityGrid = this.sorter.getDensityGridBuffer();
+    this.centersBindGroup = null;
+  }
+
+  /**
+   * 创建绑定指定绘制索引的 bind group（排序结果或过滤后的中心点）
+   */
+  private createSplatBindGroup(splatBuffer: GPUBuffer, indicesBuffer: GPUBuffer): GPUBindGroup {
+    return this.renderer.device.createBindGroup({
       layout: this.bindGroupLayout,
       entries: [
         { binding: 0, resource: { buffer: this.uniformBuffer } },
-        { binding: 1, resource: { buffer: this.splatBuffer } },
-        { binding: 2, resource: { buffer: this.sorter.getIndicesBuffer() } },
+        { binding: 1, resource: { buffer: splatBuffer } },
+        { binding: 2, resource: { buffer: indicesBuffer } },
+        { binding: 3, resource: { buffer: this.labelBuffer ?? this.placeholderBuffer } },
+        { binding: 4, resource: { buffer: this.selectionBuffer ?? this.placeholderBuffer } },
+        { binding: 5, resource: this.getScalarBinding() },
+        { binding: 6, resource: this.transferFunctionTexture.createView() },
+        { binding: 7, resource: { buffer: this.sorter?.getDensityGridBuffer() ?? this.placeholderBuffer } },
+        { binding: 8, resource: { buffer: this.appearanceBuffer ?? this.placeholderBuffer } },
       ],
     });
   }
 
-  render(pass: GPURenderPassEncoder): void {
-    if (this.splatCount === 0 || !this.bindGroup || !this.sorter) {
+  /**
+   * 创建选择状态 buffer（每个 splat 一个 u32）
+   */
+  private createSelectionBuffer(): void {
+    this.selectionUploadVersion = -1;
+    this.selectionBoundsVersion = -1;
+    this.selectionBuffer = this.renderer.device.createBuffer({
+      size: Math.max(this.splatCount * 4, 16),
+      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+    });
+  }
+
+  /**
+   * 标量属性的绑定（外部 buffer 按偏移绑定 splatCount 个 f32）
+   */
+  private getScalarBinding(): GPUBufferBinding {
+    if (this.externalScalar) {
+      return { buffer: this.externalScalar.buffer, offset: this.externalScalar.offset ?? 0, size: this.splatCount * 4 };
+    }
+    return { buffer: this.scalarBuffer ?? this.placeholderBuffer };
+  }
+
+  /**
+   * 释放标量属性（外部 buffer 只解除引用）
+   */
+  private releaseScalarBuffer(): void {
+    if (this.scalarBuffer && !this.externalScalar) {
+      this.scalarBuffer.destroy();
+    }
+    this.scalarBuffer = null;
+    this.externalScalar = null;
+    this.scalars = null;
+  }
+
+  /**
+   * 销毁标签、选择和标量属性相关的 buffer
+   */
+  private destroyAttributeBuffers(): void {
+    this.releaseScalarBuffer();
+    if (this.labelBuffer) {
+      this.labelBuffer.destroy();
+      this.labelBuffer = null;
+    }
+    if (this.selectionBuffer) {
+      this.selectionBuffer.destroy();
+      this.selectionBuffer = null;
+    }
+    this.labels = null;
+    this.selection = null;
+    this.groups = null;
+    this.hover = null;
+    this.deleted = null;
+    this.deletedCount = 0;
+    if (this.appearanceBuffer) {
+      this.appearanceBuffer.destroy();
+      this.appearanceBuffer = null;
+    }
+    this.appearance = null;
+  }
+
+  // ============================================
+  // 语义标签 / 可视化 / 选择
+  // ============================================
+
+  /**
+   * 当前数据是否为 2DGS (surfel)，是则使用 2D 高斯核管线
+   */
+  isSurfelData(): boolean {
+    return this.is2DGS;
+  }
+
+  /**
+   * 设置逐 splat 语义标签，传入 null 清除
+   */
+  setLabels(labels: SplatLabels | null): void {
+    if (labels && labels.count !== this.splatCount) {
+      throw new Error(`标签数量 (${labels.count}) 与 splat 数量 (${this.splatCount}) 不一致`);
+    }
+
+    if (this.labelBuffer) {
+      this.labelBuffer.destroy();
+      this.labelBuffer = null;
+    }
+    this.labels = labels;
+
+    if (labels && labels.count > 0) {
+      const data = labels.toGPUData();
+      this.labelBuffer = this.renderer.device.createBuffer({
+        size: data.byteLength,
+        usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      });
+      this.renderer.device.queue.writeBuffer(this.labelBuffer, 0, data);
+    }
+
+    this.createBindGroup();
+  }
+
+  /**
+   * 获取逐 splat 语义标签
+   */
+  getLabels(): SplatLabels | null {
+    return this.labels;
+  }
+
+  /**
+   * 设置可视化模式（内置模式或 registerSplatVisMode 注册的自定义模式，可传名称）
+   */
+  setVisMode(mode: SplatVisMode | string): void {
+    const resolved = resolveSplatVisMode(mode);
+    if (resolved === null) {
+      console.warn(`[GSSplatRenderer] 未注册的可视化模式: "${mode}"`);
       return;
     }
+    this.visMode = resolved;
+  }
 
-    // 更新 uniforms
-    this.renderer.device.queue.writeBuffer(
-      this.uniformBuffer, 0,
-      new Float32Array(this.camera.viewMatrix),
-    );
-    this.renderer.device.queue.writeBuffer(
-      this.uniformBuffer, 64,
-      new Float32Array(this.camera.projectionMatrix),
-    );
-    this.renderer.device.queue.writeBuffer(
-      this.uniformBuffer, 128,
-      new Float32Array(this.modelMatrix),
+  /**
+   * 获取当前可视化模式
+   */
+  getVisMode(): SplatVisMode {
+    return this.visMode;
+  }
+
+  /**
+   * 获取选择集（无数据时返回 null）
+   */
+  getSelection(): SplatSelection | null {
+    return this.selection;
+  }
+
+  /**
+   * 设置逐 splat 标量属性，传入 null 清除
+   * @param range 映射范围，不传则使用数据的 min/max（忽略 NaN）
+   */
+  setScalarAttribute(values: Float32Array | null, range?: [number, number]): void {
+    if (values && values.length !== this.splatCount) {
+      throw new Error(`标量数量 (${values.length}) 与 splat 数量 (${this.splatCount}) 不一致`);
+    }
+
+    this.releaseScalarBuffer();
+    this.scalars = values;
+
+    if (values && values.length > 0) {
+      this.scalarBuffer = this.renderer.device.createBuffer({
+        size: values.byteLength,
+        usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      });
+      this.renderer.device.queue.writeBuffer(this.scalarBuffer, 0, values);
+
+      if (range) {
+        this.scalarRange = [range[0], range[1]];
+      } else {
+        let min = Infinity;
+        let max = -Infinity;
+        for (let i = 0; i < values.length; i++) {
+          const v = values[i];
+          if (v < min) min = v;
+          if (v > max) max = v;
+        }
+        this.scalarRange = min <= max ? [min, max] : [0, 1];
+      }
+    }
+
+    this.createBindGroup();
+  }
+
+  /**
+   * 获取逐 splat 标量属性
+   */
+  getScalarAttribute(): Float32Array | null {
+    return this.scalars;
+  }
+
+  /**
+   * 使用外部 GPU buffer 作为逐 splat 标量属性（每个 splat 一个 f32），传入 null 清除
+   * 训练循环可以直接写入梯度大小、致密化得分等统计，每帧读取最新内容，不经过 CPU。
+   * buffer 由调用方持有，数据替换或压缩后需要重新设置
+   * @param range 映射范围，不传则保持当前范围（可用 fitScalarRange 按数据调整）
+   */
+  setExternalScalarBuffer(source: SplatExternalBuffer | null, range?: [number, number]): void {
+    if (source) {
+      validateExternalBuffer(this.renderer.device, "scalars", source, this.splatCount, 1);
+    }
+    this.releaseScalarBuffer();
+    if (source && this.splatCount > 0) {
+      this.externalScalar = { buffer: source.buffer, offset: source.offset ?? 0 };
+      this.scalarBuffer = source.buffer;
+      if (range) {
+        this.scalarRange = [range[0], range[1]];
+      }
+    }
+    this.createBindGroup();
+  }
+
+  /**
+   * 按当前标量数据调整映射范围（忽略 NaN / 无穷大）
+   * 外部 buffer 从 GPU 回读，需要 COPY_SRC 用途
+   * @param clip 两端各忽略的比例（0~0.5），用于排除长尾的极端值
+   * @returns 新的映射范围，没有标量数据时为 null
+   */
+  async fitScalarRange(clip: number = 0): Promise<[number, number] | null> {
+    const values = this.scalars ?? await this.readExternalScalars();
+    if (!values) return null;
+    const finite = values.filter((v) => Number.isFinite(v));
+    if (finite.length === 0) return null;
+
+    let range: [number, number];
+    if (clip > 0) {
+      finite.sort();
+      const k = Math.min(Math.floor(finite.length * Math.min(clip, 0.5)), finite.length - 1);
+      range = [finite[k], finite[finite.length - 1 - k]];
+    } else {
+      let min = Infinity;
+      let max = -Infinity;
+      for (let i = 0; i < finite.length; i++) {
+        if (finite[i] < min) min = finite[i];
+        if (finite[i] > max) max = finite[i];
+      }
+      range = [min, max];
+    }
+    this.scalarRange = range;
+    return [range[0], range[1]];
+  }
+
+  /**
+   * 回读外部标量 buffer
+   */
+  private async readExternalScalars(): Promise<Float32Array | null> {
+    const source = this.externalScalar;
+    if (!source || this.splatCount === 0) return null;
+    if ((source.buffer.usage & GPUBufferUsage.COPY_SRC) === 0) {
+      throw new Error("[GSSplatRenderer] 外部标量 buffer 需要 COPY_SRC 用途才能回读");
+    }
+    const device = this.renderer.device;
+    const size = this.splatCount * 4;
+    const pool = SplatBufferPool.get(device);
+    const readBuffer = pool.acquire(size, GPUBufferUsage.COPY_DST | GPUBufferUsage.MAP_READ, "splat-scalar-readback");
+    const encoder = device.createCommandEncoder({ label: "splat-scalar-readback-encoder" });
+    encoder.copyBufferToBuffer(source.buffer, source.offset ?? 0, readBuffer, 0, size);
+    device.queue.submit([encoder.finish()]);
+
+    try {
+      await readBuffer.mapAsync(GPUMapMode.READ, 0, size);
+      const values = new Float32Array(readBuffer.getMappedRange(0, size).slice(0));
+      readBuffer.unmap();
+      pool.release(readBuffer);
+      return values;
+    } catch (error) {
+      // 映射失败（设备丢失 / 已销毁）时 buffer 不能再复用
+      readBuffer.destroy();
+      throw error;
+    }
+  }
+
+  /**
+   * 设置标量属性的映射范围
+   */
+  setScalarRange(min: number, max: number): void {
+    this.scalarRange = [min, max];
+  }
+
+  /**
+   * 获取标量属性的映射范围
+   */
+  getScalarRange(): [number, number] {
+    return [this.scalarRange[0], this.scalarRange[1]];
+  }
+
+  /**
+   * 设置体渲染传递函数
+   * @param transferFunction 控制点，或 RGBA8 查找表（长度为 4 的倍数）；null 恢复默认
+   */
+  setTransferFunction(transferFunction: TransferFunctionStop[] | Uint8Array | null): void {
+    let table: Uint8Array;
+    if (transferFunction instanceof Uint8Array) {
+      if (transferFunction.length < 4 || transferFunction.length % 4 !== 0) {
+        throw new Error(`传递函数查找表长度 (${transferFunction.length}) 必须为 4 的正整数倍`);
+      }
+      table = transferFunction;
+    } else {
+      table = createTransferFunction(transferFunction ?? DEFAULT_TRANSFER_FUNCTION);
+    }
+    const previous = this.transferFunctionTexture;
+    this.writeTransferFunction(table);
+    if (this.transferFunctionTexture !== previous) {
+      this.createBindGroup();
+    }
+  }
+
+  /**
+   * 设置体渲染模式的不透明度倍数
+   */
+  setVolumeOpacity(scale: number): void {
+    this.volumeOpacity = Math.max(0, scale);
+  }
+
+  /**
+   * 获取体渲染模式的不透明度倍数
+   */
+  getVolumeOpacity(): number {
+    return this.volumeOpacity;
+  }
+
+  /**
+   * 设置整体不透明度倍数 (0~1)
+   */
+  setOpacity(opacity: number): void {
+    this.opacityScale = Math.min(1, Math.max(0, opacity));
+  }
+
+  /**
+   * 获取整体不透明度倍数
+   */
+  getOpacity(): number {
+    return this.opacityScale;
+  }
+
+  /**
+   * 设置 colormap
+   */
+  setColormap(colormap: Colormap): void {
+    this.colormap = colormap;
+  }
+
+  /**
+   * 获取 colormap
+   */
+  getColormap(): Colormap {
+    return this.colormap;
+  }
+
+  /**
+   * 设置光栅化模式
+   * Tile 模式使用 compute tile 分箱光栅化，2DGS 数据始终回退到 quad 路径
+   * Stochastic 模式跳过排序，以 hashed alpha test + 深度测试代替混合
+   */
+  setRasterMode(mode: SplatRasterMode): void {
+    this.rasterMode = mode;
+    if (mode !== SplatRasterMode.Tile) {
+      this.destroyTileRasterizer();
+    }
+  }
+
+  /**
+   * 获取光栅化模式
+   */
+  getRasterMode(): SplatRasterMode {
+    return this.rasterMode;
+  }
+
+  /**
+   * 设置点显示模式
+   * Points 把每个 splat 绘制为圆点（始终排序，忽略随机透明度和 tile 光栅化），
+   * Centers 在高斯渲染之上叠加中心点
+   */
+  setPointMode(mode: SplatPointMode): void {
+    this.pointMode = mode;
+  }
+
+  /**
+   * 获取点显示模式
+   */
+  getPointMode(): SplatPointMode {
+    return this.pointMode;
+  }
+
+  /**
+   * 设置点尺寸（未指定的字段保持不变）
+   */
+  setPointSizeConfig(config: Partial<SplatPointSizeConfig>): void {
+    const next = { ...this.pointSize, ...config };
+    next.minSize = Math.max(0, next.minSize);
+    next.maxSize = Math.max(next.minSize, next.maxSize);
+    next.coverage = Math.max(0, next.coverage);
+    this.pointSize = next;
+  }
+
+  /**
+   * 获取点尺寸配置
+   */
+  getPointSizeConfig(): SplatPointSizeConfig {
+    return { ...this.pointSize };
+  }
+
+  /**
+   * 设置中心点叠加的子采样（未指定的字段保持不变）
+   */
+  setCentersConfig(config: Partial<SplatCentersConfig>): void {
+    const next = { ...this.centersConfig, ...config };
+    next.stride = Math.max(1, Math.floor(next.stride));
+    next.cursorRadius = Math.max(0, next.cursorRadius);
+    this.centersConfig = next;
+  }
+
+  /**
+   * 获取中心点叠加配置
+   */
+  getCentersConfig(): SplatCentersConfig {
+    return { ...this.centersConfig };
+  }
+
+  /**
+   * 设置光标位置（画布像素坐标，cursorRadius > 0 时使用），null 表示光标离开画布
+   */
+  setCentersCursor(cursor: [number, number] | null): void {
+    this.centersCursor = cursor ? [cursor[0], cursor[1]] : null;
+  }
+
+  /**
+   * 设置混合模式
+   * Additive / Max 与绘制顺序无关，只做剔除不排序；
+   * Tile 模式只支持标准混合，其他混合模式回退到 quad 路径，Stochastic 模式不混合
+   */
+  setBlendMode(mode: SplatBlendMode): void {
+    this.blendMode = mode;
+  }
+
+  /**
+   * 获取混合模式
+   */
+  getBlendMode(): SplatBlendMode {
+    return this.blendMode;
+  }
+
+  /**
+   * 设置每个像素最多合成的 splat 数量（K-limit，从近到远的前 K 个），0 表示不限制
+   * 只在 Tile 光栅化模式下生效
+   */
+  setMaxSplatsPerPixel(count: number): void {
+    this.maxSplatsPerPixel = Math.max(0, Math.floor(count));
+    this.tileRasterizer?.setMaxSplatsPerPixel(this.maxSplatsPerPixel);
+  }
+
+  /**
+   * 获取每个像素最多合成的 splat 数量
+   */
+  getMaxSplatsPerPixel(): number {
+    return this.maxSplatsPerPixel;
+  }
+
+  /**
+   * 设置深度输出：混合绘制前把不透明度超过 alphaThreshold 的片段写入深度纹理，
+   * 供景深等后处理按 splat 深度计算弥散圆；之后绘制的网格也会被不透明的 splat 遮挡。
+   * 深度取 splat 中心（2DGS 为盘面），半透明边缘不写入；开启时不能同时使用遮挡剔除
+   * @param alphaThreshold 不透明度阈值，0 关闭
+   */
+  setDepthWrite(alphaThreshold: number): void {
+    this.depthWriteAlpha = Math.min(Math.max(alphaThreshold, 0), 1);
+  }
+
+  /**
+   * 获取深度输出的不透明度阈值（0 表示关闭）
+   */
+  getDepthWrite(): number {
+    return this.depthWriteAlpha;
+  }
+
+  /**
+   * 获取 (按需创建) tile 光栅化器
+   */
+  private getTileRasterizer(): GSSplatTileRasterizer {
+    if (!this.tileRasterizer) {
+      this.tileRasterizer = new GSSplatTileRasterizer(
+        this.renderer.device,
+        this.renderer.format,
+        this.renderer.depthFormat,
+        gsOptimizedShader,
+        this.bindGroupLayout,
+        this.splatCount,
+        { maxSplatsPerPixel: this.maxSplatsPerPixel },
+      );
+    }
+    return this.tileRasterizer;
+  }
+
+  private destroyTileRasterizer(): void {
+    if (this.tileRasterizer) {
+      this.tileRasterizer.destroy();
+      this.tileRasterizer = null;
+    }
+  }
+
+  /**
+   * 创建拾取管线
+   */
+  private createPickPipelines(): void {
+    const pipelines = PipelineCache.get(this.renderer.device);
+    const createVariant = (vertexEntry: string, fragmentEntry: string): GPURenderPipeline => pipelines.getRenderPipeline({
+      layout: this.pipelineLayout,
+      vertex: { module: this.shaderModule, entryPoint: vertexEntry, buffers: [] },
+      fragment: {
+        module: this.shaderModule,
+        entryPoint: fragmentEntry,
+        targets: [{ format: "r32uint" }, { format: "r32float" }],
+      },
+      primitive: { topology: "triangle-strip" },
+      depthStencil: {
+        format: this.renderer.depthFormat,
+        depthWriteEnabled: true,
+        depthCompare: getDepthCompare(this.renderer.depthConfig),
+      },
+    });
+
+    this.pickPipeline = createVariant("vs_main", "fs_pick");
+    this.pickPipeline2DGS = createVariant("vs_main_2d", "fs_pick_2d");
+
+    // 混合深度：不做深度测试，预乘 alpha 混合（与标准混合模式相同）
+    const blend: GPUBlendComponent = { srcFactor: "one", dstFactor: "one-minus-src-alpha", operation: "add" };
+    const createBlendedVariant = (vertexEntry: string, fragmentEntry: string): GPURenderPipeline => pipelines.getRenderPipeline({
+      layout: this.pipelineLayout,
+      vertex: { module: this.shaderModule, entryPoint: vertexEntry, buffers: [] },
+      fragment: {
+        module: this.shaderModule,
+        entryPoint: fragmentEntry,
+        targets: [{ format: "rgba16float", blend: { color: blend, alpha: blend } }],
+      },
+      primitive: { topology: "triangle-strip" },
+    });
+    this.blendedDepthPipeline = createBlendedVariant("vs_main", "fs_pick_blended_depth");
+    this.blendedDepthPipeline2DGS = createBlendedVariant("vs_main_2d", "fs_pick_blended_depth_2d");
+  }
+
+  /**
+   * 拾取指定像素下最近的 splat（使用上一帧的相机和排序结果）
+   * @param x 画布像素坐标 x
+   * @param y 画布像素坐标 y
+   */
+  async pick(x: number, y: number): Promise<SplatPickResult | null> {
+    const px = Math.floor(x);
+    const py = Math.floor(y);
+    const region = await this.readPickRegion(px, py, 1, 1);
+    if (!region || region.ids[0] === 0) {
+      return null;
+    }
+    const depth = region.depths[0];
+    const ndcX = ((px + 0.5) / this.renderer.width) * 2 - 1;
+    const ndcY = 1 - ((py + 0.5) / this.renderer.height) * 2;
+    return {
+      index: region.ids[0] - 1,
+      depth,
+      worldPosition: unprojectPoint(this.camera.viewProjectionMatrix, ndcX, ndcY, depth),
+    };
+  }
+
+  /**
+   * 查询画布像素 (x, y) 处的 splat 深度和世界坐标（使用上一帧的相机和排序结果），未命中返回 null
+   * blended 模式在 rgba16float 中累加，距离的相对精度约 0.1%；累计不透明度过低的像素视为未命中
+   * @param x 画布像素坐标 x
+   * @param y 画布像素坐标 y
+   */
+  async depthAt(x: number, y: number, mode: SplatDepthQueryMode = "blended"): Promise<SplatDepthResult | null> {
+    const px = Math.floor(x);
+    const py = Math.floor(y);
+    // 回读在之后的帧到达，按提交时的相机重建坐标
+    const projection = this.camera.projectionMatrix.slice();
+    const viewProjection = this.camera.viewProjectionMatrix.slice();
+    let depth: number;
+    let coverage = 1;
+    if (mode === "nearest") {
+      const region = await this.readPickRegion(px, py, 1, 1);
+      if (!region || region.ids[0] === 0) {
+        return null;
+      }
+      depth = region.depths[0];
+    } else {
+      const sample = await this.readBlendedDepth(px, py);
+      if (!sample || sample.coverage < MIN_DEPTH_COVERAGE) {
+        return null;
+      }
+      coverage = sample.coverage;
+      depth = projection[14] / sample.distance - projection[10];
+    }
+    const ndcX = ((px + 0.5) / this.renderer.width) * 2 - 1;
+    const ndcY = 1 - ((py + 0.5) / this.renderer.height) * 2;
+    return {
+      depth,
+      distance: linearizeDepth(projection, depth),
+      worldPosition: unprojectPoint(viewProjection, ndcX, ndcY, depth),
+      coverage,
+    };
+  }
+
+  /**
+   * 拾取圆形区域内可见的最前面的 splat（每个像素取最近的 splat，被遮挡的 splat 不会命中）
+   * @param x 圆心画布像素坐标 x
+   * @param y 圆心画布像素坐标 y
+   * @param radius 半径（画布像素）
+   * @returns 命中的 splat 索引（去重，升序）
+   */
+  async pickRegion(x: number, y: number, radius: number): Promise<Uint32Array> {
+    const r = Math.max(0, radius);
+    const x0 = Math.max(0, Math.floor(x - r));
+    const y0 = Math.max(0, Math.floor(y - r));
+    const x1 = Math.min(this.renderer.width, Math.ceil(x + r) + 1);
+    const y1 = Math.min(this.renderer.height, Math.ceil(y + r) + 1);
+    if (x1 <= x0 || y1 <= y0) {
+      return new Uint32Array(0);
+    }
+    const region = await this.readPickRegion(x0, y0, x1 - x0, y1 - y0);
+    if (!region) {
+      return new Uint32Array(0);
+    }
+    const hits = new Set<number>();
+    const r2 = r * r;
+    for (let py = y0; py < y1; py++) {
+      const dy = py + 0.5 - y;
+      for (let px = x0; px < x1; px++) {
+        const dx = px + 0.5 - x;
+        if (dx * dx + dy * dy > r2) continue;
+        const id = region.ids[(py - y0) * region.stride + (px - x0)];
+        if (id !== 0) hits.add(id - 1);
+      }
+    }
+    return Uint32Array.from(hits).sort();
+  }
+
+  /**
+   * 查询中心投影落在圆形区域内的 splat（CPU 投影，包括被遮挡的 splat，不含已删除的 splat）
+   * @param x 圆心画布像素坐标 x
+   * @param y 圆心画布像素坐标 y
+   * @param radius 半径（画布像素）
+   * @returns 命中的 splat 索引（升序）
+   */
+  querySplatsInCircle(x: number, y: number, radius: number): Uint32Array {
+    if (!this.splats || this.splatCount === 0) {
+      return new Uint32Array(0);
+    }
+    const positions = this.splats.getData().positions;
+    const m = multiplyTransforms(this.camera.viewProjectionMatrix, this.modelMatrix);
+    const halfW = this.renderer.width * 0.5;
+    const halfH = this.renderer.height * 0.5;
+    const r2 = radius * radius;
+    const hits: number[] = [];
+    for (let i = 0; i < this.splatCount; i++) {
+      if (this.deleted && this.deleted[i] !== 0) continue;
+      const px = positions[i * 3 + 0];
+      const py = positions[i * 3 + 1];
+      const pz = positions[i * 3 + 2];
+      const w = m[3] * px + m[7] * py + m[11] * pz + m[15];
+      if (w <= 0) continue;
+      const sx = ((m[0] * px + m[4] * py + m[8] * pz + m[12]) / w + 1) * halfW;
+      const sy = (1 - (m[1] * px + m[5] * py + m[9] * pz + m[13]) / w) * halfH;
+      const dx = sx - x;
+      const dy = sy - y;
+      if (dx * dx + dy * dy <= r2) hits.push(i);
+    }
+    return Uint32Array.from(hits);
+  }
+
+  /**
+   * 查询中心投影落在屏幕多边形内的 splat（GPU 测试，包括被遮挡的 splat，不含已删除的 splat）
+   * @param points 多边形顶点（画布像素）
+   * @returns 命中的 splat 索引（升序）
+   */
+  async queryPolygon(points: ReadonlyArray<readonly [number, number]>): Promise<Uint32Array> {
+    if (!this.bindGroup || this.splatCount === 0 || points.length < 3) {
+      return new Uint32Array(0);
+    }
+    if (!this.polygonSelector) {
+      this.polygonSelector = new SplatPolygonSelector(
+        this.renderer.device,
+        gsOptimizedShader,
+        this.bindGroupLayout,
+        this.splatCount,
+      );
+    }
+    const hits = await this.polygonSelector.select(this.bindGroup, points);
+    const deleted = this.deleted;
+    return deleted && this.deletedCount > 0 ? hits.filter((i) => deleted[i] === 0) : hits;
+  }
+
+  /**
+   * 查询满足全部属性谓词的 splat（GPU 筛选，不含已删除的 splat）
+   * @returns 命中的 splat 索引（升序）
+   */
+  async queryAttributes(predicates: ReadonlyArray<SplatAttributePredicate>): Promise<Uint32Array> {
+    if (!this.bindGroup || this.splatCount === 0) {
+      return new Uint32Array(0);
+    }
+    if (!this.attributeSelector) {
+      this.attributeSelector = new SplatAttributeSelector(
+        this.renderer.device,
+        gsOptimizedShader,
+        this.bindGroupLayout,
+        this.splatCount,
+      );
+    }
+    const hits = await this.attributeSelector.select(this.bindGroup, predicates);
+    const deleted = this.deleted;
+    return deleted && this.deletedCount > 0 ? hits.filter((i) => deleted[i] === 0) : hits;
+  }
+
+  /**
+   * 回读整帧每个像素最近的 splat id 和视图空间距离（与拾取相同的 pass，使用上一帧的相机和排序结果）
+   */
+  async readIdDepth(): Promise<SplatIdDepth | null> {
+    const width = this.renderer.width;
+    const height = this.renderer.height;
+    const projection = this.camera.projectionMatrix.slice();
+    const region = await this.readPickRegion(0, 0, width, height);
+    return region ? createSplatIdDepth(region, width, height, projection) : null;
+  }
+
+  /**
+   * 渲染拾取 pass 并回读矩形区域的 splat id（索引 + 1，0 表示没有命中）和 NDC 深度
+   * stride 为每行的元素数（按 256 字节对齐）
+   */
+  private async readPickRegion(
+    x: number,
+    y: number,
+    regionWidth: number,
+    regionHeight: number,
+  ): Promise<{ ids: Uint32Array; depths: Float32Array; stride: number } | null> {
+    const width = this.renderer.width;
+    const height = this.renderer.height;
+    if (!this.bindGroup || !this.sorter || x < 0 || y < 0 || x + regionWidth > width || y + regionHeight > height) {
+      return null;
+    }
+    if (!this.pickPipeline) {
+      this.createPickPipelines();
+    }
+
+    const device = this.renderer.device;
+    const [idTexture, depthColorTexture, depthTexture] = SplatPickTargets.get(device).getPickTargets(width, height, this.renderer.depthFormat);
+    // copyTextureToBuffer 要求 bytesPerRow 256 对齐
+    const bytesPerRow = Math.ceil((regionWidth * 4) / 256) * 256;
+    const planeSize = bytesPerRow * regionHeight;
+    // 回读 buffer 从对象池申请，映射完成后归还（不阻塞渲染，结果在之后的帧到达）
+    const pool = SplatBufferPool.get(device);
+    const readBuffer = pool.acquire(planeSize * 2, GPUBufferUsage.COPY_DST | GPUBufferUsage.MAP_READ, "splat-pick-readback");
+
+    const encoder = device.createCommandEncoder({ label: "splat-pick-encoder" });
+    const pass = encoder.beginRenderPass({
+      colorAttachments: [
+        { view: idTexture.createView(), clearValue: { r: 0, g: 0, b: 0, a: 0 }, loadOp: "clear", storeOp: "store" },
+        { view: depthColorTexture.createView(), clearValue: { r: 0, g: 0, b: 0, a: 0 }, loadOp: "clear", storeOp: "store" },
+      ],
+      depthStencilAttachment: {
+        view: depthTexture.createView(),
+        depthClearValue: getDepthClearValue(this.renderer.depthConfig),
+        depthLoadOp: "clear",
+        depthStoreOp: "discard",
+      },
+    });
+    // 只光栅化目标区域
+    pass.setScissorRect(x, y, regionWidth, regionHeight);
+    pass.setPipeline(this.is2DGS ? this.pickPipeline2DGS! : this.pickPipeline!);
+    pass.setBindGroup(0, this.bindGroup);
+    this.drawSorted(pass, this.sorter.getDrawIndirectBuffer());
+    pass.end();
+    this.passTracker?.record("pick", "splat-pick", this.getDrawReads(), []);
+
+    const copySize = { width: regionWidth, height: regionHeight };
+    encoder.copyTextureToBuffer(
+      { texture: idTexture, origin: { x, y } },
+      { buffer: readBuffer, offset: 0, bytesPerRow },
+      copySize,
     );
-    this.renderer.device.queue.writeBuffer(
-      this.uniformBuffer, 192,
-      new Float32Array(this.camera.position),
+    encoder.copyTextureToBuffer(
+      { texture: depthColorTexture, origin: { x, y } },
+      { buffer: readBuffer, offset: planeSize, bytesPerRow },
+      copySize,
     );
-    this.renderer.device.queue.writeBuffer(
-      this.uniformBuffer, 208,
-      new Float32Array([this.renderer.width, this.renderer.height, 0, 0]),
+    device.queue.submit([encoder.finish()]);
+
+    try {
+      await readBuffer.mapAsync(GPUMapMode.READ, 0, planeSize * 2);
+      const data = readBuffer.getMappedRange(0, planeSize * 2);
+      const ids = new Uint32Array(data.slice(0, planeSize));
+      const depths = new Float32Array(data.slice(planeSize, planeSize * 2));
+      readBuffer.unmap();
+      pool.release(readBuffer);
+      return { ids, depths, stride: bytesPerRow / 4 };
+    } catch (error) {
+      // 映射失败（设备丢失 / 已销毁）时 buffer 不能再复用
+      readBuffer.destroy();
+      throw error;
+    }
+  }
+
+  /**
+   * 渲染混合深度 pass 并回读单个像素：按不透明度加权的视图空间距离和累计不透明度
+   */
+  private async readBlendedDepth(x: number, y: number): Promise<{ distance: number; coverage: number } | null> {
+    const width = this.renderer.width;
+    const height = this.renderer.height;
+    if (!this.bindGroup || !this.sorter || x < 0 || y < 0 || x >= width || y >= height) {
+      return null;
+    }
+    if (!this.blendedDepthPipeline) {
+      this.createPickPipelines();
+    }
+
+    const device = this.renderer.device;
+    const target = SplatPickTargets.get(device).getBlendedDepthTarget(width, height, this.renderer.depthFormat);
+    // copyTextureToBuffer 要求 bytesPerRow 256 对齐
+    const size = 256;
+    const pool = SplatBufferPool.get(device);
+    const readBuffer = pool.acquire(size, GPUBufferUsage.COPY_DST | GPUBufferUsage.MAP_READ, "splat-depth-readback");
+
+    const encoder = device.createCommandEncoder({ label: "splat-depth-encoder" });
+    const pass = encoder.beginRenderPass({
+      colorAttachments: [
+        { view: target.createView(), clearValue: { r: 0, g: 0, b: 0, a: 0 }, loadOp: "clear", storeOp: "store" },
+      ],
+    });
+    pass.setScissorRect(x, y, 1, 1);
+    pass.setPipeline(this.is2DGS ? this.blendedDepthPipeline2DGS! : this.blendedDepthPipeline!);
+    pass.setBindGroup(0, this.bindGroup);
+    this.drawSorted(pass, this.sorter.getDrawIndirectBuffer());
+    pass.end();
+    this.passTracker?.record("pick", "splat-depth", this.getDrawReads(), []);
+
+    encoder.copyTextureToBuffer(
+      { texture: target, origin: { x, y } },
+      { buffer: readBuffer, offset: 0, bytesPerRow: size },
+      { width: 1, height: 1 },
     );
+    device.queue.submit([encoder.finish()]);
+
+    try {
+      await readBuffer.mapAsync(GPUMapMode.READ, 0, size);
+      const halves = new Uint16Array(readBuffer.getMappedRange(0, 8).slice(0));
+      readBuffer.unmap();
+      pool.release(readBuffer);
+      const coverage = halfToFloat(halves[3]);
+      return { distance: coverage > 0 ? halfToFloat(halves[0]) / coverage : 0, coverage };
+    } catch (error) {
+      readBuffer.destroy();
+      throw error;
+    }
+  }
+
+
+  render(pass: GPURenderPassEncoder): void {
+    if (this.splatCount === 0 || !this.bindGroup || !this.sorter) {
+      return;
+    }
+    this.passTracker?.beginFrame();
+    this.syncSplatEdits();
+    if (this.directDraw === this.renderer.indirectDraw) {
+      this.directDraw = !this.renderer.indirectDraw;
+      this.applyVisibleCountReadback();
+    }
+
+    // 更新 uniforms（经共享上传区合并为一次写入，排序器提交前 flush）
+    const uploads = this.renderer.uploads;
+    const transforms = computeTransformUniforms(this.camera, this.modelMatrix, this.cameraRelative);
+    uploads.write(this.uniformBuffer, 0, transforms.view);
+    uploads.write(this.uniformBuffer, 64, this.camera.projectionMatrix);
+    uploads.write(this.uniformBuffer, 128, transforms.model);
+    uploads.write(this.uniformBuffer, 192, transforms.cameraPos);
+    uploads.writeF32(this.uniformBuffer, 208, this.renderer.width, this.renderer.height, this.maxSplatSize);
+
+    // 同步选择状态
+    // 选中状态 (bit 0) 和分组 id (bit 8~15) 打包上传
+    const groupsVersion = this.groups?.getVersion() ?? -1;
+    if (
+      this.selection && this.selectionBuffer &&
+      (this.selection.getVersion() !== this.selectionUploadVersion || groupsVersion !== this.groupsUploadVersion)
+    ) {
+      const data = this.selection.toGPUData();
+      if (this.groups) {
+        const ids = this.groups.ids;
+        for (let i = 0; i < data.length; i++) data[i] |= ids[i] << 8;
+      }
+      uploads.write(this.selectionBuffer, 0, data);
+      this.selectionUploadVersion = this.selection.getVersion();
+      this.groupsUploadVersion = groupsVersion;
+    }
+    const hasSelection = this.selection !== null && this.selection.getSelectedCount() > 0;
+    uploads.writeU32(this.uniformBuffer, 224, this.visMode, this.labelBuffer ? 1 : 0, hasSelection ? 1 : 0, this.colormap);
+    this.frameSeed = (this.frameSeed + 1) >>> 0;
+    const scalarParams = uploads.stage(this.uniformBuffer, 240, 16);
+    uploads.f32[scalarParams] = this.scalarRange[0];
+    uploads.f32[scalarParams + 1] = this.scalarRange[1];
+    uploads.u32[scalarParams + 2] = this.scalarBuffer ? 1 : 0;
+    uploads.u32[scalarParams + 3] = this.frameSeed;
+    uploads.writeF32(this.uniformBuffer, 256, this.exposure, this.volumeOpacity, this.camera.near, this.nearFadeDistance);
+    uploads.writeU32(this.uniformBuffer, 272, this.cameraInsideFade ? 1 : 0, this.shMode);
+    uploads.writeF32(this.uniformBuffer, 280, this.depthWriteAlpha, this.opacityScale);
+    uploads.writeF32(this.uniformBuffer, 288, this.shDistances[0], this.shDistances[1], this.shDistances[2]);
+    const pointSize = this.pointSize;
+    const densityCellSize = this.pointMode !== SplatPointMode.Off && pointSize.adaptive ? POINT_DENSITY_CELL_SIZE : 0;
+    uploads.writeF32(this.uniformBuffer, 304, pointSize.minSize, pointSize.maxSize, pointSize.coverage);
+    uploads.writeU32(this.uniformBuffer, 320, densityCellSize, this.pointMode === SplatPointMode.Centers ? 1 : 0);
+    uploads.write(this.uniformBuffer, SWAY_OFFSET, getSplatSwayUniforms(
+      this.swayConfig, (performance.now() - this.swayStartTime) / 1000, this.swayUniforms,
+    ));
+    uploads.write(this.uniformBuffer, COLOR_ADJUST_OFFSET, getColorAdjustUniforms(this.colorAdjustPreview, this.colorAdjustUniforms));
+    uploads.writeF32(this.uniformBuffer, APPEARANCE_OFFSET, this.appearanceBuffer ? this.appearanceBlend : 0);
+    uploads.write(this.uniformBuffer, OPACITY_EDIT_OFFSET, getOpacityEditUniforms(this.opacityEditPreview, this.opacityEditUniforms));
+    uploads.write(this.uniformBuffer, CROP_BOX_OFFSET, getCropBoxUniforms(this.cropBox, this.modelMatrix, this.cropBoxUniforms));
+    uploads.write(this.uniformBuffer, GROUP_TINTS_OFFSET, getSplatGroupUniforms(this.groups, this.groupUniforms));
+    uploads.writeU32(this.uniformBuffer, GROUP_PARAMS_OFFSET, getSplatGroupHiddenMask(this.groups));
+    uploads.write(this.uniformBuffer, HOVER_OFFSET, this.getHoverUniforms());
 
     // 更新排序器参数
     this.sorter.setScreenSize(this.renderer.width, this.renderer.height);
@@ -702,15 +3273,188 @@ export class GSSplatRenderer implements IGSSplatRendererWithCapabilities {
       nearPlane: this.camera.near,
       farPlane: this.camera.far,
       pixelThreshold: this.pixelCullThreshold,
+      cullCameraInside: this.cameraInsideCull,
+      depthKeyMode: this.depthKeyMode,
     });
+    this.sorter.setDensityCellSize(densityCellSize);
+    this.sorter.setChunkCullMatrices(this.camera.viewProjectionMatrix, this.modelMatrix, this.camera.projectionMatrix);
+    const pvs = this.chunkedCulling ? this.getPVS() : null;
+    this.sorter.setChunkVisibility(pvs ? pvs.getVisibilityFromWorld(this.camera.position, this.modelMatrix) : null);
+
+    // 随机透明度模式和深度输出时 splat 自身写入深度，不能用作遮挡源；点显示始终按深度排序后混合
+    const points = this.pointMode === SplatPointMode.Points;
+    const stochastic = this.rasterMode === SplatRasterMode.Stochastic && !points;
+    const depthWrite = this.depthWriteAlpha > 0 && !points && !stochastic;
+    const occlusion = this.occlusionCulling && !stochastic && !depthWrite;
+    this.sorter.setOcclusionDepth(
+      occlusion ? this.renderer.depthTexture : null,
+      this.renderer.depthConfig,
+    );
 
-    // 执行 GPU 排序
-    this.sorter.sort();
+    // 执行 GPU 排序 (随机透明度模式和顺序无关的混合模式只做剔除)
+    // 本实体的排序输入和数据都没有变化时复用上一帧的结果；遮挡剔除依赖逐帧变化的深度，不能复用
+    const cullOnly = !points && (stochastic || isOrderIndependentBlend(this.blendMode));
+    const keyBits = this.selectSortKeyBits();
+    if (this.sortCache.needsSort(this.camera, this.buildSortKey(cullOnly, keyBits, densityCellSize, occlusion)) || occlusion) {
+      this.sorter.sort(cullOnly, keyBits);
+    } else {
+      uploads.flush();
+    }
+    if (this.sorter.getDensityGridBuffer() !== this.boundDensityGrid) {
+      this.createBindGroup();
+    }
+    const bindGroup = this.bindGroup!;
+
+    if (points) {
+      this.drawPoints(pass, bindGroup, this.sorter.getDrawIndirectBuffer());
+      return;
+    }
+
+    if (depthWrite) {
+      pass.setPipeline(this.is2DGS ? this.pipeline2DGSDepth : this.pipelineDepth);
+      pass.setBindGroup(0, bindGroup);
+      this.drawSorted(pass, this.sorter.getDrawIndirectBuffer());
+      this.passTracker?.record("draw", "splat-depth", this.getDrawReads(), []);
+    }
+
+    // Tile 光栅化：在当前帧提交前完成 compute，随后合成到渲染通道
+    if (this.rasterMode === SplatRasterMode.Tile && !this.is2DGS && this.blendMode === SplatBlendMode.Standard) {
+      const tileRasterizer = this.getTileRasterizer();
+      tileRasterizer.rasterize(
+        bindGroup,
+        this.sorter.getDrawIndirectBuffer(),
+        this.renderer.width,
+        this.renderer.height,
+      );
+      tileRasterizer.composite(pass);
+      this.passTracker?.record("draw", "tile-raster", this.getDrawReads(), []);
+      if (this.pointMode === SplatPointMode.Centers) {
+        this.drawCenters(pass, bindGroup);
+      }
+      return;
+    }
 
     // 渲染
-    pass.setPipeline(this.pipeline);
-    pass.setBindGroup(0, this.bindGroup);
-    pass.drawIndirect(this.sorter.getDrawIndirectBuffer(), 0);
+    pass.setPipeline(this.getColorPipeline(stochastic ? "stochastic" : "quad"));
+    pass.setBindGroup(0, bindGroup);
+    this.drawSorted(pass, this.sorter.getDrawIndirectBuffer());
+    this.passTracker?.record("draw", "splat-draw", this.getDrawReads(), []);
+    if (this.pointMode === SplatPointMode.Centers) {
+      this.drawCenters(pass, bindGroup);
+    }
+  }
+
+  /**
+   * 以屏幕空间圆点绘制可见 splat（Points 模式或 Centers 叠加）
+   */
+  private drawPoints(pass: GPURenderPassEncoder, bindGroup: GPUBindGroup, indirectBuffer: GPUBuffer): void {
+    pass.setPipeline(this.getColorPipeline("points"));
+    pass.setBindGroup(0, bindGroup);
+    this.drawSorted(pass, indirectBuffer);
+    const reads = this.getDrawReads();
+    const densityGrid = this.sorter!.getDensityGridBuffer();
+    if (densityGrid) reads.push(densityGrid);
+    if (indirectBuffer !== this.sorter!.getDrawIndirectBuffer()) reads.push(indirectBuffer);
+    this.passTracker?.record("draw", "splat-points", reads, []);
+  }
+
+  /**
+   * 绘制排序结果：indirect 模式使用 indirectBuffer；直接绘制模式按回读的可见数量加余量绘制，
+   * 多出的实例由顶点着色器按 uniforms.visibleCount 剔除
+   */
+  private drawSorted(pass: GPURenderPassEncoder, indirectBuffer: GPUBuffer): void {
+    if (this.directDraw) {
+      pass.draw(4, getDirectDrawInstanceCount(this.sorter?.getVisibleCount() ?? -1, this.splatCount));
+    } else {
+      pass.drawIndirect(indirectBuffer, 0);
+    }
+  }
+
+  /**
+   * 绘制中心点叠加（按配置先过滤可见 splat；直接绘制模式下过滤结果数量未知，不过滤）
+   */
+  private drawCenters(pass: GPURenderPassEncoder, bindGroup: GPUBindGroup): void {
+    const sorter = this.sorter!;
+    if (this.directDraw || !needsCentersFilter(this.centersConfig)) {
+      this.drawPoints(pass, bindGroup, sorter.getDrawIndirectBuffer());
+      return;
+    }
+    if (!this.centersFilter) {
+      this.centersFilter = new SplatCentersFilter(
+        this.renderer.device,
+        gsOptimizedShader,
+        this.bindGroupLayout,
+        this.splatCount,
+      );
+      this.centersBindGroup = null;
+    }
+    const bounds = this.centersConfig.selection === "bounds" ? this.getSelectionBounds() : null;
+    this.centersFilter.filter(bindGroup, sorter.getDrawIndirectBuffer(), this.centersConfig, this.centersCursor, bounds);
+    this.passTracker?.record(
+      "draw", "centers-filter",
+      [this.uniformBuffer, sorter.getIndicesBuffer(), sorter.getDrawIndirectBuffer()],
+      [this.centersFilter.getIndicesBuffer(), this.centersFilter.getDrawIndirectBuffer()],
+    );
+    if (!this.centersBindGroup) {
+      this.centersBindGroup = this.createSplatBindGroup(this.splatBuffer!, this.centersFilter.getIndicesBuffer());
+    }
+    this.drawPoints(pass, this.centersBindGroup, this.centersFilter.getDrawIndirectBuffer());
+  }
+
+  /**
+   * 获取选择包围盒（模型空间，没有选中 splat 时为 null）
+   */
+  private getSelectionBounds(): { min: Vec3Tuple; max: Vec3Tuple } | null {
+    const version = this.selection?.getVersion() ?? -1;
+    if (version === this.selectionBoundsVersion) {
+      return this.selectionBounds;
+    }
+    this.selectionBoundsVersion = version;
+    this.selectionBounds = null;
+    if (!this.selection || !this.splats || this.selection.getSelectedCount() === 0) {
+      return null;
+    }
+    const mask = this.selection.getMask();
+    const positions = this.splats.getData().positions;
+    const min: Vec3Tuple = [Infinity, Infinity, Infinity];
+    const max: Vec3Tuple = [-Infinity, -Infinity, -Infinity];
+    for (let i = 0; i < mask.length; i++) {
+      if (mask[i] === 0) continue;
+      for (let axis = 0; axis < 3; axis++) {
+        const v = positions[i * 3 + axis];
+        if (v < min[axis]) min[axis] = v;
+        if (v > max[axis]) max[axis] = v;
+      }
+    }
+    this.selectionBounds = { min, max };
+    return this.selectionBounds;
+  }
+
+  private destroyAttributeSelector(): void {
+    this.attributeSelector?.destroy();
+    this.attributeSelector = null;
+  }
+
+  private destroyPolygonSelector(): void {
+    this.polygonSelector?.destroy();
+    this.polygonSelector = null;
+  }
+
+  private destroyCentersFilter(): void {
+    if (this.centersFilter) {
+      this.centersFilter.destroy();
+      this.centersFilter = null;
+    }
+    this.centersBindGroup = null;
+  }
+
+  /**
+   * 绘制 / 拾取读取的 splat buffer（用于 pass 校验）
+   */
+  private getDrawReads(): GPUBuffer[] {
+    const reads = [this.uniformBuffer, this.sorter!.getIndicesBuffer(), this.sorter!.getDrawIndirectBuffer()];
+    if (this.splatBuffer) reads.push(this.splatBuffer);
+    return reads;
   }
 
   getSplatCount(): number {
@@ -721,6 +3465,24 @@ export class GSSplatRenderer implements IGSSplatRendererWithCapabilities {
     return this.boundingBox;
   }
 
+  /**
+   * 获取位置相对的原点（f64 精度加载时为数据质心，否则为 0）
+   */
+  getOrigin(): Vec3Tuple {
+    return [...this.origin];
+  }
+
+  /**
+   * 计算位置统计（包含原点，结果为原始坐标）
+   */
+  getStatistics(precision: SplatPrecision = "f32"): SplatStatistics | null {
+    if (!this.splats) {
+      return null;
+    }
+    const data = this.splats.getData();
+    return computeSplatStatistics(data.positions, data.count, precision, this.origin);
+  }
+
   private computeBoundingBox(splats: SplatCPU[]): BoundingBox {
     if (splats.length === 0) {
       return { min: [0, 0, 0], max: [0, 0, 0], center: [0, 0, 0], radius: 0 };
@@ -801,16 +3563,114 @@ export class GSSplatRenderer implements IGSSplatRendererWithCapabilities {
     };
   }
 
+  /**
+   * 获取当前占用的显存（字节）：splat buffer、排序器、tile 光栅化器、中心点过滤器和逐 splat 属性 / 外观 buffer
+   */
+  getGPUMemoryUsage(): number {
+    return getBuffersByteSize([this.splatBuffer, this.labelBuffer, this.selectionBuffer, this.externalScalar ? null : this.scalarBuffer, this.appearanceBuffer])
+      + (this.sorter?.getGPUMemoryUsage() ?? 0)
+      + (this.tileRasterizer?.getGPUMemoryUsage() ?? 0)
+      + (this.centersFilter?.getGPUMemoryUsage() ?? 0)
+      + (this.polygonSelector?.getGPUMemoryUsage() ?? 0)
+      + (this.attributeSelector?.getGPUMemoryUsage() ?? 0)
+      + (this.externalPacker?.getGPUMemoryUsage() ?? 0);
+  }
+
+  /**
+   * 按显存预算降级：DropSH 把 SH 降到 L0（256 字节布局不变，只减少着色带宽）；
+   * 单个渲染器的 splat buffer 是一整块，不支持 Pack / EvictChunks（由上层切换或分段逐出）
+   * @returns 释放的字节数
+   */
+  downgradeMemory(step: SplatMemoryDowngrade): number {
+    if (step === SplatMemoryDowngrade.DropSH) {
+      this.shMode = SHMode.L0;
+    }
+    return 0;
+  }
+
+  /**
+   * 是否已逐出 GPU 数据
+   */
+  isGPUDataReleased(): boolean {
+    return this.splatCount > 0 && this.splatBuffer === null;
+  }
+
+  /**
+   * 逐出 GPU 数据：释放 splat buffer、排序器和 tile 光栅化器，CPU 数据、设置和选择集保留
+   * 逐出期间不绘制，restoreGPUData 后恢复
+   * @returns 释放的字节数
+   */
+  releaseGPUData(): number {
+    if (!this.splatBuffer) {
+      return 0;
+    }
+    const before = this.getGPUMemoryUsage();
+    SplatBufferPool.get(this.renderer.device).release(this.splatBuffer);
+    this.splatBuffer = null;
+    this.sorter?.destroy();
+    this.sorter = null;
+    this.destroyTileRasterizer();
+    this.destroyCentersFilter();
+    this.destroyPolygonSelector();
+    this.destroyAttributeSelector();
+    this.bindGroup = null;
+    return before - this.getGPUMemoryUsage();
+  }
+
+  /**
+   * 估算 restoreGPUData 需要分配的字节数（splat buffer + 排序器）
+   */
+  getReleasedGPUBytes(): number {
+    if (!this.isGPUDataReleased()) {
+      return 0;
+    }
+    // 排序器约为每 splat 6 个 u32（深度键、可见索引、排序临时区、输出索引）
+    return this.splatCount * (SPLAT_BYTE_SIZE + 24);
+  }
+
+  /**
+   * 从 CPU 数据重新上传被逐出的 GPU 数据
+   * @returns 新分配的字节数
+   */
+  restoreGPUData(): number {
+    if (!this.isGPUDataReleased() || !this.splats) {
+      return 0;
+    }
+    const before = this.getGPUMemoryUsage();
+    const device = this.renderer.device;
+    const data = this.splats.getData();
+    const gpuData = compactDataToGPUBuffer(data, data.shCoeffs !== undefined);
+    this.splatBuffer = SplatBufferPool.get(device).acquire(
+      gpuData.byteLength,
+      GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      "splats",
+    );
+    device.queue.writeBuffer(this.splatBuffer, 0, gpuData.buffer);
+    this.createSorter();
+    this.createBindGroup();
+    return this.getGPUMemoryUsage() - before;
+  }
+
   destroy(): void {
     if (this.splatBuffer) {
-      this.splatBuffer.destroy();
+      SplatBufferPool.get(this.renderer.device).release(this.splatBuffer);
       this.splatBuffer = null;
     }
     if (this.sorter) {
       this.sorter.destroy();
       this.sorter = null;
     }
+    this.destroyTileRasterizer();
+    this.destroyCentersFilter();
+    this.destroyPolygonSelector();
+    this.destroyAttributeSelector();
+    this.destroyAttributeBuffers();
+    this.destroyExternalPacker();
     this.uniformBuffer.destroy();
+    this.placeholderBuffer.destroy();
+    this.transferFunctionTexture.destroy();
+    this.octree = null;
+    this.splats = null;
     this.splatCount = 0;
     this.bindGroup = null;
   }
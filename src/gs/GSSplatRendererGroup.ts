@@ -0,0 +1,687 @@
+/**
+ * GSSplatRendererGroup - 超出单个渲染器上限的 splat 数据
+ *
+ * 数据按 SplatSegments 拆成若干段，每段一个 GSSplatRenderer 独立剔除、排序和绘制，
+ * 对外表现为一个渲染器：变换和渲染设置转发给所有段，查询结果合并，
+ * 拾取结果的索引映射回原数据。段内严格按深度排序，段之间按包围盒中心到相机的距离由远到近绘制。
+ * 显存预算不足时按段逐出最久未在视锥内出现的数据，回到视锥且预算允许时重新上传
+ */
+
+import { Renderer } from "../core/Renderer";
+import { Camera } from "../core/Camera";
+import { GSSplatRenderer } from "./GSSplatRenderer";
+import { getMaxSplatsPerSegment, splitSplatData } from "./SplatSegments";
+import { isBoxInFrustum } from "./SplatOctree";
+import { SplatMemoryDowngrade } from "./SplatMemoryBudget";
+import type { CompactSplatData } from "./PLYLoaderMobile";
+import type { IGSSplatRendererWithCapabilities } from "./IGSSplatRenderer";
+import type { TransformIssue } from "../utils/transform";
+import type { SplatLodConfig } from "./SplatLod";
+import { SplatPointSizeConfig, DEFAULT_SPLAT_POINT_SIZE_CONFIG } from "./SplatPoints";
+import { SplatCentersConfig, DEFAULT_SPLAT_CENTERS_CONFIG } from "./SplatCentersFilter";
+import type { SplatSortCacheStats } from "./SplatSortCache";
+import type { SplatPVSConfig } from "./SplatPVS";
+import { DEFAULT_SPLAT_SWAY_CONFIG } from "./SplatSway";
+import type { SplatSwayConfig } from "./SplatSway";
+import { selectSplatAppearance } from "./SplatAppearance";
+import type { SplatAppearanceData } from "./SplatAppearance";
+import type { SplatCropBox } from "./SplatCropBox";
+import type { SplatIdDepth } from "./SplatRenderLayers";
+import type { BoundingBox, Vec3Tuple, SplatPickResult, RendererCapabilities } from "../types";
+import { SHMode, SplatVisMode, Colormap, SplatRasterMode, SplatBlendMode, SplatPointMode, SplatDepthKeyMode } from "../types";
+
+interface SegmentRenderer {
+  renderer: GSSplatRenderer;
+  indices: Uint32Array;
+  // 最近一次在视锥内的帧
+  lastViewedFrame: number;
+}
+
+/**
+ * 分段 splat 渲染器
+ */
+export class GSSplatRendererGroup implements IGSSplatRendererWithCapabilities {
+  private renderer: Renderer;
+  private camera: Camera;
+  private maxSplatsPerSegment: number;
+  private segments: SegmentRenderer[] = [];
+  private splatCount: number = 0;
+  private boundingBox: BoundingBox | null = null;
+  private frame: number = 0;
+
+  private position: Vec3Tuple = [0, 0, 0];
+  private rotation: Vec3Tuple = [0, 0, 0];
+  private scale: Vec3Tuple = [1, 1, 1];
+  private pivot: Vec3Tuple = [0, 0, 0];
+  private parentMatrix: Float32Array | null = null;
+  private identity: Float32Array = new Float32Array([1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1]);
+
+  /**
+   * @param maxSplatsPerSegment 每段最多的 splat 数量，默认取设备上限
+   */
+  constructor(renderer: Renderer, camera: Camera, maxSplatsPerSegment?: number) {
+    this.renderer = renderer;
+    this.camera = camera;
+    this.maxSplatsPerSegment = maxSplatsPerSegment ?? getMaxSplatsPerSegment(renderer.device);
+  }
+
+  setCompactData(data: CompactSplatData): void {
+    this.destroySegments();
+    for (const segment of splitSplatData(data, this.maxSplatsPerSegment)) {
+      const renderer = new GSSplatRenderer(this.renderer, this.camera);
+      renderer.setCompactData(segment.data);
+      renderer.setPosition(...this.position);
+      renderer.setRotation(...this.rotation);
+      renderer.setScale(...this.scale);
+      renderer.setPivot(...this.pivot);
+      renderer.setParentMatrix(this.parentMatrix);
+      this.segments.push({ renderer, indices: segment.indices, lastViewedFrame: this.frame });
+    }
+    this.splatCount = data.count;
+    this.boundingBox = this.mergeBoundingBoxes();
+  }
+
+  /**
+   * 获取分段数量
+   */
+  getSegmentCount(): number {
+    return this.segments.length;
+  }
+
+  render(pass: GPURenderPassEncoder): void {
+    if (this.segments.length === 0) {
+      return;
+    }
+    this.frame++;
+    // 段之间由远到近
+    const cameraPos = this.camera.position;
+    const distances = new Map<SegmentRenderer, number>();
+    for (const segment of this.segments) {
+      if (this.isSegmentInView(segment)) {
+        segment.lastViewedFrame = this.frame;
+      }
+      const center = this.segmentWorldCenter(segment.renderer);
+      const dx = center[0] - cameraPos[0];
+      const dy = center[1] - cameraPos[1];
+      const dz = center[2] - cameraPos[2];
+      distances.set(segment, dx * dx + dy * dy + dz * dz);
+    }
+    const ordered = [...this.segments].sort((a, b) => distances.get(b)! - distances.get(a)!);
+    for (const segment of ordered) {
+      segment.renderer.render(pass);
+    }
+  }
+
+  private segmentWorldCenter(renderer: GSSplatRenderer): Vec3Tuple {
+    const box = renderer.getBoundingBox();
+    const c = box ? box.center : [0, 0, 0];
+    const m = renderer.getModelMatrix();
+    return [
+      m[0] * c[0] + m[4] * c[1] + m[8] * c[2] + m[12],
+      m[1] * c[0] + m[5] * c[1] + m[9] * c[2] + m[13],
+      m[2] * c[0] + m[6] * c[1] + m[10] * c[2] + m[14],
+    ];
+  }
+
+  private isSegmentInView(segment: SegmentRenderer): boolean {
+    const box = segment.renderer.getBoundingBox();
+    return box !== null && isBoxInFrustum(
+      box.min, box.max, this.camera.viewProjectionMatrix, segment.renderer.getModelMatrix(),
+    );
+  }
+
+  private forEach(fn: (renderer: GSSplatRenderer) => void): void {
+    for (const segment of this.segments) {
+      fn(segment.renderer);
+    }
+  }
+
+  private first(): GSSplatRenderer | null {
+    return this.segments.length > 0 ? this.segments[0].renderer : null;
+  }
+
+  // ============================================
+  // 变换
+  // ============================================
+
+  setPosition(x: number, y: number, z: number): void {
+    this.position = [x, y, z];
+    this.forEach((r) => r.setPosition(x, y, z));
+  }
+
+  getPosition(): Vec3Tuple {
+    return [...this.position];
+  }
+
+  setRotation(x: number, y: number, z: number): void {
+    this.rotation = [x, y, z];
+    this.forEach((r) => r.setRotation(x, y, z));
+  }
+
+  getRotation(): Vec3Tuple {
+    return [...this.rotation];
+  }
+
+  setScale(x: number, y: number, z: number): void {
+    this.scale = [x, y, z];
+    this.forEach((r) => r.setScale(x, y, z));
+  }
+
+  getScale(): Vec3Tuple {
+    return [...this.scale];
+  }
+
+  setPivot(x: number, y: number, z: number): void {
+    this.pivot = [x, y, z];
+    this.forEach((r) => r.setPivot(x, y, z));
+  }
+
+  getPivot(): Vec3Tuple {
+    return [...this.pivot];
+  }
+
+  getModelMatrix(): Float32Array {
+    return this.first()?.getModelMatrix() ?? this.identity;
+  }
+
+  setParentMatrix(matrix: Float32Array | null): void {
+    this.parentMatrix = matrix ? new Float32Array(matrix) : null;
+    this.forEach((r) => r.setParentMatrix(matrix));
+  }
+
+  getParentMatrix(): Float32Array | null {
+    return this.parentMatrix;
+  }
+
+  setTransformAutoCorrect(enabled: boolean): void {
+    this.forEach((r) => r.setTransformAutoCorrect(enabled));
+  }
+
+  getTransformIssues(): TransformIssue[] {
+    return this.first()?.getTransformIssues() ?? [];
+  }
+
+  setCameraRelative(enabled: boolean): void {
+    this.forEach((r) => r.setCameraRelative(enabled));
+  }
+
+  isCameraRelative(): boolean {
+    return this.first()?.isCameraRelative() ?? true;
+  }
+
+  // ============================================
+  // 渲染设置（转发给所有段）
+  // ============================================
+
+  setExposure(exposure: number): void {
+    this.forEach((r) => r.setExposure(exposure));
+  }
+
+  getExposure(): number {
+    return this.first()?.getExposure() ?? 1;
+  }
+
+  setOpacity(opacity: number): void {
+    this.forEach((r) => r.setOpacity(opacity));
+  }
+
+  getOpacity(): number {
+    return this.first()?.getOpacity() ?? 1;
+  }
+
+  setBlendMode(mode: SplatBlendMode): void {
+    this.forEach((r) => r.setBlendMode(mode));
+  }
+
+  getBlendMode(): SplatBlendMode {
+    return this.first()?.getBlendMode() ?? SplatBlendMode.Standard;
+  }
+
+  setChunkedCulling(enabled: boolean): void {
+    this.forEach((r) => r.setChunkedCulling(enabled));
+  }
+
+  isChunkedCulling(): boolean {
+    return this.first()?.isChunkedCulling() ?? true;
+  }
+
+  setVisibleCountReadback(interval: number): void {
+    this.forEach((r) => r.setVisibleCountReadback(interval));
+  }
+
+  getVisibleCount(): number {
+    let total = 0;
+    for (const segment of this.segments) {
+      const count = segment.renderer.getVisibleCount();
+      if (count < 0) {
+        return -1;
+      }
+      total += count;
+    }
+    return this.segments.length > 0 ? total : -1;
+  }
+
+  async buildPVS(config: Partial<SplatPVSConfig> = {}): Promise<void> {
+    for (const segment of this.segments) {
+      await segment.renderer.buildPVS(config);
+    }
+  }
+
+  clearPVS(): void {
+    this.forEach((r) => r.clearPVS());
+  }
+
+  setOcclusionCulling(enabled: boolean): void {
+    this.forEach((r) => r.setOcclusionCulling(enabled));
+  }
+
+  isOcclusionCulling(): boolean {
+    return this.first()?.isOcclusionCulling() ?? false;
+  }
+
+  setSortAmortization(interval: number): void {
+    this.forEach((r) => r.setSortAmortization(interval));
+  }
+
+  getSortAmortization(): number {
+    return this.first()?.getSortAmortization() ?? 0;
+  }
+
+  setPixelCullThreshold(threshold: number): void {
+    this.forEach((r) => r.setPixelCullThreshold(threshold));
+  }
+
+  setMaxSplatSize(size: number): void {
+    this.forEach((r) => r.setMaxSplatSize(size));
+  }
+
+  getMaxSplatSize(): number {
+    return this.first()?.getMaxSplatSize() ?? 0;
+  }
+
+  setSway(config: Partial<SplatSwayConfig>): void {
+    this.forEach((r) => r.setSway(config));
+  }
+
+  getSway(): SplatSwayConfig {
+    return this.first()?.getSway() ?? { ...DEFAULT_SPLAT_SWAY_CONFIG };
+  }
+
+  // 按段的全局索引拆分外观数据
+  setSecondaryAppearance(data: SplatAppearanceData | null): void {
+    if (data && data.count !== this.splatCount) {
+      throw new Error(`外观数量 (${data.count}) 与 splat 数量 (${this.splatCount}) 不一致`);
+    }
+    for (const segment of this.segments) {
+      segment.renderer.setSecondaryAppearance(data ? selectSplatAppearance(data, segment.indices) : null);
+    }
+  }
+
+  hasSecondaryAppearance(): boolean {
+    return this.first()?.hasSecondaryAppearance() ?? false;
+  }
+
+  setAppearanceBlend(t: number): void {
+    this.forEach((r) => r.setAppearanceBlend(t));
+  }
+
+  getAppearanceBlend(): number {
+    return this.first()?.getAppearanceBlend() ?? 0;
+  }
+
+  // 裁剪盒在世界空间，各段共用
+  setCropBox(cropBox: SplatCropBox | null): void {
+    this.forEach((r) => r.setCropBox(cropBox));
+  }
+
+  getCropBox(): SplatCropBox | null {
+    return this.first()?.getCropBox() ?? null;
+  }
+
+  // 每段各自判断是否复用：只有被编辑的段重新排序
+  setSortReuse(enabled: boolean): void {
+    this.forEach((r) => r.setSortReuse(enabled));
+  }
+
+  isSortReuse(): boolean {
+    return this.first()?.isSortReuse() ?? true;
+  }
+
+  getSortReuseStats(): SplatSortCacheStats {
+    const stats: SplatSortCacheStats = { sortedFrames: 0, skippedFrames: 0 };
+    this.forEach((r) => {
+      const s = r.getSortReuseStats();
+      stats.sortedFrames += s.sortedFrames;
+      stats.skippedFrames += s.skippedFrames;
+    });
+    return stats;
+  }
+
+  setDepthKeyMode(mode: SplatDepthKeyMode): void {
+    this.forEach((r) => r.setDepthKeyMode(mode));
+  }
+
+  getDepthKeyMode(): SplatDepthKeyMode {
+    return this.first()?.getDepthKeyMode() ?? SplatDepthKeyMode.Float;
+  }
+
+  setCameraInsideFade(enabled: boolean): void {
+    this.forEach((r) => r.setCameraInsideFade(enabled));
+  }
+
+  isCameraInsideFade(): boolean {
+    return this.first()?.isCameraInsideFade() ?? true;
+  }
+
+  setCameraInsideCull(enabled: boolean): void {
+    this.forEach((r) => r.setCameraInsideCull(enabled));
+  }
+
+  isCameraInsideCull(): boolean {
+    return this.first()?.isCameraInsideCull() ?? false;
+  }
+
+  setNearFadeDistance(distance: number): void {
+    this.forEach((r) => r.setNearFadeDistance(distance));
+  }
+
+  getNearFadeDistance(): number {
+    return this.first()?.getNearFadeDistance() ?? 0;
+  }
+
+  setSHDistances(distances: Vec3Tuple | null): void {
+    this.forEach((r) => r.setSHDistances(distances));
+  }
+
+  getSHDistances(): Vec3Tuple {
+    return this.first()?.getSHDistances() ?? [0, 0, 0];
+  }
+
+  /**
+   * 设置 LOD 配置，预算按各段的 splat 数量比例分配
+   */
+  setLodConfig(config: Partial<SplatLodConfig> | null): void {
+    for (const segment of this.segments) {
+      if (config && config.budget) {
+        const share = segment.indices.length / Math.max(1, this.splatCount);
+        segment.renderer.setLodConfig({ ...config, budget: Math.max(1, Math.round(config.budget * share)) });
+      } else {
+        segment.renderer.setLodConfig(config);
+      }
+    }
+  }
+
+  getLodConfig(): SplatLodConfig | null {
+    const config = this.first()?.getLodConfig() ?? null;
+    if (!config || !config.budget) {
+      return config;
+    }
+    let budget = 0;
+    this.forEach((r) => { budget += r.getLodConfig()?.budget ?? 0; });
+    return { ...config, budget };
+  }
+
+  setSHMode(mode: SHMode): void {
+    this.forEach((r) => r.setSHMode(mode));
+  }
+
+  getSHMode(): SHMode {
+    return this.first()?.getSHMode() ?? SHMode.L0;
+  }
+
+  supportsSHMode(mode: SHMode): boolean {
+    return mode >= SHMode.L0 && mode <= SHMode.L3;
+  }
+
+  isSurfelData(): boolean {
+    return this.first()?.isSurfelData() ?? false;
+  }
+
+  setVisMode(mode: SplatVisMode | string): void {
+    this.forEach((r) => r.setVisMode(mode));
+  }
+
+  getVisMode(): SplatVisMode {
+    return this.first()?.getVisMode() ?? SplatVisMode.Color;
+  }
+
+  setColormap(colormap: Colormap): void {
+    this.forEach((r) => r.setColormap(colormap));
+  }
+
+  getColormap(): Colormap {
+    return this.first()?.getColormap() ?? Colormap.Viridis;
+  }
+
+  setVolumeOpacity(scale: number): void {
+    this.forEach((r) => r.setVolumeOpacity(scale));
+  }
+
+  getVolumeOpacity(): number {
+    return this.first()?.getVolumeOpacity() ?? 1;
+  }
+
+  setRasterMode(mode: SplatRasterMode): void {
+    this.forEach((r) => r.setRasterMode(mode));
+  }
+
+  getRasterMode(): SplatRasterMode {
+    return this.first()?.getRasterMode() ?? SplatRasterMode.Quad;
+  }
+
+  setMaxSplatsPerPixel(count: number): void {
+    this.forEach((r) => r.setMaxSplatsPerPixel(count));
+  }
+
+  getMaxSplatsPerPixel(): number {
+    return this.first()?.getMaxSplatsPerPixel() ?? 0;
+  }
+
+  setDepthWrite(alphaThreshold: number): void {
+    this.forEach((r) => r.setDepthWrite(alphaThreshold));
+  }
+
+  getDepthWrite(): number {
+    return this.first()?.getDepthWrite() ?? 0;
+  }
+
+  // 自适应点尺寸按段各自统计密度（段之间空间上基本不重叠）
+  setPointMode(mode: SplatPointMode): void {
+    this.forEach((r) => r.setPointMode(mode));
+  }
+
+  getPointMode(): SplatPointMode {
+    return this.first()?.getPointMode() ?? SplatPointMode.Off;
+  }
+
+  setPointSizeConfig(config: Partial<SplatPointSizeConfig>): void {
+    this.forEach((r) => r.setPointSizeConfig(config));
+  }
+
+  getPointSizeConfig(): SplatPointSizeConfig {
+    return this.first()?.getPointSizeConfig() ?? { ...DEFAULT_SPLAT_POINT_SIZE_CONFIG };
+  }
+
+  setCentersConfig(config: Partial<SplatCentersConfig>): void {
+    this.forEach((r) => r.setCentersConfig(config));
+  }
+
+  getCentersConfig(): SplatCentersConfig {
+    return this.first()?.getCentersConfig() ?? { ...DEFAULT_SPLAT_CENTERS_CONFIG };
+  }
+
+  setCentersCursor(cursor: [number, number] | null): void {
+    this.forEach((r) => r.setCentersCursor(cursor));
+  }
+
+  // ============================================
+  // 拾取
+  // ============================================
+
+  /**
+   * 逐段拾取，取离相机最近的命中，索引映射回原数据
+   */
+  async pick(x: number, y: number): Promise<SplatPickResult | null> {
+    const results = await Promise.all(this.segments.map((segment) => segment.renderer.pick(x, y)));
+    const cameraPos = this.camera.position;
+    let best: SplatPickResult | null = null;
+    let bestDistance = Infinity;
+    for (let i = 0; i < results.length; i++) {
+      const result = results[i];
+      if (!result) continue;
+      const [wx, wy, wz] = result.worldPosition;
+      const dx = wx - cameraPos[0];
+      const dy = wy - cameraPos[1];
+      const dz = wz - cameraPos[2];
+      const distance = dx * dx + dy * dy + dz * dz;
+      if (distance < bestDistance) {
+        bestDistance = distance;
+        best = { ...result, index: this.segments[i].indices[result.index] };
+      }
+    }
+    return best;
+  }
+
+  /**
+   * 逐段回读，每个像素取距离最近的段，索引映射回原数据
+   */
+  async readIdDepth(): Promise<SplatIdDepth | null> {
+    const results = await Promise.all(this.segments.map((segment) => segment.renderer.readIdDepth()));
+    let merged: SplatIdDepth | null = null;
+    for (let s = 0; s < results.length; s++) {
+      const result = results[s];
+      if (!result) continue;
+      if (!merged) {
+        merged = {
+          width: result.width,
+          height: result.height,
+          ids: new Uint32Array(result.ids.length),
+          depths: new Float32Array(result.depths.length).fill(Infinity),
+        };
+      }
+      const indices = this.segments[s].indices;
+      for (let i = 0; i < result.ids.length; i++) {
+        if (result.ids[i] !== 0 && result.depths[i] < merged.depths[i]) {
+          merged.ids[i] = indices[result.ids[i] - 1] + 1;
+          merged.depths[i] = result.depths[i];
+        }
+      }
+    }
+    return merged;
+  }
+
+  // ============================================
+  // 查询
+  // ============================================
+
+  getSplatCount(): number {
+    return this.splatCount;
+  }
+
+  getBoundingBox(): BoundingBox | null {
+    return this.boundingBox;
+  }
+
+  private mergeBoundingBoxes(): BoundingBox | null {
+    let min: Vec3Tuple | null = null;
+    let max: Vec3Tuple | null = null;
+    for (const segment of this.segments) {
+      const box = segment.renderer.getBoundingBox();
+      if (!box) continue;
+      min = min ? [Math.min(min[0], box.min[0]), Math.min(min[1], box.min[1]), Math.min(min[2], box.min[2])] : [...box.min];
+      max = max ? [Math.max(max[0], box.max[0]), Math.max(max[1], box.max[1]), Math.max(max[2], box.max[2])] : [...box.max];
+    }
+    if (!min || !max) {
+      return null;
+    }
+    const [x0, y0, z0] = min;
+    const [x1, y1, z1] = max;
+    const dx = x1 - x0;
+    const dy = y1 - y0;
+    const dz = z1 - z0;
+    return {
+      min,
+      max,
+      center: [(x0 + x1) / 2, (y0 + y1) / 2, (z0 + z1) / 2],
+      radius: Math.sqrt(dx * dx + dy * dy + dz * dz) / 2,
+    };
+  }
+
+  getCapabilities(): RendererCapabilities {
+    return {
+      maxSHMode: SHMode.L3,
+      supportsRawData: false,
+      isMobileOptimized: false,
+      maxSplatCount: 0,
+    };
+  }
+
+  // ============================================
+  // 显存预算
+  // ============================================
+
+  getGPUMemoryUsage(): number {
+    let total = 0;
+    this.forEach((r) => { total += r.getGPUMemoryUsage(); });
+    return total;
+  }
+
+  /**
+   * 按显存预算降级：EvictChunks 每次逐出一个最久未在视锥内、且本帧不可见的段
+   * @returns 释放的字节数
+   */
+  downgradeMemory(step: SplatMemoryDowngrade): number {
+    if (step === SplatMemoryDowngrade.DropSH) {
+      this.forEach((r) => r.downgradeMemory(step));
+      return 0;
+    }
+    if (step !== SplatMemoryDowngrade.EvictChunks) {
+      return 0;
+    }
+    let victim: SegmentRenderer | null = null;
+    for (const segment of this.segments) {
+      if (segment.renderer.isGPUDataReleased() || segment.lastViewedFrame >= this.frame) continue;
+      if (!victim || segment.lastViewedFrame < victim.lastViewedFrame) {
+        victim = segment;
+      }
+    }
+    return victim ? victim.renderer.releaseGPUData() : 0;
+  }
+
+  /**
+   * 重新上传本帧可见的已逐出段（总量不超过 availableBytes）
+   * @returns 新分配的字节数
+   */
+  restoreMemory(availableBytes: number): number {
+    let allocated = 0;
+    for (const segment of this.segments) {
+      const renderer = segment.renderer;
+      if (!renderer.isGPUDataReleased() || segment.lastViewedFrame < this.frame) continue;
+      if (allocated + renderer.getReleasedGPUBytes() > availableBytes) continue;
+      allocated += renderer.restoreGPUData();
+    }
+    return allocated;
+  }
+
+  /**
+   * 获取已逐出的段数量
+   */
+  getEvictedSegmentCount(): number {
+    return this.segments.filter((s) => s.renderer.isGPUDataReleased()).length;
+  }
+
+  // ============================================
+  // 生命周期
+  // ============================================
+
+  private destroySegments(): void {
+    this.forEach((r) => r.destroy());
+    this.segments = [];
+  }
+
+  destroy(): void {
+    this.destroySegments();
+    this.splatCount = 0;
+    this.boundingBox = null;
+  }
+}
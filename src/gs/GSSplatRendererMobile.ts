@@ -2,10 +2,12 @@
  * GSSplatRendererMobile - 移动端优化的 3D Gaussian Splatting 渲染器
  *
  * 优化特点：
- * 1. 使用纹理存储 splat 数据，减少 GPU 内存占用 (~52 bytes/splat vs 256 bytes)
- * 2. 仅支持 L0 模式（无 SH 计算）
- * 3. 从纹理采样获取 splat 属性（使用 RGBA32Float 保证精度）
+ * 1. 使用纹理存储 splat 数据，减少 GPU 内存占用 (32~52 bytes/splat vs 256 bytes)
+ * 2. SH 按 PackModeConfig.sh 存储（默认不存储，仅 L0）
+ * 3. 从纹理采样获取 splat 属性（rotation / position / SH 编码可通过 PackModeConfig 调整，
+ *    量化位置时数据先按八叉树顺序重排，使每块 splat 空间紧凑）
  * 4. 使用简化的排序器
+ * 5. 2DGS (surfel) 数据使用 2D 高斯核变体
  */
 
 import { Renderer } from "../core/Renderer";
@@ -13,19 +15,34 @@ import { Camera } from "../core/Camera";
 import { CompactSplatData } from "./PLYLoaderMobile";
 import {
   CompressedSplatTextures,
+  PackModeConfig,
+  DEFAULT_PACK_MODE_CONFIG,
   compressSplatsToTextures,
   destroyCompressedTextures,
+  getRotationDecodeWGSL,
+  getPositionDecodeWGSL,
+  getPositionSampleType,
+  getSHDecodeWGSL,
+  getSHSampleType,
 } from "./TextureCompressor";
+import { buildSplatOctree } from "./SplatOctree";
+import { gatherSplatData } from "./SplatSegments";
 import { GSSplatSorterMobile } from "./GSSplatSorterMobile";
+import { computeTransformUniforms } from "./TransformUniforms";
+import { validateTransform, multiplyTransforms } from "../utils/transform";
+import type { TransformIssue } from "../utils/transform";
 import type { BoundingBox, Vec3Tuple } from "../types";
-import { SHMode, RendererCapabilities } from "../types";
+import { SHMode, SplatBlendMode, RendererCapabilities } from "../types";
+import { getSplatBlendState } from "./SplatBlend";
 import type { IGSSplatRenderer, IGSSplatRendererWithCapabilities } from "./IGSSplatRenderer";
+import { getDirectDrawInstanceCount } from "./SplatDirectDraw";
 
 // 重新导出类型以保持向后兼容
 export type { BoundingBox };
 
 // ============================================
-// 移动端 L0 Shader - 从纹理采样数据（简化版）
+// 移动端 Shader - 从纹理采样数据（简化版）
+// {{DECODE_ROTATION}} / {{DECODE_POSITION}} / {{DECODE_SH}} 按打包配置替换为对应的解码函数
 // ============================================
 const shaderCodeMobileL0 = /* wgsl */ `
 struct Uniforms {
@@ -35,20 +52,29 @@ struct Uniforms {
   cameraPos: vec3<f32>,
   _pad: f32,
   screenSize: vec2<f32>,
-  _pad2: vec2<f32>,
+  opacityScale: f32,       // 整体不透明度倍数（图层淡入淡出）
+  _pad2: f32,
   textureSize: vec2<f32>,  // 纹理尺寸 (用于坐标计算)
-  _pad3: vec2<f32>,
+  exposure: f32,           // 曝光倍数
+  maxColor: f32,           // 颜色上限 (LDR 为 1，HDR 不截断)
+  visibleCount: u32,       // 本帧可见数量（排序器拷贝），直接绘制时剔除多出的实例
+  shRange: f32,            // SH 11-10-11 量化范围
+  shDegree: u32,           // SH 阶数 (SHMode)，没有 SH 数据时为 0
 }
 
 @group(0) @binding(0) var<uniform> uniforms: Uniforms;
 @group(0) @binding(1) var<storage, read> sortedIndices: array<u32>;
 
-// 纹理绑定 - 4 张纹理（使用 RGBA32Float 保证精度）
-@group(1) @binding(0) var positionTex: texture_2d<f32>;   // RGBA32Float: xyz + unused
-@group(1) @binding(1) var scaleRotTex1: texture_2d<f32>;  // RGBA32Float: scale_xyz + rot_w
-@group(1) @binding(2) var scaleRotTex2: texture_2d<f32>;  // RGBA32Float: rot_xyz + unused
+// 纹理绑定 - 4 张纹理（量化位置时另有块包围盒纹理）
+// positionTex (binding 0) 按打包配置在 {{DECODE_POSITION}} 中声明
+@group(1) @binding(1) var scaleTex: texture_2d<f32>;      // RGBA32Float: scale_xyz + unused
+@group(1) @binding(2) var rotationTex: texture_2d<f32>;   // 按打包配置编码的 rotation
 @group(1) @binding(3) var colorTex: texture_2d<f32>;      // RGBA8Unorm: rgb + opacity
 
+{{DECODE_ROTATION}}
+{{DECODE_POSITION}}
+{{DECODE_SH}}
+
 struct VertexOutput {
   @builtin(position) position: vec4<f32>,
   @location(0) localUV: vec2<f32>,
@@ -64,6 +90,8 @@ const QUAD_POSITIONS = array<vec2<f32>, 4>(
 );
 
 const ELLIPSE_SCALE: f32 = 3.0;
+// 2DGS surfel quad 覆盖的 σ 倍数
+const SURFEL_EXTENT: f32 = 3.0;
 
 // 将索引转换为纹理坐标
 fn indexToTexCoord(index: u32) -> vec2<u32> {
@@ -87,22 +115,17 @@ fn quatToMat3(q: vec4<f32>) -> mat3x3<f32> {
   );
 }
 
-// 从模型矩阵提取统一缩放因子（取 X 轴向量长度）
-fn getModelScale(model: mat4x4<f32>) -> f32 {
-  return length(model[0].xyz);
-}
-
 // 计算 2D 协方差
-fn computeCov2D(mean: vec3<f32>, scale: vec3<f32>, rotation: vec4<f32>, modelView: mat4x4<f32>, proj: mat4x4<f32>, modelScale: f32) -> vec3<f32> {
+// 模型缩放（包括非均匀缩放和剪切）完全由 modelView 的 3x3 线性部分 A 处理：
+// Σ_view = A Σ Aᵀ，不需要再单独提取缩放因子
+fn computeCov2D(mean: vec3<f32>, scale: vec3<f32>, rotation: vec4<f32>, modelView: mat4x4<f32>, proj: mat4x4<f32>) -> vec3<f32> {
   let R = quatToMat3(rotation);
-  // 应用模型缩放到 splat scale
-  let scaledScale = scale * modelScale;
-  let s2 = scaledScale * scaledScale;
+  let s2 = scale * scale;
   let M = mat3x3<f32>(R[0] * s2.x, R[1] * s2.y, R[2] * s2.z);
   let Sigma = M * transpose(R);
   let viewPos = (modelView * vec4<f32>(mean, 1.0)).xyz;
-  let viewRot = mat3x3<f32>(modelView[0].xyz, modelView[1].xyz, modelView[2].xyz);
-  let SigmaView = viewRot * Sigma * transpose(viewRot);
+  let A = mat3x3<f32>(modelView[0].xyz, modelView[1].xyz, modelView[2].xyz);
+  let SigmaView = A * Sigma * transpose(A);
   let fx = proj[0][0]; let fy = proj[1][1];
   let z = -viewPos.z;
   let z_clamped = max(z, 0.001);
@@ -116,6 +139,55 @@ fn computeCov2D(mean: vec3<f32>, scale: vec3<f32>, rotation: vec4<f32>, modelVie
   return vec3<f32>(dot(j1, Sj1), dot(j1, Sj2), dot(j2, Sj2));
 }
 
+const SH_C1: f32 = 0.4886025119029199;
+const SH_C2 = array<f32, 5>(1.0925484305920792, -1.0925484305920792, 0.31539156525252005, -1.0925484305920792, 0.5462742152960396);
+const SH_C3 = array<f32, 7>(
+  -0.5900435899266435, 2.890611442640554, -0.4570457994644658, 0.3731763325901154,
+  -0.4570457994644658, 1.445305721320277, -0.5900435899266435,
+);
+
+fn inverse3x3(m: mat3x3<f32>) -> mat3x3<f32> {
+  let c0 = cross(m[1], m[2]);
+  let c1 = cross(m[2], m[0]);
+  let c2 = cross(m[0], m[1]);
+  return transpose(mat3x3<f32>(c0, c1, c2)) * (1.0 / dot(m[0], c0));
+}
+
+// 视角相关颜色 (L1 ~ shDegree)，方向在模型空间计算 (从相机指向 splat)，与桌面端渲染器一致
+fn shColor(texCoord: vec2<u32>, viewPos: vec3<f32>, modelView: mat4x4<f32>) -> vec3<f32> {
+  let degree = uniforms.shDegree;
+  if degree == 0u { return vec3<f32>(0.0); }
+  let range = uniforms.shRange;
+  let m = mat3x3<f32>(modelView[0].xyz, modelView[1].xyz, modelView[2].xyz);
+  let dir = normalize(inverse3x3(m) * viewPos);
+  let x = dir.x; let y = dir.y; let z = dir.z;
+
+  var color = SH_C1 * (
+    -y * loadSHCoeff(texCoord, 0u, range) +
+    z * loadSHCoeff(texCoord, 1u, range) -
+    x * loadSHCoeff(texCoord, 2u, range)
+  );
+  if degree < 2u { return color; }
+
+  let xx = x * x; let yy = y * y; let zz = z * z;
+  let xy = x * y; let yz = y * z; let xz = x * z;
+  color += SH_C2[0] * xy * loadSHCoeff(texCoord, 3u, range) +
+    SH_C2[1] * yz * loadSHCoeff(texCoord, 4u, range) +
+    SH_C2[2] * (2.0 * zz - xx - yy) * loadSHCoeff(texCoord, 5u, range) +
+    SH_C2[3] * xz * loadSHCoeff(texCoord, 6u, range) +
+    SH_C2[4] * (xx - yy) * loadSHCoeff(texCoord, 7u, range);
+  if degree < 3u { return color; }
+
+  color += SH_C3[0] * y * (3.0 * xx - yy) * loadSHCoeff(texCoord, 8u, range) +
+    SH_C3[1] * xy * z * loadSHCoeff(texCoord, 9u, range) +
+    SH_C3[2] * y * (4.0 * zz - xx - yy) * loadSHCoeff(texCoord, 10u, range) +
+    SH_C3[3] * z * (2.0 * zz - 3.0 * xx - 3.0 * yy) * loadSHCoeff(texCoord, 11u, range) +
+    SH_C3[4] * x * (4.0 * zz - xx - yy) * loadSHCoeff(texCoord, 12u, range) +
+    SH_C3[5] * z * (xx - yy) * loadSHCoeff(texCoord, 13u, range) +
+    SH_C3[6] * x * (xx - 3.0 * yy) * loadSHCoeff(texCoord, 14u, range);
+  return color;
+}
+
 // 计算椭圆轴
 fn computeEllipseAxes(cov2D: vec3<f32>) -> mat2x2<f32> {
   let a = cov2D.x; let b = cov2D.y; let c = cov2D.z;
@@ -141,21 +213,20 @@ fn computeEllipseAxes(cov2D: vec3<f32>) -> mat2x2<f32> {
 @vertex
 fn vs_main(@builtin(vertex_index) vertexIndex: u32, @builtin(instance_index) instanceIndex: u32) -> VertexOutput {
   var output: VertexOutput;
+  if instanceIndex >= uniforms.visibleCount { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
   
   // 获取排序后的索引
   let splatIndex = sortedIndices[instanceIndex];
   let texCoord = indexToTexCoord(splatIndex);
   
-  // 从纹理采样位置数据（RGBA32Float，直接读取）
-  let posSample = textureLoad(positionTex, texCoord, 0);
+  // 从纹理采样位置数据（按打包配置解码为 xyz + emissive）
+  let posSample = loadPosition(splatIndex, texCoord);
   let mean = posSample.xyz;
+  let emissive = posSample.w;
   
-  // 从纹理采样缩放和旋转（RGBA16Float，GPU 自动转换为 f32）
-  let scaleRot1 = textureLoad(scaleRotTex1, texCoord, 0);
-  let scaleRot2 = textureLoad(scaleRotTex2, texCoord, 0);
-  
-  let scale = scaleRot1.xyz;
-  let rotation = vec4<f32>(scaleRot1.w, scaleRot2.x, scaleRot2.y, scaleRot2.z);
+  // 从纹理采样缩放和旋转（rotation 按打包配置解码为 wxyz）
+  let scale = textureLoad(scaleTex, texCoord, 0).xyz;
+  let rotation = decodeRotation(textureLoad(rotationTex, texCoord, 0));
   
   // 从纹理采样颜色（RGBA8Unorm，GPU 自动归一化到 0-1）
   let colorSample = textureLoad(colorTex, texCoord, 0);
@@ -166,11 +237,10 @@ fn vs_main(@builtin(vertex_index) vertexIndex: u32, @builtin(instance_index) ins
   let quadPos = QUAD_POSITIONS[vertexIndex];
   output.localUV = quadPos;
   
-  // 计算 modelView 矩阵和模型缩放
+  // 计算 modelView 矩阵
   let modelView = uniforms.view * uniforms.model;
-  let modelScale = getModelScale(uniforms.model);
   
-  let cov2D = computeCov2D(mean, scale, rotation, modelView, uniforms.proj, modelScale);
+  let cov2D = computeCov2D(mean, scale, rotation, modelView, uniforms.proj);
   let axes = computeEllipseAxes(cov2D);
   let screenOffset = axes[0] * quadPos.x * ELLIPSE_SCALE + axes[1] * quadPos.y * ELLIPSE_SCALE;
   
@@ -181,8 +251,9 @@ fn vs_main(@builtin(vertex_index) vertexIndex: u32, @builtin(instance_index) ins
   clipPos.x = clipPos.x + screenOffset.x * clipPos.w;
   clipPos.y = clipPos.y + screenOffset.y * clipPos.w;
   output.position = clipPos;
-  output.color = color;
-  output.opacity = opacity;
+  let shaded = color + shColor(texCoord, viewPos.xyz, modelView);
+  output.color = min(shaded * (1.0 + emissive) * uniforms.exposure, vec3<f32>(uniforms.maxColor));
+  output.opacity = opacity * uniforms.opacityScale;
   
   return output;
 }
@@ -194,11 +265,64 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
   let gaussianWeight = exp(-r * r * 4.0);
   let alpha = input.opacity * gaussianWeight;
   if (alpha < 0.004) { discard; }  // 丢弃几乎透明的像素
-  let color = clamp(input.color, vec3<f32>(0.0), vec3<f32>(1.0));
+  let color = max(input.color, vec3<f32>(0.0));
+  return vec4<f32>(color * alpha, alpha);
+}
+
+// ============================================
+// 2DGS (surfel) 变体，与桌面端渲染器一致：
+// 在 splat 平面上绘制覆盖 3σ 的 quad，透视校正插值等价于像素射线与盘面求交
+// ============================================
+
+@vertex
+fn vs_main_2d(@builtin(vertex_index) vertexIndex: u32, @builtin(instance_index) instanceIndex: u32) -> VertexOutput {
+  var output: VertexOutput;
+  if instanceIndex >= uniforms.visibleCount { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
+
+  let splatIndex = sortedIndices[instanceIndex];
+  let texCoord = indexToTexCoord(splatIndex);
+  let posSample = loadPosition(splatIndex, texCoord);
+  let mean = posSample.xyz;
+  let emissive = posSample.w;
+  let scale = textureLoad(scaleTex, texCoord, 0).xyz;
+  let rotation = decodeRotation(textureLoad(rotationTex, texCoord, 0));
+  let colorSample = textureLoad(colorTex, texCoord, 0);
+
+  let quatNormSqr = dot(rotation, rotation);
+  if quatNormSqr < 1e-6 { output.position = vec4<f32>(0.0, 0.0, 2.0, 1.0); return output; }
+
+  let modelView = uniforms.view * uniforms.model;
+  let viewPos = modelView * vec4<f32>(mean, 1.0);
+
+  // 盘面切向量 (旋转矩阵前两列 * 对应 scale)
+  let R = quatToMat3(rotation * inverseSqrt(quatNormSqr));
+  let quadPos = QUAD_POSITIONS[vertexIndex];
+  let localPos = mean + R[0] * (scale.x * SURFEL_EXTENT * quadPos.x) + R[1] * (scale.y * SURFEL_EXTENT * quadPos.y);
+
+  output.position = uniforms.proj * (modelView * vec4<f32>(localPos, 1.0));
+  output.localUV = quadPos;
+  let shaded = colorSample.rgb + shColor(texCoord, viewPos.xyz, modelView);
+  output.color = min(shaded * (1.0 + emissive) * uniforms.exposure, vec3<f32>(uniforms.maxColor));
+  output.opacity = colorSample.a * uniforms.opacityScale;
+  return output;
+}
+
+@fragment
+fn fs_main_2d(input: VertexOutput) -> @location(0) vec4<f32> {
+  // 盘面坐标，单位为 σ
+  let uv = input.localUV * SURFEL_EXTENT;
+  let alpha = input.opacity * exp(-0.5 * dot(uv, uv));
+  if (alpha < 0.004) { discard; }
+  let color = max(input.color, vec3<f32>(0.0));
   return vec4<f32>(color * alpha, alpha);
 }
 `;
 
+// uniforms.visibleCount 的偏移
+const VISIBLE_COUNT_OFFSET = 240;
+// uniforms.shRange / shDegree 的偏移
+const SH_PARAMS_OFFSET = 244;
+
 /**
  * GSSplatRendererMobile - 移动端优化渲染器
  * 实现 IGSSplatRenderer 接口
@@ -209,6 +333,13 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
 
   // GPU 资源
   private pipeline!: GPURenderPipeline;
+  private shaderModule!: GPUShaderModule;
+  private pipelineLayout!: GPUPipelineLayout;
+  // 混合模式及其管线变体（按 "混合模式:2d/3d" 缓存）
+  private blendMode: SplatBlendMode = SplatBlendMode.Standard;
+  private blendPipelines: Map<string, GPURenderPipeline> = new Map();
+  // 当前数据是否为 2DGS (surfel)，是则使用 2D 高斯核管线
+  private is2DGS: boolean = false;
   private uniformBindGroupLayout!: GPUBindGroupLayout;
   private textureBindGroupLayout!: GPUBindGroupLayout;
   private uniformBuffer!: GPUBuffer;
@@ -228,10 +359,18 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
   // Bounding box
   private boundingBox: BoundingBox | null = null;
 
+  // 逐属性打包编码配置（决定纹理格式和 shader 解码方式）
+  private packMode: PackModeConfig;
+  // SH 阶数（packMode.sh 为 none 时固定为 L0）
+  private shMode: SHMode = SHMode.L0;
+
   // 帧计数（用于排序频率控制）
   private frameCount: number = 0;
   private sortEveryNFrames: number = 1;
 
+  // 不使用 indirect 绘制（按回读的可见数量直接绘制，跟随 Renderer.indirectDraw）
+  private directDraw: boolean = false;
+
   // ============================================
   // 变换相关 (position, rotation, scale)
   // ============================================
@@ -240,10 +379,21 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
   private scaleValue: Vec3Tuple = [1, 1, 1];
   private pivot: Vec3Tuple = [0, 0, 0]; // 旋转/缩放中心点
   private modelMatrix: Float32Array = new Float32Array(16); // 4x4 model matrix
-
-  constructor(renderer: Renderer, camera: Camera) {
+  private parentMatrix: Float32Array | null = null; // 父级（图层）变换
+  // 变换检查（零缩放 / 剪切）
+  private autoCorrectTransform: boolean = false;
+  private transformIssues: TransformIssue[] = [];
+  // 相机相对渲染（消除远离原点时的 f32 抖动）
+  private cameraRelative: boolean = true;
+  // 曝光倍数（HDR 输出时颜色可超过 1.0）
+  private exposure: number = 1.0;
+  // 整体不透明度倍数
+  private opacityScale: number = 1.0;
+
+  constructor(renderer: Renderer, camera: Camera, packMode: Partial<PackModeConfig> = {}) {
     this.renderer = renderer;
     this.camera = camera;
+    this.packMode = { ...DEFAULT_PACK_MODE_CONFIG, ...packMode };
 
     this.createPipeline();
     this.createUniformBuffer();
@@ -376,6 +526,16 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
     this.modelMatrix[13] = finalTy;
     this.modelMatrix[14] = finalTz;
     this.modelMatrix[15] = 1;
+
+    // 父级（图层）变换
+    if (this.parentMatrix) {
+      multiplyTransforms(this.parentMatrix, this.modelMatrix, this.modelMatrix);
+    }
+
+    // 自身的 TRS 不会产生剪切，只在组合了父级矩阵时检查
+    this.transformIssues = validateTransform(
+      this.modelMatrix, "splat", this.autoCorrectTransform, this.transformIssues, this.parentMatrix !== null,
+    );
   }
 
   /**
@@ -385,6 +545,92 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
     return this.modelMatrix;
   }
 
+  /**
+   * 设置父级变换（列主序），null 表示没有父级
+   */
+  setParentMatrix(matrix: Float32Array | null): void {
+    this.parentMatrix = matrix ? new Float32Array(matrix) : null;
+    this.updateModelMatrix();
+  }
+
+  /**
+   * 获取父级变换
+   */
+  getParentMatrix(): Float32Array | null {
+    return this.parentMatrix;
+  }
+
+  /**
+   * 设置是否自动修正有问题的模型矩阵（非有限值、父级变换带来的剪切；零缩放只警告）
+   */
+  setTransformAutoCorrect(enabled: boolean): void {
+    this.autoCorrectTransform = enabled;
+    this.updateModelMatrix();
+  }
+
+  /**
+   * 获取当前模型矩阵检测到的问题（修正前）
+   */
+  getTransformIssues(): TransformIssue[] {
+    return [...this.transformIssues];
+  }
+
+  /**
+   * 设置是否使用相机相对渲染（默认开启）
+   */
+  setCameraRelative(enabled: boolean): void {
+    this.cameraRelative = enabled;
+  }
+
+  /**
+   * 是否使用相机相对渲染
+   */
+  isCameraRelative(): boolean {
+    return this.cameraRelative;
+  }
+
+  /**
+   * 设置曝光倍数
+   */
+  setExposure(exposure: number): void {
+    this.exposure = Math.max(0, exposure);
+  }
+
+  /**
+   * 获取曝光倍数
+   */
+  getExposure(): number {
+    return this.exposure;
+  }
+
+  /**
+   * 设置整体不透明度倍数 (0~1)
+   */
+  setOpacity(opacity: number): void {
+    this.opacityScale = Math.min(1, Math.max(0, opacity));
+  }
+
+  /**
+   * 获取整体不透明度倍数
+   */
+  getOpacity(): number {
+    return this.opacityScale;
+  }
+
+  /**
+   * 设置混合模式
+   */
+  setBlendMode(mode: SplatBlendMode): void {
+    this.blendMode = mode;
+  }
+
+  /**
+   * 获取混合模式
+   */
+  getBlendMode(): SplatBlendMode {
+    return this.blendMode;
+  }
+
   /**
    * 创建渲染管线
    */
@@ -392,8 +638,11 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
     const device = this.renderer.device;
 
     // 创建 shader 模块
-    const shaderModule = device.createShaderModule({
-      code: shaderCodeMobileL0,
+    this.shaderModule = device.createShaderModule({
+      code: shaderCodeMobileL0
+        .replace("{{DECODE_ROTATION}}", getRotationDecodeWGSL(this.packMode.rotation))
+        .replace("{{DECODE_POSITION}}", getPositionDecodeWGSL(this.packMode.position))
+        .replace("{{DECODE_SH}}", getSHDecodeWGSL(this.packMode.sh)),
       label: "mobile-splat-shader",
     });
 
@@ -414,69 +663,78 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
     });
 
     // Texture bind group layout (group 1) - 简化版 4 张纹理
-    this.textureBindGroupLayout = device.createBindGroupLayout({
-      entries: [
-        {
-          // positionTex (RGBA32Float)
-          binding: 0,
-          visibility: GPUShaderStage.VERTEX,
-          texture: { sampleType: "unfilterable-float" },
-        },
-        {
-          // scaleRotTex1 (RGBA32Float)
-          binding: 1,
-          visibility: GPUShaderStage.VERTEX,
-          texture: { sampleType: "unfilterable-float" },
-        },
-        {
-          // scaleRotTex2 (RGBA32Float)
-          binding: 2,
-          visibility: GPUShaderStage.VERTEX,
-          texture: { sampleType: "unfilterable-float" },
-        },
-        {
-          // colorTex (RGBA8Unorm)
-          binding: 3,
-          visibility: GPUShaderStage.VERTEX,
-          texture: { sampleType: "unfilterable-float" },
-        },
-      ],
-    });
+    const textureEntries: GPUBindGroupLayoutEntry[] = [
+      {
+        // positionTex (RGBA32Float / RGBA16Float / RGBA16Uint)
+        binding: 0,
+        visibility: GPUShaderStage.VERTEX,
+        texture: { sampleType: getPositionSampleType(this.packMode.position) },
+      },
+      {
+        // scaleTex (RGBA32Float)
+        binding: 1,
+        visibility: GPUShaderStage.VERTEX,
+        texture: { sampleType: "unfilterable-float" },
+      },
+      {
+        // rotationTex (RGBA32Float / RGBA16Float / RGB10A2Unorm)
+        binding: 2,
+        visibility: GPUShaderStage.VERTEX,
+        texture: { sampleType: "unfilterable-float" },
+      },
+      {
+        // colorTex (RGBA8Unorm)
+        binding: 3,
+        visibility: GPUShaderStage.VERTEX,
+        texture: { sampleType: "unfilterable-float" },
+      },
+    ];
+    if (this.packMode.position !== "f32") {
+      // chunkTex (RGBA32Float) - 量化位置的块包围盒
+      textureEntries.push({
+        binding: 4,
+        visibility: GPUShaderStage.VERTEX,
+        texture: { sampleType: "unfilterable-float" },
+      });
+    }
+    if (this.packMode.sh !== "none") {
+      // shTex (RGBA16Float / R32Uint 2D 数组) - SH 系数
+      textureEntries.push({
+        binding: 5,
+        visibility: GPUShaderStage.VERTEX,
+        texture: { sampleType: getSHSampleType(this.packMode.sh), viewDimension: "2d-array" },
+      });
+    }
+    this.textureBindGroupLayout = device.createBindGroupLayout({ entries: textureEntries });
 
     // Pipeline layout
-    const pipelineLayout = device.createPipelineLayout({
+    this.pipelineLayout = device.createPipelineLayout({
       bindGroupLayouts: [this.uniformBindGroupLayout, this.textureBindGroupLayout],
     });
 
-    // Blend state
-    const blendState: GPUBlendState = {
-      color: {
-        srcFactor: "one",
-        dstFactor: "one-minus-src-alpha",
-        operation: "add",
-      },
-      alpha: {
-        srcFactor: "one",
-        dstFactor: "one-minus-src-alpha",
-        operation: "add",
-      },
-    };
+    this.pipeline = this.createBlendPipeline(SplatBlendMode.Standard, false);
+  }
 
-    // 创建管线
-    this.pipeline = device.createRenderPipeline({
-      layout: pipelineLayout,
+  /**
+   * 创建指定混合模式的管线
+   * @param surfel 使用 2DGS (surfel) 入口
+   */
+  private createBlendPipeline(blendMode: SplatBlendMode, surfel: boolean): GPURenderPipeline {
+    const suffix = surfel ? "_2d" : "";
+    return this.renderer.device.createRenderPipeline({
+      layout: this.pipelineLayout,
       vertex: {
-        module: shaderModule,
-        entryPoint: "vs_main",
+        module: this.shaderModule,
+        entryPoint: `vs_main${suffix}`,
         buffers: [],
       },
       fragment: {
-        module: shaderModule,
-        entryPoint: "fs_main",
+        module: this.shaderModule,
+        entryPoint: `fs_main${suffix}`,
         targets: [
           {
             format: this.renderer.format,
-            blend: blendState,
+            blend: getSplatBlendState(blendMode),
           },
         ],
       },
@@ -489,16 +747,32 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
         depthCompare: "always",
       },
     });
+  }
 
+  /**
+   * 获取当前混合模式和数据类型的管线（标准混合的 3DGS 以外按需创建并缓存）
+   */
+  private getBlendPipeline(): GPURenderPipeline {
+    if (this.blendMode === SplatBlendMode.Standard && !this.is2DGS) {
+      return this.pipeline;
+    }
+    const key = `${this.blendMode}:${this.is2DGS ? "2d" : "3d"}`;
+    let pipeline = this.blendPipelines.get(key);
+    if (!pipeline) {
+      pipeline = this.createBlendPipeline(this.blendMode, this.is2DGS);
+      this.blendPipelines.set(key, pipeline);
+    }
+    return pipeline;
   }
 
   /**
    * 创建 uniform buffer
-   * 布局: view (64) + proj (64) + model (64) + cameraPos (12) + pad (4) + screenSize (8) + pad (8) + textureSize (8) + pad (8) = 240 bytes
+   * 布局: view (64) + proj (64) + model (64) + cameraPos (12) + pad (4) + screenSize (8) + opacityScale (4) + pad (4) + textureSize (8) + exposure (4) + maxColor (4) + visibleCount (4) + shRange (4) + shDegree (4 + pad 4) = 256 bytes
+   * visibleCount 由排序器在 GPU 上写入，CPU 不写
    */
   private createUniformBuffer(): void {
     this.uniformBuffer = this.renderer.device.createBuffer({
-      size: 240,
+      size: 256,
       usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
     });
   }
@@ -516,13 +790,19 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
 
       this.splatCount = data.count;
       this.frameCount = 0;
+      this.is2DGS = data.is2DGS === true;
 
       if (this.splatCount === 0) {
         return;
       }
 
+      // 量化位置按块存储相对坐标，先按八叉树顺序重排使每块空间紧凑
+      if (this.packMode.position !== "f32") {
+        data = gatherSplatData(data, buildSplatOctree(data.positions, data.count).order);
+      }
+
       // 压缩数据到纹理
-      this.compressedTextures = compressSplatsToTextures(device, data);
+      this.compressedTextures = compressSplatsToTextures(device, data, this.packMode);
 
       // 计算 bounding box
       this.boundingBox = this.computeBoundingBox(data);
@@ -540,9 +820,12 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
         device,
         this.splatCount,
         this.positionsBuffer,
-        this.uniformBuffer
+        this.uniformBuffer,
+        { uploads: this.renderer.uploads },
       );
 
+      this.sorter.setVisibleCountTarget(this.uniformBuffer, VISIBLE_COUNT_OFFSET);
+      this.sorter.setVisibleCountReadback(this.directDraw ? 1 : 0);
       this.sorter.setScreenSize(this.renderer.width, this.renderer.height);
       this.sorter.setCullingOptions({
         nearPlane: this.camera.near,
@@ -556,7 +839,7 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
       const memoryMB = (
         this.compressedTextures.width *
         this.compressedTextures.height *
-        52 / // 约 52 bytes per texel (16+16+16+4)
+        this.compressedTextures.bytesPerSplat /
         (1024 * 1024)
       ).toFixed(2);
     } catch (error) {
@@ -590,26 +873,39 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
     });
 
     // Texture bind group (group 1) - 简化版 4 张纹理
+    const textureEntries: GPUBindGroupEntry[] = [
+      {
+        binding: 0,
+        resource: this.compressedTextures.positionTexture.createView(),
+      },
+      {
+        binding: 1,
+        resource: this.compressedTextures.scaleTexture.createView(),
+      },
+      {
+        binding: 2,
+        resource: this.compressedTextures.rotationTexture.createView(),
+      },
+      {
+        binding: 3,
+        resource: this.compressedTextures.colorTexture.createView(),
+      },
+    ];
+    if (this.compressedTextures.chunkTexture) {
+      textureEntries.push({
+        binding: 4,
+        resource: this.compressedTextures.chunkTexture.createView(),
+      });
+    }
+    if (this.compressedTextures.shTexture) {
+      textureEntries.push({
+        binding: 5,
+        resource: this.compressedTextures.shTexture.createView({ dimension: "2d-array" }),
+      });
+    }
     this.textureBindGroup = device.createBindGroup({
       layout: this.textureBindGroupLayout,
-      entries: [
-        {
-          binding: 0,
-          resource: this.compressedTextures.positionTexture.createView(),
-        },
-        {
-          binding: 1,
-          resource: this.compressedTextures.scaleRotTexture1.createView(),
-        },
-        {
-          binding: 2,
-          resource: this.compressedTextures.scaleRotTexture2.createView(),
-        },
-        {
-          binding: 3,
-          resource: this.compressedTextures.colorTexture.createView(),
-        },
-      ],
+      entries: textureEntries,
     });
   }
 
@@ -667,23 +963,31 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
     }
 
     this.frameCount++;
+    if (this.directDraw === this.renderer.indirectDraw) {
+      this.directDraw = !this.renderer.indirectDraw;
+      this.sorter.setVisibleCountReadback(this.directDraw ? 1 : 0);
+    }
 
-    // 更新 uniform buffer
-    const device = this.renderer.device;
-    device.queue.writeBuffer(this.uniformBuffer, 0, new Float32Array(this.camera.viewMatrix));
-    device.queue.writeBuffer(this.uniformBuffer, 64, new Float32Array(this.camera.projectionMatrix));
-    device.queue.writeBuffer(this.uniformBuffer, 128, new Float32Array(this.modelMatrix));
-    device.queue.writeBuffer(this.uniformBuffer, 192, new Float32Array(this.camera.position));
-    device.queue.writeBuffer(
-      this.uniformBuffer,
-      208,
-      new Float32Array([this.renderer.width, this.renderer.height, 0, 0])
-    );
-    device.queue.writeBuffer(
+    // 更新 uniform buffer（经共享上传区，排序器或帧提交前 flush）
+    const uploads = this.renderer.uploads;
+    const transforms = computeTransformUniforms(this.camera, this.modelMatrix, this.cameraRelative);
+    uploads.write(this.uniformBuffer, 0, transforms.view);
+    uploads.write(this.uniformBuffer, 64, this.camera.projectionMatrix);
+    uploads.write(this.uniformBuffer, 128, transforms.model);
+    uploads.write(this.uniformBuffer, 192, transforms.cameraPos);
+    uploads.writeF32(this.uniformBuffer, 208, this.renderer.width, this.renderer.height, this.opacityScale);
+    uploads.writeF32(
       this.uniformBuffer,
       224,
-      new Float32Array([this.compressedTextures.width, this.compressedTextures.height, 0, 0])
+      this.compressedTextures.width,
+      this.compressedTextures.height,
+      this.exposure,
+      this.renderer.hdr ? 65504 : 1,
     );
+    // 不能覆盖排序器写入的 visibleCount，单独写入 SH 参数
+    const shParams = uploads.stage(this.uniformBuffer, SH_PARAMS_OFFSET, 8);
+    uploads.f32[shParams] = this.compressedTextures.shRange;
+    uploads.u32[shParams + 1] = this.compressedTextures.hasSH ? this.shMode : SHMode.L0;
 
     // 更新排序器参数
     this.sorter.setScreenSize(this.renderer.width, this.renderer.height);
@@ -702,10 +1006,14 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
     }
 
     // 渲染
-    pass.setPipeline(this.pipeline);
+    pass.setPipeline(this.getBlendPipeline());
     pass.setBindGroup(0, this.uniformBindGroup);
     pass.setBindGroup(1, this.textureBindGroup);
-    pass.drawIndirect(this.sorter.getDrawIndirectBuffer(), 0);
+    if (this.directDraw) {
+      pass.draw(4, getDirectDrawInstanceCount(this.sorter.getVisibleCount(), this.splatCount));
+    } else {
+      pass.drawIndirect(this.sorter.getDrawIndirectBuffer(), 0);
+    }
   }
 
   /**
@@ -722,6 +1030,20 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
     return this.boundingBox;
   }
 
+  /**
+   * 当前数据是否为 2DGS (surfel)，是则使用 2D 高斯核管线
+   */
+  isSurfelData(): boolean {
+    return this.is2DGS;
+  }
+
+  /**
+   * 获取当前打包编码配置
+   */
+  getPackModeConfig(): PackModeConfig {
+    return { ...this.packMode };
+  }
+
   /**
    * 设置排序频率
    * @param n 每 n 帧排序一次
@@ -735,24 +1057,32 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
   // ============================================
 
   /**
-   * 设置 SH 模式（移动端仅支持 L0）
+   * 设置 SH 模式（打包配置不存储 SH 或数据没有 SH 系数时只支持 L0）
    */
   setSHMode(mode: SHMode): void {
-    // 移动端仅支持 L0 模式
+    this.shMode = this.supportsSHMode(mode) ? mode : SHMode.L0;
   }
 
   /**
    * 获取当前 SH 模式
    */
   getSHMode(): SHMode {
-    return SHMode.L0;
+    return this.shMode;
   }
 
   /**
    * 是否支持指定的 SH 模式
    */
   supportsSHMode(mode: SHMode): boolean {
-    return mode === SHMode.L0;
+    return mode >= SHMode.L0 && mode <= this.getMaxSHMode();
+  }
+
+  /**
+   * 支持的最高 SH 模式
+   */
+  private getMaxSHMode(): SHMode {
+    const hasSH = this.compressedTextures ? this.compressedTextures.hasSH : true;
+    return this.packMode.sh !== "none" && hasSH ? SHMode.L3 : SHMode.L0;
   }
 
   /**
@@ -760,13 +1090,28 @@ export class GSSplatRendererMobile implements IGSSplatRendererWithCapabilities {
    */
   getCapabilities(): RendererCapabilities {
     return {
-      maxSHMode: SHMode.L0,
+      maxSHMode: this.getMaxSHMode(),
       supportsRawData: false,
       isMobileOptimized: true,
       maxSplatCount: 0, // 无限制（受 GPU 内存限制）
     };
   }
 
+  /**
+   * 获取当前占用的显存（字节）：属性纹理、块包围盒纹理、排序用位置 buffer 和排序器
+   */
+  getGPUMemoryUsage(): number {
+    const textures = this.compressedTextures;
+    if (!textures) {
+      return 0;
+    }
+    const chunk = textures.chunkTexture;
+    return textures.width * textures.height * textures.bytesPerSplat
+      + (chunk ? chunk.width * chunk.height * 16 : 0)
+      + (this.positionsBuffer?.size ?? 0)
+      + (this.sorter?.getGPUMemoryUsage() ?? 0);
+  }
+
   /**
    * 内部销毁资源（不销毁管线）
    */
@@ -5,15 +5,28 @@
  * - 4 个 pass (8-bit 增量，总共 32 位)
  * - 每个 pass 包含: Upsweep -> Spine -> Downsweep
  * - 稳定排序，解决远距离闪烁问题
+ * - 设备支持 subgroups 时 downsweep 改为块内 split 排序 + 并行散射
  *
  * 参考: rfs-gsplat-render/assets/shaders/radix_sort.wgsl
  */
 
+import { SplatOctree, SplatNodeVisibility, cullSplatOctree, collectVisibleLeaves } from "./SplatOctree";
+import { SplatLodSelector, SplatLodConfig } from "./SplatLod";
+import { SplatHiZ } from "./SplatHiZ";
+import type { DepthConfig } from "../core/DepthConvention";
+import { UploadArena } from "../core/UploadArena";
+import { PipelineCache } from "../core/PipelineCache";
+import { SplatBufferPool } from "./SplatBufferPool";
+import { getBuffersByteSize } from "./SplatMemoryBudget";
+import type { SplatPassTracker } from "./SplatPassTracker";
+import { SplatDepthKeyMode } from "../types";
+
 const WORKGROUP_SIZE = 256;
-const RADIX_BITS = 8;
-const RADIX_SIZE = 256; // 2^8
+export const RADIX_BITS = 8;
+export const RADIX_SIZE = 256; // 2^8
 const ELEMENTS_PER_THREAD = 4;
-const BLOCK_SIZE = WORKGROUP_SIZE * ELEMENTS_PER_THREAD; // 1024
+export const RADIX_BLOCK_SIZE = WORKGROUP_SIZE * ELEMENTS_PER_THREAD; // 1024
+const BLOCK_SIZE = RADIX_BLOCK_SIZE;
 
 /**
  * 生成 Culling Shader 代码
@@ -49,14 +62,21 @@ struct CameraUniforms {
 }
 
 struct CullingParams {
-  splatCount: u32,
+  splatCount: u32,  // 本次处理的 splat 数量（分块剔除时为可见 chunk 的 splat 总数）
   nearPlane: f32,
   farPlane: f32,
   screenWidth: f32,
   screenHeight: f32,
   frustumDilation: f32,
   pixelThreshold: f32,
-  _pad1: f32,
+  rangeCount: u32,  // 可见 chunk 区间数量，0 表示不分块
+  occlusion: u32,   // 是否启用 Hi-Z 遮挡剔除
+  reverseZ: u32,
+  hizMipCount: u32,
+  cullCameraInside: u32,  // 剔除中心在近平面之后且包含相机的 splat
+  densityCellSize: u32,   // 屏幕密度格子边长 (像素)，0 表示不统计
+  densityGridWidth: u32,  // 密度格子列数
+  _pad: vec2<u32>,
 }
 
 @group(0) @binding(0) var<storage, read> splats: array<Splat>;
@@ -65,16 +85,62 @@ struct CullingParams {
 @group(0) @binding(3) var<storage, read_write> depthKeys: array<u32>;
 @group(0) @binding(4) var<storage, read_write> visibleIndices: array<u32>;
 @group(0) @binding(5) var<storage, read_write> indirectBuffer: array<atomic<u32>, 4>;
+// 八叉树重排后的 splat 索引，以及可见区间 (线程偏移, order 起始位置)
+@group(0) @binding(6) var<storage, read> chunkOrder: array<u32>;
+@group(0) @binding(7) var<storage, read> chunkRanges: array<vec2<u32>>;
+// 上一帧深度的 Hi-Z 金字塔 (每级保存最远深度)
+@group(0) @binding(8) var hiz: texture_2d<f32>;
+// 每个屏幕格子内的可见 splat 数量 (点显示的自适应尺寸)
+@group(0) @binding(9) var<storage, read_write> densityGrid: array<atomic<u32>>;
+
+// 线程索引 -> splat 索引：二分查找所在的可见区间
+fn resolveSplatIndex(i: u32) -> u32 {
+  if params.rangeCount == 0u { return i; }
+  var lo = 0u;
+  var hi = params.rangeCount - 1u;
+  while lo < hi {
+    let mid = (lo + hi + 1u) / 2u;
+    if chunkRanges[mid].x <= i { lo = mid; } else { hi = mid - 1u; }
+  }
+  let range = chunkRanges[lo];
+  return chunkOrder[range.y + (i - range.x)];
+}
+
+// 相机 (视图空间原点) 到 splat 中心的马氏距离
+fn cameraMahalanobis(splat: Splat, viewPos: vec4<f32>) -> f32 {
+  let mv = camera.view * camera.model;
+  let m = mat3x3<f32>(mv[0].xyz, mv[1].xyz, mv[2].xyz);
+  let c0 = cross(m[1], m[2]);
+  let c1 = cross(m[2], m[0]);
+  let c2 = cross(m[0], m[1]);
+  let toCamera = transpose(mat3x3<f32>(c0, c1, c2)) * (-viewPos.xyz / dot(m[0], c0));
+  // 四元数 (w, x, y, z) 旋转到 splat 局部坐标
+  let q = normalize(splat.rotation);
+  let u = vec3<f32>(-q.y, -q.z, -q.w);
+  let local = toCamera + 2.0 * cross(u, cross(u, toCamera) + q.x * toCamera);
+  return length(local / max(splat.scale, vec3<f32>(1e-6)));
+}
 
 fn maxScale(scale: vec3<f32>) -> f32 {
   return max(max(scale.x, scale.y), scale.z);
 }
 
+// 模型矩阵 3x3 部分的最大拉伸量 (最大奇异值)
+// 非均匀缩放/剪切时列向量长度的最大值会低估拉伸，这里求 AᵀA 的最大特征值
 fn getModelMaxScale(model: mat4x4<f32>) -> f32 {
-  let sx = length(model[0].xyz);
-  let sy = length(model[1].xyz);
-  let sz = length(model[2].xyz);
-  return max(max(sx, sy), sz);
+  let a = mat3x3<f32>(model[0].xyz, model[1].xyz, model[2].xyz);
+  let b = transpose(a) * a;
+  let p1 = b[0][1] * b[0][1] + b[0][2] * b[0][2] + b[1][2] * b[1][2];
+  let q = (b[0][0] + b[1][1] + b[2][2]) / 3.0;
+  let p2 = (b[0][0] - q) * (b[0][0] - q) + (b[1][1] - q) * (b[1][1] - q) + (b[2][2] - q) * (b[2][2] - q) + 2.0 * p1;
+  let p = sqrt(p2 / 6.0);
+  if p < 1e-8 {
+    return sqrt(max(q, 0.0));
+  }
+  let c = (b - mat3x3<f32>(vec3<f32>(q, 0.0, 0.0), vec3<f32>(0.0, q, 0.0), vec3<f32>(0.0, 0.0, q))) * (1.0 / p);
+  let r = clamp(determinant(c) * 0.5, -1.0, 1.0);
+  let lambdaMax = q + 2.0 * p * cos(acos(r) / 3.0);
+  return sqrt(max(lambdaMax, 0.0));
 }
 
 // IEEE 754 位操作编码浮点数为可排序的 u32
@@ -101,10 +167,47 @@ fn isInFrustum(clipPos: vec4<f32>, frustumDilation: f32) -> bool {
   return true;
 }
 
+// Hi-Z 遮挡测试：splat 包围球最近点的深度比覆盖区域内最远的遮挡深度还远时剔除
+fn isOccluded(viewPos: vec4<f32>, radius: f32) -> bool {
+  let nearZ = viewPos.z + radius;
+  // 包围球包含相机或跨越相机平面时不剔除
+  if nearZ >= -params.nearPlane { return false; }
+
+  let clipPos = camera.proj * viewPos;
+  let ndc = clipPos.xy / clipPos.w;
+  let nearClip = camera.proj * vec4<f32>(viewPos.xy, nearZ, 1.0);
+  let nearDepth = nearClip.z / nearClip.w;
+
+  // 屏幕空间包围矩形 (像素)，按最近距离估计半径以保证保守
+  let hizSize = vec2<f32>(textureDimensions(hiz, 0));
+  let focal = max(abs(camera.proj[0][0]) * hizSize.x, abs(camera.proj[1][1]) * hizSize.y) * 0.5;
+  let radiusPx = radius * focal / -nearZ;
+  let center = vec2<f32>(ndc.x * 0.5 + 0.5, 0.5 - ndc.y * 0.5) * hizSize;
+  let rectMin = clamp(center - radiusPx, vec2<f32>(0.0), hizSize - 1.0);
+  let rectMax = clamp(center + radiusPx, vec2<f32>(0.0), hizSize - 1.0);
+
+  // 选择包围矩形约覆盖 2x2 texel 的 mip 级
+  let extent = max(rectMax.x - rectMin.x, rectMax.y - rectMin.y);
+  let level = min(u32(ceil(log2(max(extent, 1.0)))), params.hizMipCount - 1u);
+  // 奇数尺寸时每级最后一个 texel 覆盖到边缘，越界坐标夹到最后一个 texel
+  let levelMax = vec2<i32>(textureDimensions(hiz, level)) - 1;
+  let t0 = min(vec2<i32>(rectMin) >> vec2<u32>(level), levelMax);
+  let t1 = min(vec2<i32>(rectMax) >> vec2<u32>(level), levelMax);
+
+  for (var y = t0.y; y <= t1.y; y++) {
+    for (var x = t0.x; x <= t1.x; x++) {
+      let occluder = textureLoad(hiz, vec2<i32>(x, y), i32(level)).r;
+      let visible = select(nearDepth <= occluder, nearDepth >= occluder, params.reverseZ != 0u);
+      if visible { return false; }
+    }
+  }
+  return true;
+}
+
 @compute @workgroup_size(${WORKGROUP_SIZE})
 fn projectAndCull(@builtin(global_invocation_id) gid: vec3<u32>) {
-  let i = gid.x;
-  if i >= params.splatCount { return; }
+  if gid.x >= params.splatCount { return; }
+  let i = resolveSplatIndex(gid.x);
   
   let splat = splats[i];
   
@@ -119,6 +222,17 @@ fn projectAndCull(@builtin(global_invocation_id) gid: vec3<u32>) {
   // 视锥剔除
   if !isInFrustum(clipPos, params.frustumDilation) { return; }
   
+  // 中心在近平面之后而相机位于 splat 内部：投影退化为覆盖全屏的大 quad
+  if params.cullCameraInside != 0u && -viewPos.z < params.nearPlane && cameraMahalanobis(splat, viewPos) < 2.82842712475 {
+    return;
+  }
+  
+  // 遮挡剔除 (3σ 包围球)
+  if params.occlusion != 0u {
+    let radius = 3.0 * maxScale(splat.scale) * getModelMaxScale(camera.model);
+    if isOccluded(viewPos, radius) { return; }
+  }
+  
   // 深度编码 (viewPos.z 是负数)
   let depth = viewPos.z;
   let sortableDepth = encodeDepthKey(depth);
@@ -130,6 +244,17 @@ fn projectAndCull(@builtin(global_invocation_id) gid: vec3<u32>) {
   // 写入可见点列表
   depthKeys[visibleIdx] = sortableDepth;
   visibleIndices[visibleIdx] = i;
+  
+  // 局部投影密度：中心落在屏幕内的 splat 计入所在格子
+  if params.densityCellSize != 0u {
+    let ndc = clipPos.xy / clipPos.w;
+    let screen = vec2<f32>(params.screenWidth, params.screenHeight);
+    let pixel = vec2<f32>(ndc.x * 0.5 + 0.5, 0.5 - ndc.y * 0.5) * screen;
+    if all(pixel >= vec2<f32>(0.0)) && all(pixel < screen) {
+      let cell = vec2<u32>(pixel) / params.densityCellSize;
+      atomicAdd(&densityGrid[cell.y * params.densityGridWidth + cell.x], 1u);
+    }
+  }
 }
 
 @compute @workgroup_size(1)
@@ -143,12 +268,178 @@ fn initIndirectBuffer() {
 `;
 }
 
+/**
+ * 设备是否支持 subgroup 加速的 radix sort（Renderer 在适配器支持时请求 subgroups 特性）
+ */
+export function supportsSubgroupSort(device: GPUDevice): boolean {
+  return device.features.has("subgroups");
+}
+
+/**
+ * 获取 downsweep 入口：subgroup 可用时使用并行散射，否则回退到线程 0 顺序散射
+ */
+export function getDownsweepEntryPoint(subgroups: boolean): string {
+  return subgroups ? "downsweepParallel" : "downsweep";
+}
+
+/**
+ * 生成 subgroup 加速的并行 downsweep
+ * 块内先做 8 轮 1-bit 稳定 split（前缀和用 subgroupInclusiveAdd），得到按 digit 稳定排好的块，
+ * 再由每个线程直接计算全局写入位置，代替线程 0 顺序散射 1024 个元素
+ */
+function generateParallelDownsweepCode(): string {
+  return /* wgsl */ `
+// ============================================================================
+// Pass 3 (subgroup): 块内稳定 split 排序 + 并行散射
+// ============================================================================
+
+var<workgroup> splitKeys: array<u32, BLOCK_SIZE>;
+var<workgroup> splitValues: array<u32, BLOCK_SIZE>;
+var<workgroup> digitBase: array<u32, RADIX_SIZE>;
+// 每个 subgroup 的前缀和总量（subgroup 最小 4 宽）
+var<workgroup> subgroupTotals: array<u32, 64>;
+var<workgroup> subgroupSlots: atomic<u32>;
+var<workgroup> blockTotal: u32;
+
+var<private> subgroupLane: u32;
+var<private> subgroupWidth: u32;
+
+// 工作组内 exclusive 前缀和，返回 (exclusive, total)
+// lid 是按 subgroup 连续编号的逻辑线程号，保证 subgroup 内前缀和与逻辑顺序一致
+fn blockExclusiveScan(lid: u32, value: u32) -> vec2<u32> {
+  let inclusive = subgroupInclusiveAdd(value);
+  let subgroupIndex = lid / subgroupWidth;
+  if subgroupLane == subgroupWidth - 1u {
+    subgroupTotals[subgroupIndex] = inclusive;
+  }
+  workgroupBarrier();
+  if lid == 0u {
+    var sum = 0u;
+    for (var s = 0u; s < WG / subgroupWidth; s++) {
+      let total = subgroupTotals[s];
+      subgroupTotals[s] = sum;
+      sum += total;
+    }
+    blockTotal = sum;
+  }
+  workgroupBarrier();
+  let result = vec2<u32>(subgroupTotals[subgroupIndex] + inclusive - value, blockTotal);
+  workgroupBarrier();
+  return result;
+}
+
+@compute @workgroup_size(256, 1, 1)
+fn downsweepParallel(
+  @builtin(local_invocation_id) localId: vec3<u32>,
+  @builtin(workgroup_id) workgroupId: vec3<u32>,
+  @builtin(subgroup_invocation_id) laneId: u32,
+  @builtin(subgroup_size) laneCount: u32,
+) {
+  let numKeys = indirectBufferDownsweep[1];
+  let numPartitions = divCeil(numKeys, BLOCK_SIZE);
+  let partitionId = workgroupId.x;
+
+  if partitionId >= numPartitions { return; }
+
+  // subgroup 与 local_invocation_id 的对应关系没有保证，按领取顺序给 subgroup 连续编号
+  subgroupLane = laneId;
+  subgroupWidth = laneCount;
+  if localId.x == 0u {
+    atomicStore(&subgroupSlots, 0u);
+  }
+  workgroupBarrier();
+  var slot = 0u;
+  if laneId == 0u {
+    slot = atomicAdd(&subgroupSlots, 1u);
+  }
+  let lid = subgroupBroadcastFirst(slot) * laneCount + laneId;
+
+  let partitionStart = partitionId * BLOCK_SIZE;
+  let count = min(BLOCK_SIZE, numKeys - partitionStart);
+  let shift = downsweepParams.bitShift;
+  let passIdx = downsweepParams.passIndex;
+
+  // 每个线程持有连续的 4 个元素；块尾越界元素的 digit 视为最大值，
+  // 它们在原顺序中位于最后，稳定 split 后始终停留在块尾
+  var keys: array<u32, ELEMENTS_PER_THREAD>;
+  var values: array<u32, ELEMENTS_PER_THREAD>;
+  var digits: array<u32, ELEMENTS_PER_THREAD>;
+  for (var j = 0u; j < ELEMENTS_PER_THREAD; j++) {
+    let idx = lid * ELEMENTS_PER_THREAD + j;
+    if idx < count {
+      keys[j] = downsweepKeysIn[partitionStart + idx];
+      values[j] = downsweepValuesIn[partitionStart + idx];
+      digits[j] = (keys[j] >> shift) & RADIX_MASK;
+    } else {
+      digits[j] = RADIX_MASK;
+    }
+  }
+
+  // 8 轮 1-bit 稳定 split
+  for (var b = 0u; b < RADIX_BITS; b++) {
+    var zeros = 0u;
+    for (var j = 0u; j < ELEMENTS_PER_THREAD; j++) {
+      zeros += 1u - ((digits[j] >> b) & 1u);
+    }
+    let scan = blockExclusiveScan(lid, zeros);
+    var zeroPos = scan.x;
+    var onePos = scan.y + lid * ELEMENTS_PER_THREAD - scan.x;
+    for (var j = 0u; j < ELEMENTS_PER_THREAD; j++) {
+      var dst = onePos;
+      if ((digits[j] >> b) & 1u) == 0u {
+        dst = zeroPos;
+        zeroPos++;
+      } else {
+        onePos++;
+      }
+      splitKeys[dst] = keys[j];
+      splitValues[dst] = values[j];
+    }
+    workgroupBarrier();
+    for (var j = 0u; j < ELEMENTS_PER_THREAD; j++) {
+      let src = lid * ELEMENTS_PER_THREAD + j;
+      keys[j] = splitKeys[src];
+      values[j] = splitValues[src];
+      digits[j] = select((keys[j] >> shift) & RADIX_MASK, RADIX_MASK, src >= count);
+    }
+    workgroupBarrier();
+  }
+
+  // 块内 digit 直方图与起始位置（WG == RADIX_SIZE，每个线程负责一个 digit）
+  atomicStore(&localHistogram[lid], 0u);
+  workgroupBarrier();
+  for (var j = 0u; j < ELEMENTS_PER_THREAD; j++) {
+    if lid * ELEMENTS_PER_THREAD + j < count {
+      atomicAdd(&localHistogram[digits[j]], 1u);
+    }
+  }
+  workgroupBarrier();
+  let binScan = blockExclusiveScan(lid, atomicLoad(&localHistogram[lid]));
+  // 全局位置 = 全局偏移 + 分区偏移 + (块内位置 - 块内 digit 起点)，u32 回绕相加结果不变
+  digitBase[lid] = globalHistogramDownsweep[RADIX_SIZE * passIdx + lid] +
+                   partitionHistogramDownsweep[RADIX_SIZE * partitionId + lid] - binScan.x;
+  workgroupBarrier();
+
+  for (var j = 0u; j < ELEMENTS_PER_THREAD; j++) {
+    let localIdx = lid * ELEMENTS_PER_THREAD + j;
+    if localIdx < count {
+      let writePos = digitBase[digits[j]] + localIdx;
+      downsweepKeysOut[writePos] = keys[j];
+      downsweepValuesOut[writePos] = values[j];
+    }
+  }
+}
+`;
+}
+
 /**
  * 生成 Radix Sort Shader 代码
  * 完整移植自 rfs-gsplat-render/assets/shaders/radix_sort.wgsl
+ * 元素数量从绑定的 indirect buffer [1] 读取，tile 光栅化器也复用此 shader
+ * @param subgroups 额外生成 subgroup 加速的 downsweepParallel 入口（需要设备启用 subgroups 特性）
  */
-function generateRadixSortShaderCode(): string {
-  return /* wgsl */ `
+export function generateRadixSortShaderCode(subgroups: boolean = false): string {
+  return /* wgsl */ `${subgroups ? "enable subgroups;\n" : ""}
 /**
  * GPU Radix Sort - 3-Pass Architecture
  * 基于 rfs-gsplat-render 实现
@@ -435,7 +726,7 @@ fn downsweep(
     }
   }
 }
-`;
+${subgroups ? generateParallelDownsweepCode() : ""}`;
 }
 
 /**
@@ -454,6 +745,10 @@ export interface CullingOptions {
   farPlane: number;
   pixelThreshold: number;
   frustumDilation?: number;
+  /** 剔除中心在近平面之后且相机位于其内部的 splat */
+  cullCameraInside?: boolean;
+  /** 深度排序键模式 */
+  depthKeyMode?: SplatDepthKeyMode;
 }
 
 /**
@@ -462,6 +757,10 @@ export interface CullingOptions {
 export interface SorterOptions {
   /** 暂时保留，Radix Sort 不使用桶配置 */
   numBuckets?: number;
+  /** 共享上传区（不传时使用独立的上传区） */
+  uploads?: UploadArena;
+  /** 是否使用 subgroup 加速的 downsweep（默认按设备是否启用 subgroups 特性自动选择） */
+  subgroups?: boolean;
 }
 
 /**
@@ -470,8 +769,14 @@ export interface SorterOptions {
  */
 export class GSSplatSorter {
   private device: GPUDevice;
+  private pool: SplatBufferPool;
   private splatCount: number;
 
+  // 每帧参数写入
+  private uploads: UploadArena;
+  // 调试：pass 顺序校验
+  private passTracker: SplatPassTracker | null = null;
+
   // Culling Buffers
   private cullingParamsBuffer: GPUBuffer;
   private depthKeysBuffer: GPUBuffer;
@@ -485,6 +790,8 @@ export class GSSplatSorter {
   private valuesTempBuffer: GPUBuffer;
   // 每个 pass 独立的参数 buffer (避免竞争)
   private sortParamsBuffers: GPUBuffer[] = [];
+  // Stable64：按 splat 索引预排序的 pass 数（索引位数向上取整到偶数个 8-bit pass）
+  private indexSortPassCount: number;
 
   // Sorted output
   private sortedIndicesBuffer: GPUBuffer;
@@ -493,7 +800,47 @@ export class GSSplatSorter {
   private initIndirectPipeline: GPUComputePipeline;
   private projectCullPipeline: GPUComputePipeline;
   private cullingBindGroupLayout: GPUBindGroupLayout;
-  private cullingBindGroup: GPUBindGroup;
+  private cullingBindGroup!: GPUBindGroup;
+  private splatBuffer: GPUBuffer;
+  private cameraBuffer: GPUBuffer;
+
+  // 分块剔除：八叉树 + 每帧 CPU 层级视锥剔除得到的可见区间
+  private octree: SplatOctree | null = null;
+  private chunkOrderBuffer: GPUBuffer;
+  private chunkRangesBuffer: GPUBuffer;
+  private chunkCullViewProjection: Float32Array | null = null;
+  private chunkCullModel: Float32Array | null = null;
+  private chunkCullProjection: Float32Array | null = null;
+  // 节点可见性（PVS），null 表示不过滤
+  private chunkVisibility: SplatNodeVisibility | null = null;
+  private visibleRanges: number[] = [];
+  private chunkVisibleCount: number = 0;
+
+  // 可见数量回读（诊断和直接绘制用）：每隔若干次排序从 indirect buffer 异步读回 instance_count
+  private visibleCountReadback: GPUBuffer | null = null;
+  private visibleCountPending: boolean = false;
+  private visibleCountInterval: number = 0;
+  private sortsSinceReadback: number = 0;
+  private visibleCount: number = -1;
+  // 每次排序把 instance_count 拷贝到的目标（渲染器 uniform，顶点着色器据此剔除多余实例）
+  private visibleCountTarget: GPUBuffer | null = null;
+  private visibleCountTargetOffset: number = 0;
+
+  // LOD：叶子内按重要性排序，逐 chunk 截取前缀
+  private lodConfig: SplatLodConfig | null = null;
+  private lodSelector: SplatLodSelector | null = null;
+  private visibleLeaves: number[] = [];
+
+  // 遮挡剔除：上一帧深度构建的 Hi-Z
+  private hiZ: SplatHiZ | null = null;
+  private hiZPlaceholder: GPUTexture;
+  private occlusionDepth: GPUTexture | null = null;
+  private occlusionReverseZ: boolean = false;
+
+  // 屏幕密度格子（按需创建），格子边长为 0 表示关闭
+  private densityCellSize: number = 0;
+  private densityGridBuffer: GPUBuffer | null = null;
+  private densityPlaceholder: GPUBuffer;
 
   // Radix Sort Pipelines
   private upsweepPipeline: GPUComputePipeline;
@@ -507,6 +854,9 @@ export class GSSplatSorter {
   private upsweepBindGroups: GPUBindGroup[] = [];
   private spineBindGroups: GPUBindGroup[] = [];
   private downsweepBindGroups: GPUBindGroup[] = [];
+  // Stable64 索引预排序：key 为 splat 索引，value 为深度键
+  private indexUpsweepBindGroups: GPUBindGroup[] = [];
+  private indexDownsweepBindGroups: GPUBindGroup[] = [];
 
   private numPartitions: number;
 
@@ -525,22 +875,32 @@ export class GSSplatSorter {
     splatCount: number,
     splatBuffer: GPUBuffer,
     cameraBuffer: GPUBuffer,
-    _options: SorterOptions = {},
+    options: SorterOptions = {},
   ) {
     this.device = device;
+    this.uploads = options.uploads ?? new UploadArena(device);
+    // 模块、布局和管线按设备共享，重新加载数据时不再重复编译
+    const pipelines = PipelineCache.get(device);
+    // 与 splat 数量成正比的 buffer 从共享池申请，销毁时归还
+    this.pool = SplatBufferPool.get(device);
     this.splatCount = splatCount;
+    this.splatBuffer = splatBuffer;
+    this.cameraBuffer = cameraBuffer;
     this.numPartitions = Math.ceil(splatCount / BLOCK_SIZE);
+    this.indexSortPassCount = splatCount <= 0x10000 ? 2 : 4;
 
     // ============================================
     // 创建 Shader 模块
     // ============================================
-    const cullingModule = device.createShaderModule({
+    const cullingModule = pipelines.getShaderModule({
       code: generateCullingShaderCode(),
       label: "culling-shader",
     });
 
-    const radixSortModule = device.createShaderModule({
-      code: generateRadixSortShaderCode(),
+    // subgroup 不可用时回退到 WebGPU 基线的顺序散射
+    const subgroups = (options.subgroups ?? true) && supportsSubgroupSort(device);
+    const radixSortModule = pipelines.getShaderModule({
+      code: generateRadixSortShaderCode(subgroups),
       label: "radix-sort-shader",
     });
 
@@ -548,24 +908,25 @@ export class GSSplatSorter {
     // 创建 Buffers
     // ============================================
 
-    // Culling params: splatCount, nearPlane, farPlane, screenWidth, screenHeight, frustumDilation, pixelThreshold, _pad
+    // Culling params: splatCount, nearPlane, farPlane, screenWidth, screenHeight, frustumDilation, pixelThreshold, rangeCount,
+    // occlusion, reverseZ, hizMipCount, cullCameraInside, densityCellSize, densityGridWidth, pad
     this.cullingParamsBuffer = device.createBuffer({
-      size: 32,
+      size: 64,
       usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
       label: "culling-params",
     });
 
-    this.depthKeysBuffer = device.createBuffer({
-      size: splatCount * 4,
-      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
-      label: "depth-keys",
-    });
+    this.depthKeysBuffer = this.pool.acquire(
+      splatCount * 4,
+      GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      "depth-keys",
+    );
 
-    this.visibleIndicesBuffer = device.createBuffer({
-      size: splatCount * 4,
-      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
-      label: "visible-indices",
-    });
+    this.visibleIndicesBuffer = this.pool.acquire(
+      splatCount * 4,
+      GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST | GPUBufferUsage.COPY_SRC,
+      "visible-indices",
+    );
 
     // Indirect buffer: [vertex_count, instance_count, first_vertex, first_instance]
     this.indirectBuffer = device.createBuffer({
@@ -573,7 +934,8 @@ export class GSSplatSorter {
       usage:
         GPUBufferUsage.STORAGE |
         GPUBufferUsage.INDIRECT |
-        GPUBufferUsage.COPY_DST,
+        GPUBufferUsage.COPY_DST |
+        GPUBufferUsage.COPY_SRC,
       label: "indirect-buffer",
     });
 
@@ -585,24 +947,24 @@ export class GSSplatSorter {
     });
 
     // Partition histogram: numPartitions * RADIX_SIZE
-    this.partitionHistogramBuffer = device.createBuffer({
-      size: this.numPartitions * RADIX_SIZE * 4,
-      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
-      label: "partition-histogram",
-    });
+    this.partitionHistogramBuffer = this.pool.acquire(
+      this.numPartitions * RADIX_SIZE * 4,
+      GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      "partition-histogram",
+    );
 
     // Temp buffers for ping-pong
-    this.keysTempBuffer = device.createBuffer({
-      size: splatCount * 4,
-      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
-      label: "keys-temp",
-    });
-
-    this.valuesTempBuffer = device.createBuffer({
-      size: splatCount * 4,
-      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
-      label: "values-temp",
-    });
+    this.keysTempBuffer = this.pool.acquire(
+      splatCount * 4,
+      GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      "keys-temp",
+    );
+
+    this.valuesTempBuffer = this.pool.acquire(
+      splatCount * 4,
+      GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      "values-temp",
+    );
 
     // 为每个 pass 创建独立的参数 buffer (4 个 pass)
     for (let i = 0; i < 4; i++) {
@@ -624,16 +986,43 @@ export class GSSplatSorter {
     }
 
     // Final sorted indices
-    this.sortedIndicesBuffer = device.createBuffer({
-      size: splatCount * 4,
-      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_SRC,
-      label: "sorted-indices",
+    this.sortedIndicesBuffer = this.pool.acquire(
+      splatCount * 4,
+      GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_SRC | GPUBufferUsage.COPY_DST,
+      "sorted-indices",
+    );
+
+    // 未启用分块剔除时绑定的占位 buffer
+    this.chunkOrderBuffer = device.createBuffer({
+      size: 16,
+      usage: GPUBufferUsage.STORAGE,
+      label: "chunk-order-placeholder",
+    });
+    this.chunkRangesBuffer = device.createBuffer({
+      size: 16,
+      usage: GPUBufferUsage.STORAGE,
+      label: "chunk-ranges-placeholder",
+    });
+
+    // 未启用遮挡剔除时绑定的占位纹理
+    this.hiZPlaceholder = device.createTexture({
+      size: { width: 1, height: 1 },
+      format: "r32float",
+      usage: GPUTextureUsage.TEXTURE_BINDING,
+      label: "hiz-placeholder",
+    });
+
+    // 未统计密度时绑定的占位 buffer
+    this.densityPlaceholder = device.createBuffer({
+      size: 16,
+      usage: GPUBufferUsage.STORAGE,
+      label: "density-grid-placeholder",
     });
 
     // ============================================
     // 创建 Culling Pipelines
     // ============================================
-    this.cullingBindGroupLayout = device.createBindGroupLayout({
+    this.cullingBindGroupLayout = pipelines.getBindGroupLayout({
       label: "culling-bind-group-layout",
       entries: [
         { binding: 0, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
@@ -642,44 +1031,35 @@ export class GSSplatSorter {
         { binding: 3, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
         { binding: 4, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
         { binding: 5, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+        { binding: 6, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 7, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 8, visibility: GPUShaderStage.COMPUTE, texture: { sampleType: "unfilterable-float" } },
+        { binding: 9, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
       ],
     });
 
-    const cullingPipelineLayout = device.createPipelineLayout({
-      bindGroupLayouts: [this.cullingBindGroupLayout],
-    });
+    const cullingPipelineLayout = pipelines.getPipelineLayout([this.cullingBindGroupLayout]);
 
-    this.initIndirectPipeline = device.createComputePipeline({
+    this.initIndirectPipeline = pipelines.getComputePipeline({
       layout: cullingPipelineLayout,
       compute: { module: cullingModule, entryPoint: "initIndirectBuffer" },
       label: "init-indirect-pipeline",
     });
 
-    this.projectCullPipeline = device.createComputePipeline({
+    this.projectCullPipeline = pipelines.getComputePipeline({
       layout: cullingPipelineLayout,
       compute: { module: cullingModule, entryPoint: "projectAndCull" },
       label: "project-cull-pipeline",
     });
 
-    this.cullingBindGroup = device.createBindGroup({
-      layout: this.cullingBindGroupLayout,
-      entries: [
-        { binding: 0, resource: { buffer: splatBuffer } },
-        { binding: 1, resource: { buffer: cameraBuffer } },
-        { binding: 2, resource: { buffer: this.cullingParamsBuffer } },
-        { binding: 3, resource: { buffer: this.depthKeysBuffer } },
-        { binding: 4, resource: { buffer: this.visibleIndicesBuffer } },
-        { binding: 5, resource: { buffer: this.indirectBuffer } },
-      ],
-      label: "culling-bind-group",
-    });
+    this.createCullingBindGroup();
 
     // ============================================
     // 创建 Radix Sort Pipelines
     // ============================================
 
     // Upsweep layout: params, indirect, keys_in, global_histogram, partition_histogram
-    this.upsweepBindGroupLayout = device.createBindGroupLayout({
+    this.upsweepBindGroupLayout = pipelines.getBindGroupLayout({
       label: "upsweep-layout",
       entries: [
         { binding: 0, visibility: GPUShaderStage.COMPUTE, buffer: { type: "uniform" } },
@@ -691,7 +1071,7 @@ export class GSSplatSorter {
     });
 
     // Spine layout: indirect, global_histogram, partition_histogram, params
-    this.spineBindGroupLayout = device.createBindGroupLayout({
+    this.spineBindGroupLayout = pipelines.getBindGroupLayout({
       label: "spine-layout",
       entries: [
         { binding: 0, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
@@ -702,7 +1082,7 @@ export class GSSplatSorter {
     });
 
     // Downsweep layout: params, indirect, global_histogram, partition_histogram, keys_in, values_in, keys_out, values_out
-    this.downsweepBindGroupLayout = device.createBindGroupLayout({
+    this.downsweepBindGroupLayout = pipelines.getBindGroupLayout({
       label: "downsweep-layout",
       entries: [
         { binding: 0, visibility: GPUShaderStage.COMPUTE, buffer: { type: "uniform" } },
@@ -716,21 +1096,21 @@ export class GSSplatSorter {
       ],
     });
 
-    this.upsweepPipeline = device.createComputePipeline({
-      layout: device.createPipelineLayout({ bindGroupLayouts: [this.upsweepBindGroupLayout] }),
+    this.upsweepPipeline = pipelines.getComputePipeline({
+      layout: pipelines.getPipelineLayout([this.upsweepBindGroupLayout]),
       compute: { module: radixSortModule, entryPoint: "upsweep" },
       label: "upsweep-pipeline",
     });
 
-    this.spinePipeline = device.createComputePipeline({
-      layout: device.createPipelineLayout({ bindGroupLayouts: [this.spineBindGroupLayout] }),
+    this.spinePipeline = pipelines.getComputePipeline({
+      layout: pipelines.getPipelineLayout([this.spineBindGroupLayout]),
       compute: { module: radixSortModule, entryPoint: "spine" },
       label: "spine-pipeline",
     });
 
-    this.downsweepPipeline = device.createComputePipeline({
-      layout: device.createPipelineLayout({ bindGroupLayouts: [this.downsweepBindGroupLayout] }),
-      compute: { module: radixSortModule, entryPoint: "downsweep" },
+    this.downsweepPipeline = pipelines.getComputePipeline({
+      layout: pipelines.getPipelineLayout([this.downsweepBindGroupLayout]),
+      compute: { module: radixSortModule, entryPoint: getDownsweepEntryPoint(subgroups) },
       label: "downsweep-pipeline",
     });
 
@@ -740,6 +1120,28 @@ export class GSSplatSorter {
     this.createRadixSortBindGroups();
   }
 
+  /**
+   * 创建剔除 bind group（分块数据、Hi-Z 纹理或密度格子变化时重建）
+   */
+  private createCullingBindGroup(): void {
+    this.cullingBindGroup = this.device.createBindGroup({
+      layout: this.cullingBindGroupLayout,
+      entries: [
+        { binding: 0, resource: { buffer: this.splatBuffer } },
+        { binding: 1, resource: { buffer: this.cameraBuffer } },
+        { binding: 2, resource: { buffer: this.cullingParamsBuffer } },
+        { binding: 3, resource: { buffer: this.depthKeysBuffer } },
+        { binding: 4, resource: { buffer: this.visibleIndicesBuffer } },
+        { binding: 5, resource: { buffer: this.indirectBuffer } },
+        { binding: 6, resource: { buffer: this.chunkOrderBuffer } },
+        { binding: 7, resource: { buffer: this.chunkRangesBuffer } },
+        { binding: 8, resource: this.hiZ?.getView() ?? this.hiZPlaceholder.createView() },
+        { binding: 9, resource: { buffer: this.densityGridBuffer ?? this.densityPlaceholder } },
+      ],
+      label: "culling-bind-group",
+    });
+  }
+
   /**
    * 创建 Radix Sort 的 bind groups
    * 4 个 pass，使用 ping-pong buffers
@@ -809,6 +1211,39 @@ export class GSSplatSorter {
         ],
         label: `downsweep-bind-group-${passIdx}`,
       });
+
+      // 索引预排序的 ping-pong：偶数 pass 写入临时 buffer，偶数个 pass 后回到 visibleIndices/depthKeys
+      const indexKeysIn = isEvenPass ? this.visibleIndicesBuffer : this.valuesTempBuffer;
+      const indexValuesIn = isEvenPass ? this.depthKeysBuffer : this.keysTempBuffer;
+      const indexKeysOut = isEvenPass ? this.valuesTempBuffer : this.visibleIndicesBuffer;
+      const indexValuesOut = isEvenPass ? this.keysTempBuffer : this.depthKeysBuffer;
+
+      this.indexUpsweepBindGroups[passIdx] = this.device.createBindGroup({
+        layout: this.upsweepBindGroupLayout,
+        entries: [
+          { binding: 0, resource: { buffer: this.sortParamsBuffers[passIdx] } },
+          { binding: 1, resource: { buffer: this.indirectBuffer } },
+          { binding: 2, resource: { buffer: indexKeysIn } },
+          { binding: 3, resource: { buffer: this.globalHistogramBuffer } },
+          { binding: 4, resource: { buffer: this.partitionHistogramBuffer } },
+        ],
+        label: `index-upsweep-bind-group-${passIdx}`,
+      });
+
+      this.indexDownsweepBindGroups[passIdx] = this.device.createBindGroup({
+        layout: this.downsweepBindGroupLayout,
+        entries: [
+          { binding: 0, resource: { buffer: this.sortParamsBuffers[passIdx] } },
+          { binding: 1, resource: { buffer: this.indirectBuffer } },
+          { binding: 2, resource: { buffer: this.globalHistogramBuffer } },
+          { binding: 3, resource: { buffer: this.partitionHistogramBuffer } },
+          { binding: 4, resource: { buffer: indexKeysIn } },
+          { binding: 5, resource: { buffer: indexValuesIn } },
+          { binding: 6, resource: { buffer: indexKeysOut } },
+          { binding: 7, resource: { buffer: indexValuesOut } },
+        ],
+        label: `index-downsweep-bind-group-${passIdx}`,
+      });
     }
   }
 
@@ -827,28 +1262,250 @@ export class GSSplatSorter {
     this.cullingOptions = { ...this.cullingOptions, ...options };
   }
 
+  /**
+   * 设置 pass 顺序校验记录器（null 关闭）
+   */
+  setPassTracker(tracker: SplatPassTracker | null): void {
+    this.passTracker = tracker;
+  }
+
+  /**
+   * 设置八叉树分块（null 关闭分块剔除，每帧处理全部 splat）
+   */
+  setOctree(octree: SplatOctree | null): void {
+    if (octree && octree.order.length !== this.splatCount) {
+      throw new Error("Octree splat count does not match sorter");
+    }
+    this.octree = octree;
+    this.chunkVisibility = null;
+    this.lodSelector = octree && this.lodConfig ? new SplatLodSelector(octree) : null;
+    this.pool.release(this.chunkOrderBuffer);
+    this.pool.release(this.chunkRangesBuffer);
+
+    if (octree) {
+      this.chunkOrderBuffer = this.pool.acquire(
+        octree.order.byteLength,
+        GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+        "chunk-order",
+      );
+      this.device.queue.writeBuffer(this.chunkOrderBuffer, 0, octree.order);
+      this.chunkRangesBuffer = this.pool.acquire(
+        octree.leafCount * 8,
+        GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+        "chunk-ranges",
+      );
+    } else {
+      this.chunkOrderBuffer = this.device.createBuffer({ size: 16, usage: GPUBufferUsage.STORAGE, label: "chunk-order-placeholder" });
+      this.chunkRangesBuffer = this.device.createBuffer({ size: 16, usage: GPUBufferUsage.STORAGE, label: "chunk-ranges-placeholder" });
+    }
+    this.createCullingBindGroup();
+  }
+
+  /**
+   * 设置分块剔除使用的矩阵（与 cameraBuffer 中的变换等价的绝对坐标矩阵）
+   * @param projection 投影矩阵，LOD 用于估计 chunk 的像素尺寸
+   */
+  setChunkCullMatrices(viewProjection: Float32Array, model: Float32Array, projection?: Float32Array): void {
+    this.chunkCullViewProjection = viewProjection;
+    this.chunkCullModel = model;
+    this.chunkCullProjection = projection ?? null;
+  }
+
+  /**
+   * 设置分块剔除的节点可见性掩码（按八叉树节点索引，如 PVS 查询结果），null 表示不过滤
+   */
+  setChunkVisibility(visibility: SplatNodeVisibility | null): void {
+    this.chunkVisibility = visibility;
+  }
+
+  /**
+   * 设置 LOD 配置（null 关闭），需要同时启用分块剔除
+   */
+  setLodConfig(config: SplatLodConfig | null): void {
+    if (config && !this.lodConfig && this.octree) {
+      this.lodSelector = new SplatLodSelector(this.octree);
+    } else if (!config) {
+      this.lodSelector = null;
+    }
+    this.lodConfig = config;
+  }
+
+  /**
+   * 设置遮挡剔除使用的深度纹理（上一帧的场景深度，null 关闭遮挡剔除）
+   * 深度纹理需要 TEXTURE_BINDING 用途
+   */
+  setOcclusionDepth(depthTexture: GPUTexture | null, depthConfig: DepthConfig): void {
+    this.occlusionDepth = depthTexture;
+    this.occlusionReverseZ = depthConfig.reverseZ;
+    if (depthTexture && !this.hiZ) {
+      this.hiZ = new SplatHiZ(this.device, depthConfig);
+    }
+  }
+
+  /**
+   * 设置屏幕密度格子边长（像素），0 关闭
+   * 开启后剔除 pass 统计每个格子内中心可见的 splat 数量，格子按行排列，列数为 ceil(屏幕宽度 / 边长)
+   */
+  setDensityCellSize(cellSize: number): void {
+    this.densityCellSize = Math.max(0, Math.floor(cellSize));
+  }
+
+  /**
+   * 获取屏幕密度格子 buffer（未开启时为 null，尺寸变化时重建）
+   */
+  getDensityGridBuffer(): GPUBuffer | null {
+    return this.densityGridBuffer;
+  }
+
+  /**
+   * 按屏幕尺寸创建 / 释放密度格子 buffer
+   */
+  private updateDensityGrid(): void {
+    let size = 0;
+    if (this.densityCellSize > 0) {
+      const columns = Math.ceil(this.screenWidth / this.densityCellSize);
+      const rows = Math.ceil(this.screenHeight / this.densityCellSize);
+      size = Math.max(columns * rows * 4, 16);
+    }
+    if ((this.densityGridBuffer?.size ?? 0) === size) {
+      return;
+    }
+    this.densityGridBuffer?.destroy();
+    this.densityGridBuffer = size > 0
+      ? this.device.createBuffer({
+        size,
+        usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+        label: "density-grid",
+      })
+      : null;
+    this.createCullingBindGroup();
+  }
+
+  /**
+   * 获取上一帧分块剔除后需要逐 splat 剔除的数量
+   */
+  getChunkCulledCount(): number {
+    return this.chunkVisibleCount;
+  }
+
+  /**
+   * 设置可见数量回读间隔（排序次数），0 关闭
+   */
+  setVisibleCountReadback(interval: number): void {
+    this.visibleCountInterval = Math.max(0, Math.floor(interval));
+    this.sortsSinceReadback = 0;
+    if (this.visibleCountInterval === 0) {
+      this.visibleCount = -1;
+    }
+  }
+
+  /**
+   * 最近一次回读的可见 splat 数量（未开启回读或尚未读回时为 -1）
+   */
+  getVisibleCount(): number {
+    return this.visibleCount;
+  }
+
+  /**
+   * 设置每次排序后接收可见数量 (u32) 的 buffer，需要 COPY_DST
+   */
+  setVisibleCountTarget(buffer: GPUBuffer | null, offset: number = 0): void {
+    this.visibleCountTarget = buffer;
+    this.visibleCountTargetOffset = offset;
+  }
+
   /**
    * 执行剔除和排序
    * 每帧调用
+   * @param cullOnly 只做剔除，可见索引按未排序顺序输出 (用于无需排序的随机透明度)
+   * @param keyBits 排序键精度：16 只排序高 16 位（跳过前两个 pass，相机移动时使用）
    */
-  sort(): void {
+  sort(cullOnly: boolean = false, keyBits: 16 | 32 = 32): void {
+    // ============================================
+    // 分块剔除：CPU 遍历八叉树，只把可见 chunk 交给 GPU 逐 splat 剔除
+    // ============================================
+    let processCount = this.splatCount;
+    let rangeCount = 0;
+    const frustumDilation = this.cullingOptions.frustumDilation ?? 0.2;
+    if (this.octree && this.chunkCullViewProjection && this.chunkCullModel) {
+      if (this.lodSelector && this.lodConfig && this.chunkCullProjection) {
+        collectVisibleLeaves(
+          this.octree,
+          this.chunkCullViewProjection,
+          this.chunkCullModel,
+          frustumDilation,
+          this.visibleLeaves,
+          this.chunkVisibility,
+        );
+        processCount = this.lodSelector.select(
+          this.visibleLeaves,
+          this.chunkCullViewProjection,
+          this.chunkCullModel,
+          Math.abs(this.chunkCullProjection[5]) * this.screenHeight * 0.5,
+          this.lodConfig,
+          this.visibleRanges,
+        );
+      } else {
+        processCount = cullSplatOctree(
+          this.octree,
+          this.chunkCullViewProjection,
+          this.chunkCullModel,
+          frustumDilation,
+          this.visibleRanges,
+          this.chunkVisibility,
+        );
+      }
+      rangeCount = this.visibleRanges.length / 2;
+      if (rangeCount > 0) {
+        // (线程偏移, order 起始位置)
+        const ranges = new Uint32Array(rangeCount * 2);
+        let offset = 0;
+        for (let r = 0; r < rangeCount; r++) {
+          ranges[r * 2] = offset;
+          ranges[r * 2 + 1] = this.visibleRanges[r * 2];
+          offset += this.visibleRanges[r * 2 + 1];
+        }
+        this.uploads.write(this.chunkRangesBuffer, 0, ranges);
+      }
+    }
+    this.chunkVisibleCount = processCount;
+
     // ============================================
     // 更新 Culling 参数
     // ============================================
-    const cullingParamsData = new ArrayBuffer(32);
+    const occlusion = this.occlusionDepth !== null && this.hiZ !== null;
+    const cullingParamsData = new ArrayBuffer(64);
     const view = new DataView(cullingParamsData);
-    view.setUint32(0, this.splatCount, true);
+    view.setUint32(0, processCount, true);
     view.setFloat32(4, this.cullingOptions.nearPlane, true);
     view.setFloat32(8, this.cullingOptions.farPlane, true);
     view.setFloat32(12, this.screenWidth, true);
     view.setFloat32(16, this.screenHeight, true);
-    view.setFloat32(20, this.cullingOptions.frustumDilation ?? 0.2, true);
+    view.setFloat32(20, frustumDilation, true);
     view.setFloat32(24, this.cullingOptions.pixelThreshold, true);
-    view.setFloat32(28, 0, true);
-    this.device.queue.writeBuffer(this.cullingParamsBuffer, 0, cullingParamsData);
+    view.setUint32(28, rangeCount, true);
 
     const encoder = this.device.createCommandEncoder({ label: "splat-sort-encoder" });
 
+    // ============================================
+    // Hi-Z：此时本帧主 pass 尚未提交，深度纹理仍是上一帧的结果
+    // ============================================
+    if (occlusion) {
+      if (this.hiZ!.build(encoder, this.occlusionDepth!)) {
+        this.createCullingBindGroup();
+      }
+    }
+    view.setUint32(32, occlusion ? 1 : 0, true);
+    view.setUint32(36, this.occlusionReverseZ ? 1 : 0, true);
+    view.setUint32(40, this.hiZ?.getMipLevelCount() ?? 0, true);
+    view.setUint32(44, this.cullingOptions.cullCameraInside ? 1 : 0, true);
+    this.updateDensityGrid();
+    const densityCellSize = this.densityGridBuffer ? this.densityCellSize : 0;
+    view.setUint32(48, densityCellSize, true);
+    view.setUint32(52, densityCellSize > 0 ? Math.ceil(this.screenWidth / densityCellSize) : 0, true);
+    this.uploads.write(this.cullingParamsBuffer, 0, cullingParamsData);
+    this.uploads.flush();
+
     // ============================================
     // 清理 buffers (关键! 防止上一帧数据导致闪烁)
     // ============================================
@@ -858,6 +1515,9 @@ export class GSSplatSorter {
     encoder.clearBuffer(this.valuesTempBuffer);
     encoder.clearBuffer(this.globalHistogramBuffer);
     encoder.clearBuffer(this.partitionHistogramBuffer);
+    if (this.densityGridBuffer) {
+      encoder.clearBuffer(this.densityGridBuffer);
+    }
 
     // ============================================
     // Pass 0: 初始化 Indirect Buffer
@@ -877,45 +1537,126 @@ export class GSSplatSorter {
       const pass = encoder.beginComputePass({ label: "project-cull" });
       pass.setPipeline(this.projectCullPipeline);
       pass.setBindGroup(0, this.cullingBindGroup);
-      pass.dispatchWorkgroups(Math.ceil(this.splatCount / WORKGROUP_SIZE));
+      pass.dispatchWorkgroups(Math.ceil(processCount / WORKGROUP_SIZE));
       pass.end();
     }
+    this.passTracker?.record(
+      "cull", "project-cull",
+      [this.cullingParamsBuffer, this.splatBuffer, this.chunkOrderBuffer, this.chunkRangesBuffer],
+      this.densityGridBuffer
+        ? [this.depthKeysBuffer, this.visibleIndicesBuffer, this.indirectBuffer, this.densityGridBuffer]
+        : [this.depthKeysBuffer, this.visibleIndicesBuffer, this.indirectBuffer],
+    );
+
+    const readback = this.encodeVisibleCountReadback(encoder);
+    if (this.visibleCountTarget) {
+      encoder.copyBufferToBuffer(this.indirectBuffer, 4, this.visibleCountTarget, this.visibleCountTargetOffset, 4);
+    }
+
+    if (cullOnly) {
+      encoder.copyBufferToBuffer(this.visibleIndicesBuffer, 0, this.sortedIndicesBuffer, 0, this.splatCount * 4);
+      this.device.queue.submit([encoder.finish()]);
+      if (readback) this.mapVisibleCount();
+      this.passTracker?.record("sort", "copy-visible", [this.visibleIndicesBuffer], [this.sortedIndicesBuffer]);
+      return;
+    }
 
     // ============================================
     // Radix Sort: 4 passes (8-bit increments)
     // 每个 pass 在独立的 compute pass 中以确保内存同步
     // 参数已在构造函数中预填充到独立的 buffer
+    // 16 位精度时从 pass 2 开始：pass 2 读原始 buffer、pass 3 写入最终结果，ping-pong 不受影响
+    // 深度键是浮点位编码，高 16 位保留符号、指数和 7 位尾数 (约 1% 相对深度精度)
     // ============================================
-    for (let passIdx = 0; passIdx < 4; passIdx++) {
-      // Upsweep
-      {
-        const pass = encoder.beginComputePass({ label: `upsweep-p${passIdx}` });
-        pass.setPipeline(this.upsweepPipeline);
-        pass.setBindGroup(0, this.upsweepBindGroups[passIdx]);
-        pass.dispatchWorkgroups(this.numPartitions);
-        pass.end();
-      }
-
-      // Spine
-      {
-        const pass = encoder.beginComputePass({ label: `spine-p${passIdx}` });
-        pass.setPipeline(this.spinePipeline);
-        pass.setBindGroup(0, this.spineBindGroups[passIdx]);
-        pass.dispatchWorkgroups(RADIX_SIZE);
-        pass.end();
+    // Stable64：剔除输出顺序来自 atomicAdd，不确定；先按 splat 索引排序，
+    // 之后的深度排序是稳定的，深度键相同的 splat 按索引排列，等价于 (深度, 索引) 64 位键
+    if (this.cullingOptions.depthKeyMode === SplatDepthKeyMode.Stable64) {
+      for (let passIdx = 0; passIdx < this.indexSortPassCount; passIdx++) {
+        this.encodeRadixPass(encoder, passIdx, this.indexUpsweepBindGroups[passIdx], this.indexDownsweepBindGroups[passIdx], "index-");
       }
+      encoder.clearBuffer(this.globalHistogramBuffer);
+    }
 
-      // Downsweep
-      {
-        const pass = encoder.beginComputePass({ label: `downsweep-p${passIdx}` });
-        pass.setPipeline(this.downsweepPipeline);
-        pass.setBindGroup(0, this.downsweepBindGroups[passIdx]);
-        pass.dispatchWorkgroups(this.numPartitions);
-        pass.end();
-      }
+    for (let passIdx = keyBits === 16 ? 2 : 0; passIdx < 4; passIdx++) {
+      this.encodeRadixPass(encoder, passIdx, this.upsweepBindGroups[passIdx], this.downsweepBindGroups[passIdx]);
     }
 
     this.device.queue.submit([encoder.finish()]);
+    if (readback) this.mapVisibleCount();
+    this.passTracker?.record(
+      "sort", "radix-sort",
+      [this.depthKeysBuffer, this.visibleIndicesBuffer, this.indirectBuffer],
+      [this.sortedIndicesBuffer],
+    );
+  }
+
+  // 到达回读间隔且上一次回读已完成时，把 instance_count 复制到回读 buffer
+  private encodeVisibleCountReadback(encoder: GPUCommandEncoder): boolean {
+    if (this.visibleCountInterval === 0 || this.visibleCountPending) {
+      return false;
+    }
+    if (++this.sortsSinceReadback < this.visibleCountInterval) {
+      return false;
+    }
+    this.sortsSinceReadback = 0;
+    this.visibleCountReadback ??= this.device.createBuffer({
+      size: 4,
+      usage: GPUBufferUsage.MAP_READ | GPUBufferUsage.COPY_DST,
+      label: "visible-count-readback",
+    });
+    encoder.copyBufferToBuffer(this.indirectBuffer, 4, this.visibleCountReadback, 0, 4);
+    this.visibleCountPending = true;
+    return true;
+  }
+
+  private mapVisibleCount(): void {
+    const buffer = this.visibleCountReadback!;
+    buffer.mapAsync(GPUMapMode.READ).then(() => {
+      this.visibleCount = new Uint32Array(buffer.getMappedRange())[0];
+      buffer.unmap();
+    }).catch(() => {
+      // buffer 已销毁
+    }).finally(() => {
+      this.visibleCountPending = false;
+    });
+  }
+
+  /**
+   * 编码一个 8-bit radix pass：Upsweep -> Spine -> Downsweep
+   */
+  private encodeRadixPass(
+    encoder: GPUCommandEncoder,
+    passIdx: number,
+    upsweepBindGroup: GPUBindGroup,
+    downsweepBindGroup: GPUBindGroup,
+    labelPrefix: string = "",
+  ): void {
+    // Upsweep
+    {
+      const pass = encoder.beginComputePass({ label: `${labelPrefix}upsweep-p${passIdx}` });
+      pass.setPipeline(this.upsweepPipeline);
+      pass.setBindGroup(0, upsweepBindGroup);
+      pass.dispatchWorkgroups(this.numPartitions);
+      pass.end();
+    }
+
+    // Spine
+    {
+      const pass = encoder.beginComputePass({ label: `${labelPrefix}spine-p${passIdx}` });
+      pass.setPipeline(this.spinePipeline);
+      pass.setBindGroup(0, this.spineBindGroups[passIdx]);
+      pass.dispatchWorkgroups(RADIX_SIZE);
+      pass.end();
+    }
+
+    // Downsweep
+    {
+      const pass = encoder.beginComputePass({ label: `${labelPrefix}downsweep-p${passIdx}` });
+      pass.setPipeline(this.downsweepPipeline);
+      pass.setBindGroup(0, downsweepBindGroup);
+      pass.dispatchWorkgroups(this.numPartitions);
+      pass.end();
+    }
   }
 
   /**
@@ -939,21 +1680,49 @@ export class GSSplatSorter {
     return this.splatCount;
   }
 
+  /**
+   * 获取排序器自身占用的显存（字节，不含共享的 splat / uniform buffer）
+   */
+  getGPUMemoryUsage(): number {
+    return getBuffersByteSize([
+      this.cullingParamsBuffer,
+      this.depthKeysBuffer,
+      this.visibleIndicesBuffer,
+      this.indirectBuffer,
+      this.globalHistogramBuffer,
+      this.partitionHistogramBuffer,
+      this.keysTempBuffer,
+      this.valuesTempBuffer,
+      ...this.sortParamsBuffers,
+      this.sortedIndicesBuffer,
+      this.chunkOrderBuffer,
+      this.chunkRangesBuffer,
+      this.densityGridBuffer,
+    ]);
+  }
+
   /**
    * 销毁资源
    */
   destroy(): void {
     this.cullingParamsBuffer.destroy();
-    this.depthKeysBuffer.destroy();
-    this.visibleIndicesBuffer.destroy();
+    this.pool.release(this.depthKeysBuffer);
+    this.pool.release(this.visibleIndicesBuffer);
     this.indirectBuffer.destroy();
+    this.visibleCountReadback?.destroy();
     this.globalHistogramBuffer.destroy();
-    this.partitionHistogramBuffer.destroy();
-    this.keysTempBuffer.destroy();
-    this.valuesTempBuffer.destroy();
+    this.pool.release(this.partitionHistogramBuffer);
+    this.pool.release(this.keysTempBuffer);
+    this.pool.release(this.valuesTempBuffer);
     for (const buffer of this.sortParamsBuffers) {
       buffer.destroy();
     }
-    this.sortedIndicesBuffer.destroy();
+    this.pool.release(this.sortedIndicesBuffer);
+    this.pool.release(this.chunkOrderBuffer);
+    this.pool.release(this.chunkRangesBuffer);
+    this.hiZ?.destroy();
+    this.hiZPlaceholder.destroy();
+    this.densityGridBuffer?.destroy();
+    this.densityPlaceholder.destroy();
   }
 }
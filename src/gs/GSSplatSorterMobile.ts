@@ -7,6 +7,9 @@
  * 3. 针对 iOS 优化的桶数量
  */
 
+import { UploadArena } from "../core/UploadArena";
+import { getBuffersByteSize } from "./SplatMemoryBudget";
+
 // 默认配置
 const DEFAULT_NUM_BUCKETS = 65536;
 const IOS_NUM_BUCKETS = 4096;
@@ -17,6 +20,8 @@ const WORKGROUP_SIZE = 256;
  */
 export interface SorterOptions {
   numBuckets?: number;
+  /** 共享上传区（不传时使用独立的上传区） */
+  uploads?: UploadArena;
 }
 
 /**
@@ -241,6 +246,7 @@ fn resetBucketPositions(@builtin(global_invocation_id) gid: vec3<u32>) {
 export class GSSplatSorterMobile {
   private device: GPUDevice;
   private splatCount: number;
+  private uploads: UploadArena;
 
   // Buffers
   private cullingParamsBuffer: GPUBuffer;
@@ -284,6 +290,16 @@ export class GSSplatSorterMobile {
     pixelThreshold: 1.0,
   };
 
+  // 可见数量回读（直接绘制用）：每隔若干次排序从 indirect buffer 异步读回 instance_count
+  private visibleCountReadback: GPUBuffer | null = null;
+  private visibleCountPending: boolean = false;
+  private visibleCountInterval: number = 0;
+  private sortsSinceReadback: number = 0;
+  private visibleCount: number = -1;
+  // 每次排序把 instance_count 拷贝到的目标（渲染器 uniform）
+  private visibleCountTarget: GPUBuffer | null = null;
+  private visibleCountTargetOffset: number = 0;
+
   constructor(
     device: GPUDevice,
     splatCount: number,
@@ -293,6 +309,7 @@ export class GSSplatSorterMobile {
   ) {
     this.device = device;
     this.splatCount = splatCount;
+    this.uploads = options.uploads ?? new UploadArena(device);
 
     const isIOS = isIOSDevice();
     this.numBuckets = options.numBuckets ?? (isIOS ? IOS_NUM_BUCKETS : DEFAULT_NUM_BUCKETS);
@@ -356,7 +373,7 @@ export class GSSplatSorterMobile {
 
     this.drawIndirectBuffer = device.createBuffer({
       size: 16,
-      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.INDIRECT | GPUBufferUsage.COPY_DST,
+      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.INDIRECT | GPUBufferUsage.COPY_DST | GPUBufferUsage.COPY_SRC,
     });
 
     // 创建 Bind Group Layouts 和 Pipelines
@@ -508,7 +525,8 @@ export class GSSplatSorterMobile {
       cullingParamsView.setFloat32(20, this.cullingOptions.pixelThreshold, true);
       cullingParamsView.setFloat32(24, 0, true);
       cullingParamsView.setFloat32(28, 0, true);
-      this.device.queue.writeBuffer(this.cullingParamsBuffer, 0, cullingParamsData);
+      this.uploads.write(this.cullingParamsBuffer, 0, cullingParamsData);
+      this.uploads.flush();
 
       const cullWorkgroupCount = Math.ceil(this.splatCount / this.WORKGROUP_SIZE);
       const bucketResetWorkgroups = Math.ceil(this.numBuckets / this.WORKGROUP_SIZE);
@@ -578,7 +596,12 @@ export class GSSplatSorterMobile {
         pass.end();
       }
 
+      if (this.visibleCountTarget) {
+        encoder.copyBufferToBuffer(this.drawIndirectBuffer, 4, this.visibleCountTarget, this.visibleCountTargetOffset, 4);
+      }
+      const readback = this.encodeVisibleCountReadback(encoder);
       this.device.queue.submit([encoder.finish()]);
+      if (readback) this.mapVisibleCount();
     } catch (error) {
       // 排序错误（静默处理）
     }
@@ -605,6 +628,80 @@ export class GSSplatSorterMobile {
     return this.splatCount;
   }
 
+  /**
+   * 设置可见数量回读间隔（排序次数），0 关闭
+   */
+  setVisibleCountReadback(interval: number): void {
+    this.visibleCountInterval = Math.max(0, Math.floor(interval));
+    this.sortsSinceReadback = 0;
+    if (this.visibleCountInterval === 0) {
+      this.visibleCount = -1;
+    }
+  }
+
+  /**
+   * 最近一次回读的可见 splat 数量（未开启回读或尚未读回时为 -1）
+   */
+  getVisibleCount(): number {
+    return this.visibleCount;
+  }
+
+  /**
+   * 设置每次排序后接收可见数量 (u32) 的 buffer，需要 COPY_DST
+   */
+  setVisibleCountTarget(buffer: GPUBuffer | null, offset: number = 0): void {
+    this.visibleCountTarget = buffer;
+    this.visibleCountTargetOffset = offset;
+  }
+
+  // 到达回读间隔且上一次回读已完成时，把 instance_count 复制到回读 buffer
+  private encodeVisibleCountReadback(encoder: GPUCommandEncoder): boolean {
+    if (this.visibleCountInterval === 0 || this.visibleCountPending) {
+      return false;
+    }
+    if (++this.sortsSinceReadback < this.visibleCountInterval) {
+      return false;
+    }
+    this.sortsSinceReadback = 0;
+    this.visibleCountReadback ??= this.device.createBuffer({
+      size: 4,
+      usage: GPUBufferUsage.MAP_READ | GPUBufferUsage.COPY_DST,
+      label: "visible-count-readback",
+    });
+    encoder.copyBufferToBuffer(this.drawIndirectBuffer, 4, this.visibleCountReadback, 0, 4);
+    this.visibleCountPending = true;
+    return true;
+  }
+
+  private mapVisibleCount(): void {
+    const buffer = this.visibleCountReadback!;
+    buffer.mapAsync(GPUMapMode.READ).then(() => {
+      this.visibleCount = new Uint32Array(buffer.getMappedRange())[0];
+      buffer.unmap();
+    }).catch(() => {
+      // buffer 已销毁
+    }).finally(() => {
+      this.visibleCountPending = false;
+    });
+  }
+
+  /**
+   * 获取排序器自身占用的显存（字节，不含共享的位置 / uniform buffer）
+   */
+  getGPUMemoryUsage(): number {
+    return getBuffersByteSize([
+      this.cullingParamsBuffer,
+      this.countersBuffer,
+      this.visibleIndicesBuffer,
+      this.depthKeysBuffer,
+      this.bucketCountsBuffer,
+      this.bucketOffsetsBuffer,
+      this.bucketPositionsBuffer,
+      this.sortedIndicesBuffer,
+      this.drawIndirectBuffer,
+    ]);
+  }
+
   /**
    * 销毁资源
    */
@@ -618,5 +715,7 @@ export class GSSplatSorterMobile {
     this.bucketPositionsBuffer.destroy();
     this.sortedIndicesBuffer.destroy();
     this.drawIndirectBuffer.destroy();
+    this.visibleCountReadback?.destroy();
+    this.visibleCountReadback = null;
   }
 }
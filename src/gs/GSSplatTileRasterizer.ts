@@ -0,0 +1,865 @@
+/**
+ * GSSplatTileRasterizer - 基于 tile 分箱的 compute 光栅化后端
+ *
+ * 与原版 3DGS 论文 / brush 的做法一致，不再绘制实例化 quad：
+ * 1. Preprocess: 对排序后的可见 splat 计算 2D conic、屏幕中心和覆盖的 tile 数
+ * 2. Scan: 对每个 splat 的 tile 数做前缀和，得到 (tile, splat) 对的写入偏移
+ * 3. Emit: 写出 (tileId, 排序序号) 对；同一 tile 内的序号天然按深度有序
+ * 4. Sort: 复用 GSSplatSorter 的稳定 radix sort 按 tileId 排序，保持深度顺序
+ * 5. Ranges: 计算每个 tile 在排序结果中的区间
+ * 6. Rasterize: 每个 tile 一个 16x16 工作组，从近到远 alpha 合成并写入存储纹理
+ * 7. Composite: 全屏三角形把结果以预乘 alpha 混合到当前渲染通道
+ *
+ * 深度顺序来自 GSSplatSorter 的结果 (从远到近)，因此 tile 内不需要再按深度排序。
+ */
+
+import {
+  generateRadixSortShaderCode,
+  getDownsweepEntryPoint,
+  supportsSubgroupSort,
+  RADIX_BITS,
+  RADIX_SIZE,
+  RADIX_BLOCK_SIZE,
+} from "./GSSplatSorter";
+import { PipelineCache } from "../core/PipelineCache";
+import { SplatBufferPool } from "./SplatBufferPool";
+import { getBuffersByteSize } from "./SplatMemoryBudget";
+
+const TILE_SIZE = 16;
+const WORKGROUP_SIZE = 256;
+const SCAN_BLOCK_SIZE = 1024;
+
+/**
+ * tile 光栅化器配置
+ */
+export interface TileRasterizerOptions {
+  /** (tile, splat) 对的最大数量，超出部分会被丢弃 */
+  maxTilePairs?: number;
+  /** 每个像素最多合成的 splat 数量（从近到远的前 K 个），0 表示不限制 */
+  maxSplatsPerPixel?: number;
+}
+
+/**
+ * Tile 光栅化 compute shader
+ * 拼接在渲染器 shader 之后，复用其 Uniforms / Splat / 协方差投影 / splatColor
+ */
+const tileComputeShader = /* wgsl */ `
+// ============================================
+// Tile 光栅化
+// ============================================
+
+const TILE_SIZE: u32 = ${TILE_SIZE}u;
+const SCAN_BLOCK: u32 = ${SCAN_BLOCK_SIZE}u;
+
+struct TileParams {
+  tilesX: u32,
+  tilesY: u32,
+  pairCapacity: u32,
+  maxSplatsPerPixel: u32,  // 0 表示不限制
+}
+
+struct ProjectedSplat {
+  centerRadius: vec4<f32>,  // 像素中心 (y 向下), 半径 (像素), 不透明度
+  conic: vec4<f32>,         // 逆协方差 (a, b, c), 未使用
+  color: vec4<f32>,
+}
+
+@group(1) @binding(0) var<uniform> tileParams: TileParams;
+@group(1) @binding(1) var<storage, read> drawIndirect: array<u32>;
+@group(1) @binding(2) var<storage, read_write> projected: array<ProjectedSplat>;
+@group(1) @binding(3) var<storage, read_write> tileCounts: array<u32>;
+@group(1) @binding(4) var<storage, read_write> tileOffsets: array<u32>;
+@group(1) @binding(5) var<storage, read_write> blockSums: array<u32>;
+@group(1) @binding(6) var<storage, read_write> pairInfo: array<u32>;
+@group(1) @binding(7) var<storage, read_write> pairKeys: array<u32>;
+@group(1) @binding(8) var<storage, read_write> pairValues: array<u32>;
+@group(1) @binding(9) var<storage, read_write> tileRanges: array<vec2<u32>>;
+@group(1) @binding(10) var outputTex: texture_storage_2d<rgba16float, write>;
+
+// splat 覆盖的 tile 区间 [min, max)
+fn tileRect(centerRadius: vec4<f32>) -> vec4<i32> {
+  let tiles = vec2<i32>(i32(tileParams.tilesX), i32(tileParams.tilesY));
+  let lo = vec2<i32>(floor((centerRadius.xy - centerRadius.z) / f32(TILE_SIZE)));
+  let hi = vec2<i32>(floor((centerRadius.xy + centerRadius.z) / f32(TILE_SIZE))) + 1;
+  return vec4<i32>(clamp(lo, vec2<i32>(0), tiles), clamp(hi, vec2<i32>(0), tiles));
+}
+
+@compute @workgroup_size(256)
+fn tilePreprocess(@builtin(global_invocation_id) gid: vec3<u32>) {
+  let i = gid.x;
+  if i >= arrayLength(&tileCounts) { return; }
+  tileCounts[i] = 0u;
+  if i >= drawIndirect[1] { return; }
+
+  let splatIndex = sortedIndices[i];
+  let splat = animatedSplat(splatIndex);
+  let baseOpacity = splatOpacity(splatIndex, splat.opacity);
+  if baseOpacity < ALPHA_CULL_THRESHOLD { return; }
+
+  let modelViewMat = uniforms.view * uniforms.model;
+  let viewPos = modelViewMat * vec4<f32>(splat.mean, 1.0);
+  if viewPos.z >= 0.0 { return; }
+  let opacity = baseOpacity * cameraProximityFade(splat, viewPos, modelViewMat);
+  if opacity < ALPHA_CULL_THRESHOLD { return; }
+  let clipPos = uniforms.proj * viewPos;
+  let ndc = clipPos.xy / clipPos.w;
+
+  let focal = vec2<f32>(
+    abs(uniforms.proj[0][0]) * 0.5 * uniforms.screenSize.x,
+    abs(uniforms.proj[1][1]) * 0.5 * uniforms.screenSize.y
+  );
+  let cov3d = computeCovariance3D(splat.scale, splat.rotation);
+  var cov2d = projectCovariance(cov3d, viewPos, focal, modelViewMat);
+  cov2d.x += LOW_PASS_FILTER;
+  cov2d.z += LOW_PASS_FILTER;
+
+  let det = cov2d.x * cov2d.z - cov2d.y * cov2d.y;
+  if det <= 0.0 { return; }
+  let mid = 0.5 * (cov2d.x + cov2d.z);
+  let lambda1 = mid + sqrt(max(0.1, mid * mid - det));
+  let radius = ceil(3.0 * sqrt(lambda1));
+
+  // cov2d 在 y 向上的像素空间，翻转到 y 向下 (纹理坐标) 时非对角项取反
+  let center = vec2<f32>(
+    (ndc.x * 0.5 + 0.5) * uniforms.screenSize.x,
+    (0.5 - ndc.y * 0.5) * uniforms.screenSize.y
+  );
+  let centerRadius = vec4<f32>(center, radius, opacity);
+  let rect = tileRect(centerRadius);
+  let count = max(rect.z - rect.x, 0) * max(rect.w - rect.y, 0);
+  if count <= 0 { return; }
+
+  let invDet = 1.0 / det;
+  projected[i] = ProjectedSplat(
+    centerRadius,
+    vec4<f32>(cov2d.z * invDet, cov2d.y * invDet, cov2d.x * invDet, 0.0),
+    vec4<f32>(splatColor(splatIndex, shadedColor(splatIndex, viewPos, modelViewMat)), 0.0),
+  );
+  tileCounts[i] = u32(count);
+}
+
+// ============ 前缀和 ============
+
+var<workgroup> scanA: array<u32, 256>;
+var<workgroup> scanB: array<u32, 256>;
+var<workgroup> scanCarry: u32;
+
+// 256 个线程值的 inclusive Hillis-Steele scan，结果在 scanA 中
+fn workgroupInclusiveScan(tid: u32) {
+  var useA = true;
+  var offset = 1u;
+  for (var d = 0u; d < 8u; d++) {
+    if useA {
+      if tid >= offset { scanB[tid] = scanA[tid] + scanA[tid - offset]; } else { scanB[tid] = scanA[tid]; }
+    } else {
+      if tid >= offset { scanA[tid] = scanB[tid] + scanB[tid - offset]; } else { scanA[tid] = scanB[tid]; }
+    }
+    workgroupBarrier();
+    useA = !useA;
+    offset <<= 1u;
+  }
+}
+
+fn numScanBlocks() -> u32 {
+  return (drawIndirect[1] + SCAN_BLOCK - 1u) / SCAN_BLOCK;
+}
+
+@compute @workgroup_size(256)
+fn scanBlocks(@builtin(local_invocation_id) lid: vec3<u32>, @builtin(workgroup_id) wid: vec3<u32>) {
+  if wid.x >= numScanBlocks() { return; }
+  let tid = lid.x;
+  let base = wid.x * SCAN_BLOCK + tid * 4u;
+  let count = drawIndirect[1];
+  var sum = 0u;
+  for (var j = 0u; j < 4u; j++) {
+    if base + j < count { sum += tileCounts[base + j]; }
+  }
+  scanA[tid] = sum;
+  workgroupBarrier();
+  workgroupInclusiveScan(tid);
+  if tid == 255u {
+    blockSums[wid.x] = scanA[255];
+  }
+}
+
+@compute @workgroup_size(256)
+fn scanBlockSums(@builtin(local_invocation_id) lid: vec3<u32>) {
+  let tid = lid.x;
+  let numBlocks = numScanBlocks();
+  if tid == 0u { scanCarry = 0u; }
+  workgroupBarrier();
+
+  for (var batchStart = 0u; batchStart < numBlocks; batchStart += 256u) {
+    let idx = batchStart + tid;
+    var value = 0u;
+    if idx < numBlocks { value = blockSums[idx]; }
+    scanA[tid] = value;
+    workgroupBarrier();
+    workgroupInclusiveScan(tid);
+    let carry = scanCarry;
+    if idx < numBlocks {
+      blockSums[idx] = carry + scanA[tid] - value;
+    }
+    workgroupBarrier();
+    if tid == 0u { scanCarry = carry + scanA[255]; }
+    workgroupBarrier();
+  }
+
+  if tid == 0u {
+    pairInfo[0] = 0u;
+    pairInfo[1] = min(scanCarry, tileParams.pairCapacity);
+    pairInfo[2] = scanCarry;
+    pairInfo[3] = 0u;
+  }
+}
+
+@compute @workgroup_size(256)
+fn scanApply(@builtin(local_invocation_id) lid: vec3<u32>, @builtin(workgroup_id) wid: vec3<u32>) {
+  if wid.x >= numScanBlocks() { return; }
+  let tid = lid.x;
+  let base = wid.x * SCAN_BLOCK + tid * 4u;
+  let count = drawIndirect[1];
+  var values: array<u32, 4>;
+  var sum = 0u;
+  for (var j = 0u; j < 4u; j++) {
+    values[j] = 0u;
+    if base + j < count { values[j] = tileCounts[base + j]; }
+    sum += values[j];
+  }
+  scanA[tid] = sum;
+  workgroupBarrier();
+  workgroupInclusiveScan(tid);
+
+  var running = blockSums[wid.x] + scanA[tid] - sum;
+  for (var j = 0u; j < 4u; j++) {
+    if base + j < count { tileOffsets[base + j] = running; }
+    running += values[j];
+  }
+}
+
+// ============ 生成 (tile, splat) 对 ============
+
+@compute @workgroup_size(256)
+fn emitPairs(@builtin(global_invocation_id) gid: vec3<u32>) {
+  let i = gid.x;
+  if i >= drawIndirect[1] || tileCounts[i] == 0u { return; }
+
+  let rect = tileRect(projected[i].centerRadius);
+  var offset = tileOffsets[i];
+  for (var ty = rect.y; ty < rect.w; ty++) {
+    for (var tx = rect.x; tx < rect.z; tx++) {
+      if offset >= tileParams.pairCapacity { return; }
+      pairKeys[offset] = u32(ty) * tileParams.tilesX + u32(tx);
+      pairValues[offset] = i;
+      offset++;
+    }
+  }
+}
+
+@compute @workgroup_size(256)
+fn identifyRanges(@builtin(global_invocation_id) gid: vec3<u32>) {
+  let k = gid.x;
+  let count = pairInfo[1];
+  if k >= count { return; }
+  let tile = pairKeys[k];
+  if k == 0u || pairKeys[k - 1u] != tile {
+    tileRanges[tile].x = k;
+  }
+  if k == count - 1u || pairKeys[k + 1u] != tile {
+    tileRanges[tile].y = k + 1u;
+  }
+}
+
+// ============ 光栅化 ============
+
+var<workgroup> batchSplats: array<ProjectedSplat, 256>;
+var<workgroup> tileStart: u32;
+var<workgroup> tileEnd: u32;
+
+@compute @workgroup_size(16, 16)
+fn rasterizeTiles(
+  @builtin(workgroup_id) wid: vec3<u32>,
+  @builtin(local_invocation_id) lid: vec3<u32>,
+  @builtin(local_invocation_index) lidx: u32,
+) {
+  let tileId = wid.y * tileParams.tilesX + wid.x;
+  let pixel = wid.xy * TILE_SIZE + lid.xy;
+  let inside = f32(pixel.x) < uniforms.screenSize.x && f32(pixel.y) < uniforms.screenSize.y;
+  let pixelCenter = vec2<f32>(pixel) + 0.5;
+
+  if lidx == 0u {
+    let range = tileRanges[tileId];
+    tileStart = range.x;
+    tileEnd = max(range.x, range.y);
+  }
+  let start = workgroupUniformLoad(&tileStart);
+  let end = workgroupUniformLoad(&tileEnd);
+
+  var transmittance = 1.0;
+  var color = vec3<f32>(0.0);
+  var done = !inside;
+  var contributed = 0u;
+
+  // 排序结果为从远到近，逆序遍历实现从近到远合成，透射率足够低时提前结束
+  let total = end - start;
+  for (var batch = 0u; batch < total; batch += 256u) {
+    workgroupBarrier();
+    let loadIdx = batch + lidx;
+    if loadIdx < total {
+      batchSplats[lidx] = projected[pairValues[end - 1u - loadIdx]];
+    }
+    workgroupBarrier();
+
+    let batchCount = min(256u, total - batch);
+    for (var j = 0u; j < batchCount; j++) {
+      if done { break; }
+      let s = batchSplats[j];
+      let d = s.centerRadius.xy - pixelCenter;
+      let power = -0.5 * (s.conic.x * d.x * d.x + s.conic.z * d.y * d.y) - s.conic.y * d.x * d.y;
+      if power > 0.0 { continue; }
+      let alpha = min(0.99, s.centerRadius.w * exp(power));
+      if alpha < ALPHA_CULL_THRESHOLD { continue; }
+      color += max(s.color.rgb, vec3<f32>(0.0)) * alpha * transmittance;
+      transmittance *= 1.0 - alpha;
+      contributed++;
+      if transmittance < 0.0001 { done = true; }
+      // K-limit: 只合成最近的前 K 个 splat，限制进入密集区域时的最坏开销
+      if tileParams.maxSplatsPerPixel != 0u && contributed >= tileParams.maxSplatsPerPixel { done = true; }
+    }
+  }
+
+  if inside {
+    textureStore(outputTex, pixel, vec4<f32>(color, 1.0 - transmittance));
+  }
+}
+`;
+
+/**
+ * 合成 shader：全屏三角形读取 tile 光栅化结果 (预乘 alpha)
+ */
+const compositeShader = /* wgsl */ `
+@group(0) @binding(0) var resultTex: texture_2d<f32>;
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertexIndex: u32) -> @builtin(position) vec4<f32> {
+  let uv = vec2<f32>(f32((vertexIndex << 1u) & 2u), f32(vertexIndex & 2u));
+  return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+  let result = textureLoad(resultTex, vec2<i32>(position.xy), 0);
+  if result.a <= 0.0 { discard; }
+  return result;
+}
+`;
+
+/**
+ * GSSplatTileRasterizer - tile 分箱 compute 光栅化器
+ * 由 GSSplatRenderer 在 tile 光栅化模式下持有
+ */
+export class GSSplatTileRasterizer {
+  private device: GPUDevice;
+  private pool: SplatBufferPool;
+  private splatCount: number;
+  private pairCapacity: number;
+  private numPairPartitions: number;
+
+  // Compute pipelines
+  private tileBindGroupLayout: GPUBindGroupLayout;
+  private preprocessPipeline: GPUComputePipeline;
+  private scanBlocksPipeline: GPUComputePipeline;
+  private scanBlockSumsPipeline: GPUComputePipeline;
+  private scanApplyPipeline: GPUComputePipeline;
+  private emitPairsPipeline: GPUComputePipeline;
+  private identifyRangesPipeline: GPUComputePipeline;
+  private rasterizePipeline: GPUComputePipeline;
+
+  // Radix sort pipelines (复用 GSSplatSorter 的 shader)
+  private upsweepPipeline: GPUComputePipeline;
+  private spinePipeline: GPUComputePipeline;
+  private downsweepPipeline: GPUComputePipeline;
+  private upsweepBindGroups: GPUBindGroup[] = [];
+  private spineBindGroups: GPUBindGroup[] = [];
+  private downsweepBindGroups: GPUBindGroup[] = [];
+
+  // Composite pipeline
+  private compositePipeline: GPURenderPipeline;
+  private compositeBindGroupLayout: GPUBindGroupLayout;
+  private compositeBindGroup: GPUBindGroup | null = null;
+
+  // Buffers
+  private tileParamsBuffer: GPUBuffer;
+  private projectedBuffer: GPUBuffer;
+  private tileCountsBuffer: GPUBuffer;
+  private tileOffsetsBuffer: GPUBuffer;
+  private blockSumsBuffer: GPUBuffer;
+  private pairInfoBuffer: GPUBuffer;
+  private pairKeysBuffer: GPUBuffer;
+  private pairValuesBuffer: GPUBuffer;
+  private pairKeysTempBuffer: GPUBuffer;
+  private pairValuesTempBuffer: GPUBuffer;
+  private globalHistogramBuffer: GPUBuffer;
+  private partitionHistogramBuffer: GPUBuffer;
+  private sortParamsBuffers: GPUBuffer[] = [];
+
+  // 随屏幕尺寸变化的资源
+  private tileRangesBuffer: GPUBuffer | null = null;
+  private outputTexture: GPUTexture | null = null;
+  private tileBindGroup: GPUBindGroup | null = null;
+  private drawIndirectBuffer: GPUBuffer | null = null;
+  private width: number = 0;
+  private height: number = 0;
+  private tilesX: number = 0;
+  private tilesY: number = 0;
+  private sortPasses: number = 2;
+  private maxSplatsPerPixel: number = 0;
+
+  constructor(
+    device: GPUDevice,
+    format: GPUTextureFormat,
+    depthFormat: GPUTextureFormat,
+    splatShaderCode: string,
+    splatBindGroupLayout: GPUBindGroupLayout,
+    splatCount: number,
+    options: TileRasterizerOptions = {},
+  ) {
+    this.device = device;
+    const pipelines = PipelineCache.get(device);
+    this.pool = SplatBufferPool.get(device);
+    this.splatCount = splatCount;
+    this.pairCapacity = Math.max(
+      options.maxTilePairs ?? Math.min(splatCount * 4, 1 << 23),
+      RADIX_BLOCK_SIZE,
+    );
+    this.numPairPartitions = Math.ceil(this.pairCapacity / RADIX_BLOCK_SIZE);
+    this.maxSplatsPerPixel = Math.max(0, Math.floor(options.maxSplatsPerPixel ?? 0));
+
+    // ============================================
+    // Buffers
+    // ============================================
+    const storage = GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST;
+    const count = Math.max(splatCount, 1);
+
+    this.tileParamsBuffer = device.createBuffer({
+      size: 16,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+      label: "tile-params",
+    });
+    // 大 buffer 从共享池申请；tileCounts 的清零 pass 依赖 arrayLength，保持精确尺寸单独创建
+    this.projectedBuffer = this.pool.acquire(count * 48, storage, "tile-projected");
+    this.tileCountsBuffer = device.createBuffer({ size: count * 4, usage: storage, label: "tile-counts" });
+    this.tileOffsetsBuffer = this.pool.acquire(count * 4, storage, "tile-offsets");
+    this.blockSumsBuffer = device.createBuffer({
+      size: Math.ceil(count / SCAN_BLOCK_SIZE) * 4,
+      usage: storage,
+      label: "tile-block-sums",
+    });
+    this.pairInfoBuffer = device.createBuffer({ size: 16, usage: storage, label: "tile-pair-info" });
+    this.pairKeysBuffer = this.pool.acquire(this.pairCapacity * 4, storage, "tile-pair-keys");
+    this.pairValuesBuffer = this.pool.acquire(this.pairCapacity * 4, storage, "tile-pair-values");
+    this.pairKeysTempBuffer = this.pool.acquire(this.pairCapacity * 4, storage, "tile-pair-keys-temp");
+    this.pairValuesTempBuffer = this.pool.acquire(this.pairCapacity * 4, storage, "tile-pair-values-temp");
+    this.globalHistogramBuffer = device.createBuffer({
+      size: RADIX_SIZE * 4 * 4,
+      usage: storage,
+      label: "tile-global-histogram",
+    });
+    this.partitionHistogramBuffer = this.pool.acquire(this.numPairPartitions * RADIX_SIZE * 4, storage, "tile-partition-histogram");
+
+    for (let i = 0; i < 4; i++) {
+      const paramsBuffer = device.createBuffer({
+        size: 16,
+        usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+        label: `tile-sort-params-${i}`,
+      });
+      device.queue.writeBuffer(paramsBuffer, 0, new Uint32Array([this.pairCapacity, i * RADIX_BITS, i, 0]));
+      this.sortParamsBuffers.push(paramsBuffer);
+    }
+
+    // ============================================
+    // Tile compute pipelines
+    // ============================================
+    const tileModule = pipelines.getShaderModule({
+      code: splatShaderCode + tileComputeShader,
+      label: "tile-raster-shader",
+    });
+
+    this.tileBindGroupLayout = pipelines.getBindGroupLayout({
+      label: "tile-raster-layout",
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.COMPUTE, buffer: { type: "uniform" } },
+        { binding: 1, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 2, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+        { binding: 3, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+        { binding: 4, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+        { binding: 5, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+        { binding: 6, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+        { binding: 7, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+        { binding: 8, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+        { binding: 9, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+        {
+          binding: 10,
+          visibility: GPUShaderStage.COMPUTE,
+          storageTexture: { access: "write-only", format: "rgba16float" },
+        },
+      ],
+    });
+
+    const tileLayout = pipelines.getPipelineLayout([splatBindGroupLayout, this.tileBindGroupLayout]);
+    const createTilePipeline = (entryPoint: string): GPUComputePipeline => pipelines.getComputePipeline({
+      layout: tileLayout,
+      compute: { module: tileModule, entryPoint },
+      label: `tile-${entryPoint}`,
+    });
+
+    this.preprocessPipeline = createTilePipeline("tilePreprocess");
+    this.scanBlocksPipeline = createTilePipeline("scanBlocks");
+    this.scanBlockSumsPipeline = createTilePipeline("scanBlockSums");
+    this.scanApplyPipeline = createTilePipeline("scanApply");
+    this.emitPairsPipeline = createTilePipeline("emitPairs");
+    this.identifyRangesPipeline = createTilePipeline("identifyRanges");
+    this.rasterizePipeline = createTilePipeline("rasterizeTiles");
+
+    // ============================================
+    // Radix sort (按 tileId 稳定排序)
+    // ============================================
+    const subgroups = supportsSubgroupSort(device);
+    const radixSortModule = pipelines.getShaderModule({
+      code: generateRadixSortShaderCode(subgroups),
+      label: "tile-radix-sort-shader",
+    });
+
+    const upsweepLayout = pipelines.getBindGroupLayout({
+      label: "tile-upsweep-layout",
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.COMPUTE, buffer: { type: "uniform" } },
+        { binding: 1, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 2, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 3, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+        { binding: 4, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+      ],
+    });
+    const spineLayout = pipelines.getBindGroupLayout({
+      label: "tile-spine-layout",
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 1, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+        { binding: 2, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+        { binding: 3, visibility: GPUShaderStage.COMPUTE, buffer: { type: "uniform" } },
+      ],
+    });
+    const downsweepLayout = pipelines.getBindGroupLayout({
+      label: "tile-downsweep-layout",
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.COMPUTE, buffer: { type: "uniform" } },
+        { binding: 1, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 2, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 3, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 4, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 5, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 6, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+        { binding: 7, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+      ],
+    });
+
+    this.upsweepPipeline = pipelines.getComputePipeline({
+      layout: pipelines.getPipelineLayout([upsweepLayout]),
+      compute: { module: radixSortModule, entryPoint: "upsweep" },
+      label: "tile-upsweep-pipeline",
+    });
+    this.spinePipeline = pipelines.getComputePipeline({
+      layout: pipelines.getPipelineLayout([spineLayout]),
+      compute: { module: radixSortModule, entryPoint: "spine" },
+      label: "tile-spine-pipeline",
+    });
+    this.downsweepPipeline = pipelines.getComputePipeline({
+      layout: pipelines.getPipelineLayout([downsweepLayout]),
+      compute: { module: radixSortModule, entryPoint: getDownsweepEntryPoint(subgroups) },
+      label: "tile-downsweep-pipeline",
+    });
+
+    // Ping-pong: 偶数 pass 写入临时 buffer，奇数 pass 写回，偶数个 pass 后结果在 pairKeys/pairValues
+    for (let passIdx = 0; passIdx < 4; passIdx++) {
+      const isEvenPass = passIdx % 2 === 0;
+      const keysIn = isEvenPass ? this.pairKeysBuffer : this.pairKeysTempBuffer;
+      const valuesIn = isEvenPass ? this.pairValuesBuffer : this.pairValuesTempBuffer;
+      const keysOut = isEvenPass ? this.pairKeysTempBuffer : this.pairKeysBuffer;
+      const valuesOut = isEvenPass ? this.pairValuesTempBuffer : this.pairValuesBuffer;
+
+      this.upsweepBindGroups[passIdx] = device.createBindGroup({
+        layout: upsweepLayout,
+        entries: [
+          { binding: 0, resource: { buffer: this.sortParamsBuffers[passIdx] } },
+          { binding: 1, resource: { buffer: this.pairInfoBuffer } },
+          { binding: 2, resource: { buffer: keysIn } },
+          { binding: 3, resource: { buffer: this.globalHistogramBuffer } },
+          { binding: 4, resource: { buffer: this.partitionHistogramBuffer } },
+        ],
+      });
+      this.spineBindGroups[passIdx] = device.createBindGroup({
+        layout: spineLayout,
+        entries: [
+          { binding: 0, resource: { buffer: this.pairInfoBuffer } },
+          { binding: 1, resource: { buffer: this.globalHistogramBuffer } },
+          { binding: 2, resource: { buffer: this.partitionHistogramBuffer } },
+          { binding: 3, resource: { buffer: this.sortParamsBuffers[passIdx] } },
+        ],
+      });
+      this.downsweepBindGroups[passIdx] = device.createBindGroup({
+        layout: downsweepLayout,
+        entries: [
+          { binding: 0, resource: { buffer: this.sortParamsBuffers[passIdx] } },
+          { binding: 1, resource: { buffer: this.pairInfoBuffer } },
+          { binding: 2, resource: { buffer: this.globalHistogramBuffer } },
+          { binding: 3, resource: { buffer: this.partitionHistogramBuffer } },
+          { binding: 4, resource: { buffer: keysIn } },
+          { binding: 5, resource: { buffer: valuesIn } },
+          { binding: 6, resource: { buffer: keysOut } },
+          { binding: 7, resource: { buffer: valuesOut } },
+        ],
+      });
+    }
+
+    // ============================================
+    // Composite pipeline
+    // ============================================
+    const compositeModule = pipelines.getShaderModule({
+      code: compositeShader,
+      label: "tile-composite-shader",
+    });
+
+    this.compositeBindGroupLayout = pipelines.getBindGroupLayout({
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.FRAGMENT, texture: { sampleType: "unfilterable-float" } },
+      ],
+    });
+
+    this.compositePipeline = pipelines.getRenderPipeline({
+      layout: pipelines.getPipelineLayout([this.compositeBindGroupLayout]),
+      vertex: { module: compositeModule, entryPoint: "vs_main", buffers: [] },
+      fragment: {
+        module: compositeModule,
+        entryPoint: "fs_main",
+        targets: [{
+          format,
+          blend: {
+            color: { srcFactor: "one", dstFactor: "one-minus-src-alpha", operation: "add" },
+            alpha: { srcFactor: "one", dstFactor: "one-minus-src-alpha", operation: "add" },
+          },
+        }],
+      },
+      primitive: { topology: "triangle-list" },
+      depthStencil: {
+        format: depthFormat,
+        depthWriteEnabled: false,
+        depthCompare: "always",
+      },
+    });
+  }
+
+  /**
+   * 设置每个像素最多合成的 splat 数量，0 表示不限制
+   */
+  setMaxSplatsPerPixel(count: number): void {
+    this.maxSplatsPerPixel = Math.max(0, Math.floor(count));
+    this.device.queue.writeBuffer(this.tileParamsBuffer, 12, new Uint32Array([this.maxSplatsPerPixel]));
+  }
+
+  /**
+   * 获取每个像素最多合成的 splat 数量
+   */
+  getMaxSplatsPerPixel(): number {
+    return this.maxSplatsPerPixel;
+  }
+
+  /**
+   * 根据屏幕尺寸 (重新) 创建 tile 区间 buffer 和输出纹理
+   */
+  private ensureScreenResources(width: number, height: number, drawIndirectBuffer: GPUBuffer): void {
+    if (
+      width === this.width &&
+      height === this.height &&
+      drawIndirectBuffer === this.drawIndirectBuffer &&
+      this.tileBindGroup
+    ) {
+      return;
+    }
+
+    this.width = width;
+    this.height = height;
+    this.drawIndirectBuffer = drawIndirectBuffer;
+    this.tilesX = Math.ceil(width / TILE_SIZE);
+    this.tilesY = Math.ceil(height / TILE_SIZE);
+    const numTiles = this.tilesX * this.tilesY;
+    // tileId 不超过 16 位时只需要 2 个 8-bit pass
+    this.sortPasses = numTiles > 0xffff ? 4 : 2;
+
+    this.tileRangesBuffer?.destroy();
+    this.outputTexture?.destroy();
+
+    this.tileRangesBuffer = this.device.createBuffer({
+      size: Math.max(numTiles, 1) * 8,
+      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      label: "tile-ranges",
+    });
+
+    this.outputTexture = this.device.createTexture({
+      size: { width, height },
+      format: "rgba16float",
+      usage: GPUTextureUsage.STORAGE_BINDING | GPUTextureUsage.TEXTURE_BINDING,
+      label: "tile-raster-output",
+    });
+
+    this.device.queue.writeBuffer(
+      this.tileParamsBuffer, 0,
+      new Uint32Array([this.tilesX, this.tilesY, this.pairCapacity, this.maxSplatsPerPixel]),
+    );
+
+    this.tileBindGroup = this.device.createBindGroup({
+      layout: this.tileBindGroupLayout,
+      entries: [
+        { binding: 0, resource: { buffer: this.tileParamsBuffer } },
+        { binding: 1, resource: { buffer: drawIndirectBuffer } },
+        { binding: 2, resource: { buffer: this.projectedBuffer } },
+        { binding: 3, resource: { buffer: this.tileCountsBuffer } },
+        { binding: 4, resource: { buffer: this.tileOffsetsBuffer } },
+        { binding: 5, resource: { buffer: this.blockSumsBuffer } },
+        { binding: 6, resource: { buffer: this.pairInfoBuffer } },
+        { binding: 7, resource: { buffer: this.pairKeysBuffer } },
+        { binding: 8, resource: { buffer: this.pairValuesBuffer } },
+        { binding: 9, resource: { buffer: this.tileRangesBuffer } },
+        { binding: 10, resource: this.outputTexture.createView() },
+      ],
+    });
+
+    this.compositeBindGroup = this.device.createBindGroup({
+      layout: this.compositeBindGroupLayout,
+      entries: [{ binding: 0, resource: this.outputTexture.createView() }],
+    });
+  }
+
+  /**
+   * 执行 tile 光栅化 (在 sorter.sort() 之后、渲染通道提交之前调用)
+   * @param splatBindGroup 渲染器的 bind group (uniforms / splats / sortedIndices / ...)
+   * @param drawIndirectBuffer 排序器的 indirect buffer，[1] 为可见数量
+   */
+  rasterize(splatBindGroup: GPUBindGroup, drawIndirectBuffer: GPUBuffer, width: number, height: number): void {
+    if (width <= 0 || height <= 0) return;
+    this.ensureScreenResources(width, height, drawIndirectBuffer);
+
+    const encoder = this.device.createCommandEncoder({ label: "tile-raster-encoder" });
+    encoder.clearBuffer(this.tileRangesBuffer!);
+    encoder.clearBuffer(this.globalHistogramBuffer);
+    encoder.clearBuffer(this.partitionHistogramBuffer);
+
+    const splatGroups = Math.ceil(Math.max(this.splatCount, 1) / WORKGROUP_SIZE);
+    const scanGroups = Math.ceil(Math.max(this.splatCount, 1) / SCAN_BLOCK_SIZE);
+    const pairGroups = Math.ceil(this.pairCapacity / WORKGROUP_SIZE);
+
+    const runTile = (pipeline: GPUComputePipeline, x: number, y: number = 1, label: string = ""): void => {
+      const pass = encoder.beginComputePass({ label });
+      pass.setPipeline(pipeline);
+      pass.setBindGroup(0, splatBindGroup);
+      pass.setBindGroup(1, this.tileBindGroup!);
+      pass.dispatchWorkgroups(x, y);
+      pass.end();
+    };
+
+    runTile(this.preprocessPipeline, splatGroups, 1, "tile-preprocess");
+    runTile(this.scanBlocksPipeline, scanGroups, 1, "tile-scan-blocks");
+    runTile(this.scanBlockSumsPipeline, 1, 1, "tile-scan-block-sums");
+    runTile(this.scanApplyPipeline, scanGroups, 1, "tile-scan-apply");
+    runTile(this.emitPairsPipeline, splatGroups, 1, "tile-emit-pairs");
+
+    for (let passIdx = 0; passIdx < this.sortPasses; passIdx++) {
+      {
+        const pass = encoder.beginComputePass({ label: `tile-upsweep-p${passIdx}` });
+        pass.setPipeline(this.upsweepPipeline);
+        pass.setBindGroup(0, this.upsweepBindGroups[passIdx]);
+        pass.dispatchWorkgroups(this.numPairPartitions);
+        pass.end();
+      }
+      {
+        const pass = encoder.beginComputePass({ label: `tile-spine-p${passIdx}` });
+        pass.setPipeline(this.spinePipeline);
+        pass.setBindGroup(0, this.spineBindGroups[passIdx]);
+        pass.dispatchWorkgroups(RADIX_SIZE);
+        pass.end();
+      }
+      {
+        const pass = encoder.beginComputePass({ label: `tile-downsweep-p${passIdx}` });
+        pass.setPipeline(this.downsweepPipeline);
+        pass.setBindGroup(0, this.downsweepBindGroups[passIdx]);
+        pass.dispatchWorkgroups(this.numPairPartitions);
+        pass.end();
+      }
+    }
+
+    runTile(this.identifyRangesPipeline, pairGroups, 1, "tile-identify-ranges");
+    runTile(this.rasterizePipeline, this.tilesX, this.tilesY, "tile-rasterize");
+
+    this.device.queue.submit([encoder.finish()]);
+  }
+
+  /**
+   * 把光栅化结果合成到当前渲染通道
+   */
+  composite(pass: GPURenderPassEncoder): void {
+    if (!this.compositeBindGroup) return;
+    pass.setPipeline(this.compositePipeline);
+    pass.setBindGroup(0, this.compositeBindGroup);
+    pass.draw(3);
+  }
+
+  /**
+   * 获取 tile 光栅化器占用的显存（字节）
+   */
+  getGPUMemoryUsage(): number {
+    const output = this.outputTexture;
+    const outputBytes = output ? output.width * output.height * 8 : 0; // rgba16float
+    return outputBytes + getBuffersByteSize([
+      this.tileParamsBuffer,
+      this.projectedBuffer,
+      this.tileCountsBuffer,
+      this.tileOffsetsBuffer,
+      this.blockSumsBuffer,
+      this.pairInfoBuffer,
+      this.pairKeysBuffer,
+      this.pairValuesBuffer,
+      this.pairKeysTempBuffer,
+      this.pairValuesTempBuffer,
+      this.globalHistogramBuffer,
+      this.partitionHistogramBuffer,
+      ...this.sortParamsBuffers,
+      this.tileRangesBuffer,
+    ]);
+  }
+
+  /**
+   * 销毁资源
+   */
+  destroy(): void {
+    this.tileParamsBuffer.destroy();
+    this.pool.release(this.projectedBuffer);
+    this.tileCountsBuffer.destroy();
+    this.pool.release(this.tileOffsetsBuffer);
+    this.blockSumsBuffer.destroy();
+    this.pairInfoBuffer.destroy();
+    this.pool.release(this.pairKeysBuffer);
+    this.pool.release(this.pairValuesBuffer);
+    this.pool.release(this.pairKeysTempBuffer);
+    this.pool.release(this.pairValuesTempBuffer);
+    this.globalHistogramBuffer.destroy();
+    this.pool.release(this.partitionHistogramBuffer);
+    for (const buffer of this.sortParamsBuffers) {
+      buffer.destroy();
+    }
+    this.tileRangesBuffer?.destroy();
+    this.outputTexture?.destroy();
+    this.tileRangesBuffer = null;
+    this.outputTexture = null;
+    this.tileBindGroup = null;
+    this.compositeBindGroup = null;
+  }
+}
@@ -0,0 +1,611 @@
+/**
+ * GaussianSplats - CPU 端 splat 数据的逐 splat 访问接口
+ *
+ * CompactSplatData 按属性分成多个并列数组，下游工具逐个 splat 处理时需要自己换算偏移，
+ * 修改后还要记得重新上传。这里提供：
+ * - get(i) / 迭代：返回 SplatRef 值对象（拷贝）
+ * - getMut(i) / iterMut()：返回可写视图，写入直接落到底层数组并记录脏区间
+ * - chunksMut() / forEachMut()：按块批量处理（重新着色、过滤等），脏标记按块记录，
+ *   渲染器只上传被修改过的块，相距很远的两处修改不会把中间整段一起上传
+ * - applyUpdate() / replaceData()：训练器整批更新时只复制（或直接接管）新数据，只上传 SplatDirtyRanges 中的区间
+ * - share()：共享底层数组的浅拷贝（写时复制），交给其他模块时不复制数据
+ * - removeDeleted()：物理移除已删除的 splat 并重新映射选择集
+ * 渲染器每帧只把脏块重新打包上传；位置变化时同时重建包围盒和八叉树
+ */
+
+import type { CompactSplatData } from "./PLYLoaderMobile";
+import type { SplatCPU } from "./PLYLoader";
+import type { SplatDirtyRanges } from "./SplatDirtyRanges";
+import type { Vec3Tuple, Vec4Tuple } from "../types";
+import { SplatSelection } from "./SplatSelection";
+import { gatherSplatData } from "./SplatSegments";
+
+/** 每个 splat 的 SH 系数数量 (L1 9 + L2 15 + L3 21) */
+const SH_COEFF_COUNT = 45;
+/** GPU 端每个 splat 的 float 数量 (256 字节) */
+const GPU_SPLAT_FLOATS = 64;
+/** 脏标记的块大小（splat 数量） */
+export const SPLAT_DIRTY_CHUNK_SIZE = 4096;
+
+/**
+ * removeDeleted 的结果
+ */
+export interface SplatRemoval {
+  /** 剩余的 splat */
+  splats: GaussianSplats;
+  /** 剩余 splat 的原索引（新索引 -> 原索引） */
+  keep: Uint32Array;
+  /** 重新映射后的选择集（未传入选择集时为 null） */
+  selection: SplatSelection | null;
+}
+
+/**
+ * 单个 splat 的只读快照
+ */
+export interface SplatRef {
+  index: number;
+  mean: Vec3Tuple;
+  /** 四元数 [w, x, y, z] */
+  rotation: Vec4Tuple;
+  scale: Vec3Tuple;
+  opacity: number;
+  /** DC 颜色 */
+  color: Vec3Tuple;
+  /** 45 个 SH 系数的拷贝，数据不含 SH 时为 null */
+  sh: Float32Array | null;
+}
+
+/**
+ * 单个 splat 的可写视图（setter 写入底层数组并标记脏区间）
+ */
+export class SplatMut {
+  private splats: GaussianSplats;
+  private data: CompactSplatData;
+  index: number;
+
+  constructor(splats: GaussianSplats, data: CompactSplatData, index: number) {
+    this.splats = splats;
+    this.data = data;
+    this.index = index;
+  }
+
+  get mean(): Vec3Tuple {
+    const p = this.data.positions;
+    const i = this.index * 3;
+    return [p[i], p[i + 1], p[i + 2]];
+  }
+
+  set mean(value: Vec3Tuple) {
+    this.data.positions.set(value, this.index * 3);
+    this.splats.markDirty(this.index, true);
+  }
+
+  get rotation(): Vec4Tuple {
+    const r = this.data.rotations;
+    const i = this.index * 4;
+    return [r[i], r[i + 1], r[i + 2], r[i + 3]];
+  }
+
+  set rotation(value: Vec4Tuple) {
+    this.data.rotations.set(value, this.index * 4);
+    this.splats.markDirty(this.index);
+  }
+
+  get scale(): Vec3Tuple {
+    const s = this.data.scales;
+    const i = this.index * 3;
+    return [s[i], s[i + 1], s[i + 2]];
+  }
+
+  set scale(value: Vec3Tuple) {
+    this.data.scales.set(value, this.index * 3);
+    this.splats.markDirty(this.index);
+  }
+
+  get opacity(): number {
+    return this.data.opacities[this.index];
+  }
+
+  set opacity(value: number) {
+    this.data.opacities[this.index] = value;
+    this.splats.markDirty(this.index);
+  }
+
+  get color(): Vec3Tuple {
+    const c = this.data.colors;
+    const i = this.index * 3;
+    return [c[i], c[i + 1], c[i + 2]];
+  }
+
+  set color(value: Vec3Tuple) {
+    this.data.colors.set(value, this.index * 3);
+    this.splats.markDirty(this.index);
+  }
+
+  /**
+   * SH 系数的可写子数组（数据不含 SH 时为 null）
+   * 直接写入子数组不会自动标记，写完调用 touch()
+   */
+  get sh(): Float32Array | null {
+    const sh = this.data.shCoeffs;
+    return sh ? sh.subarray(this.index * SH_COEFF_COUNT, (this.index + 1) * SH_COEFF_COUNT) : null;
+  }
+
+  /**
+   * 手动标记当前 splat 已修改
+   */
+  touch(): void {
+    this.splats.markDirty(this.index);
+  }
+}
+
+/**
+ * 一段连续 splat 的批量视图：[start, end) 区间内各属性的子数组
+ * 直接写入子数组后调用 markDirty()，或通过 forEach 的 SplatMut 视图写入自动标记
+ */
+export class SplatChunk {
+  private splats: GaussianSplats;
+  private data: CompactSplatData;
+  readonly start: number;
+  readonly end: number;
+
+  constructor(splats: GaussianSplats, data: CompactSplatData, start: number, end: number) {
+    this.splats = splats;
+    this.data = data;
+    this.start = start;
+    this.end = end;
+  }
+
+  get count(): number {
+    return this.end - this.start;
+  }
+
+  get positions(): Float32Array {
+    return this.data.positions.subarray(this.start * 3, this.end * 3);
+  }
+
+  get scales(): Float32Array {
+    return this.data.scales.subarray(this.start * 3, this.end * 3);
+  }
+
+  get rotations(): Float32Array {
+    return this.data.rotations.subarray(this.start * 4, this.end * 4);
+  }
+
+  get colors(): Float32Array {
+    return this.data.colors.subarray(this.start * 3, this.end * 3);
+  }
+
+  get opacities(): Float32Array {
+    return this.data.opacities.subarray(this.start, this.end);
+  }
+
+  get sh(): Float32Array | null {
+    const sh = this.data.shCoeffs;
+    return sh ? sh.subarray(this.start * SH_COEFF_COUNT, this.end * SH_COEFF_COUNT) : null;
+  }
+
+  /**
+   * 标记整块已修改
+   */
+  markDirty(positionChanged: boolean = false): void {
+    this.splats.markRangeDirty(this.start, this.end, positionChanged);
+  }
+
+  /**
+   * 逐 splat 遍历块内可写视图（复用同一个视图对象）
+   */
+  forEach(fn: (splat: SplatMut) => void): void {
+    const view = new SplatMut(this.splats, this.data, this.start);
+    for (let i = this.start; i < this.end; i++) {
+      view.index = i;
+      fn(view);
+    }
+  }
+}
+
+/**
+ * splat 数据访问器
+ */
+export class GaussianSplats {
+  private data: CompactSplatData;
+  private dirtyStart: number = Infinity;
+  private dirtyEnd: number = 0;
+  private positionsDirty: boolean = false;
+  private version: number = 0;
+  // 每块一个脏标记
+  private dirtyChunks: Uint8Array;
+  // 共享同一组底层数组的访问器数量（写时复制）
+  private shared: { refs: number } = { refs: 1 };
+
+  constructor(data: CompactSplatData) {
+    this.data = data;
+    this.dirtyChunks = new Uint8Array(Math.ceil(data.count / SPLAT_DIRTY_CHUNK_SIZE));
+  }
+
+  /**
+   * 从 SplatCPU 数组构建（复制为并列数组）
+   */
+  static fromSplatCPU(splats: SplatCPU[]): GaussianSplats {
+    const count = splats.length;
+    const data: CompactSplatData = {
+      count,
+      positions: new Float32Array(count * 3),
+      scales: new Float32Array(count * 3),
+      rotations: new Float32Array(count * 4),
+      colors: new Float32Array(count * 3),
+      opacities: new Float32Array(count),
+    };
+    const hasSH = splats.some((s) => s.shRest !== undefined);
+    const hasEmissive = splats.some((s) => s.emissive !== undefined);
+    if (hasSH) data.shCoeffs = new Float32Array(count * SH_COEFF_COUNT);
+    if (hasEmissive) data.emissive = new Float32Array(count);
+    for (let i = 0; i < count; i++) {
+      const s = splats[i];
+      data.positions.set(s.mean, i * 3);
+      data.scales.set(s.scale, i * 3);
+      data.rotations.set(s.rotation, i * 4);
+      data.colors.set(s.colorDC, i * 3);
+      data.opacities[i] = s.opacity;
+      if (data.shCoeffs && s.shRest) data.shCoeffs.set(s.shRest.subarray(0, SH_COEFF_COUNT), i * SH_COEFF_COUNT);
+      if (data.emissive) data.emissive[i] = s.emissive ?? 0;
+    }
+    return new GaussianSplats(data);
+  }
+
+  /**
+   * splat 数量
+   */
+  get count(): number {
+    return this.data.count;
+  }
+
+  /**
+   * 底层并列数组（只读；直接修改请使用 getDataMut）
+   */
+  getData(): CompactSplatData {
+    return this.data;
+  }
+
+  /**
+   * 获取可直接写入的底层数组（共享时先复制；修改后需要调用 markDirty）
+   */
+  getDataMut(): CompactSplatData {
+    this.ensureUnique();
+    return this.data;
+  }
+
+  /**
+   * 获取第 i 个 splat 的快照
+   */
+  get(index: number): SplatRef {
+    if (index < 0 || index >= this.data.count) {
+      throw new RangeError(`splat index ${index} out of range [0, ${this.data.count})`);
+    }
+    const d = this.data;
+    const i3 = index * 3;
+    const i4 = index * 4;
+    return {
+      index,
+      mean: [d.positions[i3], d.positions[i3 + 1], d.positions[i3 + 2]],
+      rotation: [d.rotations[i4], d.rotations[i4 + 1], d.rotations[i4 + 2], d.rotations[i4 + 3]],
+      scale: [d.scales[i3], d.scales[i3 + 1], d.scales[i3 + 2]],
+      opacity: d.opacities[index],
+      color: [d.colors[i3], d.colors[i3 + 1], d.colors[i3 + 2]],
+      sh: d.shCoeffs ? d.shCoeffs.slice(index * SH_COEFF_COUNT, (index + 1) * SH_COEFF_COUNT) : null,
+    };
+  }
+
+  /**
+   * 获取第 i 个 splat 的可写视图
+   */
+  getMut(index: number): SplatMut {
+    if (index < 0 || index >= this.data.count) {
+      throw new RangeError(`splat index ${index} out of range [0, ${this.data.count})`);
+    }
+    this.ensureUnique();
+    return new SplatMut(this, this.data, index);
+  }
+
+  /**
+   * 按索引顺序遍历快照
+   */
+  *iter(): IterableIterator<SplatRef> {
+    for (let i = 0; i < this.data.count; i++) {
+      yield this.get(i);
+    }
+  }
+
+  [Symbol.iterator](): IterableIterator<SplatRef> {
+    return this.iter();
+  }
+
+  /**
+   * 按索引顺序遍历可写视图（复用同一个视图对象，不要在循环外保留）
+   */
+  *iterMut(): IterableIterator<SplatMut> {
+    this.ensureUnique();
+    const view = new SplatMut(this, this.data, 0);
+    for (let i = 0; i < this.data.count; i++) {
+      view.index = i;
+      yield view;
+    }
+  }
+
+  /**
+   * 按块遍历（默认块大小与脏标记一致，块内修改只会标记所在块）
+   */
+  *chunksMut(chunkSize: number = SPLAT_DIRTY_CHUNK_SIZE): IterableIterator<SplatChunk> {
+    const size = Math.max(1, Math.floor(chunkSize));
+    this.ensureUnique();
+    for (let start = 0; start < this.data.count; start += size) {
+      yield new SplatChunk(this, this.data, start, Math.min(this.data.count, start + size));
+    }
+  }
+
+  /**
+   * 按块批量处理全部 splat，每处理完 budgetMs 毫秒让出一次主线程，避免大数据量时卡住页面
+   * fn 通过 SplatMut 写入时自动按块记录脏标记
+   */
+  async forEachMut(
+    fn: (splat: SplatMut) => void,
+    options: { chunkSize?: number; budgetMs?: number } = {},
+  ): Promise<void> {
+    const budgetMs = options.budgetMs ?? 8;
+    let sliceStart = performance.now();
+    for (const chunk of this.chunksMut(options.chunkSize)) {
+      chunk.forEach(fn);
+      if (performance.now() - sliceStart > budgetMs) {
+        await new Promise((resolve) => setTimeout(resolve, 0));
+        sliceStart = performance.now();
+      }
+    }
+  }
+
+  /**
+   * 从 source 复制 dirty 中的区间并标记脏块（source 与当前数据同一布局；
+   * source 就是底层数据时只标记不复制）
+   */
+  applyUpdate(source: CompactSplatData, dirty: SplatDirtyRanges): void {
+    if (source !== this.data) {
+      this.ensureUnique();
+    }
+    const d = this.data;
+    const positionChanged = dirty.hasPositionChanges();
+    for (const [rangeStart, rangeEnd] of dirty.getRanges()) {
+      const start = Math.max(0, rangeStart);
+      const end = Math.min(d.count, source.count, rangeEnd);
+      if (end <= start) continue;
+      if (source !== this.data) {
+        copyRange(source.positions, d.positions, start, end, 3);
+        copyRange(source.scales, d.scales, start, end, 3);
+        copyRange(source.rotations, d.rotations, start, end, 4);
+        copyRange(source.colors, d.colors, start, end, 3);
+        copyRange(source.opacities, d.opacities, start, end, 1);
+        if (source.shCoeffs && d.shCoeffs) copyRange(source.shCoeffs, d.shCoeffs, start, end, SH_COEFF_COUNT);
+        if (source.emissive && d.emissive) copyRange(source.emissive, d.emissive, start, end, 1);
+      }
+      this.markRangeDirty(start, end, positionChanged);
+    }
+  }
+
+  /**
+   * 直接接管 source 的数组（不复制），只把 dirty 中的区间标记为脏块
+   * source 必须与当前数据数量和布局一致，dirty 之外的区间应与当前数据相同；之后调用方不应再修改 source
+   */
+  replaceData(source: CompactSplatData, dirty: SplatDirtyRanges): void {
+    if (source !== this.data) {
+      this.release();
+      this.data = source;
+    }
+    const positionChanged = dirty.hasPositionChanges();
+    for (const [start, end] of dirty.getRanges()) {
+      this.markRangeDirty(start, end, positionChanged);
+    }
+  }
+
+  /**
+   * 创建共享底层数组的访问器（不复制数据）
+   * 任一方通过 getMut / iterMut / chunksMut / applyUpdate 写入前先复制自己的数组，另一方不受影响；
+   * 共享期间不要直接修改 getData() 返回的数组
+   */
+  share(): GaussianSplats {
+    const copy = new GaussianSplats(this.data);
+    this.shared.refs++;
+    copy.shared = this.shared;
+    return copy;
+  }
+
+  /**
+   * 底层数组是否与其他访问器共享
+   */
+  isShared(): boolean {
+    return this.shared.refs > 1;
+  }
+
+  /**
+   * 写入前确保独占底层数组（共享时复制）
+   */
+  private ensureUnique(): void {
+    if (this.shared.refs > 1) {
+      this.release();
+      this.data = cloneCompactSplatData(this.data);
+    }
+  }
+
+  /**
+   * 退出共享
+   */
+  private release(): void {
+    this.shared.refs--;
+    this.shared = { refs: 1 };
+  }
+
+  /**
+   * 标记 splat 已修改
+   * @param positionChanged 位置变化时渲染器还会重建包围盒和八叉树
+   */
+  markDirty(index: number, positionChanged: boolean = false): void {
+    this.markRangeDirty(index, index + 1, positionChanged);
+  }
+
+  /**
+   * 标记区间 [start, end) 已修改
+   */
+  markRangeDirty(start: number, end: number, positionChanged: boolean = false): void {
+    this.dirtyStart = Math.min(this.dirtyStart, Math.max(0, start));
+    this.dirtyEnd = Math.max(this.dirtyEnd, Math.min(this.data.count, end));
+    this.positionsDirty ||= positionChanged;
+    this.version++;
+    const firstChunk = Math.floor(Math.max(0, start) / SPLAT_DIRTY_CHUNK_SIZE);
+    const lastChunk = Math.ceil(Math.min(this.data.count, end) / SPLAT_DIRTY_CHUNK_SIZE);
+    this.dirtyChunks.fill(1, firstChunk, lastChunk);
+  }
+
+  /**
+   * 获取脏区间 [start, end)，没有修改时返回 null
+   */
+  getDirtyRange(): [number, number] | null {
+    return this.dirtyStart < this.dirtyEnd ? [this.dirtyStart, this.dirtyEnd] : null;
+  }
+
+  /**
+   * 获取按块合并后的脏区间列表 [start, end)（相邻的脏块合并为一个区间）
+   */
+  getDirtyRanges(): [number, number][] {
+    const ranges: [number, number][] = [];
+    const chunks = this.dirtyChunks;
+    for (let c = 0; c < chunks.length; c++) {
+      if (!chunks[c]) continue;
+      const start = c * SPLAT_DIRTY_CHUNK_SIZE;
+      while (c + 1 < chunks.length && chunks[c + 1]) c++;
+      ranges.push([start, Math.min(this.data.count, (c + 1) * SPLAT_DIRTY_CHUNK_SIZE)]);
+    }
+    return ranges;
+  }
+
+  /**
+   * 上次同步后是否修改过位置
+   */
+  isPositionsDirty(): boolean {
+    return this.positionsDirty;
+  }
+
+  /**
+   * 修改计数（每次 markDirty 递增）
+   */
+  getVersion(): number {
+    return this.version;
+  }
+
+  /**
+   * 清除 [start, end) 所在块的脏标记（分帧上传时逐段调用，区间需按块对齐或到达末尾）
+   */
+  markRangeClean(start: number, end: number): void {
+    const chunks = this.dirtyChunks;
+    chunks.fill(0, Math.floor(start / SPLAT_DIRTY_CHUNK_SIZE), Math.ceil(end / SPLAT_DIRTY_CHUNK_SIZE));
+    const first = chunks.indexOf(1);
+    if (first < 0) {
+      this.dirtyStart = Infinity;
+      this.dirtyEnd = 0;
+      return;
+    }
+    this.dirtyStart = Math.max(this.dirtyStart, first * SPLAT_DIRTY_CHUNK_SIZE);
+    this.dirtyEnd = Math.min(this.dirtyEnd, (chunks.lastIndexOf(1) + 1) * SPLAT_DIRTY_CHUNK_SIZE);
+  }
+
+  /**
+   * 清除位置修改标记（渲染器重建包围盒和八叉树后调用）
+   */
+  markPositionsClean(): void {
+    this.positionsDirty = false;
+  }
+
+  /**
+   * 清除脏标记（渲染器上传后调用）
+   */
+  markClean(): void {
+    this.dirtyStart = Infinity;
+    this.dirtyEnd = 0;
+    this.positionsDirty = false;
+    this.dirtyChunks.fill(0);
+  }
+
+  /**
+   * 物理移除标记的 splat（deleted[i] 非 0），按原顺序收集剩余的 splat 到新的 GaussianSplats。
+   * 原对象不变（撤销快照可能仍引用它），选择集按新索引重新映射
+   */
+  removeDeleted(deleted: Uint8Array, selection: SplatSelection | null = null): SplatRemoval {
+    let kept = 0;
+    for (let i = 0; i < this.data.count; i++) {
+      if (!deleted[i]) kept++;
+    }
+    const keep = new Uint32Array(kept);
+    for (let i = 0, n = 0; i < this.data.count; i++) {
+      if (!deleted[i]) keep[n++] = i;
+    }
+    let remapped: SplatSelection | null = null;
+    if (selection) {
+      const mask = selection.getMask();
+      remapped = new SplatSelection(kept);
+      remapped.selectWhere((i) => mask[keep[i]] !== 0);
+    }
+    return { splats: new GaussianSplats(gatherSplatData(this.data, keep)), keep, selection: remapped };
+  }
+
+  /**
+   * 打包 [start, end) 为 GPU 布局（每个 splat 64 float，与 compactDataToGPUBuffer 的完整 SH 格式一致）
+   */
+  toGPUData(start: number, end: number): Float32Array {
+    const d = this.data;
+    const out = new Float32Array((end - start) * GPU_SPLAT_FLOATS);
+    for (let i = start; i < end; i++) {
+      const o = (i - start) * GPU_SPLAT_FLOATS;
+      out[o + 0] = d.positions[i * 3];
+      out[o + 1] = d.positions[i * 3 + 1];
+      out[o + 2] = d.positions[i * 3 + 2];
+      out[o + 3] = d.emissive ? d.emissive[i] : 0;
+      out[o + 4] = d.scales[i * 3];
+      out[o + 5] = d.scales[i * 3 + 1];
+      out[o + 6] = d.scales[i * 3 + 2];
+      out[o + 8] = d.rotations[i * 4];
+      out[o + 9] = d.rotations[i * 4 + 1];
+      out[o + 10] = d.rotations[i * 4 + 2];
+      out[o + 11] = d.rotations[i * 4 + 3];
+      out[o + 12] = d.colors[i * 3];
+      out[o + 13] = d.colors[i * 3 + 1];
+      out[o + 14] = d.colors[i * 3 + 2];
+      out[o + 15] = d.opacities[i];
+      if (d.shCoeffs) {
+        // sh1 / sh2 / sh3 在 GPU 布局中连续存放于 16..60
+        out.set(d.shCoeffs.subarray(i * SH_COEFF_COUNT, (i + 1) * SH_COEFF_COUNT), o + 16);
+      }
+    }
+    return out;
+  }
+}
+
+/**
+ * 复制 [start, end) 个 splat 的属性（每个 splat stride 个元素）
+ */
+function copyRange(source: Float32Array, target: Float32Array, start: number, end: number, stride: number): void {
+  target.set(source.subarray(start * stride, end * stride), start * stride);
+}
+
+/**
+ * 复制 CompactSplatData 的全部数组
+ */
+export function cloneCompactSplatData(data: CompactSplatData): CompactSplatData {
+  return {
+    ...data,
+    positions: data.positions.slice(),
+    scales: data.scales.slice(),
+    rotations: data.rotations.slice(),
+    colors: data.colors.slice(),
+    opacities: data.opacities.slice(),
+    shCoeffs: data.shCoeffs?.slice(),
+    labels: data.labels?.slice(),
+    emissive: data.emissive?.slice(),
+    origin: data.origin ? [...data.origin] : undefined,
+  };
+}
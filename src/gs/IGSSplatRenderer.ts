@@ -6,12 +6,34 @@
 
 import type { CompactSplatData } from "./PLYLoaderMobile";
 import type { SplatCPU } from "./PLYLoader";
-import type { BoundingBox, Vec3Tuple } from "../types";
-import { SHMode, RendererCapabilities } from "../types";
+import type { SplatLabels } from "./SplatLabels";
+import type { SplatSelection } from "./SplatSelection";
+import type { GaussianSplats } from "./GaussianSplats";
+import type { TransformIssue } from "../utils/transform";
+import type { TransferFunctionStop } from "./TransferFunction";
+import type { SplatLodConfig } from "./SplatLod";
+import type { SplatPrecision, SplatStatistics } from "./SplatStatistics";
+import type { SplatMemoryDowngrade } from "./SplatMemoryBudget";
+import type { BoundingBox, Vec3Tuple, SplatPickResult, SplatDepthQueryMode, SplatDepthResult } from "../types";
+import { SHMode, SplatVisMode, Colormap, SplatRasterMode, SplatBlendMode, SplatPointMode, SplatDepthKeyMode, RendererCapabilities } from "../types";
+import type { SplatPointSizeConfig } from "./SplatPoints";
+import type { SplatCentersConfig } from "./SplatCentersFilter";
+import type { SplatSortCacheStats } from "./SplatSortCache";
+import type { SplatDirtyRanges } from "./SplatDirtyRanges";
+import type { SplatPVSConfig } from "./SplatPVS";
+import type { SplatSwayConfig } from "./SplatSway";
+import type { SplatColorAdjustment } from "./SplatColorAdjust";
+import type { SplatAppearanceData } from "./SplatAppearance";
+import type { SplatOpacityEdit } from "./SplatOpacityEdit";
+import type { SplatCropBox } from "./SplatCropBox";
+import type { SplatAttributePredicate } from "./SplatAttributeFilter";
+import type { SplatGroups } from "./SplatGroups";
+import type { SplatExternalBuffer, SplatExternalBuffers } from "./SplatExternalBuffers";
+import type { SplatIdDepth } from "./SplatRenderLayers";
 
 // 重新导出类型，保持向后兼容
 export type { BoundingBox, Vec3Tuple, RendererCapabilities };
-export { SHMode };
+export { SHMode, SplatVisMode, Colormap, SplatRasterMode, SplatBlendMode, SplatPointMode, SplatDepthKeyMode };
 
 /**
  * 3D Gaussian Splatting 渲染器接口
@@ -32,6 +54,16 @@ export interface IGSSplatRenderer {
    */
   setData?(splats: SplatCPU[]): void;
 
+  /**
+   * 整批更新 splat 数据，只上传 dirty 中的区间（数量或布局变化时等同 setCompactData）
+   */
+  updateCompactData?(data: CompactSplatData, dirty?: SplatDirtyRanges): void;
+
+  /**
+   * 获取 CPU 端 splat 数据访问器（逐 splat 读取 / 修改，修改后自动重新上传）
+   */
+  getSplats?(): GaussianSplats | null;
+
   // ============================================
   // 渲染
   // ============================================
@@ -90,6 +122,350 @@ export interface IGSSplatRenderer {
    */
   getModelMatrix(): Float32Array;
 
+  /**
+   * 设置父级变换（列主序，如所在图层的世界矩阵），模型矩阵 = parent * local，null 表示没有父级
+   */
+  setParentMatrix?(matrix: Float32Array | null): void;
+
+  /**
+   * 获取父级变换
+   */
+  getParentMatrix?(): Float32Array | null;
+
+  /**
+   * 设置是否自动修正有问题的模型矩阵（非有限值、父级变换带来的剪切；零缩放只警告）
+   */
+  setTransformAutoCorrect?(enabled: boolean): void;
+
+  /**
+   * 获取当前模型矩阵检测到的问题
+   */
+  getTransformIssues?(): TransformIssue[];
+
+  /**
+   * 设置是否使用相机相对渲染（在 CPU 端减去相机位置，避免远离原点时的抖动）
+   */
+  setCameraRelative?(enabled: boolean): void;
+
+  /**
+   * 是否使用相机相对渲染
+   */
+  isCameraRelative?(): boolean;
+
+  /**
+   * 设置曝光倍数（HDR 输出时用于控制 splat 亮度）
+   */
+  setExposure?(exposure: number): void;
+
+  /**
+   * 获取曝光倍数
+   */
+  getExposure?(): number;
+
+  /**
+   * 设置整体不透明度倍数 (0~1)
+   */
+  setOpacity?(opacity: number): void;
+
+  /**
+   * 获取整体不透明度倍数
+   */
+  getOpacity?(): number;
+
+  /**
+   * 设置混合模式（标准 / 加法 / 最大值）
+   */
+  setBlendMode?(mode: SplatBlendMode): void;
+
+  /**
+   * 获取混合模式
+   */
+  getBlendMode?(): SplatBlendMode;
+
+  /**
+   * 设置是否启用八叉树分块剔除
+   */
+  setChunkedCulling?(enabled: boolean): void;
+
+  /**
+   * 是否启用八叉树分块剔除
+   */
+  isChunkedCulling?(): boolean;
+
+  /**
+   * 设置可见数量回读间隔（排序次数，0 关闭），用于诊断统计
+   */
+  setVisibleCountReadback?(interval: number): void;
+
+  /**
+   * 最近一次回读的可见 splat 数量（未开启回读或尚未读回时为 -1）
+   */
+  getVisibleCount?(): number;
+
+  /**
+   * 按八叉树分块预计算可见性（室内场景跳过相机所在格子看不到的 chunk），需要启用分块剔除
+   */
+  buildPVS?(config?: Partial<SplatPVSConfig>): Promise<unknown>;
+
+  /**
+   * 关闭分块可见性预计算
+   */
+  clearPVS?(): void;
+
+  /**
+   * 设置是否启用 Hi-Z 遮挡剔除
+   */
+  setOcclusionCulling?(enabled: boolean): void;
+
+  /**
+   * 是否启用 Hi-Z 遮挡剔除
+   */
+  isOcclusionCulling?(): boolean;
+
+  /**
+   * 设置移动时的分摊排序（每 interval 帧一次全精度排序，0 关闭）
+   */
+  setSortAmortization?(interval: number): void;
+
+  /**
+   * 获取分摊排序的全精度间隔
+   */
+  getSortAmortization?(): number;
+
+  /**
+   * 设置剔除阈值（屏幕投影小于该像素数的 splat 被剔除）
+   */
+  setPixelCullThreshold?(threshold: number): void;
+
+  /**
+   * 设置 splat 屏幕轴长上限（像素），0 不限制
+   */
+  setMaxSplatSize?(size: number): void;
+
+  /**
+   * 获取 splat 屏幕轴长上限
+   */
+  getMaxSplatSize?(): number;
+
+  /**
+   * 设置摆动动画（风吹植被）
+   */
+  setSway?(config: Partial<SplatSwayConfig>): void;
+
+  /**
+   * 获取摆动动画配置
+   */
+  getSway?(): SplatSwayConfig;
+
+  /**
+   * 设置选中 splat 的颜色调整预览（只在 shader 中生效），null 关闭
+   */
+  setColorAdjustPreview?(adjustment: SplatColorAdjustment | null): void;
+
+  /**
+   * 获取颜色调整预览
+   */
+  getColorAdjustPreview?(): SplatColorAdjustment | null;
+
+  /**
+   * 设置选中 splat 的不透明度编辑预览（只在 shader 中生效），null 关闭
+   */
+  setOpacityEditPreview?(edit: SplatOpacityEdit | null): void;
+
+  /**
+   * 获取不透明度编辑预览
+   */
+  getOpacityEditPreview?(): SplatOpacityEdit | null;
+
+  /**
+   * 设置软裁剪盒（只在 shader 中生效，保存引用），null 关闭
+   */
+  setCropBox?(cropBox: SplatCropBox | null): void;
+
+  /**
+   * 获取软裁剪盒
+   */
+  getCropBox?(): SplatCropBox | null;
+
+  /**
+   * 设置 splat 分组（隐藏 / 锁定 / solo / 着色），null 清除
+   */
+  setGroups?(groups: SplatGroups | null): void;
+
+  /**
+   * 获取 splat 分组
+   */
+  getGroups?(): SplatGroups | null;
+
+  /**
+   * 使用外部 GPU buffer 中的 splat 数据（同一 device），跳过 CPU 打包和上传
+   */
+  setExternalBuffers?(buffers: SplatExternalBuffers): void;
+
+  /**
+   * 外部 buffer 内容更新后重新打包，当前数据不是来自外部 buffer 时返回 false
+   */
+  updateExternalBuffers?(): boolean;
+
+  /**
+   * 设置悬停高亮的 splat（group 为 true 时高亮整个分组），null 清除
+   */
+  setHoveredSplat?(index: number | null, group?: boolean, strength?: number): void;
+
+  /**
+   * 获取悬停高亮的 splat
+   */
+  getHoveredSplat?(): number | null;
+
+  /**
+   * 设置第二套外观（同一几何、splat 一一对应的另一套颜色和 SH），null 清除
+   */
+  setSecondaryAppearance?(data: SplatAppearanceData | null): void;
+
+  /**
+   * 是否设置了第二套外观
+   */
+  hasSecondaryAppearance?(): boolean;
+
+  /**
+   * 设置第二套外观的混合权重 (0~1)
+   */
+  setAppearanceBlend?(t: number): void;
+
+  /**
+   * 获取第二套外观的混合权重
+   */
+  getAppearanceBlend?(): number;
+
+  /**
+   * 设置是否复用排序结果（相机、变换和数据都没有变化时跳过本实体的剔除和排序）
+   */
+  setSortReuse?(enabled: boolean): void;
+
+  /**
+   * 是否复用排序结果
+   */
+  isSortReuse?(): boolean;
+
+  /**
+   * 获取排序复用统计
+   */
+  getSortReuseStats?(): SplatSortCacheStats;
+
+  /**
+   * 设置深度排序键模式
+   */
+  setDepthKeyMode?(mode: SplatDepthKeyMode): void;
+
+  /**
+   * 获取深度排序键模式
+   */
+  getDepthKeyMode?(): SplatDepthKeyMode;
+
+  /**
+   * 设置是否启用 pass 顺序校验（调试用）
+   */
+  setPassValidation?(enabled: boolean): void;
+
+  /**
+   * 是否启用 pass 顺序校验
+   */
+  isPassValidation?(): boolean;
+
+  /**
+   * 获取上一帧的 pass 依赖报告
+   */
+  getPassReport?(): string;
+
+  /**
+   * 设置相机位于 splat 内部时是否淡出该 splat
+   */
+  setCameraInsideFade?(enabled: boolean): void;
+
+  /**
+   * 相机位于 splat 内部时是否淡出该 splat
+   */
+  isCameraInsideFade?(): boolean;
+
+  /**
+   * 设置是否剔除中心在近平面之后且包含相机的 splat
+   */
+  setCameraInsideCull?(enabled: boolean): void;
+
+  /**
+   * 是否剔除中心在近平面之后且包含相机的 splat
+   */
+  isCameraInsideCull?(): boolean;
+
+  /**
+   * 设置近平面淡出距离
+   */
+  setNearFadeDistance?(distance: number): void;
+
+  /**
+   * 获取近平面淡出距离
+   */
+  getNearFadeDistance?(): number;
+
+  /**
+   * 设置按距离降低 SH 阶数的阈值 [去掉 L3, 去掉 L2, 去掉 L1]，null 关闭
+   */
+  setSHDistances?(distances: Vec3Tuple | null): void;
+
+  /**
+   * 获取按距离降低 SH 阶数的阈值
+   */
+  getSHDistances?(): Vec3Tuple;
+
+  /**
+   * 设置 LOD 配置，null 关闭
+   */
+  setLodConfig?(config: Partial<SplatLodConfig> | null): void;
+
+  /**
+   * 获取 LOD 配置
+   */
+  getLodConfig?(): SplatLodConfig | null;
+
+  // ============================================
+  // 拾取
+  // ============================================
+
+  /**
+   * 拾取画布像素 (x, y) 下最近的 splat，未命中返回 null
+   */
+  pick?(x: number, y: number): Promise<SplatPickResult | null>;
+
+  /**
+   * 查询画布像素 (x, y) 处的混合 / 最近 splat 深度和世界坐标，未命中返回 null
+   */
+  depthAt?(x: number, y: number, mode?: SplatDepthQueryMode): Promise<SplatDepthResult | null>;
+
+  /**
+   * 拾取画布像素圆形区域内可见的最前面的 splat（被遮挡的不命中），返回去重后的索引
+   */
+  pickRegion?(x: number, y: number, radius: number): Promise<Uint32Array>;
+
+  /**
+   * 回读整帧每个像素最近的 splat id 和视图空间距离（用于分层导出）
+   */
+  readIdDepth?(): Promise<SplatIdDepth | null>;
+
+  /**
+   * 查询中心投影落在画布像素圆形区域内的 splat（包括被遮挡的）
+   */
+  querySplatsInCircle?(x: number, y: number, radius: number): Uint32Array;
+
+  /**
+   * 查询中心投影落在画布像素多边形内的 splat（包括被遮挡的）
+   */
+  queryPolygon?(points: ReadonlyArray<readonly [number, number]>): Promise<Uint32Array>;
+
+  /**
+   * 查询满足全部属性谓词的 splat（GPU 筛选）
+   */
+  queryAttributes?(predicates: ReadonlyArray<SplatAttributePredicate>): Promise<Uint32Array>;
+
   // ============================================
   // 查询
   // ============================================
@@ -104,6 +480,16 @@ export interface IGSSplatRenderer {
    */
   getBoundingBox(): BoundingBox | null;
 
+  /**
+   * 获取位置相对的原点（f64 精度加载时为数据质心）
+   */
+  getOrigin?(): Vec3Tuple;
+
+  /**
+   * 计算位置统计（包含原点）
+   */
+  getStatistics?(precision?: SplatPrecision): SplatStatistics | null;
+
   // ============================================
   // SH 模式（可选，移动端可能不支持）
   // ============================================
@@ -124,6 +510,228 @@ export interface IGSSplatRenderer {
    */
   supportsSHMode?(mode: SHMode): boolean;
 
+  /**
+   * 当前数据是否为 2DGS (surfel)（可选）
+   */
+  isSurfelData?(): boolean;
+
+  // ============================================
+  // 语义标签 / 可视化 / 选择（可选，移动端可能不支持）
+  // ============================================
+
+  /**
+   * 设置逐 splat 语义标签，传入 null 清除
+   */
+  setLabels?(labels: SplatLabels | null): void;
+
+  /**
+   * 获取逐 splat 语义标签
+   */
+  getLabels?(): SplatLabels | null;
+
+  /**
+   * 设置可视化模式（内置模式或已注册的自定义模式，可传名称）
+   */
+  setVisMode?(mode: SplatVisMode | string): void;
+
+  /**
+   * 获取当前可视化模式
+   */
+  getVisMode?(): SplatVisMode;
+
+  /**
+   * 获取选择集（无数据时返回 null）
+   */
+  getSelection?(): SplatSelection | null;
+
+  /**
+   * 删除 splat（不透明度置零，达到压缩阈值时自动压缩）
+   */
+  deleteSplats?(indices: ArrayLike<number>): number;
+
+  /**
+   * 删除当前选中的 splat
+   */
+  deleteSelected?(): number;
+
+  /**
+   * 恢复已删除（尚未压缩）的 splat，opacities 为删除前的不透明度
+   */
+  restoreSplats?(indices: ArrayLike<number>, opacities: ArrayLike<number>): number;
+
+  /**
+   * 获取删除标记（1 表示已删除，没有删除过时返回 null）
+   */
+  getDeletedMask?(): Uint8Array | null;
+
+  /**
+   * 获取已删除但尚未压缩的 splat 数量
+   */
+  getDeletedCount?(): number;
+
+  /**
+   * 在 GPU 上压缩掉已删除的 splat，返回移除的数量
+   */
+  compactDeleted?(): number;
+
+  /**
+   * 设置自动压缩阈值（已删除比例），0 关闭自动压缩
+   */
+  setCompactionThreshold?(fraction: number): void;
+
+  /**
+   * 获取自动压缩阈值
+   */
+  getCompactionThreshold?(): number;
+
+  /**
+   * 设置逐 splat 标量属性（置信度、误差、时间戳等），传入 null 清除
+   * @param range 映射到 colormap 的 [min, max]，不传则使用数据范围
+   */
+  setScalarAttribute?(values: Float32Array | null, range?: [number, number]): void;
+
+  /**
+   * 获取逐 splat 标量属性
+   */
+  getScalarAttribute?(): Float32Array | null;
+
+  /**
+   * 使用外部 GPU buffer 作为逐 splat 标量属性（训练器写入的梯度 / 致密化统计），传入 null 清除
+   */
+  setExternalScalarBuffer?(source: SplatExternalBuffer | null, range?: [number, number]): void;
+
+  /**
+   * 按当前标量数据调整映射范围，clip 为两端各忽略的比例
+   */
+  fitScalarRange?(clip?: number): Promise<[number, number] | null>;
+
+  /**
+   * 设置标量属性的映射范围
+   */
+  setScalarRange?(min: number, max: number): void;
+
+  /**
+   * 获取标量属性的映射范围
+   */
+  getScalarRange?(): [number, number];
+
+  /**
+   * 设置标量属性使用的 colormap
+   */
+  setColormap?(colormap: Colormap): void;
+
+  /**
+   * 获取标量属性使用的 colormap
+   */
+  getColormap?(): Colormap;
+
+  /**
+   * 设置体渲染模式的传递函数（控制点或 RGBA8 查找表），null 恢复默认
+   */
+  setTransferFunction?(transferFunction: TransferFunctionStop[] | Uint8Array | null): void;
+
+  /**
+   * 设置体渲染模式的不透明度倍数
+   */
+  setVolumeOpacity?(scale: number): void;
+
+  /**
+   * 获取体渲染模式的不透明度倍数
+   */
+  getVolumeOpacity?(): number;
+
+  // ============================================
+  // 光栅化模式（可选）
+  // ============================================
+
+  /**
+   * 设置光栅化模式（quad 或 compute tile 分箱）
+   */
+  setRasterMode?(mode: SplatRasterMode): void;
+
+  /**
+   * 获取光栅化模式
+   */
+  getRasterMode?(): SplatRasterMode;
+
+  /**
+   * 设置每个像素最多合成的 splat 数量（tile 模式下生效），0 表示不限制
+   */
+  setMaxSplatsPerPixel?(count: number): void;
+
+  /**
+   * 获取每个像素最多合成的 splat 数量
+   */
+  getMaxSplatsPerPixel?(): number;
+
+  /**
+   * 设置深度输出的不透明度阈值（供景深等后处理使用），0 关闭
+   */
+  setDepthWrite?(alphaThreshold: number): void;
+
+  /**
+   * 获取深度输出的不透明度阈值
+   */
+  getDepthWrite?(): number;
+
+  // ============================================
+  // 点显示（可选）
+  // ============================================
+
+  /**
+   * 设置点显示模式（圆点 / 中心点叠加）
+   */
+  setPointMode?(mode: SplatPointMode): void;
+
+  /**
+   * 获取点显示模式
+   */
+  getPointMode?(): SplatPointMode;
+
+  /**
+   * 设置点尺寸（最小 / 最大直径、是否按局部密度自适应）
+   */
+  setPointSizeConfig?(config: Partial<SplatPointSizeConfig>): void;
+
+  /**
+   * 获取点尺寸配置
+   */
+  getPointSizeConfig?(): SplatPointSizeConfig;
+
+  /**
+   * 设置中心点叠加的子采样（步长 / 光标半径 / 选择过滤）
+   */
+  setCentersConfig?(config: Partial<SplatCentersConfig>): void;
+
+  /**
+   * 获取中心点叠加配置
+   */
+  getCentersConfig?(): SplatCentersConfig;
+
+  /**
+   * 设置光标位置（画布像素坐标），null 表示光标离开画布
+   */
+  setCentersCursor?(cursor: [number, number] | null): void;
+
+  // ============================================
+  // 显存预算（可选）
+  // ============================================
+
+  /**
+   * 获取当前占用的显存（字节）
+   */
+  getGPUMemoryUsage?(): number;
+
+  /**
+   * 执行一次显存降级，返回释放的字节数
+   */
+  downgradeMemory?(step: SplatMemoryDowngrade): number;
+
+  /**
+   * 在 availableBytes 以内恢复之前逐出的数据，返回新分配的字节数
+   */
+  restoreMemory?(availableBytes: number): number;
+
   // ============================================
   // 生命周期
   // ============================================
@@ -134,6 +742,13 @@ export interface IGSSplatRenderer {
   destroy(): void;
 }
 
+/**
+ * 多实体拾取结果：命中的实体（渲染器）和其中的 splat 索引
+ */
+export interface SplatEntityPickResult extends SplatPickResult {
+  entity: IGSSplatRenderer;
+}
+
 /**
  * 获取渲染器能力（可选方法）
  */
@@ -4,6 +4,8 @@
  * 支持多种数据类型: float, double, int, uint, char, uchar, short, ushort
  */
 
+import { SplatImportTransform, sampleByImportance, type SplatImportOptions } from "./SplatImport";
+
 /**
  * CPU 端 Splat 数据结构
  */
@@ -14,8 +16,14 @@ export type SplatCPU = {
   colorDC: [number, number, number];
   opacity: number;
   shRest?: Float32Array; // 完整 SH 系数: L1(9) + L2(15) + L3(21) = 45
+  emissive?: number; // 自发光强度，颜色按 (1 + emissive) 放大
 };
 
+/**
+ * PLY 中可能表示自发光强度的属性名（按优先级）
+ */
+export const EMISSIVE_PROPERTY_NAMES = ["emissive", "emission", "emissive_intensity"];
+
 /**
  * PLY 数据类型到字节大小的映射
  */
@@ -250,9 +258,10 @@ function sigmoid(x: number): number {
 /**
  * 加载并解析 PLY 文件
  * @param url PLY 文件的 URL
+ * @param options 加载选项（缩放、上轴、降采样等）
  * @returns SplatCPU 数组
  */
-export async function loadPLY(url: string): Promise<SplatCPU[]> {
+export async function loadPLY(url: string, options: SplatImportOptions = {}): Promise<SplatCPU[]> {
   // 获取文件
   const response = await fetch(url);
   if (!response.ok) {
@@ -290,6 +299,7 @@ export async function loadPLY(url: string): Promise<SplatCPU[]> {
     f_dc_1: propMap.get("f_dc_1"),
     f_dc_2: propMap.get("f_dc_2"),
     opacity: propMap.get("opacity"),
+    emissive: EMISSIVE_PROPERTY_NAMES.map((name) => propMap.get(name)).find((prop) => prop !== undefined),
   };
 
   // 收集 f_rest_* 字段并按索引排序（用于 SH 系数）
@@ -309,15 +319,33 @@ export async function loadPLY(url: string): Promise<SplatCPU[]> {
   // 创建 DataView 用于读取二进制数据
   const dataView = new DataView(buffer, dataOffset);
 
+  const transform = new SplatImportTransform(options);
+
+  // 超过 maxSplats 时按重要性 (opacity * 最大缩放) 降采样，以文件大小为种子保证确定性
+  let sampleIndices: Uint32Array | null = null;
+  if (vertexCount > transform.maxSplats) {
+    const importance = new Float32Array(vertexCount);
+    for (let i = 0; i < vertexCount; i++) {
+      const base = i * stride;
+      importance[i] = sigmoid(readProperty(dataView, base, props.opacity, littleEndian)) * Math.exp(Math.max(
+        readProperty(dataView, base, props.scale_0, littleEndian),
+        readProperty(dataView, base, props.scale_1, littleEndian),
+        readProperty(dataView, base, props.scale_2, littleEndian),
+      ));
+    }
+    sampleIndices = sampleByImportance(importance, transform.maxSplats, buffer.byteLength);
+  }
+  const loadCount = sampleIndices ? sampleIndices.length : vertexCount;
+
   // 预分配共享的 SH 系数数组（优化内存）
-  const shRestBuffer = new Float32Array(vertexCount * 45);
+  const shRestBuffer = new Float32Array(loadCount * 45);
 
   // 解析每个 vertex
-  const splats: SplatCPU[] = new Array(vertexCount);
+  const splats: SplatCPU[] = [];
   const SH_C0 = 0.28209479177387814;
 
-  for (let i = 0; i < vertexCount; i++) {
-    const base = i * stride;
+  for (let i = 0; i < loadCount; i++) {
+    const base = (sampleIndices ? sampleIndices[i] : i) * stride;
 
     // 读取位置
     const x = readProperty(dataView, base, props.x, littleEndian);
@@ -374,8 +402,9 @@ export async function loadPLY(url: string): Promise<SplatCPU[]> {
 
     const totalRestCount = shRestProps.length;
     const perChannel = Math.floor(totalRestCount / 3); // 每通道的系数数量
+    const coefLimit = Math.min(perChannel, transform.shCoeffCount);
     
-    for (let coefIdx = 0; coefIdx < perChannel; coefIdx++) {
+    for (let coefIdx = 0; coefIdx < coefLimit; coefIdx++) {
       // PLY 中: R 在 [0..perChannel-1], G 在 [perChannel..2*perChannel-1], B 在 [2*perChannel..3*perChannel-1]
       const srcR = coefIdx;
       const srcG = perChannel + coefIdx;
@@ -394,7 +423,7 @@ export async function loadPLY(url: string): Promise<SplatCPU[]> {
       // 调试信息（静默）
     }
 
-    splats[i] = {
+    const splat: SplatCPU = {
       mean: [x, y, z],
       scale: [scale_0, scale_1, scale_2],
       rotation: [
@@ -406,7 +435,16 @@ export async function loadPLY(url: string): Promise<SplatCPU[]> {
       colorDC: [colorR, colorG, colorB],
       opacity,
       shRest,
+      emissive: props.emissive ? Math.max(0, readProperty(dataView, base, props.emissive, littleEndian)) : undefined,
     };
+
+    if (transform.transformsGeometry) {
+      transform.apply(splat.mean, 0, splat.scale, 0, splat.rotation, 0, shRest, 0);
+    }
+    if (transform.sanitize && !transform.isValid(splat.mean, 0, splat.scale, 0, splat.rotation, 0, splat.opacity)) {
+      continue;
+    }
+    splats.push(splat);
   }
 
   return splats;
@@ -10,6 +10,11 @@
  * 6. 确定性采样（基于文件内容的种子）
  */
 
+import { LABEL_PROPERTY_NAMES } from "./SplatLabels";
+import { EMISSIVE_PROPERTY_NAMES } from "./PLYLoader";
+import { SplatImportTransform, sampleByImportance, type SplatImportOptions } from "./SplatImport";
+import { rebasePositions } from "./SplatStatistics";
+
 /**
  * PLY 数据类型到字节大小的映射
  */
@@ -40,9 +45,7 @@ type PLYFormat = "binary_little_endian" | "binary_big_endian" | "ascii";
 /**
  * 移动端加载配置
  */
-export interface MobileLoadOptions {
-  /** 最大 splat 数量，超过则降采样 */
-  maxSplats?: number;
+export interface MobileLoadOptions extends SplatImportOptions {
   /** 是否加载 SH 系数（false 时只加载 DC 颜色） */
   loadSH?: boolean;
   /** 进度回调 */
@@ -70,6 +73,14 @@ export interface CompactSplatData {
   opacities: Float32Array;
   /** SH 系数（可选）Float32Array，每个 splat 45 个系数 */
   shCoeffs?: Float32Array;
+  /** 语义标签（可选）Uint32Array，每个 splat 一个类别 id */
+  labels?: Uint32Array;
+  /** 是否为 2DGS (surfel) 数据：只有 scale_0/scale_1，scale z 为 0 */
+  is2DGS?: boolean;
+  /** 自发光强度（可选）Float32Array，颜色按 (1 + emissive) 放大，HDR 输出下可超过 1.0 */
+  emissive?: Float32Array;
+  /** 位置相对的原点（f64，可选）：原始坐标 = positions + origin */
+  origin?: [number, number, number];
 }
 
 /**
@@ -249,21 +260,6 @@ function readProperty(
   }
 }
 
-/**
- * 简单的确定性伪随机数生成器 (Mulberry32)
- * 给定相同的种子，总是产生相同的序列
- */
-function createSeededRandom(seed: number): () => number {
-  let state = seed >>> 0;
-  return () => {
-    state = (state + 0x6d2b79f5) >>> 0;
-    let t = state;
-    t = Math.imul(t ^ (t >>> 15), t | 1);
-    t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
-    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
-  };
-}
-
 const SH_C0 = 0.28209479177387814;
 
 /**
@@ -289,11 +285,9 @@ function computeImportanceSampling(
   seed: number
 ): Uint32Array {
   const dataView = new DataView(buffer, dataOffset);
-  const random = createSeededRandom(seed);
 
   // 计算每个 splat 的重要性分数
   const importance = new Float32Array(totalCount);
-  let totalImportance = 0;
 
   for (let i = 0; i < totalCount; i++) {
     const base = i * stride;
@@ -318,57 +312,10 @@ function computeImportanceSampling(
 
     // 重要性分数：opacity * scale
     importance[i] = opacity * maxScale;
-    totalImportance += importance[i];
-  }
-
-  // 使用加权随机采样（Reservoir Sampling with Weights）
-  // 这是一种 O(n) 的算法，比完整排序更高效
-  const result = new Uint32Array(sampleCount);
-  const weights = new Float32Array(sampleCount);
-
-  // 初始化：填充前 sampleCount 个元素
-  for (let i = 0; i < Math.min(sampleCount, totalCount); i++) {
-    result[i] = i;
-    // 使用 -log(random) / weight 作为键值（Efraimidis-Spirakis 算法）
-    weights[i] = importance[i] > 0 ? -Math.log(random()) / importance[i] : Infinity;
-  }
-
-  // 构建最小堆（用于维护 top-k）
-  // 简化实现：直接找最大权重的位置
-  let maxWeightIdx = 0;
-  let maxWeight = weights[0];
-  for (let i = 1; i < sampleCount; i++) {
-    if (weights[i] > maxWeight) {
-      maxWeight = weights[i];
-      maxWeightIdx = i;
-    }
-  }
-
-  // 处理剩余元素
-  for (let i = sampleCount; i < totalCount; i++) {
-    const key = importance[i] > 0 ? -Math.log(random()) / importance[i] : Infinity;
-
-    // 如果当前元素的键值小于堆中最大的键值，替换它
-    if (key < maxWeight) {
-      result[maxWeightIdx] = i;
-      weights[maxWeightIdx] = key;
-
-      // 重新找最大权重
-      maxWeight = weights[0];
-      maxWeightIdx = 0;
-      for (let j = 1; j < sampleCount; j++) {
-        if (weights[j] > maxWeight) {
-          maxWeight = weights[j];
-          maxWeightIdx = j;
-        }
-      }
-    }
   }
 
-  // 按原始顺序排列（保持空间局部性，有利于缓存）
-  result.sort((a, b) => a - b);
-
-  return result;
+  // 加权随机采样（Efraimidis-Spirakis），O(n)，比完整排序更高效
+  return sampleByImportance(importance, sampleCount, seed);
 }
 
 /**
@@ -406,6 +353,9 @@ export async function parsePLYBuffer(
   // 使用文件大小作为默认种子（确保相同文件产生相同结果）
   const seed = options.seed ?? buffer.byteLength;
 
+  // 单位换算、坐标轴转换、SH 阶数限制
+  const transform = new SplatImportTransform(options);
+
   // 解析 header
   const { headerText, dataOffset } = extractHeader(buffer);
   const { vertexCount, properties, stride, format } = parseHeader(headerText);
@@ -462,6 +412,9 @@ export async function parsePLYBuffer(
     opacity: getType("opacity"),
   };
 
+  // 2DGS (surfel) 只导出两个缩放轴
+  const is2DGS = offsets.scale_0 >= 0 && offsets.scale_1 >= 0 && offsets.scale_2 < 0;
+
   // SH 系数属性（可选）
   let shProps: PropertyInfo[] = [];
   if (loadSH) {
@@ -474,6 +427,14 @@ export async function parsePLYBuffer(
       });
   }
 
+  // 语义标签属性（可选，ML 分割结果）
+  const labelName = LABEL_PROPERTY_NAMES.find((name) => propMap.has(name));
+  const labelProp = labelName !== undefined ? getProp(labelName) : undefined;
+
+  // 自发光强度属性（可选，灯、屏幕等光源）
+  const emissiveName = EMISSIVE_PROPERTY_NAMES.find((name) => propMap.has(name));
+  const emissiveProp = emissiveName !== undefined ? getProp(emissiveName) : undefined;
+
   // 计算实际加载数量
   const needSample = vertexCount > maxSplats;
   const actualCount = Math.min(vertexCount, maxSplats);
@@ -500,11 +461,16 @@ export async function parsePLYBuffer(
 
   // 预分配输出数组（一次性分配，避免多次扩容）
   const positions = new Float32Array(actualCount * 3);
+  // f64 精度下先按 f64 读取位置，解析完成后以质心为原点重定位
+  const positions64 = options.precision === "f64" ? new Float64Array(actualCount * 3) : null;
+  const positionTarget = positions64 ?? positions;
   const scales = new Float32Array(actualCount * 3);
   const rotations = new Float32Array(actualCount * 4);
   const colors = new Float32Array(actualCount * 3);
   const opacities = new Float32Array(actualCount);
   const shCoeffs = loadSH ? new Float32Array(actualCount * 45) : undefined;
+  const labels = labelProp ? new Uint32Array(actualCount) : undefined;
+  const emissive = emissiveProp ? new Float32Array(actualCount) : undefined;
 
   // 创建 DataView
   const dataView = new DataView(buffer, dataOffset);
@@ -519,14 +485,14 @@ export async function parsePLYBuffer(
     const base = srcIdx * stride;
 
     // 位置
-    positions[outputIdx * 3 + 0] = offsets.x >= 0 ? readProperty(dataView, base + offsets.x, types.x, littleEndian) : 0;
-    positions[outputIdx * 3 + 1] = offsets.y >= 0 ? readProperty(dataView, base + offsets.y, types.y, littleEndian) : 0;
-    positions[outputIdx * 3 + 2] = offsets.z >= 0 ? readProperty(dataView, base + offsets.z, types.z, littleEndian) : 0;
+    positionTarget[outputIdx * 3 + 0] = offsets.x >= 0 ? readProperty(dataView, base + offsets.x, types.x, littleEndian) : 0;
+    positionTarget[outputIdx * 3 + 1] = offsets.y >= 0 ? readProperty(dataView, base + offsets.y, types.y, littleEndian) : 0;
+    positionTarget[outputIdx * 3 + 2] = offsets.z >= 0 ? readProperty(dataView, base + offsets.z, types.z, littleEndian) : 0;
 
     // 缩放（exp 转换）
     scales[outputIdx * 3 + 0] = offsets.scale_0 >= 0 ? Math.exp(readProperty(dataView, base + offsets.scale_0, types.scale_0, littleEndian)) : 1;
     scales[outputIdx * 3 + 1] = offsets.scale_1 >= 0 ? Math.exp(readProperty(dataView, base + offsets.scale_1, types.scale_1, littleEndian)) : 1;
-    scales[outputIdx * 3 + 2] = offsets.scale_2 >= 0 ? Math.exp(readProperty(dataView, base + offsets.scale_2, types.scale_2, littleEndian)) : 1;
+    scales[outputIdx * 3 + 2] = offsets.scale_2 >= 0 ? Math.exp(readProperty(dataView, base + offsets.scale_2, types.scale_2, littleEndian)) : (is2DGS ? 0 : 1);
 
     // 旋转四元数（归一化）
     const rot_0 = offsets.rot_0 >= 0 ? readProperty(dataView, base + offsets.rot_0, types.rot_0, littleEndian) : 1;
@@ -562,7 +528,9 @@ export async function parsePLYBuffer(
       const shBase = outputIdx * 45;
       const perChannel = Math.floor(shProps.length / 3); // 每通道的系数数量
       
-      for (let coefIdx = 0; coefIdx < perChannel && coefIdx < 15; coefIdx++) {
+      const coefLimit = Math.min(perChannel, transform.shCoeffCount);
+
+      for (let coefIdx = 0; coefIdx < coefLimit; coefIdx++) {
         // PLY 中: R 在 [0..perChannel-1], G 在 [perChannel..2*perChannel-1], B 在 [2*perChannel..3*perChannel-1]
         const srcR = coefIdx;
         const srcG = perChannel + coefIdx;
@@ -586,7 +554,35 @@ export async function parsePLYBuffer(
       }
     }
 
-    outputIdx++;
+    // 语义标签（可选）
+    if (labels && labelProp) {
+      labels[outputIdx] = readProperty(dataView, base + labelProp.byteOffset, labelProp.type, littleEndian) >>> 0;
+    }
+
+    // 自发光强度（可选）
+    if (emissive && emissiveProp) {
+      emissive[outputIdx] = Math.max(0, readProperty(dataView, base + emissiveProp.byteOffset, emissiveProp.type, littleEndian));
+    }
+
+    // 缩放、旋转到 Y-up
+    if (transform.transformsGeometry) {
+      transform.apply(
+        positionTarget, outputIdx * 3,
+        scales, outputIdx * 3,
+        rotations, outputIdx * 4,
+        shCoeffs, outputIdx * 45,
+      );
+    }
+
+    // 无效 splat 不推进输出位置，下一个 splat 会覆盖它
+    if (!transform.sanitize || transform.isValid(
+      positionTarget, outputIdx * 3,
+      scales, outputIdx * 3,
+      rotations, outputIdx * 4,
+      opacities[outputIdx],
+    )) {
+      outputIdx++;
+    }
 
     // 进度回调
     if (onProgress) {
@@ -598,14 +594,24 @@ export async function parsePLYBuffer(
     }
   }
 
+  const origin = positions64 ? rebasePositions(positions64, positions, outputIdx) : undefined;
+
+  // 丢弃了无效 splat 时裁剪到实际数量
+  const trim = <T extends Float32Array | Uint32Array>(arr: T, n: number): T =>
+    outputIdx < actualCount ? arr.subarray(0, outputIdx * n) as T : arr;
+
   return {
     count: outputIdx,
-    positions,
-    scales,
-    rotations,
-    colors,
-    opacities,
-    shCoeffs,
+    positions: trim(positions, 3),
+    scales: trim(scales, 3),
+    rotations: trim(rotations, 4),
+    colors: trim(colors, 3),
+    opacities: trim(opacities, 1),
+    shCoeffs: shCoeffs && trim(shCoeffs, 45),
+    labels: labels && trim(labels, 1),
+    is2DGS,
+    emissive: emissive && trim(emissive, 1),
+    origin,
   };
 }
 
@@ -629,11 +635,11 @@ export function compactDataToGPUBuffer(
     for (let i = 0; i < count; i++) {
       const offset = i * 64;
 
-      // mean (vec3) + padding
+      // mean (vec3) + emissive
       buffer[offset + 0] = data.positions[i * 3 + 0];
       buffer[offset + 1] = data.positions[i * 3 + 1];
       buffer[offset + 2] = data.positions[i * 3 + 2];
-      buffer[offset + 3] = 0;
+      buffer[offset + 3] = data.emissive ? data.emissive[i] : 0;
 
       // scale (vec3) + padding
       buffer[offset + 4] = data.scales[i * 3 + 0];
@@ -680,11 +686,11 @@ export function compactDataToGPUBuffer(
     for (let i = 0; i < count; i++) {
       const offset = i * 64;
       
-      // mean (vec3) + padding
+      // mean (vec3) + emissive
       buffer[offset + 0] = data.positions[i * 3 + 0];
       buffer[offset + 1] = data.positions[i * 3 + 1];
       buffer[offset + 2] = data.positions[i * 3 + 2];
-      buffer[offset + 3] = 0;
+      buffer[offset + 3] = data.emissive ? data.emissive[i] : 0;
       
       // scale (vec3) + padding
       buffer[offset + 4] = data.scales[i * 3 + 0];
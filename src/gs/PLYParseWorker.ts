@@ -0,0 +1,47 @@
+/**
+ * PLYParseWorker - 在 worker 中解析 PLY
+ *
+ * 消息：{ id, buffer, options } -> { id, type: "progress", loaded, total } / { id, type: "done", data } / { id, type: "error", message }
+ * 解析结果中的数组以 transfer 方式返回，不复制。模块加载完成后先发送 { type: "ready" }
+ */
+
+import { parsePLYBuffer, type CompactSplatData, type MobileLoadOptions } from "./PLYLoaderMobile";
+
+interface ParseRequest {
+  id: number;
+  buffer: ArrayBuffer;
+  options: Omit<MobileLoadOptions, "onProgress">;
+}
+
+const scope = self as unknown as {
+  postMessage(message: unknown, transfer?: Transferable[]): void;
+  onmessage: ((event: MessageEvent<ParseRequest>) => void) | null;
+};
+
+/**
+ * 解析结果中所有数组的底层 buffer（去重）
+ */
+function collectTransferables(data: CompactSplatData): ArrayBuffer[] {
+  const buffers = new Set<ArrayBuffer>();
+  for (const value of Object.values(data)) {
+    if (ArrayBuffer.isView(value) && value.buffer instanceof ArrayBuffer) {
+      buffers.add(value.buffer);
+    }
+  }
+  return [...buffers];
+}
+
+scope.onmessage = async (event) => {
+  const { id, buffer, options } = event.data;
+  try {
+    const data = await parsePLYBuffer(buffer, {
+      ...options,
+      onProgress: (loaded, total) => scope.postMessage({ id, type: "progress", loaded, total }),
+    });
+    scope.postMessage({ id, type: "done", data }, collectTransferables(data));
+  } catch (error) {
+    scope.postMessage({ id, type: "error", message: error instanceof Error ? error.message : String(error) });
+  }
+};
+
+scope.postMessage({ type: "ready" });
@@ -0,0 +1,110 @@
+/**
+ * PLYParseWorkerClient - 把 PLY 解析放到 worker 中
+ *
+ * 大文件的解析要数秒，在主线程上会冻结页面。这里把 buffer 以 transfer 方式交给共享的 worker，
+ * 结果数组同样 transfer 回来（都不复制），进度回调照常在主线程调用。
+ * worker 第一次使用时创建，等它发送 ready 后才交出 buffer：环境不支持模块 worker 或脚本加载失败时，
+ * buffer 仍然可用，回退到主线程解析
+ */
+
+import { parsePLYBuffer, type CompactSplatData, type MobileLoadOptions } from "./PLYLoaderMobile";
+
+type WorkerResponse =
+  | { type: "ready" }
+  | { id: number; type: "progress"; loaded: number; total: number }
+  | { id: number; type: "done"; data: CompactSplatData }
+  | { id: number; type: "error"; message: string };
+
+interface PendingParse {
+  resolve: (data: CompactSplatData) => void;
+  reject: (error: Error) => void;
+  onProgress?: (loaded: number, total: number) => void;
+}
+
+let workerPromise: Promise<Worker | null> | null = null;
+let nextId = 1;
+const pending: Map<number, PendingParse> = new Map();
+
+/**
+ * 创建共享的 worker，不可用时返回 null
+ */
+function getWorker(): Promise<Worker | null> {
+  if (workerPromise) return workerPromise;
+  workerPromise = new Promise((resolve) => {
+    if (typeof Worker === "undefined") {
+      resolve(null);
+      return;
+    }
+    let worker: Worker;
+    try {
+      worker = new Worker(new URL("./PLYParseWorker.ts", import.meta.url), { type: "module" });
+    } catch {
+      resolve(null);
+      return;
+    }
+    let ready = false;
+    worker.onmessage = (event: MessageEvent<WorkerResponse>) => {
+      const message = event.data;
+      if (message.type === "ready") {
+        ready = true;
+        resolve(worker);
+        return;
+      }
+      const request = pending.get(message.id);
+      if (!request) return;
+      if (message.type === "progress") {
+        request.onProgress?.(message.loaded, message.total);
+        return;
+      }
+      pending.delete(message.id);
+      if (message.type === "done") {
+        request.resolve(message.data);
+      } else {
+        request.reject(new Error(message.message));
+      }
+    };
+    worker.onerror = (event) => {
+      if (!ready) {
+        // 脚本加载失败：以后都在主线程解析
+        worker.terminate();
+        resolve(null);
+        return;
+      }
+      const error = new Error(`[PLYParseWorker] ${event.message}`);
+      pending.forEach((request) => request.reject(error));
+      pending.clear();
+      worker.terminate();
+      workerPromise = null;
+    };
+  });
+  return workerPromise;
+}
+
+/**
+ * 在 worker 中解析 PLY（不可用时在主线程解析）
+ * 使用 worker 时 buffer 会被 transfer，调用后不能再使用
+ */
+export async function parsePLYBufferInWorker(buffer: ArrayBuffer, options: MobileLoadOptions = {}): Promise<CompactSplatData> {
+  const worker = await getWorker();
+  if (!worker) {
+    return parsePLYBuffer(buffer, options);
+  }
+  const { onProgress, ...cloneable } = options;
+  const id = nextId++;
+  return new Promise((resolve, reject) => {
+    pending.set(id, { resolve, reject, onProgress });
+    worker.postMessage({ id, buffer, options: cloneable }, [buffer]);
+  });
+}
+
+/**
+ * 终止共享的 worker（正在进行的解析会失败），下次使用时重新创建
+ */
+export function terminatePLYParseWorker(): void {
+  const current = workerPromise;
+  workerPromise = null;
+  current?.then((worker) => worker?.terminate());
+  const error = new Error("[PLYParseWorker] worker 已终止");
+  pending.forEach((request) => request.reject(error));
+  pending.clear();
+}
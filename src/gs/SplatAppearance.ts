@@ -0,0 +1,85 @@
+/**
+ * SplatAppearance - 第二套外观（DC 颜色 + SH 系数）及混合
+ *
+ * 同一几何的两次采集（例如白天 / 夜晚，splat 一一对应）只有颜色不同时，
+ * 第二套外观单独存放在一个 storage buffer 中（每个 splat 48 个 f32：DC 3 + SH 45），
+ * shader 按 uniform 混合系数在两套颜色和 SH 之间线性插值，实现简单的昼夜过渡
+ */
+
+import type { CompactSplatData } from "./PLYLoaderMobile";
+import { SPLAT_FEATURE_APPEARANCE } from "./SplatShaderFeatures";
+
+/**
+ * 每个 splat 的外观数据长度（f32）：DC 颜色 3 + SH 系数 45
+ */
+export const SPLAT_APPEARANCE_STRIDE = 48;
+
+/**
+ * 外观数据（只使用颜色和 SH，几何字段忽略）
+ */
+export type SplatAppearanceData = Pick<CompactSplatData, "count" | "colors" | "shCoeffs">;
+
+/**
+ * 打包为 GPU 布局（没有 SH 时 SH 系数为 0）
+ */
+export function packSplatAppearance(data: SplatAppearanceData): Float32Array {
+  const packed = new Float32Array(data.count * SPLAT_APPEARANCE_STRIDE);
+  for (let i = 0; i < data.count; i++) {
+    const offset = i * SPLAT_APPEARANCE_STRIDE;
+    packed[offset] = data.colors[i * 3];
+    packed[offset + 1] = data.colors[i * 3 + 1];
+    packed[offset + 2] = data.colors[i * 3 + 2];
+    if (data.shCoeffs) {
+      packed.set(data.shCoeffs.subarray(i * 45, i * 45 + 45), offset + 3);
+    }
+  }
+  return packed;
+}
+
+/**
+ * 按索引抽取外观（压缩删除的 splat 时使用）
+ */
+export function gatherSplatAppearance(packed: Float32Array, indices: Uint32Array): Float32Array {
+  const result = new Float32Array(indices.length * SPLAT_APPEARANCE_STRIDE);
+  for (let k = 0; k < indices.length; k++) {
+    const offset = indices[k] * SPLAT_APPEARANCE_STRIDE;
+    result.set(packed.subarray(offset, offset + SPLAT_APPEARANCE_STRIDE), k * SPLAT_APPEARANCE_STRIDE);
+  }
+  return result;
+}
+
+/**
+ * 按索引抽取未打包的外观数据（分段渲染器按段拆分时使用）
+ */
+export function selectSplatAppearance(data: SplatAppearanceData, indices: Uint32Array): SplatAppearanceData {
+  const colors = new Float32Array(indices.length * 3);
+  const shCoeffs = data.shCoeffs ? new Float32Array(indices.length * 45) : undefined;
+  for (let k = 0; k < indices.length; k++) {
+    const i = indices[k];
+    colors.set(data.colors.subarray(i * 3, i * 3 + 3), k * 3);
+    shCoeffs?.set(data.shCoeffs!.subarray(i * 45, i * 45 + 45), k * 45);
+  }
+  return { count: indices.length, colors, shCoeffs };
+}
+
+// 外观混合 shader 函数（读取 uniforms.appearance 和 appearanceB）
+const appearanceShader = /* wgsl */ `
+const APPEARANCE_STRIDE: u32 = ${SPLAT_APPEARANCE_STRIDE}u;
+
+// 把第一套外观的系数与第二套外观 offset 处的系数按混合系数插值（offset: 0 为 DC，3 起为 SH）
+fn blendAppearance(i: u32, offset: u32, value: vec3<f32>) -> vec3<f32> {
+  let t = uniforms.appearance.x;
+  if t <= 0.0 { return value; }
+  let base = i * APPEARANCE_STRIDE + offset;
+  return mix(value, vec3<f32>(appearanceB[base], appearanceB[base + 1u], appearanceB[base + 2u]), t);
+}
+`;
+
+/**
+ * 外观混合 shader 函数（拼接在渲染器 shader 中），构建时关闭外观混合功能时为空实现
+ */
+export const splatAppearanceShader = SPLAT_FEATURE_APPEARANCE ? appearanceShader : /* wgsl */ `
+fn blendAppearance(i: u32, offset: u32, value: vec3<f32>) -> vec3<f32> {
+  return value;
+}
+`;
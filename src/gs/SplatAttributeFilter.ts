@@ -0,0 +1,285 @@
+/**
+ * SplatAttributeFilter - 按属性阈值选择 splat
+ *
+ * 谓词作用于存储的属性（不含不透明度编辑预览等渲染时调整），多个谓词之间为“与”：
+ * - opacity：不透明度 (0~1)
+ * - scale：最大轴缩放
+ * - luminance：DC 颜色的亮度 (Rec.709)
+ * - shEnergy：高阶 SH 系数的平方和（没有 SH 时为 0），可用于找出视角相关颜色很强的 splat
+ * CPU 端逐 splat 判断；GPU 变体把谓词上传到 compute pass，结果写入位掩码后回读，
+ * 百万级场景不需要在主线程遍历
+ */
+
+import { PipelineCache } from "../core/PipelineCache";
+import { SplatBufferPool } from "./SplatBufferPool";
+import { getBuffersByteSize } from "./SplatMemoryBudget";
+import { decodeSelectionMask } from "./SplatPolygonSelector";
+import type { CompactSplatData } from "./PLYLoaderMobile";
+
+/**
+ * 可筛选的属性
+ */
+export type SplatAttribute = "opacity" | "scale" | "luminance" | "shEnergy";
+
+/**
+ * 比较方式
+ */
+export type SplatAttributeComparison = "<" | "<=" | ">" | ">=";
+
+/**
+ * 属性谓词，例如 { attribute: "opacity", op: "<", value: 0.05 }
+ */
+export interface SplatAttributePredicate {
+  attribute: SplatAttribute;
+  op: SplatAttributeComparison;
+  value: number;
+}
+
+/**
+ * 单次查询的谓词数量上限
+ */
+export const MAX_SPLAT_ATTRIBUTE_PREDICATES = 8;
+
+const WORKGROUP_SIZE = 256;
+const ATTRIBUTE_IDS: Record<SplatAttribute, number> = { opacity: 0, scale: 1, luminance: 2, shEnergy: 3 };
+const COMPARISON_IDS: Record<SplatAttributeComparison, number> = { "<": 0, "<=": 1, ">": 2, ">=": 3 };
+
+/**
+ * 读取 splat 的属性值
+ */
+export function getSplatAttribute(data: CompactSplatData, index: number, attribute: SplatAttribute): number {
+  switch (attribute) {
+    case "opacity":
+      return data.opacities[index];
+    case "scale":
+      return Math.max(data.scales[index * 3], data.scales[index * 3 + 1], data.scales[index * 3 + 2]);
+    case "luminance":
+      return 0.2126 * data.colors[index * 3] + 0.7152 * data.colors[index * 3 + 1] + 0.0722 * data.colors[index * 3 + 2];
+    case "shEnergy": {
+      const sh = data.shCoeffs;
+      if (!sh) return 0;
+      let energy = 0;
+      for (let k = index * 45; k < index * 45 + 45; k++) {
+        energy += sh[k] * sh[k];
+      }
+      return energy;
+    }
+  }
+}
+
+/**
+ * 判断 splat 是否满足全部谓词
+ */
+export function matchesSplatAttributes(
+  data: CompactSplatData,
+  index: number,
+  predicates: ReadonlyArray<SplatAttributePredicate>,
+): boolean {
+  return predicates.every((p) => compare(getSplatAttribute(data, index, p.attribute), p.op, p.value));
+}
+
+function compare(value: number, op: SplatAttributeComparison, threshold: number): boolean {
+  switch (op) {
+    case "<":
+      return value < threshold;
+    case "<=":
+      return value <= threshold;
+    case ">":
+      return value > threshold;
+    case ">=":
+      return value >= threshold;
+  }
+}
+
+/**
+ * 属性筛选 compute shader
+ * 拼接在渲染器 shader 之后，复用其 Uniforms / splats
+ */
+const attributeSelectShader = /* wgsl */ `
+// ============================================
+// 属性筛选
+// ============================================
+
+struct AttributeParams {
+  splatCount: u32,
+  predicateCount: u32,
+  _pad: vec2<u32>,
+}
+
+@group(1) @binding(0) var<uniform> attributeParams: AttributeParams;
+// 每个谓词：(属性, 比较方式, 阈值, 0)
+@group(1) @binding(1) var<storage, read> attributePredicates: array<vec4<f32>>;
+@group(1) @binding(2) var<storage, read_write> attributeMask: array<atomic<u32>>;
+
+fn splatAttribute(index: u32, attribute: u32) -> f32 {
+  let splat = splats[index];
+  switch attribute {
+    case 0u: { return splat.opacity; }
+    case 1u: { return max(splat.scale.x, max(splat.scale.y, splat.scale.z)); }
+    case 2u: { return dot(splat.colorDC, vec3<f32>(0.2126, 0.7152, 0.0722)); }
+    default: {
+      var energy = 0.0;
+      for (var k = 0u; k < 9u; k++) { energy += splat.sh1[k] * splat.sh1[k]; }
+      for (var k = 0u; k < 15u; k++) { energy += splat.sh2[k] * splat.sh2[k]; }
+      for (var k = 0u; k < 21u; k++) { energy += splat.sh3[k] * splat.sh3[k]; }
+      return energy;
+    }
+  }
+}
+
+@compute @workgroup_size(${WORKGROUP_SIZE})
+fn selectAttributes(@builtin(global_invocation_id) gid: vec3<u32>) {
+  let splatIndex = gid.x;
+  if splatIndex >= attributeParams.splatCount { return; }
+
+  for (var i = 0u; i < attributeParams.predicateCount; i++) {
+    let predicate = attributePredicates[i];
+    let value = splatAttribute(splatIndex, u32(predicate.x));
+    let threshold = predicate.z;
+    var hit = false;
+    switch u32(predicate.y) {
+      case 0u: { hit = value < threshold; }
+      case 1u: { hit = value <= threshold; }
+      case 2u: { hit = value > threshold; }
+      default: { hit = value >= threshold; }
+    }
+    if !hit { return; }
+  }
+  atomicOr(&attributeMask[splatIndex / 32u], 1u << (splatIndex % 32u));
+}
+`;
+
+/**
+ * GPU 属性筛选器
+ */
+export class SplatAttributeSelector {
+  private device: GPUDevice;
+  private splatCount: number;
+
+  private paramsBuffer: GPUBuffer;
+  private predicateBuffer: GPUBuffer;
+  private maskBuffer: GPUBuffer;
+  private bindGroup: GPUBindGroup;
+  private pipeline: GPUComputePipeline;
+
+  constructor(
+    device: GPUDevice,
+    splatShaderCode: string,
+    splatBindGroupLayout: GPUBindGroupLayout,
+    splatCount: number,
+  ) {
+    this.device = device;
+    this.splatCount = splatCount;
+    const pipelines = PipelineCache.get(device);
+
+    this.paramsBuffer = device.createBuffer({
+      size: 16,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+      label: "attribute-select-params",
+    });
+    this.predicateBuffer = device.createBuffer({
+      size: MAX_SPLAT_ATTRIBUTE_PREDICATES * 16,
+      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      label: "attribute-select-predicates",
+    });
+    this.maskBuffer = device.createBuffer({
+      size: Math.max(1, Math.ceil(splatCount / 32)) * 4,
+      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_SRC | GPUBufferUsage.COPY_DST,
+      label: "attribute-select-mask",
+    });
+
+    const bindGroupLayout = pipelines.getBindGroupLayout({
+      label: "attribute-select-layout",
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.COMPUTE, buffer: { type: "uniform" } },
+        { binding: 1, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 2, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+      ],
+    });
+    this.bindGroup = device.createBindGroup({
+      layout: bindGroupLayout,
+      entries: [
+        { binding: 0, resource: { buffer: this.paramsBuffer } },
+        { binding: 1, resource: { buffer: this.predicateBuffer } },
+        { binding: 2, resource: { buffer: this.maskBuffer } },
+      ],
+      label: "attribute-select-bind-group",
+    });
+
+    const module = pipelines.getShaderModule({
+      code: splatShaderCode + attributeSelectShader,
+      label: "attribute-select-shader",
+    });
+    this.pipeline = pipelines.getComputePipeline({
+      layout: pipelines.getPipelineLayout([splatBindGroupLayout, bindGroupLayout]),
+      compute: { module, entryPoint: "selectAttributes" },
+      label: "attribute-select-pipeline",
+    });
+  }
+
+  /**
+   * 查询满足全部谓词的 splat
+   * @param splatBindGroup 渲染器的 bind group (uniforms / splats / ...)
+   * @returns 命中的 splat 索引（升序）；没有谓词时命中全部
+   */
+  async select(splatBindGroup: GPUBindGroup, predicates: ReadonlyArray<SplatAttributePredicate>): Promise<Uint32Array> {
+    if (predicates.length > MAX_SPLAT_ATTRIBUTE_PREDICATES) {
+      throw new Error(`[SplatAttributeSelector] 谓词数量超过上限 ${MAX_SPLAT_ATTRIBUTE_PREDICATES}`);
+    }
+    if (this.splatCount === 0) {
+      return new Uint32Array(0);
+    }
+
+    this.device.queue.writeBuffer(this.paramsBuffer, 0, new Uint32Array([this.splatCount, predicates.length, 0, 0]));
+    if (predicates.length > 0) {
+      const data = new Float32Array(predicates.length * 4);
+      predicates.forEach((p, i) => {
+        data[i * 4] = ATTRIBUTE_IDS[p.attribute];
+        data[i * 4 + 1] = COMPARISON_IDS[p.op];
+        data[i * 4 + 2] = p.value;
+      });
+      this.device.queue.writeBuffer(this.predicateBuffer, 0, data);
+    }
+
+    const size = this.maskBuffer.size;
+    const pool = SplatBufferPool.get(this.device);
+    const readBuffer = pool.acquire(size, GPUBufferUsage.COPY_DST | GPUBufferUsage.MAP_READ, "attribute-select-readback");
+    const encoder = this.device.createCommandEncoder({ label: "attribute-select-encoder" });
+    encoder.clearBuffer(this.maskBuffer);
+    const pass = encoder.beginComputePass({ label: "attribute-select" });
+    pass.setBindGroup(0, splatBindGroup);
+    pass.setBindGroup(1, this.bindGroup);
+    pass.setPipeline(this.pipeline);
+    pass.dispatchWorkgroups(Math.ceil(this.splatCount / WORKGROUP_SIZE));
+    pass.end();
+    encoder.copyBufferToBuffer(this.maskBuffer, 0, readBuffer, 0, size);
+    this.device.queue.submit([encoder.finish()]);
+
+    try {
+      await readBuffer.mapAsync(GPUMapMode.READ, 0, size);
+      const hits = decodeSelectionMask(new Uint32Array(readBuffer.getMappedRange(0, size)), this.splatCount);
+      readBuffer.unmap();
+      pool.release(readBuffer);
+      return hits;
+    } catch (error) {
+      readBuffer.destroy();
+      throw error;
+    }
+  }
+
+  /**
+   * 获取占用的显存（字节）
+   */
+  getGPUMemoryUsage(): number {
+    return getBuffersByteSize([this.paramsBuffer, this.predicateBuffer, this.maskBuffer]);
+  }
+
+  /**
+   * 销毁资源
+   */
+  destroy(): void {
+    this.paramsBuffer.destroy();
+    this.predicateBuffer.destroy();
+    this.maskBuffer.destroy();
+  }
+}
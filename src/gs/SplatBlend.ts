@@ -0,0 +1,40 @@
+/**
+ * SplatBlend - splat 混合模式对应的混合状态
+ *
+ * shader 统一输出预乘 alpha 颜色 (color * alpha, alpha)，
+ * 不同混合模式只改变管线的 blend state，因此以混合模式作为管线变体的 key
+ */
+
+import { SplatBlendMode } from "../types";
+
+/**
+ * 获取混合模式对应的 blend state
+ */
+export function getSplatBlendState(mode: SplatBlendMode): GPUBlendState {
+  switch (mode) {
+    case SplatBlendMode.Additive:
+      return {
+        color: { srcFactor: "one", dstFactor: "one", operation: "add" },
+        alpha: { srcFactor: "one", dstFactor: "one", operation: "add" },
+      };
+    case SplatBlendMode.Max:
+      // min / max 运算要求混合因子为 one
+      return {
+        color: { srcFactor: "one", dstFactor: "one", operation: "max" },
+        alpha: { srcFactor: "one", dstFactor: "one", operation: "max" },
+      };
+    case SplatBlendMode.Standard:
+    default:
+      return {
+        color: { srcFactor: "one", dstFactor: "one-minus-src-alpha", operation: "add" },
+        alpha: { srcFactor: "one", dstFactor: "one-minus-src-alpha", operation: "add" },
+      };
+  }
+}
+
+/**
+ * 混合结果是否与绘制顺序无关（无关时可以跳过深度排序）
+ */
+export function isOrderIndependentBlend(mode: SplatBlendMode): boolean {
+  return mode === SplatBlendMode.Additive || mode === SplatBlendMode.Max;
+}
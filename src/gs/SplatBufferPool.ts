@@ -0,0 +1,165 @@
+/**
+ * SplatBufferPool - 按设备回收复用 splat 相关的大 buffer
+ *
+ * 切换场景时渲染器、排序器和 tile 光栅化器会一次性释放再创建十几个与 splat 数量成正比的 buffer
+ * （splat 数据、深度键、可见索引、基数排序的 ping-pong / 直方图临时 buffer 等）。
+ * 这里把释放的 buffer 按 (尺寸档位, usage) 放回空闲列表，下次申请相同档位时直接复用，避免分配尖峰。
+ *
+ * 尺寸档位：每个 2 的幂区间等分为 8 档（浪费不超过 12.5%），最小 256 字节。
+ * 复用的 buffer 内容不清零，调用方需要自己写入或 clearBuffer；
+ * 复用的 buffer 可能比申请的尺寸大，依赖 arrayLength 的绑定需要显式指定绑定尺寸
+ */
+
+/** 默认最多缓存的空闲字节数 */
+const DEFAULT_MAX_POOLED_BYTES = 1024 * 1024 * 1024;
+/** 最小档位 */
+const MIN_SIZE_CLASS = 256;
+/** 每个 2 的幂区间的档位数 */
+const CLASSES_PER_OCTAVE = 8;
+
+/**
+ * 计算尺寸档位
+ */
+export function getBufferSizeClass(size: number): number {
+  if (size <= MIN_SIZE_CLASS) {
+    return MIN_SIZE_CLASS;
+  }
+  const octave = 2 ** Math.floor(Math.log2(size));
+  const step = Math.max(MIN_SIZE_CLASS, octave / CLASSES_PER_OCTAVE);
+  return Math.ceil(size / step) * step;
+}
+
+/**
+ * splat buffer 池
+ */
+export class SplatBufferPool {
+  private static pools: WeakMap<GPUDevice, SplatBufferPool> = new WeakMap();
+
+  private device: GPUDevice;
+  // "usage:size" -> 空闲 buffer
+  private free: Map<string, GPUBuffer[]> = new Map();
+  // 空闲 buffer 按释放顺序排列，超出预算时从最早释放的开始销毁
+  private freeOrder: GPUBuffer[] = [];
+  private pooledBytes: number = 0;
+  private maxPooledBytes: number = DEFAULT_MAX_POOLED_BYTES;
+  private hits: number = 0;
+  private misses: number = 0;
+
+  /**
+   * 获取设备的共享 buffer 池
+   */
+  static get(device: GPUDevice): SplatBufferPool {
+    let pool = SplatBufferPool.pools.get(device);
+    if (!pool) {
+      pool = new SplatBufferPool(device);
+      SplatBufferPool.pools.set(device, pool);
+    }
+    return pool;
+  }
+
+  constructor(device: GPUDevice) {
+    this.device = device;
+  }
+
+  /**
+   * 申请至少 size 字节的 buffer（优先复用同档位同 usage 的空闲 buffer）
+   */
+  acquire(size: number, usage: GPUBufferUsageFlags, label?: string): GPUBuffer {
+    const pooledSize = this.getPooledSize(size, usage);
+    const key = `${usage}:${pooledSize}`;
+    const list = this.free.get(key);
+    const buffer = list?.pop();
+    if (buffer) {
+      this.hits++;
+      this.pooledBytes -= buffer.size;
+      this.freeOrder.splice(this.freeOrder.indexOf(buffer), 1);
+      if (label) buffer.label = label;
+      return buffer;
+    }
+    this.misses++;
+    return this.device.createBuffer({ size: pooledSize, usage, label });
+  }
+
+  /**
+   * 归还 buffer（不在档位上的 buffer 直接销毁）
+   */
+  release(buffer: GPUBuffer | null | undefined): void {
+    if (!buffer) {
+      return;
+    }
+    if (buffer.size !== this.getPooledSize(buffer.size, buffer.usage) || buffer.size > this.maxPooledBytes) {
+      buffer.destroy();
+      return;
+    }
+    const key = `${buffer.usage}:${buffer.size}`;
+    let list = this.free.get(key);
+    if (!list) {
+      list = [];
+      this.free.set(key, list);
+    }
+    list.push(buffer);
+    this.freeOrder.push(buffer);
+    this.pooledBytes += buffer.size;
+    this.trim(this.maxPooledBytes);
+  }
+
+  /**
+   * 设置最多缓存的空闲字节数（超出时销毁最早归还的 buffer）
+   */
+  setMaxPooledBytes(bytes: number): void {
+    this.maxPooledBytes = Math.max(0, bytes);
+    this.trim(this.maxPooledBytes);
+  }
+
+  /**
+   * 获取最多缓存的空闲字节数
+   */
+  getMaxPooledBytes(): number {
+    return this.maxPooledBytes;
+  }
+
+  /**
+   * 销毁空闲 buffer 直到缓存量不超过 maxBytes
+   */
+  trim(maxBytes: number = 0): void {
+    while (this.pooledBytes > maxBytes && this.freeOrder.length > 0) {
+      const buffer = this.freeOrder.shift()!;
+      const list = this.free.get(`${buffer.usage}:${buffer.size}`)!;
+      list.splice(list.indexOf(buffer), 1);
+      this.pooledBytes -= buffer.size;
+      buffer.destroy();
+    }
+  }
+
+  /**
+   * 获取池统计
+   */
+  getStats(): { pooledBuffers: number; pooledBytes: number; hits: number; misses: number } {
+    return {
+      pooledBuffers: this.freeOrder.length,
+      pooledBytes: this.pooledBytes,
+      hits: this.hits,
+      misses: this.misses,
+    };
+  }
+
+  /**
+   * 销毁全部空闲 buffer
+   */
+  clear(): void {
+    this.trim(0);
+    this.free.clear();
+  }
+
+  /**
+   * 档位尺寸，不超过设备对该 usage 的上限
+   */
+  private getPooledSize(size: number, usage: GPUBufferUsageFlags): number {
+    const limits = this.device.limits;
+    const limit = usage & GPUBufferUsage.STORAGE
+      ? Math.min(limits.maxStorageBufferBindingSize, limits.maxBufferSize)
+      : limits.maxBufferSize;
+    const sizeClass = getBufferSizeClass(size);
+    return sizeClass <= limit ? sizeClass : size;
+  }
+}
@@ -0,0 +1,272 @@
+/**
+ * SplatCentersFilter - 中心点叠加的子采样
+ *
+ * 千万级场景下为每个可见 splat 绘制中心点既看不清也拖慢编辑交互。
+ * 过滤 pass 在排序之后遍历可见 splat，只把满足条件的写入独立的索引列表和 indirect buffer：
+ * - stride：按 splat 索引每 stride 个保留一个（与相机无关，移动时不闪烁）
+ * - cursorRadius：只保留投影到光标附近的 splat
+ * - selection：只保留选中的 splat，或位于选择包围盒内的所有 splat
+ * 中心点绘制只处理过滤后的实例
+ */
+
+import { PipelineCache } from "../core/PipelineCache";
+import { SplatBufferPool } from "./SplatBufferPool";
+import { getBuffersByteSize } from "./SplatMemoryBudget";
+import type { Vec3Tuple } from "../types";
+
+const WORKGROUP_SIZE = 256;
+
+/**
+ * 按选择过滤
+ * - none: 不过滤
+ * - selected: 只显示选中的 splat
+ * - bounds: 显示位于选择包围盒内的所有 splat（便于调整选区边界）
+ */
+export type SplatCentersSelectionFilter = "none" | "selected" | "bounds";
+
+/**
+ * 中心点叠加配置
+ */
+export interface SplatCentersConfig {
+  /** 按 splat 索引每 stride 个显示一个，1 表示全部 */
+  stride: number;
+  /** 只显示投影到光标附近的中心点（像素半径），0 表示不限制 */
+  cursorRadius: number;
+  /** 按选择过滤 */
+  selection: SplatCentersSelectionFilter;
+}
+
+/** 默认中心点叠加配置 */
+export const DEFAULT_SPLAT_CENTERS_CONFIG: SplatCentersConfig = {
+  stride: 1,
+  cursorRadius: 0,
+  selection: "none",
+};
+
+/**
+ * 配置是否需要过滤 pass（不需要时直接绘制全部可见 splat）
+ */
+export function needsCentersFilter(config: SplatCentersConfig): boolean {
+  return config.stride > 1 || config.cursorRadius > 0 || config.selection !== "none";
+}
+
+/**
+ * 过滤 compute shader
+ * 拼接在渲染器 shader 之后，复用其 Uniforms / splats / sortedIndices / selection
+ */
+const centersFilterShader = /* wgsl */ `
+// ============================================
+// 中心点过滤
+// ============================================
+
+struct CentersParams {
+  stride: u32,
+  selection: u32,     // 0: 不过滤, 1: 选中, 2: 选择包围盒
+  cursorRadius: f32,  // 像素，0 表示不限制
+  _pad0: u32,
+  cursor: vec2<f32>,  // 光标像素坐标 (y 向下)
+  _pad1: vec2<f32>,
+  boundsMin: vec4<f32>,
+  boundsMax: vec4<f32>,
+}
+
+@group(1) @binding(0) var<uniform> centersParams: CentersParams;
+@group(1) @binding(1) var<storage, read> visibleDraw: array<u32>;
+@group(1) @binding(2) var<storage, read_write> centerIndices: array<u32>;
+@group(1) @binding(3) var<storage, read_write> centerDraw: array<atomic<u32>, 4>;
+
+@compute @workgroup_size(1)
+fn initCenters() {
+  atomicStore(&centerDraw[0], 4u);
+  atomicStore(&centerDraw[1], 0u);
+  atomicStore(&centerDraw[2], 0u);
+  atomicStore(&centerDraw[3], 0u);
+}
+
+@compute @workgroup_size(${WORKGROUP_SIZE})
+fn filterCenters(@builtin(global_invocation_id) gid: vec3<u32>) {
+  if gid.x >= visibleDraw[1] { return; }
+  let splatIndex = sortedIndices[gid.x];
+  if splatIndex % centersParams.stride != 0u { return; }
+
+  if centersParams.selection == 1u && (uniforms.hasSelection == 0u || (selection[splatIndex] & 1u) == 0u) { return; }
+  let mean = splats[splatIndex].mean;
+  if centersParams.selection == 2u && (any(mean < centersParams.boundsMin.xyz) || any(mean > centersParams.boundsMax.xyz)) {
+    return;
+  }
+
+  if centersParams.cursorRadius > 0.0 {
+    let clipPos = uniforms.proj * (uniforms.view * (uniforms.model * vec4<f32>(mean, 1.0)));
+    if clipPos.w <= 0.0 { return; }
+    let ndc = clipPos.xy / clipPos.w;
+    let pixel = vec2<f32>(ndc.x * 0.5 + 0.5, 0.5 - ndc.y * 0.5) * uniforms.screenSize;
+    if distance(pixel, centersParams.cursor) > centersParams.cursorRadius { return; }
+  }
+
+  let slot = atomicAdd(&centerDraw[1], 1u);
+  centerIndices[slot] = splatIndex;
+}
+`;
+
+/**
+ * 中心点过滤器
+ */
+export class SplatCentersFilter {
+  private device: GPUDevice;
+  private pool: SplatBufferPool;
+  private splatCount: number;
+
+  private paramsBuffer: GPUBuffer;
+  private indicesBuffer: GPUBuffer;
+  private drawBuffer: GPUBuffer;
+
+  private bindGroupLayout: GPUBindGroupLayout;
+  private bindGroup: GPUBindGroup | null = null;
+  private visibleDrawBuffer: GPUBuffer | null = null;
+  private initPipeline: GPUComputePipeline;
+  private filterPipeline: GPUComputePipeline;
+
+  constructor(
+    device: GPUDevice,
+    splatShaderCode: string,
+    splatBindGroupLayout: GPUBindGroupLayout,
+    splatCount: number,
+  ) {
+    this.device = device;
+    const pipelines = PipelineCache.get(device);
+    this.pool = SplatBufferPool.get(device);
+    this.splatCount = splatCount;
+
+    this.paramsBuffer = device.createBuffer({
+      size: 64,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+      label: "centers-params",
+    });
+    this.indicesBuffer = this.pool.acquire(
+      Math.max(splatCount, 1) * 4,
+      GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      "centers-indices",
+    );
+    this.drawBuffer = device.createBuffer({
+      size: 16,
+      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.INDIRECT | GPUBufferUsage.COPY_DST,
+      label: "centers-indirect",
+    });
+
+    this.bindGroupLayout = pipelines.getBindGroupLayout({
+      label: "centers-filter-layout",
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.COMPUTE, buffer: { type: "uniform" } },
+        { binding: 1, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 2, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+        { binding: 3, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+      ],
+    });
+
+    const module = pipelines.getShaderModule({
+      code: splatShaderCode + centersFilterShader,
+      label: "centers-filter-shader",
+    });
+    const layout = pipelines.getPipelineLayout([splatBindGroupLayout, this.bindGroupLayout]);
+    this.initPipeline = pipelines.getComputePipeline({
+      layout,
+      compute: { module, entryPoint: "initCenters" },
+      label: "centers-init-pipeline",
+    });
+    this.filterPipeline = pipelines.getComputePipeline({
+      layout,
+      compute: { module, entryPoint: "filterCenters" },
+      label: "centers-filter-pipeline",
+    });
+  }
+
+  /**
+   * 执行过滤（在 sorter.sort() 之后、渲染通道提交之前调用）
+   * @param splatBindGroup 渲染器的 bind group (uniforms / splats / sortedIndices / ...)
+   * @param visibleDrawBuffer 排序器的 indirect buffer，[1] 为可见数量
+   * @param cursor 光标像素坐标，null 表示光标不在画布上
+   * @param bounds 选择包围盒（模型空间，selection 为 "bounds" 时使用），null 表示没有选择
+   */
+  filter(
+    splatBindGroup: GPUBindGroup,
+    visibleDrawBuffer: GPUBuffer,
+    config: SplatCentersConfig,
+    cursor: [number, number] | null,
+    bounds: { min: Vec3Tuple; max: Vec3Tuple } | null,
+  ): void {
+    if (this.visibleDrawBuffer !== visibleDrawBuffer || !this.bindGroup) {
+      this.visibleDrawBuffer = visibleDrawBuffer;
+      this.bindGroup = this.device.createBindGroup({
+        layout: this.bindGroupLayout,
+        entries: [
+          { binding: 0, resource: { buffer: this.paramsBuffer } },
+          { binding: 1, resource: { buffer: visibleDrawBuffer } },
+          { binding: 2, resource: { buffer: this.indicesBuffer } },
+          { binding: 3, resource: { buffer: this.drawBuffer } },
+        ],
+        label: "centers-filter-bind-group",
+      });
+    }
+
+    const selectionMode = config.selection === "selected" ? 1 : config.selection === "bounds" ? 2 : 0;
+    // 没有选择时包围盒取空集 (min > max)
+    const min = bounds?.min ?? [1, 1, 1];
+    const max = bounds?.max ?? [0, 0, 0];
+    // 光标不在画布上时放到远处，半径过滤后没有中心点
+    const [cx, cy] = cursor ?? [-1e9, -1e9];
+    const params = new ArrayBuffer(64);
+    const u32 = new Uint32Array(params);
+    const f32 = new Float32Array(params);
+    u32[0] = Math.max(1, Math.floor(config.stride));
+    u32[1] = selectionMode;
+    f32[2] = Math.max(0, config.cursorRadius);
+    f32[4] = cx;
+    f32[5] = cy;
+    f32.set(min, 8);
+    f32.set(max, 12);
+    this.device.queue.writeBuffer(this.paramsBuffer, 0, params);
+
+    const encoder = this.device.createCommandEncoder({ label: "centers-filter-encoder" });
+    const pass = encoder.beginComputePass({ label: "centers-filter" });
+    pass.setBindGroup(0, splatBindGroup);
+    pass.setBindGroup(1, this.bindGroup);
+    pass.setPipeline(this.initPipeline);
+    pass.dispatchWorkgroups(1);
+    pass.setPipeline(this.filterPipeline);
+    pass.dispatchWorkgroups(Math.ceil(Math.max(this.splatCount, 1) / WORKGROUP_SIZE));
+    pass.end();
+    this.device.queue.submit([encoder.finish()]);
+  }
+
+  /**
+   * 获取过滤后的 splat 索引 buffer
+   */
+  getIndicesBuffer(): GPUBuffer {
+    return this.indicesBuffer;
+  }
+
+  /**
+   * 获取过滤后的 DrawIndirect buffer
+   */
+  getDrawIndirectBuffer(): GPUBuffer {
+    return this.drawBuffer;
+  }
+
+  /**
+   * 获取占用的显存（字节）
+   */
+  getGPUMemoryUsage(): number {
+    return getBuffersByteSize([this.paramsBuffer, this.indicesBuffer, this.drawBuffer]);
+  }
+
+  /**
+   * 销毁资源
+   */
+  destroy(): void {
+    this.paramsBuffer.destroy();
+    this.pool.release(this.indicesBuffer);
+    this.drawBuffer.destroy();
+    this.bindGroup = null;
+    this.visibleDrawBuffer = null;
+  }
+}
@@ -0,0 +1,149 @@
+/**
+ * SplatColorAdjust - splat 颜色调整（色相 / 饱和度 / 亮度 / 着色）
+ *
+ * 只修改 DC 颜色（SH 0 阶），视角相关的高阶 SH 保持不变。
+ * 同一套公式有 CPU 和 WGSL 两份实现：预览时渲染器在 shader 中对选中的 splat 实时调整，
+ * 确认后在 CPU 端写回 GaussianSplats 数据（可撤销），两者结果一致
+ */
+
+import type { Vec3Tuple } from "../types";
+import { SPLAT_FEATURE_EDITING } from "./SplatShaderFeatures";
+
+/**
+ * 颜色调整参数，依次执行：色相旋转、饱和度缩放、亮度缩放（HSV 空间），再乘以着色
+ */
+export interface SplatColorAdjustment {
+  /** 色相旋转（度） */
+  hueShift: number;
+  /** 饱和度倍数 */
+  saturation: number;
+  /** 亮度倍数 */
+  brightness: number;
+  /** 着色（逐通道相乘） */
+  tint: Vec3Tuple;
+}
+
+export const DEFAULT_SPLAT_COLOR_ADJUSTMENT: SplatColorAdjustment = {
+  hueShift: 0,
+  saturation: 1,
+  brightness: 1,
+  tint: [1, 1, 1],
+};
+
+/**
+ * 调整是否不改变颜色
+ */
+export function isIdentityColorAdjustment(adjustment: SplatColorAdjustment): boolean {
+  return (
+    adjustment.hueShift % 360 === 0 &&
+    adjustment.saturation === 1 &&
+    adjustment.brightness === 1 &&
+    adjustment.tint.every((v) => v === 1)
+  );
+}
+
+/**
+ * 调整一个 DC 颜色（与 shader 中的 adjustSplatColor 一致）
+ */
+export function adjustSplatColor(color: Vec3Tuple, adjustment: SplatColorAdjustment): Vec3Tuple {
+  const r = Math.max(color[0], 0);
+  const g = Math.max(color[1], 0);
+  const b = Math.max(color[2], 0);
+  const max = Math.max(r, g, b);
+  const min = Math.min(r, g, b);
+  const delta = max - min;
+
+  let h = 0;
+  if (delta > 0) {
+    if (max === r) h = ((g - b) / delta + 6) % 6;
+    else if (max === g) h = (b - r) / delta + 2;
+    else h = (r - g) / delta + 4;
+    h /= 6;
+  }
+  const s = max > 0 ? delta / max : 0;
+
+  h = fract(h + adjustment.hueShift / 360);
+  const sat = Math.min(1, Math.max(0, s * adjustment.saturation));
+  const v = max * adjustment.brightness;
+
+  const [tr, tg, tb] = adjustment.tint;
+  return [
+    hsvChannel(h, sat, v, 5) * tr,
+    hsvChannel(h, sat, v, 3) * tg,
+    hsvChannel(h, sat, v, 1) * tb,
+  ];
+}
+
+// HSV -> RGB 的单个通道：v - v * s * clamp(min(k, 4 - k), 0, 1)，k = (n + h * 6) mod 6
+function hsvChannel(h: number, s: number, v: number, n: number): number {
+  const k = (n + h * 6) % 6;
+  return v - v * s * Math.min(1, Math.max(0, Math.min(k, 4 - k)));
+}
+
+function fract(x: number): number {
+  return x - Math.floor(x);
+}
+
+/**
+ * 颜色调整的 uniform 数据：[着色 rgb, 启用], [色相 (圈), 饱和度, 亮度, 0]
+ */
+export function getColorAdjustUniforms(
+  adjustment: SplatColorAdjustment | null,
+  data: Float32Array = new Float32Array(8),
+): Float32Array {
+  data.fill(0);
+  if (!adjustment) {
+    return data;
+  }
+  data[0] = adjustment.tint[0];
+  data[1] = adjustment.tint[1];
+  data[2] = adjustment.tint[2];
+  data[3] = 1;
+  data[4] = adjustment.hueShift / 360;
+  data[5] = adjustment.saturation;
+  data[6] = adjustment.brightness;
+  return data;
+}
+
+// 颜色调整 shader 函数（读取 uniforms.colorTint / colorAdjust）
+const colorAdjustShader = /* wgsl */ `
+fn hsvChannel(h: f32, s: f32, v: f32, n: f32) -> f32 {
+  let k = (n + h * 6.0) % 6.0;
+  return v - v * s * clamp(min(k, 4.0 - k), 0.0, 1.0);
+}
+
+// 调整 DC 颜色：色相旋转、饱和度 / 亮度缩放，再乘以着色（与 CPU 端 adjustSplatColor 一致）
+fn adjustSplatColor(color: vec3<f32>) -> vec3<f32> {
+  let c = max(color, vec3<f32>(0.0));
+  let maxC = max(c.r, max(c.g, c.b));
+  let minC = min(c.r, min(c.g, c.b));
+  let delta = maxC - minC;
+  var h = 0.0;
+  if delta > 0.0 {
+    if maxC == c.r {
+      h = ((c.g - c.b) / delta + 6.0) % 6.0;
+    } else if maxC == c.g {
+      h = (c.b - c.r) / delta + 2.0;
+    } else {
+      h = (c.r - c.g) / delta + 4.0;
+    }
+    h /= 6.0;
+  }
+  let s = select(0.0, delta / maxC, maxC > 0.0);
+
+  h = fract(h + uniforms.colorAdjust.x);
+  let sat = clamp(s * uniforms.colorAdjust.y, 0.0, 1.0);
+  let v = maxC * uniforms.colorAdjust.z;
+  let rgb = vec3<f32>(hsvChannel(h, sat, v, 5.0), hsvChannel(h, sat, v, 3.0), hsvChannel(h, sat, v, 1.0));
+  return rgb * uniforms.colorTint.rgb;
+}
+`;
+
+/**
+ * 颜色调整 shader 函数（拼接在渲染器 shader 中），构建时关闭编辑功能时为空实现
+ */
+export const splatColorAdjustShader = SPLAT_FEATURE_EDITING ? colorAdjustShader : /* wgsl */ `
+fn adjustSplatColor(color: vec3<f32>) -> vec3<f32> {
+  return color;
+}
+`;
@@ -0,0 +1,109 @@
+/**
+ * SplatCompactor - 在 GPU 上剔除已删除的 splat
+ *
+ * 删除的 splat 只是把不透明度置零，仍然占用 buffer 并参与每帧的剔除和排序。
+ * 压缩时 CPU 只上传保留 splat 的源索引 (4 字节/splat)，由 compute pass 把对应的
+ * 256 字节 splat 记录从旧 buffer 收集到新 buffer，不需要重新打包上传整份数据
+ */
+
+import { PipelineCache } from "../core/PipelineCache";
+
+const WORKGROUP_SIZE = 256;
+/** 每个 splat 的 vec4 数量 (256 字节) */
+const SPLAT_VEC4S = 16;
+
+const compactShaderCode = /* wgsl */ `
+@group(0) @binding(0) var<uniform> keepCount: u32;
+@group(0) @binding(1) var<storage, read> sourceIndices: array<u32>;
+@group(0) @binding(2) var<storage, read> srcSplats: array<vec4<f32>>;
+@group(0) @binding(3) var<storage, read_write> dstSplats: array<vec4<f32>>;
+
+@compute @workgroup_size(${WORKGROUP_SIZE})
+fn gather(@builtin(global_invocation_id) gid: vec3<u32>) {
+  let i = gid.x;
+  if i >= keepCount { return; }
+  let src = sourceIndices[i] * ${SPLAT_VEC4S}u;
+  let dst = i * ${SPLAT_VEC4S}u;
+  for (var j = 0u; j < ${SPLAT_VEC4S}u; j++) {
+    dstSplats[dst + j] = srcSplats[src + j];
+  }
+}
+`;
+
+/**
+ * splat buffer 压缩器
+ */
+export class SplatCompactor {
+  private device: GPUDevice;
+  private pipeline: GPUComputePipeline;
+  private bindGroupLayout: GPUBindGroupLayout;
+
+  constructor(device: GPUDevice) {
+    this.device = device;
+    const pipelines = PipelineCache.get(device);
+
+    this.bindGroupLayout = pipelines.getBindGroupLayout({
+      label: "splat-compact-layout",
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.COMPUTE, buffer: { type: "uniform" } },
+        { binding: 1, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 2, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 3, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+      ],
+    });
+    this.pipeline = pipelines.getComputePipeline({
+      layout: pipelines.getPipelineLayout([this.bindGroupLayout]),
+      compute: {
+        module: pipelines.getShaderModule({ code: compactShaderCode, label: "splat-compact-shader" }),
+        entryPoint: "gather",
+      },
+      label: "splat-compact-pipeline",
+    });
+  }
+
+  /**
+   * 把 src 中 keepIndices 指向的 splat 依次写入 dst（dst 至少 keepIndices.length * 256 字节）
+   */
+  compact(src: GPUBuffer, dst: GPUBuffer, keepIndices: Uint32Array): void {
+    const device = this.device;
+    const count = keepIndices.length;
+    if (count === 0) {
+      return;
+    }
+
+    const paramsBuffer = device.createBuffer({
+      size: 16,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+      label: "splat-compact-params",
+    });
+    device.queue.writeBuffer(paramsBuffer, 0, new Uint32Array([count, 0, 0, 0]));
+    const indexBuffer = device.createBuffer({
+      size: keepIndices.byteLength,
+      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      label: "splat-compact-indices",
+    });
+    device.queue.writeBuffer(indexBuffer, 0, keepIndices);
+
+    const bindGroup = device.createBindGroup({
+      layout: this.bindGroupLayout,
+      entries: [
+        { binding: 0, resource: { buffer: paramsBuffer } },
+        { binding: 1, resource: { buffer: indexBuffer } },
+        { binding: 2, resource: { buffer: src } },
+        { binding: 3, resource: { buffer: dst } },
+      ],
+    });
+
+    const encoder = device.createCommandEncoder({ label: "splat-compact" });
+    const pass = encoder.beginComputePass({ label: "splat-compact" });
+    pass.setPipeline(this.pipeline);
+    pass.setBindGroup(0, bindGroup);
+    pass.dispatchWorkgroups(Math.ceil(count / WORKGROUP_SIZE));
+    pass.end();
+    device.queue.submit([encoder.finish()]);
+
+    // 已提交的命令完成后才会真正释放
+    paramsBuffer.destroy();
+    indexBuffer.destroy();
+  }
+}
@@ -0,0 +1,326 @@
+/**
+ * SplatCropBox - 交互式裁剪盒
+ *
+ * 世界空间中的有向包围盒（中心、尺寸、欧拉角旋转），两种裁剪方式：
+ * - 软裁剪：把盒子传给渲染器，在 shader 中逐 splat 判断中心是否在盒内，盒外的 splat 不透明度为 0
+ *   （不修改数据，拖动盒子时每帧只更新一个 uniform，百万级场景也能实时反馈）
+ * - 硬裁剪：在 CPU 端用同样的判断找出盒外的 splat 并删除（可撤销）
+ * 手柄：6 个面中心手柄，拖动时对面保持不动；外部 gizmo 也可以直接设置中心 / 尺寸 / 旋转
+ */
+
+import type { BoundingBox, Vec3Tuple } from "../types";
+import { multiplyTransforms } from "../utils/transform";
+
+/** 盒子每个轴的最小尺寸 */
+const MIN_CROP_BOX_SIZE = 1e-4;
+
+/**
+ * 裁剪盒状态
+ */
+export interface SplatCropBoxState {
+  /** 中心（世界空间） */
+  center: Vec3Tuple;
+  /** 各轴尺寸（盒子局部空间） */
+  size: Vec3Tuple;
+  /** 欧拉角旋转（弧度，R = Rz * Ry * Rx，与实体旋转一致） */
+  rotation: Vec3Tuple;
+  /** 反转：隐藏 / 删除盒内的 splat */
+  invert: boolean;
+}
+
+export const DEFAULT_SPLAT_CROP_BOX_STATE: SplatCropBoxState = {
+  center: [0, 0, 0],
+  size: [1, 1, 1],
+  rotation: [0, 0, 0],
+  invert: false,
+};
+
+/**
+ * 面手柄 id：轴和方向
+ */
+export type SplatCropBoxHandleId = "+x" | "-x" | "+y" | "-y" | "+z" | "-z";
+
+/**
+ * 面手柄
+ */
+export interface SplatCropBoxHandle {
+  id: SplatCropBoxHandleId;
+  /** 轴 (0 / 1 / 2) */
+  axis: number;
+  /** 方向 (1 / -1) */
+  sign: number;
+  /** 面中心（世界空间） */
+  position: Vec3Tuple;
+  /** 面法线（世界空间，指向盒外） */
+  normal: Vec3Tuple;
+}
+
+const HANDLE_IDS: SplatCropBoxHandleId[] = ["+x", "-x", "+y", "-y", "+z", "-z"];
+
+/**
+ * 裁剪盒
+ */
+export class SplatCropBox {
+  private state: SplatCropBoxState;
+  // 旋转矩阵（行主序）
+  private rotationMatrix: number[] = [1, 0, 0, 0, 1, 0, 0, 0, 1];
+  private version: number = 0;
+
+  constructor(state: Partial<SplatCropBoxState> = {}) {
+    this.state = copyState(DEFAULT_SPLAT_CROP_BOX_STATE);
+    this.setState(state);
+  }
+
+  /**
+   * 包围盒外扩 margin（比例）作为裁剪盒（轴对齐）
+   */
+  static fromBoundingBox(box: BoundingBox, margin: number = 0): SplatCropBox {
+    const size = box.max.map((max, i) => Math.max((max - box.min[i]) * (1 + margin), MIN_CROP_BOX_SIZE)) as Vec3Tuple;
+    return new SplatCropBox({ center: [...box.center], size });
+  }
+
+  // ============================================
+  // 状态
+  // ============================================
+
+  getState(): SplatCropBoxState {
+    return copyState(this.state);
+  }
+
+  setState(state: Partial<SplatCropBoxState>): void {
+    this.state = copyState({ ...this.state, ...state });
+    this.state.size = this.state.size.map((s) => Math.max(Math.abs(s), MIN_CROP_BOX_SIZE)) as Vec3Tuple;
+    this.updateRotation();
+  }
+
+  setCenter(x: number, y: number, z: number): void {
+    this.setState({ center: [x, y, z] });
+  }
+
+  getCenter(): Vec3Tuple {
+    return [...this.state.center];
+  }
+
+  setSize(x: number, y: number, z: number): void {
+    this.setState({ size: [x, y, z] });
+  }
+
+  getSize(): Vec3Tuple {
+    return [...this.state.size];
+  }
+
+  /**
+   * 设置旋转（欧拉角，弧度）
+   */
+  setRotation(x: number, y: number, z: number): void {
+    this.setState({ rotation: [x, y, z] });
+  }
+
+  getRotation(): Vec3Tuple {
+    return [...this.state.rotation];
+  }
+
+  setInvert(invert: boolean): void {
+    this.setState({ invert });
+  }
+
+  isInverted(): boolean {
+    return this.state.invert;
+  }
+
+  /**
+   * 每次修改递增，用于判断是否需要重新上传
+   */
+  getVersion(): number {
+    return this.version;
+  }
+
+  // ============================================
+  // 手柄
+  // ============================================
+
+  /**
+   * 6 个面中心手柄
+   */
+  getHandles(): SplatCropBoxHandle[] {
+    return HANDLE_IDS.map((id) => {
+      const axis = handleAxis(id);
+      const sign = id[0] === "+" ? 1 : -1;
+      const local: Vec3Tuple = [0, 0, 0];
+      local[axis] = sign * 0.5;
+      const normal = this.rotateVector(axis === 0 ? [sign, 0, 0] : axis === 1 ? [0, sign, 0] : [0, 0, sign]);
+      return { id, axis, sign, position: this.toWorld(local), normal };
+    });
+  }
+
+  /**
+   * 拖动面手柄到世界空间点（投影到该面的法线上），对面保持不动
+   */
+  dragHandle(id: SplatCropBoxHandleId, point: ArrayLike<number>): void {
+    const axis = handleAxis(id);
+    const sign = id[0] === "+" ? 1 : -1;
+    const local = this.toLocal(point);
+    const half = this.state.size[axis] / 2;
+    // 对面在局部坐标中的位置（以尺寸为单位）
+    const opposite = -sign * half;
+    const target = local[axis] * this.state.size[axis];
+    const extent = Math.max((target - opposite) * sign, MIN_CROP_BOX_SIZE);
+    const shift: Vec3Tuple = [0, 0, 0];
+    shift[axis] = opposite + (sign * extent) / 2;
+    const [dx, dy, dz] = this.rotateVector(shift);
+    const size: Vec3Tuple = [...this.state.size];
+    size[axis] = extent;
+    const [cx, cy, cz] = this.state.center;
+    this.setState({ center: [cx + dx, cy + dy, cz + dz], size });
+  }
+
+  /**
+   * 8 个角点（世界空间），用于绘制线框
+   */
+  getCorners(): Vec3Tuple[] {
+    const corners: Vec3Tuple[] = [];
+    for (let i = 0; i < 8; i++) {
+      corners.push(this.toWorld([(i & 1 ? 0.5 : -0.5), (i & 2 ? 0.5 : -0.5), (i & 4 ? 0.5 : -0.5)]));
+    }
+    return corners;
+  }
+
+  // ============================================
+  // 判断
+  // ============================================
+
+  /**
+   * 世界空间点是否被保留（在盒内，反转时在盒外）
+   */
+  keeps(point: ArrayLike<number>): boolean {
+    const local = this.toLocal(point);
+    const inside = Math.abs(local[0]) <= 0.5 && Math.abs(local[1]) <= 0.5 && Math.abs(local[2]) <= 0.5;
+    return inside !== this.state.invert;
+  }
+
+  /**
+   * 找出被裁掉的 splat（位置经 modelMatrix 变换到世界空间后判断）
+   * @param positions 模型空间位置 [x,y,z, ...]
+   * @param modelMatrix 列主序模型矩阵
+   */
+  findCropped(positions: ArrayLike<number>, count: number, modelMatrix: ArrayLike<number>): Uint32Array {
+    const m = modelMatrix;
+    const cropped: number[] = [];
+    const world = [0, 0, 0];
+    for (let i = 0; i < count; i++) {
+      const x = positions[i * 3], y = positions[i * 3 + 1], z = positions[i * 3 + 2];
+      world[0] = m[0] * x + m[4] * y + m[8] * z + m[12];
+      world[1] = m[1] * x + m[5] * y + m[9] * z + m[13];
+      world[2] = m[2] * x + m[6] * y + m[10] * z + m[14];
+      if (!this.keeps(world)) cropped.push(i);
+    }
+    return Uint32Array.from(cropped);
+  }
+
+  /**
+   * 世界空间 -> 盒子单位空间（[-0.5, 0.5]³ 为盒内）的列主序矩阵
+   */
+  getWorldToBoxMatrix(out: Float32Array = new Float32Array(16)): Float32Array {
+    const r = this.rotationMatrix;
+    const [cx, cy, cz] = this.state.center;
+    out.fill(0);
+    for (let row = 0; row < 3; row++) {
+      const inv = 1 / this.state.size[row];
+      // 第 row 行 = Rᵀ 的第 row 行 / size = R 的第 row 列 / size
+      out[row] = r[row] * inv;
+      out[4 + row] = r[3 + row] * inv;
+      out[8 + row] = r[6 + row] * inv;
+      out[12 + row] = -(out[row] * cx + out[4 + row] * cy + out[8 + row] * cz);
+    }
+    out[15] = 1;
+    return out;
+  }
+
+  private toLocal(point: ArrayLike<number>): Vec3Tuple {
+    const r = this.rotationMatrix;
+    const dx = point[0] - this.state.center[0];
+    const dy = point[1] - this.state.center[1];
+    const dz = point[2] - this.state.center[2];
+    const [sx, sy, sz] = this.state.size;
+    return [
+      (r[0] * dx + r[3] * dy + r[6] * dz) / sx,
+      (r[1] * dx + r[4] * dy + r[7] * dz) / sy,
+      (r[2] * dx + r[5] * dy + r[8] * dz) / sz,
+    ];
+  }
+
+  private toWorld(local: ArrayLike<number>): Vec3Tuple {
+    const [sx, sy, sz] = this.state.size;
+    const [x, y, z] = this.rotateVector([local[0] * sx, local[1] * sy, local[2] * sz]);
+    return [x + this.state.center[0], y + this.state.center[1], z + this.state.center[2]];
+  }
+
+  private rotateVector(v: ArrayLike<number>): Vec3Tuple {
+    const r = this.rotationMatrix;
+    return [
+      r[0] * v[0] + r[1] * v[1] + r[2] * v[2],
+      r[3] * v[0] + r[4] * v[1] + r[5] * v[2],
+      r[6] * v[0] + r[7] * v[1] + r[8] * v[2],
+    ];
+  }
+
+  // 与 composeTransform 相同的欧拉角约定
+  private updateRotation(): void {
+    const [rx, ry, rz] = this.state.rotation;
+    const cx = Math.cos(rx), sx = Math.sin(rx);
+    const cy = Math.cos(ry), sy = Math.sin(ry);
+    const cz = Math.cos(rz), sz = Math.sin(rz);
+    this.rotationMatrix = [
+      cy * cz, sx * sy * cz - cx * sz, cx * sy * cz + sx * sz,
+      cy * sz, sx * sy * sz + cx * cz, cx * sy * sz - sx * cz,
+      -sy, sx * cy, cx * cy,
+    ];
+    this.version++;
+  }
+}
+
+/**
+ * 裁剪盒 uniform 数据：模型空间 -> 盒子单位空间矩阵 (16) + [启用, 反转, 0, 0]
+ * 在 CPU 端与模型矩阵合并，不受相机相对渲染的影响；null 表示不裁剪
+ */
+export function getCropBoxUniforms(
+  cropBox: SplatCropBox | null,
+  modelMatrix: Float32Array,
+  data: Float32Array = new Float32Array(20),
+): Float32Array {
+  data.fill(0);
+  if (!cropBox) {
+    return data;
+  }
+  const matrix = data.subarray(0, 16);
+  multiplyTransforms(cropBox.getWorldToBoxMatrix(matrix), modelMatrix, matrix);
+  data[16] = 1;
+  data[17] = cropBox.isInverted() ? 1 : 0;
+  return data;
+}
+
+/**
+ * 裁剪盒 shader 函数（读取 uniforms.cropBox / cropParams）
+ */
+export const splatCropBoxShader = /* wgsl */ `
+// 软裁剪：splat 中心（模型空间）不在保留区域时返回 true
+fn isSplatCropped(mean: vec3<f32>) -> bool {
+  if uniforms.cropParams.x == 0.0 { return false; }
+  let local = (uniforms.cropBox * vec4<f32>(mean, 1.0)).xyz;
+  let inside = all(abs(local) <= vec3<f32>(0.5));
+  return inside == (uniforms.cropParams.y != 0.0);
+}
+`;
+
+function handleAxis(id: SplatCropBoxHandleId): number {
+  return id[1] === "x" ? 0 : id[1] === "y" ? 1 : 2;
+}
+
+function copyState(state: SplatCropBoxState): SplatCropBoxState {
+  return {
+    center: [...state.center],
+    size: [...state.size],
+    rotation: [...state.rotation],
+    invert: state.invert,
+  };
+}
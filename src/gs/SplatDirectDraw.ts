@@ -0,0 +1,25 @@
+/**
+ * SplatDirectDraw - 不使用 indirect 绘制时的实例数量
+ *
+ * 部分 WebGPU 实现（兼容模式适配器）的 drawIndirect 不稳定或很慢。这时渲染器改用 draw：
+ * 可见数量由排序器异步回读（晚一帧或更多），绘制时按回读值加余量作为实例数；
+ * 排序器每次排序把本帧的可见数量拷贝到 uniform，顶点着色器剔除超出的实例，
+ * 因此回读值偏大只会多跑顶点着色器，偏小（相机快速移动时）才会少画最后的几个 splat
+ */
+
+/** 回读数量的余量比例 */
+export const DIRECT_DRAW_HEADROOM = 1.25;
+
+/** 回读数量的最小余量 */
+export const DIRECT_DRAW_MARGIN = 1024;
+
+/**
+ * 直接绘制的实例数量
+ * @param visibleCount 最近一次回读的可见数量，尚未读回时为 -1（绘制全部）
+ */
+export function getDirectDrawInstanceCount(visibleCount: number, splatCount: number): number {
+  if (visibleCount < 0) {
+    return splatCount;
+  }
+  return Math.min(splatCount, Math.ceil(visibleCount * DIRECT_DRAW_HEADROOM) + DIRECT_DRAW_MARGIN);
+}
@@ -0,0 +1,120 @@
+/**
+ * SplatDirtyRanges - 训练等整批更新时记录哪些 splat 被修改
+ *
+ * 训练器每一步通常只更新一部分 splat，但每次都用 setCompactData 传入完整数据会重新打包、
+ * 重新分配并上传整个数据集。这里收集被修改的索引（区间、索引列表或位掩码），
+ * updateCompactData 只把这些区间复制到现有数据并标记脏块，渲染器按块上传
+ */
+
+/**
+ * 被修改的 splat 集合（内部以有序、合并后的 [start, end) 区间保存）
+ */
+export class SplatDirtyRanges {
+  // 扁平的 start, end 对，可能未排序 / 重叠，读取时合并
+  private pairs: number[] = [];
+  private merged: [number, number][] | null = [];
+  private positionsChanged: boolean = false;
+
+  /**
+   * 标记区间 [start, end)
+   * @param positionChanged 位置变化时渲染器还会重建包围盒和八叉树
+   */
+  addRange(start: number, end: number, positionChanged: boolean = false): this {
+    if (end > start) {
+      this.pairs.push(start, end);
+      this.merged = null;
+    }
+    this.positionsChanged ||= positionChanged;
+    return this;
+  }
+
+  /**
+   * 标记索引列表（无需有序，连续的索引合并为区间）
+   */
+  addIndices(indices: ArrayLike<number>, positionChanged: boolean = false): this {
+    const sorted = Uint32Array.from(indices).sort();
+    let i = 0;
+    while (i < sorted.length) {
+      const start = sorted[i];
+      let end = start + 1;
+      while (++i < sorted.length && sorted[i] <= end) {
+        end = Math.max(end, sorted[i] + 1);
+      }
+      this.addRange(start, end);
+    }
+    this.positionsChanged ||= positionChanged;
+    return this;
+  }
+
+  /**
+   * 标记位掩码中非零的位置（每个 splat 一个元素）
+   */
+  addMask(mask: ArrayLike<number>, positionChanged: boolean = false): this {
+    let i = 0;
+    while (i < mask.length) {
+      if (!mask[i]) {
+        i++;
+        continue;
+      }
+      const start = i;
+      while (i < mask.length && mask[i]) i++;
+      this.addRange(start, i);
+    }
+    this.positionsChanged ||= positionChanged;
+    return this;
+  }
+
+  /**
+   * 获取有序、合并后的区间列表 [start, end)
+   */
+  getRanges(): [number, number][] {
+    if (!this.merged) {
+      const ranges: [number, number][] = [];
+      for (let i = 0; i < this.pairs.length; i += 2) {
+        ranges.push([this.pairs[i], this.pairs[i + 1]]);
+      }
+      ranges.sort((a, b) => a[0] - b[0]);
+      const merged: [number, number][] = [];
+      for (const range of ranges) {
+        const last = merged[merged.length - 1];
+        if (last && range[0] <= last[1]) {
+          last[1] = Math.max(last[1], range[1]);
+        } else {
+          merged.push([range[0], range[1]]);
+        }
+      }
+      this.merged = merged;
+    }
+    return this.merged;
+  }
+
+  /**
+   * 被修改的 splat 数量
+   */
+  getCount(): number {
+    return this.getRanges().reduce((sum, [start, end]) => sum + end - start, 0);
+  }
+
+  /**
+   * 是否修改过位置
+   */
+  hasPositionChanges(): boolean {
+    return this.positionsChanged;
+  }
+
+  /**
+   * 是否没有任何修改
+   */
+  isEmpty(): boolean {
+    return this.pairs.length === 0;
+  }
+
+  /**
+   * 清空（训练器可在每一步之后复用同一个对象）
+   */
+  clear(): void {
+    this.pairs.length = 0;
+    this.merged = [];
+    this.positionsChanged = false;
+  }
+}
@@ -0,0 +1,798 @@
+/**
+ * SplatEditHistory - splat 编辑的撤销 / 重做
+ *
+ * 选择、删除、变换、复制、改色都记录为可逆命令：
+ * - 按索引的编辑（选择、删除、改色）只保存受影响 splat 的前后值
+ * - 改变 splat 数量或顺序的编辑（复制、压缩）保存编辑前的完整数据快照，重做时重新执行
+ * 历史按保存的字节数和步数限制，超出时丢弃最早的记录。
+ * 命令绑定记录时的数据布局，数据在历史之外被替换（重新加载、setCompactData 等）后撤销会失败并清空历史
+ */
+
+import type { IGSSplatRenderer } from "./IGSSplatRenderer";
+import type { CompactSplatData } from "./PLYLoaderMobile";
+import type { SplatSelection } from "./SplatSelection";
+import { cloneCompactSplatData } from "./GaussianSplats";
+import { gatherSplatData } from "./SplatSegments";
+import { mirrorSplatData } from "./SplatMirror";
+import type { SplatMirrorPlane } from "./SplatMirror";
+import type { Vec3Tuple } from "../types";
+
+/**
+ * 编辑历史配置
+ */
+export interface SplatEditHistoryConfig {
+  /** 历史保存的数据上限（字节），默认 256MB */
+  maxBytes: number;
+  /** 最多保留的步数，默认 100 */
+  maxSteps: number;
+}
+
+export const DEFAULT_SPLAT_EDIT_HISTORY_CONFIG: SplatEditHistoryConfig = {
+  maxBytes: 256 * 1024 * 1024,
+  maxSteps: 100,
+};
+
+/**
+ * 可逆编辑命令
+ */
+export interface SplatEditCommand {
+  readonly label: string;
+  /** 命令保存的数据占用的字节数 */
+  readonly byteSize: number;
+  /** 撤销，返回 false 表示数据已在历史之外被修改 */
+  undo(): boolean;
+  /** 重做，返回 false 表示数据已在历史之外被修改 */
+  redo(): boolean;
+}
+
+/**
+ * 实体变换
+ */
+export interface SplatTransformState {
+  position: Vec3Tuple;
+  rotation: Vec3Tuple;
+  scale: Vec3Tuple;
+  pivot: Vec3Tuple;
+}
+
+/**
+ * 读取实体当前变换（用于 recordTransform 的编辑前状态）
+ */
+export function captureSplatTransform(renderer: IGSSplatRenderer): SplatTransformState {
+  return {
+    position: copyVec3(renderer.getPosition()),
+    rotation: copyVec3(renderer.getRotation()),
+    scale: copyVec3(renderer.getScale()),
+    pivot: copyVec3(renderer.getPivot()),
+  };
+}
+
+// 结构编辑前的完整状态
+interface SplatSnapshot {
+  data: CompactSplatData;
+  deleted: Uint32Array;
+  selected: Uint32Array;
+  scalars: Float32Array | null;
+  scalarRange: [number, number] | undefined;
+  byteSize: number;
+}
+
+/**
+ * splat 编辑历史
+ */
+export class SplatEditHistory {
+  private config: SplatEditHistoryConfig;
+  private undoStack: SplatEditCommand[] = [];
+  private redoStack: SplatEditCommand[] = [];
+  private byteSize: number = 0;
+  // 数据布局标识：撤销结构编辑恢复出的数据沿用编辑前的标识
+  private layouts: WeakMap<object, object> = new WeakMap();
+
+  constructor(config: Partial<SplatEditHistoryConfig> = {}) {
+    this.config = { ...DEFAULT_SPLAT_EDIT_HISTORY_CONFIG, ...config };
+  }
+
+  /**
+   * 修改配置，超出新上限的最早记录会被丢弃
+   */
+  setConfig(config: Partial<SplatEditHistoryConfig>): void {
+    this.config = { ...this.config, ...config };
+    this.trim();
+  }
+
+  getConfig(): SplatEditHistoryConfig {
+    return { ...this.config };
+  }
+
+  // ============================================
+  // 撤销 / 重做
+  // ============================================
+
+  /**
+   * 记录已经执行过的命令（清空重做栈）
+   */
+  push(command: SplatEditCommand): void {
+    for (const c of this.redoStack) {
+      this.byteSize -= c.byteSize;
+    }
+    this.redoStack = [];
+    this.undoStack.push(command);
+    this.byteSize += command.byteSize;
+    this.trim();
+  }
+
+  /**
+   * 撤销最近一次编辑
+   * @returns 是否撤销成功（数据已在历史之外被修改时清空历史并返回 false）
+   */
+  undo(): boolean {
+    const command = this.undoStack.pop();
+    if (!command) {
+      return false;
+    }
+    if (!command.undo()) {
+      console.warn(`[SplatEditHistory] 无法撤销 "${command.label}"：数据已在编辑历史之外被修改，清空历史`);
+      this.clear();
+      return false;
+    }
+    this.redoStack.push(command);
+    return true;
+  }
+
+  /**
+   * 重做最近一次撤销的编辑
+   * @returns 是否重做成功
+   */
+  redo(): boolean {
+    const command = this.redoStack.pop();
+    if (!command) {
+      return false;
+    }
+    if (!command.redo()) {
+      console.warn(`[SplatEditHistory] 无法重做 "${command.label}"：数据已在编辑历史之外被修改，清空历史`);
+      this.clear();
+      return false;
+    }
+    this.undoStack.push(command);
+    return true;
+  }
+
+  canUndo(): boolean {
+    return this.undoStack.length > 0;
+  }
+
+  canRedo(): boolean {
+    return this.redoStack.length > 0;
+  }
+
+  /**
+   * 下一次撤销的命令名称
+   */
+  getUndoLabel(): string | null {
+    return this.undoStack[this.undoStack.length - 1]?.label ?? null;
+  }
+
+  /**
+   * 下一次重做的命令名称
+   */
+  getRedoLabel(): string | null {
+    return this.redoStack[this.redoStack.length - 1]?.label ?? null;
+  }
+
+  getUndoCount(): number {
+    return this.undoStack.length;
+  }
+
+  getRedoCount(): number {
+    return this.redoStack.length;
+  }
+
+  /**
+   * 历史保存的数据占用的字节数
+   */
+  getByteSize(): number {
+    return this.byteSize;
+  }
+
+  /**
+   * 清空历史
+   */
+  clear(): void {
+    this.undoStack = [];
+    this.redoStack = [];
+    this.byteSize = 0;
+  }
+
+  // ============================================
+  // 可撤销的编辑
+  // ============================================
+
+  /**
+   * 修改选择集并记录
+   * @param edit 对选择集的修改，例如 (s) => s.selectWhere(...)
+   * @returns 选中状态发生变化的 splat 数量
+   */
+  editSelection(renderer: IGSSplatRenderer, edit: (selection: SplatSelection) => void, label: string = "selection"): number {
+    const command = this.makeSelection(renderer, edit, label);
+    if (command) {
+      this.push(command);
+    }
+    return command ? command.changed : 0;
+  }
+
+  /**
+   * 删除 splat 并记录（达到压缩阈值时的自动压缩与删除合并为一步）
+   * @returns 新删除的数量
+   */
+  deleteSplats(renderer: IGSSplatRenderer, indices: ArrayLike<number>, label: string = "delete"): number {
+    const deletion = this.makeDelete(renderer, indices, label);
+    if (!deletion) {
+      return 0;
+    }
+    this.push(this.withAutoCompaction(renderer, label, [deletion]));
+    return deletion.removed;
+  }
+
+  /**
+   * 删除选中的 splat 并清空选择，记录为一步
+   * @param compact 是否立即物理移除（压缩数据、缩小 buffer 和排序量），否则只在达到压缩阈值时压缩
+   * @returns 新删除的数量
+   */
+  deleteSelected(renderer: IGSSplatRenderer, label: string = "delete", compact: boolean = false): number {
+    const selection = renderer.getSelection?.();
+    if (!selection || selection.getSelectedCount() === 0) {
+      return 0;
+    }
+    const deletion = this.makeDelete(renderer, selection.getSelectedIndices(), label);
+    if (!deletion) {
+      return 0;
+    }
+    const commands: SplatEditCommand[] = [deletion];
+    const clear = this.makeSelection(renderer, (s) => s.clear(), label);
+    if (clear) {
+      commands.push(clear);
+    }
+    this.push(this.withAutoCompaction(renderer, label, commands, compact));
+    return deletion.removed;
+  }
+
+  /**
+   * 压缩已删除的 splat 并记录（保存压缩前的快照）
+   * @returns 移除的数量
+   */
+  compactDeleted(renderer: IGSSplatRenderer, label: string = "compact"): number {
+    const removed = renderer.getDeletedCount?.() ?? 0;
+    if (removed === 0 || !renderer.compactDeleted) {
+      return 0;
+    }
+    const command = this.makeStructural(renderer, label, () => renderer.compactDeleted?.());
+    if (!command) {
+      return 0;
+    }
+    this.push(command);
+    return removed;
+  }
+
+  /**
+   * 记录一次已完成的变换（例如 gizmo 拖动结束时）
+   * @param before 编辑前由 captureSplatTransform 读取的变换
+   * @returns 变换是否有变化
+   */
+  recordTransform(renderer: IGSSplatRenderer, before: SplatTransformState, label: string = "transform"): boolean {
+    const after = captureSplatTransform(renderer);
+    if (transformEquals(before, after)) {
+      return false;
+    }
+    this.push({
+      label,
+      byteSize: 0,
+      undo: () => {
+        applySplatTransform(renderer, before);
+        return true;
+      },
+      redo: () => {
+        applySplatTransform(renderer, after);
+        return true;
+      },
+    });
+    return true;
+  }
+
+  /**
+   * 设置变换并记录（只修改传入的字段）
+   */
+  setTransform(renderer: IGSSplatRenderer, transform: Partial<SplatTransformState>, label: string = "transform"): boolean {
+    const before = captureSplatTransform(renderer);
+    applySplatTransform(renderer, { ...before, ...transform });
+    return this.recordTransform(renderer, before, label);
+  }
+
+  /**
+   * 修改 splat 颜色并记录
+   * @param color 基础颜色 RGB (0~1)，或按索引和原颜色计算新颜色的函数
+   * @returns 修改的数量
+   */
+  recolorSplats(
+    renderer: IGSSplatRenderer,
+    indices: ArrayLike<number>,
+    color: Vec3Tuple | ((index: number, color: Vec3Tuple) => Vec3Tuple),
+    label: string = "recolor",
+  ): number {
+    const splats = renderer.getSplats?.();
+    if (!splats) {
+      return 0;
+    }
+    const layout = this.layoutOf(renderer);
+    const targets = uniqueIndices(indices, splats.count, null);
+    if (targets.length === 0) {
+      return 0;
+    }
+    const colors = splats.getData().colors;
+    const before = new Float32Array(targets.length * 3);
+    const after = new Float32Array(targets.length * 3);
+    for (let k = 0; k < targets.length; k++) {
+      const i = targets[k];
+      const old: Vec3Tuple = [colors[i * 3], colors[i * 3 + 1], colors[i * 3 + 2]];
+      before.set(old, k * 3);
+      after.set(typeof color === "function" ? color(i, old) : color, k * 3);
+    }
+    this.writeColors(renderer, layout, targets, after);
+    this.push({
+      label,
+      byteSize: targets.byteLength + before.byteLength + after.byteLength,
+      undo: () => this.writeColors(renderer, layout, targets, before),
+      redo: () => this.writeColors(renderer, layout, targets, after),
+    });
+    return targets.length;
+  }
+
+  /**
+   * 修改 splat 不透明度并记录（跳过已删除的 splat）
+   * @param opacity 新的不透明度 (0~1)，或按索引和原不透明度计算新值的函数
+   * @returns 修改的数量
+   */
+  setSplatOpacities(
+    renderer: IGSSplatRenderer,
+    indices: ArrayLike<number>,
+    opacity: number | ((index: number, opacity: number) => number),
+    label: string = "opacity",
+  ): number {
+    const splats = renderer.getSplats?.();
+    if (!splats) {
+      return 0;
+    }
+    const layout = this.layoutOf(renderer);
+    const targets = uniqueIndices(indices, splats.count, renderer.getDeletedMask?.() ?? null);
+    if (targets.length === 0) {
+      return 0;
+    }
+    const opacities = splats.getData().opacities;
+    const before = Float32Array.from(targets, (i) => opacities[i]);
+    const after = Float32Array.from(targets, (i) => (typeof opacity === "function" ? opacity(i, opacities[i]) : opacity));
+    this.writeOpacities(renderer, layout, targets, after);
+    this.push({
+      label,
+      byteSize: targets.byteLength + before.byteLength + after.byteLength,
+      undo: () => this.writeOpacities(renderer, layout, targets, before),
+      redo: () => this.writeOpacities(renderer, layout, targets, after),
+    });
+    return targets.length;
+  }
+
+  /**
+   * 复制 splat 并记录：副本追加到末尾并成为新的选择（保存复制前的快照）
+   * @param offset 副本的位置偏移（局部坐标）
+   * @returns 复制的数量
+   */
+  duplicateSplats(
+    renderer: IGSSplatRenderer,
+    indices: ArrayLike<number>,
+    offset: Vec3Tuple = [0, 0, 0],
+    label: string = "duplicate",
+  ): number {
+    const splats = renderer.getSplats?.();
+    if (!splats) {
+      return 0;
+    }
+    const targets = uniqueIndices(indices, splats.count, renderer.getDeletedMask?.() ?? null);
+    if (targets.length === 0) {
+      return 0;
+    }
+    const command = this.makeStructural(renderer, label, () => duplicateInto(renderer, targets, (data, first) => {
+      for (let i = first; i < data.count; i++) {
+        data.positions[i * 3] += offset[0];
+        data.positions[i * 3 + 1] += offset[1];
+        data.positions[i * 3 + 2] += offset[2];
+      }
+    }));
+    if (!command) {
+      return 0;
+    }
+    this.push(command);
+    return targets.length;
+  }
+
+  /**
+   * 复制 splat 并镜像到平面另一侧，记录为一步：副本追加到末尾并成为新的选择
+   * @param plane 镜像平面（局部坐标）
+   * @returns 复制的数量
+   */
+  mirrorSplats(
+    renderer: IGSSplatRenderer,
+    indices: ArrayLike<number>,
+    plane: SplatMirrorPlane,
+    label: string = "mirror",
+  ): number {
+    const splats = renderer.getSplats?.();
+    if (!splats) {
+      return 0;
+    }
+    const targets = uniqueIndices(indices, splats.count, renderer.getDeletedMask?.() ?? null);
+    if (targets.length === 0) {
+      return 0;
+    }
+    const command = this.makeStructural(
+      renderer,
+      label,
+      () => duplicateInto(renderer, targets, (data, first) => mirrorSplatData(data, plane, first)),
+    );
+    if (!command) {
+      return 0;
+    }
+    this.push(command);
+    return targets.length;
+  }
+
+  /**
+   * 复制选中的 splat 并记录
+   * @returns 复制的数量
+   */
+  duplicateSelected(renderer: IGSSplatRenderer, offset: Vec3Tuple = [0, 0, 0], label: string = "duplicate"): number {
+    const selection = renderer.getSelection?.();
+    if (!selection || selection.getSelectedCount() === 0) {
+      return 0;
+    }
+    return this.duplicateSplats(renderer, selection.getSelectedIndices(), offset, label);
+  }
+
+  // ============================================
+  // 命令
+  // ============================================
+
+  private makeSelection(
+    renderer: IGSSplatRenderer,
+    edit: (selection: SplatSelection) => void,
+    label: string,
+  ): (SplatEditCommand & { changed: number }) | null {
+    const selection = renderer.getSelection?.();
+    if (!selection) {
+      return null;
+    }
+    const layout = this.layoutOf(renderer);
+    const before = selection.getMask().slice();
+    edit(selection);
+    const after = selection.getMask();
+    // 锁定或隐藏分组中的 splat 不能被选中
+    const groups = renderer.getGroups?.();
+    if (groups) {
+      const blocked: number[] = [];
+      for (let i = 0; i < after.length; i++) {
+        if (after[i] !== 0 && before[i] === 0 && !groups.isSelectable(i)) blocked.push(i);
+      }
+      if (blocked.length > 0) selection.selectIndices(blocked, "subtract");
+    }
+    let changed = 0;
+    for (let i = 0; i < after.length; i++) {
+      if ((before[i] !== 0) !== (after[i] !== 0)) changed++;
+    }
+    if (changed === 0) {
+      return null;
+    }
+    const toggled = new Uint32Array(changed);
+    for (let i = 0, n = 0; i < after.length; i++) {
+      if ((before[i] !== 0) !== (after[i] !== 0)) toggled[n++] = i;
+    }
+    // 撤销和重做都是翻转这些 splat 的选中状态
+    const toggle = (): boolean => {
+      const current = this.isLayout(renderer, layout) ? renderer.getSelection?.() : null;
+      if (!current) {
+        return false;
+      }
+      const flip = new Uint8Array(current.count);
+      for (const i of toggled) flip[i] = 1;
+      const mask = current.getMask();
+      current.selectWhere((i) => (mask[i] !== 0) !== (flip[i] !== 0));
+      return true;
+    };
+    return { label, byteSize: toggled.byteLength, undo: toggle, redo: toggle, changed };
+  }
+
+  private makeDelete(
+    renderer: IGSSplatRenderer,
+    indices: ArrayLike<number>,
+    label: string,
+  ): (SplatEditCommand & { removed: number }) | null {
+    const splats = renderer.getSplats?.();
+    if (!splats || !renderer.deleteSplats || !renderer.restoreSplats) {
+      return null;
+    }
+    const layout = this.layoutOf(renderer);
+    const targets = uniqueIndices(indices, splats.count, renderer.getDeletedMask?.() ?? null);
+    if (targets.length === 0) {
+      return null;
+    }
+    const source = splats.getData().opacities;
+    const opacities = Float32Array.from(targets, (i) => source[i]);
+    withoutCompaction(renderer, () => renderer.deleteSplats?.(targets));
+    return {
+      label,
+      byteSize: targets.byteLength + opacities.byteLength,
+      undo: () => {
+        if (!this.isLayout(renderer, layout)) return false;
+        renderer.restoreSplats?.(targets, opacities);
+        return true;
+      },
+      redo: () => {
+        if (!this.isLayout(renderer, layout)) return false;
+        withoutCompaction(renderer, () => renderer.deleteSplats?.(targets));
+        return true;
+      },
+      removed: targets.length,
+    };
+  }
+
+  /**
+   * 改变 splat 数量或顺序的编辑：保存编辑前的快照，撤销时恢复快照，重做时重新执行 apply
+   */
+  private makeStructural(renderer: IGSSplatRenderer, label: string, apply: () => void): SplatEditCommand | null {
+    const splats = renderer.getSplats?.();
+    if (!splats) {
+      return null;
+    }
+    const before = this.layoutOf(renderer);
+    const snapshot = captureSnapshot(renderer, splats.getData());
+    apply();
+    const after = this.layoutOf(renderer);
+    if (after === before) {
+      return null;
+    }
+    return {
+      label,
+      byteSize: snapshot.byteSize,
+      undo: () => {
+        if (!this.isLayout(renderer, after)) return false;
+        restoreSnapshot(renderer, snapshot);
+        this.setLayout(renderer, before);
+        return true;
+      },
+      redo: () => {
+        if (!this.isLayout(renderer, before)) return false;
+        apply();
+        this.setLayout(renderer, after);
+        return true;
+      },
+    };
+  }
+
+  // 删除后达到压缩阈值时执行压缩，与之前的命令合并为一步
+  private withAutoCompaction(
+    renderer: IGSSplatRenderer,
+    label: string,
+    commands: SplatEditCommand[],
+    force: boolean = false,
+  ): SplatEditCommand {
+    const threshold = renderer.getCompactionThreshold?.() ?? 0;
+    const deleted = renderer.getDeletedCount?.() ?? 0;
+    if (deleted > 0 && (force || (threshold > 0 && deleted >= renderer.getSplatCount() * threshold))) {
+      const compact = this.makeStructural(renderer, label, () => renderer.compactDeleted?.());
+      if (compact) {
+        commands.push(compact);
+      }
+    }
+    if (commands.length === 1) {
+      return commands[0];
+    }
+    return {
+      label,
+      byteSize: commands.reduce((sum, c) => sum + c.byteSize, 0),
+      undo: () => {
+        for (let i = commands.length - 1; i >= 0; i--) {
+          if (!commands[i].undo()) return false;
+        }
+        return true;
+      },
+      redo: () => commands.every((c) => c.redo()),
+    };
+  }
+
+  private writeColors(renderer: IGSSplatRenderer, layout: object, indices: Uint32Array, values: Float32Array): boolean {
+    const splats = renderer.getSplats?.();
+    if (!splats || !this.isLayout(renderer, layout)) {
+      return false;
+    }
+    const colors = splats.getDataMut().colors;
+    for (let k = 0; k < indices.length; k++) {
+      const i = indices[k];
+      colors.set(values.subarray(k * 3, k * 3 + 3), i * 3);
+      splats.markDirty(i);
+    }
+    return true;
+  }
+
+  private writeOpacities(renderer: IGSSplatRenderer, layout: object, indices: Uint32Array, values: Float32Array): boolean {
+    const splats = renderer.getSplats?.();
+    if (!splats || !this.isLayout(renderer, layout)) {
+      return false;
+    }
+    const opacities = splats.getDataMut().opacities;
+    for (let k = 0; k < indices.length; k++) {
+      opacities[indices[k]] = values[k];
+      splats.markDirty(indices[k]);
+    }
+    return true;
+  }
+
+  private layoutOf(renderer: IGSSplatRenderer): object {
+    const key = renderer.getSplats?.() ?? renderer;
+    let layout = this.layouts.get(key);
+    if (!layout) {
+      layout = {};
+      this.layouts.set(key, layout);
+    }
+    return layout;
+  }
+
+  private setLayout(renderer: IGSSplatRenderer, layout: object): void {
+    this.layouts.set(renderer.getSplats?.() ?? renderer, layout);
+  }
+
+  private isLayout(renderer: IGSSplatRenderer, layout: object): boolean {
+    return this.layoutOf(renderer) === layout;
+  }
+
+  // 丢弃超出步数或字节上限的最早记录
+  private trim(): void {
+    while (
+      this.undoStack.length > 0 &&
+      (this.undoStack.length > this.config.maxSteps || this.byteSize > this.config.maxBytes)
+    ) {
+      this.byteSize -= this.undoStack.shift()!.byteSize;
+    }
+  }
+}
+
+function copyVec3(v: Vec3Tuple): Vec3Tuple {
+  return [v[0], v[1], v[2]];
+}
+
+function transformEquals(a: SplatTransformState, b: SplatTransformState): boolean {
+  const keys = ["position", "rotation", "scale", "pivot"] as const;
+  return keys.every((key) => a[key][0] === b[key][0] && a[key][1] === b[key][1] && a[key][2] === b[key][2]);
+}
+
+/**
+ * 写回 captureSplatTransform 读取的变换
+ */
+export function applySplatTransform(renderer: IGSSplatRenderer, state: SplatTransformState): void {
+  renderer.setPivot(...state.pivot);
+  renderer.setScale(...state.scale);
+  renderer.setRotation(...state.rotation);
+  renderer.setPosition(...state.position);
+}
+
+// 去重并过滤越界和已删除的索引
+function uniqueIndices(indices: ArrayLike<number>, count: number, deleted: Uint8Array | null): Uint32Array {
+  const seen = new Uint8Array(count);
+  const result: number[] = [];
+  for (let k = 0; k < indices.length; k++) {
+    const i = indices[k];
+    if (i < 0 || i >= count || seen[i] || deleted?.[i]) continue;
+    seen[i] = 1;
+    result.push(i);
+  }
+  return Uint32Array.from(result);
+}
+
+// 临时关闭自动压缩，撤销 / 重做的删除不能改变索引
+function withoutCompaction(renderer: IGSSplatRenderer, fn: () => void): void {
+  const threshold = renderer.getCompactionThreshold?.() ?? 0;
+  renderer.setCompactionThreshold?.(0);
+  try {
+    fn();
+  } finally {
+    renderer.setCompactionThreshold?.(threshold);
+  }
+}
+
+function captureSnapshot(renderer: IGSSplatRenderer, source: CompactSplatData): SplatSnapshot {
+  const data = cloneCompactSplatData(source);
+  const labels = renderer.getLabels?.();
+  if (labels) {
+    data.labels = Uint32Array.from(labels.ids);
+  }
+  const deletedMask = renderer.getDeletedMask?.() ?? null;
+  const deleted = deletedMask ? maskIndices(deletedMask) : new Uint32Array(0);
+  const selected = renderer.getSelection?.()?.getSelectedIndices() ?? new Uint32Array(0);
+  const scalars = renderer.getScalarAttribute?.()?.slice() ?? null;
+  const arrays = [
+    data.positions, data.scales, data.rotations, data.colors, data.opacities,
+    data.shCoeffs, data.labels, data.emissive, deleted, selected, scalars,
+  ];
+  return {
+    data,
+    deleted,
+    selected,
+    scalars,
+    scalarRange: scalars ? renderer.getScalarRange?.() : undefined,
+    byteSize: arrays.reduce((sum, a) => sum + (a?.byteLength ?? 0), 0),
+  };
+}
+
+function restoreSnapshot(renderer: IGSSplatRenderer, snapshot: SplatSnapshot): void {
+  renderer.setCompactData(cloneCompactSplatData(snapshot.data));
+  if (snapshot.scalars) {
+    renderer.setScalarAttribute?.(snapshot.scalars.slice(), snapshot.scalarRange);
+  }
+  if (snapshot.deleted.length > 0) {
+    withoutCompaction(renderer, () => renderer.deleteSplats?.(snapshot.deleted));
+  }
+  selectIndices(renderer.getSelection?.() ?? null, snapshot.selected);
+}
+
+// 把 indices 指向的 splat 复制到末尾（modify 修改从 first 开始的副本），保留原有的删除标记和标量属性，并选中副本
+function duplicateInto(
+  renderer: IGSSplatRenderer,
+  indices: Uint32Array,
+  modify: (data: CompactSplatData, first: number) => void,
+): void {
+  const splats = renderer.getSplats?.();
+  if (!splats) {
+    return;
+  }
+  const count = splats.count;
+  const order = new Uint32Array(count + indices.length);
+  for (let i = 0; i < count; i++) order[i] = i;
+  order.set(indices, count);
+
+  const data = gatherSplatData(splats.getData(), order);
+  const labels = renderer.getLabels?.();
+  if (labels) {
+    data.labels = Uint32Array.from(order, (i) => labels.ids[i]);
+  }
+  modify(data, count);
+  const source = renderer.getScalarAttribute?.();
+  const scalars = source ? Float32Array.from(order, (i) => source[i]) : null;
+  const scalarRange = scalars ? renderer.getScalarRange?.() : undefined;
+  const deletedMask = renderer.getDeletedMask?.();
+  const deleted = deletedMask ? maskIndices(deletedMask) : null;
+
+  renderer.setCompactData(data);
+  if (scalars) {
+    renderer.setScalarAttribute?.(scalars, scalarRange);
+  }
+  if (deleted && deleted.length > 0) {
+    withoutCompaction(renderer, () => renderer.deleteSplats?.(deleted));
+  }
+  renderer.getSelection?.()?.selectWhere((i) => i >= count);
+}
+
+function maskIndices(mask: Uint8Array): Uint32Array {
+  let n = 0;
+  for (let i = 0; i < mask.length; i++) {
+    if (mask[i] !== 0) n++;
+  }
+  const result = new Uint32Array(n);
+  for (let i = 0, k = 0; i < mask.length; i++) {
+    if (mask[i] !== 0) result[k++] = i;
+  }
+  return result;
+}
+
+function selectIndices(selection: SplatSelection | null, indices: Uint32Array): void {
+  if (!selection || indices.length === 0) {
+    return;
+  }
+  const mask = new Uint8Array(selection.count);
+  for (const i of indices) mask[i] = 1;
+  selection.selectWhere((i) => mask[i] !== 0);
+}
@@ -0,0 +1,256 @@
+/**
+ * SplatExternalBuffers - 使用外部 GPU buffer 中的 splat 数据
+ *
+ * 训练循环等在同一个 GPUDevice 上持有逐属性的 buffer（位置 / 旋转 / 缩放 / 颜色 / 不透明度 / SH），
+ * 由 compute pass 直接打包为渲染器的 splat buffer 布局，不经过 CPU 打包和上传。
+ * 每个属性为紧密排列的 f32 数组，可以指定起始偏移（需要满足 minStorageBufferOffsetAlignment）。
+ * raw 为 true 时输入是训练参数（log 缩放、logit 不透明度、SH DC 系数、未归一化的四元数），打包时激活
+ */
+
+import { PipelineCache } from "../core/PipelineCache";
+import { getBuffersByteSize } from "./SplatMemoryBudget";
+import type { Vec3Tuple } from "../types";
+
+/**
+ * 外部 buffer 中的一个属性
+ */
+export interface SplatExternalBuffer {
+  buffer: GPUBuffer;
+  /** 起始字节偏移，默认 0 */
+  offset?: number;
+}
+
+/**
+ * 外部 splat 数据
+ */
+export interface SplatExternalBuffers {
+  count: number;
+  /** xyz，每个 splat 3 个 f32 */
+  positions: SplatExternalBuffer;
+  /** 四元数 wxyz，每个 splat 4 个 f32 */
+  rotations: SplatExternalBuffer;
+  /** 缩放 xyz，每个 splat 3 个 f32 */
+  scales: SplatExternalBuffer;
+  /** DC 颜色 rgb，每个 splat 3 个 f32 */
+  colors: SplatExternalBuffer;
+  /** 不透明度，每个 splat 1 个 f32 */
+  opacities: SplatExternalBuffer;
+  /** 高阶 SH 系数，每个 splat 45 个 f32（布局与 CompactSplatData.shCoeffs 相同），可选 */
+  shCoeffs?: SplatExternalBuffer;
+  /** 输入为未激活的训练参数，默认 false（与 CompactSplatData 的约定相同） */
+  raw?: boolean;
+  /** 包围盒（模型空间），没有 CPU 数据时用于相机取景，可选 */
+  bounds?: { min: Vec3Tuple; max: Vec3Tuple };
+}
+
+const WORKGROUP_SIZE = 256;
+const SPLAT_FLOAT_COUNT = 64;
+const SH_COEFF_COUNT = 45;
+
+const packShader = /* wgsl */ `
+struct PackParams {
+  count: u32,
+  raw: u32,
+  hasSH: u32,
+  _pad: u32,
+}
+
+@group(0) @binding(0) var<uniform> params: PackParams;
+@group(0) @binding(1) var<storage, read> positions: array<f32>;
+@group(0) @binding(2) var<storage, read> rotations: array<f32>;
+@group(0) @binding(3) var<storage, read> scales: array<f32>;
+@group(0) @binding(4) var<storage, read> colors: array<f32>;
+@group(0) @binding(5) var<storage, read> opacities: array<f32>;
+@group(0) @binding(6) var<storage, read> shCoeffs: array<f32>;
+@group(0) @binding(7) var<storage, read_write> splats: array<f32>;
+
+const SH_C0: f32 = 0.28209479177387814;
+
+@compute @workgroup_size(${WORKGROUP_SIZE})
+fn packSplats(@builtin(global_invocation_id) gid: vec3<u32>) {
+  let i = gid.x;
+  if i >= params.count { return; }
+
+  var scale = vec3<f32>(scales[i * 3u], scales[i * 3u + 1u], scales[i * 3u + 2u]);
+  // (w, x, y, z)
+  var rotation = vec4<f32>(rotations[i * 4u], rotations[i * 4u + 1u], rotations[i * 4u + 2u], rotations[i * 4u + 3u]);
+  var color = vec3<f32>(colors[i * 3u], colors[i * 3u + 1u], colors[i * 3u + 2u]);
+  var opacity = opacities[i];
+  if params.raw != 0u {
+    scale = exp(scale);
+    let len = length(rotation);
+    rotation = select(vec4<f32>(1.0, 0.0, 0.0, 0.0), rotation / len, len > 0.0);
+    color = color * SH_C0 + 0.5;
+    opacity = 1.0 / (1.0 + exp(-opacity));
+  }
+
+  // 布局与渲染器的 Splat 结构一致：mean, emissive, scale, pad, rotation, colorDC, opacity, sh1~3, pad
+  let o = i * ${SPLAT_FLOAT_COUNT}u;
+  splats[o] = positions[i * 3u];
+  splats[o + 1u] = positions[i * 3u + 1u];
+  splats[o + 2u] = positions[i * 3u + 2u];
+  splats[o + 3u] = 0.0;
+  splats[o + 4u] = scale.x;
+  splats[o + 5u] = scale.y;
+  splats[o + 6u] = scale.z;
+  splats[o + 7u] = 0.0;
+  splats[o + 8u] = rotation.x;
+  splats[o + 9u] = rotation.y;
+  splats[o + 10u] = rotation.z;
+  splats[o + 11u] = rotation.w;
+  splats[o + 12u] = color.x;
+  splats[o + 13u] = color.y;
+  splats[o + 14u] = color.z;
+  splats[o + 15u] = opacity;
+  for (var k = 0u; k < ${SH_COEFF_COUNT}u; k++) {
+    var value = 0.0;
+    if params.hasSH != 0u { value = shCoeffs[i * ${SH_COEFF_COUNT}u + k]; }
+    splats[o + 16u + k] = value;
+  }
+  splats[o + 61u] = 0.0;
+  splats[o + 62u] = 0.0;
+  splats[o + 63u] = 0.0;
+}
+`;
+
+/**
+ * 把外部属性 buffer 打包进 splat buffer
+ */
+export class SplatExternalPacker {
+  private device: GPUDevice;
+  private count: number;
+  private paramsBuffer: GPUBuffer;
+  // 没有 SH 时绑定的占位 buffer
+  private placeholder: GPUBuffer | null = null;
+  private bindGroup: GPUBindGroup;
+  private pipeline: GPUComputePipeline;
+
+  /**
+   * @param target 渲染器的 splat buffer（至少 count * 256 字节，需要 STORAGE 用途）
+   */
+  constructor(device: GPUDevice, source: SplatExternalBuffers, target: GPUBuffer) {
+    this.device = device;
+    this.count = source.count;
+    validateExternalBuffers(device, source);
+    const pipelines = PipelineCache.get(device);
+
+    this.paramsBuffer = device.createBuffer({
+      size: 16,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+      label: "splat-external-params",
+    });
+    device.queue.writeBuffer(this.paramsBuffer, 0, new Uint32Array([
+      source.count, source.raw ? 1 : 0, source.shCoeffs ? 1 : 0, 0,
+    ]));
+    if (!source.shCoeffs) {
+      this.placeholder = device.createBuffer({ size: 16, usage: GPUBufferUsage.STORAGE, label: "splat-external-placeholder" });
+    }
+
+    const bindGroupLayout = pipelines.getBindGroupLayout({
+      label: "splat-external-layout",
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.COMPUTE, buffer: { type: "uniform" } },
+        ...[1, 2, 3, 4, 5, 6].map((binding) => ({
+          binding,
+          visibility: GPUShaderStage.COMPUTE,
+          buffer: { type: "read-only-storage" as const },
+        })),
+        { binding: 7, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+      ],
+    });
+    const n = source.count;
+    const entry = (binding: number, input: SplatExternalBuffer, floats: number): GPUBindGroupEntry => ({
+      binding,
+      resource: { buffer: input.buffer, offset: input.offset ?? 0, size: n * floats * 4 },
+    });
+    this.bindGroup = device.createBindGroup({
+      layout: bindGroupLayout,
+      entries: [
+        { binding: 0, resource: { buffer: this.paramsBuffer } },
+        entry(1, source.positions, 3),
+        entry(2, source.rotations, 4),
+        entry(3, source.scales, 3),
+        entry(4, source.colors, 3),
+        entry(5, source.opacities, 1),
+        source.shCoeffs
+          ? entry(6, source.shCoeffs, SH_COEFF_COUNT)
+          : { binding: 6, resource: { buffer: this.placeholder! } },
+        { binding: 7, resource: { buffer: target, offset: 0, size: n * SPLAT_FLOAT_COUNT * 4 } },
+      ],
+      label: "splat-external-bind-group",
+    });
+
+    const module = pipelines.getShaderModule({ code: packShader, label: "splat-external-shader" });
+    this.pipeline = pipelines.getComputePipeline({
+      layout: pipelines.getPipelineLayout([bindGroupLayout]),
+      compute: { module, entryPoint: "packSplats" },
+      label: "splat-external-pipeline",
+    });
+  }
+
+  /**
+   * 重新打包（外部数据更新后调用），encoder 省略时单独提交
+   */
+  pack(encoder?: GPUCommandEncoder): void {
+    if (this.count === 0) return;
+    const ownEncoder = encoder ?? this.device.createCommandEncoder({ label: "splat-external-encoder" });
+    const pass = ownEncoder.beginComputePass({ label: "splat-external-pack" });
+    pass.setPipeline(this.pipeline);
+    pass.setBindGroup(0, this.bindGroup);
+    pass.dispatchWorkgroups(Math.ceil(this.count / WORKGROUP_SIZE));
+    pass.end();
+    if (!encoder) {
+      this.device.queue.submit([ownEncoder.finish()]);
+    }
+  }
+
+  /**
+   * 获取占用的显存（字节，不含外部 buffer）
+   */
+  getGPUMemoryUsage(): number {
+    return getBuffersByteSize(this.placeholder ? [this.paramsBuffer, this.placeholder] : [this.paramsBuffer]);
+  }
+
+  destroy(): void {
+    this.paramsBuffer.destroy();
+    this.placeholder?.destroy();
+  }
+}
+
+/**
+ * 检查外部 buffer 的用途、偏移对齐和大小，不满足时抛出错误
+ */
+export function validateExternalBuffers(device: GPUDevice, source: SplatExternalBuffers): void {
+  const check = (name: string, input: SplatExternalBuffer | undefined, floats: number) => {
+    if (input) validateExternalBuffer(device, name, input, source.count, floats);
+  };
+  check("positions", source.positions, 3);
+  check("rotations", source.rotations, 4);
+  check("scales", source.scales, 3);
+  check("colors", source.colors, 3);
+  check("opacities", source.opacities, 1);
+  check("shCoeffs", source.shCoeffs, SH_COEFF_COUNT);
+}
+
+/**
+ * 检查单个外部属性 buffer（每个 splat floats 个 f32），不满足时抛出错误
+ */
+export function validateExternalBuffer(
+  device: GPUDevice,
+  name: string,
+  input: SplatExternalBuffer,
+  count: number,
+  floats: number,
+): void {
+  const alignment = device.limits.minStorageBufferOffsetAlignment;
+  const offset = input.offset ?? 0;
+  if ((input.buffer.usage & GPUBufferUsage.STORAGE) === 0) {
+    throw new Error(`[SplatExternalBuffers] ${name} 需要 STORAGE 用途`);
+  }
+  if (offset % alignment !== 0) {
+    throw new Error(`[SplatExternalBuffers] ${name} 的偏移 ${offset} 不是 ${alignment} 的倍数`);
+  }
+  if (offset + count * floats * 4 > input.buffer.size) {
+    throw new Error(`[SplatExternalBuffers] ${name} 的大小不足 ${count} 个 splat`);
+  }
+}
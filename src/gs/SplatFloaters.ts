@@ -0,0 +1,138 @@
+/**
+ * SplatFloaters - 启发式漂浮物检测
+ *
+ * 训练产生的漂浮物通常有两类特征：
+ * - 超大且几乎透明：最大缩放远大于场景中位数，不透明度很低（雾状的大椭球）
+ * - 孤立的大 splat：缩放偏大且邻域内几乎没有其他 splat，常见于训练相机附近
+ *   （只在少数视角可见，优化时没有被约束）
+ * 检测结果是 splat 索引，由调用方选中（检查后再删除）或直接删除（可撤销）。
+ * 所有距离都在模型空间中计算
+ */
+
+import type { CompactSplatData } from "./PLYLoaderMobile";
+import { SplatSpatialHash } from "./SplatSpatialHash";
+
+/**
+ * 漂浮物检测参数
+ */
+export interface SplatFloaterConfig {
+  /** 规则 1：最大缩放超过中位数的倍数，0 表示禁用该规则 */
+  largeScaleFactor: number;
+  /** 规则 1：不透明度低于该值 */
+  maxOpacity: number;
+  /** 规则 2：最大缩放超过中位数的倍数，0 表示禁用该规则 */
+  isolatedScaleFactor: number;
+  /** 规则 2：邻域半径（模型空间），0 表示取包围盒半对角线的 2% */
+  neighborRadius: number;
+  /** 规则 2：邻域内其他 splat 少于该数量视为孤立 */
+  minNeighbors: number;
+  /** 规则 2：只检测相机位置附近的 splat（模型空间，例如训练相机轨迹），不设置时检测全部 */
+  cameraPositions?: ArrayLike<number>[];
+  /** 规则 2：相机附近的距离，0 表示取包围盒半对角线的 10% */
+  cameraRadius: number;
+}
+
+/** 默认漂浮物检测参数 */
+export const DEFAULT_SPLAT_FLOATER_CONFIG: SplatFloaterConfig = {
+  largeScaleFactor: 10,
+  maxOpacity: 0.1,
+  isolatedScaleFactor: 3,
+  neighborRadius: 0,
+  minNeighbors: 3,
+  cameraRadius: 0,
+};
+
+// 估算中位数时的最大采样数
+const MEDIAN_SAMPLES = 65536;
+
+/**
+ * 找出符合漂浮物特征的 splat
+ * @param deleted 已删除标记（非 0 的 splat 不参与检测，也不算作邻居）
+ * @returns 升序的 splat 索引
+ */
+export function findSplatFloaters(
+  data: CompactSplatData,
+  config: Partial<SplatFloaterConfig> = {},
+  deleted: Uint8Array | null = null,
+): Uint32Array {
+  const cfg = { ...DEFAULT_SPLAT_FLOATER_CONFIG, ...config };
+  const { count, positions, scales, opacities } = data;
+  const alive = (i: number) => !deleted || !deleted[i];
+
+  const maxScales = new Float32Array(count);
+  for (let i = 0; i < count; i++) {
+    maxScales[i] = Math.max(scales[i * 3], scales[i * 3 + 1], scales[i * 3 + 2]);
+  }
+  const median = estimateMedian(maxScales, alive);
+  if (median <= 0) {
+    return new Uint32Array(0);
+  }
+
+  const floaters = new Uint8Array(count);
+  const candidates: number[] = [];
+  for (let i = 0; i < count; i++) {
+    if (!alive(i)) continue;
+    if (cfg.largeScaleFactor > 0 && maxScales[i] > median * cfg.largeScaleFactor && opacities[i] < cfg.maxOpacity) {
+      floaters[i] = 1;
+    } else if (cfg.isolatedScaleFactor > 0 && maxScales[i] > median * cfg.isolatedScaleFactor) {
+      candidates.push(i);
+    }
+  }
+
+  if (candidates.length > 0) {
+    const extent = computeExtent(positions, count, alive);
+    const radius = cfg.neighborRadius > 0 ? cfg.neighborRadius : extent * 0.02;
+    const cameraRadius = cfg.cameraRadius > 0 ? cfg.cameraRadius : extent * 0.1;
+    const cameras = cfg.cameraPositions;
+    const grid = radius <= 0 || cameras?.length === 0 ? null : new SplatSpatialHash(positions, count, radius, alive);
+    for (const i of candidates) {
+      if (cameras && !isNearAny(positions, i, cameras, cameraRadius)) continue;
+      const [x, y, z] = [positions[i * 3], positions[i * 3 + 1], positions[i * 3 + 2]];
+      if (grid && grid.countNeighbors(x, y, z, radius, cfg.minNeighbors, i) < cfg.minNeighbors) {
+        floaters[i] = 1;
+      }
+    }
+  }
+
+  let total = 0;
+  for (let i = 0; i < count; i++) total += floaters[i];
+  const result = new Uint32Array(total);
+  for (let i = 0, n = 0; i < count; i++) {
+    if (floaters[i]) result[n++] = i;
+  }
+  return result;
+}
+
+// 采样估算中位数
+function estimateMedian(values: Float32Array, alive: (i: number) => boolean): number {
+  const stride = Math.max(1, Math.floor(values.length / MEDIAN_SAMPLES));
+  const samples: number[] = [];
+  for (let i = 0; i < values.length; i += stride) {
+    if (alive(i)) samples.push(values[i]);
+  }
+  if (samples.length === 0) return 0;
+  samples.sort((a, b) => a - b);
+  return samples[samples.length >> 1];
+}
+
+// 包围盒半对角线
+function computeExtent(positions: Float32Array, count: number, alive: (i: number) => boolean): number {
+  const min = [Infinity, Infinity, Infinity];
+  const max = [-Infinity, -Infinity, -Infinity];
+  for (let i = 0; i < count; i++) {
+    if (!alive(i)) continue;
+    for (let a = 0; a < 3; a++) {
+      const v = positions[i * 3 + a];
+      if (v < min[a]) min[a] = v;
+      if (v > max[a]) max[a] = v;
+    }
+  }
+  if (min[0] > max[0]) return 0;
+  return Math.hypot(max[0] - min[0], max[1] - min[1], max[2] - min[2]) / 2;
+}
+
+function isNearAny(positions: Float32Array, i: number, cameras: ArrayLike<number>[], radius: number): boolean {
+  const x = positions[i * 3], y = positions[i * 3 + 1], z = positions[i * 3 + 2];
+  const r2 = radius * radius;
+  return cameras.some((c) => (c[0] - x) ** 2 + (c[1] - y) ** 2 + (c[2] - z) ** 2 <= r2);
+}
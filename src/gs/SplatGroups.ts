@@ -0,0 +1,329 @@
+/**
+ * SplatGroups - 命名的 splat 分组（编辑图层）
+ *
+ * 每个 splat 属于至多一个分组（逐 splat 1 字节分组 id，0 表示不属于任何分组），
+ * 分组可以隐藏 / 锁定 / 单独显示 (solo) / 着色：
+ * - 隐藏：shader 中不透明度为 0（不参与渲染、拾取和瓦片光栅化），数据不变
+ * - 锁定：不能被选中（选择操作跳过锁定和隐藏分组中的 splat）
+ * - solo：只显示该分组，其余分组和未分组的 splat 都隐藏
+ * - 着色：颜色按强度混合到分组颜色，用于区分分组
+ * 分组 id 与选中状态打包上传到同一个 buffer（bit 0 选中，bit 8~15 分组 id），
+ * 分组状态只占一小段 uniform，切换显示 / 着色不需要重新上传逐 splat 数据
+ */
+
+import type { Vec3Tuple } from "../types";
+
+/**
+ * 分组数量上限（id 1 ~ 15）
+ */
+export const MAX_SPLAT_GROUPS = 15;
+
+/** 默认着色强度 */
+export const DEFAULT_SPLAT_GROUP_TINT_STRENGTH = 0.5;
+
+/**
+ * 分组信息
+ */
+export interface SplatGroupInfo {
+  id: number;
+  name: string;
+  visible: boolean;
+  locked: boolean;
+  /** 着色颜色（线性 0~1），null 表示不着色 */
+  tint: Vec3Tuple | null;
+  /** 着色强度 (0~1) */
+  tintStrength: number;
+}
+
+/**
+ * splat 分组
+ */
+export class SplatGroups {
+  /** 逐 splat 分组 id，0 表示未分组 */
+  readonly ids: Uint8Array;
+  private groups: Map<number, SplatGroupInfo> = new Map();
+  private solo: number | null = null;
+  // 逐 splat 分组 id 的版本号（变化时需要重新上传）
+  private version: number = 0;
+  // 分组状态（显示 / 锁定 / 着色 / solo）的版本号
+  private stateVersion: number = 0;
+
+  constructor(count: number) {
+    this.ids = new Uint8Array(count);
+  }
+
+  get count(): number {
+    return this.ids.length;
+  }
+
+  // ============================================
+  // 分组
+  // ============================================
+
+  /**
+   * 创建分组
+   * @returns 分组 id，达到上限时返回 null
+   */
+  createGroup(name: string, tint: Vec3Tuple | null = null): number | null {
+    for (let id = 1; id <= MAX_SPLAT_GROUPS; id++) {
+      if (!this.groups.has(id)) {
+        this.groups.set(id, {
+          id,
+          name,
+          visible: true,
+          locked: false,
+          tint: tint ? [...tint] : null,
+          tintStrength: DEFAULT_SPLAT_GROUP_TINT_STRENGTH,
+        });
+        this.stateVersion++;
+        return id;
+      }
+    }
+    return null;
+  }
+
+  /**
+   * 删除分组（其中的 splat 变为未分组）
+   */
+  deleteGroup(id: number): boolean {
+    if (!this.groups.delete(id)) return false;
+    this.assignWhere((i) => this.ids[i] === id, 0);
+    if (this.solo === id) this.solo = null;
+    this.stateVersion++;
+    return true;
+  }
+
+  getGroup(id: number): SplatGroupInfo | null {
+    const group = this.groups.get(id);
+    return group ? { ...group, tint: group.tint ? [...group.tint] : null } : null;
+  }
+
+  /**
+   * 全部分组（按 id 升序）
+   */
+  getGroups(): SplatGroupInfo[] {
+    return Array.from(this.groups.keys()).sort((a, b) => a - b).map((id) => this.getGroup(id)!);
+  }
+
+  /**
+   * 按名称查找分组 id
+   */
+  findGroup(name: string): number | null {
+    for (const group of this.groups.values()) {
+      if (group.name === name) return group.id;
+    }
+    return null;
+  }
+
+  renameGroup(id: number, name: string): void {
+    this.update(id, (group) => (group.name = name));
+  }
+
+  // ============================================
+  // 成员
+  // ============================================
+
+  /**
+   * 把 splat 分配到分组（id 为 0 时移出分组）
+   * @returns 分组发生变化的数量
+   */
+  assign(indices: ArrayLike<number>, id: number): number {
+    this.checkId(id);
+    let changed = 0;
+    for (let k = 0; k < indices.length; k++) {
+      const i = indices[k];
+      if (i < 0 || i >= this.ids.length || this.ids[i] === id) continue;
+      this.ids[i] = id;
+      changed++;
+    }
+    if (changed > 0) this.version++;
+    return changed;
+  }
+
+  /**
+   * 按谓词分配
+   * @returns 分组发生变化的数量
+   */
+  assignWhere(predicate: (index: number) => boolean, id: number): number {
+    this.checkId(id);
+    let changed = 0;
+    for (let i = 0; i < this.ids.length; i++) {
+      if (this.ids[i] !== id && predicate(i)) {
+        this.ids[i] = id;
+        changed++;
+      }
+    }
+    if (changed > 0) this.version++;
+    return changed;
+  }
+
+  getGroupId(index: number): number {
+    return this.ids[index];
+  }
+
+  /**
+   * 分组中的 splat 索引（升序）
+   */
+  getIndices(id: number): Uint32Array {
+    const indices: number[] = [];
+    for (let i = 0; i < this.ids.length; i++) {
+      if (this.ids[i] === id) indices.push(i);
+    }
+    return Uint32Array.from(indices);
+  }
+
+  /**
+   * 统计每个分组的 splat 数量（键 0 为未分组）
+   */
+  countByGroup(): Map<number, number> {
+    const counts = new Map<number, number>();
+    for (let i = 0; i < this.ids.length; i++) {
+      counts.set(this.ids[i], (counts.get(this.ids[i]) ?? 0) + 1);
+    }
+    return counts;
+  }
+
+  // ============================================
+  // 状态
+  // ============================================
+
+  setVisible(id: number, visible: boolean): void {
+    this.update(id, (group) => (group.visible = visible));
+  }
+
+  setLocked(id: number, locked: boolean): void {
+    this.update(id, (group) => (group.locked = locked));
+  }
+
+  /**
+   * 设置着色，null 取消着色
+   */
+  setTint(id: number, tint: Vec3Tuple | null, strength: number = DEFAULT_SPLAT_GROUP_TINT_STRENGTH): void {
+    this.update(id, (group) => {
+      group.tint = tint ? [...tint] : null;
+      group.tintStrength = Math.min(Math.max(strength, 0), 1);
+    });
+  }
+
+  /**
+   * 只显示该分组，null 取消
+   */
+  setSolo(id: number | null): void {
+    if (id !== null && !this.groups.has(id)) {
+      throw new Error(`[SplatGroups] 分组 ${id} 不存在`);
+    }
+    this.solo = id;
+    this.stateVersion++;
+  }
+
+  getSolo(): number | null {
+    return this.solo;
+  }
+
+  /**
+   * 分组 id（0 为未分组）当前是否隐藏（考虑 solo）
+   */
+  isGroupHidden(id: number): boolean {
+    if (this.solo !== null) {
+      return id !== this.solo;
+    }
+    return id !== 0 && this.groups.get(id)?.visible === false;
+  }
+
+  /**
+   * splat 是否可以被选中（不在锁定或隐藏的分组中）
+   */
+  isSelectable(index: number): boolean {
+    const id = this.ids[index];
+    return !this.isGroupHidden(id) && !(id !== 0 && this.groups.get(id)?.locked);
+  }
+
+  /**
+   * 逐 splat 分组 id 的版本号
+   */
+  getVersion(): number {
+    return this.version;
+  }
+
+  /**
+   * 分组状态的版本号
+   */
+  getStateVersion(): number {
+    return this.stateVersion;
+  }
+
+  /**
+   * 按 keep 顺序收集逐 splat 分组 id（压缩已删除的 splat 后使用），分组状态保持不变
+   */
+  gather(keep: ArrayLike<number>): SplatGroups {
+    const result = new SplatGroups(keep.length);
+    for (let k = 0; k < keep.length; k++) {
+      result.ids[k] = this.ids[keep[k]];
+    }
+    for (const group of this.groups.values()) {
+      result.groups.set(group.id, { ...group, tint: group.tint ? [...group.tint] : null });
+    }
+    result.solo = this.solo;
+    return result;
+  }
+
+  private update(id: number, apply: (group: SplatGroupInfo) => void): void {
+    const group = this.groups.get(id);
+    if (!group) {
+      throw new Error(`[SplatGroups] 分组 ${id} 不存在`);
+    }
+    apply(group);
+    this.stateVersion++;
+  }
+
+  private checkId(id: number): void {
+    if (id !== 0 && !this.groups.has(id)) {
+      throw new Error(`[SplatGroups] 分组 ${id} 不存在`);
+    }
+  }
+}
+
+/**
+ * 分组 uniform 数据：16 个分组的着色 (rgb, 强度)，索引为分组 id；null 表示没有分组
+ */
+export function getSplatGroupUniforms(groups: SplatGroups | null, data: Float32Array = new Float32Array(64)): Float32Array {
+  data.fill(0);
+  if (!groups) return data;
+  for (const group of groups.getGroups()) {
+    if (!group.tint) continue;
+    data.set(group.tint, group.id * 4);
+    data[group.id * 4 + 3] = group.tintStrength;
+  }
+  return data;
+}
+
+/**
+ * 隐藏分组的位掩码（bit i 对应分组 id i，bit 0 为未分组）
+ */
+export function getSplatGroupHiddenMask(groups: SplatGroups | null): number {
+  if (!groups) return 0;
+  let mask = 0;
+  for (let id = 0; id <= MAX_SPLAT_GROUPS; id++) {
+    if (groups.isGroupHidden(id)) mask |= 1 << id;
+  }
+  return mask >>> 0;
+}
+
+/**
+ * 分组 shader 函数（读取打包在 selection buffer 中的分组 id 和 uniforms.groupTints / groupParams）
+ */
+export const splatGroupsShader = /* wgsl */ `
+fn splatGroup(splatIndex: u32) -> u32 {
+  return (selection[splatIndex] >> 8u) & 0xffu;
+}
+
+fn isSplatGroupHidden(splatIndex: u32) -> bool {
+  return ((uniforms.groupParams.x >> splatGroup(splatIndex)) & 1u) != 0u;
+}
+
+// 分组着色
+fn tintSplatGroup(splatIndex: u32, color: vec3<f32>) -> vec3<f32> {
+  let tint = uniforms.groupTints[splatGroup(splatIndex)];
+  return mix(color, tint.rgb, tint.w);
+}
+`;
@@ -0,0 +1,213 @@
+/**
+ * SplatHiZ - 层级深度 (Hi-Z) 金字塔
+ *
+ * 从上一帧的深度缓冲构建 mip 链，每一级保存覆盖区域内 "最远" 的深度
+ * （标准深度取 max，reverse-Z 取 min），剔除 pass 据此判断 splat 是否被网格完全遮挡。
+ * 深度来自上一帧，相机快速移动时新露出的区域会晚一帧出现
+ */
+
+import type { DepthConfig } from "../core/DepthConvention";
+import { PipelineCache } from "../core/PipelineCache";
+
+const WORKGROUP_SIZE = 8;
+
+/**
+ * 生成 Hi-Z shader 代码
+ */
+function generateHiZShaderCode(reverseZ: boolean): string {
+  const farthest = reverseZ ? "min" : "max";
+  return /* wgsl */ `
+@group(0) @binding(0) var depthTex: texture_depth_2d;
+@group(0) @binding(1) var hizOut: texture_storage_2d<r32float, write>;
+
+// mip 0: 直接拷贝深度
+@compute @workgroup_size(${WORKGROUP_SIZE}, ${WORKGROUP_SIZE})
+fn copyDepth(@builtin(global_invocation_id) gid: vec3<u32>) {
+  let size = textureDimensions(hizOut);
+  if gid.x >= size.x || gid.y >= size.y { return; }
+  let depth = textureLoad(depthTex, vec2<i32>(gid.xy), 0);
+  textureStore(hizOut, vec2<i32>(gid.xy), vec4<f32>(depth, 0.0, 0.0, 0.0));
+}
+
+@group(0) @binding(0) var hizIn: texture_2d<f32>;
+@group(0) @binding(1) var hizDown: texture_storage_2d<r32float, write>;
+
+// mip n: 取上一级 3x3 邻域的最远深度 (奇数尺寸时保证覆盖完整)
+@compute @workgroup_size(${WORKGROUP_SIZE}, ${WORKGROUP_SIZE})
+fn downsample(@builtin(global_invocation_id) gid: vec3<u32>) {
+  let size = textureDimensions(hizDown);
+  if gid.x >= size.x || gid.y >= size.y { return; }
+  let srcMax = vec2<i32>(textureDimensions(hizIn)) - 1;
+  let base = vec2<i32>(gid.xy) * 2;
+  var depth = textureLoad(hizIn, min(base, srcMax), 0).r;
+  for (var y = 0; y < 3; y++) {
+    for (var x = 0; x < 3; x++) {
+      let coord = min(base + vec2<i32>(x, y), srcMax);
+      depth = ${farthest}(depth, textureLoad(hizIn, coord, 0).r);
+    }
+  }
+  textureStore(hizDown, vec2<i32>(gid.xy), vec4<f32>(depth, 0.0, 0.0, 0.0));
+}
+`;
+}
+
+/**
+ * SplatHiZ - Hi-Z 金字塔
+ */
+export class SplatHiZ {
+  private device: GPUDevice;
+
+  private copyPipeline: GPUComputePipeline;
+  private downsamplePipeline: GPUComputePipeline;
+  private copyBindGroupLayout: GPUBindGroupLayout;
+  private downsampleBindGroupLayout: GPUBindGroupLayout;
+
+  private texture: GPUTexture | null = null;
+  private view: GPUTextureView | null = null;
+  private copyBindGroup: GPUBindGroup | null = null;
+  private downsampleBindGroups: GPUBindGroup[] = [];
+  private sourceDepth: GPUTexture | null = null;
+
+  constructor(device: GPUDevice, depthConfig: DepthConfig) {
+    this.device = device;
+    const pipelines = PipelineCache.get(device);
+
+    const module = pipelines.getShaderModule({
+      code: generateHiZShaderCode(depthConfig.reverseZ),
+      label: "hiz-shader",
+    });
+
+    this.copyBindGroupLayout = pipelines.getBindGroupLayout({
+      label: "hiz-copy-layout",
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.COMPUTE, texture: { sampleType: "depth" } },
+        { binding: 1, visibility: GPUShaderStage.COMPUTE, storageTexture: { access: "write-only", format: "r32float" } },
+      ],
+    });
+    this.downsampleBindGroupLayout = pipelines.getBindGroupLayout({
+      label: "hiz-downsample-layout",
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.COMPUTE, texture: { sampleType: "unfilterable-float" } },
+        { binding: 1, visibility: GPUShaderStage.COMPUTE, storageTexture: { access: "write-only", format: "r32float" } },
+      ],
+    });
+
+    this.copyPipeline = pipelines.getComputePipeline({
+      layout: pipelines.getPipelineLayout([this.copyBindGroupLayout]),
+      compute: { module, entryPoint: "copyDepth" },
+      label: "hiz-copy-pipeline",
+    });
+    this.downsamplePipeline = pipelines.getComputePipeline({
+      layout: pipelines.getPipelineLayout([this.downsampleBindGroupLayout]),
+      compute: { module, entryPoint: "downsample" },
+      label: "hiz-downsample-pipeline",
+    });
+  }
+
+  /**
+   * 获取 Hi-Z 纹理视图（包含全部 mip），尚未构建时返回 null
+   */
+  getView(): GPUTextureView | null {
+    return this.view;
+  }
+
+  /**
+   * 获取 mip 级数
+   */
+  getMipLevelCount(): number {
+    return this.texture?.mipLevelCount ?? 0;
+  }
+
+  /**
+   * 从深度纹理构建 Hi-Z（深度纹理需要 TEXTURE_BINDING 用途）
+   * @returns 纹理是否被重建（需要重建引用它的 bind group）
+   */
+  build(encoder: GPUCommandEncoder, depthTexture: GPUTexture): boolean {
+    const recreated = this.ensureResources(depthTexture);
+    const texture = this.texture!;
+
+    {
+      const pass = encoder.beginComputePass({ label: "hiz-copy" });
+      pass.setPipeline(this.copyPipeline);
+      pass.setBindGroup(0, this.copyBindGroup!);
+      pass.dispatchWorkgroups(
+        Math.ceil(texture.width / WORKGROUP_SIZE),
+        Math.ceil(texture.height / WORKGROUP_SIZE),
+      );
+      pass.end();
+    }
+
+    for (let level = 1; level < texture.mipLevelCount; level++) {
+      const width = Math.max(1, texture.width >> level);
+      const height = Math.max(1, texture.height >> level);
+      const pass = encoder.beginComputePass({ label: `hiz-downsample-${level}` });
+      pass.setPipeline(this.downsamplePipeline);
+      pass.setBindGroup(0, this.downsampleBindGroups[level - 1]);
+      pass.dispatchWorkgroups(Math.ceil(width / WORKGROUP_SIZE), Math.ceil(height / WORKGROUP_SIZE));
+      pass.end();
+    }
+
+    return recreated;
+  }
+
+  /**
+   * 深度纹理变化（尺寸变化会重建深度纹理）时重建 Hi-Z 纹理和 bind group
+   */
+  private ensureResources(depthTexture: GPUTexture): boolean {
+    if (this.texture && this.sourceDepth === depthTexture) {
+      return false;
+    }
+
+    this.texture?.destroy();
+    this.sourceDepth = depthTexture;
+
+    const width = depthTexture.width;
+    const height = depthTexture.height;
+    const mipLevelCount = Math.floor(Math.log2(Math.max(width, height))) + 1;
+    this.texture = this.device.createTexture({
+      size: { width, height },
+      format: "r32float",
+      mipLevelCount,
+      usage: GPUTextureUsage.STORAGE_BINDING | GPUTextureUsage.TEXTURE_BINDING,
+      label: "hiz",
+    });
+    this.view = this.texture.createView();
+
+    const levelView = (level: number) => this.texture!.createView({ baseMipLevel: level, mipLevelCount: 1 });
+
+    this.copyBindGroup = this.device.createBindGroup({
+      layout: this.copyBindGroupLayout,
+      entries: [
+        { binding: 0, resource: depthTexture.createView({ aspect: "depth-only" }) },
+        { binding: 1, resource: levelView(0) },
+      ],
+      label: "hiz-copy-bind-group",
+    });
+
+    this.downsampleBindGroups = [];
+    for (let level = 1; level < mipLevelCount; level++) {
+      this.downsampleBindGroups.push(this.device.createBindGroup({
+        layout: this.downsampleBindGroupLayout,
+        entries: [
+          { binding: 0, resource: levelView(level - 1) },
+          { binding: 1, resource: levelView(level) },
+        ],
+        label: `hiz-downsample-bind-group-${level}`,
+      }));
+    }
+
+    return true;
+  }
+
+  /**
+   * 销毁资源
+   */
+  destroy(): void {
+    this.texture?.destroy();
+    this.texture = null;
+    this.view = null;
+    this.copyBindGroup = null;
+    this.downsampleBindGroups = [];
+    this.sourceDepth = null;
+  }
+}
@@ -0,0 +1,364 @@
+/**
+ * SplatImport - 加载时的单位换算、坐标轴转换与降采样
+ *
+ * 在解析过程中逐 splat 应用，避免先加载全部数据再后处理：
+ * - scale: 统一缩放（如厘米 -> 米）
+ * - upAxis: 源数据的上轴，统一转换为 Y-up（位置、旋转和 SH 系数一起旋转）
+ * - maxSplats: 超过时按重要性 (opacity * scale) 确定性降采样
+ * - shDegreeLimit: 丢弃高阶 SH 系数
+ * - sanitize: 丢弃含 NaN / Infinity 或零四元数的无效 splat
+ * - precision: "f64" 时位置按 f64 读取，以 f64 质心为原点重定位（地理参考数据）
+ */
+
+import type { SplatPrecision } from "./SplatStatistics";
+
+/**
+ * 源数据的上轴
+ */
+export type SplatUpAxis = "y" | "-y" | "z" | "-z";
+
+/**
+ * 加载选项
+ */
+export interface SplatImportOptions {
+  /** 统一缩放系数 */
+  scale?: number;
+  /** 源数据的上轴，默认 "y"（不转换） */
+  upAxis?: SplatUpAxis;
+  /** 最大 splat 数量，超过则按重要性降采样 */
+  maxSplats?: number;
+  /** 保留的 SH 阶数上限 (0-3) */
+  shDegreeLimit?: number;
+  /** 是否丢弃无效 splat */
+  sanitize?: boolean;
+  /** 位置统计与原点重定位的精度，默认 "f32" */
+  precision?: SplatPrecision;
+}
+
+const SQRT1_2 = Math.SQRT1_2;
+
+/** 上轴 -> Y-up 的旋转四元数 [w, x, y, z] */
+const UP_AXIS_ROTATIONS: Record<SplatUpAxis, [number, number, number, number]> = {
+  y: [1, 0, 0, 0],
+  "-y": [0, 1, 0, 0],
+  z: [SQRT1_2, -SQRT1_2, 0, 0],
+  "-z": [SQRT1_2, SQRT1_2, 0, 0],
+};
+
+/** 每阶 SH 的 (起始系数, 系数数量)，不含 DC */
+const SH_BANDS: [number, number][] = [[0, 3], [3, 5], [8, 7]];
+
+/**
+ * 与 shader 中 shadedColor 一致的 SH 基函数（不含 DC，共 15 个）
+ */
+function evalSHBasis(x: number, y: number, z: number): number[] {
+  const C1 = 0.4886025119029199;
+  const xx = x * x, yy = y * y, zz = z * z;
+  const xy = x * y, yz = y * z, xz = x * z;
+  return [
+    -C1 * y, C1 * z, -C1 * x,
+    1.0925484305920792 * xy,
+    -1.0925484305920792 * yz,
+    0.31539156525252005 * (2 * zz - xx - yy),
+    -1.0925484305920792 * xz,
+    0.5462742152960396 * (xx - yy),
+    -0.5900435899266435 * y * (3 * xx - yy),
+    2.890611442640554 * xy * z,
+    -0.4570457994644658 * y * (4 * zz - xx - yy),
+    0.3731763325901154 * z * (2 * zz - 3 * xx - 3 * yy),
+    -0.4570457994644658 * x * (4 * zz - xx - yy),
+    1.445305721320277 * z * (xx - yy),
+    -0.5900435899266435 * x * (xx - 3 * yy),
+  ];
+}
+
+/**
+ * 四元数 [w, x, y, z] -> 3x3 行主序矩阵
+ */
+function quatToMatrix([w, x, y, z]: [number, number, number, number]): number[] {
+  return [
+    1 - 2 * (y * y + z * z), 2 * (x * y - w * z), 2 * (x * z + w * y),
+    2 * (x * y + w * z), 1 - 2 * (x * x + z * z), 2 * (y * z - w * x),
+    2 * (x * z - w * y), 2 * (y * z + w * x), 1 - 2 * (x * x + y * y),
+  ];
+}
+
+/**
+ * 求解 n x n 线性方程组 A X = B（部分主元高斯消元，B 为 n x m）
+ */
+function solveLinear(a: number[][], b: number[][]): number[][] {
+  const n = a.length;
+  const m = b[0].length;
+  const A = a.map((row) => row.slice());
+  const B = b.map((row) => row.slice());
+  for (let col = 0; col < n; col++) {
+    let pivot = col;
+    for (let r = col + 1; r < n; r++) {
+      if (Math.abs(A[r][col]) > Math.abs(A[pivot][col])) pivot = r;
+    }
+    [A[col], A[pivot]] = [A[pivot], A[col]];
+    [B[col], B[pivot]] = [B[pivot], B[col]];
+    for (let r = 0; r < n; r++) {
+      if (r === col) continue;
+      const f = A[r][col] / A[col][col];
+      for (let c = col; c < n; c++) A[r][c] -= f * A[col][c];
+      for (let c = 0; c < m; c++) B[r][c] -= f * B[col][c];
+    }
+  }
+  return B.map((row, r) => row.map((v) => v / A[r][r]));
+}
+
+/**
+ * 计算旋转 R（3x3 行主序，正交矩阵）下每阶 SH 系数的变换矩阵 X：c' = X c
+ * 由 Y(R⁻¹ d) = M Y(d) 在一组采样方向上最小二乘拟合得到（X = Mᵀ）
+ */
+export function computeSHRotation(rotation: number[]): number[][][] {
+  // 黄金螺旋采样方向
+  const SAMPLE_COUNT = 32;
+  const directions: [number, number, number][] = [];
+  for (let k = 0; k < SAMPLE_COUNT; k++) {
+    const z = 1 - (2 * k + 1) / SAMPLE_COUNT;
+    const r = Math.sqrt(1 - z * z);
+    const phi = k * Math.PI * (3 - Math.sqrt(5));
+    directions.push([r * Math.cos(phi), r * Math.sin(phi), z]);
+  }
+
+  // R⁻¹ = Rᵀ
+  const rotated = directions.map(([x, y, z]) => evalSHBasis(
+    rotation[0] * x + rotation[3] * y + rotation[6] * z,
+    rotation[1] * x + rotation[4] * y + rotation[7] * z,
+    rotation[2] * x + rotation[5] * y + rotation[8] * z,
+  ));
+  const original = directions.map(([x, y, z]) => evalSHBasis(x, y, z));
+
+  return SH_BANDS.map(([offset, n]) => {
+    // 正规方程 (AᵀA) X = AᵀB
+    const ata = Array.from({ length: n }, () => new Array<number>(n).fill(0));
+    const atb = Array.from({ length: n }, () => new Array<number>(n).fill(0));
+    for (let k = 0; k < SAMPLE_COUNT; k++) {
+      for (let i = 0; i < n; i++) {
+        const ai = original[k][offset + i];
+        for (let j = 0; j < n; j++) {
+          ata[i][j] += ai * original[k][offset + j];
+          atb[i][j] += ai * rotated[k][offset + j];
+        }
+      }
+    }
+    return solveLinear(ata, atb);
+  });
+}
+
+/**
+ * 用 computeSHRotation 的结果变换一个 splat 的 SH 系数（交错存储，原地修改）
+ * @param bands 变换的阶数（1~3），更高阶保持不变
+ */
+export function rotateSHCoefficients(
+  sh: Float32Array, shOffset: number, rotation: number[][][], bands: number = 3,
+): void {
+  const coeffs = new Float32Array(21);
+  SH_BANDS.forEach(([offset, n], band) => {
+    if (band >= bands) return;
+    const x = rotation[band];
+    for (let c = 0; c < 3; c++) {
+      for (let j = 0; j < n; j++) {
+        let sum = 0;
+        for (let i = 0; i < n; i++) {
+          sum += x[j][i] * sh[shOffset + (offset + i) * 3 + c];
+        }
+        coeffs[j * 3 + c] = sum;
+      }
+    }
+    sh.set(coeffs.subarray(0, n * 3), shOffset + offset * 3);
+  });
+}
+
+/**
+ * SplatImportTransform - 逐 splat 应用加载选项
+ * 数据以 (数组, 偏移) 形式传入，同时服务于对象数组和紧凑 TypedArray 两种加载路径
+ */
+export class SplatImportTransform {
+  readonly scale: number;
+  readonly maxSplats: number;
+  readonly shDegreeLimit: number;
+  readonly sanitize: boolean;
+
+  private rotation: [number, number, number, number];
+  private rotationMatrix: number[];
+  private shRotation: number[][][] | null = null;
+  private rotates: boolean;
+
+  constructor(options: SplatImportOptions = {}) {
+    this.scale = options.scale ?? 1;
+    this.maxSplats = options.maxSplats ?? Infinity;
+    this.shDegreeLimit = Math.min(Math.max(Math.floor(options.shDegreeLimit ?? 3), 0), 3);
+    this.sanitize = options.sanitize ?? false;
+    this.rotation = UP_AXIS_ROTATIONS[options.upAxis ?? "y"];
+    this.rotationMatrix = quatToMatrix(this.rotation);
+    this.rotates = (options.upAxis ?? "y") !== "y";
+  }
+
+  /**
+   * 保留的 SH 系数数量（每通道，不含 DC）
+   */
+  get shCoeffCount(): number {
+    return (this.shDegreeLimit + 1) * (this.shDegreeLimit + 1) - 1;
+  }
+
+  /**
+   * 是否需要逐 splat 变换几何数据
+   */
+  get transformsGeometry(): boolean {
+    return this.scale !== 1 || this.rotates;
+  }
+
+  /**
+   * 变换位置 / 缩放 / 旋转 / SH 系数（原地修改）
+   * @param sh 交错存储的 SH 系数 [R0,G0,B0, R1,G1,B1, ...]，可选
+   */
+  apply(
+    positions: Float32Array | Float64Array | number[], positionOffset: number,
+    scales: Float32Array | number[], scaleOffset: number,
+    rotations: Float32Array | number[], rotationOffset: number,
+    sh?: Float32Array, shOffset: number = 0,
+  ): void {
+    const s = this.scale;
+    if (this.rotates) {
+      const m = this.rotationMatrix;
+      const x = positions[positionOffset];
+      const y = positions[positionOffset + 1];
+      const z = positions[positionOffset + 2];
+      positions[positionOffset] = s * (m[0] * x + m[1] * y + m[2] * z);
+      positions[positionOffset + 1] = s * (m[3] * x + m[4] * y + m[5] * z);
+      positions[positionOffset + 2] = s * (m[6] * x + m[7] * y + m[8] * z);
+
+      // q' = r ⊗ q
+      const [rw, rx, ry, rz] = this.rotation;
+      const qw = rotations[rotationOffset];
+      const qx = rotations[rotationOffset + 1];
+      const qy = rotations[rotationOffset + 2];
+      const qz = rotations[rotationOffset + 3];
+      rotations[rotationOffset] = rw * qw - rx * qx - ry * qy - rz * qz;
+      rotations[rotationOffset + 1] = rw * qx + rx * qw + ry * qz - rz * qy;
+      rotations[rotationOffset + 2] = rw * qy - rx * qz + ry * qw + rz * qx;
+      rotations[rotationOffset + 3] = rw * qz + rx * qy - ry * qx + rz * qw;
+
+      if (sh) {
+        this.rotateSH(sh, shOffset);
+      }
+    } else if (s !== 1) {
+      positions[positionOffset] *= s;
+      positions[positionOffset + 1] *= s;
+      positions[positionOffset + 2] *= s;
+    }
+
+    if (s !== 1) {
+      scales[scaleOffset] *= s;
+      scales[scaleOffset + 1] *= s;
+      scales[scaleOffset + 2] *= s;
+    }
+  }
+
+  /**
+   * 清零超过阶数上限的 SH 系数
+   */
+  limitSH(sh: Float32Array, shOffset: number = 0): void {
+    sh.fill(0, shOffset + this.shCoeffCount * 3, shOffset + 45);
+  }
+
+  /**
+   * 检查 splat 是否有效（有限数值、非零四元数）
+   */
+  isValid(
+    positions: ArrayLike<number>, positionOffset: number,
+    scales: ArrayLike<number>, scaleOffset: number,
+    rotations: ArrayLike<number>, rotationOffset: number,
+    opacity: number,
+  ): boolean {
+    for (let i = 0; i < 3; i++) {
+      if (!Number.isFinite(positions[positionOffset + i]) || !Number.isFinite(scales[scaleOffset + i])) {
+        return false;
+      }
+    }
+    let qlen = 0;
+    for (let i = 0; i < 4; i++) {
+      const q = rotations[rotationOffset + i];
+      if (!Number.isFinite(q)) return false;
+      qlen += q * q;
+    }
+    return qlen > 1e-12 && Number.isFinite(opacity);
+  }
+
+  private rotateSH(sh: Float32Array, shOffset: number): void {
+    if (!this.shRotation) {
+      this.shRotation = computeSHRotation(this.rotationMatrix);
+    }
+    rotateSHCoefficients(sh, shOffset, this.shRotation, this.shDegreeLimit);
+  }
+}
+
+/**
+ * 简单的确定性伪随机数生成器 (Mulberry32)
+ * 给定相同的种子，总是产生相同的序列
+ */
+function createSeededRandom(seed: number): () => number {
+  let state = seed >>> 0;
+  return () => {
+    state = (state + 0x6d2b79f5) >>> 0;
+    let t = state;
+    t = Math.imul(t ^ (t >>> 15), t | 1);
+    t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
+    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+  };
+}
+
+/**
+ * 按重要性加权的确定性采样 (Efraimidis-Spirakis)
+ * @returns 按原始顺序排列的采样索引（保持空间局部性）
+ */
+export function sampleByImportance(importance: Float32Array, sampleCount: number, seed: number): Uint32Array {
+  const totalCount = importance.length;
+  const random = createSeededRandom(seed);
+  const result = new Uint32Array(sampleCount);
+  const weights = new Float32Array(sampleCount);
+
+  // 初始化：填充前 sampleCount 个元素
+  for (let i = 0; i < Math.min(sampleCount, totalCount); i++) {
+    result[i] = i;
+    // 使用 -log(random) / weight 作为键值
+    weights[i] = importance[i] > 0 ? -Math.log(random()) / importance[i] : Infinity;
+  }
+
+  // 简化实现：直接找最大键值的位置
+  let maxWeightIdx = 0;
+  let maxWeight = weights[0];
+  for (let i = 1; i < sampleCount; i++) {
+    if (weights[i] > maxWeight) {
+      maxWeight = weights[i];
+      maxWeightIdx = i;
+    }
+  }
+
+  // 处理剩余元素
+  for (let i = sampleCount; i < totalCount; i++) {
+    const key = importance[i] > 0 ? -Math.log(random()) / importance[i] : Infinity;
+
+    // 如果当前元素的键值小于最大键值，替换它
+    if (key < maxWeight) {
+      result[maxWeightIdx] = i;
+      weights[maxWeightIdx] = key;
+
+      // 重新找最大键值
+      maxWeight = weights[0];
+      maxWeightIdx = 0;
+      for (let j = 1; j < sampleCount; j++) {
+        if (weights[j] > maxWeight) {
+          maxWeight = weights[j];
+          maxWeightIdx = j;
+        }
+      }
+    }
+  }
+
+  result.sort((a, b) => a - b);
+  return result;
+}
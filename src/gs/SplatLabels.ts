@@ -0,0 +1,66 @@
+/**
+ * SplatLabels - 逐 splat 语义标签
+ *
+ * 每个 splat 对应一个类别 id（u16 或 u32），通常来自 ML 分割结果。
+ * 渲染器会把标签上传到独立的 storage buffer，用于按标签着色和按标签选择。
+ */
+
+/**
+ * PLY 中可能表示语义标签的属性名（按优先级）
+ */
+export const LABEL_PROPERTY_NAMES = ["label", "class_id", "class", "segment", "semantic"];
+
+/**
+ * 逐 splat 语义标签
+ */
+export class SplatLabels {
+  /** 类别 id 数组，长度等于 splat 数量 */
+  readonly ids: Uint16Array | Uint32Array;
+
+  constructor(ids: Uint16Array | Uint32Array) {
+    this.ids = ids;
+  }
+
+  /**
+   * 标签数量（等于 splat 数量）
+   */
+  get count(): number {
+    return this.ids.length;
+  }
+
+  /**
+   * 获取指定 splat 的标签
+   */
+  get(index: number): number {
+    return this.ids[index];
+  }
+
+  /**
+   * 获取出现过的所有标签（升序）
+   */
+  getUniqueLabels(): number[] {
+    return Array.from(this.countByLabel().keys()).sort((a, b) => a - b);
+  }
+
+  /**
+   * 统计每个标签包含的 splat 数量
+   */
+  countByLabel(): Map<number, number> {
+    const counts = new Map<number, number>();
+    for (let i = 0; i < this.ids.length; i++) {
+      const id = this.ids[i];
+      counts.set(id, (counts.get(id) ?? 0) + 1);
+    }
+    return counts;
+  }
+
+  /**
+   * 转换为 GPU 上传格式（统一扩展为 u32）
+   */
+  toGPUData(): Uint32Array {
+    if (this.ids instanceof Uint32Array) {
+      return this.ids;
+    }
+    return Uint32Array.from(this.ids);
+  }
+}
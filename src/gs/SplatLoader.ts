@@ -12,6 +12,7 @@
  */
 
 import { SplatCPU } from "./PLYLoader";
+import { SplatImportTransform, sampleByImportance, type SplatImportOptions } from "./SplatImport";
 
 /** .splat 文件每个 splat 的字节大小 */
 const SPLAT_SIZE = 32;
@@ -82,9 +83,10 @@ function inverseSigmoid(y: number): number {
 /**
  * 加载并解析 .splat 文件
  * @param url .splat 文件的 URL
+ * @param options 加载选项（缩放、上轴、降采样等）
  * @returns SplatCPU 数组
  */
-export async function loadSplat(url: string): Promise<SplatCPU[]> {
+export async function loadSplat(url: string, options: SplatImportOptions = {}): Promise<SplatCPU[]> {
   // 获取文件
   const response = await fetch(url);
   if (!response.ok) {
@@ -92,28 +94,46 @@ export async function loadSplat(url: string): Promise<SplatCPU[]> {
   }
   const buffer = await response.arrayBuffer();
 
-  return deserializeSplat(buffer);
+  return deserializeSplat(buffer, options);
 }
 
 /**
  * 从 ArrayBuffer 解析 splat 数据
  * @param data 文件的 ArrayBuffer
+ * @param options 加载选项（缩放、上轴、降采样等）
  * @returns SplatCPU 数组
  */
-export function deserializeSplat(data: ArrayBufferLike): SplatCPU[] {
+export function deserializeSplat(data: ArrayBufferLike, options: SplatImportOptions = {}): SplatCPU[] {
   // 验证文件格式
   validateSplatFile(data);
 
   const totalSplats = Math.floor(data.byteLength / SPLAT_SIZE);
 
   const dataView = new DataView(data);
-  const splats: SplatCPU[] = new Array(totalSplats);
+  const splats: SplatCPU[] = [];
+  const transform = new SplatImportTransform(options);
+
+  // 超过 maxSplats 时按重要性 (opacity * 最大缩放) 降采样，以文件大小为种子保证确定性
+  let sampleIndices: Uint32Array | null = null;
+  if (totalSplats > transform.maxSplats) {
+    const importance = new Float32Array(totalSplats);
+    for (let i = 0; i < totalSplats; i++) {
+      const off = i * SPLAT_SIZE;
+      importance[i] = (dataView.getUint8(off + 27) / 255) * Math.max(
+        dataView.getFloat32(off + 12, true),
+        dataView.getFloat32(off + 16, true),
+        dataView.getFloat32(off + 20, true),
+      );
+    }
+    sampleIndices = sampleByImportance(importance, transform.maxSplats, data.byteLength);
+  }
+  const loadCount = sampleIndices ? sampleIndices.length : totalSplats;
 
   // 预分配共享的 SH 系数数组（全为 0，因为 .splat 不包含 SH）
-  const shRestBuffer = new Float32Array(totalSplats * 45);
+  const shRestBuffer = new Float32Array(loadCount * 45);
 
-  for (let i = 0; i < totalSplats; i++) {
-    const off = i * SPLAT_SIZE;
+  for (let i = 0; i < loadCount; i++) {
+    const off = (sampleIndices ? sampleIndices[i] : i) * SPLAT_SIZE;
 
     // 读取位置 (float32 × 3 = 12 bytes)
     const x = dataView.getFloat32(off + 0, true);
@@ -163,7 +183,7 @@ export function deserializeSplat(data: ArrayBufferLike): SplatCPU[] {
     const shOffset = i * 45;
     const shRest = shRestBuffer.subarray(shOffset, shOffset + 45);
 
-    splats[i] = {
+    const splat: SplatCPU = {
       mean: [x, y, z],
       scale: [scale_0, scale_1, scale_2],
       rotation: [
@@ -176,6 +196,15 @@ export function deserializeSplat(data: ArrayBufferLike): SplatCPU[] {
       opacity,
       shRest,
     };
+
+    // .splat 不含高阶 SH，无需旋转 SH 系数
+    if (transform.transformsGeometry) {
+      transform.apply(splat.mean, 0, splat.scale, 0, splat.rotation, 0);
+    }
+    if (transform.sanitize && !transform.isValid(splat.mean, 0, splat.scale, 0, splat.rotation, 0, splat.opacity)) {
+      continue;
+    }
+    splats.push(splat);
   }
 
   return splats;
@@ -0,0 +1,125 @@
+/**
+ * SplatLod - 基于 chunk 投影尺寸和全局预算的 LOD 选择
+ *
+ * 八叉树叶子内部的 splat 按重要性降序排列，某个 LOD 级别即叶子区间的前缀：
+ * 投影尺寸越小的 chunk 保留的比例越低，总数超过预算时整体按比例缩减。
+ * 每个 chunk 记录上一帧的比例，相对变化不超过 hysteresis 时沿用，避免来回跳变
+ */
+
+import type { SplatOctree } from "./SplatOctree";
+
+/**
+ * LOD 配置
+ */
+export interface SplatLodConfig {
+  /** 每帧最多交给 GPU 剔除和排序的 splat 数量，0 表示不限制 */
+  budget: number;
+  /** chunk 投影直径 (像素) 达到该值时保留全部 splat */
+  fullDetailPixels: number;
+  /** 每个 chunk 至少保留的比例（预算不足时仍可能更低） */
+  minFraction: number;
+  /** 比例的相对变化小于该值时沿用上一帧的比例 */
+  hysteresis: number;
+}
+
+/** 默认 LOD 配置 */
+export const DEFAULT_SPLAT_LOD_CONFIG: SplatLodConfig = {
+  budget: 0,
+  fullDetailPixels: 256,
+  minFraction: 0.05,
+  hysteresis: 0.15,
+};
+
+/**
+ * SplatLodSelector - 逐 chunk 选择保留比例
+ */
+export class SplatLodSelector {
+  private octree: SplatOctree;
+  // 每个节点上一帧的保留比例（只用到叶子）
+  private fractions: Float32Array;
+  private counts: number[] = [];
+
+  constructor(octree: SplatOctree) {
+    this.octree = octree;
+    this.fractions = new Float32Array(octree.nodes.length).fill(1);
+  }
+
+  /**
+   * 为可见叶子选择 LOD
+   * @param leaves 可见叶子节点索引（按 order 顺序）
+   * @param viewProjection 列主序 view-projection 矩阵
+   * @param model 列主序 model 矩阵
+   * @param pixelScale 投影到像素的缩放 (|proj[1][1]| * 屏幕高度 / 2)
+   * @param out 输出 [start, count] 区间对，相邻区间会合并
+   * @returns 选中的 splat 总数
+   */
+  select(
+    leaves: number[],
+    viewProjection: ArrayLike<number>,
+    model: ArrayLike<number>,
+    pixelScale: number,
+    config: SplatLodConfig,
+    out: number[],
+  ): number {
+    out.length = 0;
+    const nodes = this.octree.nodes;
+    const modelScale = Math.max(
+      Math.hypot(model[0], model[1], model[2]),
+      Math.hypot(model[4], model[5], model[6]),
+      Math.hypot(model[8], model[9], model[10]),
+    );
+    const minFraction = Math.min(Math.max(config.minFraction, 0), 1);
+
+    // 目标比例：按 chunk 包围球的投影直径
+    const counts = this.counts;
+    counts.length = leaves.length;
+    let total = 0;
+    for (let i = 0; i < leaves.length; i++) {
+      const node = nodes[leaves[i]];
+      const cx = (node.min[0] + node.max[0]) * 0.5;
+      const cy = (node.min[1] + node.max[1]) * 0.5;
+      const cz = (node.min[2] + node.max[2]) * 0.5;
+      const radius = 0.5 * modelScale * Math.hypot(
+        node.max[0] - node.min[0],
+        node.max[1] - node.min[1],
+        node.max[2] - node.min[2],
+      );
+      const wx = model[0] * cx + model[4] * cy + model[8] * cz + model[12];
+      const wy = model[1] * cx + model[5] * cy + model[9] * cz + model[13];
+      const wz = model[2] * cx + model[6] * cy + model[10] * cz + model[14];
+      const w = viewProjection[3] * wx + viewProjection[7] * wy + viewProjection[11] * wz + viewProjection[15];
+
+      let target = 1;
+      if (w > radius && config.fullDetailPixels > 0) {
+        const diameter = (2 * radius * pixelScale) / w;
+        target = Math.min(Math.max(diameter / config.fullDetailPixels, minFraction), 1);
+      }
+
+      const last = this.fractions[leaves[i]];
+      if (Math.abs(target - last) > config.hysteresis * last) {
+        this.fractions[leaves[i]] = target;
+      }
+      counts[i] = Math.ceil(node.count * this.fractions[leaves[i]]);
+      total += counts[i];
+    }
+
+    // 全局预算：整体按比例缩减（不写回比例，预算恢复后立即回到原级别）
+    const scale = config.budget > 0 && total > config.budget ? config.budget / total : 1;
+
+    let selected = 0;
+    for (let i = 0; i < leaves.length; i++) {
+      const node = nodes[leaves[i]];
+      const count = scale < 1 ? Math.floor(counts[i] * scale) : counts[i];
+      if (count === 0) continue;
+      const last = out.length - 2;
+      if (last >= 0 && out[last] + out[last + 1] === node.start) {
+        out[last + 1] += count;
+      } else {
+        out.push(node.start, count);
+      }
+      selected += count;
+    }
+
+    return selected;
+  }
+}
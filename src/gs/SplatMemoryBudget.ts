@@ -0,0 +1,211 @@
+/**
+ * SplatMemoryBudget - splat 显存预算
+ *
+ * 各 splat 实体通过 getGPUMemoryUsage 上报自己占用的显存，总量超出预算时按以下顺序自动降级，
+ * 而不是让设备分配失败：
+ * 1. 释放 SplatBufferPool 中缓存的空闲 buffer
+ * 2. DropSH：SH 降到 L0（存储布局固定的渲染器只减少着色带宽，不减少显存）
+ * 3. Pack：切换到纹理打包的紧凑格式 (约 40~52 bytes/splat)
+ * 4. EvictChunks：逐出最久未被看到的分块（CPU 数据保留，回到视野且预算允许时恢复）
+ * 每个步骤都从最久未被看到的实体开始，直到总量回到预算以内
+ */
+
+import { SplatBufferPool } from "./SplatBufferPool";
+
+/**
+ * 降级步骤
+ */
+export enum SplatMemoryDowngrade {
+  DropSH = "drop-sh",
+  Pack = "pack",
+  EvictChunks = "evict-chunks",
+}
+
+/** 降级顺序 */
+const DOWNGRADE_ORDER: SplatMemoryDowngrade[] = [
+  SplatMemoryDowngrade.DropSH,
+  SplatMemoryDowngrade.Pack,
+  SplatMemoryDowngrade.EvictChunks,
+];
+
+/**
+ * 受预算管理的实体（渲染器直接实现，或由上层包装）
+ */
+export interface SplatMemoryEntity {
+  /** 当前占用的显存（字节） */
+  getGPUMemoryUsage?(): number;
+  /** 执行一次降级，返回释放的字节数（不支持或无可释放时返回 0） */
+  downgradeMemory?(step: SplatMemoryDowngrade): number;
+  /** 在 availableBytes 以内恢复之前逐出的数据，返回新分配的字节数 */
+  restoreMemory?(availableBytes: number): number;
+}
+
+/**
+ * 显存使用报告
+ */
+export interface SplatMemoryReport {
+  /** 预算（字节），Infinity 表示不限制 */
+  budget: number;
+  /** 总占用（实体 + 池中空闲 buffer） */
+  total: number;
+  /** SplatBufferPool 中缓存的空闲字节数 */
+  pooledBytes: number;
+  /** 各实体占用，按注册顺序 */
+  entities: { label: string; bytes: number }[];
+}
+
+interface BudgetEntry {
+  entity: SplatMemoryEntity;
+  label: string;
+  lastViewed: number;
+}
+
+/**
+ * 汇总 buffer 尺寸
+ */
+export function getBuffersByteSize(buffers: ReadonlyArray<GPUBuffer | null | undefined>): number {
+  let total = 0;
+  for (const buffer of buffers) {
+    if (buffer) total += buffer.size;
+  }
+  return total;
+}
+
+/**
+ * splat 显存预算管理器
+ */
+export class SplatMemoryBudget {
+  private pool: SplatBufferPool;
+  private entries: BudgetEntry[] = [];
+  private budget: number = Infinity;
+  private viewCounter: number = 0;
+
+  constructor(device: GPUDevice) {
+    this.pool = SplatBufferPool.get(device);
+  }
+
+  /**
+   * 设置预算（字节），Infinity 关闭
+   */
+  setBudget(bytes: number): void {
+    this.budget = bytes > 0 ? bytes : Infinity;
+  }
+
+  /**
+   * 获取预算（字节）
+   */
+  getBudget(): number {
+    return this.budget;
+  }
+
+  /**
+   * 注册实体
+   */
+  register(entity: SplatMemoryEntity, label: string = "splats"): void {
+    if (this.entries.some((e) => e.entity === entity)) {
+      return;
+    }
+    this.entries.push({ entity, label, lastViewed: ++this.viewCounter });
+  }
+
+  /**
+   * 注销实体
+   */
+  unregister(entity: SplatMemoryEntity): void {
+    this.entries = this.entries.filter((e) => e.entity !== entity);
+  }
+
+  /**
+   * 标记实体本帧被看到（用于最久未看到优先降级）
+   */
+  markViewed(entity: SplatMemoryEntity): void {
+    const entry = this.entries.find((e) => e.entity === entity);
+    if (entry) {
+      entry.lastViewed = ++this.viewCounter;
+    }
+  }
+
+  /**
+   * 获取总占用（字节）
+   */
+  getUsage(): number {
+    let total = this.pool.getStats().pooledBytes;
+    for (const entry of this.entries) {
+      total += entry.entity.getGPUMemoryUsage?.() ?? 0;
+    }
+    return total;
+  }
+
+  /**
+   * 获取显存使用报告
+   */
+  getReport(): SplatMemoryReport {
+    const pooledBytes = this.pool.getStats().pooledBytes;
+    const entities = this.entries.map((e) => ({ label: e.label, bytes: e.entity.getGPUMemoryUsage?.() ?? 0 }));
+    return {
+      budget: this.budget,
+      total: entities.reduce((sum, e) => sum + e.bytes, pooledBytes),
+      pooledBytes,
+      entities,
+    };
+  }
+
+  /**
+   * 为即将分配的 bytes 字节腾出空间（按需降级已有实体）
+   * @returns 腾出后是否能放下
+   */
+  reserve(bytes: number): boolean {
+    if (this.budget === Infinity) {
+      return true;
+    }
+    return this.makeRoom(this.budget - bytes);
+  }
+
+  /**
+   * 每帧调用：超出预算时降级，有余量时恢复之前逐出的数据
+   */
+  enforce(): void {
+    if (this.budget === Infinity) {
+      return;
+    }
+    if (!this.makeRoom(this.budget)) {
+      return;
+    }
+    // 最近看到的实体优先恢复
+    let available = this.budget - this.getUsage();
+    const entries = [...this.entries].sort((a, b) => b.lastViewed - a.lastViewed);
+    for (const entry of entries) {
+      if (available <= 0) break;
+      available -= entry.entity.restoreMemory?.(available) ?? 0;
+    }
+  }
+
+  /**
+   * 降级直到总占用不超过 limit
+   */
+  private makeRoom(limit: number): boolean {
+    let usage = this.getUsage();
+    if (usage <= limit) {
+      return true;
+    }
+    this.pool.trim(0);
+    usage = this.getUsage();
+
+    const entries = [...this.entries].sort((a, b) => a.lastViewed - b.lastViewed);
+    for (const step of DOWNGRADE_ORDER) {
+      for (const entry of entries) {
+        while (usage > limit) {
+          const freed = entry.entity.downgradeMemory?.(step) ?? 0;
+          if (freed <= 0) break;
+          // 释放的 buffer 会回到池中，同样需要销毁
+          this.pool.trim(0);
+          usage = this.getUsage();
+        }
+        if (usage <= limit) {
+          return true;
+        }
+      }
+    }
+    return usage <= limit;
+  }
+}
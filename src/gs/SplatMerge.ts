@@ -0,0 +1,396 @@
+/**
+ * SplatMerge - 合并 / 拆分 splat 实体的数据
+ *
+ * 合并：把每个实体的世界变换烘焙进 splat 数据（位置、旋转、缩放和 SH 系数），
+ * 再拼接为一份数据，合并后的实体使用单位变换（或目标图层的逆变换）即可保持原样。
+ * - 旋转 + 等比缩放：四元数左乘旋转，缩放乘以比例
+ * - 非等比缩放 / 剪切：协方差 Σ' = A Σ Aᵀ 特征分解得到新的旋转和缩放（2DGS 的最小轴放在 z）
+ * - SH 系数按线性部分的正交分量（极分解）旋转
+ * 拆分：按选择集把数据分成两份，变换保持不变
+ */
+
+import type { CompactSplatData } from "./PLYLoaderMobile";
+import type { IGSSplatRenderer } from "./IGSSplatRenderer";
+import { gatherSplatData } from "./SplatSegments";
+import { computeSHRotation, rotateSHCoefficients } from "./SplatImport";
+
+/** 每个 splat 的 SH 系数数量 */
+const SH_COEFF_COUNT = 45;
+
+/**
+ * 待合并的一份数据及其变换
+ */
+export interface SplatMergeItem {
+  data: CompactSplatData;
+  /** 烘焙进数据的变换（列主序 4x4），不传表示单位变换 */
+  matrix?: ArrayLike<number>;
+}
+
+/**
+ * 拆分结果
+ */
+export interface SplatSplitResult {
+  /** 选中的 splat */
+  selected: CompactSplatData;
+  /** 其余的 splat */
+  rest: CompactSplatData;
+}
+
+/**
+ * 把变换烘焙进 splat 数据，返回新数据（不修改输入，颜色等不受变换影响的属性与输入共享）
+ * @param matrix 列主序 4x4 仿射变换
+ */
+export function bakeSplatTransform(data: CompactSplatData, matrix: ArrayLike<number>): CompactSplatData {
+  const out: CompactSplatData = {
+    ...data,
+    positions: new Float32Array(data.positions),
+    scales: new Float32Array(data.scales),
+    rotations: new Float32Array(data.rotations),
+  };
+  if (data.shCoeffs) out.shCoeffs = new Float32Array(data.shCoeffs);
+
+  // 线性部分（行主序）
+  const a = [
+    matrix[0], matrix[4], matrix[8],
+    matrix[1], matrix[5], matrix[9],
+    matrix[2], matrix[6], matrix[10],
+  ];
+  const similarity = getSimilarityScale(a);
+  const rotation = similarity > 0 ? a.map((v) => v / similarity) : polarRotation(a);
+  const rotationQuat = similarity > 0 ? matrixToQuat(rotation) : null;
+  const shRotation = out.shCoeffs ? computeSHRotation(rotation) : null;
+
+  const { positions, scales, rotations } = out;
+  for (let i = 0; i < data.count; i++) {
+    const x = positions[i * 3], y = positions[i * 3 + 1], z = positions[i * 3 + 2];
+    positions[i * 3] = a[0] * x + a[1] * y + a[2] * z + matrix[12];
+    positions[i * 3 + 1] = a[3] * x + a[4] * y + a[5] * z + matrix[13];
+    positions[i * 3 + 2] = a[6] * x + a[7] * y + a[8] * z + matrix[14];
+
+    if (rotationQuat) {
+      // q' = r ⊗ q
+      const [rw, rx, ry, rz] = rotationQuat;
+      const qw = rotations[i * 4], qx = rotations[i * 4 + 1], qy = rotations[i * 4 + 2], qz = rotations[i * 4 + 3];
+      rotations[i * 4] = rw * qw - rx * qx - ry * qy - rz * qz;
+      rotations[i * 4 + 1] = rw * qx + rx * qw + ry * qz - rz * qy;
+      rotations[i * 4 + 2] = rw * qy - rx * qz + ry * qw + rz * qx;
+      rotations[i * 4 + 3] = rw * qz + rx * qy - ry * qx + rz * qw;
+      scales[i * 3] *= similarity;
+      scales[i * 3 + 1] *= similarity;
+      scales[i * 3 + 2] *= similarity;
+    } else {
+      bakeCovariance(a, scales, rotations, i, data.is2DGS ?? false);
+    }
+
+    if (shRotation) {
+      rotateSHCoefficients(out.shCoeffs!, i * SH_COEFF_COUNT, shRotation);
+    }
+  }
+  return out;
+}
+
+/**
+ * 合并多份数据（先烘焙各自的变换）
+ * 任一份包含 SH / 标签 / 自发光时结果包含对应属性，缺少的部分填 0；
+ * 原点相同时保留，否则丢弃（位置已经是烘焙后的坐标）
+ */
+export function mergeSplatData(items: ReadonlyArray<SplatMergeItem>): CompactSplatData {
+  if (items.length === 0) {
+    throw new Error("[SplatMerge] 没有可合并的数据");
+  }
+  const is2DGS = items[0].data.is2DGS ?? false;
+  if (items.some((item) => (item.data.is2DGS ?? false) !== is2DGS)) {
+    throw new Error("[SplatMerge] 不能合并 3DGS 和 2DGS 数据");
+  }
+
+  const baked = items.map((item) => (item.matrix ? bakeSplatTransform(item.data, item.matrix) : item.data));
+  const count = baked.reduce((sum, data) => sum + data.count, 0);
+  const origin = baked[0].origin;
+  const out: CompactSplatData = {
+    count,
+    positions: new Float32Array(count * 3),
+    scales: new Float32Array(count * 3),
+    rotations: new Float32Array(count * 4),
+    colors: new Float32Array(count * 3),
+    opacities: new Float32Array(count),
+    is2DGS,
+  };
+  if (origin && baked.every((data) => data.origin && data.origin.every((v, i) => v === origin[i]))) {
+    out.origin = [...origin];
+  }
+  if (baked.some((data) => data.shCoeffs)) out.shCoeffs = new Float32Array(count * SH_COEFF_COUNT);
+  if (baked.some((data) => data.labels)) out.labels = new Uint32Array(count);
+  if (baked.some((data) => data.emissive)) out.emissive = new Float32Array(count);
+
+  let offset = 0;
+  for (const data of baked) {
+    out.positions.set(data.positions.subarray(0, data.count * 3), offset * 3);
+    out.scales.set(data.scales.subarray(0, data.count * 3), offset * 3);
+    out.rotations.set(data.rotations.subarray(0, data.count * 4), offset * 4);
+    out.colors.set(data.colors.subarray(0, data.count * 3), offset * 3);
+    out.opacities.set(data.opacities.subarray(0, data.count), offset);
+    if (out.shCoeffs && data.shCoeffs) {
+      out.shCoeffs.set(data.shCoeffs.subarray(0, data.count * SH_COEFF_COUNT), offset * SH_COEFF_COUNT);
+    }
+    if (out.labels && data.labels) out.labels.set(data.labels.subarray(0, data.count), offset);
+    if (out.emissive && data.emissive) out.emissive.set(data.emissive.subarray(0, data.count), offset);
+    offset += data.count;
+  }
+  return out;
+}
+
+/**
+ * 按掩码把数据拆成两份（掩码非 0 的进入 selected）
+ */
+export function partitionSplatData(data: CompactSplatData, mask: ArrayLike<number>): SplatSplitResult {
+  let selectedCount = 0;
+  for (let i = 0; i < data.count; i++) {
+    if (mask[i]) selectedCount++;
+  }
+  const selected = new Uint32Array(selectedCount);
+  const rest = new Uint32Array(data.count - selectedCount);
+  for (let i = 0, s = 0, r = 0; i < data.count; i++) {
+    if (mask[i]) selected[s++] = i;
+    else rest[r++] = i;
+  }
+  return { selected: gatherSplatData(data, selected), rest: gatherSplatData(data, rest) };
+}
+
+/**
+ * 读取实体的数据用于合并（需要实体提供 CPU 端数据 getSplats），已删除的 splat 不参与合并
+ * @param parentMatrix 合并后实体的父级世界矩阵，烘焙时抵消该变换；不传表示放在世界空间
+ */
+export function getSplatMergeItem(renderer: IGSSplatRenderer, parentMatrix?: ArrayLike<number>): SplatMergeItem {
+  const splats = renderer.getSplats?.();
+  if (!splats) {
+    throw new Error("[SplatMerge] 渲染器不提供 CPU 端 splat 数据，无法合并");
+  }
+  const deleted = renderer.getDeletedMask?.();
+  const data = deleted && (renderer.getDeletedCount?.() ?? 0) > 0
+    ? splats.removeDeleted(deleted).splats.getData()
+    : splats.getData();
+  let matrix: ArrayLike<number> = renderer.getModelMatrix();
+  if (parentMatrix) {
+    const inverse = invertAffine(parentMatrix);
+    if (!inverse) {
+      throw new Error("[SplatMerge] 目标父级变换不可逆");
+    }
+    matrix = multiplyAffine(inverse, matrix);
+  }
+  return { data, matrix };
+}
+
+/**
+ * 按实体的选择集拆分数据（需要 getSplats），已删除的 splat 丢弃
+ * @returns 没有选中或全部选中时返回 null
+ */
+export function splitSplatEntityData(renderer: IGSSplatRenderer): SplatSplitResult | null {
+  const splats = renderer.getSplats?.();
+  if (!splats) {
+    throw new Error("[SplatMerge] 渲染器不提供 CPU 端 splat 数据，无法拆分");
+  }
+  const selection = renderer.getSelection?.();
+  if (!selection || selection.getSelectedCount() === 0) {
+    return null;
+  }
+  const deleted = renderer.getDeletedMask?.();
+  let data = splats.getData();
+  let mask = selection.getMask();
+  if (deleted && (renderer.getDeletedCount?.() ?? 0) > 0) {
+    const removal = splats.removeDeleted(deleted, selection);
+    data = removal.splats.getData();
+    mask = removal.selection!.getMask();
+  }
+  const result = partitionSplatData(data, mask);
+  return result.selected.count > 0 && result.rest.count > 0 ? result : null;
+}
+
+// ============================================
+// 矩阵工具（3x3 行主序）
+// ============================================
+
+// 线性部分为旋转 × 等比缩放时返回缩放比例，否则返回 0
+function getSimilarityScale(a: number[]): number {
+  const det = a[0] * (a[4] * a[8] - a[5] * a[7]) - a[1] * (a[3] * a[8] - a[5] * a[6]) + a[2] * (a[3] * a[7] - a[4] * a[6]);
+  if (!(det > 0)) return 0;
+  const s = Math.cbrt(det);
+  const tolerance = 1e-5 * s * s;
+  // AᵀA = s² I
+  for (let i = 0; i < 3; i++) {
+    for (let j = 0; j < 3; j++) {
+      const dot = a[i] * a[j] + a[3 + i] * a[3 + j] + a[6 + i] * a[6 + j];
+      if (Math.abs(dot - (i === j ? s * s : 0)) > tolerance) return 0;
+    }
+  }
+  return s;
+}
+
+// 极分解的正交分量：R ← (R + R⁻ᵀ) / 2 迭代
+function polarRotation(a: number[]): number[] {
+  let r = a.slice();
+  for (let iter = 0; iter < 20; iter++) {
+    const inv = invert3(r);
+    if (!inv) break;
+    const next = r.map((v, k) => (v + inv[(k % 3) * 3 + Math.floor(k / 3)]) / 2);
+    const delta = next.reduce((sum, v, k) => sum + Math.abs(v - r[k]), 0);
+    r = next;
+    if (delta < 1e-9) break;
+  }
+  return r;
+}
+
+function invert3(m: number[]): number[] | null {
+  const c0 = m[4] * m[8] - m[5] * m[7];
+  const c1 = m[5] * m[6] - m[3] * m[8];
+  const c2 = m[3] * m[7] - m[4] * m[6];
+  const det = m[0] * c0 + m[1] * c1 + m[2] * c2;
+  if (Math.abs(det) < 1e-20) return null;
+  const d = 1 / det;
+  return [
+    c0 * d, (m[2] * m[7] - m[1] * m[8]) * d, (m[1] * m[5] - m[2] * m[4]) * d,
+    c1 * d, (m[0] * m[8] - m[2] * m[6]) * d, (m[2] * m[3] - m[0] * m[5]) * d,
+    c2 * d, (m[1] * m[6] - m[0] * m[7]) * d, (m[0] * m[4] - m[1] * m[3]) * d,
+  ];
+}
+
+// 旋转矩阵 -> 四元数 [w, x, y, z]
+function matrixToQuat(m: number[]): [number, number, number, number] {
+  const trace = m[0] + m[4] + m[8];
+  let w: number, x: number, y: number, z: number;
+  if (trace > 0) {
+    const s = Math.sqrt(trace + 1) * 2;
+    w = 0.25 * s;
+    x = (m[7] - m[5]) / s;
+    y = (m[2] - m[6]) / s;
+    z = (m[3] - m[1]) / s;
+  } else if (m[0] > m[4] && m[0] > m[8]) {
+    const s = Math.sqrt(1 + m[0] - m[4] - m[8]) * 2;
+    w = (m[7] - m[5]) / s;
+    x = 0.25 * s;
+    y = (m[1] + m[3]) / s;
+    z = (m[2] + m[6]) / s;
+  } else if (m[4] > m[8]) {
+    const s = Math.sqrt(1 + m[4] - m[0] - m[8]) * 2;
+    w = (m[2] - m[6]) / s;
+    x = (m[1] + m[3]) / s;
+    y = 0.25 * s;
+    z = (m[5] + m[7]) / s;
+  } else {
+    const s = Math.sqrt(1 + m[8] - m[0] - m[4]) * 2;
+    w = (m[3] - m[1]) / s;
+    x = (m[2] + m[6]) / s;
+    y = (m[5] + m[7]) / s;
+    z = 0.25 * s;
+  }
+  const len = Math.hypot(w, x, y, z) || 1;
+  return [w / len, x / len, y / len, z / len];
+}
+
+// 一般线性变换下的协方差：Σ' = (A R S)(A R S)ᵀ，特征分解写回缩放和旋转
+function bakeCovariance(a: number[], scales: Float32Array, rotations: Float32Array, i: number, is2DGS: boolean): void {
+  const qw = rotations[i * 4], qx = rotations[i * 4 + 1], qy = rotations[i * 4 + 2], qz = rotations[i * 4 + 3];
+  const qlen = Math.hypot(qw, qx, qy, qz) || 1;
+  const w = qw / qlen, x = qx / qlen, y = qy / qlen, z = qz / qlen;
+  const r = [
+    1 - 2 * (y * y + z * z), 2 * (x * y - w * z), 2 * (x * z + w * y),
+    2 * (x * y + w * z), 1 - 2 * (x * x + z * z), 2 * (y * z - w * x),
+    2 * (x * z - w * y), 2 * (y * z + w * x), 1 - 2 * (x * x + y * y),
+  ];
+  // M = A R S
+  const m = new Array<number>(9);
+  for (let row = 0; row < 3; row++) {
+    for (let col = 0; col < 3; col++) {
+      m[row * 3 + col] = (a[row * 3] * r[col] + a[row * 3 + 1] * r[3 + col] + a[row * 3 + 2] * r[6 + col]) * scales[i * 3 + col];
+    }
+  }
+  const cov = new Array<number>(9);
+  for (let row = 0; row < 3; row++) {
+    for (let col = 0; col < 3; col++) {
+      cov[row * 3 + col] = m[row * 3] * m[col * 3] + m[row * 3 + 1] * m[col * 3 + 1] + m[row * 3 + 2] * m[col * 3 + 2];
+    }
+  }
+
+  const { values, vectors } = eigenSymmetric3(cov);
+  // 2DGS 的法线方向（最小特征值）必须在 z 轴
+  const order = [0, 1, 2];
+  if (is2DGS) order.sort((p, q) => values[q] - values[p]);
+  const v = new Array<number>(9);
+  for (let col = 0; col < 3; col++) {
+    for (let row = 0; row < 3; row++) {
+      v[row * 3 + col] = vectors[row * 3 + order[col]];
+    }
+    scales[i * 3 + col] = Math.sqrt(Math.max(values[order[col]], 0));
+  }
+  if (is2DGS) scales[i * 3 + 2] = 0;
+  // 特征向量符号任意，保证右手系
+  const det = v[0] * (v[4] * v[8] - v[5] * v[7]) - v[1] * (v[3] * v[8] - v[5] * v[6]) + v[2] * (v[3] * v[7] - v[4] * v[6]);
+  if (det < 0) {
+    v[2] = -v[2];
+    v[5] = -v[5];
+    v[8] = -v[8];
+  }
+  rotations.set(matrixToQuat(v), i * 4);
+}
+
+// 对称 3x3 矩阵的 Jacobi 特征分解，vectors 按列存放特征向量（行主序）
+function eigenSymmetric3(m: number[]): { values: number[]; vectors: number[] } {
+  const a = m.slice();
+  const v = [1, 0, 0, 0, 1, 0, 0, 0, 1];
+  for (let sweep = 0; sweep < 16; sweep++) {
+    const off = Math.abs(a[1]) + Math.abs(a[2]) + Math.abs(a[5]);
+    if (off < 1e-20 * (Math.abs(a[0]) + Math.abs(a[4]) + Math.abs(a[8]) + 1e-30)) break;
+    for (const [p, q] of [[0, 1], [0, 2], [1, 2]]) {
+      const apq = a[p * 3 + q];
+      if (apq === 0) continue;
+      const theta = (a[q * 3 + q] - a[p * 3 + p]) / (2 * apq);
+      const t = Math.sign(theta || 1) / (Math.abs(theta) + Math.sqrt(theta * theta + 1));
+      const c = 1 / Math.sqrt(t * t + 1);
+      const s = t * c;
+      // A ← Jᵀ A J
+      for (let k = 0; k < 3; k++) {
+        const akp = a[k * 3 + p], akq = a[k * 3 + q];
+        a[k * 3 + p] = c * akp - s * akq;
+        a[k * 3 + q] = s * akp + c * akq;
+      }
+      for (let k = 0; k < 3; k++) {
+        const apk = a[p * 3 + k], aqk = a[q * 3 + k];
+        a[p * 3 + k] = c * apk - s * aqk;
+        a[q * 3 + k] = s * apk + c * aqk;
+      }
+      for (let k = 0; k < 3; k++) {
+        const vkp = v[k * 3 + p], vkq = v[k * 3 + q];
+        v[k * 3 + p] = c * vkp - s * vkq;
+        v[k * 3 + q] = s * vkp + c * vkq;
+      }
+    }
+  }
+  return { values: [a[0], a[4], a[8]], vectors: v };
+}
+
+// ============================================
+// 矩阵工具（4x4 列主序仿射）
+// ============================================
+
+function multiplyAffine(a: ArrayLike<number>, b: ArrayLike<number>): Float64Array {
+  const out = new Float64Array(16);
+  for (let col = 0; col < 4; col++) {
+    for (let row = 0; row < 4; row++) {
+      let sum = 0;
+      for (let k = 0; k < 4; k++) sum += a[k * 4 + row] * b[col * 4 + k];
+      out[col * 4 + row] = sum;
+    }
+  }
+  return out;
+}
+
+function invertAffine(m: ArrayLike<number>): Float64Array | null {
+  const inv = invert3([m[0], m[4], m[8], m[1], m[5], m[9], m[2], m[6], m[10]]);
+  if (!inv) return null;
+  const out = new Float64Array(16);
+  for (let row = 0; row < 3; row++) {
+    for (let col = 0; col < 3; col++) out[col * 4 + row] = inv[row * 3 + col];
+    out[12 + row] = -(inv[row * 3] * m[12] + inv[row * 3 + 1] * m[13] + inv[row * 3 + 2] * m[14]);
+  }
+  out[15] = 1;
+  return out;
+}
@@ -0,0 +1,78 @@
+/**
+ * SplatMirror - 按平面镜像 splat
+ *
+ * 镜像 M = I - 2nnᵀ 的行列式为 -1，不能直接写成四元数。利用 M = -R₁₈₀（绕法线旋转 180°）：
+ * - 位置：p' = p - 2((p - p₀)·n) n
+ * - 协方差：M Σ Mᵀ = R₁₈₀ Σ R₁₈₀ᵀ（负号抵消），旋转左乘 q₁₈₀ = (0, n)，缩放不变
+ * - SH：先按 R₁₈₀ 旋转，再乘以宇称 (-1)^l，即奇数阶 (l = 1, 3) 取反
+ * 用于修复只拍到一半的对称物体：复制选中的 splat 并镜像到平面另一侧
+ */
+
+import type { CompactSplatData } from "./PLYLoaderMobile";
+import type { Vec3Tuple } from "../types";
+import { computeSHRotation, rotateSHCoefficients } from "./SplatImport";
+
+/** 每个 splat 的 SH 系数数量 */
+const SH_COEFF_COUNT = 45;
+// 各阶在交错存储中的 (起始系数, 数量)：l = 1, 2, 3
+const SH_BANDS: [number, number][] = [[0, 3], [3, 5], [8, 7]];
+
+/**
+ * 镜像平面（模型空间）
+ */
+export interface SplatMirrorPlane {
+  /** 法线（不需要归一化） */
+  normal: Vec3Tuple;
+  /** 平面上的一点 */
+  point: Vec3Tuple;
+}
+
+/**
+ * 原地镜像 [start, end) 范围内的 splat
+ */
+export function mirrorSplatData(
+  data: CompactSplatData,
+  plane: SplatMirrorPlane,
+  start: number = 0,
+  end: number = data.count,
+): void {
+  const length = Math.hypot(...plane.normal);
+  if (!(length > 0)) {
+    throw new Error("[SplatMirror] 平面法线不能为零向量");
+  }
+  const [nx, ny, nz] = plane.normal.map((v) => v / length);
+  const [px, py, pz] = plane.point;
+  const { positions, rotations, shCoeffs } = data;
+  // R₁₈₀ = 2nnᵀ - I（行主序）
+  const shRotation = shCoeffs ? computeSHRotation([
+    2 * nx * nx - 1, 2 * nx * ny, 2 * nx * nz,
+    2 * ny * nx, 2 * ny * ny - 1, 2 * ny * nz,
+    2 * nz * nx, 2 * nz * ny, 2 * nz * nz - 1,
+  ]) : null;
+
+  for (let i = start; i < end; i++) {
+    const d = 2 * ((positions[i * 3] - px) * nx + (positions[i * 3 + 1] - py) * ny + (positions[i * 3 + 2] - pz) * nz);
+    positions[i * 3] -= d * nx;
+    positions[i * 3 + 1] -= d * ny;
+    positions[i * 3 + 2] -= d * nz;
+
+    // q' = (0, n) ⊗ q
+    const qw = rotations[i * 4], qx = rotations[i * 4 + 1], qy = rotations[i * 4 + 2], qz = rotations[i * 4 + 3];
+    rotations[i * 4] = -nx * qx - ny * qy - nz * qz;
+    rotations[i * 4 + 1] = nx * qw + ny * qz - nz * qy;
+    rotations[i * 4 + 2] = -nx * qz + ny * qw + nz * qx;
+    rotations[i * 4 + 3] = nx * qy - ny * qx + nz * qw;
+
+    if (shRotation) {
+      const offset = i * SH_COEFF_COUNT;
+      rotateSHCoefficients(shCoeffs!, offset, shRotation);
+      // 奇数阶取反（l = 1 和 l = 3）
+      for (const band of [0, 2]) {
+        const [first, n] = SH_BANDS[band];
+        for (let k = first * 3; k < (first + n) * 3; k++) {
+          shCoeffs![offset + k] = -shCoeffs![offset + k];
+        }
+      }
+    }
+  }
+}
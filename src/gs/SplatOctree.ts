@@ -0,0 +1,330 @@
+/**
+ * SplatOctree - splat 空间分块
+ *
+ * 加载时按 splat 中心构建八叉树，每个叶子是一个 chunk。
+ * order 是重排后的 splat 索引：任意节点的子树在 order 中占一段连续区间，
+ * 因此 CPU 端的层级视锥剔除只需输出若干 [start, count] 区间，
+ * GPU 剔除 pass 只处理这些区间内的 splat，而不是每帧遍历全部 splat。
+ * 提供重要性时叶子内部按重要性降序排列，LOD 只需截取叶子区间的前缀
+ */
+
+import type { Vec3Tuple } from "../types";
+
+/**
+ * 八叉树节点
+ */
+export interface SplatOctreeNode {
+  /** splat 中心的包围盒（模型空间） */
+  min: Vec3Tuple;
+  max: Vec3Tuple;
+  /** 在 order 中的起始位置 */
+  start: number;
+  /** 子树包含的 splat 数量 */
+  count: number;
+  /** 子节点索引，叶子为空 */
+  children: number[];
+}
+
+/**
+ * 八叉树
+ */
+export interface SplatOctree {
+  /** 节点列表，0 为根节点 */
+  nodes: SplatOctreeNode[];
+  /** 按节点重排后的 splat 索引 */
+  order: Uint32Array;
+  /** 叶子数量（即剔除结果区间数量的上限） */
+  leafCount: number;
+}
+
+/**
+ * 构建选项
+ */
+export interface SplatOctreeOptions {
+  /** 叶子最多包含的 splat 数量 */
+  maxSplatsPerChunk?: number;
+  /** 最大深度 */
+  maxDepth?: number;
+  /** 逐 splat 重要性（如 opacity * 面积），提供时叶子内部按重要性降序排列 */
+  importance?: ArrayLike<number>;
+}
+
+/** 默认叶子大小 */
+export const DEFAULT_SPLAT_CHUNK_SIZE = 4096;
+const DEFAULT_MAX_DEPTH = 12;
+
+/**
+ * 构建八叉树
+ * @param positions splat 中心 [x,y,z, x,y,z, ...]
+ * @param count splat 数量
+ */
+export function buildSplatOctree(
+  positions: ArrayLike<number>,
+  count: number,
+  options: SplatOctreeOptions = {},
+): SplatOctree {
+  const maxSplatsPerChunk = Math.max(1, options.maxSplatsPerChunk ?? DEFAULT_SPLAT_CHUNK_SIZE);
+  const maxDepth = options.maxDepth ?? DEFAULT_MAX_DEPTH;
+
+  const order = new Uint32Array(count);
+  for (let i = 0; i < count; i++) {
+    order[i] = i;
+  }
+  const scratch = new Uint32Array(count);
+  const octants = new Uint8Array(count);
+  const nodes: SplatOctreeNode[] = [];
+  let leafCount = 0;
+
+  const importance = options.importance;
+  const sortLeaf = (start: number, nodeCount: number): void => {
+    if (!importance) return;
+    order.subarray(start, start + nodeCount).sort((a, b) => importance[b] - importance[a]);
+  };
+
+  const build = (start: number, nodeCount: number, depth: number): number => {
+    const min: Vec3Tuple = [Infinity, Infinity, Infinity];
+    const max: Vec3Tuple = [-Infinity, -Infinity, -Infinity];
+    for (let i = start; i < start + nodeCount; i++) {
+      const p = order[i] * 3;
+      for (let axis = 0; axis < 3; axis++) {
+        const v = positions[p + axis];
+        if (v < min[axis]) min[axis] = v;
+        if (v > max[axis]) max[axis] = v;
+      }
+    }
+
+    const nodeIndex = nodes.length;
+    const node: SplatOctreeNode = { min, max, start, count: nodeCount, children: [] };
+    nodes.push(node);
+
+    if (nodeCount <= maxSplatsPerChunk || depth >= maxDepth) {
+      sortLeaf(start, nodeCount);
+      leafCount++;
+      return nodeIndex;
+    }
+
+    // 按中心点所在卦限划分 (counting sort)
+    const cx = (min[0] + max[0]) * 0.5;
+    const cy = (min[1] + max[1]) * 0.5;
+    const cz = (min[2] + max[2]) * 0.5;
+    const octantCounts = [0, 0, 0, 0, 0, 0, 0, 0];
+    for (let i = start; i < start + nodeCount; i++) {
+      const p = order[i] * 3;
+      const octant =
+        (positions[p] > cx ? 1 : 0) |
+        (positions[p + 1] > cy ? 2 : 0) |
+        (positions[p + 2] > cz ? 4 : 0);
+      octants[i] = octant;
+      octantCounts[octant]++;
+    }
+
+    // 所有点落在同一卦限（重合点），无法继续划分
+    if (octantCounts.some((c) => c === nodeCount)) {
+      sortLeaf(start, nodeCount);
+      leafCount++;
+      return nodeIndex;
+    }
+
+    const offsets = [0, 0, 0, 0, 0, 0, 0, 0];
+    for (let o = 1; o < 8; o++) {
+      offsets[o] = offsets[o - 1] + octantCounts[o - 1];
+    }
+    const cursor = offsets.slice();
+    for (let i = start; i < start + nodeCount; i++) {
+      scratch[start + cursor[octants[i]]++] = order[i];
+    }
+    order.set(scratch.subarray(start, start + nodeCount), start);
+
+    for (let o = 0; o < 8; o++) {
+      if (octantCounts[o] > 0) {
+        node.children.push(build(start + offsets[o], octantCounts[o], depth + 1));
+      }
+    }
+    return nodeIndex;
+  };
+
+  if (count > 0) {
+    build(0, count, 0);
+  }
+
+  return { nodes, order, leafCount };
+}
+
+/**
+ * 模型空间的视锥平面 (a, b, c, d)：a x + b y + c z + d >= 0 为内侧
+ * 与 GPU 剔除使用相同的裁剪条件（|x|,|y| <= (1 + dilation) w，-dilation w <= z <= w）
+ */
+function getFrustumPlanes(
+  viewProjection: ArrayLike<number>,
+  model: ArrayLike<number>,
+  frustumDilation: number,
+): number[][] {
+  // mvp = viewProjection * model，行 r = [m[r], m[4 + r], m[8 + r], m[12 + r]]
+  const mvp = new Float64Array(16);
+  for (let col = 0; col < 4; col++) {
+    for (let row = 0; row < 4; row++) {
+      let sum = 0;
+      for (let k = 0; k < 4; k++) {
+        sum += viewProjection[k * 4 + row] * model[col * 4 + k];
+      }
+      mvp[col * 4 + row] = sum;
+    }
+  }
+  const getRow = (r: number): number[] => [mvp[r], mvp[4 + r], mvp[8 + r], mvp[12 + r]];
+  const rx = getRow(0);
+  const ry = getRow(1);
+  const rz = getRow(2);
+  const rw = getRow(3);
+  const s = 1 + frustumDilation;
+  return [
+    rw.map((w, i) => s * w - rx[i]),
+    rw.map((w, i) => s * w + rx[i]),
+    rw.map((w, i) => s * w - ry[i]),
+    rw.map((w, i) => s * w + ry[i]),
+    rz.map((z, i) => z + frustumDilation * rw[i]),
+    rw.map((w, i) => w - rz[i]),
+  ];
+}
+
+/** 节点与视锥的关系 */
+const OUTSIDE = 0;
+const INTERSECTS = 1;
+const INSIDE = 2;
+
+function classifyNode(planes: number[][], node: Pick<SplatOctreeNode, "min" | "max">): number {
+  let result = INSIDE;
+  for (const [a, b, c, d] of planes) {
+    // 沿法线方向最远 / 最近的包围盒顶点
+    const far =
+      a * (a > 0 ? node.max[0] : node.min[0]) +
+      b * (b > 0 ? node.max[1] : node.min[1]) +
+      c * (c > 0 ? node.max[2] : node.min[2]) + d;
+    if (far < 0) {
+      return OUTSIDE;
+    }
+    const near =
+      a * (a > 0 ? node.min[0] : node.max[0]) +
+      b * (b > 0 ? node.min[1] : node.max[1]) +
+      c * (c > 0 ? node.min[2] : node.max[2]) + d;
+    if (near < 0) {
+      result = INTERSECTS;
+    }
+  }
+  return result;
+}
+
+/**
+ * 模型空间包围盒是否与视锥相交
+ */
+export function isBoxInFrustum(
+  min: ArrayLike<number>,
+  max: ArrayLike<number>,
+  viewProjection: ArrayLike<number>,
+  model: ArrayLike<number>,
+  frustumDilation: number = 0,
+): boolean {
+  const planes = getFrustumPlanes(viewProjection, model, frustumDilation);
+  return classifyNode(planes, { min: [min[0], min[1], min[2]], max: [max[0], max[1], max[2]] }) !== OUTSIDE;
+}
+
+/**
+ * 节点可见性掩码（如 PVS）的取值，按节点索引
+ * - 0: 子树全部不可见
+ * - 1: 子树部分可见
+ * - 2: 子树全部可见
+ */
+export type SplatNodeVisibility = Uint8Array;
+
+/**
+ * 层级视锥剔除
+ * 完全在视锥内的节点直接输出整个子树区间
+ * @param viewProjection 列主序 view-projection 矩阵
+ * @param model 列主序 model 矩阵
+ * @param out 输出 [start, count] 区间对，相邻区间会合并
+ * @param visibility 节点可见性掩码，不可见的子树直接跳过
+ * @returns 可见区间内的 splat 总数
+ */
+export function cullSplatOctree(
+  octree: SplatOctree,
+  viewProjection: ArrayLike<number>,
+  model: ArrayLike<number>,
+  frustumDilation: number,
+  out: number[],
+  visibility: SplatNodeVisibility | null = null,
+): number {
+  out.length = 0;
+  if (octree.nodes.length === 0) {
+    return 0;
+  }
+  const planes = getFrustumPlanes(viewProjection, model, frustumDilation);
+
+  let total = 0;
+  const emit = (start: number, count: number): void => {
+    const last = out.length - 2;
+    if (last >= 0 && out[last] + out[last + 1] === start) {
+      out[last + 1] += count;
+    } else {
+      out.push(start, count);
+    }
+    total += count;
+  };
+
+  const stack = [0];
+  while (stack.length > 0) {
+    const index = stack.pop()!;
+    const node = octree.nodes[index];
+    const visible = visibility ? visibility[index] : 2;
+    if (visible === 0) continue;
+    const state = classifyNode(planes, node);
+
+    if (state === OUTSIDE) continue;
+    if ((state === INSIDE && visible === 2) || node.children.length === 0) {
+      emit(node.start, node.count);
+      continue;
+    }
+    // 逆序压栈，保证按 order 顺序输出以便合并区间
+    for (let i = node.children.length - 1; i >= 0; i--) {
+      stack.push(node.children[i]);
+    }
+  }
+
+  return total;
+}
+
+/**
+ * 视锥剔除到叶子粒度（用于逐 chunk 选择 LOD）
+ * @param out 输出可见叶子节点索引，按 order 顺序
+ * @param visibility 节点可见性掩码，不可见的子树直接跳过
+ */
+export function collectVisibleLeaves(
+  octree: SplatOctree,
+  viewProjection: ArrayLike<number>,
+  model: ArrayLike<number>,
+  frustumDilation: number,
+  out: number[],
+  visibility: SplatNodeVisibility | null = null,
+): void {
+  out.length = 0;
+  if (octree.nodes.length === 0) {
+    return;
+  }
+  const planes = getFrustumPlanes(viewProjection, model, frustumDilation);
+
+  // 栈中元素为 (节点索引, 是否已确定完全在视锥内)
+  const stack: [number, boolean][] = [[0, false]];
+  while (stack.length > 0) {
+    const [index, knownInside] = stack.pop()!;
+    if (visibility && visibility[index] === 0) continue;
+    const node = octree.nodes[index];
+    const state = knownInside ? INSIDE : classifyNode(planes, node);
+
+    if (state === OUTSIDE) continue;
+    if (node.children.length === 0) {
+      out.push(index);
+      continue;
+    }
+    for (let i = node.children.length - 1; i >= 0; i--) {
+      stack.push([node.children[i], state === INSIDE]);
+    }
+  }
+}
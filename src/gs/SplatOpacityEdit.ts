@@ -0,0 +1,75 @@
+/**
+ * SplatOpacityEdit - 选中 splat 的不透明度编辑
+ *
+ * 缩放或直接设置不透明度（例如淡出半透明的噪点）。与颜色调整一样分两步：
+ * 预览时渲染器在 shader 中按选择状态修改选中 splat 的不透明度（不修改数据），
+ * 确认后在 CPU 端写回 GaussianSplats 数据（可撤销），两者结果一致
+ */
+
+import { SPLAT_FEATURE_EDITING } from "./SplatShaderFeatures";
+
+/**
+ * 不透明度编辑：scale 乘以 value，set 设为 value，结果限制在 0~1
+ */
+export interface SplatOpacityEdit {
+  mode: "scale" | "set";
+  value: number;
+}
+
+export const DEFAULT_SPLAT_OPACITY_EDIT: SplatOpacityEdit = {
+  mode: "scale",
+  value: 1,
+};
+
+/**
+ * 编辑是否不改变不透明度
+ */
+export function isIdentityOpacityEdit(edit: SplatOpacityEdit): boolean {
+  return edit.mode === "scale" && edit.value === 1;
+}
+
+/**
+ * 编辑一个不透明度（与 shader 中的 editSplatOpacity 一致）
+ */
+export function editSplatOpacity(opacity: number, edit: SplatOpacityEdit): number {
+  const value = edit.mode === "set" ? edit.value : opacity * edit.value;
+  return Math.min(1, Math.max(0, value));
+}
+
+/**
+ * 不透明度编辑的 uniform 数据：[启用, 模式 (0 缩放 / 1 设置), 值, 0]
+ */
+export function getOpacityEditUniforms(
+  edit: SplatOpacityEdit | null,
+  data: Float32Array = new Float32Array(4),
+): Float32Array {
+  data.fill(0);
+  if (!edit) {
+    return data;
+  }
+  data[0] = 1;
+  data[1] = edit.mode === "set" ? 1 : 0;
+  data[2] = edit.value;
+  return data;
+}
+
+// 不透明度编辑 shader 函数（读取 uniforms.opacityEdit 和 selection）
+const opacityEditShader = /* wgsl */ `
+// 预览时修改选中 splat 的不透明度；不透明度为 0（已删除）的 splat 保持不变
+fn editSplatOpacity(splatIndex: u32, opacity: f32) -> f32 {
+  let edit = uniforms.opacityEdit;
+  if edit.x == 0.0 || uniforms.hasSelection == 0u || (selection[splatIndex] & 1u) == 0u || opacity <= 0.0 {
+    return opacity;
+  }
+  return clamp(select(opacity * edit.z, edit.z, edit.y != 0.0), 0.0, 1.0);
+}
+`;
+
+/**
+ * 不透明度编辑 shader 函数（拼接在渲染器 shader 中），构建时关闭编辑功能时为空实现
+ */
+export const splatOpacityEditShader = SPLAT_FEATURE_EDITING ? opacityEditShader : /* wgsl */ `
+fn editSplatOpacity(splatIndex: u32, opacity: f32) -> f32 {
+  return opacity;
+}
+`;
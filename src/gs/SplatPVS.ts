@@ -0,0 +1,527 @@
+/**
+ * SplatPVS - 室内场景的分块可见性预计算（采样式 PVS）
+ *
+ * 把八叉树根包围盒划分为相机格子，用不透明 splat 体素化得到遮挡体（墙、地板），
+ * 从每个格子内的采样点向各方向发射射线，射线经过的体素中的 chunk（八叉树叶子）标记为可见，
+ * 遇到遮挡体素后停止。运行时按相机所在格子取出可见 chunk，分块剔除直接跳过其余 chunk，
+ * 多房间建筑中相邻房间的 splat 不再参与剔除和排序。
+ * 采样式 PVS 是保守近似：结果按相邻格子膨胀，相机位于格子之外或格子没有结果时不过滤
+ */
+
+import { Mat4 } from "../core/math/Mat4";
+import type { SplatOctree, SplatNodeVisibility } from "./SplatOctree";
+import type { CompactSplatData } from "./PLYLoaderMobile";
+import {
+  BINARY_FORMAT_HEADER_SIZE,
+  migrateBinaryFormat,
+  writeBinaryFormatHeader,
+  type BinaryFormat,
+} from "../utils/format";
+
+/**
+ * PVS 预计算参数
+ */
+export interface SplatPVSConfig {
+  /** 相机格子边长（模型空间），0 表示取包围盒最长边的 1/16 */
+  cellSize: number;
+  /** 遮挡体素边长，0 表示取格子边长的 1/4 */
+  voxelSize: number;
+  /** 体素内不透明 splat 达到该数量时视为遮挡 */
+  minOccupancy: number;
+  /** 参与遮挡的最小不透明度 */
+  minOpacity: number;
+  /** 每个格子的采样点数 */
+  samplesPerCell: number;
+  /** 每个采样点的射线数 */
+  raysPerSample: number;
+  /** 可见集合向相邻格子膨胀的格数 */
+  dilation: number;
+  /** 随机种子 */
+  seed: number;
+  /** 进度回调 (0~1) */
+  onProgress?: (progress: number) => void;
+}
+
+/** 默认 PVS 参数 */
+export const DEFAULT_SPLAT_PVS_CONFIG: SplatPVSConfig = {
+  cellSize: 0,
+  voxelSize: 0,
+  minOccupancy: 4,
+  minOpacity: 0.5,
+  samplesPerCell: 2,
+  raysPerSample: 128,
+  dilation: 1,
+  seed: 1,
+};
+
+// 每个轴的格子 / 体素数量上限
+const MAX_CELLS_PER_AXIS = 32;
+const MAX_VOXELS_PER_AXIS = 128;
+// 预计算时每隔多少毫秒让出主线程
+const YIELD_INTERVAL_MS = 30;
+// v1 没有格式头：[magic "PVS1"][leafCount][dims x3][min x3][cellSize]
+const PVS_V1_MAGIC = 0x31535650; // "PVS1"
+const PVS_V1_HEADER_SIZE = 36;
+// v2：[格式头][leafCount][nodeCount][dims x3][min x3][cellSize]
+const PVS_HEADER_SIZE = BINARY_FORMAT_HEADER_SIZE + 36;
+
+/**
+ * PVS 序列化格式（v2 增加版本号和八叉树节点数量）
+ */
+export const SPLAT_PVS_FORMAT: BinaryFormat = {
+  name: "SplatPVS",
+  magic: 0x53565053, // "SPVS"
+  version: 2,
+  migrations: { 1: migratePVSv1ToV2 },
+  detectLegacy: (view) => (view.getUint32(0, true) === PVS_V1_MAGIC ? 1 : null),
+};
+
+/**
+ * v1 -> v2：插入格式头，节点数量记为 0（未知，读取时只校验叶子数量）
+ */
+export function migratePVSv1ToV2(buffer: ArrayBuffer): ArrayBuffer {
+  const source = new DataView(buffer);
+  const result = new ArrayBuffer(buffer.byteLength - PVS_V1_HEADER_SIZE + PVS_HEADER_SIZE);
+  const view = new DataView(result);
+  writeBinaryFormatHeader(view, SPLAT_PVS_FORMAT, 2);
+  view.setUint32(8, source.getUint32(4, true), true);
+  view.setUint32(12, 0, true);
+  // dims / min / cellSize 布局不变
+  new Uint8Array(result, 16, 28).set(new Uint8Array(buffer, 8, 28));
+  new Uint8Array(result, PVS_HEADER_SIZE).set(new Uint8Array(buffer, PVS_V1_HEADER_SIZE));
+  return result;
+}
+
+/**
+ * 预计算的可见集合（绑定到构建时使用的八叉树）
+ */
+export class SplatPVS {
+  /** 构建时使用的八叉树，八叉树重建后结果失效 */
+  readonly octree: SplatOctree;
+  private min: [number, number, number];
+  private cellSize: number;
+  private dims: [number, number, number];
+  private leafCount: number;
+  // 叶子节点索引 -> 紧凑叶子编号
+  private leafIds: Int32Array;
+  // 每个格子一个位集（leafCount 位），格子没有结果时为空
+  private cells: (Uint8Array | null)[];
+  // 按格子缓存的节点可见性
+  private visibilityCache: Map<number, SplatNodeVisibility> = new Map();
+
+  constructor(
+    octree: SplatOctree,
+    min: [number, number, number],
+    cellSize: number,
+    dims: [number, number, number],
+    cells: (Uint8Array | null)[],
+  ) {
+    this.octree = octree;
+    this.min = min;
+    this.cellSize = cellSize;
+    this.dims = dims;
+    this.cells = cells;
+    this.leafIds = new Int32Array(octree.nodes.length).fill(-1);
+    let leafCount = 0;
+    octree.nodes.forEach((node, index) => {
+      if (node.children.length === 0) {
+        this.leafIds[index] = leafCount++;
+      }
+    });
+    this.leafCount = leafCount;
+  }
+
+  /**
+   * 格子数量
+   */
+  getCellCount(): number {
+    return this.dims[0] * this.dims[1] * this.dims[2];
+  }
+
+  /**
+   * 查询模型空间位置的节点可见性，位置在格子外或格子没有结果时返回 null（不过滤）
+   */
+  getVisibility(x: number, y: number, z: number): SplatNodeVisibility | null {
+    const cell = this.cellIndex(x, y, z);
+    if (cell < 0 || !this.cells[cell]) {
+      return null;
+    }
+    let visibility = this.visibilityCache.get(cell);
+    if (!visibility) {
+      visibility = this.buildNodeVisibility(this.cells[cell]!);
+      this.visibilityCache.set(cell, visibility);
+    }
+    return visibility;
+  }
+
+  /**
+   * 按相机世界坐标和模型矩阵查询
+   */
+  getVisibilityFromWorld(cameraPosition: ArrayLike<number>, modelMatrix: ArrayLike<number>): SplatNodeVisibility | null {
+    const m = new Mat4();
+    m.elements.set(Array.from(modelMatrix));
+    const inv = m.inverse();
+    if (!inv) {
+      return null;
+    }
+    const e = inv.elements;
+    const [x, y, z] = [cameraPosition[0], cameraPosition[1], cameraPosition[2]];
+    return this.getVisibility(
+      e[0] * x + e[4] * y + e[8] * z + e[12],
+      e[1] * x + e[5] * y + e[9] * z + e[13],
+      e[2] * x + e[6] * y + e[10] * z + e[14],
+    );
+  }
+
+  /**
+   * 可见 chunk 占全部 chunk 的平均比例（没有结果的格子不计）
+   */
+  getAverageVisibleFraction(): number {
+    let sum = 0;
+    let cells = 0;
+    for (const bits of this.cells) {
+      if (!bits) continue;
+      let visible = 0;
+      for (let i = 0; i < this.leafCount; i++) {
+        if (bits[i >> 3] & (1 << (i & 7))) visible++;
+      }
+      sum += visible / Math.max(1, this.leafCount);
+      cells++;
+    }
+    return cells > 0 ? sum / cells : 1;
+  }
+
+  /**
+   * 序列化（离线预计算后随场景一起分发，写出当前版本）
+   */
+  toArrayBuffer(): ArrayBuffer {
+    const bytesPerCell = Math.ceil(this.leafCount / 8);
+    const cellCount = this.getCellCount();
+    const buffer = new ArrayBuffer(PVS_HEADER_SIZE + cellCount + cellCount * bytesPerCell);
+    const view = new DataView(buffer);
+    writeBinaryFormatHeader(view, SPLAT_PVS_FORMAT);
+    view.setUint32(8, this.leafCount, true);
+    view.setUint32(12, this.octree.nodes.length, true);
+    view.setUint32(16, this.dims[0], true);
+    view.setUint32(20, this.dims[1], true);
+    view.setUint32(24, this.dims[2], true);
+    view.setFloat32(28, this.min[0], true);
+    view.setFloat32(32, this.min[1], true);
+    view.setFloat32(36, this.min[2], true);
+    view.setFloat32(40, this.cellSize, true);
+    const present = new Uint8Array(buffer, PVS_HEADER_SIZE, cellCount);
+    const data = new Uint8Array(buffer, PVS_HEADER_SIZE + cellCount);
+    this.cells.forEach((bits, i) => {
+      if (bits) {
+        present[i] = 1;
+        data.set(bits, i * bytesPerCell);
+      }
+    });
+    return buffer;
+  }
+
+  /**
+   * 反序列化（旧版本数据先迁移到当前版本），八叉树必须与构建时相同（叶子 / 节点数量不一致时抛出错误）
+   */
+  static fromArrayBuffer(buffer: ArrayBuffer, octree: SplatOctree): SplatPVS {
+    buffer = migrateBinaryFormat(buffer, SPLAT_PVS_FORMAT);
+    const view = new DataView(buffer);
+    const leafCount = view.getUint32(8, true);
+    if (leafCount !== octree.leafCount) {
+      throw new Error(`[SplatPVS] chunk 数量不匹配 (${leafCount} / ${octree.leafCount})`);
+    }
+    const nodeCount = view.getUint32(12, true);
+    if (nodeCount !== 0 && nodeCount !== octree.nodes.length) {
+      throw new Error(`[SplatPVS] 八叉树节点数量不匹配 (${nodeCount} / ${octree.nodes.length})`);
+    }
+    const dims: [number, number, number] = [view.getUint32(16, true), view.getUint32(20, true), view.getUint32(24, true)];
+    const min: [number, number, number] = [view.getFloat32(28, true), view.getFloat32(32, true), view.getFloat32(36, true)];
+    const cellSize = view.getFloat32(40, true);
+    const cellCount = dims[0] * dims[1] * dims[2];
+    const bytesPerCell = Math.ceil(leafCount / 8);
+    const dataOffset = PVS_HEADER_SIZE + cellCount;
+    const present = new Uint8Array(buffer, PVS_HEADER_SIZE, cellCount);
+    const cells: (Uint8Array | null)[] = [];
+    for (let i = 0; i < cellCount; i++) {
+      cells.push(present[i] ? new Uint8Array(buffer.slice(dataOffset + i * bytesPerCell, dataOffset + (i + 1) * bytesPerCell)) : null);
+    }
+    return new SplatPVS(octree, min, cellSize, dims, cells);
+  }
+
+  private cellIndex(x: number, y: number, z: number): number {
+    const cx = Math.floor((x - this.min[0]) / this.cellSize);
+    const cy = Math.floor((y - this.min[1]) / this.cellSize);
+    const cz = Math.floor((z - this.min[2]) / this.cellSize);
+    if (cx < 0 || cy < 0 || cz < 0 || cx >= this.dims[0] || cy >= this.dims[1] || cz >= this.dims[2]) {
+      return -1;
+    }
+    return (cz * this.dims[1] + cy) * this.dims[0] + cx;
+  }
+
+  // 叶子位集 -> 节点可见性（0 不可见 / 1 部分 / 2 全部）
+  private buildNodeVisibility(bits: Uint8Array): SplatNodeVisibility {
+    const nodes = this.octree.nodes;
+    const visibility = new Uint8Array(nodes.length);
+    // 子节点索引总是大于父节点，逆序遍历即可自底向上
+    for (let index = nodes.length - 1; index >= 0; index--) {
+      const node = nodes[index];
+      if (node.children.length === 0) {
+        const leaf = this.leafIds[index];
+        visibility[index] = bits[leaf >> 3] & (1 << (leaf & 7)) ? 2 : 0;
+        continue;
+      }
+      let all = true;
+      let any = false;
+      for (const child of node.children) {
+        all &&= visibility[child] === 2;
+        any ||= visibility[child] !== 0;
+      }
+      visibility[index] = all ? 2 : any ? 1 : 0;
+    }
+    return visibility;
+  }
+}
+
+/**
+ * 预计算 PVS（在主线程分段执行，期间定期让出）
+ * @param data 构建八叉树时使用的 splat 数据（模型空间）
+ */
+export async function buildSplatPVS(
+  octree: SplatOctree,
+  data: CompactSplatData,
+  config: Partial<SplatPVSConfig> = {},
+): Promise<SplatPVS> {
+  const cfg = { ...DEFAULT_SPLAT_PVS_CONFIG, ...config };
+  const root = octree.nodes[0];
+  if (!root || root.count === 0) {
+    throw new Error("[SplatPVS] 八叉树为空");
+  }
+  const min: [number, number, number] = [...root.min];
+  const size = [0, 1, 2].map((a) => Math.max(root.max[a] - root.min[a], 1e-6));
+  const longest = Math.max(...size);
+  const cellSize = Math.max(cfg.cellSize > 0 ? cfg.cellSize : longest / 16, longest / MAX_CELLS_PER_AXIS);
+  const dims = size.map((s) => Math.max(1, Math.ceil(s / cellSize))) as [number, number, number];
+  const voxelSize = Math.max(cfg.voxelSize > 0 ? cfg.voxelSize : cellSize / 4, longest / MAX_VOXELS_PER_AXIS);
+  const grid = buildOccupancy(octree, data, min, size, voxelSize, cfg);
+
+  const leafCount = octree.leafCount;
+  const bytesPerCell = Math.ceil(leafCount / 8);
+  const cellCount = dims[0] * dims[1] * dims[2];
+  const raw: (Uint8Array | null)[] = new Array(cellCount).fill(null);
+  const directions = fibonacciSphere(cfg.raysPerSample);
+  const random = createRandom(cfg.seed);
+  const maxDistance = Math.hypot(size[0], size[1], size[2]);
+
+  let lastYield = performance.now();
+  for (let cz = 0; cz < dims[2]; cz++) {
+    for (let cy = 0; cy < dims[1]; cy++) {
+      for (let cx = 0; cx < dims[0]; cx++) {
+        const cell = (cz * dims[1] + cy) * dims[0] + cx;
+        const bits = new Uint8Array(bytesPerCell);
+        let validSamples = 0;
+        for (let s = 0; s < cfg.samplesPerCell; s++) {
+          const origin = [
+            min[0] + (cx + random()) * cellSize,
+            min[1] + (cy + random()) * cellSize,
+            min[2] + (cz + random()) * cellSize,
+          ];
+          // 采样点位于墙体内部时跳过
+          if (grid.isOccupied(origin[0], origin[1], origin[2])) continue;
+          validSamples++;
+          for (let d = 0; d < directions.length; d += 3) {
+            grid.traceRay(origin, directions[d], directions[d + 1], directions[d + 2], maxDistance, bits);
+          }
+        }
+        raw[cell] = validSamples > 0 ? bits : null;
+
+        const now = performance.now();
+        if (now - lastYield > YIELD_INTERVAL_MS) {
+          cfg.onProgress?.((cell + 1) / cellCount);
+          await new Promise((resolve) => setTimeout(resolve, 0));
+          lastYield = performance.now();
+        }
+      }
+    }
+  }
+
+  // 向相邻格子膨胀，相机跨越格子边界时不突然出现 chunk
+  const cells: (Uint8Array | null)[] = new Array(cellCount).fill(null);
+  const r = Math.max(0, Math.floor(cfg.dilation));
+  for (let cz = 0; cz < dims[2]; cz++) {
+    for (let cy = 0; cy < dims[1]; cy++) {
+      for (let cx = 0; cx < dims[0]; cx++) {
+        const cell = (cz * dims[1] + cy) * dims[0] + cx;
+        if (!raw[cell]) continue;
+        const bits = new Uint8Array(raw[cell]!);
+        for (let dz = -r; dz <= r; dz++) {
+          for (let dy = -r; dy <= r; dy++) {
+            for (let dx = -r; dx <= r; dx++) {
+              const nx = cx + dx, ny = cy + dy, nz = cz + dz;
+              if (nx < 0 || ny < 0 || nz < 0 || nx >= dims[0] || ny >= dims[1] || nz >= dims[2]) continue;
+              const neighbor = raw[(nz * dims[1] + ny) * dims[0] + nx];
+              if (!neighbor) continue;
+              for (let b = 0; b < bytesPerCell; b++) bits[b] |= neighbor[b];
+            }
+          }
+        }
+        cells[cell] = bits;
+      }
+    }
+  }
+  cfg.onProgress?.(1);
+
+  return new SplatPVS(octree, min, cellSize, dims, cells);
+}
+
+/**
+ * 遮挡体素：每个体素记录不透明 splat 数量和所属的 chunk
+ */
+class OccupancyGrid {
+  private min: number[];
+  private voxelSize: number;
+  private dims: number[];
+  private counts: Uint16Array;
+  private minOccupancy: number;
+  // 体素 -> 其中 splat 所属的叶子编号（去重）
+  private leaves: Map<number, number[]> = new Map();
+
+  constructor(min: number[], size: number[], voxelSize: number, minOccupancy: number) {
+    this.min = min;
+    this.voxelSize = voxelSize;
+    this.dims = size.map((s) => Math.max(1, Math.ceil(s / voxelSize)));
+    this.counts = new Uint16Array(this.dims[0] * this.dims[1] * this.dims[2]);
+    this.minOccupancy = minOccupancy;
+  }
+
+  add(x: number, y: number, z: number, leaf: number, opaque: boolean): void {
+    const v = this.voxelIndex(
+      Math.floor((x - this.min[0]) / this.voxelSize),
+      Math.floor((y - this.min[1]) / this.voxelSize),
+      Math.floor((z - this.min[2]) / this.voxelSize),
+    );
+    if (v < 0) return;
+    if (opaque && this.counts[v] < 0xffff) {
+      this.counts[v]++;
+    }
+    let list = this.leaves.get(v);
+    if (!list) {
+      list = [];
+      this.leaves.set(v, list);
+    }
+    if (list[list.length - 1] !== leaf) {
+      list.push(leaf);
+    }
+  }
+
+  isOccupied(x: number, y: number, z: number): boolean {
+    const v = this.voxelIndex(
+      Math.floor((x - this.min[0]) / this.voxelSize),
+      Math.floor((y - this.min[1]) / this.voxelSize),
+      Math.floor((z - this.min[2]) / this.voxelSize),
+    );
+    return v >= 0 && this.counts[v] >= this.minOccupancy;
+  }
+
+  /**
+   * 3D DDA 遍历体素，标记经过的 chunk，遇到遮挡体素后停止
+   */
+  traceRay(origin: number[], dx: number, dy: number, dz: number, maxDistance: number, bits: Uint8Array): void {
+    const s = this.voxelSize;
+    const dir = [dx, dy, dz];
+    const pos = [
+      Math.floor((origin[0] - this.min[0]) / s),
+      Math.floor((origin[1] - this.min[1]) / s),
+      Math.floor((origin[2] - this.min[2]) / s),
+    ];
+    const step = [0, 0, 0];
+    const tMax = [Infinity, Infinity, Infinity];
+    const tDelta = [Infinity, Infinity, Infinity];
+    for (let a = 0; a < 3; a++) {
+      if (dir[a] > 0) {
+        step[a] = 1;
+        tMax[a] = ((pos[a] + 1) * s + this.min[a] - origin[a]) / dir[a];
+        tDelta[a] = s / dir[a];
+      } else if (dir[a] < 0) {
+        step[a] = -1;
+        tMax[a] = (pos[a] * s + this.min[a] - origin[a]) / dir[a];
+        tDelta[a] = -s / dir[a];
+      }
+    }
+
+    let t = 0;
+    while (t <= maxDistance) {
+      const v = this.voxelIndex(pos[0], pos[1], pos[2]);
+      if (v < 0) return;
+      const list = this.leaves.get(v);
+      if (list) {
+        for (const leaf of list) {
+          bits[leaf >> 3] |= 1 << (leaf & 7);
+        }
+      }
+      if (this.counts[v] >= this.minOccupancy) return;
+      const a = tMax[0] < tMax[1] ? (tMax[0] < tMax[2] ? 0 : 2) : (tMax[1] < tMax[2] ? 1 : 2);
+      t = tMax[a];
+      tMax[a] += tDelta[a];
+      pos[a] += step[a];
+    }
+  }
+
+  private voxelIndex(x: number, y: number, z: number): number {
+    if (x < 0 || y < 0 || z < 0 || x >= this.dims[0] || y >= this.dims[1] || z >= this.dims[2]) {
+      return -1;
+    }
+    return (z * this.dims[1] + y) * this.dims[0] + x;
+  }
+}
+
+function buildOccupancy(
+  octree: SplatOctree,
+  data: CompactSplatData,
+  min: number[],
+  size: number[],
+  voxelSize: number,
+  cfg: SplatPVSConfig,
+): OccupancyGrid {
+  const grid = new OccupancyGrid(min, size, voxelSize, cfg.minOccupancy);
+  let leaf = 0;
+  for (const node of octree.nodes) {
+    if (node.children.length > 0) continue;
+    for (let k = node.start; k < node.start + node.count; k++) {
+      const i = octree.order[k];
+      grid.add(
+        data.positions[i * 3], data.positions[i * 3 + 1], data.positions[i * 3 + 2],
+        leaf, data.opacities[i] >= cfg.minOpacity,
+      );
+    }
+    leaf++;
+  }
+  return grid;
+}
+
+// 单位球面上近似均匀分布的方向 [x,y,z, ...]
+function fibonacciSphere(count: number): Float64Array {
+  const n = Math.max(1, count);
+  const out = new Float64Array(n * 3);
+  const golden = Math.PI * (3 - Math.sqrt(5));
+  for (let i = 0; i < n; i++) {
+    const y = 1 - ((i + 0.5) / n) * 2;
+    const r = Math.sqrt(1 - y * y);
+    const phi = i * golden;
+    out[i * 3] = Math.cos(phi) * r;
+    out[i * 3 + 1] = y;
+    out[i * 3 + 2] = Math.sin(phi) * r;
+  }
+  return out;
+}
+
+// 可复现的伪随机数 (mulberry32)
+function createRandom(seed: number): () => number {
+  let state = seed >>> 0;
+  return () => {
+    state = (state + 0x6d2b79f5) >>> 0;
+    let t = state;
+    t = Math.imul(t ^ (t >>> 15), t | 1);
+    t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
+    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+  };
+}
@@ -0,0 +1,138 @@
+/**
+ * SplatPassTracker - 调试用的逐帧 pass / buffer 访问记录
+ *
+ * 记录每帧各 pass 读写了哪些 splat buffer，并检查执行顺序 (cull → sort → draw → pick)：
+ * - 阶段倒序：例如 pick 之后又执行了 sort
+ * - 先读后写：同一帧内某 buffer 被读取之后才被其他 pass 写入，读取方拿到的是上一帧的数据
+ * 发现问题时 console.warn（同一问题只报告一次），report() 输出上一帧的 pass 依赖关系
+ */
+
+/**
+ * Pass 阶段（按期望的执行顺序）
+ */
+export type SplatPassStage = "cull" | "sort" | "draw" | "pick";
+
+const STAGE_ORDER: Record<SplatPassStage, number> = {
+  cull: 0,
+  sort: 1,
+  draw: 2,
+  pick: 3,
+};
+
+/**
+ * 单个 pass 的访问记录
+ */
+export interface SplatPassRecord {
+  stage: SplatPassStage;
+  label: string;
+  reads: string[];
+  writes: string[];
+}
+
+/**
+ * SplatPassTracker - pass 访问记录与顺序校验
+ */
+export class SplatPassTracker {
+  private frame: number = 0;
+  private records: SplatPassRecord[] = [];
+  private lastFrameRecords: SplatPassRecord[] = [];
+  private hazards: string[] = [];
+  private reported: Set<string> = new Set();
+  private bufferNames: WeakMap<GPUBuffer, string> = new WeakMap();
+  private unnamedCount: number = 0;
+
+  /**
+   * 开始新的一帧（上一帧的记录保留用于 report）
+   */
+  beginFrame(): void {
+    if (this.records.length > 0) {
+      this.lastFrameRecords = this.records;
+      this.records = [];
+    }
+    this.frame++;
+  }
+
+  /**
+   * 记录一个 pass 的 buffer 访问并校验顺序
+   */
+  record(stage: SplatPassStage, label: string, reads: GPUBuffer[], writes: GPUBuffer[]): void {
+    const record: SplatPassRecord = {
+      stage,
+      label,
+      reads: reads.map((buffer) => this.nameOf(buffer)),
+      writes: writes.map((buffer) => this.nameOf(buffer)),
+    };
+
+    for (const prev of this.records) {
+      if (STAGE_ORDER[prev.stage] > STAGE_ORDER[stage]) {
+        this.reportHazard(`${label} (${stage}) 在 ${prev.label} (${prev.stage}) 之后执行`);
+      }
+      for (const name of record.writes) {
+        if (prev.label !== label && prev.reads.includes(name)) {
+          this.reportHazard(`${prev.label} 读取 ${name} 之后才被 ${label} 写入，读到的是上一帧的数据`);
+        }
+      }
+    }
+
+    this.records.push(record);
+  }
+
+  /**
+   * 获取已发现的问题
+   */
+  getHazards(): string[] {
+    return [...this.hazards];
+  }
+
+  /**
+   * 生成上一完整帧的 pass 依赖报告
+   */
+  report(): string {
+    const records = this.lastFrameRecords.length > 0 ? this.lastFrameRecords : this.records;
+    const lines: string[] = [`splat passes (frame ${this.frame}):`];
+    records.forEach((record, i) => {
+      lines.push(
+        `  ${i}. [${record.stage}] ${record.label}` +
+        `  读: ${record.reads.join(", ") || "-"}` +
+        `  写: ${record.writes.join(", ") || "-"}`,
+      );
+    });
+
+    lines.push("依赖:");
+    records.forEach((reader, i) => {
+      for (const name of reader.reads) {
+        // 最近一次写入该 buffer 的 pass
+        for (let j = i - 1; j >= 0; j--) {
+          if (records[j].writes.includes(name)) {
+            lines.push(`  ${records[j].label} -> ${reader.label} (${name})`);
+            break;
+          }
+        }
+      }
+    });
+
+    if (this.hazards.length > 0) {
+      lines.push("问题:");
+      for (const hazard of this.hazards) {
+        lines.push(`  ${hazard}`);
+      }
+    }
+    return lines.join("\n");
+  }
+
+  private nameOf(buffer: GPUBuffer): string {
+    let name = this.bufferNames.get(buffer);
+    if (!name) {
+      name = buffer.label || `buffer-${this.unnamedCount++}`;
+      this.bufferNames.set(buffer, name);
+    }
+    return name;
+  }
+
+  private reportHazard(message: string): void {
+    if (this.reported.has(message)) return;
+    this.reported.add(message);
+    this.hazards.push(message);
+    console.warn(`[SplatPassTracker] ${message}`);
+  }
+}
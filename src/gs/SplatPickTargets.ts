@@ -0,0 +1,100 @@
+/**
+ * SplatPickTargets - 按设备共享的拾取渲染目标
+ *
+ * 拾取、深度查询和 id / 深度回读都需要画布大小的 id (r32uint)、深度颜色 (r32float)、深度纹理，
+ * 混合深度查询还需要一张 rgba16float。场景中有多个 splat 实体（图层）时如果每个渲染器各持有一套，
+ * 显存占用随实体数量线性增长；这里按设备只保留一套。
+ * 每次拾取的 pass 和回读复制在同一个命令编码器中提交，队列按提交顺序执行，多个实体共享纹理不会互相覆盖。
+ * 尺寸或深度格式变化时重建（已提交的命令仍可以安全完成）
+ */
+export class SplatPickTargets {
+  private static targets: WeakMap<GPUDevice, SplatPickTargets> = new WeakMap();
+
+  private device: GPUDevice;
+  private width: number = 0;
+  private height: number = 0;
+  private depthFormat: GPUTextureFormat | null = null;
+  private pickTextures: GPUTexture[] | null = null;
+  private blendedDepthTexture: GPUTexture | null = null;
+
+  /**
+   * 获取设备的共享拾取目标
+   */
+  static get(device: GPUDevice): SplatPickTargets {
+    let targets = SplatPickTargets.targets.get(device);
+    if (!targets) {
+      targets = new SplatPickTargets(device);
+      SplatPickTargets.targets.set(device, targets);
+    }
+    return targets;
+  }
+
+  constructor(device: GPUDevice) {
+    this.device = device;
+  }
+
+  /**
+   * 拾取 pass 的渲染目标：id (r32uint)、深度颜色 (r32float)、深度
+   */
+  getPickTargets(width: number, height: number, depthFormat: GPUTextureFormat): GPUTexture[] {
+    this.resize(width, height, depthFormat);
+    if (!this.pickTextures) {
+      const size = { width, height };
+      this.pickTextures = [
+        this.device.createTexture({
+          size,
+          format: "r32uint",
+          usage: GPUTextureUsage.RENDER_ATTACHMENT | GPUTextureUsage.COPY_SRC,
+          label: "splat-pick-ids",
+        }),
+        this.device.createTexture({
+          size,
+          format: "r32float",
+          usage: GPUTextureUsage.RENDER_ATTACHMENT | GPUTextureUsage.COPY_SRC,
+          label: "splat-pick-depth-color",
+        }),
+        this.device.createTexture({
+          size,
+          format: depthFormat,
+          usage: GPUTextureUsage.RENDER_ATTACHMENT,
+          label: "splat-pick-depth",
+        }),
+      ];
+    }
+    return this.pickTextures;
+  }
+
+  /**
+   * 混合深度查询的渲染目标 (rgba16float)
+   */
+  getBlendedDepthTarget(width: number, height: number, depthFormat: GPUTextureFormat): GPUTexture {
+    this.resize(width, height, depthFormat);
+    this.blendedDepthTexture ??= this.device.createTexture({
+      size: { width, height },
+      format: "rgba16float",
+      usage: GPUTextureUsage.RENDER_ATTACHMENT | GPUTextureUsage.COPY_SRC,
+      label: "splat-pick-blended-depth",
+    });
+    return this.blendedDepthTexture;
+  }
+
+  /**
+   * 释放全部纹理（下次拾取时重新创建）
+   */
+  clear(): void {
+    this.pickTextures?.forEach((texture) => texture.destroy());
+    this.pickTextures = null;
+    this.blendedDepthTexture?.destroy();
+    this.blendedDepthTexture = null;
+  }
+
+  private resize(width: number, height: number, depthFormat: GPUTextureFormat): void {
+    if (width === this.width && height === this.height && depthFormat === this.depthFormat) {
+      return;
+    }
+    this.clear();
+    this.width = width;
+    this.height = height;
+    this.depthFormat = depthFormat;
+  }
+}
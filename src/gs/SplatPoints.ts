@@ -0,0 +1,45 @@
+/**
+ * SplatPoints - 点显示 (Points / Centers) 的尺寸配置
+ *
+ * 固定像素尺寸的点在密集区域连成一片、在稀疏区域几乎看不见。
+ * 自适应模式下剔除 pass 按屏幕格子统计可见 splat 数量，顶点着色器按所在格子的
+ * 每像素点数选择直径，使格子内点面积之和约为 coverage 倍格子面积，再限制到 [minSize, maxSize]
+ */
+
+/** 密度统计的屏幕格子边长（像素） */
+export const POINT_DENSITY_CELL_SIZE = 16;
+
+/**
+ * 点尺寸配置
+ */
+export interface SplatPointSizeConfig {
+  /** 按局部投影密度调整尺寸，关闭时所有点使用 maxSize */
+  adaptive: boolean;
+  /** 最小直径（像素） */
+  minSize: number;
+  /** 最大直径（像素） */
+  maxSize: number;
+  /** 目标覆盖率：格子内点面积之和 / 格子面积 */
+  coverage: number;
+}
+
+/** 默认点尺寸配置 */
+export const DEFAULT_SPLAT_POINT_SIZE_CONFIG: SplatPointSizeConfig = {
+  adaptive: true,
+  minSize: 1,
+  maxSize: 8,
+  coverage: 0.5,
+};
+
+/**
+ * 计算点直径（与 shader 中的 pointSize 一致）
+ * @param cellCount 所在格子内的可见 splat 数量
+ */
+export function computePointSize(cellCount: number, config: SplatPointSizeConfig): number {
+  if (!config.adaptive) {
+    return config.maxSize;
+  }
+  const pointsPerPixel = Math.max(cellCount, 1) / (POINT_DENSITY_CELL_SIZE * POINT_DENSITY_CELL_SIZE);
+  const diameter = 2 * Math.sqrt(config.coverage / (Math.PI * pointsPerPixel));
+  return Math.min(Math.max(diameter, config.minSize), config.maxSize);
+}
@@ -0,0 +1,226 @@
+/**
+ * SplatPolygonSelector - 屏幕空间多边形（套索）选择
+ *
+ * 多边形顶点（画布像素）上传到一个小 storage buffer，compute pass 把每个 splat 中心投影到屏幕，
+ * 用奇偶规则判断是否落在多边形内，结果写入位掩码（每 32 个 splat 一个 u32）后回读。
+ * 与中心点叠加一样只看 splat 中心，包括被遮挡的 splat；使用上一帧的相机
+ */
+
+import { PipelineCache } from "../core/PipelineCache";
+import { SplatBufferPool } from "./SplatBufferPool";
+import { getBuffersByteSize } from "./SplatMemoryBudget";
+
+const WORKGROUP_SIZE = 256;
+
+/**
+ * 多边形顶点上限（超过时按间隔抽稀）
+ */
+export const MAX_SELECTION_POLYGON_POINTS = 1024;
+
+/**
+ * 多边形测试 compute shader
+ * 拼接在渲染器 shader 之后，复用其 Uniforms / splats
+ */
+const polygonSelectShader = /* wgsl */ `
+// ============================================
+// 多边形选择
+// ============================================
+
+struct PolygonParams {
+  splatCount: u32,
+  pointCount: u32,
+  _pad: vec2<u32>,
+}
+
+@group(1) @binding(0) var<uniform> polygonParams: PolygonParams;
+@group(1) @binding(1) var<storage, read> polygon: array<vec2<f32>>;
+@group(1) @binding(2) var<storage, read_write> polygonMask: array<atomic<u32>>;
+
+@compute @workgroup_size(${WORKGROUP_SIZE})
+fn selectPolygon(@builtin(global_invocation_id) gid: vec3<u32>) {
+  let splatIndex = gid.x;
+  if splatIndex >= polygonParams.splatCount { return; }
+
+  let clipPos = uniforms.proj * (uniforms.view * (uniforms.model * vec4<f32>(splats[splatIndex].mean, 1.0)));
+  if clipPos.w <= 0.0 { return; }
+  let ndc = clipPos.xy / clipPos.w;
+  let p = vec2<f32>(ndc.x * 0.5 + 0.5, 0.5 - ndc.y * 0.5) * uniforms.screenSize;
+
+  // 奇偶规则：统计向 +x 方向的射线与边的交点数
+  var inside = false;
+  var j = polygonParams.pointCount - 1u;
+  for (var i = 0u; i < polygonParams.pointCount; i++) {
+    let a = polygon[i];
+    let b = polygon[j];
+    if (a.y > p.y) != (b.y > p.y) {
+      let x = a.x + (p.y - a.y) / (b.y - a.y) * (b.x - a.x);
+      if p.x < x { inside = !inside; }
+    }
+    j = i;
+  }
+  if inside {
+    atomicOr(&polygonMask[splatIndex / 32u], 1u << (splatIndex % 32u));
+  }
+}
+`;
+
+/**
+ * 多边形选择器
+ */
+export class SplatPolygonSelector {
+  private device: GPUDevice;
+  private splatCount: number;
+
+  private paramsBuffer: GPUBuffer;
+  private polygonBuffer: GPUBuffer;
+  private maskBuffer: GPUBuffer;
+  private bindGroup: GPUBindGroup;
+  private pipeline: GPUComputePipeline;
+
+  constructor(
+    device: GPUDevice,
+    splatShaderCode: string,
+    splatBindGroupLayout: GPUBindGroupLayout,
+    splatCount: number,
+  ) {
+    this.device = device;
+    this.splatCount = splatCount;
+    const pipelines = PipelineCache.get(device);
+
+    this.paramsBuffer = device.createBuffer({
+      size: 16,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+      label: "polygon-select-params",
+    });
+    this.polygonBuffer = device.createBuffer({
+      size: MAX_SELECTION_POLYGON_POINTS * 8,
+      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_DST,
+      label: "polygon-select-points",
+    });
+    this.maskBuffer = device.createBuffer({
+      size: Math.max(1, Math.ceil(splatCount / 32)) * 4,
+      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_SRC | GPUBufferUsage.COPY_DST,
+      label: "polygon-select-mask",
+    });
+
+    const bindGroupLayout = pipelines.getBindGroupLayout({
+      label: "polygon-select-layout",
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.COMPUTE, buffer: { type: "uniform" } },
+        { binding: 1, visibility: GPUShaderStage.COMPUTE, buffer: { type: "read-only-storage" } },
+        { binding: 2, visibility: GPUShaderStage.COMPUTE, buffer: { type: "storage" } },
+      ],
+    });
+    this.bindGroup = device.createBindGroup({
+      layout: bindGroupLayout,
+      entries: [
+        { binding: 0, resource: { buffer: this.paramsBuffer } },
+        { binding: 1, resource: { buffer: this.polygonBuffer } },
+        { binding: 2, resource: { buffer: this.maskBuffer } },
+      ],
+      label: "polygon-select-bind-group",
+    });
+
+    const module = pipelines.getShaderModule({
+      code: splatShaderCode + polygonSelectShader,
+      label: "polygon-select-shader",
+    });
+    this.pipeline = pipelines.getComputePipeline({
+      layout: pipelines.getPipelineLayout([splatBindGroupLayout, bindGroupLayout]),
+      compute: { module, entryPoint: "selectPolygon" },
+      label: "polygon-select-pipeline",
+    });
+  }
+
+  /**
+   * 查询中心投影落在多边形内的 splat
+   * @param splatBindGroup 渲染器的 bind group (uniforms / splats / ...)
+   * @param points 多边形顶点（画布像素，y 向下），少于 3 个时没有命中
+   * @returns 命中的 splat 索引（升序）
+   */
+  async select(splatBindGroup: GPUBindGroup, points: ReadonlyArray<readonly [number, number]>): Promise<Uint32Array> {
+    const polygon = simplifyPolygon(points);
+    if (polygon.length < 3 || this.splatCount === 0) {
+      return new Uint32Array(0);
+    }
+
+    const params = new Uint32Array([this.splatCount, polygon.length, 0, 0]);
+    this.device.queue.writeBuffer(this.paramsBuffer, 0, params);
+    const data = new Float32Array(polygon.length * 2);
+    polygon.forEach(([x, y], i) => {
+      data[i * 2] = x;
+      data[i * 2 + 1] = y;
+    });
+    this.device.queue.writeBuffer(this.polygonBuffer, 0, data);
+
+    const size = this.maskBuffer.size;
+    const pool = SplatBufferPool.get(this.device);
+    const readBuffer = pool.acquire(size, GPUBufferUsage.COPY_DST | GPUBufferUsage.MAP_READ, "polygon-select-readback");
+    const encoder = this.device.createCommandEncoder({ label: "polygon-select-encoder" });
+    encoder.clearBuffer(this.maskBuffer);
+    const pass = encoder.beginComputePass({ label: "polygon-select" });
+    pass.setBindGroup(0, splatBindGroup);
+    pass.setBindGroup(1, this.bindGroup);
+    pass.setPipeline(this.pipeline);
+    pass.dispatchWorkgroups(Math.ceil(this.splatCount / WORKGROUP_SIZE));
+    pass.end();
+    encoder.copyBufferToBuffer(this.maskBuffer, 0, readBuffer, 0, size);
+    this.device.queue.submit([encoder.finish()]);
+
+    try {
+      await readBuffer.mapAsync(GPUMapMode.READ, 0, size);
+      const hits = decodeSelectionMask(new Uint32Array(readBuffer.getMappedRange(0, size)), this.splatCount);
+      readBuffer.unmap();
+      pool.release(readBuffer);
+      return hits;
+    } catch (error) {
+      readBuffer.destroy();
+      throw error;
+    }
+  }
+
+  /**
+   * 获取占用的显存（字节）
+   */
+  getGPUMemoryUsage(): number {
+    return getBuffersByteSize([this.paramsBuffer, this.polygonBuffer, this.maskBuffer]);
+  }
+
+  /**
+   * 销毁资源
+   */
+  destroy(): void {
+    this.paramsBuffer.destroy();
+    this.polygonBuffer.destroy();
+    this.maskBuffer.destroy();
+  }
+}
+
+/**
+ * 位掩码（每 32 个 splat 一个 u32）-> 命中的 splat 索引（升序）
+ */
+export function decodeSelectionMask(words: Uint32Array, count: number): Uint32Array {
+  const hits: number[] = [];
+  for (let w = 0; w < words.length; w++) {
+    let bits = words[w];
+    while (bits !== 0) {
+      const index = w * 32 + 31 - Math.clz32(bits & -bits);
+      if (index < count) hits.push(index);
+      bits &= bits - 1;
+    }
+  }
+  return Uint32Array.from(hits);
+}
+
+// 顶点超过上限时按间隔抽稀
+function simplifyPolygon(points: ReadonlyArray<readonly [number, number]>): ReadonlyArray<readonly [number, number]> {
+  if (points.length <= MAX_SELECTION_POLYGON_POINTS) {
+    return points;
+  }
+  const step = points.length / MAX_SELECTION_POLYGON_POINTS;
+  const result: (readonly [number, number])[] = [];
+  for (let i = 0; i < MAX_SELECTION_POLYGON_POINTS; i++) {
+    result.push(points[Math.floor(i * step)]);
+  }
+  return result;
+}
@@ -0,0 +1,760 @@
+/**
+ * SplatRegistration - 两个重叠扫描之间的自动配准
+ *
+ * 在降采样后的 splat 中心上由粗到精地估计刚体变换：
+ * - 粗配准：PCA 法线 + FPFH 特征，特征最近邻作为对应，RANSAC 选出内点最多的变换
+ *   （初始位置已大致对齐时可以关闭）
+ * - 精配准：多尺度点到点 ICP，体素由粗到细逐级收紧对应距离
+ * 结果是世界空间的修正矩阵 T（T * source 对齐到 target），
+ * applySplatRegistration 把它写回实体的位置 / 旋转，不修改 splat 数据
+ */
+
+import { Mat4 } from "../core/math/Mat4";
+import type { CompactSplatData } from "./PLYLoaderMobile";
+import type { IGSSplatRenderer } from "./IGSSplatRenderer";
+import type { Vec3Tuple } from "../types";
+
+/**
+ * 配准参数
+ */
+export interface SplatRegistrationConfig {
+  /** 精配准最细一级的体素边长（世界单位），0 表示取 target 包围盒对角线的 1/100 */
+  voxelSize: number;
+  /** 每个实体参与配准的最大 splat 数（先均匀抽样再体素降采样） */
+  maxSamples: number;
+  /** 忽略不透明度低于该值的 splat（漂浮物、已删除） */
+  minOpacity: number;
+  /** 是否做 FPFH + RANSAC 粗配准，初始位置已大致对齐时可以关闭 */
+  coarse: boolean;
+  /** 粗配准使用的体素边长相对 voxelSize 的倍数 */
+  coarseVoxelScale: number;
+  /** 粗配准最多使用的特征点数 */
+  maxFeaturePoints: number;
+  /** RANSAC 迭代次数 */
+  ransacIterations: number;
+  /** ICP 尺度级数（每级体素减半） */
+  icpLevels: number;
+  /** 每级 ICP 最大迭代次数 */
+  icpIterations: number;
+  /** 随机种子（结果可复现） */
+  seed: number;
+}
+
+/** 默认配准参数 */
+export const DEFAULT_SPLAT_REGISTRATION_CONFIG: SplatRegistrationConfig = {
+  voxelSize: 0,
+  maxSamples: 200000,
+  minOpacity: 0.1,
+  coarse: true,
+  coarseVoxelScale: 4,
+  maxFeaturePoints: 3000,
+  ransacIterations: 4000,
+  icpLevels: 3,
+  icpIterations: 30,
+  seed: 1,
+};
+
+/**
+ * 配准结果
+ */
+export interface SplatRegistrationResult {
+  /** 世界空间修正矩阵（列主序），T * source 对齐到 target */
+  transform: Float32Array;
+  /** 最细一级中找到对应点的 source 点比例 (0~1) */
+  fitness: number;
+  /** 对应点的均方根距离（世界单位） */
+  rmse: number;
+  /** 粗配准的 RANSAC 内点数（未做粗配准时为 0） */
+  coarseInliers: number;
+  /** ICP 总迭代次数 */
+  icpIterations: number;
+}
+
+const FPFH_BINS = 11;
+const FPFH_SIZE = FPFH_BINS * 3;
+
+/**
+ * 计算把 source 对齐到 target 的世界空间刚体变换
+ * @param sourceMatrix source 的模型矩阵（世界变换）
+ * @param targetMatrix target 的模型矩阵（世界变换）
+ */
+export function registerSplats(
+  source: CompactSplatData,
+  sourceMatrix: ArrayLike<number>,
+  target: CompactSplatData,
+  targetMatrix: ArrayLike<number>,
+  config: Partial<SplatRegistrationConfig> = {},
+): SplatRegistrationResult {
+  const cfg = { ...DEFAULT_SPLAT_REGISTRATION_CONFIG, ...config };
+  const random = createRandom(cfg.seed);
+
+  const sourcePoints = samplePoints(source, sourceMatrix, cfg.maxSamples, cfg.minOpacity, random);
+  const targetPoints = samplePoints(target, targetMatrix, cfg.maxSamples, cfg.minOpacity, random);
+  if (sourcePoints.length < 9 || targetPoints.length < 9) {
+    throw new Error("[SplatRegistration] 有效 splat 太少，无法配准");
+  }
+
+  const voxelSize = cfg.voxelSize > 0 ? cfg.voxelSize : boxDiagonal(targetPoints) / 100;
+  let transform = identity();
+  let coarseInliers = 0;
+
+  if (cfg.coarse) {
+    const coarseVoxel = voxelSize * cfg.coarseVoxelScale;
+    const src = limitPoints(voxelDownsample(sourcePoints, coarseVoxel), cfg.maxFeaturePoints, random);
+    const dst = limitPoints(voxelDownsample(targetPoints, coarseVoxel), cfg.maxFeaturePoints, random);
+    const srcFeatures = computeFPFH(src, coarseVoxel);
+    const dstFeatures = computeFPFH(dst, coarseVoxel);
+    const matches = matchFeatures(srcFeatures, dstFeatures);
+    const ransac = ransacAlign(src, dst, matches, coarseVoxel * 1.5, cfg.ransacIterations, random);
+    if (ransac) {
+      transform = ransac.transform;
+      coarseInliers = ransac.inliers;
+    }
+  }
+
+  // 由粗到细的 ICP
+  let fitness = 0;
+  let rmse = 0;
+  let icpIterations = 0;
+  for (let level = cfg.icpLevels - 1; level >= 0; level--) {
+    const levelVoxel = voxelSize * (1 << level);
+    const src = voxelDownsample(sourcePoints, levelVoxel);
+    const dst = voxelDownsample(targetPoints, levelVoxel);
+    const icp = icpAlign(src, dst, transform, levelVoxel * 2, cfg.icpIterations);
+    transform = icp.transform;
+    fitness = icp.fitness;
+    rmse = icp.rmse;
+    icpIterations += icp.iterations;
+  }
+
+  return { transform: new Float32Array(transform), fitness, rmse, coarseInliers, icpIterations };
+}
+
+/**
+ * 配准两个实体（需要实体提供 CPU 端数据 getSplats），返回修正矩阵但不修改实体
+ */
+export function registerSplatEntities(
+  source: IGSSplatRenderer,
+  target: IGSSplatRenderer,
+  config: Partial<SplatRegistrationConfig> = {},
+): SplatRegistrationResult {
+  const sourceSplats = source.getSplats?.();
+  const targetSplats = target.getSplats?.();
+  if (!sourceSplats || !targetSplats) {
+    throw new Error("[SplatRegistration] 渲染器不提供 CPU 端 splat 数据，无法配准");
+  }
+  return registerSplats(
+    sourceSplats.getData(), source.getModelMatrix(),
+    targetSplats.getData(), target.getModelMatrix(),
+    config,
+  );
+}
+
+/**
+ * 把世界空间修正矩阵写回实体的位置 / 旋转（保留缩放和中心点，考虑父级变换）
+ */
+export function applySplatRegistration(renderer: IGSSplatRenderer, transform: ArrayLike<number>): void {
+  const world = multiply(transform, renderer.getModelMatrix());
+  const parent = renderer.getParentMatrix?.();
+  let local = world;
+  if (parent) {
+    const m = new Mat4();
+    m.elements.set(parent);
+    const inv = m.inverse();
+    if (inv) {
+      local = multiply(inv.elements, world);
+    }
+  }
+
+  const scale = renderer.getScale();
+  const pivot = renderer.getPivot();
+  // 旋转 = 线性部分逐列除以缩放
+  const r = new Float64Array(9);
+  for (let col = 0; col < 3; col++) {
+    const s = scale[col] !== 0 ? scale[col] : 1;
+    for (let row = 0; row < 3; row++) {
+      r[row * 3 + col] = local[col * 4 + row] / s;
+    }
+  }
+  const rotation = eulerFromRotation(r);
+  // 平移 = position + (I - RS) * pivot，反解 position
+  const position: Vec3Tuple = [0, 0, 0];
+  for (let row = 0; row < 3; row++) {
+    const rsPivot = local[row] * pivot[0] + local[4 + row] * pivot[1] + local[8 + row] * pivot[2];
+    position[row] = local[12 + row] - pivot[row] + rsPivot;
+  }
+  renderer.setRotation(...rotation);
+  renderer.setPosition(...position);
+}
+
+// ============================================
+// 采样
+// ============================================
+
+// 点集：扁平的 xyz（f64）
+type Points = Float64Array;
+
+function samplePoints(
+  data: CompactSplatData,
+  matrix: ArrayLike<number>,
+  maxSamples: number,
+  minOpacity: number,
+  random: () => number,
+): Points {
+  const stride = Math.max(1, data.count / Math.max(1, maxSamples));
+  const out: number[] = [];
+  const m = matrix;
+  for (let f = random() * stride; f < data.count; f += stride) {
+    const i = Math.floor(f);
+    if (data.opacities[i] < minOpacity) {
+      continue;
+    }
+    const x = data.positions[i * 3];
+    const y = data.positions[i * 3 + 1];
+    const z = data.positions[i * 3 + 2];
+    if (!Number.isFinite(x) || !Number.isFinite(y) || !Number.isFinite(z)) {
+      continue;
+    }
+    out.push(
+      m[0] * x + m[4] * y + m[8] * z + m[12],
+      m[1] * x + m[5] * y + m[9] * z + m[13],
+      m[2] * x + m[6] * y + m[10] * z + m[14],
+    );
+  }
+  return new Float64Array(out);
+}
+
+function voxelDownsample(points: Points, voxel: number): Points {
+  const cells = new Map<string, number[]>();
+  for (let i = 0; i < points.length; i += 3) {
+    const key = `${Math.floor(points[i] / voxel)},${Math.floor(points[i + 1] / voxel)},${Math.floor(points[i + 2] / voxel)}`;
+    let cell = cells.get(key);
+    if (!cell) {
+      cell = [0, 0, 0, 0];
+      cells.set(key, cell);
+    }
+    cell[0] += points[i];
+    cell[1] += points[i + 1];
+    cell[2] += points[i + 2];
+    cell[3]++;
+  }
+  const out = new Float64Array(cells.size * 3);
+  let o = 0;
+  for (const cell of cells.values()) {
+    out[o++] = cell[0] / cell[3];
+    out[o++] = cell[1] / cell[3];
+    out[o++] = cell[2] / cell[3];
+  }
+  return out;
+}
+
+function limitPoints(points: Points, maxCount: number, random: () => number): Points {
+  const count = points.length / 3;
+  if (count <= maxCount) {
+    return points;
+  }
+  const stride = count / maxCount;
+  const out = new Float64Array(maxCount * 3);
+  const start = random() * stride;
+  for (let k = 0; k < maxCount; k++) {
+    const i = Math.min(count - 1, Math.floor(start + k * stride));
+    out[k * 3] = points[i * 3];
+    out[k * 3 + 1] = points[i * 3 + 1];
+    out[k * 3 + 2] = points[i * 3 + 2];
+  }
+  return out;
+}
+
+function boxDiagonal(points: Points): number {
+  const min = [Infinity, Infinity, Infinity];
+  const max = [-Infinity, -Infinity, -Infinity];
+  for (let i = 0; i < points.length; i += 3) {
+    for (let k = 0; k < 3; k++) {
+      min[k] = Math.min(min[k], points[i + k]);
+      max[k] = Math.max(max[k], points[i + k]);
+    }
+  }
+  return Math.hypot(max[0] - min[0], max[1] - min[1], max[2] - min[2]) || 1;
+}
+
+// ============================================
+// 空间哈希（近邻查询）
+// ============================================
+
+class PointGrid {
+  private points: Points;
+  private cellSize: number;
+  private cells = new Map<string, number[]>();
+
+  constructor(points: Points, cellSize: number) {
+    this.points = points;
+    this.cellSize = cellSize;
+    for (let i = 0; i < points.length / 3; i++) {
+      const key = this.key(points[i * 3], points[i * 3 + 1], points[i * 3 + 2], 0, 0, 0);
+      let cell = this.cells.get(key);
+      if (!cell) {
+        cell = [];
+        this.cells.set(key, cell);
+      }
+      cell.push(i);
+    }
+  }
+
+  // 半径内的点（radius 不超过 cellSize）
+  radius(x: number, y: number, z: number, radius: number, out: number[]): number[] {
+    out.length = 0;
+    const r2 = radius * radius;
+    for (let dx = -1; dx <= 1; dx++) {
+      for (let dy = -1; dy <= 1; dy++) {
+        for (let dz = -1; dz <= 1; dz++) {
+          const cell = this.cells.get(this.key(x, y, z, dx, dy, dz));
+          if (!cell) continue;
+          for (const j of cell) {
+            if (distance2(this.points, j, x, y, z) <= r2) {
+              out.push(j);
+            }
+          }
+        }
+      }
+    }
+    return out;
+  }
+
+  // 最大距离（不超过 cellSize）内的最近点，返回 [索引, 距离平方]，没有时索引为 -1
+  nearest(x: number, y: number, z: number, maxDistance: number): [number, number] {
+    let best = -1;
+    let bestD2 = maxDistance * maxDistance;
+    for (let dx = -1; dx <= 1; dx++) {
+      for (let dy = -1; dy <= 1; dy++) {
+        for (let dz = -1; dz <= 1; dz++) {
+          const cell = this.cells.get(this.key(x, y, z, dx, dy, dz));
+          if (!cell) continue;
+          for (const j of cell) {
+            const d2 = distance2(this.points, j, x, y, z);
+            if (d2 < bestD2) {
+              bestD2 = d2;
+              best = j;
+            }
+          }
+        }
+      }
+    }
+    return [best, bestD2];
+  }
+
+  private key(x: number, y: number, z: number, dx: number, dy: number, dz: number): string {
+    const s = this.cellSize;
+    return `${Math.floor(x / s) + dx},${Math.floor(y / s) + dy},${Math.floor(z / s) + dz}`;
+  }
+}
+
+function distance2(points: Points, i: number, x: number, y: number, z: number): number {
+  const dx = points[i * 3] - x;
+  const dy = points[i * 3 + 1] - y;
+  const dz = points[i * 3 + 2] - z;
+  return dx * dx + dy * dy + dz * dz;
+}
+
+// ============================================
+// 法线与 FPFH 特征
+// ============================================
+
+function computeNormals(points: Points, grid: PointGrid, radius: number): Float64Array {
+  const count = points.length / 3;
+  const normals = new Float64Array(count * 3);
+  const neighbors: number[] = [];
+  let cx = 0, cy = 0, cz = 0;
+  for (let i = 0; i < count; i++) {
+    cx += points[i * 3]; cy += points[i * 3 + 1]; cz += points[i * 3 + 2];
+  }
+  cx /= count; cy /= count; cz /= count;
+
+  for (let i = 0; i < count; i++) {
+    const px = points[i * 3], py = points[i * 3 + 1], pz = points[i * 3 + 2];
+    grid.radius(px, py, pz, radius, neighbors);
+    let n: number[] = [0, 0, 1];
+    if (neighbors.length >= 3) {
+      let mx = 0, my = 0, mz = 0;
+      for (const j of neighbors) {
+        mx += points[j * 3]; my += points[j * 3 + 1]; mz += points[j * 3 + 2];
+      }
+      mx /= neighbors.length; my /= neighbors.length; mz /= neighbors.length;
+      const cov = new Float64Array(9);
+      for (const j of neighbors) {
+        const d = [points[j * 3] - mx, points[j * 3 + 1] - my, points[j * 3 + 2] - mz];
+        for (let a = 0; a < 3; a++) {
+          for (let b = 0; b < 3; b++) {
+            cov[a * 3 + b] += d[a] * d[b];
+          }
+        }
+      }
+      const { values, vectors } = symmetricEigen(cov, 3);
+      const k = values[0] <= values[1] ? (values[0] <= values[2] ? 0 : 2) : (values[1] <= values[2] ? 1 : 2);
+      n = [vectors[k], vectors[3 + k], vectors[6 + k]];
+    }
+    // 法线朝向点云外侧，两个点云的符号一致
+    if (n[0] * (px - cx) + n[1] * (py - cy) + n[2] * (pz - cz) < 0) {
+      n = [-n[0], -n[1], -n[2]];
+    }
+    normals[i * 3] = n[0];
+    normals[i * 3 + 1] = n[1];
+    normals[i * 3 + 2] = n[2];
+  }
+  return normals;
+}
+
+function computeFPFH(points: Points, voxel: number): Float32Array {
+  const count = points.length / 3;
+  const featureRadius = voxel * 5;
+  const grid = new PointGrid(points, featureRadius);
+  const normals = computeNormals(points, grid, voxel * 2);
+  const neighborLists: number[][] = [];
+  const spfh = new Float32Array(count * FPFH_SIZE);
+  const neighbors: number[] = [];
+
+  // SPFH：点与邻居之间的 Darboux 角 (alpha, phi, theta) 直方图
+  for (let i = 0; i < count; i++) {
+    grid.radius(points[i * 3], points[i * 3 + 1], points[i * 3 + 2], featureRadius, neighbors);
+    const list = neighbors.filter((j) => j !== i);
+    neighborLists.push(list);
+    if (list.length === 0) continue;
+    const weight = 100 / list.length;
+    for (const j of list) {
+      const f = darbouxFeatures(points, normals, i, j);
+      if (!f) continue;
+      spfh[i * FPFH_SIZE + binIndex((f[0] + 1) / 2)] += weight;
+      spfh[i * FPFH_SIZE + FPFH_BINS + binIndex((f[1] + 1) / 2)] += weight;
+      spfh[i * FPFH_SIZE + FPFH_BINS * 2 + binIndex((f[2] + Math.PI) / (2 * Math.PI))] += weight;
+    }
+  }
+
+  // FPFH = SPFH(p) + 邻居 SPFH 按距离倒数加权的平均
+  const fpfh = new Float32Array(count * FPFH_SIZE);
+  for (let i = 0; i < count; i++) {
+    const list = neighborLists[i];
+    for (let b = 0; b < FPFH_SIZE; b++) {
+      fpfh[i * FPFH_SIZE + b] = spfh[i * FPFH_SIZE + b];
+    }
+    if (list.length === 0) continue;
+    for (const j of list) {
+      const dist = Math.sqrt(distance2(points, j, points[i * 3], points[i * 3 + 1], points[i * 3 + 2]));
+      const w = 1 / (Math.max(dist, 1e-12) * list.length);
+      for (let b = 0; b < FPFH_SIZE; b++) {
+        fpfh[i * FPFH_SIZE + b] += w * spfh[j * FPFH_SIZE + b];
+      }
+    }
+  }
+  return fpfh;
+}
+
+function darbouxFeatures(points: Points, normals: Float64Array, s: number, t: number): [number, number, number] | null {
+  let dx = points[t * 3] - points[s * 3];
+  let dy = points[t * 3 + 1] - points[s * 3 + 1];
+  let dz = points[t * 3 + 2] - points[s * 3 + 2];
+  const dist = Math.hypot(dx, dy, dz);
+  if (dist === 0) return null;
+  dx /= dist; dy /= dist; dz /= dist;
+  const ux = normals[s * 3], uy = normals[s * 3 + 1], uz = normals[s * 3 + 2];
+  const nx = normals[t * 3], ny = normals[t * 3 + 1], nz = normals[t * 3 + 2];
+  // v = u × d, w = u × v
+  let vx = uy * dz - uz * dy, vy = uz * dx - ux * dz, vz = ux * dy - uy * dx;
+  const vLen = Math.hypot(vx, vy, vz);
+  if (vLen === 0) return null;
+  vx /= vLen; vy /= vLen; vz /= vLen;
+  const wx = uy * vz - uz * vy, wy = uz * vx - ux * vz, wz = ux * vy - uy * vx;
+  const alpha = vx * nx + vy * ny + vz * nz;
+  const phi = ux * dx + uy * dy + uz * dz;
+  const theta = Math.atan2(wx * nx + wy * ny + wz * nz, ux * nx + uy * ny + uz * nz);
+  return [alpha, phi, theta];
+}
+
+function binIndex(t: number): number {
+  return Math.min(FPFH_BINS - 1, Math.max(0, Math.floor(t * FPFH_BINS)));
+}
+
+// 每个 source 特征在 target 中的最近特征（暴力搜索，特征点数已限制）
+function matchFeatures(source: Float32Array, target: Float32Array): Int32Array {
+  const sourceCount = source.length / FPFH_SIZE;
+  const targetCount = target.length / FPFH_SIZE;
+  const matches = new Int32Array(sourceCount).fill(-1);
+  for (let i = 0; i < sourceCount; i++) {
+    let best = Infinity;
+    for (let j = 0; j < targetCount; j++) {
+      let d = 0;
+      for (let b = 0; b < FPFH_SIZE && d < best; b++) {
+        const diff = source[i * FPFH_SIZE + b] - target[j * FPFH_SIZE + b];
+        d += diff * diff;
+      }
+      if (d < best) {
+        best = d;
+        matches[i] = j;
+      }
+    }
+  }
+  return matches;
+}
+
+// ============================================
+// RANSAC / ICP
+// ============================================
+
+function ransacAlign(
+  source: Points,
+  target: Points,
+  matches: Int32Array,
+  threshold: number,
+  iterations: number,
+  random: () => number,
+): { transform: Float64Array; inliers: number } | null {
+  const count = matches.length;
+  if (count < 3) return null;
+  const threshold2 = threshold * threshold;
+  let best: Float64Array | null = null;
+  let bestInliers = 0;
+
+  const countInliers = (t: Float64Array, collect?: number[]): number => {
+    let inliers = 0;
+    for (let i = 0; i < count; i++) {
+      const j = matches[i];
+      const p = transformPoint(t, source, i);
+      if (distance2(target, j, p[0], p[1], p[2]) <= threshold2) {
+        inliers++;
+        collect?.push(i);
+      }
+    }
+    return inliers;
+  };
+
+  for (let it = 0; it < iterations; it++) {
+    const sample = [
+      Math.floor(random() * count),
+      Math.floor(random() * count),
+      Math.floor(random() * count),
+    ];
+    if (sample[0] === sample[1] || sample[1] === sample[2] || sample[0] === sample[2]) continue;
+    // 边长相似性检查，快速剔除错误对应
+    let consistent = true;
+    for (let a = 0; a < 3 && consistent; a++) {
+      const b = (a + 1) % 3;
+      const ds = Math.sqrt(distance2(source, sample[a], source[sample[b] * 3], source[sample[b] * 3 + 1], source[sample[b] * 3 + 2]));
+      const dt = Math.sqrt(distance2(target, matches[sample[a]], target[matches[sample[b]] * 3], target[matches[sample[b]] * 3 + 1], target[matches[sample[b]] * 3 + 2]));
+      consistent = Math.min(ds, dt) >= 0.9 * Math.max(ds, dt);
+    }
+    if (!consistent) continue;
+    const t = estimateRigid(source, target, sample, sample.map((i) => matches[i]));
+    if (!t) continue;
+    const inliers = countInliers(t);
+    if (inliers > bestInliers) {
+      bestInliers = inliers;
+      best = t;
+    }
+  }
+  if (!best || bestInliers < 3) return null;
+
+  // 用全部内点重新估计
+  const inlierIndices: number[] = [];
+  countInliers(best, inlierIndices);
+  const refined = estimateRigid(source, target, inlierIndices, inlierIndices.map((i) => matches[i]));
+  return { transform: refined ?? best, inliers: bestInliers };
+}
+
+function icpAlign(
+  source: Points,
+  target: Points,
+  initial: Float64Array,
+  maxDistance: number,
+  maxIterations: number,
+): { transform: Float64Array; fitness: number; rmse: number; iterations: number } {
+  const grid = new PointGrid(target, maxDistance);
+  const count = source.length / 3;
+  let transform = initial;
+  let fitness = 0;
+  let rmse = 0;
+  let iterations = 0;
+  let previousRmse = Infinity;
+
+  for (let it = 0; it <= maxIterations; it++) {
+    const srcIndices: number[] = [];
+    const dstIndices: number[] = [];
+    let error = 0;
+    for (let i = 0; i < count; i++) {
+      const p = transformPoint(transform, source, i);
+      const [j, d2] = grid.nearest(p[0], p[1], p[2], maxDistance);
+      if (j >= 0) {
+        srcIndices.push(i);
+        dstIndices.push(j);
+        error += d2;
+      }
+    }
+    fitness = srcIndices.length / count;
+    rmse = srcIndices.length > 0 ? Math.sqrt(error / srcIndices.length) : 0;
+    if (it === maxIterations || srcIndices.length < 3 || previousRmse - rmse < 1e-6 * maxDistance) {
+      break;
+    }
+    previousRmse = rmse;
+    const t = estimateRigid(source, target, srcIndices, dstIndices, transform);
+    if (!t) break;
+    transform = t;
+    iterations++;
+  }
+  return { transform, fitness, rmse, iterations };
+}
+
+/**
+ * 最小二乘刚体变换（Horn 四元数法），返回 dst ≈ T * (prior * src)，T 已与 prior 合并
+ */
+function estimateRigid(
+  source: Points,
+  target: Points,
+  srcIndices: number[],
+  dstIndices: number[],
+  prior?: Float64Array,
+): Float64Array | null {
+  const n = srcIndices.length;
+  if (n < 3) return null;
+  const src = new Float64Array(n * 3);
+  for (let k = 0; k < n; k++) {
+    const p = prior ? transformPoint(prior, source, srcIndices[k]) : [
+      source[srcIndices[k] * 3], source[srcIndices[k] * 3 + 1], source[srcIndices[k] * 3 + 2],
+    ];
+    src.set(p, k * 3);
+  }
+  const cs = [0, 0, 0];
+  const ct = [0, 0, 0];
+  for (let k = 0; k < n; k++) {
+    for (let a = 0; a < 3; a++) {
+      cs[a] += src[k * 3 + a] / n;
+      ct[a] += target[dstIndices[k] * 3 + a] / n;
+    }
+  }
+  // 互协方差 S
+  const s = new Float64Array(9);
+  for (let k = 0; k < n; k++) {
+    for (let a = 0; a < 3; a++) {
+      const ps = src[k * 3 + a] - cs[a];
+      for (let b = 0; b < 3; b++) {
+        s[a * 3 + b] += ps * (target[dstIndices[k] * 3 + b] - ct[b]);
+      }
+    }
+  }
+  const [sxx, sxy, sxz, syx, syy, syz, szx, szy, szz] = s;
+  const nMat = new Float64Array([
+    sxx + syy + szz, syz - szy, szx - sxz, sxy - syx,
+    syz - szy, sxx - syy - szz, sxy + syx, szx + sxz,
+    szx - sxz, sxy + syx, -sxx + syy - szz, syz + szy,
+    sxy - syx, szx + sxz, syz + szy, -sxx - syy + szz,
+  ]);
+  const { values, vectors } = symmetricEigen(nMat, 4);
+  let k = 0;
+  for (let i = 1; i < 4; i++) {
+    if (values[i] > values[k]) k = i;
+  }
+  const [w, x, y, z] = [vectors[k], vectors[4 + k], vectors[8 + k], vectors[12 + k]];
+  if (![w, x, y, z].every(Number.isFinite)) return null;
+
+  // 旋转矩阵（列主序）和平移 t = ct - R * cs
+  const r = new Float64Array(16);
+  r[0] = 1 - 2 * (y * y + z * z); r[4] = 2 * (x * y - w * z); r[8] = 2 * (x * z + w * y);
+  r[1] = 2 * (x * y + w * z); r[5] = 1 - 2 * (x * x + z * z); r[9] = 2 * (y * z - w * x);
+  r[2] = 2 * (x * z - w * y); r[6] = 2 * (y * z + w * x); r[10] = 1 - 2 * (x * x + y * y);
+  r[15] = 1;
+  for (let a = 0; a < 3; a++) {
+    r[12 + a] = ct[a] - (r[a] * cs[0] + r[4 + a] * cs[1] + r[8 + a] * cs[2]);
+  }
+  return prior ? multiply(r, prior) : r;
+}
+
+// ============================================
+// 线性代数
+// ============================================
+
+// 对称矩阵特征分解（Jacobi），vectors 按列存放特征向量（行主序 n x n）
+function symmetricEigen(matrix: Float64Array, n: number): { values: Float64Array; vectors: Float64Array } {
+  const a = new Float64Array(matrix);
+  const v = new Float64Array(n * n);
+  for (let i = 0; i < n; i++) v[i * n + i] = 1;
+
+  for (let sweep = 0; sweep < 50; sweep++) {
+    let off = 0;
+    for (let p = 0; p < n; p++) {
+      for (let q = p + 1; q < n; q++) off += a[p * n + q] * a[p * n + q];
+    }
+    if (off < 1e-20) break;
+    for (let p = 0; p < n; p++) {
+      for (let q = p + 1; q < n; q++) {
+        const apq = a[p * n + q];
+        if (Math.abs(apq) < 1e-30) continue;
+        const theta = (a[q * n + q] - a[p * n + p]) / (2 * apq);
+        const t = Math.sign(theta || 1) / (Math.abs(theta) + Math.sqrt(theta * theta + 1));
+        const c = 1 / Math.sqrt(t * t + 1);
+        const s = t * c;
+        for (let k = 0; k < n; k++) {
+          const akp = a[k * n + p], akq = a[k * n + q];
+          a[k * n + p] = c * akp - s * akq;
+          a[k * n + q] = s * akp + c * akq;
+        }
+        for (let k = 0; k < n; k++) {
+          const apk = a[p * n + k], aqk = a[q * n + k];
+          a[p * n + k] = c * apk - s * aqk;
+          a[q * n + k] = s * apk + c * aqk;
+        }
+        for (let k = 0; k < n; k++) {
+          const vkp = v[k * n + p], vkq = v[k * n + q];
+          v[k * n + p] = c * vkp - s * vkq;
+          v[k * n + q] = s * vkp + c * vkq;
+        }
+      }
+    }
+  }
+  const values = new Float64Array(n);
+  for (let i = 0; i < n; i++) values[i] = a[i * n + i];
+  return { values, vectors: v };
+}
+
+function identity(): Float64Array {
+  return new Float64Array([1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1]);
+}
+
+// 列主序 a * b
+function multiply(a: ArrayLike<number>, b: ArrayLike<number>): Float64Array {
+  const out = new Float64Array(16);
+  for (let col = 0; col < 4; col++) {
+    for (let row = 0; row < 4; row++) {
+      out[col * 4 + row] =
+        a[row] * b[col * 4] + a[row + 4] * b[col * 4 + 1] + a[row + 8] * b[col * 4 + 2] + a[row + 12] * b[col * 4 + 3];
+    }
+  }
+  return out;
+}
+
+function transformPoint(m: Float64Array, points: Points, i: number): [number, number, number] {
+  const x = points[i * 3], y = points[i * 3 + 1], z = points[i * 3 + 2];
+  return [
+    m[0] * x + m[4] * y + m[8] * z + m[12],
+    m[1] * x + m[5] * y + m[9] * z + m[13],
+    m[2] * x + m[6] * y + m[10] * z + m[14],
+  ];
+}
+
+// 旋转矩阵（行主序 3x3，R = Rz * Ry * Rx）转欧拉角
+function eulerFromRotation(r: Float64Array): Vec3Tuple {
+  const sy = Math.min(1, Math.max(-1, -r[6]));
+  const y = Math.asin(sy);
+  if (Math.abs(sy) < 0.999999) {
+    return [Math.atan2(r[7], r[8]), y, Math.atan2(r[3], r[0])];
+  }
+  // 万向节锁：z 取 0
+  return [Math.atan2(-r[5], r[4]), y, 0];
+}
+
+// 可复现的伪随机数 (mulberry32)
+function createRandom(seed: number): () => number {
+  let state = seed >>> 0;
+  return () => {
+    state = (state + 0x6d2b79f5) >>> 0;
+    let t = state;
+    t = Math.imul(t ^ (t >>> 15), t | 1);
+    t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
+    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+  };
+}
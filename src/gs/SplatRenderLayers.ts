@@ -0,0 +1,164 @@
+/**
+ * SplatRenderLayers - 当前视图的分层导出（颜色 / 深度 / splat ID）
+ *
+ * 颜色来自画布回读（包含网格、地面和 gizmo 等所有内容），深度和 ID 来自拾取 pass：
+ * 每个像素取不透明度超过拾取阈值的最近 splat，深度为该 splat 的视图空间距离（近似深度，
+ * 不是混合后的深度）。用于在外部工具中做后期合成和按 splat 遮罩。
+ * - EXR：单个多通道文件，R/G/B/A（线性、预乘 alpha）、Z（视图距离，无命中为 +Inf）、SplatID（索引 + 1，0 表示无命中）
+ * - PNG：颜色 PNG、ID PNG（索引 + 1 按 24 位写入 RGB，最多约 1677 万个 splat）和单独的深度 EXR
+ */
+
+import { encodeEXR } from "../utils/exr";
+import { encodePNG } from "../utils/png";
+import { linearizeDepth } from "../core/DepthConvention";
+import type { FrameCapture } from "../core/FrameCapture";
+
+/**
+ * 整帧的 splat ID 和深度
+ */
+export interface SplatIdDepth {
+  width: number;
+  height: number;
+  /** splat 索引 + 1，0 表示没有命中 */
+  ids: Uint32Array;
+  /** 视图空间距离，没有命中为 Infinity */
+  depths: Float32Array;
+}
+
+/**
+ * 导出的图层
+ */
+export interface SplatRenderLayers {
+  width: number;
+  height: number;
+  /** 线性 RGBA（预乘 alpha），逐行从上到下 */
+  color: Float32Array;
+  /** splat 索引 + 1，0 表示没有命中 */
+  ids: Uint32Array;
+  /** 视图空间距离，没有命中为 Infinity */
+  depths: Float32Array;
+}
+
+/**
+ * 分文件导出的结果
+ */
+export interface SplatRenderLayerFiles {
+  color: Blob;
+  id: Blob;
+  depth: Blob;
+}
+
+/**
+ * 把拾取 pass 的回读（按行对齐、NDC 深度）整理为紧凑的 ID / 视图距离
+ * @param projection 拾取时使用的投影矩阵
+ */
+export function createSplatIdDepth(
+  region: { ids: Uint32Array; depths: Float32Array; stride: number },
+  width: number,
+  height: number,
+  projection: ArrayLike<number>,
+): SplatIdDepth {
+  const ids = new Uint32Array(width * height);
+  const depths = new Float32Array(width * height).fill(Infinity);
+  for (let y = 0; y < height; y++) {
+    for (let x = 0; x < width; x++) {
+      const src = y * region.stride + x;
+      const id = region.ids[src];
+      if (id === 0) continue;
+      ids[y * width + x] = id;
+      depths[y * width + x] = linearizeDepth(projection, region.depths[src]);
+    }
+  }
+  return { width, height, ids, depths };
+}
+
+/**
+ * 合并画布回读和 ID / 深度为图层（颜色从 sRGB 编码转为线性）
+ */
+export function createSplatRenderLayers(capture: FrameCapture, idDepth: SplatIdDepth | null): SplatRenderLayers {
+  const { width, height, pixels } = capture;
+  if (idDepth && (idDepth.width !== width || idDepth.height !== height)) {
+    throw new Error("ID / 深度图层与颜色图层尺寸不一致");
+  }
+  const color = new Float32Array(pixels.length);
+  for (let i = 0; i < pixels.length; i += 4) {
+    const alpha = pixels[i + 3];
+    color[i + 3] = alpha;
+    if (alpha <= 0) continue;
+    // 画布是预乘的 sRGB 编码值：先还原再线性化，再重新预乘
+    for (let c = 0; c < 3; c++) {
+      color[i + c] = srgbToLinear(pixels[i + c] / alpha) * alpha;
+    }
+  }
+  return {
+    width,
+    height,
+    color,
+    ids: idDepth?.ids ?? new Uint32Array(width * height),
+    depths: idDepth?.depths ?? new Float32Array(width * height).fill(Infinity),
+  };
+}
+
+/**
+ * 编码为单个多通道 EXR
+ */
+export function encodeSplatRenderLayersEXR(layers: SplatRenderLayers): ArrayBuffer {
+  const { width, height } = layers;
+  return encodeEXR(width, height, [
+    ...["R", "G", "B", "A"].map((name, c) => ({
+      name,
+      type: "float" as const,
+      data: extractChannel(layers.color, c),
+    })),
+    { name: "Z", type: "float", data: layers.depths },
+    { name: "SplatID", type: "uint", data: layers.ids },
+  ]);
+}
+
+/**
+ * 编码为分开的文件：颜色 PNG、ID PNG（24 位 RGB）和深度 EXR
+ */
+export async function encodeSplatRenderLayerFiles(layers: SplatRenderLayers): Promise<SplatRenderLayerFiles> {
+  const { width, height } = layers;
+  const color = new Uint8ClampedArray(width * height * 4);
+  const id = new Uint8ClampedArray(width * height * 4);
+  for (let i = 0; i < width * height; i++) {
+    const alpha = layers.color[i * 4 + 3];
+    for (let c = 0; c < 3; c++) {
+      // PNG 不预乘
+      color[i * 4 + c] = alpha > 0 ? Math.round(linearToSrgb(layers.color[i * 4 + c] / alpha) * 255) : 0;
+    }
+    color[i * 4 + 3] = Math.round(alpha * 255);
+    const value = layers.ids[i];
+    id[i * 4] = value & 0xff;
+    id[i * 4 + 1] = (value >>> 8) & 0xff;
+    id[i * 4 + 2] = (value >>> 16) & 0xff;
+    id[i * 4 + 3] = 255;
+  }
+  const depth = encodeEXR(width, height, [{ name: "Z", type: "float", data: layers.depths }]);
+  return {
+    color: await encodePNG(color, width, height),
+    id: await encodePNG(id, width, height),
+    depth: new Blob([depth], { type: "image/x-exr" }),
+  };
+}
+
+function extractChannel(rgba: Float32Array, channel: number): Float32Array {
+  const result = new Float32Array(rgba.length / 4);
+  for (let i = 0; i < result.length; i++) {
+    result[i] = rgba[i * 4 + channel];
+  }
+  return result;
+}
+
+// HDR 画布的值可能超出 [0, 1]，按符号对称延伸
+function srgbToLinear(value: number): number {
+  const v = Math.abs(value);
+  const linear = v <= 0.04045 ? v / 12.92 : Math.pow((v + 0.055) / 1.055, 2.4);
+  return Math.sign(value) * linear;
+}
+
+function linearToSrgb(value: number): number {
+  const v = Math.min(1, Math.max(0, value));
+  return v <= 0.0031308 ? v * 12.92 : 1.055 * Math.pow(v, 1 / 2.4) - 0.055;
+}
@@ -0,0 +1,101 @@
+/**
+ * SplatSegments - 超出单个渲染器上限时把 splat 数据拆成多个段
+ *
+ * 单个 GSSplatRenderer 的 splat 数量受两个限制：
+ * - splat buffer 作为一个 storage binding 绑定，每个 splat 256 字节，
+ *   上限为 min(maxStorageBufferBindingSize, maxBufferSize) / 256
+ * - 剔除 / 排序按 256 线程一个 workgroup 一维 dispatch，每维最多 65535 个 workgroup，
+ *   上限约 1677 万
+ * 超过上限的数据按八叉树顺序切成空间上连续的段，每段由一个子渲染器独立剔除、排序和绘制，
+ * 段之间按到相机的距离由远到近合成
+ */
+
+import type { CompactSplatData } from "./PLYLoaderMobile";
+import { buildSplatOctree } from "./SplatOctree";
+
+/** GPU 端每个 splat 的字节数 */
+const SPLAT_BYTES = 256;
+/** 剔除 / 排序 compute pass 的 workgroup 大小 */
+const CULL_WORKGROUP_SIZE = 256;
+/** 单维 dispatch 的 workgroup 数量上限 */
+const MAX_WORKGROUPS_PER_DIMENSION = 65535;
+/** 每个 splat 的 SH 系数数量 */
+const SH_COEFF_COUNT = 45;
+
+/**
+ * 拆分后的一段数据
+ */
+export interface SplatSegment {
+  /** 段内的 splat 数据 */
+  data: CompactSplatData;
+  /** 段内索引 -> 原数据索引 */
+  indices: Uint32Array;
+}
+
+/**
+ * 获取单个渲染器能处理的最大 splat 数量
+ */
+export function getMaxSplatsPerSegment(device: GPUDevice): number {
+  const bindingBytes = Math.min(device.limits.maxStorageBufferBindingSize, device.limits.maxBufferSize);
+  const dispatchLimit = MAX_WORKGROUPS_PER_DIMENSION * CULL_WORKGROUP_SIZE;
+  return Math.min(Math.floor(bindingBytes / SPLAT_BYTES), dispatchLimit);
+}
+
+/**
+ * 按八叉树顺序把数据拆成每段不超过 maxPerSegment 个 splat
+ * 数量未超过上限时返回只含原数据的一段
+ */
+export function splitSplatData(data: CompactSplatData, maxPerSegment: number): SplatSegment[] {
+  const count = data.count;
+  if (count <= maxPerSegment) {
+    const indices = new Uint32Array(count);
+    for (let i = 0; i < count; i++) indices[i] = i;
+    return [{ data, indices }];
+  }
+
+  // 八叉树的 order 按子树连续排列，切成等长区间即得到空间上紧凑的段
+  const order = buildSplatOctree(data.positions, count).order;
+  const segmentCount = Math.ceil(count / maxPerSegment);
+  const segmentSize = Math.ceil(count / segmentCount);
+  const segments: SplatSegment[] = [];
+  for (let start = 0; start < count; start += segmentSize) {
+    const indices = order.slice(start, Math.min(count, start + segmentSize));
+    segments.push({ data: gatherSplatData(data, indices), indices });
+  }
+  return segments;
+}
+
+/**
+ * 按索引抽取子集
+ */
+export function gatherSplatData(data: CompactSplatData, indices: Uint32Array): CompactSplatData {
+  const count = indices.length;
+  const out: CompactSplatData = {
+    count,
+    positions: new Float32Array(count * 3),
+    scales: new Float32Array(count * 3),
+    rotations: new Float32Array(count * 4),
+    colors: new Float32Array(count * 3),
+    opacities: new Float32Array(count),
+    is2DGS: data.is2DGS,
+    origin: data.origin,
+  };
+  if (data.shCoeffs) out.shCoeffs = new Float32Array(count * SH_COEFF_COUNT);
+  if (data.labels) out.labels = new Uint32Array(count);
+  if (data.emissive) out.emissive = new Float32Array(count);
+
+  for (let i = 0; i < count; i++) {
+    const src = indices[i];
+    out.positions.set(data.positions.subarray(src * 3, src * 3 + 3), i * 3);
+    out.scales.set(data.scales.subarray(src * 3, src * 3 + 3), i * 3);
+    out.rotations.set(data.rotations.subarray(src * 4, src * 4 + 4), i * 4);
+    out.colors.set(data.colors.subarray(src * 3, src * 3 + 3), i * 3);
+    out.opacities[i] = data.opacities[src];
+    if (out.shCoeffs) {
+      out.shCoeffs.set(data.shCoeffs!.subarray(src * SH_COEFF_COUNT, (src + 1) * SH_COEFF_COUNT), i * SH_COEFF_COUNT);
+    }
+    if (out.labels) out.labels[i] = data.labels![src];
+    if (out.emissive) out.emissive[i] = data.emissive![src];
+  }
+  return out;
+}
@@ -0,0 +1,266 @@
+/**
+ * SplatSelection - splat 选择集
+ *
+ * CPU 端维护每个 splat 的选中状态（1 字节/splat），
+ * 渲染器在状态变化后将其上传到 GPU 用于高亮显示。
+ * 每次修改递增 version，使用方记录上次看到的版本号并比较，无需逐帧比较全部状态
+ */
+
+import type { SplatLabels } from "./SplatLabels";
+import type { CompactSplatData } from "./PLYLoaderMobile";
+import { matchesSplatAttributes } from "./SplatAttributeFilter";
+import type { SplatAttributePredicate } from "./SplatAttributeFilter";
+import { SplatSpatialHash } from "./SplatSpatialHash";
+
+/**
+ * 选择操作
+ * - set: 替换当前选择
+ * - add: 加入当前选择
+ * - subtract: 从当前选择中移除
+ */
+export type SelectionOp = "set" | "add" | "subtract";
+
+/**
+ * splat 选择集
+ */
+export class SplatSelection {
+  private mask: Uint8Array;
+  private selectedCount: number = 0;
+  // 单调递增的修改版本号
+  private version: number = 0;
+  private cleanVersion: number = -1;
+
+  constructor(count: number) {
+    this.mask = new Uint8Array(count);
+  }
+
+  /**
+   * splat 总数量
+   */
+  get count(): number {
+    return this.mask.length;
+  }
+
+  /**
+   * 指定 splat 是否被选中
+   */
+  isSelected(index: number): boolean {
+    return this.mask[index] !== 0;
+  }
+
+  /**
+   * 已选中的 splat 数量
+   */
+  getSelectedCount(): number {
+    return this.selectedCount;
+  }
+
+  /**
+   * 获取选择掩码（只读使用，1 表示选中）
+   */
+  getMask(): Uint8Array {
+    return this.mask;
+  }
+
+  /**
+   * 获取所有选中 splat 的索引
+   */
+  getSelectedIndices(): Uint32Array {
+    const result = new Uint32Array(this.selectedCount);
+    let n = 0;
+    for (let i = 0; i < this.mask.length; i++) {
+      if (this.mask[i] !== 0) {
+        result[n++] = i;
+      }
+    }
+    return result;
+  }
+
+  /**
+   * 清空选择
+   */
+  clear(): void {
+    this.mask.fill(0);
+    this.selectedCount = 0;
+    this.version++;
+  }
+
+  /**
+   * 全选
+   */
+  selectAll(): void {
+    this.mask.fill(1);
+    this.selectedCount = this.mask.length;
+    this.version++;
+  }
+
+  /**
+   * 反选
+   */
+  invert(): void {
+    for (let i = 0; i < this.mask.length; i++) {
+      this.mask[i] = this.mask[i] !== 0 ? 0 : 1;
+    }
+    this.selectedCount = this.mask.length - this.selectedCount;
+    this.version++;
+  }
+
+  /**
+   * 按谓词选择
+   * @param predicate 返回 true 表示该 splat 命中
+   * @param op 选择操作
+   * @returns 操作后的选中数量
+   */
+  selectWhere(predicate: (index: number) => boolean, op: SelectionOp = "set"): number {
+    let selected = 0;
+    for (let i = 0; i < this.mask.length; i++) {
+      const hit = predicate(i);
+      let value = this.mask[i];
+      if (op === "set") {
+        value = hit ? 1 : 0;
+      } else if (op === "add") {
+        if (hit) value = 1;
+      } else if (hit) {
+        value = 0;
+      }
+      this.mask[i] = value;
+      selected += value;
+    }
+    this.selectedCount = selected;
+    this.version++;
+    return selected;
+  }
+
+  /**
+   * 按索引选择（只访问给定的索引，适合画笔等小范围的增量修改）
+   * @param indices splat 索引
+   * @param op 选择操作
+   * @returns 选中状态发生变化的数量
+   */
+  selectIndices(indices: ArrayLike<number>, op: SelectionOp = "set"): number {
+    if (op === "set") {
+      this.clear();
+    }
+    const value = op === "subtract" ? 0 : 1;
+    let changed = 0;
+    for (let k = 0; k < indices.length; k++) {
+      const i = indices[k];
+      if (i < 0 || i >= this.mask.length || this.mask[i] === value) continue;
+      this.mask[i] = value;
+      changed++;
+    }
+    this.selectedCount += value ? changed : -changed;
+    this.version++;
+    return changed;
+  }
+
+  /**
+   * 按语义标签选择
+   * @param labels 逐 splat 标签
+   * @param label 单个标签或标签列表
+   * @param op 选择操作
+   * @returns 操作后的选中数量
+   */
+  selectByLabel(labels: SplatLabels, label: number | number[], op: SelectionOp = "set"): number {
+    if (labels.count !== this.mask.length) {
+      throw new Error(`标签数量 (${labels.count}) 与 splat 数量 (${this.mask.length}) 不一致`);
+    }
+    const ids = labels.ids;
+    if (Array.isArray(label)) {
+      const wanted = new Set(label);
+      return this.selectWhere((i) => wanted.has(ids[i]), op);
+    }
+    return this.selectWhere((i) => ids[i] === label, op);
+  }
+
+  /**
+   * 按属性阈值选择（谓词之间为“与”），例如 [{ attribute: "opacity", op: "<", value: 0.05 }]
+   * @param data splat 数据
+   * @param deleted 已删除标记（非 0 的 splat 不命中）
+   * @param op 选择操作
+   * @returns 操作后的选中数量
+   */
+  selectByAttributes(
+    data: CompactSplatData,
+    predicates: ReadonlyArray<SplatAttributePredicate>,
+    op: SelectionOp = "set",
+    deleted: Uint8Array | null = null,
+  ): number {
+    if (data.count !== this.mask.length) {
+      throw new Error(`splat 数量 (${data.count}) 与选择集 (${this.mask.length}) 不一致`);
+    }
+    return this.selectWhere((i) => !(deleted && deleted[i]) && matchesSplatAttributes(data, i, predicates), op);
+  }
+
+  /**
+   * 扩张选择：选中与已选 splat 中心距离不超过 distance 的 splat
+   * @param positions 位置 [x,y,z, ...]（与 distance 同一坐标空间）
+   * @param deleted 已删除标记（非 0 的 splat 不参与）
+   * @returns 新选中的数量
+   */
+  grow(positions: ArrayLike<number>, distance: number, deleted: Uint8Array | null = null): number {
+    if (distance <= 0 || this.selectedCount === 0) return 0;
+    const alive = (i: number) => !(deleted && deleted[i]);
+    const selected = new SplatSpatialHash(positions, this.mask.length, distance, (i) => this.mask[i] !== 0 && alive(i));
+    const added: number[] = [];
+    for (let i = 0; i < this.mask.length; i++) {
+      if (this.mask[i] || !alive(i)) continue;
+      if (selected.hasNeighbor(positions[i * 3], positions[i * 3 + 1], positions[i * 3 + 2], distance)) {
+        added.push(i);
+      }
+    }
+    return added.length > 0 ? this.selectIndices(added, "add") : 0;
+  }
+
+  /**
+   * 收缩选择：取消选中边界 splat（距离 distance 内存在未选中的 splat）
+   * @param positions 位置 [x,y,z, ...]（与 distance 同一坐标空间）
+   * @param deleted 已删除标记（非 0 的 splat 不算作未选中的邻居）
+   * @returns 取消选中的数量
+   */
+  shrink(positions: ArrayLike<number>, distance: number, deleted: Uint8Array | null = null): number {
+    if (distance <= 0 || this.selectedCount === 0) return 0;
+    const unselected = new SplatSpatialHash(
+      positions,
+      this.mask.length,
+      distance,
+      (i) => this.mask[i] === 0 && !(deleted && deleted[i]),
+    );
+    const removed: number[] = [];
+    for (let i = 0; i < this.mask.length; i++) {
+      if (!this.mask[i]) continue;
+      if (unselected.hasNeighbor(positions[i * 3], positions[i * 3 + 1], positions[i * 3 + 2], distance)) {
+        removed.push(i);
+      }
+    }
+    return removed.length > 0 ? this.selectIndices(removed, "subtract") : 0;
+  }
+
+  /**
+   * 修改版本号（每次修改递增）
+   */
+  getVersion(): number {
+    return this.version;
+  }
+
+  /**
+   * 选择状态是否有未上传的修改（单一使用方；多个使用方请各自比较 getVersion）
+   */
+  isDirty(): boolean {
+    return this.version !== this.cleanVersion;
+  }
+
+  /**
+   * 标记选择状态已同步到 GPU
+   */
+  markClean(): void {
+    this.cleanVersion = this.version;
+  }
+
+  /**
+   * 转换为 GPU 上传格式（每个 splat 一个 u32）
+   */
+  toGPUData(): Uint32Array {
+    return Uint32Array.from(this.mask);
+  }
+}
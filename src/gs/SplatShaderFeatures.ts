@@ -0,0 +1,96 @@
+/**
+ * SplatShaderFeatures - 构建时裁剪 shader 功能
+ *
+ * 每个功能对应一个构建时常量（__GS_FEATURE_*__），由打包工具的 define 替换为 true / false，
+ * 未定义时默认启用。关闭的功能在 JS 中换成空实现的 WGSL 片段，在 WGSL 中通过 const bool
+ * 让编译器去掉对应分支：打包时未使用的 shader 字符串被 tree-shaking 移除，
+ * 运行时也不会生成对应的管线变体（例如只读查看器不需要编辑预览和自定义可视化模式）。
+ *
+ * 构建库时通过环境变量 GS_DISABLE_SHADER_FEATURES 关闭（逗号分隔的功能名），
+ * 在自己的构建中使用源码时可以用 getSplatShaderFeatureDefines 生成 define 配置
+ */
+
+/**
+ * 可裁剪的 shader 功能
+ */
+export interface SplatShaderFeatures {
+  /** 编辑：选中高亮、颜色调整和不透明度编辑预览 */
+  editing: boolean;
+  /** 高阶 SH（关闭时只使用 DC 颜色，加载时也不读取 SH 系数） */
+  sh: boolean;
+  /** 摆动动画 */
+  sway: boolean;
+  /** 第二套外观混合 */
+  appearance: boolean;
+  /** 自定义可视化模式注册（每个模式一组管线变体） */
+  customVisModes: boolean;
+}
+
+declare const __GS_FEATURE_EDITING__: boolean | undefined;
+declare const __GS_FEATURE_SH__: boolean | undefined;
+declare const __GS_FEATURE_SWAY__: boolean | undefined;
+declare const __GS_FEATURE_APPEARANCE__: boolean | undefined;
+declare const __GS_FEATURE_CUSTOM_VIS_MODES__: boolean | undefined;
+
+// typeof 对未声明的标识符不会抛出，未配置 define 时（开发 / 测试）默认启用
+export const SPLAT_FEATURE_EDITING: boolean = typeof __GS_FEATURE_EDITING__ === "boolean" ? __GS_FEATURE_EDITING__ : true;
+export const SPLAT_FEATURE_SH: boolean = typeof __GS_FEATURE_SH__ === "boolean" ? __GS_FEATURE_SH__ : true;
+export const SPLAT_FEATURE_SWAY: boolean = typeof __GS_FEATURE_SWAY__ === "boolean" ? __GS_FEATURE_SWAY__ : true;
+export const SPLAT_FEATURE_APPEARANCE: boolean =
+  typeof __GS_FEATURE_APPEARANCE__ === "boolean" ? __GS_FEATURE_APPEARANCE__ : true;
+export const SPLAT_FEATURE_CUSTOM_VIS_MODES: boolean =
+  typeof __GS_FEATURE_CUSTOM_VIS_MODES__ === "boolean" ? __GS_FEATURE_CUSTOM_VIS_MODES__ : true;
+
+/**
+ * 当前构建启用的功能
+ */
+export function getSplatShaderFeatures(): SplatShaderFeatures {
+  return {
+    editing: SPLAT_FEATURE_EDITING,
+    sh: SPLAT_FEATURE_SH,
+    sway: SPLAT_FEATURE_SWAY,
+    appearance: SPLAT_FEATURE_APPEARANCE,
+    customVisModes: SPLAT_FEATURE_CUSTOM_VIS_MODES,
+  };
+}
+
+const DEFINE_NAMES: Record<keyof SplatShaderFeatures, string> = {
+  editing: "__GS_FEATURE_EDITING__",
+  sh: "__GS_FEATURE_SH__",
+  sway: "__GS_FEATURE_SWAY__",
+  appearance: "__GS_FEATURE_APPEARANCE__",
+  customVisModes: "__GS_FEATURE_CUSTOM_VIS_MODES__",
+};
+
+/**
+ * 生成打包工具的 define 配置（vite / esbuild / rollup），未指定的功能保持启用
+ */
+export function getSplatShaderFeatureDefines(features: Partial<SplatShaderFeatures>): Record<string, string> {
+  const defines: Record<string, string> = {};
+  for (const key of Object.keys(DEFINE_NAMES) as (keyof SplatShaderFeatures)[]) {
+    defines[DEFINE_NAMES[key]] = JSON.stringify(features[key] ?? true);
+  }
+  return defines;
+}
+
+/**
+ * 解析逗号分隔的功能名列表（例如 "editing,sway"），返回关闭这些功能的配置
+ */
+export function parseDisabledSplatShaderFeatures(list: string | undefined): Partial<SplatShaderFeatures> {
+  const features: Partial<SplatShaderFeatures> = {};
+  for (const name of (list ?? "").split(",").map((s) => s.trim()).filter(Boolean)) {
+    if (!(name in DEFINE_NAMES)) {
+      throw new Error(`未知的 shader 功能: "${name}"，可选: ${Object.keys(DEFINE_NAMES).join(", ")}`);
+    }
+    features[name as keyof SplatShaderFeatures] = false;
+  }
+  return features;
+}
+
+/**
+ * WGSL 功能常量（拼接在渲染器 shader 开头）
+ */
+export const splatShaderFeatureConstants = /* wgsl */ `
+const FEATURE_EDITING: bool = ${SPLAT_FEATURE_EDITING};
+const FEATURE_SH: bool = ${SPLAT_FEATURE_SH};
+`;
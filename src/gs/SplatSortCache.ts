@@ -0,0 +1,99 @@
+/**
+ * SplatSortCache - 逐实体的排序结果复用（时间相干）
+ *
+ * 每个渲染器持有自己的缓存，记录上一次排序时的视图、相机 / 模型矩阵和剔除参数。
+ * 这些输入都没有变化、且数据没有更新时，直接复用上一帧的可见索引和 indirect buffer，跳过剔除和排序。
+ * 缓存按实体独立：一个实体移动或被编辑只会让它自己重新排序，静止的实体继续跳过
+ */
+
+/**
+ * 排序复用统计
+ */
+export interface SplatSortCacheStats {
+  /** 累计执行排序的帧数 */
+  sortedFrames: number;
+  /** 累计跳过排序的帧数 */
+  skippedFrames: number;
+}
+
+/**
+ * 单个实体的排序缓存
+ */
+export class SplatSortCache {
+  private enabled: boolean = true;
+  // 上一次排序对应的视图（相机），null 表示没有可复用的结果
+  private view: object | null = null;
+  private key: Float64Array = new Float64Array(0);
+  private dataUpdated: boolean = true;
+  private sortedFrames: number = 0;
+  private skippedFrames: number = 0;
+
+  /**
+   * 设置是否复用排序结果（关闭时每帧排序）
+   */
+  setEnabled(enabled: boolean): void {
+    this.enabled = enabled;
+    this.invalidate();
+  }
+
+  /**
+   * 是否复用排序结果
+   */
+  isEnabled(): boolean {
+    return this.enabled;
+  }
+
+  /**
+   * 标记 splat 数据已更新（上传编辑、重建排序器、剔除结构变化等）
+   */
+  markDataUpdated(): void {
+    this.dataUpdated = true;
+  }
+
+  /**
+   * 丢弃缓存的结果，下一帧必须排序
+   */
+  invalidate(): void {
+    this.view = null;
+    this.dataUpdated = true;
+  }
+
+  /**
+   * 判断本帧是否需要排序，需要时记录新的输入
+   * @param view 本帧使用的视图（相机）
+   * @param key 影响剔除和排序结果的全部输入（矩阵、屏幕尺寸、剔除参数等）
+   */
+  needsSort(view: object, key: ArrayLike<number>): boolean {
+    if (this.enabled && !this.dataUpdated && this.view === view && this.sameKey(key)) {
+      this.skippedFrames++;
+      return false;
+    }
+    this.view = view;
+    if (this.key.length !== key.length) {
+      this.key = new Float64Array(key.length);
+    }
+    this.key.set(key);
+    this.dataUpdated = false;
+    this.sortedFrames++;
+    return true;
+  }
+
+  /**
+   * 获取统计
+   */
+  getStats(): SplatSortCacheStats {
+    return { sortedFrames: this.sortedFrames, skippedFrames: this.skippedFrames };
+  }
+
+  private sameKey(key: ArrayLike<number>): boolean {
+    if (key.length !== this.key.length) {
+      return false;
+    }
+    for (let i = 0; i < key.length; i++) {
+      if (key[i] !== this.key[i]) {
+        return false;
+      }
+    }
+    return true;
+  }
+}
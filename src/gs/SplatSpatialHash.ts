@@ -0,0 +1,71 @@
+/**
+ * SplatSpatialHash - splat 中心的均匀网格哈希（邻域查询）
+ *
+ * 格子边长等于查询半径上限，查询只需检查相邻的 27 个格子。
+ * 键为格子坐标的哈希，冲突只会让查询多检查几个点（仍按实际距离判断）
+ */
+
+export class SplatSpatialHash {
+  readonly cellSize: number;
+  private positions: ArrayLike<number>;
+  private cells: Map<number, number[]> = new Map();
+
+  /**
+   * @param cellSize 格子边长（查询半径不能超过该值）
+   * @param filter 返回 false 的 splat 不加入网格
+   */
+  constructor(positions: ArrayLike<number>, count: number, cellSize: number, filter?: (index: number) => boolean) {
+    this.positions = positions;
+    this.cellSize = cellSize;
+    for (let i = 0; i < count; i++) {
+      if (filter && !filter(i)) continue;
+      const key = this.keyOf(positions[i * 3], positions[i * 3 + 1], positions[i * 3 + 2]);
+      let cell = this.cells.get(key);
+      if (!cell) {
+        cell = [];
+        this.cells.set(key, cell);
+      }
+      cell.push(i);
+    }
+  }
+
+  /**
+   * 统计半径内的 splat（不含 exclude），达到 limit 后提前返回
+   */
+  countNeighbors(x: number, y: number, z: number, radius: number, limit: number = Infinity, exclude: number = -1): number {
+    const p = this.positions;
+    const s = this.cellSize;
+    const cx = Math.floor(x / s), cy = Math.floor(y / s), cz = Math.floor(z / s);
+    const r2 = radius * radius;
+    let neighbors = 0;
+    for (let dz = -1; dz <= 1; dz++) {
+      for (let dy = -1; dy <= 1; dy++) {
+        for (let dx = -1; dx <= 1; dx++) {
+          const cell = this.cells.get(cellKey(cx + dx, cy + dy, cz + dz));
+          if (!cell) continue;
+          for (const j of cell) {
+            if (j === exclude) continue;
+            const d2 = (p[j * 3] - x) ** 2 + (p[j * 3 + 1] - y) ** 2 + (p[j * 3 + 2] - z) ** 2;
+            if (d2 <= r2 && ++neighbors >= limit) return neighbors;
+          }
+        }
+      }
+    }
+    return neighbors;
+  }
+
+  /**
+   * 半径内是否有 splat（不含 exclude）
+   */
+  hasNeighbor(x: number, y: number, z: number, radius: number, exclude: number = -1): boolean {
+    return this.countNeighbors(x, y, z, radius, 1, exclude) > 0;
+  }
+
+  private keyOf(x: number, y: number, z: number): number {
+    return cellKey(Math.floor(x / this.cellSize), Math.floor(y / this.cellSize), Math.floor(z / this.cellSize));
+  }
+}
+
+function cellKey(x: number, y: number, z: number): number {
+  return ((x * 73856093) ^ (y * 19349663) ^ (z * 83492791)) | 0;
+}
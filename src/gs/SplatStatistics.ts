@@ -0,0 +1,101 @@
+/**
+ * SplatStatistics - 包围盒 / 质心统计与原点重定位
+ *
+ * 地理参考数据的坐标通常在 1e5 ~ 1e7 量级，f32 只剩厘米到米级精度：
+ * - "f32"：按存储精度统计，结果舍入到 f32（与 GPU 端一致，默认）
+ * - "f64"：Neumaier 补偿求和，结果保持 f64；加载时以 f64 质心为原点重定位位置，
+ *   f32 只保存相对原点的小坐标，原点本身以 f64 另行保存
+ */
+
+import type { Vec3Tuple } from "../types";
+
+/**
+ * 统计精度
+ */
+export type SplatPrecision = "f32" | "f64";
+
+/**
+ * splat 位置统计
+ */
+export interface SplatStatistics {
+  count: number;
+  min: Vec3Tuple;
+  max: Vec3Tuple;
+  /** 包围盒中心 */
+  center: Vec3Tuple;
+  /** 包围盒半对角线 */
+  radius: number;
+  /** 位置均值 */
+  centroid: Vec3Tuple;
+}
+
+/**
+ * 计算位置统计
+ * @param origin 位置相对的原点（f64），结果加上原点后返回
+ */
+export function computeSplatStatistics(
+  positions: ArrayLike<number>,
+  count: number,
+  precision: SplatPrecision = "f32",
+  origin: Vec3Tuple = [0, 0, 0],
+): SplatStatistics {
+  if (count === 0) {
+    return { count: 0, min: [...origin], max: [...origin], center: [...origin], radius: 0, centroid: [...origin] };
+  }
+
+  const round = precision === "f64" ? (v: number) => v : Math.fround;
+  const min: Vec3Tuple = [Infinity, Infinity, Infinity];
+  const max: Vec3Tuple = [-Infinity, -Infinity, -Infinity];
+  const centroid: Vec3Tuple = [0, 0, 0];
+
+  for (let axis = 0; axis < 3; axis++) {
+    let sum = 0;
+    let compensation = 0;
+    let lo = Infinity;
+    let hi = -Infinity;
+    for (let i = 0; i < count; i++) {
+      const v = positions[i * 3 + axis];
+      if (v < lo) lo = v;
+      if (v > hi) hi = v;
+      if (precision === "f64") {
+        // Neumaier 补偿求和
+        const t = sum + v;
+        compensation += Math.abs(sum) >= Math.abs(v) ? (sum - t) + v : (v - t) + sum;
+        sum = t;
+      } else {
+        sum = Math.fround(sum + v);
+      }
+    }
+    min[axis] = round(lo + origin[axis]);
+    max[axis] = round(hi + origin[axis]);
+    centroid[axis] = round((sum + compensation) / count + origin[axis]);
+  }
+
+  const dx = max[0] - min[0];
+  const dy = max[1] - min[1];
+  const dz = max[2] - min[2];
+  return {
+    count,
+    min,
+    max,
+    center: [round((min[0] + max[0]) / 2), round((min[1] + max[1]) / 2), round((min[2] + max[2]) / 2)],
+    radius: Math.sqrt(dx * dx + dy * dy + dz * dz) / 2,
+    centroid,
+  };
+}
+
+/**
+ * 以 f64 质心为原点重定位位置
+ * @param source f64 位置
+ * @param target 写入相对原点的 f32 位置
+ * @returns 原点（f64）
+ */
+export function rebasePositions(source: Float64Array, target: Float32Array, count: number): Vec3Tuple {
+  const origin = computeSplatStatistics(source, count, "f64").centroid;
+  for (let i = 0; i < count; i++) {
+    target[i * 3] = source[i * 3] - origin[0];
+    target[i * 3 + 1] = source[i * 3 + 1] - origin[1];
+    target[i * 3 + 2] = source[i * 3 + 2] - origin[2];
+  }
+  return origin;
+}
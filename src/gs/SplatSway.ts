@@ -0,0 +1,133 @@
+/**
+ * SplatSway - 逐 splat 摆动动画（风吹植被）
+ *
+ * 在顶点着色器中按时间偏移 splat 中心，让扫描得到的树木 / 草地在交互场景中不再静止：
+ * - 摆动幅度随模型空间高度（Y-up）从 baseHeight 到 baseHeight + heightRange 由 0 平方增长到 1，
+ *   根部固定、顶部摆动最大；heightRange 为 0 时所有 splat 同样摆动
+ * - 相位来自沿风向移动的程序化值噪声，相邻 splat 同步摆动，阵风沿风向传播
+ *   （程序化噪声代替噪声纹理，不需要额外的绑定）
+ * 只影响绘制位置，不影响排序、剔除和拾取（偏移通常远小于 splat 间距）
+ */
+
+import type { Vec3Tuple } from "../types";
+import { SPLAT_FEATURE_SWAY } from "./SplatShaderFeatures";
+
+/**
+ * 摆动配置
+ */
+export interface SplatSwayConfig {
+  /** 是否启用 */
+  enabled: boolean;
+  /** 风向（模型空间，会归一化） */
+  direction: Vec3Tuple;
+  /** 最大偏移（模型空间单位） */
+  amplitude: number;
+  /** 摆动频率 (Hz) */
+  frequency: number;
+  /** 摆动起始高度（模型空间 Y） */
+  baseHeight: number;
+  /** 从起始高度到完全摆动的高度范围，0 表示不按高度衰减 */
+  heightRange: number;
+  /** 噪声空间频率（每单位长度），越大相邻 splat 的相位差越大 */
+  noiseScale: number;
+}
+
+export const DEFAULT_SPLAT_SWAY_CONFIG: SplatSwayConfig = {
+  enabled: false,
+  direction: [1, 0, 0],
+  amplitude: 0.02,
+  frequency: 0.5,
+  baseHeight: 0,
+  heightRange: 1,
+  noiseScale: 0.5,
+};
+
+/**
+ * 摆动 uniform 数据：[风向 xyz, 幅度], [频率, 时间, 起始高度, 高度范围], [噪声频率, 0, 0, 0]
+ * 未启用时幅度为 0，shader 跳过计算
+ */
+export function getSplatSwayUniforms(
+  config: SplatSwayConfig,
+  time: number,
+  data: Float32Array = new Float32Array(12),
+): Float32Array {
+  data.fill(0);
+  if (!config.enabled || config.amplitude <= 0) {
+    return data;
+  }
+  const [dx, dy, dz] = config.direction;
+  const length = Math.hypot(dx, dy, dz) || 1;
+  data.set([dx / length, dy / length, dz / length, config.amplitude], 0);
+  data.set([config.frequency, time, config.baseHeight, Math.max(0, config.heightRange)], 4);
+  data[8] = config.noiseScale;
+  return data;
+}
+
+// 摆动 shader 函数（读取 uniforms.swayWind / swayParams / swayNoise）
+const swayShader = /* wgsl */ `
+fn swayHash(p: vec3<f32>) -> f32 {
+  let q = fract(p * 0.3183099 + vec3<f32>(0.1, 0.2, 0.3)) * 17.0;
+  return fract(q.x * q.y * q.z * (q.x + q.y + q.z));
+}
+
+// 三线性插值的值噪声 (0~1)
+fn swayNoise(p: vec3<f32>) -> f32 {
+  let i = floor(p);
+  let f = fract(p);
+  let u = f * f * (3.0 - 2.0 * f);
+  return mix(
+    mix(
+      mix(swayHash(i), swayHash(i + vec3<f32>(1.0, 0.0, 0.0)), u.x),
+      mix(swayHash(i + vec3<f32>(0.0, 1.0, 0.0)), swayHash(i + vec3<f32>(1.0, 1.0, 0.0)), u.x),
+      u.y,
+    ),
+    mix(
+      mix(swayHash(i + vec3<f32>(0.0, 0.0, 1.0)), swayHash(i + vec3<f32>(1.0, 0.0, 1.0)), u.x),
+      mix(swayHash(i + vec3<f32>(0.0, 1.0, 1.0)), swayHash(i + vec3<f32>(1.0, 1.0, 1.0)), u.x),
+      u.y,
+    ),
+    u.z,
+  );
+}
+
+// splat 中心的摆动偏移（模型空间）
+fn swayOffset(mean: vec3<f32>) -> vec3<f32> {
+  let amplitude = uniforms.swayWind.w;
+  if amplitude <= 0.0 { return vec3<f32>(0.0); }
+  let dir = uniforms.swayWind.xyz;
+  let frequency = uniforms.swayParams.x;
+  let time = uniforms.swayParams.y;
+  let range = uniforms.swayParams.w;
+  var weight = 1.0;
+  if range > 0.0 {
+    let h = clamp((mean.y - uniforms.swayParams.z) / range, 0.0, 1.0);
+    weight = h * h;
+  }
+  if weight <= 0.0 { return vec3<f32>(0.0); }
+
+  // 阵风沿风向传播：噪声坐标随时间沿风向移动
+  let gust = swayNoise(mean * uniforms.swayNoise.x - dir * (time * frequency));
+  let phase = time * frequency * 2.0 * PI + gust * 2.0 * PI;
+  // 主摆动偏向下风方向，叠加垂直于风向的小幅抖动
+  let side = normalize(cross(dir, vec3<f32>(0.0, 1.0, 0.0)) + vec3<f32>(1e-4, 0.0, 0.0));
+  let lean = (0.5 + 0.5 * sin(phase)) * (0.5 + 0.5 * gust);
+  let flutter = 0.3 * sin(phase * 2.3 + 1.7);
+  return (dir * lean + side * flutter) * (amplitude * weight);
+}
+
+// 读取 splat 并应用摆动偏移
+fn animatedSplat(splatIndex: u32) -> Splat {
+  var splat = splats[splatIndex];
+  splat.mean += swayOffset(splat.mean);
+  return splat;
+}
+`;
+
+/**
+ * 摆动 shader 函数（拼接在渲染器 shader 中），构建时关闭摆动功能时为不偏移的空实现
+ */
+export const splatSwayShader = SPLAT_FEATURE_SWAY ? swayShader : /* wgsl */ `
+fn animatedSplat(splatIndex: u32) -> Splat {
+  return splats[splatIndex];
+}
+`;
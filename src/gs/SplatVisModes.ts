@@ -0,0 +1,149 @@
+/**
+ * SplatVisModes - 可扩展的可视化模式注册表
+ *
+ * 插件按名称注册自定义可视化模式（例如 "thermal"），提供一段 WGSL 颜色函数，
+ * 不需要修改 SplatVisMode 枚举和 shader 中的分支：
+ * - 注册时分配从 SPLAT_VIS_MODE_CUSTOM_BASE 开始的模式编号，编号或名称都可以传给 setVisMode
+ * - 渲染器切换到自定义模式时把颜色函数注入 shader，生成独立的 shader module 和管线，按 pipelineKey 缓存
+ * - 颜色函数可以读取 shader 中的全局绑定（uniforms / splats / labels / scalars）和内置函数，
+ *   自定义的函数和常量名建议加前缀避免与内置名称冲突
+ * 仅桌面端渲染器支持；tile 光栅化模式下显示原始颜色
+ */
+
+import { SplatVisMode } from "../types";
+import { SPLAT_FEATURE_CUSTOM_VIS_MODES } from "./SplatShaderFeatures";
+
+/**
+ * 自定义可视化模式的起始编号（之前的编号保留给 SplatVisMode）
+ */
+export const SPLAT_VIS_MODE_CUSTOM_BASE = 16;
+
+/**
+ * shader 中自定义颜色函数的占位实现，注入时整体替换
+ */
+export const SPLAT_VIS_MODE_HOOK =
+  "fn customVisColor(splatIndex: u32, color: vec3<f32>) -> vec3<f32> { return color; }";
+
+/**
+ * 自定义可视化模式定义
+ */
+export interface SplatVisModeDefinition {
+  /** 模式名称（唯一，不能与 SplatVisMode 成员重名） */
+  name: string;
+  /** WGSL 声明（函数 / 常量），必须定义 colorFunction */
+  code: string;
+  /** 颜色函数名，签名为 fn(splatIndex: u32, color: vec3<f32>) -> vec3<f32>，color 是已乘曝光的原始颜色 */
+  colorFunction: string;
+  /** shader 常量，注入为 WGSL const（布尔值为 bool，数值为 f32） */
+  defines?: Record<string, number | boolean>;
+}
+
+/**
+ * 已注册的可视化模式
+ */
+export interface RegisteredSplatVisMode extends Readonly<SplatVisModeDefinition> {
+  /** 模式编号（可传给 setVisMode） */
+  id: SplatVisMode;
+  /** 管线缓存键：名称 + 注册版本，重新注册同名模式时变化 */
+  pipelineKey: string;
+}
+
+const modes: Map<string, RegisteredSplatVisMode> = new Map();
+let nextId = SPLAT_VIS_MODE_CUSTOM_BASE;
+let version = 0;
+
+/**
+ * 注册自定义可视化模式，返回模式编号
+ * 同名模式重新注册时沿用原编号，已切换到该模式的渲染器在下一帧使用新的 shader
+ */
+export function registerSplatVisMode(definition: SplatVisModeDefinition): SplatVisMode {
+  if (!SPLAT_FEATURE_CUSTOM_VIS_MODES) {
+    throw new Error("[SplatVisModes] 当前构建关闭了自定义可视化模式");
+  }
+  if (definition.name in SplatVisMode) {
+    throw new Error(`[SplatVisModes] "${definition.name}" 是内置可视化模式`);
+  }
+  if (!/^[A-Za-z_][A-Za-z0-9_]*$/.test(definition.colorFunction)) {
+    throw new Error(`[SplatVisModes] 颜色函数名无效: "${definition.colorFunction}"`);
+  }
+  const id = modes.get(definition.name)?.id ?? (nextId++ as SplatVisMode);
+  const mode: RegisteredSplatVisMode = {
+    ...definition,
+    defines: definition.defines ? { ...definition.defines } : undefined,
+    id,
+    pipelineKey: `${definition.name}@${++version}`,
+  };
+  modes.set(definition.name, mode);
+  return id;
+}
+
+/**
+ * 注销自定义可视化模式，返回是否注销；正在使用该模式的渲染器回退为原始颜色
+ */
+export function unregisterSplatVisMode(name: string): boolean {
+  return modes.delete(name);
+}
+
+/**
+ * 按名称或编号查找已注册的自定义模式
+ */
+export function findSplatVisMode(nameOrId: string | number): RegisteredSplatVisMode | null {
+  if (typeof nameOrId === "string") {
+    return modes.get(nameOrId) ?? null;
+  }
+  if (nameOrId < SPLAT_VIS_MODE_CUSTOM_BASE) {
+    return null;
+  }
+  for (const mode of modes.values()) {
+    if (mode.id === nameOrId) {
+      return mode;
+    }
+  }
+  return null;
+}
+
+/**
+ * 获取全部已注册的自定义模式
+ */
+export function getRegisteredSplatVisModes(): RegisteredSplatVisMode[] {
+  return [...modes.values()];
+}
+
+/**
+ * 把模式名称解析为编号：内置模式按枚举成员名，自定义模式按注册名，找不到时返回 null
+ */
+export function resolveSplatVisMode(mode: SplatVisMode | string): SplatVisMode | null {
+  if (typeof mode !== "string") {
+    return mode;
+  }
+  const builtin = (SplatVisMode as unknown as Record<string, SplatVisMode | undefined>)[mode];
+  if (typeof builtin === "number") {
+    return builtin;
+  }
+  return modes.get(mode)?.id ?? null;
+}
+
+/**
+ * 生成注入了自定义颜色函数的 shader 源码
+ */
+export function buildSplatVisModeShader(baseCode: string, mode: RegisteredSplatVisMode): string {
+  if (!baseCode.includes(SPLAT_VIS_MODE_HOOK)) {
+    throw new Error("[SplatVisModes] shader 中没有自定义可视化模式的占位函数");
+  }
+  const defines = Object.entries(mode.defines ?? {}).map(([name, value]) =>
+    typeof value === "boolean" ? `const ${name}: bool = ${value};` : `const ${name}: f32 = ${formatF32(value)};`,
+  );
+  const injected = [
+    `// 自定义可视化模式: ${mode.name}`,
+    ...defines,
+    mode.code,
+    `fn customVisColor(splatIndex: u32, color: vec3<f32>) -> vec3<f32> { return ${mode.colorFunction}(splatIndex, color); }`,
+  ].join("\n");
+  return baseCode.replace(SPLAT_VIS_MODE_HOOK, () => injected);
+}
+
+// WGSL 浮点字面量必须带小数点或指数
+function formatF32(value: number): string {
+  const text = String(value);
+  return /[.eE]/.test(text) ? text : `${text}.0`;
+}
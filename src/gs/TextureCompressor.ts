@@ -4,21 +4,289 @@
  *
  * 内存对比：
  * - 原始 Storage Buffer: 256 bytes/splat
- * - 纹理压缩: ~52 bytes/splat (约 5x 压缩)
+ * - 纹理压缩: 32~52 bytes/splat (约 5-8x 压缩)
  * 
- * 数据布局（保证精度）：
- * - positionTexture (RGBA32Float): x, y, z, unused - 16 bytes
- * - scaleRotTexture1 (RGBA32Float): scale_x, scale_y, scale_z, rot_w - 16 bytes
- * - scaleRotTexture2 (RGBA32Float): rot_x, rot_y, rot_z, unused - 16 bytes
+ * 数据布局：
+ * - positionTexture: 编码由 PackModeConfig.position 决定
+ *   - f32 (RGBA32Float): x, y, z, emissive - 16 bytes
+ *   - f16 (RGBA16Float): 相对块中心的 x, y, z + emissive - 8 bytes
+ *   - unorm16 (RGBA16Uint): 块包围盒内的 16 bit 定点 x, y, z + f16 emissive - 8 bytes
+ * - scaleTexture (RGBA32Float): scale_x, scale_y, scale_z, unused - 16 bytes
+ * - rotationTexture: 编码由 PackModeConfig.rotation 决定
+ *   - f32 (RGBA32Float): w, x, y, z - 16 bytes
+ *   - f16 (RGBA16Float): w, x, y, z - 8 bytes
+ *   - snorm10 (RGB10A2Unorm): x, y, z (10-10-10 snorm)，w 在 shader 中重建 - 4 bytes
  * - colorTexture (RGBA8Unorm): r, g, b, opacity - 4 bytes
- * 总计: 52 bytes/splat
+ * - shTexture (2D 数组，15 层，每层一个 SH 系数的 rgb): 编码由 PackModeConfig.sh 决定
+ *   - none: 不存储，仅 L0 - 0 bytes
+ *   - f16 (RGBA16Float): r, g, b, unused - 120 bytes
+ *   - 11-10-11 (R32Uint): r, g, b 按全部系数的最大绝对值量化为 11-10-11 bit 定点 - 60 bytes
+ * - chunkTexture (RGBA32Float，仅量化位置时): 每 256 个 splat 一块，存块包围盒 min / extent - 32 bytes/块
+ * 总计: 52 / 44 / 40 bytes/splat（f32 位置），量化位置再减 8 bytes
  * 
- * 注意：使用 RGBA32Float 替代 RGBA16Float 以保证 scale 和 rotation 的精度，
- * 避免平面等细节渲染出现块状伪影。
+ * 注意：scale 始终使用 RGBA32Float 以保证精度，避免平面等细节渲染出现块状伪影；
+ * rotation 对精度不太敏感，可按场景选择更紧凑的编码；SH 只影响视角相关的颜色变化，11-10-11 通常看不出差别。
+ * 量化位置的精度取决于块的空间范围，数据应按空间顺序排列（如八叉树顺序）。
  */
 
 import { CompactSplatData } from "./PLYLoaderMobile";
 
+/**
+ * rotation 编码方式
+ * - f32: 完整精度
+ * - f16: 半精度
+ * - snorm10: 10-10-10-2 snorm，仅存 xyz，w 由单位长度约束重建
+ */
+export type RotationEncoding = "f32" | "f16" | "snorm10";
+
+/**
+ * position 编码方式
+ * - f32: 完整精度
+ * - f16: 相对所在块中心的半精度偏移
+ * - unorm16: 所在块包围盒内的 16 bit 定点坐标
+ */
+export type PositionEncoding = "f32" | "f16" | "unorm16";
+
+/**
+ * SH 编码方式
+ * - none: 不存储 SH，仅 L0
+ * - f16: 半精度
+ * - 11-10-11: 每个系数的 rgb 打包为一个 u32（11-10-11 bit 定点，范围取全部系数的最大绝对值）
+ */
+export type SHEncoding = "none" | "f16" | "11-10-11";
+
+/**
+ * 逐属性的打包编码配置
+ */
+export interface PackModeConfig {
+  rotation: RotationEncoding;
+  position: PositionEncoding;
+  sh: SHEncoding;
+}
+
+/**
+ * 默认打包配置（与旧版一致的完整精度，不存储 SH）
+ */
+export const DEFAULT_PACK_MODE_CONFIG: PackModeConfig = {
+  rotation: "f32",
+  position: "f32",
+  sh: "none",
+};
+
+/** 量化位置时每块的 splat 数量 */
+export const POSITION_CHUNK_SIZE = 256;
+/** 块纹理宽度（每块占 2 个 texel: min, extent） */
+const CHUNK_TEXTURE_WIDTH = 512;
+/** SH 纹理层数（L1 ~ L3 每通道 15 个系数） */
+export const SH_TEXTURE_LAYERS = 15;
+
+/**
+ * 各 rotation 编码对应的纹理格式和每像素字节数
+ */
+const ROTATION_FORMATS: Record<RotationEncoding, { format: GPUTextureFormat; bytesPerPixel: number }> = {
+  f32: { format: "rgba32float", bytesPerPixel: 16 },
+  f16: { format: "rgba16float", bytesPerPixel: 8 },
+  snorm10: { format: "rgb10a2unorm", bytesPerPixel: 4 },
+};
+
+/**
+ * 各 position 编码对应的纹理格式和每像素字节数
+ */
+const POSITION_FORMATS: Record<PositionEncoding, { format: GPUTextureFormat; bytesPerPixel: number }> = {
+  f32: { format: "rgba32float", bytesPerPixel: 16 },
+  f16: { format: "rgba16float", bytesPerPixel: 8 },
+  unorm16: { format: "rgba16uint", bytesPerPixel: 8 },
+};
+
+/**
+ * 各 SH 编码对应的纹理格式和每层每像素字节数
+ */
+const SH_FORMATS: Record<Exclude<SHEncoding, "none">, { format: GPUTextureFormat; bytesPerPixel: number }> = {
+  f16: { format: "rgba16float", bytesPerPixel: 8 },
+  "11-10-11": { format: "r32uint", bytesPerPixel: 4 },
+};
+
+/**
+ * 计算指定打包配置下每个 splat 占用的纹理字节数（不含块纹理）
+ */
+export function getBytesPerSplat(config: PackModeConfig): number {
+  const shBytes = config.sh === "none" ? 0 : SH_FORMATS[config.sh].bytesPerPixel * SH_TEXTURE_LAYERS;
+  return POSITION_FORMATS[config.position].bytesPerPixel + 16 + ROTATION_FORMATS[config.rotation].bytesPerPixel + 4 + shBytes;
+}
+
+/**
+ * 位置纹理的采样类型
+ */
+export function getPositionSampleType(encoding: PositionEncoding): GPUTextureSampleType {
+  return encoding === "unorm16" ? "uint" : "unfilterable-float";
+}
+
+/**
+ * 生成与打包配置匹配的 WGSL 位置纹理声明和解码函数
+ * positionTex 绑定在 @group(1) @binding(0)，量化编码额外声明 @binding(4) 的 chunkTex；
+ * 返回的 loadPosition(index, texCoord) 输出 (x, y, z, emissive)
+ */
+export function getPositionDecodeWGSL(encoding: PositionEncoding): string {
+  if (encoding === "f32") {
+    return /* wgsl */ `
+@group(1) @binding(0) var positionTex: texture_2d<f32>;
+
+fn loadPosition(index: u32, texCoord: vec2<u32>) -> vec4<f32> {
+  return textureLoad(positionTex, texCoord, 0);
+}
+`;
+  }
+
+  const chunkCommon = /* wgsl */ `
+@group(1) @binding(4) var chunkTex: texture_2d<f32>;
+
+// 读取 splat 所在块的包围盒 (min, extent)
+fn loadChunkBounds(index: u32) -> mat2x3<f32> {
+  let texel = (index / ${POSITION_CHUNK_SIZE}u) * 2u;
+  let coord = vec2<u32>(texel % ${CHUNK_TEXTURE_WIDTH}u, texel / ${CHUNK_TEXTURE_WIDTH}u);
+  let boundsMin = textureLoad(chunkTex, coord, 0).xyz;
+  let extent = textureLoad(chunkTex, coord + vec2<u32>(1u, 0u), 0).xyz;
+  return mat2x3<f32>(boundsMin, extent);
+}
+`;
+  if (encoding === "f16") {
+    return /* wgsl */ `
+@group(1) @binding(0) var positionTex: texture_2d<f32>;
+${chunkCommon}
+fn loadPosition(index: u32, texCoord: vec2<u32>) -> vec4<f32> {
+  let bounds = loadChunkBounds(index);
+  let sample = textureLoad(positionTex, texCoord, 0);
+  return vec4<f32>(bounds[0] + bounds[1] * 0.5 + sample.xyz, sample.w);
+}
+`;
+  }
+  return /* wgsl */ `
+@group(1) @binding(0) var positionTex: texture_2d<u32>;
+${chunkCommon}
+fn loadPosition(index: u32, texCoord: vec2<u32>) -> vec4<f32> {
+  let bounds = loadChunkBounds(index);
+  let sample = textureLoad(positionTex, texCoord, 0);
+  let t = vec3<f32>(sample.xyz) / 65535.0;
+  return vec4<f32>(bounds[0] + bounds[1] * t, unpack2x16float(sample.w).x);
+}
+`;
+}
+
+/**
+ * SH 纹理的采样类型
+ */
+export function getSHSampleType(encoding: SHEncoding): GPUTextureSampleType {
+  return encoding === "11-10-11" ? "uint" : "unfilterable-float";
+}
+
+/**
+ * 生成与打包配置匹配的 WGSL SH 纹理声明和解码函数
+ * shTex 绑定在 @group(1) @binding(5)（none 时不声明）；
+ * 返回的 loadSHCoeff(texCoord, k, range) 输出第 k 个系数的 rgb，range 为 11-10-11 的量化范围
+ */
+export function getSHDecodeWGSL(encoding: SHEncoding): string {
+  if (encoding === "none") {
+    return /* wgsl */ `
+fn loadSHCoeff(texCoord: vec2<u32>, k: u32, range: f32) -> vec3<f32> {
+  return vec3<f32>(0.0);
+}
+`;
+  }
+  if (encoding === "f16") {
+    return /* wgsl */ `
+@group(1) @binding(5) var shTex: texture_2d_array<f32>;
+
+fn loadSHCoeff(texCoord: vec2<u32>, k: u32, range: f32) -> vec3<f32> {
+  return textureLoad(shTex, texCoord, k, 0).rgb;
+}
+`;
+  }
+  return /* wgsl */ `
+@group(1) @binding(5) var shTex: texture_2d_array<u32>;
+
+fn loadSHCoeff(texCoord: vec2<u32>, k: u32, range: f32) -> vec3<f32> {
+  let bits = textureLoad(shTex, texCoord, k, 0).r;
+  let t = vec3<f32>(
+    f32(bits & 0x7ffu) / 2047.0,
+    f32((bits >> 11u) & 0x3ffu) / 1023.0,
+    f32(bits >> 21u) / 2047.0,
+  );
+  return (t * 2.0 - 1.0) * range;
+}
+`;
+}
+
+/**
+ * 生成与打包配置匹配的 WGSL rotation 解码函数
+ * 返回的 decodeRotation(sample) 输出 (w, x, y, z)
+ */
+export function getRotationDecodeWGSL(encoding: RotationEncoding): string {
+  if (encoding === "snorm10") {
+    return /* wgsl */ `
+fn decodeRotation(sample: vec4<f32>) -> vec4<f32> {
+  let xyz = sample.xyz * 2.0 - 1.0;
+  let w = sqrt(max(1.0 - dot(xyz, xyz), 0.0));
+  return vec4<f32>(w, xyz);
+}
+`;
+  }
+  return /* wgsl */ `
+fn decodeRotation(sample: vec4<f32>) -> vec4<f32> {
+  return sample;
+}
+`;
+}
+
+// float32 -> float16 位转换用的共享视图
+const f32Scratch = new Float32Array(1);
+const u32Scratch = new Uint32Array(f32Scratch.buffer);
+
+/**
+ * float32 转 float16 位表示（round-to-nearest，处理 denormal/溢出）
+ */
+export function float32ToFloat16(value: number): number {
+  f32Scratch[0] = value;
+  const x = u32Scratch[0];
+  const sign = (x >>> 16) & 0x8000;
+  const exp = (x >>> 23) & 0xff;
+  let mant = x & 0x7fffff;
+
+  // NaN / Inf
+  if (exp === 0xff) {
+    return sign | 0x7c00 | (mant ? 0x200 : 0);
+  }
+
+  const e = exp - 127 + 15;
+  if (e >= 0x1f) {
+    return sign | 0x7c00;
+  }
+  if (e <= 0) {
+    if (e < -10) return sign;
+    mant |= 0x800000;
+    const shift = 14 - e;
+    return sign | ((mant + (1 << (shift - 1))) >>> shift);
+  }
+  // 舍入可能进位到指数，结果仍然正确
+  return sign | ((e << 10) + ((mant + 0x1000) >>> 13));
+}
+
+/**
+ * 将 [-1, 1] 的 snorm 值量化为 10 bit unorm
+ */
+function packSnorm10(value: number): number {
+  const v = Math.max(-1, Math.min(1, value));
+  return Math.round((v * 0.5 + 0.5) * 1023);
+}
+
+/**
+ * 将 [-range, range] 的值量化为 bits 位 unorm
+ */
+function packSymmetric(value: number, range: number, bits: number): number {
+  const max = (1 << bits) - 1;
+  const t = range > 0 ? value / range : 0;
+  return Math.round((Math.max(-1, Math.min(1, t)) * 0.5 + 0.5) * max);
+}
+
 /**
  * 压缩后的纹理数据
  */
@@ -28,19 +296,39 @@ export interface CompressedSplatTextures {
   height: number;
   count: number;
 
-  // 位置纹理 (RGBA32Float) - 完整精度
+  // 位置纹理，格式由 packMode.position 决定
   positionTexture: GPUTexture;
 
-  // 缩放+旋转纹理 (RGBA32Float) - 保证精度
-  // R: scale_x, G: scale_y, B: scale_z, A: rot_w
-  scaleRotTexture1: GPUTexture;
-  // R: rot_x, G: rot_y, B: rot_z, A: unused
-  scaleRotTexture2: GPUTexture;
+  // 块包围盒纹理 (RGBA32Float)，仅量化位置时存在
+  chunkTexture: GPUTexture | null;
+
+  // 缩放纹理 (RGBA32Float) - 保证精度
+  // R: scale_x, G: scale_y, B: scale_z, A: unused
+  scaleTexture: GPUTexture;
+
+  // 旋转纹理，格式由 packMode.rotation 决定
+  rotationTexture: GPUTexture;
+
+  // 使用的打包配置
+  packMode: PackModeConfig;
+
+  // 每个 splat 占用的纹理字节数
+  bytesPerSplat: number;
 
   // 颜色+不透明度纹理 (RGBA8Unorm)
   // R: color_r, G: color_g, B: color_b, A: opacity
   colorTexture: GPUTexture;
 
+  // SH 纹理（2D 数组，格式由 packMode.sh 决定），none 时为 null；
+  // 数据没有 SH 系数时为 1x1 的占位纹理
+  shTexture: GPUTexture | null;
+
+  // 数据是否包含 SH 系数
+  hasSH: boolean;
+
+  // 11-10-11 的量化范围（全部系数的最大绝对值）
+  shRange: number;
+
   // Bounding box (用于剔除优化)
   boundingBox: {
     min: [number, number, number];
@@ -110,17 +398,40 @@ function computeBoundingBox(positions: Float32Array, count: number): {
   return { min, max };
 }
 
+/**
+ * 计算每块 (POSITION_CHUNK_SIZE 个 splat) 的包围盒
+ * @returns 每块 8 个 float: min.xyz, 0, extent.xyz, 0
+ */
+function computeChunkBounds(positions: Float32Array, count: number): Float32Array {
+  const chunkCount = Math.ceil(count / POSITION_CHUNK_SIZE);
+  const bounds = new Float32Array(chunkCount * 8);
+  for (let c = 0; c < chunkCount; c++) {
+    const start = c * POSITION_CHUNK_SIZE;
+    const end = Math.min(count, start + POSITION_CHUNK_SIZE);
+    const box = computeBoundingBox(positions.subarray(start * 3, end * 3), end - start);
+    for (let axis = 0; axis < 3; axis++) {
+      bounds[c * 8 + axis] = box.min[axis];
+      bounds[c * 8 + 4 + axis] = box.max[axis] - box.min[axis];
+    }
+  }
+  return bounds;
+}
+
 /**
  * 将 splat 数据压缩为纹理格式
  * @param device GPU 设备
  * @param data 紧凑格式的 splat 数据
+ * @param packMode 逐属性的打包编码配置
  * @returns 压缩后的纹理数据
  */
 export function compressSplatsToTextures(
   device: GPUDevice,
-  data: CompactSplatData
+  data: CompactSplatData,
+  packMode: PackModeConfig = DEFAULT_PACK_MODE_CONFIG
 ): CompressedSplatTextures {
   const count = data.count;
+  const rotationFormat = ROTATION_FORMATS[packMode.rotation];
+  const positionFormat = POSITION_FORMATS[packMode.position];
   const { width, height } = calculateTextureDimensions(count);
   const totalPixels = width * height;
 
@@ -131,39 +442,101 @@ export function compressSplatsToTextures(
   // 准备 CPU 端数据
   // ============================================
 
-  // 位置纹理数据 (RGBA32Float) - 使用 Float32Array
-  const positionData = new Float32Array(totalPixels * 4);
-
-  // 缩放+旋转纹理数据 (RGBA32Float) - 使用 Float32Array 保证精度
-  const scaleRotData1 = new Float32Array(totalPixels * 4);
-  const scaleRotData2 = new Float32Array(totalPixels * 4);
+  // 位置纹理数据 - f32 使用 Float32Array，量化编码使用 Uint16Array
+  const positionData = packMode.position === "f32"
+    ? new Float32Array(totalPixels * 4)
+    : new Uint16Array(totalPixels * 4);
+  const chunkBounds = packMode.position === "f32" ? null : computeChunkBounds(data.positions, count);
+
+  // 缩放纹理数据 (RGBA32Float) - 使用 Float32Array 保证精度
+  const scaleData = new Float32Array(totalPixels * 4);
+
+  // 旋转纹理数据 - 按编码选择存储类型
+  let rotationData: Float32Array | Uint16Array | Uint32Array;
+  if (packMode.rotation === "f32") {
+    rotationData = new Float32Array(totalPixels * 4);
+  } else if (packMode.rotation === "f16") {
+    rotationData = new Uint16Array(totalPixels * 4);
+  } else {
+    rotationData = new Uint32Array(totalPixels);
+  }
 
   // 颜色纹理数据 (RGBA8Unorm)
   const colorData = new Uint8Array(totalPixels * 4);
 
+  // SH 纹理数据（每层 totalPixels 个像素，第 k 层为第 k 个系数）
+  const shCoeffs = packMode.sh !== "none" ? data.shCoeffs : undefined;
+  let shRange = 0;
+  let shData: Uint16Array | Uint32Array | null = null;
+  if (shCoeffs) {
+    for (let j = 0; j < count * SH_TEXTURE_LAYERS * 3; j++) {
+      shRange = Math.max(shRange, Math.abs(shCoeffs[j]));
+    }
+    shData = packMode.sh === "f16"
+      ? new Uint16Array(totalPixels * SH_TEXTURE_LAYERS * 4)
+      : new Uint32Array(totalPixels * SH_TEXTURE_LAYERS);
+  }
+
   // ============================================
   // 填充数据
   // ============================================
   for (let i = 0; i < count; i++) {
     const pixelOffset = i * 4;
 
-    // 位置数据 - 直接存储 float32
-    positionData[pixelOffset + 0] = data.positions[i * 3 + 0];
-    positionData[pixelOffset + 1] = data.positions[i * 3 + 1];
-    positionData[pixelOffset + 2] = data.positions[i * 3 + 2];
-    positionData[pixelOffset + 3] = 0; // unused
-
-    // scaleRotTexture1: scale_x, scale_y, scale_z, rot_w (直接存储 float32)
-    scaleRotData1[pixelOffset + 0] = data.scales[i * 3 + 0];
-    scaleRotData1[pixelOffset + 1] = data.scales[i * 3 + 1];
-    scaleRotData1[pixelOffset + 2] = data.scales[i * 3 + 2];
-    scaleRotData1[pixelOffset + 3] = data.rotations[i * 4 + 0]; // rot_w
-
-    // scaleRotTexture2: rot_x, rot_y, rot_z, unused (直接存储 float32)
-    scaleRotData2[pixelOffset + 0] = data.rotations[i * 4 + 1]; // rot_x
-    scaleRotData2[pixelOffset + 1] = data.rotations[i * 4 + 2]; // rot_y
-    scaleRotData2[pixelOffset + 2] = data.rotations[i * 4 + 3]; // rot_z
-    scaleRotData2[pixelOffset + 3] = 0; // unused
+    // 位置数据
+    const emissive = data.emissive ? data.emissive[i] : 0; // 自发光强度
+    if (!chunkBounds) {
+      positionData[pixelOffset + 0] = data.positions[i * 3 + 0];
+      positionData[pixelOffset + 1] = data.positions[i * 3 + 1];
+      positionData[pixelOffset + 2] = data.positions[i * 3 + 2];
+      positionData[pixelOffset + 3] = emissive;
+    } else {
+      const chunkOffset = Math.floor(i / POSITION_CHUNK_SIZE) * 8;
+      for (let axis = 0; axis < 3; axis++) {
+        const min = chunkBounds[chunkOffset + axis];
+        const extent = chunkBounds[chunkOffset + 4 + axis];
+        const p = data.positions[i * 3 + axis];
+        if (packMode.position === "f16") {
+          // 相对块中心的偏移
+          positionData[pixelOffset + axis] = float32ToFloat16(p - (min + extent * 0.5));
+        } else {
+          const t = extent > 0 ? (p - min) / extent : 0;
+          positionData[pixelOffset + axis] = Math.round(Math.max(0, Math.min(1, t)) * 65535);
+        }
+      }
+      positionData[pixelOffset + 3] = float32ToFloat16(emissive);
+    }
+
+    // scaleTexture: scale_x, scale_y, scale_z, unused (直接存储 float32)
+    scaleData[pixelOffset + 0] = data.scales[i * 3 + 0];
+    scaleData[pixelOffset + 1] = data.scales[i * 3 + 1];
+    scaleData[pixelOffset + 2] = data.scales[i * 3 + 2];
+    scaleData[pixelOffset + 3] = 0; // unused
+
+    // rotationTexture: w, x, y, z
+    const rw = data.rotations[i * 4 + 0];
+    const rx = data.rotations[i * 4 + 1];
+    const ry = data.rotations[i * 4 + 2];
+    const rz = data.rotations[i * 4 + 3];
+    if (rotationData instanceof Float32Array) {
+      rotationData[pixelOffset + 0] = rw;
+      rotationData[pixelOffset + 1] = rx;
+      rotationData[pixelOffset + 2] = ry;
+      rotationData[pixelOffset + 3] = rz;
+    } else if (rotationData instanceof Uint16Array) {
+      rotationData[pixelOffset + 0] = float32ToFloat16(rw);
+      rotationData[pixelOffset + 1] = float32ToFloat16(rx);
+      rotationData[pixelOffset + 2] = float32ToFloat16(ry);
+      rotationData[pixelOffset + 3] = float32ToFloat16(rz);
+    } else {
+      // q 与 -q 表示同一旋转，翻转到 w >= 0 以便在 shader 中重建 w
+      const s = rw < 0 ? -1 : 1;
+      rotationData[i] = (
+        packSnorm10(rx * s) |
+        (packSnorm10(ry * s) << 10) |
+        (packSnorm10(rz * s) << 20)
+      ) >>> 0;
+    }
 
     // 颜色数据 (已经是 0-1 范围，转换为 0-255)
     const r = data.colors[i * 3 + 0];
@@ -175,6 +548,28 @@ export function compressSplatsToTextures(
     colorData[pixelOffset + 1] = Math.round(Math.max(0, Math.min(1, g)) * 255);
     colorData[pixelOffset + 2] = Math.round(Math.max(0, Math.min(1, b)) * 255);
     colorData[pixelOffset + 3] = Math.round(Math.max(0, Math.min(1, opacity)) * 255);
+
+    // SH 系数按 (系数, 通道) 交错存储
+    if (shCoeffs && shData) {
+      const shBase = i * SH_TEXTURE_LAYERS * 3;
+      for (let k = 0; k < SH_TEXTURE_LAYERS; k++) {
+        const sr = shCoeffs[shBase + k * 3 + 0];
+        const sg = shCoeffs[shBase + k * 3 + 1];
+        const sb = shCoeffs[shBase + k * 3 + 2];
+        const texel = k * totalPixels + i;
+        if (shData instanceof Uint16Array) {
+          shData[texel * 4 + 0] = float32ToFloat16(sr);
+          shData[texel * 4 + 1] = float32ToFloat16(sg);
+          shData[texel * 4 + 2] = float32ToFloat16(sb);
+        } else {
+          shData[texel] = (
+            packSymmetric(sr, shRange, 11) |
+            (packSymmetric(sg, shRange, 10) << 11) |
+            (packSymmetric(sb, shRange, 11) << 21)
+          ) >>> 0;
+        }
+      }
+    }
   }
 
   // ============================================
@@ -182,24 +577,24 @@ export function compressSplatsToTextures(
   // ============================================
   const textureUsage = GPUTextureUsage.TEXTURE_BINDING | GPUTextureUsage.COPY_DST;
 
-  // 位置纹理 (RGBA32Float) - 完整精度
+  // 位置纹理 (格式由打包配置决定)
   const positionTexture = device.createTexture({
     size: { width, height },
-    format: "rgba32float",
+    format: positionFormat.format,
     usage: textureUsage,
   });
 
-  // 缩放+旋转纹理1 (RGBA32Float) - 保证精度
-  const scaleRotTexture1 = device.createTexture({
+  // 缩放纹理 (RGBA32Float) - 保证精度
+  const scaleTexture = device.createTexture({
     size: { width, height },
     format: "rgba32float",
     usage: textureUsage,
   });
 
-  // 缩放+旋转纹理2 (RGBA32Float) - 保证精度
-  const scaleRotTexture2 = device.createTexture({
+  // 旋转纹理 (格式由打包配置决定)
+  const rotationTexture = device.createTexture({
     size: { width, height },
-    format: "rgba32float",
+    format: rotationFormat.format,
     usage: textureUsage,
   });
 
@@ -214,27 +609,48 @@ export function compressSplatsToTextures(
   // 上传数据到 GPU
   // ============================================
   
-  // 位置纹理 (RGBA32Float = 16 bytes per pixel)
+  // 位置纹理 (16 / 8 bytes per pixel)
   device.queue.writeTexture(
     { texture: positionTexture },
     positionData,
-    { bytesPerRow: width * 16 },
+    { bytesPerRow: width * positionFormat.bytesPerPixel },
     { width, height }
   );
 
-  // 缩放+旋转纹理1 (RGBA32Float = 16 bytes per pixel)
+  // 块包围盒纹理 (RGBA32Float，每块 2 个 texel)
+  let chunkTexture: GPUTexture | null = null;
+  if (chunkBounds) {
+    const texels = chunkBounds.length / 4;
+    const chunkWidth = Math.min(texels, CHUNK_TEXTURE_WIDTH);
+    const chunkHeight = Math.ceil(texels / CHUNK_TEXTURE_WIDTH);
+    const chunkData = new Float32Array(chunkWidth * chunkHeight * 4);
+    chunkData.set(chunkBounds);
+    chunkTexture = device.createTexture({
+      size: { width: chunkWidth, height: chunkHeight },
+      format: "rgba32float",
+      usage: textureUsage,
+    });
+    device.queue.writeTexture(
+      { texture: chunkTexture },
+      chunkData,
+      { bytesPerRow: chunkWidth * 16 },
+      { width: chunkWidth, height: chunkHeight }
+    );
+  }
+
+  // 缩放纹理 (RGBA32Float = 16 bytes per pixel)
   device.queue.writeTexture(
-    { texture: scaleRotTexture1 },
-    scaleRotData1,
+    { texture: scaleTexture },
+    scaleData,
     { bytesPerRow: width * 16 },
     { width, height }
   );
 
-  // 缩放+旋转纹理2 (RGBA32Float = 16 bytes per pixel)
+  // 旋转纹理 (16 / 8 / 4 bytes per pixel)
   device.queue.writeTexture(
-    { texture: scaleRotTexture2 },
-    scaleRotData2,
-    { bytesPerRow: width * 16 },
+    { texture: rotationTexture },
+    rotationData,
+    { bytesPerRow: width * rotationFormat.bytesPerPixel },
     { width, height }
   );
 
@@ -246,23 +662,42 @@ export function compressSplatsToTextures(
     { width, height }
   );
 
-  // 计算内存占用
-  const memoryBytes = 
-    width * height * 16 + // positionTexture (RGBA32Float)
-    width * height * 16 + // scaleRotTexture1 (RGBA32Float)
-    width * height * 16 + // scaleRotTexture2 (RGBA32Float)
-    width * height * 4;   // colorTexture (RGBA8Unorm)
-  const memoryMB = memoryBytes / (1024 * 1024);
-  const bytesPerSplat = memoryBytes / count;
+  // SH 纹理 (2D 数组，每层 8 / 4 bytes per pixel)；数据没有 SH 时只创建占位纹理供绑定
+  let shTexture: GPUTexture | null = null;
+  if (packMode.sh !== "none") {
+    const shFormat = SH_FORMATS[packMode.sh];
+    const shSize = shData
+      ? { width, height, depthOrArrayLayers: SH_TEXTURE_LAYERS }
+      : { width: 1, height: 1, depthOrArrayLayers: 1 };
+    shTexture = device.createTexture({
+      size: shSize,
+      format: shFormat.format,
+      usage: textureUsage,
+    });
+    if (shData) {
+      device.queue.writeTexture(
+        { texture: shTexture },
+        shData,
+        { bytesPerRow: width * shFormat.bytesPerPixel, rowsPerImage: height },
+        shSize
+      );
+    }
+  }
 
   return {
     width,
     height,
     count,
     positionTexture,
-    scaleRotTexture1,
-    scaleRotTexture2,
+    chunkTexture,
+    scaleTexture,
+    rotationTexture,
     colorTexture,
+    shTexture,
+    hasSH: shData !== null,
+    shRange,
+    packMode: { ...packMode },
+    bytesPerSplat: getBytesPerSplat(shData ? packMode : { ...packMode, sh: "none" }),
     boundingBox,
   };
 }
@@ -273,7 +708,9 @@ export function compressSplatsToTextures(
  */
 export function destroyCompressedTextures(textures: CompressedSplatTextures): void {
   textures.positionTexture.destroy();
-  textures.scaleRotTexture1.destroy();
-  textures.scaleRotTexture2.destroy();
+  textures.chunkTexture?.destroy();
+  textures.scaleTexture.destroy();
+  textures.rotationTexture.destroy();
   textures.colorTexture.destroy();
+  textures.shTexture?.destroy();
 }
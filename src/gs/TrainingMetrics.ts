@@ -0,0 +1,155 @@
+/**
+ * TrainingMetrics - 训练过程指标
+ *
+ * 训练循环每次迭代（或每隔若干次）写入迭代次数、loss、PSNR、splat 数量和当前 SH 阶数，
+ * 记录为历史样本并通知监听者，宿主 UI 可以直接绘制曲线，不需要另外建立通信通道。
+ * 历史按 maxHistory 截断，超过时丢弃最早的样本
+ */
+
+/**
+ * 一次迭代的指标
+ */
+export interface TrainingMetricsSample {
+  /** 迭代次数 */
+  iteration: number;
+  /** 训练 loss */
+  loss?: number;
+  /** PSNR (dB) */
+  psnr?: number;
+  /** 当前 splat 数量（致密化 / 剪枝后） */
+  splatCount?: number;
+  /** 当前 SH 阶数（0~3，随训练逐步提升） */
+  shDegree?: number;
+  /** 其它自定义指标（学习率、SSIM 等） */
+  extra?: Record<string, number>;
+  /** 写入时间（performance.now()，毫秒），不传时自动填写 */
+  time?: number;
+}
+
+/**
+ * 训练指标事件类型：update 写入新样本，reset 清空历史
+ */
+export type TrainingMetricsEventType = "update" | "reset";
+
+export type TrainingMetricsCallback = (sample: Readonly<TrainingMetricsSample> | null, metrics: TrainingMetrics) => void;
+
+/** 默认保留的历史样本数 */
+export const DEFAULT_TRAINING_METRICS_HISTORY = 10000;
+
+type TrainingMetricsSeriesKey = "loss" | "psnr" | "splatCount" | "shDegree";
+const SERIES_KEYS: ReadonlySet<string> = new Set<TrainingMetricsSeriesKey>(["loss", "psnr", "splatCount", "shDegree"]);
+
+/**
+ * TrainingMetrics - 训练指标记录
+ */
+export class TrainingMetrics {
+  private history: TrainingMetricsSample[] = [];
+  private current: TrainingMetricsSample | null = null;
+  private listeners: Map<TrainingMetricsEventType, Set<TrainingMetricsCallback>> = new Map();
+
+  /** 保留的历史样本数 */
+  maxHistory: number;
+
+  constructor(maxHistory: number = DEFAULT_TRAINING_METRICS_HISTORY) {
+    this.maxHistory = maxHistory;
+  }
+
+  /**
+   * 写入一次迭代的指标
+   * 未提供的字段沿用上一个样本的值（例如只在评估时计算 PSNR），extra 逐项合并
+   */
+  update(sample: TrainingMetricsSample): TrainingMetricsSample {
+    const previous = this.current;
+    const merged: TrainingMetricsSample = {
+      iteration: sample.iteration,
+      loss: sample.loss ?? previous?.loss,
+      psnr: sample.psnr ?? previous?.psnr,
+      splatCount: sample.splatCount ?? previous?.splatCount,
+      shDegree: sample.shDegree ?? previous?.shDegree,
+      extra: previous?.extra || sample.extra ? { ...previous?.extra, ...sample.extra } : undefined,
+      time: sample.time ?? performance.now(),
+    };
+    this.current = merged;
+    this.history.push(merged);
+    if (this.history.length > this.maxHistory) {
+      this.history.splice(0, this.history.length - this.maxHistory);
+    }
+    this.emit("update", merged);
+    return merged;
+  }
+
+  /**
+   * 最新的样本，没有写入时为 null
+   */
+  getCurrent(): Readonly<TrainingMetricsSample> | null {
+    return this.current;
+  }
+
+  /**
+   * 全部历史样本（按写入顺序）
+   */
+  getHistory(): ReadonlyArray<Readonly<TrainingMetricsSample>> {
+    return this.history;
+  }
+
+  /**
+   * 某个指标的曲线：迭代次数和对应的值（跳过没有该指标的样本）
+   * @param key 内置指标名，或 extra 中的自定义指标名
+   */
+  getSeries(key: TrainingMetricsSeriesKey | string): { iterations: Float64Array; values: Float64Array } {
+    const iterations: number[] = [];
+    const values: number[] = [];
+    for (const sample of this.history) {
+      const value = SERIES_KEYS.has(key) ? sample[key as TrainingMetricsSeriesKey] : sample.extra?.[key];
+      if (value === undefined || !Number.isFinite(value)) continue;
+      iterations.push(sample.iteration);
+      values.push(value);
+    }
+    return { iterations: Float64Array.from(iterations), values: Float64Array.from(values) };
+  }
+
+  /**
+   * 清空历史（重新开始训练）
+   */
+  reset(): void {
+    this.history = [];
+    this.current = null;
+    this.emit("reset", null);
+  }
+
+  /**
+   * 监听事件
+   * @returns 取消监听函数
+   */
+  on(type: TrainingMetricsEventType, callback: TrainingMetricsCallback): () => void {
+    let set = this.listeners.get(type);
+    if (!set) {
+      set = new Set();
+      this.listeners.set(type, set);
+    }
+    set.add(callback);
+    return () => this.off(type, callback);
+  }
+
+  /**
+   * 取消监听
+   */
+  off(type: TrainingMetricsEventType, callback: TrainingMetricsCallback): void {
+    this.listeners.get(type)?.delete(callback);
+  }
+
+  private emit(type: TrainingMetricsEventType, sample: TrainingMetricsSample | null): void {
+    const set = this.listeners.get(type);
+    if (!set) return;
+    for (const callback of [...set]) {
+      callback(sample, this);
+    }
+  }
+
+  /**
+   * 移除所有监听者
+   */
+  destroy(): void {
+    this.listeners.clear();
+  }
+}
@@ -0,0 +1,165 @@
+/**
+ * TrainingSplatChannel - 训练数据的双缓冲更新
+ *
+ * updateCompactData 会接管传入的数组，并在之后的若干帧中按上传预算逐块读取；
+ * 训练器如果继续原地修改同一份数组，就会上传到写了一半的数据（撕裂），而每一步都复制整份数据又会长时间阻塞主线程。
+ * 这里维护两份 CPU 数据和一个代数计数：
+ * - 训练器 beginWrite 取得后台数据并写入，publish 交换前后台并递增代数
+ * - 渲染端每帧 consume，代数变化时把前台数据交给渲染器（只上传 dirty 区间）
+ * - 后台数据只落后前台一步，beginWrite 时只复制上一步的 dirty 区间追平，不复制整份数据
+ * - 渲染端接管新的前台数据之前，后台数据仍在被读取，此时 beginWrite 返回 null
+ */
+
+import type { CompactSplatData } from "./PLYLoaderMobile";
+import { cloneCompactSplatData } from "./GaussianSplats";
+import { SplatDirtyRanges } from "./SplatDirtyRanges";
+
+/**
+ * consume 的结果
+ */
+export interface TrainingSplatUpdate {
+  /** 最新发布的数据（渲染器接管，调用方不应再修改） */
+  data: CompactSplatData;
+  /** 与渲染端上一次取得的数据相比被修改的 splat */
+  dirty: SplatDirtyRanges;
+  /** 发布代数 */
+  generation: number;
+}
+
+// 每个 splat 的分量数
+const ATTRIBUTE_STRIDES: Array<[keyof CompactSplatData, number]> = [
+  ["positions", 3],
+  ["scales", 3],
+  ["rotations", 4],
+  ["colors", 3],
+  ["opacities", 1],
+  ["shCoeffs", 45],
+  ["labels", 1],
+  ["emissive", 1],
+];
+
+/**
+ * 把 ranges 中的 splat 从 source 复制到 target（布局相同）
+ */
+function copySplatRanges(source: CompactSplatData, target: CompactSplatData, ranges: [number, number][]): void {
+  for (const [key, stride] of ATTRIBUTE_STRIDES) {
+    const from = source[key] as Float32Array | Uint32Array | undefined;
+    const to = target[key] as Float32Array | Uint32Array | undefined;
+    if (!from || !to) continue;
+    for (const [start, end] of ranges) {
+      to.set(from.subarray(start * stride, end * stride), start * stride);
+    }
+  }
+}
+
+/**
+ * TrainingSplatChannel - 训练器与渲染器之间的双缓冲通道
+ */
+export class TrainingSplatChannel {
+  // buffers[front] 为最新发布的数据，另一份为后台
+  private buffers: [CompactSplatData, CompactSplatData];
+  private front: number = 0;
+  private generation: number = 0;
+  private consumedGeneration: number = -1;
+  // 后台数据相对前台落后的 splat（上一步发布的 dirty）
+  private catchUp: SplatDirtyRanges | null = null;
+  // 渲染端尚未取得的修改（结构变化时为 null，表示全部修改）
+  private unconsumed: SplatDirtyRanges | null = null;
+  private writing: boolean = false;
+
+  /**
+   * @param initial 初始数据（通道接管该数组作为前台，并复制一份作为后台）
+   */
+  constructor(initial: CompactSplatData) {
+    this.buffers = [initial, cloneCompactSplatData(initial)];
+  }
+
+  /**
+   * 当前发布代数（每次 publish / replace 加一）
+   */
+  getGeneration(): number {
+    return this.generation;
+  }
+
+  /**
+   * 渲染端是否已经取得最新发布的数据（此时才可以写入后台）
+   */
+  isWritable(): boolean {
+    return this.consumedGeneration === this.generation;
+  }
+
+  /**
+   * 开始写入：返回追平后的后台数据，训练器原地修改后调用 publish
+   * 渲染端还没有取得上一次发布的数据时返回 null（后台数据仍在上传），训练器可以跳过这一步的同步
+   */
+  beginWrite(): CompactSplatData | null {
+    if (this.writing) {
+      return this.buffers[1 - this.front];
+    }
+    if (!this.isWritable()) {
+      return null;
+    }
+    const back = this.buffers[1 - this.front];
+    if (this.catchUp) {
+      copySplatRanges(this.buffers[this.front], back, this.catchUp.getRanges());
+      this.catchUp = null;
+    }
+    this.writing = true;
+    return back;
+  }
+
+  /**
+   * 发布 beginWrite 之后的修改：交换前后台并递增代数
+   * @param dirty 本次修改的 splat，省略时视为全部修改（之后的追平会复制整份数据）
+   */
+  publish(dirty?: SplatDirtyRanges): number {
+    if (!this.writing) {
+      throw new Error("[TrainingSplatChannel] publish 之前需要先调用 beginWrite");
+    }
+    const count = this.buffers[this.front].count;
+    const changes = dirty ?? new SplatDirtyRanges().addRange(0, count, true);
+    this.writing = false;
+    this.front = 1 - this.front;
+    this.catchUp = changes;
+    this.unconsumed = changes;
+    return ++this.generation;
+  }
+
+  /**
+   * 替换为新的数据集（致密化 / 剪枝改变了 splat 数量等结构变化），通道接管该数组
+   * 渲染端下一次 consume 时整体重新上传；同样需要渲染端已经取得上一次发布的数据
+   * @returns 是否替换成功
+   */
+  replace(data: CompactSplatData): boolean {
+    if (this.writing || !this.isWritable()) {
+      return false;
+    }
+    this.buffers = [data, cloneCompactSplatData(data)];
+    this.front = 0;
+    this.catchUp = null;
+    this.unconsumed = null;
+    this.generation++;
+    return true;
+  }
+
+  /**
+   * 渲染端每帧调用：有新发布的数据时返回数据和修改范围，否则返回 null
+   */
+  consume(): TrainingSplatUpdate | null {
+    if (this.consumedGeneration === this.generation) {
+      return null;
+    }
+    const data = this.buffers[this.front];
+    const dirty = this.unconsumed ?? new SplatDirtyRanges().addRange(0, data.count, true);
+    this.consumedGeneration = this.generation;
+    this.unconsumed = null;
+    return { data, dirty, generation: this.generation };
+  }
+
+  /**
+   * 最新发布的数据（只读）
+   */
+  getFront(): CompactSplatData {
+    return this.buffers[this.front];
+  }
+}
@@ -0,0 +1,66 @@
+/**
+ * TransferFunction - 体渲染模式的传递函数
+ *
+ * 把归一化标量 t ∈ [0, 1] 映射为颜色和不透明度，
+ * 以 RGBA8 查找表的形式上传为 N x 1 纹理，shader 中线性插值
+ */
+
+/**
+ * 传递函数控制点
+ */
+export interface TransferFunctionStop {
+  /** 归一化标量位置 [0, 1] */
+  position: number;
+  /** 颜色 [0, 1] */
+  color: [number, number, number];
+  /** 不透明度 [0, 1] */
+  opacity: number;
+}
+
+/** 默认查找表大小 */
+export const TRANSFER_FUNCTION_SIZE = 256;
+
+/**
+ * 默认传递函数：黑色透明 -> 白色不透明的线性渐变
+ */
+export const DEFAULT_TRANSFER_FUNCTION: TransferFunctionStop[] = [
+  { position: 0, color: [0, 0, 0], opacity: 0 },
+  { position: 1, color: [1, 1, 1], opacity: 1 },
+];
+
+/**
+ * 由控制点生成 RGBA8 查找表
+ * @param stops 控制点（无需有序），区间外取端点值
+ * @param size 查找表长度
+ */
+export function createTransferFunction(
+  stops: TransferFunctionStop[],
+  size: number = TRANSFER_FUNCTION_SIZE,
+): Uint8Array {
+  const sorted = [...stops].sort((a, b) => a.position - b.position);
+  const table = new Uint8Array(size * 4);
+  if (sorted.length === 0) {
+    return table;
+  }
+
+  const toByte = (v: number) => Math.round(Math.min(Math.max(v, 0), 1) * 255);
+
+  for (let i = 0; i < size; i++) {
+    const t = size > 1 ? i / (size - 1) : 0;
+
+    let upper = sorted.findIndex((stop) => stop.position >= t);
+    if (upper === -1) upper = sorted.length - 1;
+    const lower = Math.max(upper - 1, 0);
+    const a = sorted[lower];
+    const b = sorted[upper];
+    const span = b.position - a.position;
+    const f = span > 0 ? Math.min(Math.max((t - a.position) / span, 0), 1) : 1;
+
+    table[i * 4 + 0] = toByte(a.color[0] + (b.color[0] - a.color[0]) * f);
+    table[i * 4 + 1] = toByte(a.color[1] + (b.color[1] - a.color[1]) * f);
+    table[i * 4 + 2] = toByte(a.color[2] + (b.color[2] - a.color[2]) * f);
+    table[i * 4 + 3] = toByte(a.opacity + (b.opacity - a.opacity) * f);
+  }
+
+  return table;
+}
@@ -0,0 +1,58 @@
+/**
+ * TransformUniforms - splat 渲染使用的变换 uniform
+ *
+ * 相机相对渲染：在 CPU 端 (f64) 把相机位置从模型平移中减去，
+ * view 矩阵只保留旋转，GPU 上 view * model 的乘积与原来一致，
+ * 但不再出现 "大平移 - 大平移" 的 f32 相消，远离原点时顶点不再抖动
+ */
+
+import type { Camera } from "../core/Camera";
+
+/**
+ * 写入 uniform buffer 的变换
+ */
+export interface TransformUniforms {
+  /** view 矩阵（相机相对模式下不含平移） */
+  view: Float32Array;
+  /** model 矩阵（相机相对模式下平移减去了相机位置） */
+  model: Float32Array;
+  /** 相机位置（相机相对模式下为原点） */
+  cameraPos: Float32Array;
+}
+
+/**
+ * 计算变换 uniform
+ * @param cameraRelative 是否使用相机相对坐标
+ */
+export function computeTransformUniforms(
+  camera: Camera,
+  modelMatrix: Float32Array,
+  cameraRelative: boolean,
+): TransformUniforms {
+  if (!cameraRelative) {
+    return {
+      view: new Float32Array(camera.viewMatrix),
+      model: new Float32Array(modelMatrix),
+      cameraPos: new Float32Array(camera.position),
+    };
+  }
+
+  const view = new Float32Array(camera.viewMatrix);
+  view[12] = 0;
+  view[13] = 0;
+  view[14] = 0;
+
+  // 直接使用相机位置而不是 view 矩阵的平移，后者在远离原点时已经损失了精度
+  const [eyeX, eyeY, eyeZ] = camera.position;
+
+  const model = new Float32Array(modelMatrix);
+  model[12] = modelMatrix[12] - eyeX;
+  model[13] = modelMatrix[13] - eyeY;
+  model[14] = modelMatrix[14] - eyeZ;
+
+  return {
+    view,
+    model,
+    cameraPos: new Float32Array(3),
+  };
+}
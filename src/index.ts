@@ -16,9 +16,12 @@ export type {
   BoundingBoxProvider as IBoundingBoxProvider,
   MaterialData,
   RendererCapabilities,
+  SplatPickResult,
+  SplatDepthQueryMode,
+  SplatDepthResult,
 } from './types';
 
-export { SHMode, DEFAULT_MATERIAL, DEFAULT_OBJ_MATERIAL } from './types';
+export { SHMode, SplatVisMode, Colormap, SplatRasterMode, SplatBlendMode, SplatPointMode, SplatDepthKeyMode, DEFAULT_MATERIAL, DEFAULT_OBJ_MATERIAL } from './types';
 
 // ============================================
 // 工具函数
@@ -36,24 +39,98 @@ export {
   loadTextureFromBuffer,
   createTextureFromImageBitmap,
   TextureCache,
+  analyzeTransform,
+  orthonormalizeTransform,
+  validateTransform,
+  composeTransform,
+  multiplyTransforms,
+  MIN_TRANSFORM_SCALE,
+  SHEAR_TOLERANCE,
 } from './utils';
+export type { TransformIssue } from './utils';
+export { encodeEXR } from './utils';
+export type { EXRChannel } from './utils';
+export {
+  BINARY_FORMAT_HEADER_SIZE,
+  writeBinaryFormatHeader,
+  readBinaryFormatVersion,
+  migrateBinaryFormat,
+} from './utils';
+export type { BinaryFormat, BinaryFormatHeader, BinaryFormatMigration } from './utils';
+export { getCanvasPixelMapping, clientToCanvasPixels } from './utils';
+export { encodePNG, downloadBlob } from './utils';
+export type { CanvasPixelMapping } from './utils';
 
 // ============================================
 // Core
 // ============================================
-export { Renderer } from './core/Renderer';
+export { Renderer, detectIndirectDrawSupport } from './core/Renderer';
+export type { RendererOptions } from './core/Renderer';
+export { UploadArena } from './core/UploadArena';
+export { GPUResourceTracker } from './core/GPUResourceTracker';
+export type { GPUResourceCounts, GPUResourceLeak, GPUResourceSnapshot } from './core/GPUResourceTracker';
+export { UploadScheduler } from './core/UploadScheduler';
+export type { UploadSchedulerStats } from './core/UploadScheduler';
+export { TemporalJitter, halton } from './core/TemporalJitter';
+export { decodeFrameCapture, frameCaptureToRGBA8 } from './core/FrameCapture';
+export type { FrameCapture } from './core/FrameCapture';
+export { FramePreview } from './core/FramePreview';
+export { SplitViewComparison, DEFAULT_SPLIT_VIEW_COMPARISON_CONFIG } from './core/SplitViewComparison';
+export type { SplitViewComparisonConfig } from './core/SplitViewComparison';
+export {
+  SPLAT_PLATFORM_PRESETS,
+  detectSplatPlatform,
+  getSplatPlatformDefaults,
+  setSplatPlatformDefaults,
+  resetSplatPlatformDefaults,
+} from './core/PlatformDefaults';
+export type { SplatPlatform, SplatPlatformDefaults } from './core/PlatformDefaults';
+export { PipelineCache } from './core/PipelineCache';
 export { Camera } from './core/Camera';
-export { OrbitControls } from './core/OrbitControls';
+export {
+  DEFAULT_DEPTH_CONFIG,
+  resolveDepthConfig,
+  getDepthFormat,
+  getDepthClearValue,
+  getDepthCompare,
+  mapDepth,
+  getNearDepth,
+  writePerspective,
+  linearizeDepth,
+} from './core/DepthConvention';
+export type { DepthConfig } from './core/DepthConvention';
+export { OrbitControls, DEFAULT_ORBIT_CONTROLS_CONFIG } from './core/OrbitControls';
+export type { OrbitControlsConfig } from './core/OrbitControls';
+export { FlyControls, DEFAULT_FLY_CONTROLS_CONFIG } from './core/FlyControls';
+export type { FlyControlsConfig } from './core/FlyControls';
+export { CameraPath } from './core/CameraPath';
+export type { CameraKeyframe, CameraPathSample } from './core/CameraPath';
+export {
+  createPNGSequenceSink,
+  createDirectoryPNGSequenceSink,
+  getSequenceFileName,
+  DEFAULT_PNG_SEQUENCE_OPTIONS,
+} from './core/FrameSequenceExport';
+export type { FrameSequenceFrame, FrameSequenceSink, PNGSequenceOptions, WritableDirectory } from './core/FrameSequenceExport';
+export { RenderOnDemand, DEFAULT_RENDER_ON_DEMAND_CONFIG } from './core/RenderOnDemand';
+export type { RenderOnDemandConfig } from './core/RenderOnDemand';
+export { DynamicResolution, DEFAULT_DYNAMIC_RESOLUTION_CONFIG } from './core/DynamicResolution';
+export type { DynamicResolutionConfig } from './core/DynamicResolution';
 export { ViewportGizmo } from './core/ViewportGizmo';
 export { BoundingBoxRenderer } from './core/BoundingBoxRenderer';
 export type { BoundingBox as SelectionBoundingBox, BoundingBoxProvider } from './core/BoundingBoxRenderer';
+export { GridRenderer, DEFAULT_GRID_CONFIG } from './core/GridRenderer';
+export { CameraFrustumRenderer, DEFAULT_CAMERA_FRUSTUM_CONFIG } from './core/CameraFrustumRenderer';
+export type { CameraFrustumConfig } from './core/CameraFrustumRenderer';
+export type { GridConfig } from './core/GridRenderer';
 
 // ============================================
 // Mesh
 // ============================================
 export { Mesh } from './mesh/Mesh';
 export type { MeshBoundingBox } from './mesh/Mesh';
-export { MeshRenderer } from './mesh/MeshRenderer';
+export { MeshRenderer, DEFAULT_MESH_SHADOW_CONFIG } from './mesh/MeshRenderer';
+export type { MeshShadowConfig } from './mesh/MeshRenderer';
 
 // ============================================
 // Loaders
@@ -65,6 +142,17 @@ export { OBJParser } from './loaders/OBJParser';
 export type { ParsedOBJData, ParsedObject } from './loaders/OBJParser';
 export { MTLParser } from './loaders/MTLParser';
 export type { ParsedMaterial } from './loaders/MTLParser';
+export {
+  parseNerfTransforms,
+  parseColmapCamerasText,
+  parseColmapImagesText,
+  parseColmapCamerasBinary,
+  parseColmapImagesBinary,
+  createColmapTrainingCameras,
+  loadNerfTransforms,
+  loadColmapCameras,
+} from './loaders/CameraDatasetLoader';
+export type { TrainingCamera, ColmapCamera, ColmapImage } from './loaders/CameraDatasetLoader';
 
 // ============================================
 // 3D Gaussian Splatting - 接口
@@ -72,20 +160,167 @@ export type { ParsedMaterial } from './loaders/MTLParser';
 export type { 
   IGSSplatRenderer, 
   IGSSplatRendererWithCapabilities,
+  SplatEntityPickResult,
 } from './gs/IGSSplatRenderer';
 
 // ============================================
 // 3D Gaussian Splatting - 实现
 // ============================================
-export { loadPLY } from './gs/PLYLoader';
+export { loadPLY, EMISSIVE_PROPERTY_NAMES } from './gs/PLYLoader';
 export type { SplatCPU } from './gs/PLYLoader';
 export { loadPLYMobile, parsePLYBuffer, compactDataToGPUBuffer } from './gs/PLYLoaderMobile';
 export type { MobileLoadOptions, CompactSplatData } from './gs/PLYLoaderMobile';
+export { parsePLYBufferInWorker, terminatePLYParseWorker } from './gs/PLYParseWorkerClient';
 export { loadSplat, deserializeSplat } from './gs/SplatLoader';
-export { GSSplatRenderer, SHMode as GSSHMode } from './gs/GSSplatRenderer';
+export { SplatImportTransform, sampleByImportance } from './gs/SplatImport';
+export type { SplatImportOptions, SplatUpAxis } from './gs/SplatImport';
+export { GSSplatRenderer, SHMode as GSSHMode, DEFAULT_SPLAT_HOVER_STRENGTH } from './gs/GSSplatRenderer';
+export { SplatExternalPacker, validateExternalBuffer, validateExternalBuffers } from './gs/SplatExternalBuffers';
+export type { SplatExternalBuffer, SplatExternalBuffers } from './gs/SplatExternalBuffers';
+export { TrainingMetrics, DEFAULT_TRAINING_METRICS_HISTORY } from './gs/TrainingMetrics';
+export { TrainingSplatChannel } from './gs/TrainingSplatChannel';
+export type { TrainingSplatUpdate } from './gs/TrainingSplatChannel';
+export { RemoteTrainingClient, REMOTE_TRAINING_MESSAGE, DEFAULT_REMOTE_TRAINING_CLIENT_CONFIG, decodeRemoteSplats } from './net/RemoteTrainingClient';
+export type { RemoteTrainingHandlers, RemoteTrainingClientConfig, RemoteTrainingStatus } from './net/RemoteTrainingClient';
+export type { TrainingMetricsSample, TrainingMetricsEventType, TrainingMetricsCallback } from './gs/TrainingMetrics';
+export { GSSplatRendererGroup } from './gs/GSSplatRendererGroup';
+export { getMaxSplatsPerSegment, splitSplatData } from './gs/SplatSegments';
+export type { SplatSegment } from './gs/SplatSegments';
+export { SplatBufferPool, getBufferSizeClass } from './gs/SplatBufferPool';
+export { SplatPickTargets } from './gs/SplatPickTargets';
+export { SplatCompactor } from './gs/SplatCompactor';
+export { computeSplatStatistics, rebasePositions } from './gs/SplatStatistics';
+export type { SplatPrecision, SplatStatistics } from './gs/SplatStatistics';
+export { SplatMemoryBudget, SplatMemoryDowngrade, getBuffersByteSize } from './gs/SplatMemoryBudget';
+export type { SplatMemoryEntity, SplatMemoryReport } from './gs/SplatMemoryBudget';
 export type { BoundingBox as GSSplatBoundingBox } from './gs/GSSplatRenderer';
-export { GSSplatSorter } from './gs/GSSplatSorter';
+export { GSSplatSorter, supportsSubgroupSort } from './gs/GSSplatSorter';
+export { SplatLabels, LABEL_PROPERTY_NAMES } from './gs/SplatLabels';
+export { SplatSelection } from './gs/SplatSelection';
+export { GaussianSplats, SplatMut, SplatChunk, SPLAT_DIRTY_CHUNK_SIZE, cloneCompactSplatData } from './gs/GaussianSplats';
+export type { SplatRef, SplatRemoval } from './gs/GaussianSplats';
+export { SplatDirtyRanges } from './gs/SplatDirtyRanges';
+export {
+  registerSplats,
+  registerSplatEntities,
+  applySplatRegistration,
+  DEFAULT_SPLAT_REGISTRATION_CONFIG,
+} from './gs/SplatRegistration';
+export type { SplatRegistrationConfig, SplatRegistrationResult } from './gs/SplatRegistration';
+export {
+  bakeSplatTransform,
+  mergeSplatData,
+  partitionSplatData,
+  getSplatMergeItem,
+  splitSplatEntityData,
+} from './gs/SplatMerge';
+export type { SplatMergeItem, SplatSplitResult } from './gs/SplatMerge';
+export type { SelectionOp } from './gs/SplatSelection';
+export {
+  SplatEditHistory,
+  DEFAULT_SPLAT_EDIT_HISTORY_CONFIG,
+  captureSplatTransform,
+  applySplatTransform,
+} from './gs/SplatEditHistory';
+export type { SplatEditHistoryConfig, SplatEditCommand, SplatTransformState } from './gs/SplatEditHistory';
 export type { SorterOptions, CullingOptions, ScreenInfo } from './gs/GSSplatSorter';
+export { GSSplatTileRasterizer } from './gs/GSSplatTileRasterizer';
+export type { TileRasterizerOptions } from './gs/GSSplatTileRasterizer';
+export { computeTransformUniforms } from './gs/TransformUniforms';
+export { getSplatBlendState, isOrderIndependentBlend } from './gs/SplatBlend';
+export { buildSplatOctree, cullSplatOctree, collectVisibleLeaves, DEFAULT_SPLAT_CHUNK_SIZE } from './gs/SplatOctree';
+export { SplatLodSelector, DEFAULT_SPLAT_LOD_CONFIG } from './gs/SplatLod';
+export type { SplatLodConfig } from './gs/SplatLod';
+export { computePointSize, DEFAULT_SPLAT_POINT_SIZE_CONFIG, POINT_DENSITY_CELL_SIZE } from './gs/SplatPoints';
+export type { SplatPointSizeConfig } from './gs/SplatPoints';
+export { SplatCentersFilter, DEFAULT_SPLAT_CENTERS_CONFIG, needsCentersFilter } from './gs/SplatCentersFilter';
+export type { SplatCentersConfig, SplatCentersSelectionFilter } from './gs/SplatCentersFilter';
+export { SplatPassTracker } from './gs/SplatPassTracker';
+export type { SplatPassStage, SplatPassRecord } from './gs/SplatPassTracker';
+export { SplatSortCache } from './gs/SplatSortCache';
+export { SplatDiagnostics, SPLAT_DIAGNOSTICS } from './core/SplatDiagnostics';
+export type { SplatDiagnostic } from './core/SplatDiagnostics';
+export { SplatPVS, buildSplatPVS, DEFAULT_SPLAT_PVS_CONFIG, SPLAT_PVS_FORMAT, migratePVSv1ToV2 } from './gs/SplatPVS';
+export type { SplatPVSConfig } from './gs/SplatPVS';
+export type { SplatSortCacheStats } from './gs/SplatSortCache';
+export type { SplatOctree, SplatOctreeNode, SplatOctreeOptions, SplatNodeVisibility } from './gs/SplatOctree';
+export { createTransferFunction, DEFAULT_TRANSFER_FUNCTION, TRANSFER_FUNCTION_SIZE } from './gs/TransferFunction';
+export type { TransferFunctionStop } from './gs/TransferFunction';
+export {
+  SPLAT_VIS_MODE_CUSTOM_BASE,
+  registerSplatVisMode,
+  unregisterSplatVisMode,
+  findSplatVisMode,
+  getRegisteredSplatVisModes,
+  resolveSplatVisMode,
+} from './gs/SplatVisModes';
+export type { SplatVisModeDefinition, RegisteredSplatVisMode } from './gs/SplatVisModes';
+export { SplatPolygonSelector, MAX_SELECTION_POLYGON_POINTS, decodeSelectionMask } from './gs/SplatPolygonSelector';
+export { DEFAULT_SPLAT_SWAY_CONFIG, getSplatSwayUniforms } from './gs/SplatSway';
+export type { SplatSwayConfig } from './gs/SplatSway';
+export {
+  DEFAULT_SPLAT_COLOR_ADJUSTMENT,
+  adjustSplatColor,
+  isIdentityColorAdjustment,
+  getColorAdjustUniforms,
+} from './gs/SplatColorAdjust';
+export type { SplatColorAdjustment } from './gs/SplatColorAdjust';
+export {
+  SPLAT_APPEARANCE_STRIDE,
+  packSplatAppearance,
+  gatherSplatAppearance,
+  selectSplatAppearance,
+} from './gs/SplatAppearance';
+export type { SplatAppearanceData } from './gs/SplatAppearance';
+export {
+  DEFAULT_SPLAT_OPACITY_EDIT,
+  editSplatOpacity,
+  isIdentityOpacityEdit,
+  getOpacityEditUniforms,
+} from './gs/SplatOpacityEdit';
+export type { SplatOpacityEdit } from './gs/SplatOpacityEdit';
+export { SplatCropBox, DEFAULT_SPLAT_CROP_BOX_STATE, getCropBoxUniforms, splatCropBoxShader } from './gs/SplatCropBox';
+export type { SplatCropBoxState, SplatCropBoxHandle, SplatCropBoxHandleId } from './gs/SplatCropBox';
+export { findSplatFloaters, DEFAULT_SPLAT_FLOATER_CONFIG } from './gs/SplatFloaters';
+export type { SplatFloaterConfig } from './gs/SplatFloaters';
+export { SplatSpatialHash } from './gs/SplatSpatialHash';
+export {
+  SplatGroups,
+  MAX_SPLAT_GROUPS,
+  DEFAULT_SPLAT_GROUP_TINT_STRENGTH,
+  getSplatGroupUniforms,
+  getSplatGroupHiddenMask,
+  splatGroupsShader,
+} from './gs/SplatGroups';
+export type { SplatGroupInfo } from './gs/SplatGroups';
+export { mirrorSplatData } from './gs/SplatMirror';
+export type { SplatMirrorPlane } from './gs/SplatMirror';
+export {
+  SplatAttributeSelector,
+  getSplatAttribute,
+  matchesSplatAttributes,
+  MAX_SPLAT_ATTRIBUTE_PREDICATES,
+} from './gs/SplatAttributeFilter';
+export type { SplatAttribute, SplatAttributeComparison, SplatAttributePredicate } from './gs/SplatAttributeFilter';
+export {
+  SPLAT_FEATURE_EDITING,
+  SPLAT_FEATURE_SH,
+  SPLAT_FEATURE_SWAY,
+  SPLAT_FEATURE_APPEARANCE,
+  SPLAT_FEATURE_CUSTOM_VIS_MODES,
+  getSplatShaderFeatures,
+  getSplatShaderFeatureDefines,
+  parseDisabledSplatShaderFeatures,
+} from './gs/SplatShaderFeatures';
+export type { SplatShaderFeatures } from './gs/SplatShaderFeatures';
+export {
+  createSplatIdDepth,
+  createSplatRenderLayers,
+  encodeSplatRenderLayersEXR,
+  encodeSplatRenderLayerFiles,
+} from './gs/SplatRenderLayers';
+export type { SplatIdDepth, SplatRenderLayers, SplatRenderLayerFiles } from './gs/SplatRenderLayers';
+export type { TransformUniforms } from './gs/TransformUniforms';
 
 // ============================================
 // 3D Gaussian Splatting - 移动端
@@ -99,15 +334,27 @@ export type {
 export { 
   compressSplatsToTextures, 
   destroyCompressedTextures,
-  calculateTextureDimensions 
+  calculateTextureDimensions,
+  getBytesPerSplat,
+  DEFAULT_PACK_MODE_CONFIG,
+  POSITION_CHUNK_SIZE,
+  SH_TEXTURE_LAYERS,
+} from './gs/TextureCompressor';
+export type { 
+  CompressedSplatTextures,
+  PackModeConfig,
+  RotationEncoding,
+  PositionEncoding,
+  SHEncoding,
 } from './gs/TextureCompressor';
-export type { CompressedSplatTextures } from './gs/TextureCompressor';
 
 // ============================================
 // Scene Management
 // ============================================
 export { SceneManager } from './scene/SceneManager';
 export type { SceneObjectType, SceneObjectInfo } from './scene/SceneManager';
+export { SplatLayer } from './scene/SplatLayer';
+export type { SplatLayerEntry } from './scene/SplatLayer';
 
 // Scene Proxies
 export { 
@@ -125,12 +372,28 @@ export {
   MeshGroupProxy as GizmoMeshGroupProxy,
   SplatBoundingBoxProvider as GizmoSplatBoundingBoxProvider
 } from './interaction/GizmoManager';
+export { SplatPicking } from './interaction/SplatPicking';
+export type { SplatPointerEvent, SplatPointerEventType, SplatPointerCallback } from './interaction/SplatPicking';
+export { SplatHoverHighlight, DEFAULT_SPLAT_HOVER_HIGHLIGHT_CONFIG } from './interaction/SplatHoverHighlight';
+export type { SplatHoverHighlightConfig } from './interaction/SplatHoverHighlight';
+export { SplatBrushSelection, DEFAULT_SPLAT_BRUSH_CONFIG } from './interaction/SplatBrushSelection';
+export type { SplatBrushConfig, SplatBrushCommit } from './interaction/SplatBrushSelection';
+export { SplatLassoSelection, DEFAULT_SPLAT_LASSO_CONFIG } from './interaction/SplatLassoSelection';
+export type { SplatLassoConfig } from './interaction/SplatLassoSelection';
 
 // ============================================
 // App
 // ============================================
 export { App } from './App';
-export type { ProgressCallback } from './App';
+export type { ProgressCallback, OffscreenRenderView, SplatScreenshotRequest } from './App';
+export { SplatViewer, DEFAULT_SPLAT_VIEWER_OPTIONS } from './viewer/SplatViewer';
+export type {
+  SplatViewerOptions,
+  SplatViewerRenderingConfig,
+  SplatViewerCameraPose,
+  SplatViewerLoadedEvent,
+  SplatViewerLoadedCallback,
+} from './viewer/SplatViewer';
 
 // ============================================
 // Gizmo
@@ -5,6 +5,7 @@
  * - TransformGizmo（变换控制）
  * - ViewportGizmo（视口坐标轴）
  * - BoundingBoxRenderer（选中对象包围盒）
+ * - CameraFrustumRenderer（训练相机视锥）
  */
 
 import { Renderer } from "../core/Renderer";
@@ -13,6 +14,7 @@ import { OrbitControls } from "../core/OrbitControls";
 import { ViewportGizmo } from "../core/ViewportGizmo";
 import { TransformGizmoV2, TransformableObject, GizmoMode } from "../core/gizmo/TransformGizmoV2";
 import { BoundingBoxRenderer, BoundingBox as RendererBoundingBox, BoundingBoxProvider } from "../core/BoundingBoxRenderer";
+import { CameraFrustumRenderer } from "../core/CameraFrustumRenderer";
 import type { IGSSplatRenderer } from "../gs/IGSSplatRenderer";
 import type { Mesh } from "../mesh/Mesh";
 
@@ -38,6 +40,7 @@ export class GizmoManager {
   private viewportGizmo: ViewportGizmo;
   private transformGizmo: TransformGizmoV2;
   private boundingBoxRenderer: BoundingBoxRenderer;
+  private cameraFrustumRenderer: CameraFrustumRenderer;
 
   // 事件处理函数引用（用于移除监听器）
   private boundOnClick: (e: MouseEvent) => void;
@@ -61,6 +64,7 @@ export class GizmoManager {
     this.transformGizmo = new TransformGizmoV2({ renderer, camera, canvas });
     this.transformGizmo.init();
     this.boundingBoxRenderer = new BoundingBoxRenderer(renderer, camera);
+    this.cameraFrustumRenderer = new CameraFrustumRenderer(renderer, camera);
 
     // 设置 Gizmo 拖拽时禁用 OrbitControls
     this.transformGizmo.setOnDragStateChange((isDragging) => {
@@ -118,6 +122,8 @@ export class GizmoManager {
    * 渲染所有 Gizmo
    */
   render(pass: GPURenderPassEncoder): void {
+    // 渲染训练相机视锥
+    this.cameraFrustumRenderer.render(pass);
     // 渲染包围盒
     this.boundingBoxRenderer.render(pass);
     // 渲染变换 Gizmo
@@ -194,6 +200,17 @@ export class GizmoManager {
     this.boundingBoxRenderer.clear();
   }
 
+  // ============================================
+  // Camera Frustums
+  // ============================================
+
+  /**
+   * 获取训练相机视锥渲染器
+   */
+  getCameraFrustumRenderer(): CameraFrustumRenderer {
+    return this.cameraFrustumRenderer;
+  }
+
   // ============================================
   // 代理对象创建
   // ============================================
@@ -235,5 +252,6 @@ export class GizmoManager {
     // 销毁 Gizmo
     this.transformGizmo.destroy();
     this.boundingBoxRenderer.destroy();
+    this.cameraFrustumRenderer.destroy();
   }
 }
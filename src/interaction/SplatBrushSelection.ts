@@ -0,0 +1,257 @@
+/**
+ * SplatBrushSelection - 屏幕空间圆形画笔选择
+ *
+ * 按下并拖动时，在指针位置反复选择投影落在半径内的 splat，按 add / subtract 累积到选择集：
+ * - depthAware: 通过 GPU 拾取区域只选择可见的最前面的 splat
+ * - 否则按 splat 中心的 CPU 投影选择，包括被遮挡的 splat
+ * 一次按下到抬起为一笔，抬起时作为一步提交（可撤销）。按住 Alt 时使用相反的操作。
+ * 同一时间只有一次画笔计算在进行，期间到达的指针位置合并为最新一次，相邻两次之间按半径插值补点
+ */
+
+import type { IGSSplatRenderer } from "../gs/IGSSplatRenderer";
+import type { SplatSelection } from "../gs/SplatSelection";
+import { getCanvasPixelMapping } from "../utils/canvas";
+
+/**
+ * 画笔配置
+ */
+export interface SplatBrushConfig {
+  /** 半径（CSS 像素） */
+  radius: number;
+  /** 只选择可见的最前面的 splat（GPU 拾取），false 时按中心投影选择，包括被遮挡的 splat */
+  depthAware: boolean;
+  /** 选择操作 */
+  op: "add" | "subtract";
+  /** 触发画笔的鼠标按键 */
+  button: number;
+}
+
+export const DEFAULT_SPLAT_BRUSH_CONFIG: SplatBrushConfig = {
+  radius: 24,
+  depthAware: true,
+  op: "add",
+  button: 0,
+};
+
+/**
+ * 提交一笔的修改（由调用方记录到撤销历史）
+ */
+export type SplatBrushCommit = (edit: (selection: SplatSelection) => void, label: string) => void;
+
+// 一笔进行中的状态
+interface BrushStroke {
+  renderer: IGSSplatRenderer;
+  selection: SplatSelection;
+  op: "add" | "subtract";
+  // 本笔改变过选中状态的 splat
+  changed: Set<number>;
+  lastX: number;
+  lastY: number;
+}
+
+/**
+ * SplatBrushSelection - 画笔选择管理器
+ */
+export class SplatBrushSelection {
+  private canvas: HTMLCanvasElement;
+  private getRenderer: () => IGSSplatRenderer | null;
+  private commit: SplatBrushCommit;
+  private config: SplatBrushConfig = { ...DEFAULT_SPLAT_BRUSH_CONFIG };
+
+  /** 是否启用（默认关闭，避免与相机控制冲突） */
+  enabled: boolean = false;
+
+  private stroke: BrushStroke | null = null;
+  private pointerId: number = -1;
+
+  // 画笔计算节流：进行中时只保留最新的 pointermove
+  private painting: boolean = false;
+  private pendingMove: PointerEvent | null = null;
+
+  // 事件处理函数引用（用于移除监听器）
+  private boundOnPointerDown: (e: PointerEvent) => void;
+  private boundOnPointerMove: (e: PointerEvent) => void;
+  private boundOnPointerUp: (e: PointerEvent) => void;
+
+  constructor(canvas: HTMLCanvasElement, getRenderer: () => IGSSplatRenderer | null, commit: SplatBrushCommit) {
+    this.canvas = canvas;
+    this.getRenderer = getRenderer;
+    this.commit = commit;
+
+    this.boundOnPointerDown = this.onPointerDown.bind(this);
+    this.boundOnPointerMove = this.onPointerMove.bind(this);
+    this.boundOnPointerUp = this.onPointerUp.bind(this);
+
+    this.canvas.addEventListener("pointerdown", this.boundOnPointerDown);
+    this.canvas.addEventListener("pointermove", this.boundOnPointerMove);
+    this.canvas.addEventListener("pointerup", this.boundOnPointerUp);
+    this.canvas.addEventListener("pointercancel", this.boundOnPointerUp);
+  }
+
+  setConfig(config: Partial<SplatBrushConfig>): void {
+    this.config = { ...this.config, ...config };
+  }
+
+  getConfig(): SplatBrushConfig {
+    return { ...this.config };
+  }
+
+  /**
+   * 是否正在绘制一笔
+   */
+  isPainting(): boolean {
+    return this.stroke !== null;
+  }
+
+  /**
+   * 在 CSS 像素坐标（相对视口）处应用一次画笔，不记录撤销历史
+   * @returns 选中状态发生变化的数量
+   */
+  async paintAt(clientX: number, clientY: number, op: "add" | "subtract" = this.config.op): Promise<number> {
+    const renderer = this.getRenderer();
+    const selection = renderer?.getSelection?.();
+    if (!renderer || !selection) {
+      return 0;
+    }
+    const hits = await this.query(renderer, clientX, clientY);
+    return selection.selectIndices(hits, op);
+  }
+
+  // ============================================
+  // 指针事件
+  // ============================================
+
+  private onPointerDown(e: PointerEvent): void {
+    if (!this.enabled || this.stroke || e.button !== this.config.button) return;
+    const renderer = this.getRenderer();
+    const selection = renderer?.getSelection?.();
+    if (!renderer || !selection) return;
+
+    this.stroke = {
+      renderer,
+      selection,
+      op: e.altKey ? invertOp(this.config.op) : this.config.op,
+      changed: new Set(),
+      lastX: e.clientX,
+      lastY: e.clientY,
+    };
+    this.pointerId = e.pointerId;
+    this.canvas.setPointerCapture(e.pointerId);
+    e.preventDefault();
+    void this.paint(e, true);
+  }
+
+  private onPointerMove(e: PointerEvent): void {
+    if (!this.stroke || e.pointerId !== this.pointerId) return;
+    if (this.painting) {
+      this.pendingMove = e;
+      return;
+    }
+    void this.paint(e, false);
+  }
+
+  private async onPointerUp(e: PointerEvent): Promise<void> {
+    const stroke = this.stroke;
+    if (!stroke || e.pointerId !== this.pointerId) return;
+    if (this.canvas.hasPointerCapture(e.pointerId)) {
+      this.canvas.releasePointerCapture(e.pointerId);
+    }
+    this.pointerId = -1;
+    this.pendingMove = null;
+    // 等待进行中的画笔计算完成
+    while (this.painting) {
+      await new Promise((resolve) => requestAnimationFrame(resolve));
+    }
+    this.stroke = null;
+    this.finishStroke(stroke);
+  }
+
+  /**
+   * 从上一位置插值到当前位置，逐点应用画笔
+   */
+  private async paint(e: PointerEvent, first: boolean): Promise<void> {
+    const stroke = this.stroke!;
+    this.painting = true;
+    try {
+      const dx = e.clientX - stroke.lastX;
+      const dy = e.clientY - stroke.lastY;
+      const steps = first ? 1 : Math.max(1, Math.ceil(Math.hypot(dx, dy) / Math.max(1, this.config.radius)));
+      for (let step = 1; step <= steps; step++) {
+        const t = step / steps;
+        const hits = await this.query(stroke.renderer, stroke.lastX + dx * t, stroke.lastY + dy * t);
+        this.apply(stroke, hits);
+      }
+      stroke.lastX = e.clientX;
+      stroke.lastY = e.clientY;
+    } finally {
+      this.painting = false;
+    }
+
+    const pending = this.pendingMove;
+    this.pendingMove = null;
+    if (pending && this.stroke === stroke) {
+      void this.paint(pending, false);
+    }
+  }
+
+  /**
+   * 查询画笔范围内的 splat（CSS 像素转换为画布像素）
+   */
+  private async query(renderer: IGSSplatRenderer, clientX: number, clientY: number): Promise<ArrayLike<number>> {
+    const mapping = getCanvasPixelMapping(this.canvas);
+    if (!mapping) {
+      return [];
+    }
+    const x = (clientX - mapping.left) * mapping.scaleX;
+    const y = (clientY - mapping.top) * mapping.scaleY;
+    const radius = this.config.radius * mapping.scaleX;
+    if (this.config.depthAware && renderer.pickRegion) {
+      return renderer.pickRegion(x, y, radius);
+    }
+    return renderer.querySplatsInCircle?.(x, y, radius) ?? [];
+  }
+
+  private apply(stroke: BrushStroke, hits: ArrayLike<number>): void {
+    const mask = stroke.selection.getMask();
+    const value = stroke.op === "add" ? 1 : 0;
+    const changed: number[] = [];
+    for (let k = 0; k < hits.length; k++) {
+      const i = hits[k];
+      if (mask[i] !== value) changed.push(i);
+    }
+    if (changed.length === 0) return;
+    stroke.selection.selectIndices(changed, stroke.op);
+    for (const i of changed) {
+      stroke.changed.add(i);
+    }
+  }
+
+  /**
+   * 结束一笔：先恢复绘制前的状态，再通过 commit 作为一步重新应用
+   */
+  private finishStroke(stroke: BrushStroke): void {
+    // 绘制期间数据被替换（压缩 / 重新加载）时丢弃这一笔
+    if (stroke.changed.size === 0 || stroke.renderer.getSelection?.() !== stroke.selection) {
+      return;
+    }
+    const indices = Uint32Array.from(stroke.changed);
+    stroke.selection.selectIndices(indices, invertOp(stroke.op));
+    this.commit((selection) => selection.selectIndices(indices, stroke.op), "brush select");
+  }
+
+  /**
+   * 销毁
+   */
+  destroy(): void {
+    this.canvas.removeEventListener("pointerdown", this.boundOnPointerDown);
+    this.canvas.removeEventListener("pointermove", this.boundOnPointerMove);
+    this.canvas.removeEventListener("pointerup", this.boundOnPointerUp);
+    this.canvas.removeEventListener("pointercancel", this.boundOnPointerUp);
+    this.stroke = null;
+    this.pendingMove = null;
+  }
+}
+
+function invertOp(op: "add" | "subtract"): "add" | "subtract" {
+  return op === "add" ? "subtract" : "add";
+}
@@ -0,0 +1,163 @@
+/**
+ * SplatHoverHighlight - 悬停高亮
+ *
+ * 指针位于 canvas 上时按固定间隔拾取光标下的 splat，写入渲染器的悬停高亮（shader 中向白色混合），
+ * 可选高亮整个分组。按间隔而不是在 pointermove 中拾取，相机移动时光标下的 splat 变化也能更新，
+ * 同时限制拾取频率。同一时间只有一次拾取在进行，指针离开 canvas 或关闭时清除高亮
+ */
+
+import type { IGSSplatRenderer } from "../gs/IGSSplatRenderer";
+import { DEFAULT_SPLAT_HOVER_STRENGTH } from "../gs/GSSplatRenderer";
+import { clientToCanvasPixels } from "../utils/canvas";
+
+/**
+ * 悬停高亮配置
+ */
+export interface SplatHoverHighlightConfig {
+  /** 拾取间隔（毫秒） */
+  interval: number;
+  /** 高亮光标下 splat 所在的整个分组（未分组时只高亮该 splat） */
+  group: boolean;
+  /** 高亮强度（向白色混合的比例，0~1） */
+  strength: number;
+}
+
+export const DEFAULT_SPLAT_HOVER_HIGHLIGHT_CONFIG: SplatHoverHighlightConfig = {
+  interval: 100,
+  group: false,
+  strength: DEFAULT_SPLAT_HOVER_STRENGTH,
+};
+
+/**
+ * SplatHoverHighlight - 悬停高亮管理器
+ */
+export class SplatHoverHighlight {
+  private canvas: HTMLCanvasElement;
+  private getRenderer: () => IGSSplatRenderer | null;
+  private config: SplatHoverHighlightConfig = { ...DEFAULT_SPLAT_HOVER_HIGHLIGHT_CONFIG };
+
+  private _enabled: boolean = false;
+  private timer: ReturnType<typeof setInterval> | null = null;
+  private picking: boolean = false;
+
+  // 最近一次指针位置（CSS 像素，相对视口），null 表示指针不在 canvas 上
+  private pointer: { x: number; y: number; buttons: number } | null = null;
+  // 当前写入高亮的渲染器和 splat
+  private highlighted: { renderer: IGSSplatRenderer; index: number } | null = null;
+
+  // 事件处理函数引用（用于移除监听器）
+  private boundOnPointerMove: (e: PointerEvent) => void;
+  private boundOnPointerLeave: () => void;
+
+  constructor(canvas: HTMLCanvasElement, getRenderer: () => IGSSplatRenderer | null) {
+    this.canvas = canvas;
+    this.getRenderer = getRenderer;
+
+    this.boundOnPointerMove = this.onPointerMove.bind(this);
+    this.boundOnPointerLeave = this.onPointerLeave.bind(this);
+
+    this.canvas.addEventListener("pointermove", this.boundOnPointerMove);
+    this.canvas.addEventListener("pointerleave", this.boundOnPointerLeave);
+  }
+
+  /** 是否启用（默认关闭，启用后持续拾取） */
+  get enabled(): boolean {
+    return this._enabled;
+  }
+
+  set enabled(enabled: boolean) {
+    if (this._enabled === enabled) return;
+    this._enabled = enabled;
+    this.restartTimer();
+    if (!enabled) this.clear();
+  }
+
+  setConfig(config: Partial<SplatHoverHighlightConfig>): void {
+    const interval = this.config.interval;
+    this.config = { ...this.config, ...config };
+    if (this.config.interval !== interval) this.restartTimer();
+    if (this.highlighted) {
+      this.highlighted.renderer.setHoveredSplat?.(this.highlighted.index, this.config.group, this.config.strength);
+    }
+  }
+
+  getConfig(): SplatHoverHighlightConfig {
+    return { ...this.config };
+  }
+
+  /**
+   * 当前高亮的 splat 索引
+   */
+  getHovered(): number | null {
+    return this.highlighted?.index ?? null;
+  }
+
+  private restartTimer(): void {
+    if (this.timer !== null) {
+      clearInterval(this.timer);
+      this.timer = null;
+    }
+    if (this._enabled) {
+      this.timer = setInterval(() => void this.update(), Math.max(16, this.config.interval));
+    }
+  }
+
+  private onPointerMove(e: PointerEvent): void {
+    this.pointer = { x: e.clientX, y: e.clientY, buttons: e.buttons };
+  }
+
+  private onPointerLeave(): void {
+    this.pointer = null;
+    this.clear();
+  }
+
+  /**
+   * 拾取光标下的 splat 并更新高亮
+   */
+  private async update(): Promise<void> {
+    const pointer = this.pointer;
+    // 按住按键时（拖动相机 / 编辑）不更新，避免与其它交互争用拾取
+    if (!this._enabled || this.picking || !pointer || pointer.buttons !== 0) return;
+    const renderer = this.getRenderer();
+    if (!renderer?.pick || !renderer.setHoveredSplat) {
+      this.clear();
+      return;
+    }
+    const pixel = clientToCanvasPixels(this.canvas, pointer.x, pointer.y);
+    if (!pixel) return;
+
+    this.picking = true;
+    try {
+      const hit = await renderer.pick(pixel[0], pixel[1]);
+      // 拾取期间关闭、离开 canvas 或切换了渲染器
+      if (!this._enabled || !this.pointer || this.getRenderer() !== renderer) return;
+      if (!hit) {
+        this.clear();
+        return;
+      }
+      if (this.highlighted?.renderer !== renderer || this.highlighted.index !== hit.index) {
+        this.clear();
+        renderer.setHoveredSplat(hit.index, this.config.group, this.config.strength);
+        this.highlighted = { renderer, index: hit.index };
+      }
+    } finally {
+      this.picking = false;
+    }
+  }
+
+  private clear(): void {
+    this.highlighted?.renderer.setHoveredSplat?.(null);
+    this.highlighted = null;
+  }
+
+  /**
+   * 销毁
+   */
+  destroy(): void {
+    this._enabled = false;
+    this.restartTimer();
+    this.clear();
+    this.canvas.removeEventListener("pointermove", this.boundOnPointerMove);
+    this.canvas.removeEventListener("pointerleave", this.boundOnPointerLeave);
+  }
+}
@@ -0,0 +1,148 @@
+/**
+ * SplatLassoSelection - 套索（多边形）选择
+ *
+ * 按下并拖动记录屏幕路径，抬起时闭合为多边形，选择中心投影落在其中的 splat（GPU 测试，包括被遮挡的）。
+ * 默认替换当前选择，按住 Shift 加入、按住 Alt 移除；每次套索作为一步提交（可撤销）。
+ * 绘制中的路径通过 getPath() 获取，由宿主应用自行绘制叠加层
+ */
+
+import type { IGSSplatRenderer } from "../gs/IGSSplatRenderer";
+import type { SelectionOp } from "../gs/SplatSelection";
+import type { SplatBrushCommit } from "./SplatBrushSelection";
+import { getCanvasPixelMapping } from "../utils/canvas";
+
+/**
+ * 套索配置
+ */
+export interface SplatLassoConfig {
+  /** 相邻路径点的最小间距（CSS 像素） */
+  minSpacing: number;
+  /** 触发套索的鼠标按键 */
+  button: number;
+}
+
+export const DEFAULT_SPLAT_LASSO_CONFIG: SplatLassoConfig = {
+  minSpacing: 4,
+  button: 0,
+};
+
+/**
+ * SplatLassoSelection - 套索选择管理器
+ */
+export class SplatLassoSelection {
+  private canvas: HTMLCanvasElement;
+  private getRenderer: () => IGSSplatRenderer | null;
+  private commit: SplatBrushCommit;
+  private config: SplatLassoConfig = { ...DEFAULT_SPLAT_LASSO_CONFIG };
+
+  /** 是否启用（默认关闭，避免与相机控制冲突） */
+  enabled: boolean = false;
+
+  // 绘制中的路径（CSS 像素，相对视口）
+  private path: [number, number][] = [];
+  private op: SelectionOp = "set";
+  private pointerId: number = -1;
+
+  // 事件处理函数引用（用于移除监听器）
+  private boundOnPointerDown: (e: PointerEvent) => void;
+  private boundOnPointerMove: (e: PointerEvent) => void;
+  private boundOnPointerUp: (e: PointerEvent) => void;
+
+  constructor(canvas: HTMLCanvasElement, getRenderer: () => IGSSplatRenderer | null, commit: SplatBrushCommit) {
+    this.canvas = canvas;
+    this.getRenderer = getRenderer;
+    this.commit = commit;
+
+    this.boundOnPointerDown = this.onPointerDown.bind(this);
+    this.boundOnPointerMove = this.onPointerMove.bind(this);
+    this.boundOnPointerUp = this.onPointerUp.bind(this);
+
+    this.canvas.addEventListener("pointerdown", this.boundOnPointerDown);
+    this.canvas.addEventListener("pointermove", this.boundOnPointerMove);
+    this.canvas.addEventListener("pointerup", this.boundOnPointerUp);
+    this.canvas.addEventListener("pointercancel", this.boundOnPointerUp);
+  }
+
+  setConfig(config: Partial<SplatLassoConfig>): void {
+    this.config = { ...this.config, ...config };
+  }
+
+  getConfig(): SplatLassoConfig {
+    return { ...this.config };
+  }
+
+  /**
+   * 绘制中的路径（CSS 像素，相对视口），没有在绘制时为空
+   */
+  getPath(): ReadonlyArray<readonly [number, number]> {
+    return this.path;
+  }
+
+  /**
+   * 用 CSS 像素多边形（相对视口）选择，不记录撤销历史
+   * @returns 命中的 splat 索引
+   */
+  async queryPolygon(points: ReadonlyArray<readonly [number, number]>): Promise<Uint32Array> {
+    const renderer = this.getRenderer();
+    const mapping = getCanvasPixelMapping(this.canvas);
+    if (!renderer?.queryPolygon || !mapping) {
+      return new Uint32Array(0);
+    }
+    const { left, top, scaleX, scaleY } = mapping;
+    return renderer.queryPolygon(points.map(([x, y]) => [(x - left) * scaleX, (y - top) * scaleY] as const));
+  }
+
+  // ============================================
+  // 指针事件
+  // ============================================
+
+  private onPointerDown(e: PointerEvent): void {
+    if (!this.enabled || this.pointerId !== -1 || e.button !== this.config.button) return;
+    if (!this.getRenderer()?.getSelection?.()) return;
+
+    this.op = e.shiftKey ? "add" : e.altKey ? "subtract" : "set";
+    this.path = [[e.clientX, e.clientY]];
+    this.pointerId = e.pointerId;
+    this.canvas.setPointerCapture(e.pointerId);
+    e.preventDefault();
+  }
+
+  private onPointerMove(e: PointerEvent): void {
+    if (e.pointerId !== this.pointerId) return;
+    const [lastX, lastY] = this.path[this.path.length - 1];
+    if (Math.hypot(e.clientX - lastX, e.clientY - lastY) >= this.config.minSpacing) {
+      this.path.push([e.clientX, e.clientY]);
+    }
+  }
+
+  private async onPointerUp(e: PointerEvent): Promise<void> {
+    if (e.pointerId !== this.pointerId) return;
+    if (this.canvas.hasPointerCapture(e.pointerId)) {
+      this.canvas.releasePointerCapture(e.pointerId);
+    }
+    this.pointerId = -1;
+    const path = this.path;
+    const op = this.op;
+    this.path = [];
+    if (path.length < 3) return;
+
+    const renderer = this.getRenderer();
+    const selection = renderer?.getSelection?.();
+    const hits = await this.queryPolygon(path);
+    // 查询期间数据被替换（压缩 / 重新加载）时放弃
+    if (!selection || renderer?.getSelection?.() !== selection) return;
+    this.commit((s) => s.selectIndices(hits, op), "lasso select");
+  }
+
+  /**
+   * 销毁
+   */
+  destroy(): void {
+    this.canvas.removeEventListener("pointerdown", this.boundOnPointerDown);
+    this.canvas.removeEventListener("pointermove", this.boundOnPointerMove);
+    this.canvas.removeEventListener("pointerup", this.boundOnPointerUp);
+    this.canvas.removeEventListener("pointercancel", this.boundOnPointerUp);
+    this.path = [];
+    this.pointerId = -1;
+  }
+}
@@ -0,0 +1,241 @@
+/**
+ * SplatPicking - Splat 指针事件
+ *
+ * 在 canvas 指针事件上运行 GPU 拾取，把结果转换为 splat 级别的事件：
+ * - over / out：指针进入 / 离开某个 splat
+ * - click：在 splat 上按下并抬起（未拖拽）
+ * - dragstart / drag / dragend：在 splat 上按下后拖动
+ *
+ * 同一时间只有一次拾取在进行，pointermove 期间到达的新位置会合并为最新一次
+ */
+
+import type { IGSSplatRenderer } from "../gs/IGSSplatRenderer";
+import type { SplatPickResult } from "../types";
+import { clientToCanvasPixels } from "../utils/canvas";
+
+/**
+ * Splat 指针事件类型
+ */
+export type SplatPointerEventType = "over" | "out" | "click" | "dragstart" | "drag" | "dragend";
+
+/**
+ * Splat 指针事件
+ */
+export interface SplatPointerEvent {
+  type: SplatPointerEventType;
+  /** splat 索引 */
+  index: number;
+  /** 命中点世界坐标（拖拽事件中为按下时的命中点） */
+  worldPosition: [number, number, number];
+  /** 原始指针事件 */
+  originalEvent: PointerEvent;
+}
+
+export type SplatPointerCallback = (event: SplatPointerEvent) => void;
+
+// 按下后移动超过该距离（CSS 像素）视为拖拽
+const DRAG_THRESHOLD = 4;
+
+/**
+ * SplatPicking - Splat 指针事件管理器
+ */
+export class SplatPicking {
+  private canvas: HTMLCanvasElement;
+  private getRenderer: () => IGSSplatRenderer | null;
+
+  /** 是否启用 */
+  enabled: boolean = true;
+
+  private listeners: Map<SplatPointerEventType, Set<SplatPointerCallback>> = new Map();
+
+  // 当前悬停的 splat
+  private hovered: SplatPickResult | null = null;
+
+  // 按下状态
+  private pressed: SplatPickResult | null = null;
+  private pressPick: Promise<SplatPickResult | null> | null = null;
+  private pressX: number = 0;
+  private pressY: number = 0;
+  private dragging: boolean = false;
+
+  // 拾取节流：进行中时只保留最新的 pointermove
+  private picking: boolean = false;
+  private pendingMove: PointerEvent | null = null;
+
+  // 事件处理函数引用（用于移除监听器）
+  private boundOnPointerMove: (e: PointerEvent) => void;
+  private boundOnPointerDown: (e: PointerEvent) => void;
+  private boundOnPointerUp: (e: PointerEvent) => void;
+  private boundOnPointerLeave: (e: PointerEvent) => void;
+
+  constructor(canvas: HTMLCanvasElement, getRenderer: () => IGSSplatRenderer | null) {
+    this.canvas = canvas;
+    this.getRenderer = getRenderer;
+
+    this.boundOnPointerMove = this.onPointerMove.bind(this);
+    this.boundOnPointerDown = this.onPointerDown.bind(this);
+    this.boundOnPointerUp = this.onPointerUp.bind(this);
+    this.boundOnPointerLeave = this.onPointerLeave.bind(this);
+
+    this.canvas.addEventListener("pointermove", this.boundOnPointerMove);
+    this.canvas.addEventListener("pointerdown", this.boundOnPointerDown);
+    this.canvas.addEventListener("pointerup", this.boundOnPointerUp);
+    this.canvas.addEventListener("pointerleave", this.boundOnPointerLeave);
+  }
+
+  /**
+   * 监听事件
+   * @returns 取消监听函数
+   */
+  on(type: SplatPointerEventType, callback: SplatPointerCallback): () => void {
+    let set = this.listeners.get(type);
+    if (!set) {
+      set = new Set();
+      this.listeners.set(type, set);
+    }
+    set.add(callback);
+    return () => this.off(type, callback);
+  }
+
+  /**
+   * 取消监听
+   */
+  off(type: SplatPointerEventType, callback: SplatPointerCallback): void {
+    this.listeners.get(type)?.delete(callback);
+  }
+
+  /**
+   * 拾取 CSS 像素坐标（相对视口）下的 splat，坐标在画布内容区域外时返回 null
+   */
+  async pickAt(clientX: number, clientY: number): Promise<SplatPickResult | null> {
+    const renderer = this.getRenderer();
+    const pixel = clientToCanvasPixels(this.canvas, clientX, clientY);
+    if (!renderer?.pick || !pixel) {
+      return null;
+    }
+    return renderer.pick(pixel[0], pixel[1]);
+  }
+
+  // ============================================
+  // 指针事件
+  // ============================================
+
+  private onPointerMove(e: PointerEvent): void {
+    if (!this.enabled) return;
+
+    // 按下期间不更新悬停
+    if (this.pressPick) {
+      if (!this.pressed) return;
+      const dx = e.clientX - this.pressX;
+      const dy = e.clientY - this.pressY;
+      if (!this.dragging && Math.hypot(dx, dy) > DRAG_THRESHOLD) {
+        this.dragging = true;
+        this.emit("dragstart", this.pressed, e);
+      }
+      if (this.dragging) {
+        this.emit("drag", this.pressed, e);
+      }
+      return;
+    }
+
+    if (this.picking) {
+      this.pendingMove = e;
+      return;
+    }
+    void this.updateHover(e);
+  }
+
+  private async onPointerDown(e: PointerEvent): Promise<void> {
+    if (!this.enabled || e.button !== 0) return;
+
+    this.pressX = e.clientX;
+    this.pressY = e.clientY;
+    this.dragging = false;
+    this.pressed = null;
+    const pressPick = this.pickAt(e.clientX, e.clientY);
+    this.pressPick = pressPick;
+    const hit = await pressPick;
+    if (this.pressPick === pressPick) {
+      this.pressed = hit;
+    }
+  }
+
+  private async onPointerUp(e: PointerEvent): Promise<void> {
+    const pressPick = this.pressPick;
+    if (!this.enabled || !pressPick) return;
+
+    // 按下时的拾取可能还未完成
+    const pressed = await pressPick;
+    if (this.pressPick !== pressPick) return;
+    this.pressPick = null;
+    this.pressed = null;
+    if (!pressed) return;
+
+    if (this.dragging) {
+      this.dragging = false;
+      this.emit("dragend", pressed, e);
+    } else {
+      this.emit("click", pressed, e);
+    }
+  }
+
+  private onPointerLeave(e: PointerEvent): void {
+    if (this.hovered && !this.pressPick) {
+      const previous = this.hovered;
+      this.hovered = null;
+      this.emit("out", previous, e);
+    }
+  }
+
+  /**
+   * 拾取并更新悬停状态
+   */
+  private async updateHover(e: PointerEvent): Promise<void> {
+    this.picking = true;
+    try {
+      const hit = await this.pickAt(e.clientX, e.clientY);
+      const previous = this.hovered;
+      if (previous?.index !== hit?.index) {
+        if (previous) this.emit("out", previous, e);
+        if (hit) this.emit("over", hit, e);
+      }
+      this.hovered = hit;
+    } finally {
+      this.picking = false;
+    }
+
+    const pending = this.pendingMove;
+    this.pendingMove = null;
+    if (pending && this.enabled && !this.pressPick) {
+      void this.updateHover(pending);
+    }
+  }
+
+  private emit(type: SplatPointerEventType, hit: SplatPickResult, originalEvent: PointerEvent): void {
+    const set = this.listeners.get(type);
+    if (!set || set.size === 0) return;
+    const event: SplatPointerEvent = {
+      type,
+      index: hit.index,
+      worldPosition: hit.worldPosition,
+      originalEvent,
+    };
+    for (const callback of set) {
+      callback(event);
+    }
+  }
+
+  /**
+   * 销毁
+   */
+  destroy(): void {
+    this.canvas.removeEventListener("pointermove", this.boundOnPointerMove);
+    this.canvas.removeEventListener("pointerdown", this.boundOnPointerDown);
+    this.canvas.removeEventListener("pointerup", this.boundOnPointerUp);
+    this.canvas.removeEventListener("pointerleave", this.boundOnPointerLeave);
+    this.listeners.clear();
+    this.hovered = null;
+    this.pressed = null;
+    this.pressPick = null;
+  }
+}
@@ -0,0 +1,353 @@
+/**
+ * CameraDatasetLoader - 训练相机数据集加载
+ *
+ * 解析 NeRF / Blender 格式的 transforms.json 和 COLMAP 的 cameras / images 文件（文本或二进制），
+ * 统一转换为相机到世界的变换（OpenGL 约定：相机看向 -Z，+Y 向上）和视场角，
+ * 用于在训练前 / 训练中检查数据集的相机分布
+ */
+
+import type { Vec3Tuple } from "../types";
+
+/**
+ * 训练相机
+ */
+export interface TrainingCamera {
+  /** 图像名称（COLMAP 的 NAME 或 transforms.json 的 file_path） */
+  name: string;
+  /** 相机位置（世界空间） */
+  position: Vec3Tuple;
+  /** 相机到世界的变换（列主序 4x4，OpenGL 约定） */
+  cameraToWorld: Float32Array;
+  /** 垂直视场角（弧度） */
+  fovY: number;
+  /** 宽高比 */
+  aspect: number;
+  /** 图像尺寸（像素），数据集中没有时为 0 */
+  width: number;
+  height: number;
+}
+
+/**
+ * COLMAP 相机内参
+ */
+export interface ColmapCamera {
+  id: number;
+  model: string;
+  width: number;
+  height: number;
+  params: number[];
+}
+
+/**
+ * COLMAP 图像位姿（世界到相机，OpenCV 约定）
+ */
+export interface ColmapImage {
+  id: number;
+  /** 四元数 (w, x, y, z) */
+  qvec: [number, number, number, number];
+  tvec: Vec3Tuple;
+  cameraId: number;
+  name: string;
+}
+
+// 二进制格式的模型编号和参数数量
+const COLMAP_MODELS: Array<[string, number]> = [
+  ["SIMPLE_PINHOLE", 3],
+  ["PINHOLE", 4],
+  ["SIMPLE_RADIAL", 4],
+  ["RADIAL", 5],
+  ["OPENCV", 8],
+  ["OPENCV_FISHEYE", 8],
+  ["FULL_OPENCV", 12],
+  ["FOV", 5],
+  ["SIMPLE_RADIAL_FISHEYE", 4],
+  ["RADIAL_FISHEYE", 5],
+  ["THIN_PRISM_FISHEYE", 12],
+];
+
+// 只有一个焦距参数的模型（其余模型以 fx, fy 开头）
+const SINGLE_FOCAL_MODELS = new Set(["SIMPLE_PINHOLE", "SIMPLE_RADIAL", "RADIAL", "SIMPLE_RADIAL_FISHEYE", "RADIAL_FISHEYE"]);
+
+// ============================================
+// NeRF / Blender transforms.json
+// ============================================
+
+interface NerfIntrinsics {
+  camera_angle_x?: number;
+  camera_angle_y?: number;
+  fl_x?: number;
+  fl_y?: number;
+  w?: number;
+  h?: number;
+}
+
+interface NerfFrame extends NerfIntrinsics {
+  file_path?: string;
+  transform_matrix: number[][];
+}
+
+/**
+ * 解析 transforms.json（对象或 JSON 文本）
+ * transform_matrix 为行主序的相机到世界矩阵，已经是 OpenGL 约定；
+ * 内参优先使用帧上的字段（instant-ngp 的逐帧内参），其次是顶层字段
+ */
+export function parseNerfTransforms(source: string | object): TrainingCamera[] {
+  const root = (typeof source === "string" ? JSON.parse(source) : source) as NerfIntrinsics & { frames?: NerfFrame[] };
+  if (!Array.isArray(root.frames)) {
+    throw new Error("[CameraDatasetLoader] transforms.json 中没有 frames");
+  }
+
+  return root.frames.map((frame, i) => {
+    const intrinsics: NerfIntrinsics = { ...root, ...frame };
+    const width = intrinsics.w ?? 0;
+    const height = intrinsics.h ?? 0;
+
+    // 焦距（像素）和图像尺寸都有时直接计算，否则使用视场角
+    let tanX: number;
+    let tanY: number;
+    if (intrinsics.fl_x !== undefined && width > 0 && height > 0) {
+      tanX = width / (2 * intrinsics.fl_x);
+      tanY = height / (2 * (intrinsics.fl_y ?? intrinsics.fl_x));
+    } else if (intrinsics.camera_angle_x !== undefined) {
+      tanX = Math.tan(intrinsics.camera_angle_x / 2);
+      tanY = intrinsics.camera_angle_y !== undefined
+        ? Math.tan(intrinsics.camera_angle_y / 2)
+        : tanX * (width > 0 && height > 0 ? height / width : 1);
+    } else {
+      throw new Error(`[CameraDatasetLoader] 第 ${i} 帧缺少内参（camera_angle_x 或 fl_x / w / h）`);
+    }
+
+    const m = frame.transform_matrix;
+    const cameraToWorld = new Float32Array(16);
+    for (let row = 0; row < 4; row++) {
+      for (let col = 0; col < 4; col++) {
+        cameraToWorld[col * 4 + row] = m[row]?.[col] ?? (row === col ? 1 : 0);
+      }
+    }
+
+    return {
+      name: frame.file_path ?? `frame_${i}`,
+      position: [cameraToWorld[12], cameraToWorld[13], cameraToWorld[14]],
+      cameraToWorld,
+      fovY: 2 * Math.atan(tanY),
+      aspect: tanX / tanY,
+      width,
+      height,
+    };
+  });
+}
+
+// ============================================
+// COLMAP
+// ============================================
+
+/**
+ * 解析 COLMAP cameras.txt
+ */
+export function parseColmapCamerasText(text: string): ColmapCamera[] {
+  const cameras: ColmapCamera[] = [];
+  for (const line of text.split(/\r?\n/)) {
+    const trimmed = line.trim();
+    if (!trimmed || trimmed.startsWith("#")) continue;
+    const parts = trimmed.split(/\s+/);
+    cameras.push({
+      id: parseInt(parts[0], 10),
+      model: parts[1],
+      width: parseInt(parts[2], 10),
+      height: parseInt(parts[3], 10),
+      params: parts.slice(4).map(Number),
+    });
+  }
+  return cameras;
+}
+
+/**
+ * 解析 COLMAP images.txt（每个图像两行，第二行的 2D 点被忽略）
+ */
+export function parseColmapImagesText(text: string): ColmapImage[] {
+  const images: ColmapImage[] = [];
+  const lines = text.split(/\r?\n/);
+  for (let i = 0; i < lines.length; i++) {
+    const trimmed = lines[i].trim();
+    if (!trimmed || trimmed.startsWith("#")) continue;
+    const parts = trimmed.split(/\s+/);
+    // 名称可能包含空格
+    images.push({
+      id: parseInt(parts[0], 10),
+      qvec: [Number(parts[1]), Number(parts[2]), Number(parts[3]), Number(parts[4])],
+      tvec: [Number(parts[5]), Number(parts[6]), Number(parts[7])],
+      cameraId: parseInt(parts[8], 10),
+      name: parts.slice(9).join(" "),
+    });
+    // 跳过 2D 点行（可能为空行）
+    i++;
+  }
+  return images;
+}
+
+/**
+ * 解析 COLMAP cameras.bin
+ */
+export function parseColmapCamerasBinary(buffer: ArrayBuffer): ColmapCamera[] {
+  const view = new DataView(buffer);
+  let offset = 0;
+  const count = Number(view.getBigUint64(offset, true));
+  offset += 8;
+  const cameras: ColmapCamera[] = [];
+  for (let i = 0; i < count; i++) {
+    const id = view.getInt32(offset, true);
+    const modelId = view.getInt32(offset + 4, true);
+    const width = Number(view.getBigUint64(offset + 8, true));
+    const height = Number(view.getBigUint64(offset + 16, true));
+    offset += 24;
+    const model = COLMAP_MODELS[modelId];
+    if (!model) {
+      throw new Error(`[CameraDatasetLoader] 未知的 COLMAP 相机模型编号: ${modelId}`);
+    }
+    const params: number[] = [];
+    for (let k = 0; k < model[1]; k++) {
+      params.push(view.getFloat64(offset, true));
+      offset += 8;
+    }
+    cameras.push({ id, model: model[0], width, height, params });
+  }
+  return cameras;
+}
+
+/**
+ * 解析 COLMAP images.bin
+ */
+export function parseColmapImagesBinary(buffer: ArrayBuffer): ColmapImage[] {
+  const view = new DataView(buffer);
+  const bytes = new Uint8Array(buffer);
+  const decoder = new TextDecoder();
+  let offset = 0;
+  const count = Number(view.getBigUint64(offset, true));
+  offset += 8;
+  const images: ColmapImage[] = [];
+  for (let i = 0; i < count; i++) {
+    const id = view.getInt32(offset, true);
+    offset += 4;
+    const q: number[] = [];
+    for (let k = 0; k < 7; k++) {
+      q.push(view.getFloat64(offset, true));
+      offset += 8;
+    }
+    const cameraId = view.getInt32(offset, true);
+    offset += 4;
+    const nameEnd = bytes.indexOf(0, offset);
+    if (nameEnd === -1) {
+      throw new Error("[CameraDatasetLoader] images.bin 已截断");
+    }
+    const name = decoder.decode(bytes.subarray(offset, nameEnd));
+    offset = nameEnd + 1;
+    // 2D 点：x, y (f64) + point3D_id (i64)
+    const points = Number(view.getBigUint64(offset, true));
+    offset += 8 + points * 24;
+    images.push({
+      id,
+      qvec: [q[0], q[1], q[2], q[3]],
+      tvec: [q[4], q[5], q[6]],
+      cameraId,
+      name,
+    });
+  }
+  return images;
+}
+
+/**
+ * 由 COLMAP 内参和位姿生成训练相机（按图像名称排序）
+ * 位姿为世界到相机（OpenCV 约定：看向 +Z，+Y 向下），转换为相机到世界并翻转 Y / Z 轴
+ */
+export function createColmapTrainingCameras(cameras: ColmapCamera[], images: ColmapImage[]): TrainingCamera[] {
+  const byId = new Map(cameras.map((camera) => [camera.id, camera]));
+  const result: TrainingCamera[] = [];
+
+  for (const image of [...images].sort((a, b) => a.name.localeCompare(b.name))) {
+    const camera = byId.get(image.cameraId);
+    if (!camera) {
+      throw new Error(`[CameraDatasetLoader] 图像 ${image.name} 引用了不存在的相机 ${image.cameraId}`);
+    }
+    const fx = camera.params[0];
+    const fy = SINGLE_FOCAL_MODELS.has(camera.model) ? fx : camera.params[1];
+
+    // 世界到相机的旋转 R（行主序）
+    const [w, x, y, z] = image.qvec;
+    const r = [
+      1 - 2 * (y * y + z * z), 2 * (x * y - w * z), 2 * (x * z + w * y),
+      2 * (x * y + w * z), 1 - 2 * (x * x + z * z), 2 * (y * z - w * x),
+      2 * (x * z - w * y), 2 * (y * z + w * x), 1 - 2 * (x * x + y * y),
+    ];
+    const [tx, ty, tz] = image.tvec;
+    // 相机中心 C = -R^T t
+    const position: Vec3Tuple = [
+      -(r[0] * tx + r[3] * ty + r[6] * tz),
+      -(r[1] * tx + r[4] * ty + r[7] * tz),
+      -(r[2] * tx + r[5] * ty + r[8] * tz),
+    ];
+
+    // 相机到世界的旋转为 R^T，其列是 R 的行；第二、三列取反转换为 OpenGL 约定
+    const cameraToWorld = new Float32Array([
+      r[0], r[1], r[2], 0,
+      -r[3], -r[4], -r[5], 0,
+      -r[6], -r[7], -r[8], 0,
+      position[0], position[1], position[2], 1,
+    ]);
+
+    result.push({
+      name: image.name,
+      position,
+      cameraToWorld,
+      fovY: 2 * Math.atan(camera.height / (2 * fy)),
+      aspect: (camera.width / fx) / (camera.height / fy),
+      width: camera.width,
+      height: camera.height,
+    });
+  }
+  return result;
+}
+
+// ============================================
+// 加载
+// ============================================
+
+async function fetchOrRead(source: string | Blob, binary: true): Promise<ArrayBuffer>;
+async function fetchOrRead(source: string | Blob, binary: false): Promise<string>;
+async function fetchOrRead(source: string | Blob, binary: boolean): Promise<ArrayBuffer | string> {
+  if (typeof source !== "string") {
+    return binary ? source.arrayBuffer() : source.text();
+  }
+  const response = await fetch(source);
+  if (!response.ok) {
+    throw new Error(`无法加载相机文件: ${source} (HTTP ${response.status})`);
+  }
+  return binary ? response.arrayBuffer() : response.text();
+}
+
+// URL 或文件名以 .bin 结尾时按二进制解析
+function isBinarySource(source: string | Blob): boolean {
+  const name = typeof source === "string" ? source.split(/[?#]/)[0] : (source as File).name ?? "";
+  return name.toLowerCase().endsWith(".bin");
+}
+
+/**
+ * 加载 transforms.json（URL 或文件）
+ */
+export async function loadNerfTransforms(source: string | Blob): Promise<TrainingCamera[]> {
+  return parseNerfTransforms(await fetchOrRead(source, false));
+}
+
+/**
+ * 加载 COLMAP 的 cameras 和 images 文件（URL 或文件，.bin 为二进制格式，其它按文本解析）
+ */
+export async function loadColmapCameras(cameras: string | Blob, images: string | Blob): Promise<TrainingCamera[]> {
+  const [parsedCameras, parsedImages] = await Promise.all([
+    isBinarySource(cameras)
+      ? fetchOrRead(cameras, true).then(parseColmapCamerasBinary)
+      : fetchOrRead(cameras, false).then(parseColmapCamerasText),
+    isBinarySource(images)
+      ? fetchOrRead(images, true).then(parseColmapImagesBinary)
+      : fetchOrRead(images, false).then(parseColmapImagesText),
+  ]);
+  return createColmapTrainingCameras(parsedCameras, parsedImages);
+}
@@ -1,8 +1,57 @@
 import { Renderer } from "../core/Renderer";
 import { Camera } from "../core/Camera";
+import { getDepthCompare, getDepthClearValue, invertMat4 } from "../core/DepthConvention";
+import { multiplyTransforms } from "../utils/transform";
 import { Mesh, MeshBoundingBox } from "./Mesh";
 import { MaterialData } from "../loaders/GLBLoader";
 
+/**
+ * 方向光阴影配置
+ */
+export interface MeshShadowConfig {
+  /** 是否启用 */
+  enabled: boolean;
+  /** 阴影贴图尺寸（像素） */
+  mapSize: number;
+  /** 深度偏移（阴影贴图 NDC 深度），避免自阴影条纹 */
+  bias: number;
+  /** 阴影强度（0 无阴影，1 完全遮挡直射光） */
+  strength: number;
+}
+
+export const DEFAULT_MESH_SHADOW_CONFIG: MeshShadowConfig = {
+  enabled: false,
+  mapSize: 2048,
+  bias: 0.002,
+  strength: 0.6,
+};
+
+const SHADOW_MAP_FORMAT: GPUTextureFormat = "depth32float";
+
+/**
+ * 阴影贴图采样（group 1），3x3 PCF，返回受光比例
+ * 贴图范围之外、光源一侧近平面之前的点视为受光；远平面之后的点按远平面比较
+ * （贴图清除为 1，没有投射者的位置仍为受光）
+ */
+const shadowSampleCode = /* wgsl */ `
+@group(1) @binding(0) var shadowMap: texture_depth_2d;
+@group(1) @binding(1) var shadowSampler: sampler_comparison;
+
+fn sampleShadow(lightClip: vec4<f32>, bias: f32, texelSize: f32) -> f32 {
+  let ndc = lightClip.xyz / lightClip.w;
+  let uv = vec2<f32>(ndc.x * 0.5 + 0.5, 0.5 - ndc.y * 0.5);
+  if any(uv < vec2<f32>(0.0)) || any(uv > vec2<f32>(1.0)) || ndc.z < 0.0 { return 1.0; }
+  let depth = min(ndc.z, 1.0) - bias;
+  var lit = 0.0;
+  for (var y = -1; y <= 1; y++) {
+    for (var x = -1; x <= 1; x++) {
+      lit += textureSampleCompareLevel(shadowMap, shadowSampler, uv + vec2<f32>(f32(x), f32(y)) * texelSize, depth);
+    }
+  }
+  return lit / 9.0;
+}
+`;
+
 /**
  * 带纹理的 Shader
  */
@@ -13,6 +62,9 @@ struct Uniforms {
   baseColorFactor: vec4<f32>,
   lightDir: vec3<f32>,
   ambientIntensity: f32,
+  lightViewProjection: mat4x4<f32>,
+  // x: 是否启用阴影, y: 深度偏移, z: 阴影强度, w: 阴影贴图纹素大小
+  shadowParams: vec4<f32>,
 }
 
 @group(0) @binding(0) var<uniform> uniforms: Uniforms;
@@ -29,8 +81,11 @@ struct VertexOutput {
   @builtin(position) position: vec4<f32>,
   @location(0) normal: vec3<f32>,
   @location(1) uv: vec2<f32>,
+  @location(2) lightClip: vec4<f32>,
 }
 
+${shadowSampleCode}
+
 @vertex
 fn vs_main(input: VertexInput) -> VertexOutput {
   var output: VertexOutput;
@@ -38,18 +93,30 @@ fn vs_main(input: VertexInput) -> VertexOutput {
   output.position = uniforms.viewProjection * worldPos;
   output.normal = normalize((uniforms.model * vec4<f32>(input.normal, 0.0)).xyz);
   output.uv = input.uv;
+  output.lightClip = uniforms.lightViewProjection * worldPos;
   return output;
 }
 
+@vertex
+fn vs_shadow(input: VertexInput) -> @builtin(position) vec4<f32> {
+  return uniforms.lightViewProjection * uniforms.model * vec4<f32>(input.position, 1.0);
+}
+
+fn shadowFactor(lightClip: vec4<f32>) -> f32 {
+  if uniforms.shadowParams.x == 0.0 { return 1.0; }
+  let lit = sampleShadow(lightClip, uniforms.shadowParams.y, uniforms.shadowParams.w);
+  return 1.0 - uniforms.shadowParams.z * (1.0 - lit);
+}
+
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
   let texColor = textureSample(baseColorTexture, texSampler, input.uv);
   let baseColor = texColor * uniforms.baseColorFactor;
   
-  // Lambert 光照 + 环境光
+  // Lambert 光照 + 环境光，阴影只遮挡直射光
   let normal = normalize(input.normal);
   let NdotL = max(dot(normal, uniforms.lightDir), 0.0);
-  let diffuse = NdotL * (1.0 - uniforms.ambientIntensity);
+  let diffuse = NdotL * (1.0 - uniforms.ambientIntensity) * shadowFactor(input.lightClip);
   let lighting = uniforms.ambientIntensity + diffuse;
   
   return vec4<f32>(baseColor.rgb * lighting, baseColor.a);
@@ -66,6 +133,9 @@ struct Uniforms {
   baseColorFactor: vec4<f32>,
   lightDir: vec3<f32>,
   ambientIntensity: f32,
+  lightViewProjection: mat4x4<f32>,
+  // x: 是否启用阴影, y: 深度偏移, z: 阴影强度, w: 阴影贴图纹素大小
+  shadowParams: vec4<f32>,
 }
 
 @group(0) @binding(0) var<uniform> uniforms: Uniforms;
@@ -78,30 +148,82 @@ struct VertexInput {
 struct VertexOutput {
   @builtin(position) position: vec4<f32>,
   @location(0) normal: vec3<f32>,
+  @location(1) lightClip: vec4<f32>,
 }
 
+${shadowSampleCode}
+
 @vertex
 fn vs_main(input: VertexInput) -> VertexOutput {
   var output: VertexOutput;
   let worldPos = uniforms.model * vec4<f32>(input.position, 1.0);
   output.position = uniforms.viewProjection * worldPos;
   output.normal = normalize((uniforms.model * vec4<f32>(input.normal, 0.0)).xyz);
+  output.lightClip = uniforms.lightViewProjection * worldPos;
   return output;
 }
 
+@vertex
+fn vs_shadow(input: VertexInput) -> @builtin(position) vec4<f32> {
+  return uniforms.lightViewProjection * uniforms.model * vec4<f32>(input.position, 1.0);
+}
+
+fn shadowFactor(lightClip: vec4<f32>) -> f32 {
+  if uniforms.shadowParams.x == 0.0 { return 1.0; }
+  let lit = sampleShadow(lightClip, uniforms.shadowParams.y, uniforms.shadowParams.w);
+  return 1.0 - uniforms.shadowParams.z * (1.0 - lit);
+}
+
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
   let normal = normalize(input.normal);
   let NdotL = max(dot(normal, uniforms.lightDir), 0.0);
-  let diffuse = NdotL * (1.0 - uniforms.ambientIntensity);
+  let diffuse = NdotL * (1.0 - uniforms.ambientIntensity) * shadowFactor(input.lightClip);
   let lighting = uniforms.ambientIntensity + diffuse;
   
   return vec4<f32>(uniforms.baseColorFactor.rgb * lighting, uniforms.baseColorFactor.a);
 }
 `;
 
-// Uniform buffer 大小: viewProjection(64) + model(64) + baseColorFactor(16) + lightDir(12) + ambientIntensity(4) = 160 bytes
-const UNIFORM_BUFFER_SIZE = 160;
+/**
+ * 屏幕空间阴影接收：从深度缓冲重建世界坐标并与阴影贴图比较，用乘法混合压暗颜色。
+ * 在 splat 之后、网格之前绘制，只影响已写入深度的像素（开启深度输出的 splat）
+ */
+const shaderCodeShadowReceiver = /* wgsl */ `
+struct Uniforms {
+  // 屏幕 NDC -> 光源裁剪空间 (lightViewProjection * inverse(viewProjection))
+  screenToLight: mat4x4<f32>,
+  // x: 深度偏移, y: 阴影强度, z: 阴影贴图纹素大小, w: 深度清除值
+  params: vec4<f32>,
+}
+
+@group(0) @binding(0) var<uniform> uniforms: Uniforms;
+@group(0) @binding(1) var sceneDepth: texture_depth_2d;
+
+${shadowSampleCode}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertexIndex: u32) -> @builtin(position) vec4<f32> {
+  let uv = vec2<f32>(f32((vertexIndex << 1u) & 2u), f32(vertexIndex & 2u));
+  return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+  let depth = textureLoad(sceneDepth, vec2<i32>(position.xy), 0);
+  if depth == uniforms.params.w { discard; }
+  let size = vec2<f32>(textureDimensions(sceneDepth));
+  let ndc = vec2<f32>(position.x / size.x * 2.0 - 1.0, 1.0 - position.y / size.y * 2.0);
+  let lightClip = uniforms.screenToLight * vec4<f32>(ndc, depth, 1.0);
+  let lit = sampleShadow(lightClip, uniforms.params.x, uniforms.params.z);
+  let factor = 1.0 - uniforms.params.y * (1.0 - lit);
+  return vec4<f32>(factor, factor, factor, 1.0);
+}
+`;
+
+// Uniform buffer 大小: viewProjection(64) + model(64) + baseColorFactor(16) + lightDir(12) + ambientIntensity(4)
+// + lightViewProjection(64) + shadowParams(16) = 240 bytes
+const UNIFORM_BUFFER_SIZE = 240;
 
 /**
  * 渲染项（Mesh + Material + 独立的 uniform buffer）
@@ -116,6 +238,9 @@ interface RenderItem {
 /**
  * MeshRenderer - 网格渲染器
  * 支持纹理和材质，每个 mesh 有独立的 uniform buffer
+ *
+ * 方向光阴影：renderShadowMap 在主渲染通道之前提交深度 pass（正交投影贴合网格的包围球，
+ * 只有网格投射阴影），网格着色时采样阴影贴图；renderShadowReceiver 让写入了深度的 splat 接收网格的阴影
  */
 export class MeshRenderer {
   private renderer: Renderer;
@@ -135,6 +260,21 @@ export class MeshRenderer {
   private sampler!: GPUSampler;
   private defaultTexture!: GPUTexture;
 
+  // 方向光阴影
+  private shadowConfig: MeshShadowConfig = { ...DEFAULT_MESH_SHADOW_CONFIG };
+  private shadowMap!: GPUTexture;
+  private shadowSampler!: GPUSampler;
+  private shadowBindGroupLayout!: GPUBindGroupLayout;
+  private shadowBindGroup!: GPUBindGroup;
+  private shadowPipelineTextured!: GPURenderPipeline;
+  private shadowPipelineUntextured!: GPURenderPipeline;
+  private receiverPipeline!: GPURenderPipeline;
+  private receiverBindGroupLayout!: GPUBindGroupLayout;
+  private receiverUniformBuffer!: GPUBuffer;
+  private lightViewProjection: Float32Array = new Float32Array(16);
+  // 本帧是否提交了阴影贴图
+  private shadowActive: boolean = false;
+
   // 光照方向
   private lightDir: Float32Array = new Float32Array([0.5, 0.7, 0.5]);
   // 环境光强度 (0-1)
@@ -171,6 +311,51 @@ export class MeshRenderer {
       { bytesPerRow: 4 },
       [1, 1, 1]
     );
+
+    // 阴影贴图比较采样器（边缘之外的判断在 shader 中处理）
+    this.shadowSampler = device.createSampler({
+      compare: 'less-equal',
+      magFilter: 'linear',
+      minFilter: 'linear',
+      addressModeU: 'clamp-to-edge',
+      addressModeV: 'clamp-to-edge',
+    });
+
+    this.shadowBindGroupLayout = device.createBindGroupLayout({
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.FRAGMENT, texture: { sampleType: "depth" } },
+        { binding: 1, visibility: GPUShaderStage.FRAGMENT, sampler: { type: "comparison" } },
+      ],
+    });
+
+    this.receiverUniformBuffer = device.createBuffer({
+      size: 80,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+      label: 'mesh-shadow-receiver-uniforms',
+    });
+
+    // 启用阴影之前使用 1x1 占位贴图
+    this.createShadowMap(1);
+  }
+
+  /**
+   * 创建阴影贴图和对应的 bind group
+   */
+  private createShadowMap(size: number): void {
+    this.shadowMap?.destroy();
+    this.shadowMap = this.renderer.device.createTexture({
+      size: [size, size],
+      format: SHADOW_MAP_FORMAT,
+      usage: GPUTextureUsage.RENDER_ATTACHMENT | GPUTextureUsage.TEXTURE_BINDING,
+      label: 'mesh-shadow-map',
+    });
+    this.shadowBindGroup = this.renderer.device.createBindGroup({
+      layout: this.shadowBindGroupLayout,
+      entries: [
+        { binding: 0, resource: this.shadowMap.createView() },
+        { binding: 1, resource: this.shadowSampler },
+      ],
+    });
   }
 
   private createPipelines(): void {
@@ -188,7 +373,7 @@ export class MeshRenderer {
     });
 
     const pipelineLayoutTextured = device.createPipelineLayout({
-      bindGroupLayouts: [this.bindGroupLayoutTextured],
+      bindGroupLayouts: [this.bindGroupLayoutTextured, this.shadowBindGroupLayout],
     });
 
     const vertexBufferLayoutTextured: GPUVertexBufferLayout = {
@@ -213,7 +398,7 @@ export class MeshRenderer {
         targets: [{ format: this.renderer.format }],
       },
       primitive: { topology: "triangle-list", frontFace: "ccw" },
-      depthStencil: { format: this.renderer.depthFormat, depthWriteEnabled: true, depthCompare: "less" },
+      depthStencil: { format: this.renderer.depthFormat, depthWriteEnabled: true, depthCompare: getDepthCompare(this.renderer.depthConfig) },
     };
 
     this.pipelineTextured = device.createRenderPipeline({
@@ -236,7 +421,7 @@ export class MeshRenderer {
     });
 
     const pipelineLayoutUntextured = device.createPipelineLayout({
-      bindGroupLayouts: [this.bindGroupLayoutUntextured],
+      bindGroupLayouts: [this.bindGroupLayoutUntextured, this.shadowBindGroupLayout],
     });
 
     const vertexBufferLayoutUntextured: GPUVertexBufferLayout = {
@@ -260,7 +445,7 @@ export class MeshRenderer {
         targets: [{ format: this.renderer.format }],
       },
       primitive: { topology: "triangle-list", frontFace: "ccw" },
-      depthStencil: { format: this.renderer.depthFormat, depthWriteEnabled: true, depthCompare: "less" },
+      depthStencil: { format: this.renderer.depthFormat, depthWriteEnabled: true, depthCompare: getDepthCompare(this.renderer.depthConfig) },
     };
 
     this.pipelineUntextured = device.createRenderPipeline({
@@ -272,6 +457,55 @@ export class MeshRenderer {
       ...basePipelineDescUntextured,
       primitive: { ...basePipelineDescUntextured.primitive, cullMode: "none" },
     });
+
+    // === 阴影贴图管线（只有深度，不剔除背面以免薄网格漏光）===
+    const shadowDepthStencil: GPUDepthStencilState = {
+      format: SHADOW_MAP_FORMAT,
+      depthWriteEnabled: true,
+      depthCompare: "less",
+      depthBias: 2,
+      depthBiasSlopeScale: 2,
+    };
+    this.shadowPipelineTextured = device.createRenderPipeline({
+      layout: device.createPipelineLayout({ bindGroupLayouts: [this.bindGroupLayoutTextured] }),
+      vertex: { module: shaderModuleTextured, entryPoint: "vs_shadow", buffers: [vertexBufferLayoutTextured] },
+      primitive: { topology: "triangle-list", cullMode: "none" },
+      depthStencil: shadowDepthStencil,
+    });
+    this.shadowPipelineUntextured = device.createRenderPipeline({
+      layout: device.createPipelineLayout({ bindGroupLayouts: [this.bindGroupLayoutUntextured] }),
+      vertex: { module: shaderModuleUntextured, entryPoint: "vs_shadow", buffers: [vertexBufferLayoutUntextured] },
+      primitive: { topology: "triangle-list", cullMode: "none" },
+      depthStencil: shadowDepthStencil,
+    });
+
+    // === 阴影接收管线（乘法混合，深度只读）===
+    const receiverModule = device.createShaderModule({ code: shaderCodeShadowReceiver });
+    this.receiverBindGroupLayout = device.createBindGroupLayout({
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.FRAGMENT, buffer: { type: "uniform" } },
+        { binding: 1, visibility: GPUShaderStage.FRAGMENT, texture: { sampleType: "depth" } },
+      ],
+    });
+    this.receiverPipeline = device.createRenderPipeline({
+      layout: device.createPipelineLayout({
+        bindGroupLayouts: [this.receiverBindGroupLayout, this.shadowBindGroupLayout],
+      }),
+      vertex: { module: receiverModule, entryPoint: "vs_main" },
+      fragment: {
+        module: receiverModule,
+        entryPoint: "fs_main",
+        targets: [{
+          format: this.renderer.format,
+          blend: {
+            color: { srcFactor: "zero", dstFactor: "src", operation: "add" },
+            alpha: { srcFactor: "zero", dstFactor: "one", operation: "add" },
+          },
+        }],
+      },
+      primitive: { topology: "triangle-list" },
+      depthStencil: { format: this.renderer.depthFormat, depthWriteEnabled: false, depthCompare: "always" },
+    });
   }
 
   /**
@@ -381,6 +615,141 @@ export class MeshRenderer {
     return this.ambientIntensity;
   }
 
+  /**
+   * 设置方向光阴影（只修改提供的字段）
+   */
+  setShadowConfig(config: Partial<MeshShadowConfig>): void {
+    this.shadowConfig = { ...this.shadowConfig, ...config };
+    this.shadowConfig.mapSize = Math.max(1, Math.min(Math.floor(this.shadowConfig.mapSize), 8192));
+    this.shadowConfig.strength = Math.max(0, Math.min(1, this.shadowConfig.strength));
+  }
+
+  getShadowConfig(): MeshShadowConfig {
+    return { ...this.shadowConfig };
+  }
+
+  /**
+   * 提交阴影贴图的深度 pass（在主渲染通道之前调用，独立提交，队列按顺序执行）
+   * 没有网格或未启用阴影时跳过，本帧不采样阴影
+   */
+  renderShadowMap(): void {
+    this.shadowActive = false;
+    const box = this.shadowConfig.enabled ? this.getCombinedBoundingBox() : null;
+    if (!box) return;
+
+    const device = this.renderer.device;
+    if (this.shadowMap.width !== this.shadowConfig.mapSize) {
+      this.createShadowMap(this.shadowConfig.mapSize);
+    }
+    this.updateLightViewProjection(box);
+
+    const encoder = device.createCommandEncoder({ label: 'mesh-shadow-encoder' });
+    const pass = encoder.beginRenderPass({
+      colorAttachments: [],
+      depthStencilAttachment: {
+        view: this.shadowMap.createView(),
+        depthClearValue: 1,
+        depthLoadOp: 'clear',
+        depthStoreOp: 'store',
+      },
+    });
+    for (const item of this.items) {
+      const { mesh, uniformBuffer, bindGroup } = item;
+      device.queue.writeBuffer(uniformBuffer, 64, mesh.modelMatrix.buffer);
+      device.queue.writeBuffer(uniformBuffer, 160, this.lightViewProjection.buffer);
+      pass.setPipeline(mesh.hasUV ? this.shadowPipelineTextured : this.shadowPipelineUntextured);
+      pass.setBindGroup(0, bindGroup);
+      pass.setVertexBuffer(0, mesh.vertexBuffer);
+      if (mesh.indexBuffer && mesh.indexCount > 0) {
+        pass.setIndexBuffer(mesh.indexBuffer, mesh.indexFormat);
+        pass.drawIndexed(mesh.indexCount);
+      } else {
+        pass.draw(mesh.vertexCount);
+      }
+    }
+    pass.end();
+    device.queue.submit([encoder.finish()]);
+    this.shadowActive = true;
+  }
+
+  /**
+   * 本帧是否有阴影贴图（需要调用 renderShadowReceiver）
+   */
+  isShadowActive(): boolean {
+    return this.shadowActive;
+  }
+
+  /**
+   * 让深度缓冲中已有的像素（开启深度输出的 splat）接收网格阴影
+   * @param pass 深度只读的渲染通道（深度纹理同时作为纹理绑定）
+   * @param depthTexture 当前帧的深度纹理
+   */
+  renderShadowReceiver(pass: GPURenderPassEncoder, depthTexture: GPUTexture): void {
+    if (!this.shadowActive) return;
+    const device = this.renderer.device;
+    const inverseViewProjection = invertMat4(this.camera.viewProjectionMatrix);
+    if (!inverseViewProjection) return;
+
+    const data = new Float32Array(20);
+    multiplyTransforms(this.lightViewProjection, inverseViewProjection, data.subarray(0, 16));
+    data[16] = this.shadowConfig.bias;
+    data[17] = this.shadowConfig.strength;
+    data[18] = 1 / this.shadowMap.width;
+    data[19] = getDepthClearValue(this.renderer.depthConfig);
+    device.queue.writeBuffer(this.receiverUniformBuffer, 0, data.buffer);
+
+    const bindGroup = device.createBindGroup({
+      layout: this.receiverBindGroupLayout,
+      entries: [
+        { binding: 0, resource: { buffer: this.receiverUniformBuffer } },
+        { binding: 1, resource: depthTexture.createView() },
+      ],
+    });
+    pass.setPipeline(this.receiverPipeline);
+    pass.setBindGroup(0, bindGroup);
+    pass.setBindGroup(1, this.shadowBindGroup);
+    pass.draw(3);
+  }
+
+  /**
+   * 光源正交投影：以包围球为范围，光源位于球面上沿光照方向
+   */
+  private updateLightViewProjection(box: MeshBoundingBox): void {
+    const length = Math.hypot(this.lightDir[0], this.lightDir[1], this.lightDir[2]) || 1;
+    const lx = this.lightDir[0] / length, ly = this.lightDir[1] / length, lz = this.lightDir[2] / length;
+    const c = box.center;
+    const r = Math.max(box.radius, 1e-3);
+    const eye = [c[0] + lx * r, c[1] + ly * r, c[2] + lz * r];
+
+    // z 轴指向光源，x / y 与之正交
+    const up = Math.abs(ly) > 0.99 ? [0, 0, 1] : [0, 1, 0];
+    let xx = up[1] * lz - up[2] * ly;
+    let xy = up[2] * lx - up[0] * lz;
+    let xz = up[0] * ly - up[1] * lx;
+    const xLen = Math.hypot(xx, xy, xz);
+    xx /= xLen; xy /= xLen; xz /= xLen;
+    const yx = ly * xz - lz * xy;
+    const yy = lz * xx - lx * xz;
+    const yz = lx * xy - ly * xx;
+
+    const view = new Float32Array([
+      xx, yx, lx, 0,
+      xy, yy, ly, 0,
+      xz, yz, lz, 0,
+      -(xx * eye[0] + xy * eye[1] + xz * eye[2]),
+      -(yx * eye[0] + yy * eye[1] + yz * eye[2]),
+      -(lx * eye[0] + ly * eye[1] + lz * eye[2]),
+      1,
+    ]);
+    // 正交投影：xy 覆盖 [-r, r]，视图空间深度 [0, 2r] 映射到 [0, 1]
+    const projection = new Float32Array(16);
+    projection[0] = 1 / r;
+    projection[5] = 1 / r;
+    projection[10] = -1 / (2 * r);
+    projection[15] = 1;
+    multiplyTransforms(projection, view, this.lightViewProjection);
+  }
+
   /**
    * 渲染所有网格
    */
@@ -392,6 +761,9 @@ export class MeshRenderer {
     const lightData = new Float32Array([
       this.lightDir[0], this.lightDir[1], this.lightDir[2], this.ambientIntensity
     ]);
+    const shadowParams = new Float32Array([
+      this.shadowActive ? 1 : 0, this.shadowConfig.bias, this.shadowConfig.strength, 1 / this.shadowMap.width,
+    ]);
 
     for (const item of this.items) {
       const { mesh, material, uniformBuffer, bindGroup } = item;
@@ -402,6 +774,8 @@ export class MeshRenderer {
       const colorData = new Float32Array(material.baseColorFactor);
       device.queue.writeBuffer(uniformBuffer, 128, colorData.buffer);
       device.queue.writeBuffer(uniformBuffer, 144, lightData.buffer);
+      device.queue.writeBuffer(uniformBuffer, 160, this.lightViewProjection.buffer);
+      device.queue.writeBuffer(uniformBuffer, 224, shadowParams.buffer);
 
       // 选择管线
       let pipeline: GPURenderPipeline;
@@ -413,6 +787,7 @@ export class MeshRenderer {
 
       pass.setPipeline(pipeline);
       pass.setBindGroup(0, bindGroup);
+      pass.setBindGroup(1, this.shadowBindGroup);
       pass.setVertexBuffer(0, mesh.vertexBuffer);
 
       if (mesh.indexBuffer && mesh.indexCount > 0) {
@@ -510,5 +885,7 @@ export class MeshRenderer {
   destroy(): void {
     this.clear();
     if (this.defaultTexture) this.defaultTexture.destroy();
+    this.shadowMap?.destroy();
+    this.receiverUniformBuffer?.destroy();
   }
 }
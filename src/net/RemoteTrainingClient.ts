@@ -0,0 +1,314 @@
+/**
+ * RemoteTrainingClient - 远程训练可视化
+ *
+ * 训练进程在服务器上运行，通过 WebSocket 推送 splat 数据、增量更新、预览图像和训练指标，
+ * 浏览器端把增量写入 TrainingSplatChannel（双缓冲，渲染端每帧取得最新数据），实现「服务器训练，浏览器实时查看」。
+ *
+ * 二进制消息（小端）以 16 字节头开始：u8 类型, u8 标志, u16 保留, u32 a, u32 b, u32 c
+ * - 1 完整数据：a = splat 数量；之后依次为 positions (3n f32), scales (3n), rotations (4n, wxyz), colors (3n), opacities (n),
+ *   标志 bit0 置位时还有 shCoeffs (45n)。splat 数量变化（致密化 / 剪枝）时重新发送完整数据
+ * - 2 增量：a = splat 总数, b = 区间数 r；之后为 r 个 (u32 start, u32 end) 区间，
+ *   再按完整数据的属性顺序排列区间内的 m 个 splat（每个属性连续存放，区间按顺序拼接），
+ *   标志 bit0 表示包含 SH，bit1 表示位置有变化
+ * - 3 预览图像：a = 宽, b = 高；之后为 RGBA8 像素
+ * 文本消息为 JSON：{ "type": "metrics", "iteration": ..., "loss": ..., ... }（字段同 TrainingMetricsSample）
+ */
+
+import type { CompactSplatData } from "../gs/PLYLoaderMobile";
+import { TrainingSplatChannel } from "../gs/TrainingSplatChannel";
+import { SplatDirtyRanges } from "../gs/SplatDirtyRanges";
+import type { TrainingMetricsSample } from "../gs/TrainingMetrics";
+
+export const REMOTE_TRAINING_MESSAGE = {
+  SPLATS: 1,
+  DELTA: 2,
+  FRAME: 3,
+} as const;
+
+const HEADER_SIZE = 16;
+const FLAG_HAS_SH = 1;
+const FLAG_POSITIONS_CHANGED = 2;
+
+// 每个 splat 的分量数，顺序即消息中的属性顺序
+const ATTRIBUTES: Array<["positions" | "scales" | "rotations" | "colors" | "opacities" | "shCoeffs", number]> = [
+  ["positions", 3],
+  ["scales", 3],
+  ["rotations", 4],
+  ["colors", 3],
+  ["opacities", 1],
+  ["shCoeffs", 45],
+];
+
+export type RemoteTrainingStatus = "connecting" | "open" | "closed";
+
+/**
+ * 远程训练事件回调
+ */
+export interface RemoteTrainingHandlers {
+  /** 收到第一份完整数据时创建通道（之后的完整数据和增量都写入同一个通道） */
+  onChannel?(channel: TrainingSplatChannel): void;
+  /** 预览图像（RGBA8，视图指向消息数据，回调返回后不再有效） */
+  onFrame?(pixels: Uint8Array, width: number, height: number): void;
+  onMetrics?(sample: TrainingMetricsSample): void;
+  onStatus?(status: RemoteTrainingStatus): void;
+  onError?(error: Error): void;
+}
+
+/**
+ * 远程训练连接配置
+ */
+export interface RemoteTrainingClientConfig {
+  /** 断开后重新连接的延迟（毫秒），0 表示不重连 */
+  reconnectDelay: number;
+}
+
+export const DEFAULT_REMOTE_TRAINING_CLIENT_CONFIG: RemoteTrainingClientConfig = {
+  reconnectDelay: 2000,
+};
+
+interface SplatDelta {
+  count: number;
+  ranges: [number, number][];
+  blocks: Partial<Record<(typeof ATTRIBUTES)[number][0], Float32Array>>;
+  positionsChanged: boolean;
+}
+
+type PendingMessage = { kind: "splats"; data: CompactSplatData } | { kind: "delta"; delta: SplatDelta };
+
+/**
+ * 解析完整数据消息（数组为消息 buffer 上的视图，不复制）
+ */
+export function decodeRemoteSplats(buffer: ArrayBuffer): CompactSplatData {
+  const view = new DataView(buffer);
+  const hasSH = (view.getUint8(1) & FLAG_HAS_SH) !== 0;
+  const count = view.getUint32(4, true);
+  let offset = HEADER_SIZE;
+  const read = (floats: number) => {
+    const array = new Float32Array(buffer, offset, count * floats);
+    offset += count * floats * 4;
+    return array;
+  };
+  return {
+    count,
+    positions: read(3),
+    scales: read(3),
+    rotations: read(4),
+    colors: read(3),
+    opacities: read(1),
+    shCoeffs: hasSH ? read(45) : undefined,
+  };
+}
+
+/**
+ * 解析增量消息
+ */
+function decodeRemoteDelta(buffer: ArrayBuffer): SplatDelta {
+  const view = new DataView(buffer);
+  const flags = view.getUint8(1);
+  const count = view.getUint32(4, true);
+  const rangeCount = view.getUint32(8, true);
+  const ranges: [number, number][] = [];
+  let selected = 0;
+  let offset = HEADER_SIZE;
+  for (let i = 0; i < rangeCount; i++) {
+    const start = view.getUint32(offset, true);
+    const end = view.getUint32(offset + 4, true);
+    offset += 8;
+    if (end <= start || end > count) {
+      throw new Error(`[RemoteTrainingClient] 无效的增量区间 [${start}, ${end})`);
+    }
+    ranges.push([start, end]);
+    selected += end - start;
+  }
+  const blocks: SplatDelta["blocks"] = {};
+  for (const [key, stride] of ATTRIBUTES) {
+    if (key === "shCoeffs" && (flags & FLAG_HAS_SH) === 0) continue;
+    blocks[key] = new Float32Array(buffer, offset, selected * stride);
+    offset += selected * stride * 4;
+  }
+  return { count, ranges, blocks, positionsChanged: (flags & FLAG_POSITIONS_CHANGED) !== 0 };
+}
+
+/**
+ * RemoteTrainingClient - 远程训练 WebSocket 客户端
+ */
+export class RemoteTrainingClient {
+  private url: string;
+  private handlers: RemoteTrainingHandlers;
+  private config: RemoteTrainingClientConfig;
+  private socket: WebSocket | null = null;
+  private channel: TrainingSplatChannel | null = null;
+  private pending: PendingMessage[] = [];
+  private flushScheduled: boolean = false;
+  private reconnectTimer: ReturnType<typeof setTimeout> | null = null;
+  private closed: boolean = false;
+
+  constructor(url: string, handlers: RemoteTrainingHandlers = {}, config: Partial<RemoteTrainingClientConfig> = {}) {
+    this.url = url;
+    this.handlers = handlers;
+    this.config = { ...DEFAULT_REMOTE_TRAINING_CLIENT_CONFIG, ...config };
+    this.connect();
+  }
+
+  /**
+   * 远程数据写入的通道（收到第一份完整数据之前为 null）
+   */
+  getChannel(): TrainingSplatChannel | null {
+    return this.channel;
+  }
+
+  isConnected(): boolean {
+    return this.socket?.readyState === WebSocket.OPEN;
+  }
+
+  private connect(): void {
+    this.handlers.onStatus?.("connecting");
+    const socket = new WebSocket(this.url);
+    socket.binaryType = "arraybuffer";
+    socket.onopen = () => this.handlers.onStatus?.("open");
+    socket.onmessage = (event) => this.onMessage(event.data);
+    socket.onerror = () => this.handlers.onError?.(new Error(`[RemoteTrainingClient] 连接错误: ${this.url}`));
+    socket.onclose = () => {
+      this.socket = null;
+      this.handlers.onStatus?.("closed");
+      if (!this.closed && this.config.reconnectDelay > 0) {
+        this.reconnectTimer = setTimeout(() => {
+          this.reconnectTimer = null;
+          this.connect();
+        }, this.config.reconnectDelay);
+      }
+    };
+    this.socket = socket;
+  }
+
+  /**
+   * 向服务器发送控制消息（JSON，例如暂停训练），未连接时返回 false
+   */
+  send(message: object): boolean {
+    if (!this.isConnected()) return false;
+    this.socket!.send(JSON.stringify(message));
+    return true;
+  }
+
+  private onMessage(data: unknown): void {
+    try {
+      if (typeof data === "string") {
+        const message = JSON.parse(data) as { type?: string } & TrainingMetricsSample;
+        if (message.type === "metrics") {
+          this.handlers.onMetrics?.(message);
+        }
+        return;
+      }
+      if (!(data instanceof ArrayBuffer) || data.byteLength < HEADER_SIZE) return;
+      const view = new DataView(data);
+      switch (view.getUint8(0)) {
+        case REMOTE_TRAINING_MESSAGE.SPLATS:
+          this.pending.push({ kind: "splats", data: decodeRemoteSplats(data) });
+          this.flush();
+          break;
+        case REMOTE_TRAINING_MESSAGE.DELTA:
+          this.pending.push({ kind: "delta", delta: decodeRemoteDelta(data) });
+          this.flush();
+          break;
+        case REMOTE_TRAINING_MESSAGE.FRAME: {
+          const width = view.getUint32(4, true);
+          const height = view.getUint32(8, true);
+          this.handlers.onFrame?.(new Uint8Array(data, HEADER_SIZE, width * height * 4), width, height);
+          break;
+        }
+      }
+    } catch (error) {
+      this.handlers.onError?.(error instanceof Error ? error : new Error(String(error)));
+    }
+  }
+
+  /**
+   * 把排队的消息写入通道：渲染端还没有取得上一次发布的数据时留到下一帧，
+   * 同一帧内到达的多个增量合并为一次发布
+   */
+  private flush(): void {
+    // 最后一份完整数据之前的消息都已过时
+    const lastFull = this.pending.map((message) => message.kind).lastIndexOf("splats");
+    if (lastFull > 0) {
+      this.pending.splice(0, lastFull);
+    }
+
+    const first = this.pending[0];
+    if (first?.kind === "splats") {
+      if (!this.channel) {
+        this.channel = new TrainingSplatChannel(first.data);
+        this.pending.shift();
+        this.handlers.onChannel?.(this.channel);
+      } else if (this.channel.replace(first.data)) {
+        this.pending.shift();
+      } else {
+        this.scheduleFlush();
+        return;
+      }
+    }
+
+    if (this.pending.length === 0) return;
+    if (!this.channel) {
+      // 增量需要先收到完整数据
+      this.pending = [];
+      this.handlers.onError?.(new Error("[RemoteTrainingClient] 收到完整数据之前的增量已丢弃"));
+      return;
+    }
+    const target = this.channel.beginWrite();
+    if (!target) {
+      this.scheduleFlush();
+      return;
+    }
+    const dirty = new SplatDirtyRanges();
+    for (const message of this.pending) {
+      if (message.kind !== "delta") continue;
+      if (message.delta.count !== target.count) {
+        this.handlers.onError?.(new Error(`[RemoteTrainingClient] 增量的 splat 数量 (${message.delta.count}) 与当前数据 (${target.count}) 不一致`));
+        continue;
+      }
+      this.applyDelta(target, message.delta, dirty);
+    }
+    this.pending = [];
+    this.channel.publish(dirty);
+  }
+
+  private applyDelta(target: CompactSplatData, delta: SplatDelta, dirty: SplatDirtyRanges): void {
+    for (const [key, stride] of ATTRIBUTES) {
+      const block = delta.blocks[key];
+      const array = target[key];
+      if (!block || !array) continue;
+      let cursor = 0;
+      for (const [start, end] of delta.ranges) {
+        const length = (end - start) * stride;
+        array.set(block.subarray(cursor, cursor + length), start * stride);
+        cursor += length;
+      }
+    }
+    for (const [start, end] of delta.ranges) {
+      dirty.addRange(start, end, delta.positionsChanged);
+    }
+  }
+
+  private scheduleFlush(): void {
+    if (this.flushScheduled || this.closed) return;
+    this.flushScheduled = true;
+    requestAnimationFrame(() => {
+      this.flushScheduled = false;
+      if (!this.closed) this.flush();
+    });
+  }
+
+  /**
+   * 断开连接，不再重连
+   */
+  close(): void {
+    this.closed = true;
+    if (this.reconnectTimer !== null) {
+      clearTimeout(this.reconnectTimer);
+      this.reconnectTimer = null;
+    }
+    this.socket?.close();
+    this.socket = null;
+    this.pending = [];
+  }
+}
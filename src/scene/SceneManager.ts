@@ -9,8 +9,45 @@
 
 import { Mesh, MeshBoundingBox } from "../mesh/Mesh";
 import { MeshRenderer } from "../mesh/MeshRenderer";
-import type { IGSSplatRenderer } from "../gs/IGSSplatRenderer";
-import type { BoundingBox, Vec3Tuple } from "../types";
+import type { IGSSplatRenderer, SplatEntityPickResult } from "../gs/IGSSplatRenderer";
+import { SplatLabels } from "../gs/SplatLabels";
+import { SplatLayer } from "./SplatLayer";
+import type { SplatLayerEntry } from "./SplatLayer";
+import type { SplatSelection, SelectionOp } from "../gs/SplatSelection";
+import { SplatEditHistory, captureSplatTransform, applySplatTransform } from "../gs/SplatEditHistory";
+import type { SplatTransformState } from "../gs/SplatEditHistory";
+import type { GaussianSplats } from "../gs/GaussianSplats";
+import type { CompactSplatData } from "../gs/PLYLoaderMobile";
+import type { SplatDirtyRanges } from "../gs/SplatDirtyRanges";
+import type { TransformIssue } from "../utils/transform";
+import type { TransferFunctionStop } from "../gs/TransferFunction";
+import type { SplatLodConfig } from "../gs/SplatLod";
+import { DEFAULT_SPLAT_POINT_SIZE_CONFIG } from "../gs/SplatPoints";
+import type { SplatPointSizeConfig } from "../gs/SplatPoints";
+import { DEFAULT_SPLAT_CENTERS_CONFIG } from "../gs/SplatCentersFilter";
+import type { SplatCentersConfig } from "../gs/SplatCentersFilter";
+import type { SplatSortCacheStats } from "../gs/SplatSortCache";
+import type { SplatPVSConfig } from "../gs/SplatPVS";
+import { DEFAULT_SPLAT_SWAY_CONFIG } from "../gs/SplatSway";
+import type { SplatSwayConfig } from "../gs/SplatSway";
+import { adjustSplatColor, isIdentityColorAdjustment } from "../gs/SplatColorAdjust";
+import type { SplatColorAdjustment } from "../gs/SplatColorAdjust";
+import type { SplatAppearanceData } from "../gs/SplatAppearance";
+import { editSplatOpacity, isIdentityOpacityEdit } from "../gs/SplatOpacityEdit";
+import type { SplatOpacityEdit } from "../gs/SplatOpacityEdit";
+import type { SplatCropBox } from "../gs/SplatCropBox";
+import { SplatGroups } from "../gs/SplatGroups";
+import type { SplatGroupInfo } from "../gs/SplatGroups";
+import type { SplatMirrorPlane } from "../gs/SplatMirror";
+import { findSplatFloaters } from "../gs/SplatFloaters";
+import type { SplatFloaterConfig } from "../gs/SplatFloaters";
+import type { SplatAttributePredicate } from "../gs/SplatAttributeFilter";
+import { Mat4 } from "../core/math/Mat4";
+import type { SplatIdDepth } from "../gs/SplatRenderLayers";
+import type { SplatPrecision, SplatStatistics } from "../gs/SplatStatistics";
+import type { SplatExternalBuffer } from "../gs/SplatExternalBuffers";
+import type { BoundingBox, Vec3Tuple, SplatPickResult, SplatDepthQueryMode, SplatDepthResult } from "../types";
+import { SplatVisMode, Colormap, SplatRasterMode, SplatBlendMode, SplatPointMode, SplatDepthKeyMode } from "../types";
 
 /**
  * 场景对象类型
@@ -32,6 +69,10 @@ export interface SceneObjectInfo {
 export class SceneManager {
   private meshRenderer: MeshRenderer;
   private gsRenderer: IGSSplatRenderer | null = null;
+  private splatLayerRoot: SplatLayer = new SplatLayer("root");
+  private splatEditHistory: SplatEditHistory = new SplatEditHistory();
+  // beginSplatTransformEdit 时记录的变换
+  private pendingTransform: SplatTransformState | null = null;
 
   constructor(meshRenderer: MeshRenderer) {
     this.meshRenderer = meshRenderer;
@@ -42,10 +83,15 @@ export class SceneManager {
   // ============================================
 
   /**
-   * 设置 GS Splat 渲染器
+   * 设置 GS Splat 渲染器（替换时销毁原渲染器）
    */
   setGSRenderer(renderer: IGSSplatRenderer | null): void {
+    if (this.gsRenderer && this.gsRenderer !== renderer) {
+      this.gsRenderer.destroy();
+    }
     this.gsRenderer = renderer;
+    this.splatEditHistory.clear();
+    this.pendingTransform = null;
   }
 
   /**
@@ -62,6 +108,121 @@ export class SceneManager {
     return this.gsRenderer !== null && this.gsRenderer.getSplatCount() > 0;
   }
 
+  // ============================================
+  // Splat 图层
+  // ============================================
+
+  /**
+   * 获取根图层
+   */
+  getSplatRootLayer(): SplatLayer {
+    return this.splatLayerRoot;
+  }
+
+  /**
+   * 创建图层（默认挂在根图层下）
+   */
+  createSplatLayer(name: string, parent: SplatLayer = this.splatLayerRoot): SplatLayer {
+    return parent.createLayer(name);
+  }
+
+  /**
+   * 按名称查找图层
+   */
+  findSplatLayer(name: string): SplatLayer | null {
+    return this.splatLayerRoot.findLayer(name);
+  }
+
+  /**
+   * 查找实体所在的图层（当前模型不在图层中，返回 null）
+   */
+  findSplatEntityLayer(renderer: IGSSplatRenderer): SplatLayer | null {
+    return this.splatLayerRoot.findEntityLayer(renderer);
+  }
+
+  /**
+   * 用合并后的实体替换源实体：源实体从图层移除并销毁
+   * @param layer 合并结果所在的图层，null 表示作为当前模型（替换原当前模型）
+   */
+  replaceSplatEntities(sources: ReadonlyArray<IGSSplatRenderer>, merged: IGSSplatRenderer, layer: SplatLayer | null): void {
+    for (const source of sources) {
+      if (source === this.gsRenderer) {
+        this.clearSplats();
+      } else {
+        this.splatLayerRoot.findEntityLayer(source)?.removeEntity(source);
+        source.destroy();
+      }
+    }
+    if (layer) {
+      layer.addEntity(merged);
+    } else {
+      this.setGSRenderer(merged);
+    }
+  }
+
+  /**
+   * 拆分实体：源实体保留其余数据，拆分出的实体加入源实体所在的图层（当前模型拆分时加入根图层）
+   * 拆分出的实体继承源实体的变换；拆分当前模型会清空编辑历史
+   */
+  splitSplatEntity(source: IGSSplatRenderer, rest: CompactSplatData, created: IGSSplatRenderer): void {
+    applySplatTransform(created, captureSplatTransform(source));
+    const layer = this.splatLayerRoot.findEntityLayer(source) ?? this.splatLayerRoot;
+    source.setCompactData(rest);
+    if (source === this.gsRenderer) {
+      this.splatEditHistory.clear();
+      this.pendingTransform = null;
+    }
+    layer.addEntity(created);
+  }
+
+  /**
+   * 获取全部 splat 渲染器（当前模型和所有图层中的实体）
+   */
+  getSplatRenderers(): IGSSplatRenderer[] {
+    const renderers = this.splatLayerRoot.getAllEntities();
+    if (this.gsRenderer) {
+      renderers.unshift(this.gsRenderer);
+    }
+    return renderers;
+  }
+
+  /**
+   * 绘制图层中的可见实体：按渲染顺序，顺序相同时按包围盒中心到相机的距离由远到近
+   */
+  renderSplatLayers(pass: GPURenderPassEncoder, cameraPosition: ArrayLike<number>): void {
+    const entries = this.splatLayerRoot.collectVisible();
+    if (entries.length === 0) {
+      return;
+    }
+    const distances = new Map<SplatLayerEntry, number>();
+    for (const entry of entries) {
+      const box = entry.renderer.getBoundingBox();
+      const c = box ? box.center : [0, 0, 0];
+      const m = entry.renderer.getModelMatrix();
+      const dx = m[0] * c[0] + m[4] * c[1] + m[8] * c[2] + m[12] - cameraPosition[0];
+      const dy = m[1] * c[0] + m[5] * c[1] + m[9] * c[2] + m[13] - cameraPosition[1];
+      const dz = m[2] * c[0] + m[6] * c[1] + m[10] * c[2] + m[14] - cameraPosition[2];
+      distances.set(entry, dx * dx + dy * dy + dz * dz);
+    }
+    entries.sort((a, b) => a.renderOrder - b.renderOrder || distances.get(b)! - distances.get(a)!);
+    for (const entry of entries) {
+      entry.renderer.render(pass);
+    }
+  }
+
+  /**
+   * 销毁所有图层及其中的实体
+   */
+  clearSplatLayers(): void {
+    for (const layer of [...this.splatLayerRoot.getLayers()]) {
+      layer.destroy();
+    }
+    for (const renderer of [...this.splatLayerRoot.getEntities()]) {
+      this.splatLayerRoot.removeEntity(renderer);
+      renderer.destroy();
+    }
+  }
+
   // ============================================
   // Mesh 管理（委托给 MeshRenderer）
   // ============================================
@@ -119,6 +280,35 @@ export class SceneManager {
     return this.gsRenderer?.getSplatCount() ?? 0;
   }
 
+  /**
+   * 获取 Splat 数据访问器（逐 splat 读取 / 修改）
+   */
+  getSplats(): GaussianSplats | null {
+    return this.gsRenderer?.getSplats?.() ?? null;
+  }
+
+  /**
+   * 整批更新 Splat 数据（训练等场景），只上传 dirty 中的区间
+   * 渲染器不支持增量更新时退回 setCompactData 整体上传
+   */
+  updateSplatData(data: CompactSplatData, dirty?: SplatDirtyRanges): void {
+    if (!this.gsRenderer) {
+      return;
+    }
+    if (this.gsRenderer.updateCompactData) {
+      this.gsRenderer.updateCompactData(data, dirty);
+    } else {
+      this.gsRenderer.setCompactData(data);
+    }
+  }
+
+  /**
+   * 外部 GPU buffer 内容更新后重新打包当前模型，当前模型不是来自外部 buffer 时返回 false
+   */
+  updateExternalSplatBuffers(): boolean {
+    return this.gsRenderer?.updateExternalBuffers?.() ?? false;
+  }
+
   /**
    * 清空 Splats
    */
@@ -127,6 +317,8 @@ export class SceneManager {
       this.gsRenderer.destroy();
       this.gsRenderer = null;
     }
+    this.splatEditHistory.clear();
+    this.pendingTransform = null;
   }
 
   // ============================================
@@ -175,6 +367,315 @@ export class SceneManager {
     return this.gsRenderer?.getScale() ?? null;
   }
 
+  /**
+   * 设置 Splat 变换（只修改传入的字段，可撤销）
+   */
+  setSplatTransform(transform: Partial<SplatTransformState>): void {
+    if (this.gsRenderer) {
+      this.splatEditHistory.setTransform(this.gsRenderer, transform);
+    }
+  }
+
+  /**
+   * 开始一次连续的变换编辑（例如 gizmo 拖动），期间的 setSplatPosition 等不单独记录
+   */
+  beginSplatTransformEdit(): void {
+    this.pendingTransform = this.gsRenderer ? captureSplatTransform(this.gsRenderer) : null;
+  }
+
+  /**
+   * 结束变换编辑，把整个过程记录为一步
+   * @returns 变换是否有变化
+   */
+  commitSplatTransformEdit(): boolean {
+    const before = this.pendingTransform;
+    this.pendingTransform = null;
+    return this.gsRenderer && before ? this.splatEditHistory.recordTransform(this.gsRenderer, before) : false;
+  }
+
+  /**
+   * 设置是否自动修正 Splat 的非有限值 / 剪切变换（零缩放只警告）
+   */
+  setSplatTransformAutoCorrect(enabled: boolean): void {
+    this.gsRenderer?.setTransformAutoCorrect?.(enabled);
+  }
+
+  /**
+   * 获取 Splat 变换检测到的问题
+   */
+  getSplatTransformIssues(): TransformIssue[] {
+    return this.gsRenderer?.getTransformIssues?.() ?? [];
+  }
+
+  /**
+   * 设置 Splat 曝光倍数
+   */
+  setSplatExposure(exposure: number): void {
+    this.gsRenderer?.setExposure?.(exposure);
+  }
+
+  /**
+   * 获取 Splat 曝光倍数
+   */
+  getSplatExposure(): number {
+    return this.gsRenderer?.getExposure?.() ?? 1.0;
+  }
+
+  /**
+   * 设置 Splat 混合模式
+   */
+  setSplatBlendMode(mode: SplatBlendMode): void {
+    this.gsRenderer?.setBlendMode?.(mode);
+  }
+
+  /**
+   * 获取 Splat 混合模式
+   */
+  getSplatBlendMode(): SplatBlendMode {
+    return this.gsRenderer?.getBlendMode?.() ?? SplatBlendMode.Standard;
+  }
+
+  /**
+   * 设置是否启用 Splat 八叉树分块剔除
+   */
+  setSplatChunkedCulling(enabled: boolean): void {
+    this.gsRenderer?.setChunkedCulling?.(enabled);
+  }
+
+  /**
+   * 是否启用 Splat 八叉树分块剔除
+   */
+  isSplatChunkedCulling(): boolean {
+    return this.gsRenderer?.isChunkedCulling?.() ?? false;
+  }
+
+  /**
+   * 预计算 Splat 分块可见性（室内场景），渲染器不支持时直接返回
+   */
+  async buildSplatPVS(config?: Partial<SplatPVSConfig>): Promise<void> {
+    await this.gsRenderer?.buildPVS?.(config);
+  }
+
+  /**
+   * 关闭 Splat 分块可见性预计算
+   */
+  clearSplatPVS(): void {
+    this.gsRenderer?.clearPVS?.();
+  }
+
+  /**
+   * 设置是否启用 Splat 遮挡剔除（被网格遮挡的 splat 不参与排序）
+   */
+  setSplatOcclusionCulling(enabled: boolean): void {
+    this.gsRenderer?.setOcclusionCulling?.(enabled);
+  }
+
+  /**
+   * 是否启用 Splat 遮挡剔除
+   */
+  isSplatOcclusionCulling(): boolean {
+    return this.gsRenderer?.isOcclusionCulling?.() ?? false;
+  }
+
+  /**
+   * 设置 Splat 移动时的分摊排序（每 interval 帧一次全精度排序，0 关闭）
+   */
+  setSplatSortAmortization(interval: number): void {
+    this.gsRenderer?.setSortAmortization?.(interval);
+  }
+
+  /**
+   * 获取 Splat 分摊排序的全精度间隔
+   */
+  getSplatSortAmortization(): number {
+    return this.gsRenderer?.getSortAmortization?.() ?? 0;
+  }
+
+  /**
+   * 设置 Splat 屏幕轴长上限（像素），0 不限制
+   */
+  setSplatMaxSize(size: number): void {
+    this.gsRenderer?.setMaxSplatSize?.(size);
+  }
+
+  /**
+   * 获取 Splat 屏幕轴长上限
+   */
+  getSplatMaxSize(): number {
+    return this.gsRenderer?.getMaxSplatSize?.() ?? 0;
+  }
+
+  /**
+   * 设置 Splat 摆动动画（风吹植被，仅桌面端）
+   */
+  setSplatSway(config: Partial<SplatSwayConfig>): void {
+    this.gsRenderer?.setSway?.(config);
+  }
+
+  /**
+   * 获取 Splat 摆动动画配置
+   */
+  getSplatSway(): SplatSwayConfig {
+    return this.gsRenderer?.getSway?.() ?? { ...DEFAULT_SPLAT_SWAY_CONFIG };
+  }
+
+  /**
+   * 设置是否复用 Splat 排序结果（相机、变换和数据都没有变化时跳过剔除和排序）
+   */
+  setSplatSortReuse(enabled: boolean): void {
+    this.gsRenderer?.setSortReuse?.(enabled);
+  }
+
+  /**
+   * 是否复用 Splat 排序结果
+   */
+  isSplatSortReuse(): boolean {
+    return this.gsRenderer?.isSortReuse?.() ?? false;
+  }
+
+  /**
+   * 获取 Splat 排序复用统计
+   */
+  getSplatSortReuseStats(): SplatSortCacheStats | null {
+    return this.gsRenderer?.getSortReuseStats?.() ?? null;
+  }
+
+  /**
+   * 设置 Splat 深度排序键模式
+   */
+  setSplatDepthKeyMode(mode: SplatDepthKeyMode): void {
+    this.gsRenderer?.setDepthKeyMode?.(mode);
+  }
+
+  /**
+   * 获取 Splat 深度排序键模式
+   */
+  getSplatDepthKeyMode(): SplatDepthKeyMode {
+    return this.gsRenderer?.getDepthKeyMode?.() ?? SplatDepthKeyMode.Float;
+  }
+
+  /**
+   * 设置是否启用 Splat pass 顺序校验（调试用，顺序异常时 console.warn）
+   */
+  setSplatPassValidation(enabled: boolean): void {
+    this.gsRenderer?.setPassValidation?.(enabled);
+  }
+
+  /**
+   * 是否启用 Splat pass 顺序校验
+   */
+  isSplatPassValidation(): boolean {
+    return this.gsRenderer?.isPassValidation?.() ?? false;
+  }
+
+  /**
+   * 获取上一帧的 Splat pass 依赖报告
+   */
+  getSplatPassReport(): string {
+    return this.gsRenderer?.getPassReport?.() ?? "";
+  }
+
+  /**
+   * 设置相机位于 splat 内部时是否淡出该 splat
+   */
+  setSplatCameraInsideFade(enabled: boolean): void {
+    this.gsRenderer?.setCameraInsideFade?.(enabled);
+  }
+
+  /**
+   * 相机位于 splat 内部时是否淡出该 splat
+   */
+  isSplatCameraInsideFade(): boolean {
+    return this.gsRenderer?.isCameraInsideFade?.() ?? false;
+  }
+
+  /**
+   * 设置是否剔除中心在近平面之后且包含相机的 splat
+   */
+  setSplatCameraInsideCull(enabled: boolean): void {
+    this.gsRenderer?.setCameraInsideCull?.(enabled);
+  }
+
+  /**
+   * 是否剔除中心在近平面之后且包含相机的 splat
+   */
+  isSplatCameraInsideCull(): boolean {
+    return this.gsRenderer?.isCameraInsideCull?.() ?? false;
+  }
+
+  /**
+   * 设置 Splat 近平面淡出距离（0 关闭）
+   */
+  setSplatNearFadeDistance(distance: number): void {
+    this.gsRenderer?.setNearFadeDistance?.(distance);
+  }
+
+  /**
+   * 获取 Splat 近平面淡出距离
+   */
+  getSplatNearFadeDistance(): number {
+    return this.gsRenderer?.getNearFadeDistance?.() ?? 0;
+  }
+
+  /**
+   * 设置 Splat LOD 配置（null 关闭）
+   */
+  setSplatLod(config: Partial<SplatLodConfig> | null): void {
+    this.gsRenderer?.setLodConfig?.(config);
+  }
+
+  /**
+   * 获取 Splat LOD 配置
+   */
+  getSplatLod(): SplatLodConfig | null {
+    return this.gsRenderer?.getLodConfig?.() ?? null;
+  }
+
+  /**
+   * 拾取画布像素 (x, y) 下最近的 splat
+   */
+  async pickSplat(x: number, y: number): Promise<SplatPickResult | null> {
+    return (await this.gsRenderer?.pick?.(x, y)) ?? null;
+  }
+
+  /**
+   * 在当前模型和所有可见图层实体中拾取画布像素 (x, y)，返回每个命中实体的 (实体, splat 索引)
+   * 每个实体单独拾取（实体内保留最近的命中），按命中点到相机的距离由近到远排序，第一个即可见的 splat。
+   * 限制：
+   * - 每个实体各执行一次拾取 pass（只光栅化目标像素，渲染目标按设备共享），开销随实体数量线性增长
+   * - 实体之间不做深度测试，被其它实体遮挡的实体同样返回命中（按距离排在后面）
+   * - 移动端渲染器（纹理打包格式）不支持拾取，这些实体不会出现在结果中
+   */
+  async pickSplatEntities(x: number, y: number, cameraPosition: ArrayLike<number>): Promise<SplatEntityPickResult[]> {
+    const entities = this.splatLayerRoot.collectVisible().map((entry) => entry.renderer);
+    if (this.gsRenderer) {
+      entities.unshift(this.gsRenderer);
+    }
+    const results = await Promise.all(entities.map((entity) => entity.pick?.(x, y) ?? null));
+    const hits: { hit: SplatEntityPickResult; distance: number }[] = [];
+    results.forEach((result, i) => {
+      if (!result) return;
+      const [wx, wy, wz] = result.worldPosition;
+      const distance = (wx - cameraPosition[0]) ** 2 + (wy - cameraPosition[1]) ** 2 + (wz - cameraPosition[2]) ** 2;
+      hits.push({ hit: { ...result, entity: entities[i] }, distance });
+    });
+    return hits.sort((a, b) => a.distance - b.distance).map((h) => h.hit);
+  }
+
+  /**
+   * 查询画布像素 (x, y) 处的 splat 深度和世界坐标（默认按不透明度混合），未命中返回 null
+   */
+  async getSplatDepthAt(x: number, y: number, mode: SplatDepthQueryMode = "blended"): Promise<SplatDepthResult | null> {
+    return (await this.gsRenderer?.depthAt?.(x, y, mode)) ?? null;
+  }
+
+  /**
+   * 回读整帧每个像素最近的 splat id 和视图空间距离（使用上一帧的相机），不支持时返回 null
+   */
+  async readSplatIdDepth(): Promise<SplatIdDepth | null> {
+    return (await this.gsRenderer?.readIdDepth?.()) ?? null;
+  }
+
   // ============================================
   // SH 模式
   // ============================================
@@ -195,22 +696,738 @@ export class SceneManager {
     return this.gsRenderer?.getSHMode?.() ?? 0;
   }
 
+  /**
+   * 设置按距离降低 SH 阶数的阈值 [去掉 L3, 去掉 L2, 去掉 L1]，null 关闭
+   */
+  setSHDistances(distances: Vec3Tuple | null): void {
+    this.gsRenderer?.setSHDistances?.(distances);
+  }
+
+  /**
+   * 获取按距离降低 SH 阶数的阈值
+   */
+  getSHDistances(): Vec3Tuple {
+    return this.gsRenderer?.getSHDistances?.() ?? [0, 0, 0];
+  }
+
+  /**
+   * 当前 splat 是否为 2DGS (surfel) 数据
+   */
+  isSplatSurfel(): boolean {
+    return this.gsRenderer?.isSurfelData?.() ?? false;
+  }
+
   // ============================================
-  // Bounding Box 查询
+  // 语义标签 / 可视化 / 选择
   // ============================================
 
   /**
-   * 获取 Mesh 的组合 bounding box
+   * 设置逐 splat 语义标签，传入 null 清除
    */
-  getMeshBoundingBox(): MeshBoundingBox | null {
-    return this.meshRenderer.getCombinedBoundingBox();
+  setSplatLabels(ids: Uint16Array | Uint32Array | null): void {
+    this.gsRenderer?.setLabels?.(ids ? new SplatLabels(ids) : null);
   }
 
   /**
-   * 获取 Splat 的 bounding box
+   * 获取逐 splat 语义标签
    */
-  getSplatBoundingBox(): BoundingBox | null {
-    return this.gsRenderer?.getBoundingBox() ?? null;
+  getSplatLabels(): SplatLabels | null {
+    return this.gsRenderer?.getLabels?.() ?? null;
+  }
+
+  /**
+   * 设置 splat 可视化模式（内置模式或 registerSplatVisMode 注册的自定义模式，可传名称）
+   */
+  setSplatVisMode(mode: SplatVisMode | string): void {
+    this.gsRenderer?.setVisMode?.(mode);
+  }
+
+  /**
+   * 获取 splat 可视化模式
+   */
+  getSplatVisMode(): SplatVisMode {
+    return this.gsRenderer?.getVisMode?.() ?? SplatVisMode.Color;
+  }
+
+  /**
+   * 获取 splat 选择集
+   */
+  getSplatSelection(): SplatSelection | null {
+    return this.gsRenderer?.getSelection?.() ?? null;
+  }
+
+  /**
+   * 按语义标签选择 splat（可撤销）
+   * @returns 操作后的选中数量
+   */
+  selectSplatsByLabel(label: number | number[], op: SelectionOp = "set"): number {
+    const labels = this.getSplatLabels();
+    const selection = this.getSplatSelection();
+    if (!this.gsRenderer || !labels || !selection) return 0;
+    this.splatEditHistory.editSelection(this.gsRenderer, (s) => s.selectByLabel(labels, label, op), "select");
+    return selection.getSelectedCount();
+  }
+
+  /**
+   * 修改 splat 选择（可撤销）
+   * @returns 选中状态发生变化的数量
+   */
+  editSplatSelection(edit: (selection: SplatSelection) => void, label?: string): number {
+    return this.gsRenderer ? this.splatEditHistory.editSelection(this.gsRenderer, edit, label) : 0;
+  }
+
+  /**
+   * 选择中心投影落在屏幕多边形（画布像素）内的 splat（可撤销，包括被遮挡的 splat）
+   * @returns 操作后的选中数量
+   */
+  async selectSplatsInPolygon(points: ReadonlyArray<readonly [number, number]>, op: SelectionOp = "set"): Promise<number> {
+    const renderer = this.gsRenderer;
+    const selection = this.getSplatSelection();
+    if (!renderer?.queryPolygon || !selection) return 0;
+    const hits = await renderer.queryPolygon(points);
+    // 查询期间数据被替换时放弃
+    if (renderer !== this.gsRenderer || this.getSplatSelection() !== selection) return 0;
+    this.splatEditHistory.editSelection(renderer, (s) => s.selectIndices(hits, op), "lasso select");
+    return selection.getSelectedCount();
+  }
+
+  /**
+   * 按属性阈值选择 splat（可撤销），例如选中所有不透明度 < 0.05 的 splat
+   * @param gpu 使用 GPU 筛选（渲染器不支持时回退到 CPU）
+   * @returns 操作后的选中数量
+   */
+  async selectSplatsByAttributes(
+    predicates: ReadonlyArray<SplatAttributePredicate>,
+    op: SelectionOp = "set",
+    gpu: boolean = false,
+  ): Promise<number> {
+    const renderer = this.gsRenderer;
+    const selection = this.getSplatSelection();
+    if (!renderer || !selection) return 0;
+    if (gpu && renderer.queryAttributes) {
+      const hits = await renderer.queryAttributes(predicates);
+      // 查询期间数据被替换时放弃
+      if (renderer !== this.gsRenderer || this.getSplatSelection() !== selection) return 0;
+      this.splatEditHistory.editSelection(renderer, (s) => s.selectIndices(hits, op), "attribute select");
+      return selection.getSelectedCount();
+    }
+    const splats = renderer.getSplats?.();
+    if (!splats) return 0;
+    const deleted = renderer.getDeletedMask?.() ?? null;
+    this.splatEditHistory.editSelection(
+      renderer,
+      (s) => s.selectByAttributes(splats.getData(), predicates, op, deleted),
+      "attribute select",
+    );
+    return selection.getSelectedCount();
+  }
+
+  /**
+   * 扩张选择到距离已选 splat 不超过 distance（模型空间）的 splat（可撤销）
+   * @returns 操作后的选中数量
+   */
+  growSplatSelection(distance: number): number {
+    return this.morphSplatSelection(distance, true);
+  }
+
+  /**
+   * 收缩选择：取消选中距离 distance（模型空间）内有未选中 splat 的边界 splat（可撤销）
+   * @returns 操作后的选中数量
+   */
+  shrinkSplatSelection(distance: number): number {
+    return this.morphSplatSelection(distance, false);
+  }
+
+  private morphSplatSelection(distance: number, grow: boolean): number {
+    const renderer = this.gsRenderer;
+    const selection = this.getSplatSelection();
+    const splats = renderer?.getSplats?.();
+    if (!renderer || !selection || !splats) return 0;
+    const positions = splats.getData().positions;
+    const deleted = renderer.getDeletedMask?.() ?? null;
+    this.splatEditHistory.editSelection(
+      renderer,
+      (s) => (grow ? s.grow(positions, distance, deleted) : s.shrink(positions, distance, deleted)),
+      grow ? "grow selection" : "shrink selection",
+    );
+    return selection.getSelectedCount();
+  }
+
+  /**
+   * 清空 splat 选择（可撤销）
+   */
+  clearSplatSelection(): void {
+    this.editSplatSelection((s) => s.clear(), "clear selection");
+  }
+
+  /**
+   * 删除指定 splat（可撤销）
+   * @returns 新删除的数量
+   */
+  deleteSplats(indices: ArrayLike<number>): number {
+    return this.gsRenderer ? this.splatEditHistory.deleteSplats(this.gsRenderer, indices) : 0;
+  }
+
+  /**
+   * 删除选中的 splat（可撤销）
+   * @param compact 是否立即从数据中物理移除（splat 索引会变化），否则只标记删除
+   * @returns 新删除的数量
+   */
+  deleteSelectedSplats(compact: boolean = false): number {
+    return this.gsRenderer ? this.splatEditHistory.deleteSelected(this.gsRenderer, "delete", compact) : 0;
+  }
+
+  /**
+   * 找出符合漂浮物特征的 splat（已删除的不计）
+   * @param config cameraPositions 为世界空间坐标
+   */
+  findSplatFloaters(config: Partial<SplatFloaterConfig> = {}): Uint32Array {
+    const renderer = this.gsRenderer;
+    const splats = renderer?.getSplats?.();
+    if (!renderer || !splats) return new Uint32Array(0);
+    let cameraPositions = config.cameraPositions;
+    if (cameraPositions) {
+      const model = new Mat4();
+      model.elements.set(renderer.getModelMatrix());
+      const e = model.inverse()?.elements;
+      if (!e) return new Uint32Array(0);
+      cameraPositions = cameraPositions.map((p) => [
+        e[0] * p[0] + e[4] * p[1] + e[8] * p[2] + e[12],
+        e[1] * p[0] + e[5] * p[1] + e[9] * p[2] + e[13],
+        e[2] * p[0] + e[6] * p[1] + e[10] * p[2] + e[14],
+      ]);
+    }
+    return findSplatFloaters(splats.getData(), { ...config, cameraPositions }, renderer.getDeletedMask?.() ?? null);
+  }
+
+  /**
+   * 选中漂浮物（可撤销），便于检查后再删除
+   * @returns 操作后的选中数量
+   */
+  selectSplatFloaters(config: Partial<SplatFloaterConfig> = {}, op: SelectionOp = "set"): number {
+    const selection = this.getSplatSelection();
+    if (!this.gsRenderer || !selection) return 0;
+    const floaters = this.findSplatFloaters(config);
+    this.splatEditHistory.editSelection(this.gsRenderer, (s) => s.selectIndices(floaters, op), "select floaters");
+    return selection.getSelectedCount();
+  }
+
+  /**
+   * 删除漂浮物（可撤销）
+   * @returns 删除的数量
+   */
+  removeSplatFloaters(config: Partial<SplatFloaterConfig> = {}): number {
+    const floaters = this.findSplatFloaters(config);
+    return this.gsRenderer && floaters.length > 0
+      ? this.splatEditHistory.deleteSplats(this.gsRenderer, floaters, "remove floaters")
+      : 0;
+  }
+
+  /**
+   * 修改 splat 颜色（可撤销）
+   * @param color 基础颜色 RGB (0~1)，或按索引和原颜色计算新颜色的函数
+   * @returns 修改的数量
+   */
+  recolorSplats(indices: ArrayLike<number>, color: Vec3Tuple | ((index: number, color: Vec3Tuple) => Vec3Tuple)): number {
+    return this.gsRenderer ? this.splatEditHistory.recolorSplats(this.gsRenderer, indices, color) : 0;
+  }
+
+  /**
+   * 修改选中 splat 的颜色（可撤销）
+   * @returns 修改的数量
+   */
+  recolorSelectedSplats(color: Vec3Tuple | ((index: number, color: Vec3Tuple) => Vec3Tuple)): number {
+    const selection = this.getSplatSelection();
+    if (!selection || selection.getSelectedCount() === 0) return 0;
+    return this.recolorSplats(selection.getSelectedIndices(), color);
+  }
+
+  /**
+   * 预览选中 splat 的颜色调整（只在 shader 中生效，不修改数据），null 关闭预览
+   */
+  previewSplatColorAdjustment(adjustment: SplatColorAdjustment | null): void {
+    this.gsRenderer?.setColorAdjustPreview?.(adjustment);
+  }
+
+  /**
+   * 把颜色调整写入选中 splat 的 DC 颜色（可撤销）并关闭预览
+   * @param adjustment 不传则使用当前预览的调整
+   * @returns 修改的数量
+   */
+  applySplatColorAdjustment(adjustment?: SplatColorAdjustment): number {
+    const value = adjustment ?? this.gsRenderer?.getColorAdjustPreview?.() ?? null;
+    this.previewSplatColorAdjustment(null);
+    if (!value || isIdentityColorAdjustment(value)) return 0;
+    return this.recolorSelectedSplats((_, color) => adjustSplatColor(color, value));
+  }
+
+  /**
+   * 修改 splat 不透明度（可撤销，跳过已删除的 splat）
+   * @returns 修改的数量
+   */
+  setSplatOpacities(indices: ArrayLike<number>, opacity: number | ((index: number, opacity: number) => number)): number {
+    return this.gsRenderer ? this.splatEditHistory.setSplatOpacities(this.gsRenderer, indices, opacity) : 0;
+  }
+
+  /**
+   * 预览选中 splat 的不透明度编辑（只在 shader 中生效，不修改数据），null 关闭预览
+   */
+  previewSplatOpacityEdit(edit: SplatOpacityEdit | null): void {
+    this.gsRenderer?.setOpacityEditPreview?.(edit);
+  }
+
+  /**
+   * 把不透明度编辑写入选中 splat（可撤销）并关闭预览
+   * @param edit 不传则使用当前预览的编辑
+   * @returns 修改的数量
+   */
+  applySplatOpacityEdit(edit?: SplatOpacityEdit): number {
+    const value = edit ?? this.gsRenderer?.getOpacityEditPreview?.() ?? null;
+    this.previewSplatOpacityEdit(null);
+    const selection = this.getSplatSelection();
+    if (!value || isIdentityOpacityEdit(value) || !selection || selection.getSelectedCount() === 0) return 0;
+    return this.setSplatOpacities(selection.getSelectedIndices(), (_, opacity) => editSplatOpacity(opacity, value));
+  }
+
+  /**
+   * 设置软裁剪盒：盒外（反转时盒内）的 splat 在 shader 中隐藏，不修改数据，null 关闭
+   */
+  setSplatCropBox(cropBox: SplatCropBox | null): void {
+    this.gsRenderer?.setCropBox?.(cropBox);
+  }
+
+  getSplatCropBox(): SplatCropBox | null {
+    return this.gsRenderer?.getCropBox?.() ?? null;
+  }
+
+  // ============================================
+  // splat 分组
+  // ============================================
+
+  /**
+   * 获取主模型的 splat 分组
+   * @param create 没有分组时创建（渲染器不支持分组时仍返回 null）
+   */
+  getSplatGroups(create: boolean = false): SplatGroups | null {
+    const renderer = this.gsRenderer;
+    let groups = renderer?.getGroups?.() ?? null;
+    if (!groups && create && renderer?.setGroups) {
+      groups = new SplatGroups(renderer.getSplatCount());
+      renderer.setGroups(groups);
+    }
+    return groups;
+  }
+
+  /**
+   * 全部分组信息
+   */
+  getSplatGroupInfos(): SplatGroupInfo[] {
+    return this.getSplatGroups()?.getGroups() ?? [];
+  }
+
+  /**
+   * 创建分组
+   * @returns 分组 id，达到上限或渲染器不支持时返回 null
+   */
+  createSplatGroup(name: string, tint: Vec3Tuple | null = null): number | null {
+    return this.getSplatGroups(true)?.createGroup(name, tint) ?? null;
+  }
+
+  /**
+   * 删除分组（其中的 splat 变为未分组）
+   */
+  deleteSplatGroup(id: number): boolean {
+    return this.getSplatGroups()?.deleteGroup(id) ?? false;
+  }
+
+  /**
+   * 把选中的 splat 分配到分组（id 为 0 时移出分组）
+   * @returns 分组发生变化的数量
+   */
+  assignSelectionToSplatGroup(id: number): number {
+    const selection = this.getSplatSelection();
+    const groups = this.getSplatGroups(true);
+    if (!selection || !groups || selection.getSelectedCount() === 0) return 0;
+    return groups.assign(selection.getSelectedIndices(), id);
+  }
+
+  /**
+   * 选择分组中的 splat（可撤销）
+   * @returns 操作后的选中数量
+   */
+  selectSplatGroup(id: number, op: SelectionOp = "set"): number {
+    const groups = this.getSplatGroups();
+    const selection = this.getSplatSelection();
+    if (!this.gsRenderer || !groups || !selection) return 0;
+    this.editSplatSelection((s) => s.selectWhere((i) => groups.ids[i] === id, op), "select group");
+    return selection.getSelectedCount();
+  }
+
+  /**
+   * 显示 / 隐藏分组（隐藏时取消选中其中的 splat）
+   */
+  setSplatGroupVisible(id: number, visible: boolean): void {
+    this.getSplatGroups()?.setVisible(id, visible);
+    this.deselectUnselectableSplats();
+  }
+
+  /**
+   * 锁定 / 解锁分组（锁定时取消选中其中的 splat，之后不能被选中）
+   */
+  setSplatGroupLocked(id: number, locked: boolean): void {
+    this.getSplatGroups()?.setLocked(id, locked);
+    this.deselectUnselectableSplats();
+  }
+
+  /**
+   * 只显示该分组，null 取消
+   */
+  soloSplatGroup(id: number | null): void {
+    this.getSplatGroups()?.setSolo(id);
+    this.deselectUnselectableSplats();
+  }
+
+  /**
+   * 设置分组着色，null 取消
+   * @param strength 着色强度 (0~1)
+   */
+  setSplatGroupTint(id: number, tint: Vec3Tuple | null, strength?: number): void {
+    this.getSplatGroups()?.setTint(id, tint, strength);
+  }
+
+  // 取消选中锁定 / 隐藏分组中的 splat（可撤销）
+  private deselectUnselectableSplats(): void {
+    const groups = this.getSplatGroups();
+    const selection = this.getSplatSelection();
+    if (!groups || !selection || selection.getSelectedCount() === 0) return;
+    const mask = selection.getMask();
+    this.editSplatSelection((s) => s.selectWhere((i) => mask[i] !== 0 && groups.isSelectable(i)), "deselect locked");
+  }
+
+  /**
+   * 硬裁剪：删除被裁剪盒裁掉的 splat（可撤销）并关闭软裁剪
+   * @param cropBox 不传则使用当前的软裁剪盒
+   * @returns 删除的数量
+   */
+  applySplatCropBox(cropBox?: SplatCropBox): number {
+    const box = cropBox ?? this.getSplatCropBox();
+    this.setSplatCropBox(null);
+    const splats = this.gsRenderer?.getSplats?.();
+    if (!box || !splats) return 0;
+    const data = splats.getData();
+    const cropped = box.findCropped(data.positions, data.count, this.gsRenderer!.getModelMatrix());
+    return cropped.length > 0 ? this.splatEditHistory.deleteSplats(this.gsRenderer!, cropped, "crop") : 0;
+  }
+
+  /**
+   * 设置第二套外观（同一几何、splat 一一对应的另一次采集，例如夜晚），null 清除
+   */
+  setSplatSecondaryAppearance(data: SplatAppearanceData | null): void {
+    this.gsRenderer?.setSecondaryAppearance?.(data);
+  }
+
+  /**
+   * 是否设置了第二套外观
+   */
+  hasSplatSecondaryAppearance(): boolean {
+    return this.gsRenderer?.hasSecondaryAppearance?.() ?? false;
+  }
+
+  /**
+   * 设置两套外观的混合权重（0 为原始外观，1 为第二套外观）
+   */
+  setSplatAppearanceBlend(t: number): void {
+    this.gsRenderer?.setAppearanceBlend?.(t);
+  }
+
+  /**
+   * 获取两套外观的混合权重
+   */
+  getSplatAppearanceBlend(): number {
+    return this.gsRenderer?.getAppearanceBlend?.() ?? 0;
+  }
+
+  /**
+   * 复制选中的 splat，副本追加到末尾并成为新的选择（可撤销）
+   * @param offset 副本的位置偏移
+   * @returns 复制的数量
+   */
+  duplicateSelectedSplats(offset?: Vec3Tuple): number {
+    return this.gsRenderer ? this.splatEditHistory.duplicateSelected(this.gsRenderer, offset) : 0;
+  }
+
+  /**
+   * 复制选中的 splat 并镜像到平面另一侧，副本成为新的选择（可撤销）
+   * @param plane 镜像平面（模型空间）
+   * @returns 复制的数量
+   */
+  mirrorSelectedSplats(plane: SplatMirrorPlane): number {
+    const selection = this.getSplatSelection();
+    if (!this.gsRenderer || !selection || selection.getSelectedCount() === 0) return 0;
+    return this.splatEditHistory.mirrorSplats(this.gsRenderer, selection.getSelectedIndices(), plane);
+  }
+
+  /**
+   * 获取已删除但尚未压缩的 splat 数量
+   */
+  getDeletedSplatCount(): number {
+    return this.gsRenderer?.getDeletedCount?.() ?? 0;
+  }
+
+  /**
+   * 压缩已删除的 splat（压缩后 splat 索引会变化，可撤销）
+   * @returns 移除的数量
+   */
+  compactDeletedSplats(): number {
+    return this.gsRenderer ? this.splatEditHistory.compactDeleted(this.gsRenderer) : 0;
+  }
+
+  /**
+   * 设置 Splat 自动压缩阈值（已删除比例），0 关闭自动压缩
+   */
+  setSplatCompactionThreshold(fraction: number): void {
+    this.gsRenderer?.setCompactionThreshold?.(fraction);
+  }
+
+  /**
+   * 获取 Splat 自动压缩阈值
+   */
+  getSplatCompactionThreshold(): number {
+    return this.gsRenderer?.getCompactionThreshold?.() ?? 0;
+  }
+
+  /**
+   * 设置逐 splat 标量属性，传入 null 清除
+   */
+  setSplatScalarAttribute(values: Float32Array | null, range?: [number, number]): void {
+    this.gsRenderer?.setScalarAttribute?.(values, range);
+  }
+
+  /**
+   * 获取逐 splat 标量属性
+   */
+  getSplatScalarAttribute(): Float32Array | null {
+    return this.gsRenderer?.getScalarAttribute?.() ?? null;
+  }
+
+  /**
+   * 使用外部 GPU buffer 作为逐 splat 标量属性，传入 null 清除
+   */
+  setSplatScalarBuffer(source: SplatExternalBuffer | null, range?: [number, number]): void {
+    this.gsRenderer?.setExternalScalarBuffer?.(source, range);
+  }
+
+  /**
+   * 按当前标量数据调整映射范围
+   */
+  async fitSplatScalarRange(clip: number = 0): Promise<[number, number] | null> {
+    return (await this.gsRenderer?.fitScalarRange?.(clip)) ?? null;
+  }
+
+  /**
+   * 设置标量属性映射范围
+   */
+  setSplatScalarRange(min: number, max: number): void {
+    this.gsRenderer?.setScalarRange?.(min, max);
+  }
+
+  /**
+   * 获取标量属性映射范围
+   */
+  getSplatScalarRange(): [number, number] | null {
+    return this.gsRenderer?.getScalarRange?.() ?? null;
+  }
+
+  /**
+   * 设置标量属性 colormap
+   */
+  setSplatColormap(colormap: Colormap): void {
+    this.gsRenderer?.setColormap?.(colormap);
+  }
+
+  /**
+   * 获取标量属性 colormap
+   */
+  getSplatColormap(): Colormap {
+    return this.gsRenderer?.getColormap?.() ?? Colormap.Viridis;
+  }
+
+  /**
+   * 设置 Splat 体渲染传递函数
+   */
+  setSplatTransferFunction(transferFunction: TransferFunctionStop[] | Uint8Array | null): void {
+    this.gsRenderer?.setTransferFunction?.(transferFunction);
+  }
+
+  /**
+   * 设置 Splat 体渲染不透明度倍数
+   */
+  setSplatVolumeOpacity(scale: number): void {
+    this.gsRenderer?.setVolumeOpacity?.(scale);
+  }
+
+  /**
+   * 获取 Splat 体渲染不透明度倍数
+   */
+  getSplatVolumeOpacity(): number {
+    return this.gsRenderer?.getVolumeOpacity?.() ?? 1.0;
+  }
+
+  /**
+   * 设置 splat 光栅化模式（移动端渲染器不支持，调用无效果）
+   */
+  setSplatRasterMode(mode: SplatRasterMode): void {
+    this.gsRenderer?.setRasterMode?.(mode);
+  }
+
+  /**
+   * 获取 splat 光栅化模式
+   */
+  getSplatRasterMode(): SplatRasterMode {
+    return this.gsRenderer?.getRasterMode?.() ?? SplatRasterMode.Quad;
+  }
+
+  /**
+   * 设置每个像素最多合成的 splat 数量（tile 模式下生效），0 表示不限制
+   */
+  setSplatMaxPerPixel(count: number): void {
+    this.gsRenderer?.setMaxSplatsPerPixel?.(count);
+  }
+
+  /**
+   * 获取每个像素最多合成的 splat 数量
+   */
+  getSplatMaxPerPixel(): number {
+    return this.gsRenderer?.getMaxSplatsPerPixel?.() ?? 0;
+  }
+
+  /**
+   * 设置 splat 深度输出的不透明度阈值，0 关闭
+   * 开启后不透明的 splat 写入深度纹理，景深等后处理可按 splat 深度模糊（移动端渲染器不支持）
+   */
+  setSplatDepthWrite(alphaThreshold: number): void {
+    this.gsRenderer?.setDepthWrite?.(alphaThreshold);
+  }
+
+  /**
+   * 获取 splat 深度输出的不透明度阈值
+   */
+  getSplatDepthWrite(): number {
+    return this.gsRenderer?.getDepthWrite?.() ?? 0;
+  }
+
+  /**
+   * 设置 splat 点显示模式（移动端渲染器不支持，调用无效果）
+   */
+  setSplatPointMode(mode: SplatPointMode): void {
+    this.gsRenderer?.setPointMode?.(mode);
+  }
+
+  /**
+   * 获取 splat 点显示模式
+   */
+  getSplatPointMode(): SplatPointMode {
+    return this.gsRenderer?.getPointMode?.() ?? SplatPointMode.Off;
+  }
+
+  /**
+   * 设置 splat 点尺寸（未指定的字段保持不变）
+   */
+  setSplatPointSize(config: Partial<SplatPointSizeConfig>): void {
+    this.gsRenderer?.setPointSizeConfig?.(config);
+  }
+
+  /**
+   * 获取 splat 点尺寸配置
+   */
+  getSplatPointSize(): SplatPointSizeConfig {
+    return this.gsRenderer?.getPointSizeConfig?.() ?? { ...DEFAULT_SPLAT_POINT_SIZE_CONFIG };
+  }
+
+  /**
+   * 设置 splat 中心点叠加的子采样（未指定的字段保持不变）
+   */
+  setSplatCentersConfig(config: Partial<SplatCentersConfig>): void {
+    this.gsRenderer?.setCentersConfig?.(config);
+  }
+
+  /**
+   * 获取 splat 中心点叠加配置
+   */
+  getSplatCentersConfig(): SplatCentersConfig {
+    return this.gsRenderer?.getCentersConfig?.() ?? { ...DEFAULT_SPLAT_CENTERS_CONFIG };
+  }
+
+  /**
+   * 设置中心点叠加使用的光标位置（画布像素坐标），null 表示光标离开画布
+   */
+  setSplatCentersCursor(cursor: [number, number] | null): void {
+    this.gsRenderer?.setCentersCursor?.(cursor);
+  }
+
+  // ============================================
+  // 撤销 / 重做
+  // ============================================
+
+  /**
+   * 获取 splat 编辑历史
+   */
+  getSplatEditHistory(): SplatEditHistory {
+    return this.splatEditHistory;
+  }
+
+  /**
+   * 撤销最近一次 splat 编辑
+   */
+  undoSplatEdit(): boolean {
+    return this.splatEditHistory.undo();
+  }
+
+  /**
+   * 重做最近一次撤销的 splat 编辑
+   */
+  redoSplatEdit(): boolean {
+    return this.splatEditHistory.redo();
+  }
+
+  canUndoSplatEdit(): boolean {
+    return this.splatEditHistory.canUndo();
+  }
+
+  canRedoSplatEdit(): boolean {
+    return this.splatEditHistory.canRedo();
+  }
+
+  // ============================================
+  // Bounding Box 查询
+  // ============================================
+
+  /**
+   * 获取 Mesh 的组合 bounding box
+   */
+  getMeshBoundingBox(): MeshBoundingBox | null {
+    return this.meshRenderer.getCombinedBoundingBox();
+  }
+
+  /**
+   * 获取 Splat 的 bounding box
+   */
+  getSplatBoundingBox(): BoundingBox | null {
+    return this.gsRenderer?.getBoundingBox() ?? null;
+  }
+
+  /**
+   * 获取 Splat 位置相对的原点（f64 精度加载时为数据质心）
+   */
+  getSplatOrigin(): Vec3Tuple {
+    return this.gsRenderer?.getOrigin?.() ?? [0, 0, 0];
+  }
+
+  /**
+   * 计算 Splat 位置统计（包含原点）
+   */
+  getSplatStatistics(precision?: SplatPrecision): SplatStatistics | null {
+    return this.gsRenderer?.getStatistics?.(precision) ?? null;
   }
 
   /**
@@ -332,6 +1549,7 @@ export class SceneManager {
    */
   destroy(): void {
     this.clearSplats();
+    this.clearSplatLayers();
     // 注意：meshRenderer 的销毁由 App 负责
   }
 }
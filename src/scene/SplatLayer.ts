@@ -0,0 +1,355 @@
+/**
+ * SplatLayer - splat 实体的分层组织
+ *
+ * 图层包含若干 splat 实体（渲染器）和子图层，组成一棵树：
+ * - 可见性：祖先图层隐藏时整棵子树隐藏
+ * - 变换：图层的位置 / 旋转 / 缩放 / 中心点逐级相乘，作为实体的父级变换
+ * - 不透明度：逐级相乘后作为实体的整体不透明度倍数
+ * - 渲染顺序：renderOrder 小的先绘制；不同实体之间不做逐 splat 排序，
+ *   顺序相同时按包围盒中心到相机的距离由远到近绘制
+ * 根图层由 SceneManager 持有，大型项目可按扫描 / 物体分组，不必自己实现分组逻辑
+ */
+
+import type { IGSSplatRenderer } from "../gs/IGSSplatRenderer";
+import type { Vec3Tuple } from "../types";
+import { composeTransform, multiplyTransforms } from "../utils/transform";
+
+/**
+ * 图层中一个待绘制的实体
+ */
+export interface SplatLayerEntry {
+  renderer: IGSSplatRenderer;
+  layer: SplatLayer;
+  /** 所在图层的渲染顺序（逐级累加） */
+  renderOrder: number;
+}
+
+/**
+ * splat 图层
+ */
+export class SplatLayer {
+  readonly name: string;
+  private parent: SplatLayer | null = null;
+  private children: SplatLayer[] = [];
+  private entities: IGSSplatRenderer[] = [];
+
+  private visible: boolean = true;
+  private opacity: number = 1;
+  private renderOrder: number = 0;
+
+  private position: Vec3Tuple = [0, 0, 0];
+  private rotation: Vec3Tuple = [0, 0, 0];
+  private scale: Vec3Tuple = [1, 1, 1];
+  private pivot: Vec3Tuple = [0, 0, 0];
+  private localMatrix: Float32Array = composeTransform([0, 0, 0], [0, 0, 0], [1, 1, 1], [0, 0, 0]);
+  private worldMatrix: Float32Array = new Float32Array(this.localMatrix);
+
+  constructor(name: string = "layer") {
+    this.name = name;
+  }
+
+  // ============================================
+  // 层级
+  // ============================================
+
+  /**
+   * 创建子图层
+   */
+  createLayer(name: string): SplatLayer {
+    const layer = new SplatLayer(name);
+    this.addLayer(layer);
+    return layer;
+  }
+
+  /**
+   * 添加子图层（会先从原父图层移除）
+   */
+  addLayer(layer: SplatLayer): void {
+    for (let p: SplatLayer | null = this; p; p = p.parent) {
+      if (p === layer) {
+        throw new Error(`[SplatLayer] 不能把图层 "${layer.name}" 添加到自己的子图层中`);
+      }
+    }
+    layer.parent?.removeLayer(layer);
+    layer.parent = this;
+    this.children.push(layer);
+    layer.applyHierarchy();
+  }
+
+  /**
+   * 移除子图层（不销毁其中的实体），返回是否移除
+   */
+  removeLayer(layer: SplatLayer): boolean {
+    const index = this.children.indexOf(layer);
+    if (index < 0) {
+      return false;
+    }
+    this.children.splice(index, 1);
+    layer.parent = null;
+    layer.applyHierarchy();
+    return true;
+  }
+
+  /**
+   * 按名称查找图层（包括自身，深度优先）
+   */
+  findLayer(name: string): SplatLayer | null {
+    if (this.name === name) {
+      return this;
+    }
+    for (const child of this.children) {
+      const found = child.findLayer(name);
+      if (found) {
+        return found;
+      }
+    }
+    return null;
+  }
+
+  /**
+   * 查找包含实体的图层（包括自身，深度优先）
+   */
+  findEntityLayer(renderer: IGSSplatRenderer): SplatLayer | null {
+    if (this.entities.includes(renderer)) {
+      return this;
+    }
+    for (const child of this.children) {
+      const found = child.findEntityLayer(renderer);
+      if (found) {
+        return found;
+      }
+    }
+    return null;
+  }
+
+  getParent(): SplatLayer | null {
+    return this.parent;
+  }
+
+  getLayers(): readonly SplatLayer[] {
+    return this.children;
+  }
+
+  // ============================================
+  // 实体
+  // ============================================
+
+  /**
+   * 添加 splat 实体，实体获得本图层的父级变换和不透明度
+   */
+  addEntity(renderer: IGSSplatRenderer): void {
+    if (this.entities.includes(renderer)) {
+      return;
+    }
+    this.entities.push(renderer);
+    this.applyToEntity(renderer);
+  }
+
+  /**
+   * 移除实体（不销毁），恢复其父级变换和不透明度，返回是否移除
+   */
+  removeEntity(renderer: IGSSplatRenderer): boolean {
+    const index = this.entities.indexOf(renderer);
+    if (index < 0) {
+      return false;
+    }
+    this.entities.splice(index, 1);
+    renderer.setParentMatrix?.(null);
+    renderer.setOpacity?.(1);
+    return true;
+  }
+
+  getEntities(): readonly IGSSplatRenderer[] {
+    return this.entities;
+  }
+
+  /**
+   * 本图层及所有子图层的实体（不考虑可见性）
+   */
+  getAllEntities(out: IGSSplatRenderer[] = []): IGSSplatRenderer[] {
+    out.push(...this.entities);
+    for (const child of this.children) {
+      child.getAllEntities(out);
+    }
+    return out;
+  }
+
+  /**
+   * 本图层及所有子图层的实体数量
+   */
+  getEntityCount(): number {
+    return this.children.reduce((sum, child) => sum + child.getEntityCount(), this.entities.length);
+  }
+
+  /**
+   * 本图层及所有子图层的 splat 数量
+   */
+  getSplatCount(): number {
+    return this.children.reduce(
+      (sum, child) => sum + child.getSplatCount(),
+      this.entities.reduce((sum, r) => sum + r.getSplatCount(), 0),
+    );
+  }
+
+  // ============================================
+  // 可见性 / 不透明度 / 渲染顺序
+  // ============================================
+
+  setVisible(visible: boolean): void {
+    this.visible = visible;
+  }
+
+  isVisible(): boolean {
+    return this.visible;
+  }
+
+  /**
+   * 自身及所有祖先图层都可见
+   */
+  isEffectivelyVisible(): boolean {
+    return this.visible && (this.parent?.isEffectivelyVisible() ?? true);
+  }
+
+  /**
+   * 设置不透明度倍数 (0~1)，与祖先图层相乘
+   */
+  setOpacity(opacity: number): void {
+    this.opacity = Math.min(1, Math.max(0, opacity));
+    this.applyHierarchy();
+  }
+
+  getOpacity(): number {
+    return this.opacity;
+  }
+
+  /**
+   * 与祖先图层相乘后的不透明度
+   */
+  getEffectiveOpacity(): number {
+    return this.opacity * (this.parent?.getEffectiveOpacity() ?? 1);
+  }
+
+  /**
+   * 设置渲染顺序（小的先绘制），与祖先图层累加
+   */
+  setRenderOrder(order: number): void {
+    this.renderOrder = order;
+  }
+
+  getRenderOrder(): number {
+    return this.renderOrder;
+  }
+
+  // ============================================
+  // 变换
+  // ============================================
+
+  setPosition(x: number, y: number, z: number): void {
+    this.position = [x, y, z];
+    this.updateLocalMatrix();
+  }
+
+  getPosition(): Vec3Tuple {
+    return [...this.position];
+  }
+
+  /**
+   * 设置旋转（欧拉角，弧度）
+   */
+  setRotation(x: number, y: number, z: number): void {
+    this.rotation = [x, y, z];
+    this.updateLocalMatrix();
+  }
+
+  getRotation(): Vec3Tuple {
+    return [...this.rotation];
+  }
+
+  setScale(x: number, y: number, z: number): void {
+    this.scale = [x, y, z];
+    this.updateLocalMatrix();
+  }
+
+  getScale(): Vec3Tuple {
+    return [...this.scale];
+  }
+
+  /**
+   * 设置旋转/缩放中心点（图层局部坐标）
+   */
+  setPivot(x: number, y: number, z: number): void {
+    this.pivot = [x, y, z];
+    this.updateLocalMatrix();
+  }
+
+  getPivot(): Vec3Tuple {
+    return [...this.pivot];
+  }
+
+  /**
+   * 图层世界矩阵（列主序，包含所有祖先图层的变换）
+   */
+  getWorldMatrix(): Float32Array {
+    return this.worldMatrix;
+  }
+
+  // ============================================
+  // 渲染
+  // ============================================
+
+  /**
+   * 收集可见且不透明度大于 0 的实体
+   */
+  collectVisible(out: SplatLayerEntry[] = [], parentOrder: number = 0): SplatLayerEntry[] {
+    if (!this.visible || this.opacity <= 0) {
+      return out;
+    }
+    const order = parentOrder + this.renderOrder;
+    for (const renderer of this.entities) {
+      out.push({ renderer, layer: this, renderOrder: order });
+    }
+    for (const child of this.children) {
+      child.collectVisible(out, order);
+    }
+    return out;
+  }
+
+  /**
+   * 销毁本图层及所有子图层的实体，并从父图层移除
+   */
+  destroy(): void {
+    for (const child of [...this.children]) {
+      child.destroy();
+    }
+    for (const renderer of this.entities) {
+      renderer.destroy();
+    }
+    this.entities = [];
+    this.parent?.removeLayer(this);
+  }
+
+  private updateLocalMatrix(): void {
+    composeTransform(this.position, this.rotation, this.scale, this.pivot, this.localMatrix);
+    this.applyHierarchy();
+  }
+
+  // 重新计算世界矩阵和不透明度并下发到整棵子树
+  private applyHierarchy(): void {
+    if (this.parent) {
+      multiplyTransforms(this.parent.worldMatrix, this.localMatrix, this.worldMatrix);
+    } else {
+      this.worldMatrix.set(this.localMatrix);
+    }
+    for (const renderer of this.entities) {
+      this.applyToEntity(renderer);
+    }
+    for (const child of this.children) {
+      child.applyHierarchy();
+    }
+  }
+
+  private applyToEntity(renderer: IGSSplatRenderer): void {
+    renderer.setParentMatrix?.(this.worldMatrix);
+    renderer.setOpacity?.(this.getEffectiveOpacity());
+  }
+}
@@ -18,5 +18,5 @@ export type { MaterialData } from './material';
 export { DEFAULT_MATERIAL, DEFAULT_OBJ_MATERIAL } from './material';
 
 // Splat 类型
-export { SHMode } from './splat';
-export type { RendererCapabilities } from './splat';
+export { SHMode, SplatVisMode, Colormap, SplatRasterMode, SplatBlendMode, SplatPointMode, SplatDepthKeyMode } from './splat';
+export type { RendererCapabilities, SplatPickResult, SplatDepthQueryMode, SplatDepthResult } from './splat';
@@ -12,6 +12,92 @@ export enum SHMode {
   L3 = 3,  // 完整 SH（最高质量）
 }
 
+/**
+ * 可视化模式枚举 - 决定 splat 的颜色来源
+ */
+export enum SplatVisMode {
+  Color = 0,  // 原始颜色
+  Label = 1,  // 按语义标签着色
+  Scalar = 2, // 按标量属性 + colormap 着色
+  Volume = 3, // 标量经传递函数映射为颜色和不透明度（点云体渲染）
+}
+
+/**
+ * 标量属性可视化使用的 colormap
+ */
+export enum Colormap {
+  Viridis = 0,
+  Turbo = 1,
+}
+
+/**
+ * 光栅化模式枚举
+ */
+export enum SplatRasterMode {
+  Quad = 0,  // 实例化 quad + 硬件混合（默认）
+  Tile = 1,  // compute tile 分箱光栅化
+  Stochastic = 2,  // 随机透明度 (hashed alpha test)，无需排序，带噪声
+}
+
+/**
+ * 混合模式枚举
+ */
+export enum SplatBlendMode {
+  Standard = 0,  // 预乘 alpha over 混合（默认，需要排序）
+  Additive = 1,  // 加法混合，适合发光 / 密度类可视化
+  Max = 2,  // 逐通道取最大值，类似 MIP (最大密度投影)
+}
+
+/**
+ * 点显示模式（检查 splat 分布）
+ */
+export enum SplatPointMode {
+  Off = 0,  // 正常高斯渲染（默认）
+  Points = 1,  // 每个 splat 只绘制为屏幕空间圆点
+  Centers = 2,  // 高斯渲染之上叠加 splat 中心点
+}
+
+/**
+ * 深度排序键模式
+ */
+export enum SplatDepthKeyMode {
+  Float = 0,  // 视图空间深度的浮点位编码（默认）
+  Stable64 = 1,  // (深度, splat 索引) 64 位键：先按索引排序再按深度稳定排序，深度相同的 splat 顺序逐帧一致
+}
+
+/**
+ * splat 拾取结果
+ */
+export interface SplatPickResult {
+  /** 命中的 splat 索引 */
+  index: number;
+  /** 命中点的 NDC 深度（与当前深度约定一致） */
+  depth: number;
+  /** 命中点的世界坐标 */
+  worldPosition: [number, number, number];
+}
+
+/**
+ * 像素深度查询模式
+ * - blended：按不透明度混合的深度 Σ(αᵢTᵢdᵢ) / Σ(αᵢTᵢ)，与画面中看到的表面一致，适合对焦和测量
+ * - nearest：最近的命中 splat 的深度（与拾取相同）
+ */
+export type SplatDepthQueryMode = "blended" | "nearest";
+
+/**
+ * 像素深度查询结果
+ */
+export interface SplatDepthResult {
+  /** NDC 深度（与当前深度约定一致） */
+  depth: number;
+  /** 视图空间距离 */
+  distance: number;
+  /** 世界坐标 */
+  worldPosition: [number, number, number];
+  /** 像素的累计不透明度 (0~1)，nearest 模式为 1 */
+  coverage: number;
+}
+
 /**
  * 渲染器能力描述
  */
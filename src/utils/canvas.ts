@@ -0,0 +1,65 @@
+/**
+ * 画布坐标换算
+ *
+ * 指针事件是相对视口的 CSS 像素，拾取和选择使用画布像素（渲染目标分辨率）。
+ * 画布可能有 CSS 边框 / 内边距、被缩放显示或只占页面的一部分，
+ * 换算按内容区域（绘制区域）进行，而不是 getBoundingClientRect 的边框盒
+ */
+
+/**
+ * CSS 像素到画布像素的映射：canvasX = (clientX - left) * scaleX
+ */
+export interface CanvasPixelMapping {
+  /** 内容区域左上角（CSS 像素，相对视口） */
+  left: number;
+  top: number;
+  /** 每 CSS 像素对应的画布像素 */
+  scaleX: number;
+  scaleY: number;
+}
+
+/**
+ * 获取画布当前的坐标映射，画布不可见（尺寸为 0）时返回 null
+ */
+export function getCanvasPixelMapping(canvas: HTMLCanvasElement): CanvasPixelMapping | null {
+  const rect = canvas.getBoundingClientRect();
+  const style = getComputedStyle(canvas);
+  const px = (value: string) => parseFloat(value) || 0;
+  // 布局尺寸下的内容区域：clientWidth 包含内边距、不含边框
+  const offsetX = px(style.borderLeftWidth) + px(style.paddingLeft);
+  const offsetY = px(style.borderTopWidth) + px(style.paddingTop);
+  const width = canvas.clientWidth - px(style.paddingLeft) - px(style.paddingRight);
+  const height = canvas.clientHeight - px(style.paddingTop) - px(style.paddingBottom);
+  if (width <= 0 || height <= 0 || rect.width === 0 || rect.height === 0) {
+    return null;
+  }
+  // CSS transform 缩放时边框盒与布局尺寸不同，按比例换算到屏幕上
+  const zoomX = canvas.offsetWidth > 0 ? rect.width / canvas.offsetWidth : 1;
+  const zoomY = canvas.offsetHeight > 0 ? rect.height / canvas.offsetHeight : 1;
+  return {
+    left: rect.left + offsetX * zoomX,
+    top: rect.top + offsetY * zoomY,
+    scaleX: canvas.width / (width * zoomX),
+    scaleY: canvas.height / (height * zoomY),
+  };
+}
+
+/**
+ * CSS 像素坐标（相对视口）转换为画布像素坐标，在画布内容区域外时返回 null
+ */
+export function clientToCanvasPixels(
+  canvas: HTMLCanvasElement,
+  clientX: number,
+  clientY: number,
+): [number, number] | null {
+  const mapping = getCanvasPixelMapping(canvas);
+  if (!mapping) {
+    return null;
+  }
+  const x = (clientX - mapping.left) * mapping.scaleX;
+  const y = (clientY - mapping.top) * mapping.scaleY;
+  if (x < 0 || y < 0 || x >= canvas.width || y >= canvas.height) {
+    return null;
+  }
+  return [x, y];
+}
@@ -33,11 +33,11 @@ export function isMobileDevice(): boolean {
 /**
  * 获取推荐的设备像素比
  * 移动端限制 DPI 以避免性能问题
+ * @param maxDpr 像素比上限，省略时移动端 1.5、桌面端 3
  */
-export function getRecommendedDPR(): number {
-  const isMobile = isMobileDevice();
-  const maxDpr = isMobile ? 1.5 : 3;
-  return Math.min(window.devicePixelRatio || 1, maxDpr);
+export function getRecommendedDPR(maxDpr?: number): number {
+  const limit = maxDpr ?? (isMobileDevice() ? 1.5 : 3);
+  return Math.min(window.devicePixelRatio || 1, limit);
 }
 
 /**
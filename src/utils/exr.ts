@@ -0,0 +1,122 @@
+/**
+ * OpenEXR 编码（单部件、扫描线、无压缩）
+ *
+ * 只实现导出需要的最小子集：每个通道一个平面，像素类型为 UINT 或 FLOAT，
+ * 行从上到下（INCREASING_Y），无压缩时每个块一行。
+ * 通道名可以带层前缀（例如 "depth.Z"），外部工具按 "." 前的部分分层
+ */
+
+/**
+ * EXR 通道
+ */
+export interface EXRChannel {
+  /** 通道名 */
+  name: string;
+  /** 像素类型 */
+  type: "uint" | "float";
+  /** 像素数据（width * height，逐行从上到下） */
+  data: Uint32Array | Float32Array;
+}
+
+const EXR_MAGIC = 20000630;
+const PIXEL_TYPE = { uint: 0, float: 2 } as const;
+
+/**
+ * 编码为 EXR 文件
+ */
+export function encodeEXR(width: number, height: number, channels: ReadonlyArray<EXRChannel>): ArrayBuffer {
+  const pixelCount = width * height;
+  for (const channel of channels) {
+    if (channel.data.length !== pixelCount) {
+      throw new Error(`EXR 通道 ${channel.name} 的像素数量 (${channel.data.length}) 与尺寸不一致`);
+    }
+  }
+  // 规范要求通道按名称排序
+  const sorted = [...channels].sort((a, b) => (a.name < b.name ? -1 : a.name > b.name ? 1 : 0));
+
+  const header = new ByteWriter();
+  header.u32(EXR_MAGIC);
+  header.u32(2);
+
+  const channelList = new ByteWriter();
+  for (const channel of sorted) {
+    channelList.str(channel.name);
+    channelList.u32(PIXEL_TYPE[channel.type]);
+    channelList.u32(0); // pLinear + 保留字节
+    channelList.u32(1);
+    channelList.u32(1);
+  }
+  channelList.u8(0);
+  header.attribute("channels", "chlist", channelList.bytes());
+
+  const box = new ByteWriter();
+  box.u32(0);
+  box.u32(0);
+  box.u32(width - 1);
+  box.u32(height - 1);
+  header.attribute("compression", "compression", Uint8Array.of(0));
+  header.attribute("dataWindow", "box2i", box.bytes());
+  header.attribute("displayWindow", "box2i", box.bytes());
+  header.attribute("lineOrder", "lineOrder", Uint8Array.of(0));
+  header.attribute("pixelAspectRatio", "float", new Uint8Array(Float32Array.of(1).buffer));
+  header.attribute("screenWindowCenter", "v2f", new Uint8Array(8));
+  header.attribute("screenWindowWidth", "float", new Uint8Array(Float32Array.of(1).buffer));
+  header.u8(0);
+
+  const headerBytes = header.bytes();
+  // UINT 和 FLOAT 都是 4 字节
+  const lineSize = width * sorted.length * 4;
+  const chunkSize = 8 + lineSize;
+  const tableOffset = headerBytes.length;
+  const dataOffset = tableOffset + height * 8;
+  const buffer = new ArrayBuffer(dataOffset + height * chunkSize);
+  const bytes = new Uint8Array(buffer);
+  const view = new DataView(buffer);
+  bytes.set(headerBytes, 0);
+
+  for (let y = 0; y < height; y++) {
+    const chunkOffset = dataOffset + y * chunkSize;
+    view.setBigUint64(tableOffset + y * 8, BigInt(chunkOffset), true);
+    view.setInt32(chunkOffset, y, true);
+    view.setInt32(chunkOffset + 4, lineSize, true);
+    let offset = chunkOffset + 8;
+    for (const channel of sorted) {
+      const row = y * width;
+      if (channel.type === "uint") {
+        for (let x = 0; x < width; x++, offset += 4) view.setUint32(offset, channel.data[row + x], true);
+      } else {
+        for (let x = 0; x < width; x++, offset += 4) view.setFloat32(offset, channel.data[row + x], true);
+      }
+    }
+  }
+  return buffer;
+}
+
+// 小端字节写入
+class ByteWriter {
+  private chunks: number[] = [];
+
+  u8(value: number): void {
+    this.chunks.push(value & 0xff);
+  }
+
+  u32(value: number): void {
+    for (let i = 0; i < 4; i++) this.u8(value >>> (i * 8));
+  }
+
+  str(value: string): void {
+    for (const byte of new TextEncoder().encode(value)) this.u8(byte);
+    this.u8(0);
+  }
+
+  attribute(name: string, type: string, value: Uint8Array): void {
+    this.str(name);
+    this.str(type);
+    this.u32(value.length);
+    for (const byte of value) this.u8(byte);
+  }
+
+  bytes(): Uint8Array {
+    return Uint8Array.from(this.chunks);
+  }
+}
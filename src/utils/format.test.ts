@@ -0,0 +1,118 @@
+import { describe, it, expect } from 'vitest';
+import {
+  BINARY_FORMAT_HEADER_SIZE,
+  migrateBinaryFormat,
+  readBinaryFormatVersion,
+  writeBinaryFormatHeader,
+  type BinaryFormat,
+} from './format';
+import { SplatPVS, SPLAT_PVS_FORMAT, migratePVSv1ToV2 } from '../gs/SplatPVS';
+import type { SplatOctree } from '../gs/SplatOctree';
+
+// 根节点 + 3 个叶子
+const octree: SplatOctree = {
+  nodes: [
+    { min: [0, 0, 0], max: [4, 2, 2], start: 0, count: 3, children: [1, 2, 3] },
+    { min: [0, 0, 0], max: [1, 1, 1], start: 0, count: 1, children: [] },
+    { min: [1, 0, 0], max: [2, 1, 1], start: 1, count: 1, children: [] },
+    { min: [2, 0, 0], max: [3, 1, 1], start: 2, count: 1, children: [] },
+  ],
+  order: new Uint32Array([0, 1, 2]),
+  leafCount: 3,
+};
+
+// 2 x 1 x 2 个格子，其中一个没有结果
+const cells: (Uint8Array | null)[] = [
+  new Uint8Array([0b101]),
+  new Uint8Array([0b010]),
+  null,
+  new Uint8Array([0b111]),
+];
+
+function createPVS(): SplatPVS {
+  return new SplatPVS(octree, [0, 0, 0], 1.5, [2, 1, 2], cells.map((bits) => bits && bits.slice()));
+}
+
+/**
+ * 按 v1 布局写出：[magic "PVS1"][leafCount][dims x3][min x3][cellSize][present][data]
+ */
+function toV1(v2: ArrayBuffer): ArrayBuffer {
+  const v2HeaderSize = BINARY_FORMAT_HEADER_SIZE + 36;
+  const result = new ArrayBuffer(v2.byteLength - v2HeaderSize + 36);
+  const view = new DataView(result);
+  view.setUint32(0, 0x31535650, true);
+  view.setUint32(4, new DataView(v2).getUint32(8, true), true);
+  new Uint8Array(result, 8, 28).set(new Uint8Array(v2, 16, 28));
+  new Uint8Array(result, 36).set(new Uint8Array(v2, v2HeaderSize));
+  return result;
+}
+
+function createFormat(migrations: BinaryFormat['migrations'], version: number = 3): BinaryFormat {
+  return { name: 'Test', magic: 0x54534554, version, migrations };
+}
+
+function createBuffer(format: BinaryFormat, version: number, payload: number = 0): ArrayBuffer {
+  const buffer = new ArrayBuffer(BINARY_FORMAT_HEADER_SIZE + 4);
+  const view = new DataView(buffer);
+  writeBinaryFormatHeader(view, format, version);
+  view.setUint32(BINARY_FORMAT_HEADER_SIZE, payload, true);
+  return buffer;
+}
+
+describe('SplatPVS 格式迁移', () => {
+  it('v1 数据识别为版本 1', () => {
+    const v1 = toV1(createPVS().toArrayBuffer());
+    expect(readBinaryFormatVersion(v1, SPLAT_PVS_FORMAT)).toBe(1);
+  });
+
+  it('v1 迁移到 v2 后格子结果不变', () => {
+    const v2 = createPVS().toArrayBuffer();
+    const v1 = toV1(v2);
+
+    const migrated = migratePVSv1ToV2(v1);
+    const view = new DataView(migrated);
+    expect(readBinaryFormatVersion(migrated, SPLAT_PVS_FORMAT)).toBe(2);
+    expect(migrated.byteLength).toBe(v2.byteLength);
+    // 节点数量未知，记为 0
+    expect(view.getUint32(12, true)).toBe(0);
+
+    // 读取后重新写出与直接写出的 v2 相同（包括每个格子的位集）
+    const loaded = SplatPVS.fromArrayBuffer(v1, octree);
+    expect(loaded.getCellCount()).toBe(4);
+    expect(new Uint8Array(loaded.toArrayBuffer())).toEqual(new Uint8Array(v2));
+  });
+
+  it('当前版本的数据原样返回', () => {
+    const v2 = createPVS().toArrayBuffer();
+    expect(migrateBinaryFormat(v2, SPLAT_PVS_FORMAT)).toBe(v2);
+  });
+});
+
+describe('migrateBinaryFormat', () => {
+  it('按版本逐级迁移', () => {
+    const format = createFormat({
+      1: (buffer) => createBuffer(format, 2, new DataView(buffer).getUint32(BINARY_FORMAT_HEADER_SIZE, true) + 10),
+      2: (buffer) => createBuffer(format, 3, new DataView(buffer).getUint32(BINARY_FORMAT_HEADER_SIZE, true) * 2),
+    });
+    const result = migrateBinaryFormat(createBuffer(format, 1, 1), format);
+    expect(readBinaryFormatVersion(result, format)).toBe(3);
+    expect(new DataView(result).getUint32(BINARY_FORMAT_HEADER_SIZE, true)).toBe(22);
+  });
+
+  it('magic 不匹配时抛出错误', () => {
+    const format = createFormat({});
+    const other = createBuffer({ ...format, magic: 0x12345678 }, 3);
+    expect(() => migrateBinaryFormat(other, format)).toThrow(/数据格式不正确/);
+    expect(() => migrateBinaryFormat(new ArrayBuffer(2), format)).toThrow(/数据格式不正确/);
+  });
+
+  it('版本高于当前版本时抛出错误', () => {
+    const format = createFormat({});
+    expect(() => migrateBinaryFormat(createBuffer(format, 4), format)).toThrow(/高于支持的版本/);
+  });
+
+  it('缺少迁移函数时抛出错误', () => {
+    const format = createFormat({ 2: (buffer) => buffer });
+    expect(() => migrateBinaryFormat(createBuffer(format, 1), format)).toThrow(/不支持从版本 1 迁移/);
+  });
+});
@@ -0,0 +1,83 @@
+/**
+ * 带版本号的二进制格式
+ *
+ * 序列化数据（预计算结果、缓存等）以 [magic u32][version u32] 开头，读取时先按版本号
+ * 逐级迁移到当前版本（v1 -> v2 -> ...），再交给当前版本的解析代码。
+ * 格式变化时：递增版本号并注册上一版本的迁移函数，旧文件升级库之后仍然可以读取
+ */
+
+/**
+ * 格式头（magic + version，共 8 字节，小端）
+ */
+export interface BinaryFormatHeader {
+  magic: number;
+  version: number;
+}
+
+/** 格式头字节数 */
+export const BINARY_FORMAT_HEADER_SIZE = 8;
+
+/**
+ * 迁移函数：输入 from 版本的完整数据（含格式头），返回 from + 1 版本的完整数据
+ */
+export type BinaryFormatMigration = (buffer: ArrayBuffer) => ArrayBuffer;
+
+/**
+ * 二进制格式描述
+ */
+export interface BinaryFormat {
+  /** 名称（用于错误信息） */
+  name: string;
+  magic: number;
+  /** 当前版本 */
+  version: number;
+  /** 迁移函数：键为源版本 */
+  migrations: Record<number, BinaryFormatMigration>;
+  /**
+   * 识别没有格式头的旧数据（例如加入版本号之前的格式），返回其版本号，无法识别返回 null
+   */
+  detectLegacy?: (view: DataView) => number | null;
+}
+
+/**
+ * 写入格式头
+ */
+export function writeBinaryFormatHeader(view: DataView, format: BinaryFormat, version: number = format.version): void {
+  view.setUint32(0, format.magic, true);
+  view.setUint32(4, version, true);
+}
+
+/**
+ * 读取数据的版本号，magic 不匹配且不是可识别的旧数据时抛出错误
+ */
+export function readBinaryFormatVersion(buffer: ArrayBuffer, format: BinaryFormat): number {
+  const view = new DataView(buffer);
+  if (buffer.byteLength >= BINARY_FORMAT_HEADER_SIZE && view.getUint32(0, true) === format.magic) {
+    return view.getUint32(4, true);
+  }
+  const legacy = buffer.byteLength >= 4 ? format.detectLegacy?.(view) ?? null : null;
+  if (legacy === null) {
+    throw new Error(`[${format.name}] 数据格式不正确`);
+  }
+  return legacy;
+}
+
+/**
+ * 把数据迁移到当前版本（已是当前版本时原样返回）
+ * 版本号高于当前版本（新版库写出的数据）或缺少迁移函数时抛出错误
+ */
+export function migrateBinaryFormat(buffer: ArrayBuffer, format: BinaryFormat): ArrayBuffer {
+  let version = readBinaryFormatVersion(buffer, format);
+  if (version > format.version) {
+    throw new Error(`[${format.name}] 数据版本 ${version} 高于支持的版本 ${format.version}`);
+  }
+  while (version < format.version) {
+    const migrate = format.migrations[version];
+    if (!migrate) {
+      throw new Error(`[${format.name}] 不支持从版本 ${version} 迁移`);
+    }
+    buffer = migrate(buffer);
+    version++;
+  }
+  return buffer;
+}
@@ -25,3 +25,35 @@ export {
   createTextureFromImageBitmap,
   TextureCache,
 } from './texture';
+
+// 变换检查
+export {
+  analyzeTransform,
+  orthonormalizeTransform,
+  validateTransform,
+  composeTransform,
+  multiplyTransforms,
+  MIN_TRANSFORM_SCALE,
+  SHEAR_TOLERANCE,
+} from './transform';
+export type { TransformIssue } from './transform';
+
+// EXR 编码
+export { encodeEXR } from './exr';
+export type { EXRChannel } from './exr';
+
+// PNG 编码和下载
+export { encodePNG, downloadBlob } from './png';
+
+// 带版本号的二进制格式
+export {
+  BINARY_FORMAT_HEADER_SIZE,
+  writeBinaryFormatHeader,
+  readBinaryFormatVersion,
+  migrateBinaryFormat,
+} from './format';
+export type { BinaryFormat, BinaryFormatHeader, BinaryFormatMigration } from './format';
+
+// 画布坐标换算
+export { getCanvasPixelMapping, clientToCanvasPixels } from './canvas';
+export type { CanvasPixelMapping } from './canvas';
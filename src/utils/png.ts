@@ -0,0 +1,29 @@
+/**
+ * PNG 编码和下载
+ */
+
+/**
+ * 把 RGBA8（非预乘）像素编码为 PNG
+ */
+export async function encodePNG(pixels: Uint8ClampedArray, width: number, height: number): Promise<Blob> {
+  const canvas = new OffscreenCanvas(width, height);
+  const context = canvas.getContext("2d");
+  if (!context) {
+    throw new Error("无法创建 2D 上下文");
+  }
+  context.putImageData(new ImageData(pixels, width, height), 0, 0);
+  return canvas.convertToBlob({ type: "image/png" });
+}
+
+/**
+ * 以文件名下载 Blob（浏览器保存文件）
+ */
+export function downloadBlob(blob: Blob, filename: string): void {
+  const url = URL.createObjectURL(blob);
+  const link = document.createElement("a");
+  link.href = url;
+  link.download = filename;
+  link.click();
+  // 等下载开始后再释放
+  setTimeout(() => URL.revokeObjectURL(url), 0);
+}
@@ -0,0 +1,229 @@
+/**
+ * 变换矩阵检查工具函数
+ *
+ * splat 协方差投影依赖模型矩阵的 3x3 线性部分，
+ * 零缩放会让椭球退化，剪切会让椭球变形，需要在使用前检测出来。
+ * 渲染器自身由位置 / 旋转 / 缩放构造的 TRS 矩阵不会产生剪切，
+ * 剪切只来自外部传入的矩阵（父级变换，带旋转的非均匀缩放父级与子级组合时会出现）
+ */
+
+/**
+ * 变换问题类型
+ * - non-finite: 矩阵包含 NaN / Infinity
+ * - zero-scale: 某个轴的缩放接近 0
+ * - shear: 轴之间不正交（剪切）
+ */
+export type TransformIssue = "non-finite" | "zero-scale" | "shear";
+
+/** 视为零缩放的轴长度阈值 */
+export const MIN_TRANSFORM_SCALE = 1e-6;
+
+/** 视为剪切的轴夹角余弦阈值 */
+export const SHEAR_TOLERANCE = 1e-4;
+
+/**
+ * 检查列主序 4x4 矩阵线性部分的问题
+ * @param checkShear 是否检查剪切（纯 TRS 矩阵不需要）
+ * @returns 问题列表，为空表示矩阵正常
+ */
+export function analyzeTransform(matrix: ArrayLike<number>, checkShear: boolean = true): TransformIssue[] {
+  for (let i = 0; i < 16; i++) {
+    if (!Number.isFinite(matrix[i])) {
+      return ["non-finite"];
+    }
+  }
+
+  const issues: TransformIssue[] = [];
+  const axes = getAxes(matrix);
+  const lengths = axes.map((a) => Math.hypot(a[0], a[1], a[2]));
+
+  if (lengths.some((l) => l < MIN_TRANSFORM_SCALE)) {
+    issues.push("zero-scale");
+    // 零长度轴无法判断夹角
+    return issues;
+  }
+  if (!checkShear) {
+    return issues;
+  }
+
+  for (let i = 0; i < 3; i++) {
+    for (let j = i + 1; j < 3; j++) {
+      const cos = dot(axes[i], axes[j]) / (lengths[i] * lengths[j]);
+      if (Math.abs(cos) > SHEAR_TOLERANCE) {
+        issues.push("shear");
+        return issues;
+      }
+    }
+  }
+
+  return issues;
+}
+
+/**
+ * 正交化矩阵的线性部分（原地修改）
+ * 保留各轴长度（缩放）和平移，Gram-Schmidt 去除剪切，
+ * 零长度轴保持为零（不臆造缩放），
+ * 非有限值的矩阵重置线性部分为单位矩阵
+ */
+export function orthonormalizeTransform(matrix: Float32Array): Float32Array {
+  for (let i = 0; i < 16; i++) {
+    if (!Number.isFinite(matrix[i])) {
+      matrix.set([1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0], 0);
+      for (let j = 12; j < 15; j++) {
+        if (!Number.isFinite(matrix[j])) matrix[j] = 0;
+      }
+      matrix[15] = 1;
+      return matrix;
+    }
+  }
+
+  const axes = getAxes(matrix);
+  const lengths = axes.map((a) => Math.hypot(a[0], a[1], a[2]));
+
+  // 按长度从大到小正交化，尽量保留主要方向
+  const order = [0, 1, 2].sort((a, b) => lengths[b] - lengths[a]);
+  const basis: number[][] = [];
+  for (const index of order) {
+    let v = axes[index].slice();
+    for (const b of basis) {
+      const d = dot(v, b);
+      v = [v[0] - d * b[0], v[1] - d * b[1], v[2] - d * b[2]];
+    }
+    let len = Math.hypot(v[0], v[1], v[2]);
+    if (len < 1e-8) {
+      v = fallbackAxis(basis);
+      len = Math.hypot(v[0], v[1], v[2]);
+    }
+    const unit = [v[0] / len, v[1] / len, v[2] / len];
+    basis.push(unit);
+    axes[index] = unit;
+  }
+
+  for (let i = 0; i < 3; i++) {
+    matrix[i * 4] = axes[i][0] * lengths[i];
+    matrix[i * 4 + 1] = axes[i][1] * lengths[i];
+    matrix[i * 4 + 2] = axes[i][2] * lengths[i];
+  }
+  return matrix;
+}
+
+function getAxes(m: ArrayLike<number>): number[][] {
+  return [
+    [m[0], m[1], m[2]],
+    [m[4], m[5], m[6]],
+    [m[8], m[9], m[10]],
+  ];
+}
+
+function dot(a: number[], b: number[]): number {
+  return a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+}
+
+/**
+ * 选取与已有基向量正交的方向
+ */
+function fallbackAxis(basis: number[][]): number[] {
+  if (basis.length === 2) {
+    const [a, b] = basis;
+    return [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]];
+  }
+  const candidates = [[1, 0, 0], [0, 1, 0], [0, 0, 1]];
+  for (const c of candidates) {
+    let v = c;
+    for (const b of basis) {
+      const d = dot(v, b);
+      v = [v[0] - d * b[0], v[1] - d * b[1], v[2] - d * b[2]];
+    }
+    if (Math.hypot(v[0], v[1], v[2]) > 0.5) {
+      return v;
+    }
+  }
+  return [0, 0, 1];
+}
+
+/**
+ * 检查变换并输出警告，可选自动修正
+ * 自动修正只处理非有限值和剪切；零缩放只警告（修正到任何最小值都仍然退化，也可能是有意压扁）
+ * @param entityId 用于警告信息的实体标识
+ * @param previous 上一次检测到的问题，问题未变化时不重复警告
+ * @param checkShear 是否检查剪切，只有包含外部传入矩阵时需要
+ * @returns 本次检测到的问题（修正前）
+ */
+export function validateTransform(
+  matrix: Float32Array,
+  entityId: string,
+  autoCorrect: boolean,
+  previous: TransformIssue[] = [],
+  checkShear: boolean = true,
+): TransformIssue[] {
+  const issues = analyzeTransform(matrix, checkShear);
+  if (issues.length === 0) {
+    return issues;
+  }
+
+  const correct = autoCorrect && (issues.includes("non-finite") || issues.includes("shear"));
+  if (issues.join(",") !== previous.join(",")) {
+    console.warn(
+      `[${entityId}] 变换矩阵存在问题 (${issues.join(", ")})，splat 椭球会退化或变形` +
+      (correct ? "，已自动修正" : ""),
+    );
+  }
+  if (correct) {
+    orthonormalizeTransform(matrix);
+  }
+  return issues;
+}
+
+/**
+ * 由位置、欧拉角旋转（弧度，R = Rz * Ry * Rx）、缩放和中心点构造列主序模型矩阵
+ * 变换顺序与 splat 渲染器一致: T * Tp * R * S * Tp^-1
+ */
+export function composeTransform(
+  position: ArrayLike<number>,
+  rotation: ArrayLike<number>,
+  scale: ArrayLike<number>,
+  pivot: ArrayLike<number>,
+  out: Float32Array = new Float32Array(16),
+): Float32Array {
+  const cx = Math.cos(rotation[0]), sx = Math.sin(rotation[0]);
+  const cy = Math.cos(rotation[1]), sy = Math.sin(rotation[1]);
+  const cz = Math.cos(rotation[2]), sz = Math.sin(rotation[2]);
+
+  const r = [
+    cy * cz, sx * sy * cz - cx * sz, cx * sy * cz + sx * sz,
+    cy * sz, sx * sy * sz + cx * cz, cx * sy * sz - sx * cz,
+    -sy, sx * cy, cx * cy,
+  ];
+  for (let row = 0; row < 3; row++) {
+    for (let col = 0; col < 3; col++) {
+      out[col * 4 + row] = r[row * 3 + col] * scale[col];
+    }
+    // 平移 = position + (I - RS) * pivot
+    out[12 + row] = position[row] + pivot[row]
+      - (out[row] * pivot[0] + out[4 + row] * pivot[1] + out[8 + row] * pivot[2]);
+  }
+  out[3] = 0; out[7] = 0; out[11] = 0; out[15] = 1;
+  return out;
+}
+
+/**
+ * 列主序 4x4 矩阵相乘 out = a * b（out 可以与 a 或 b 相同）
+ */
+export function multiplyTransforms(
+  a: ArrayLike<number>,
+  b: ArrayLike<number>,
+  out: Float32Array = new Float32Array(16),
+): Float32Array {
+  const result = new Float32Array(16);
+  for (let col = 0; col < 4; col++) {
+    for (let row = 0; row < 4; row++) {
+      result[col * 4 + row] =
+        a[row] * b[col * 4] +
+        a[row + 4] * b[col * 4 + 1] +
+        a[row + 8] * b[col * 4 + 2] +
+        a[row + 12] * b[col * 4 + 3];
+    }
+  }
+  out.set(result);
+  return out;
+}
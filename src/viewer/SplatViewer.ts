@@ -0,0 +1,216 @@
+/**
+ * SplatViewer - 嵌入页面的查看器
+ *
+ * 在 App 之上提供一组精简、稳定的接口，网页集成方不需要了解渲染器和场景管理的细节：
+ * create 创建画布并启动渲染循环，loadUrl 按扩展名加载模型，setRenderingConfig 调整显示，
+ * pick 拾取指针下的 splat，onLoaded 监听加载完成，setCameraPose 控制相机。
+ * 需要更多功能时可以通过 getApp() 使用完整接口
+ */
+
+import { App, type ProgressCallback } from "../App";
+import { clientToCanvasPixels } from "../utils/canvas";
+import type { SplatVisMode, SplatBlendMode, SplatRasterMode, SplatPickResult, Vec3Tuple } from "../types";
+
+/**
+ * 查看器显示配置（只修改提供的字段）
+ */
+export interface SplatViewerRenderingConfig {
+  /** SH 阶数 */
+  shDegree?: 0 | 1 | 2 | 3;
+  /** 可视化模式（颜色、深度、法线等） */
+  visMode?: SplatVisMode | string;
+  blendMode?: SplatBlendMode;
+  rasterMode?: SplatRasterMode;
+  /** 背景颜色（十六进制，例如 "#1a1a26"） */
+  background?: string;
+  /** 显示地面网格 */
+  grid?: boolean;
+}
+
+/**
+ * 相机位姿
+ */
+export interface SplatViewerCameraPose {
+  position: Vec3Tuple;
+  target: Vec3Tuple;
+  /** 上方向，默认不变 */
+  up?: Vec3Tuple;
+  /** 垂直视场角（弧度），默认不变 */
+  fovY?: number;
+}
+
+/**
+ * 加载完成的信息
+ */
+export interface SplatViewerLoadedEvent {
+  url: string;
+  splatCount: number;
+}
+
+export type SplatViewerLoadedCallback = (event: SplatViewerLoadedEvent) => void;
+
+/**
+ * 查看器选项
+ */
+export interface SplatViewerOptions {
+  /** 加载完成后自动取景 */
+  frameOnLoad: boolean;
+  /** 初始显示配置（每次加载模型后重新应用） */
+  rendering: SplatViewerRenderingConfig;
+}
+
+export const DEFAULT_SPLAT_VIEWER_OPTIONS: SplatViewerOptions = {
+  frameOnLoad: true,
+  rendering: {},
+};
+
+/**
+ * SplatViewer - 查看器
+ */
+export class SplatViewer {
+  private app: App;
+  private canvas: HTMLCanvasElement;
+  // 由查看器创建的画布（destroy 时移除）
+  private ownedCanvas: boolean;
+  private options: SplatViewerOptions;
+  private loadedListeners: Set<SplatViewerLoadedCallback> = new Set();
+
+  private constructor(app: App, canvas: HTMLCanvasElement, ownedCanvas: boolean, options: SplatViewerOptions) {
+    this.app = app;
+    this.canvas = canvas;
+    this.ownedCanvas = ownedCanvas;
+    this.options = options;
+  }
+
+  /**
+   * 创建查看器：传入画布直接使用，传入其它元素时在其中创建铺满的画布
+   */
+  static async create(target: HTMLCanvasElement | HTMLElement, options: Partial<SplatViewerOptions> = {}): Promise<SplatViewer> {
+    const ownedCanvas = !(target instanceof HTMLCanvasElement);
+    let canvas: HTMLCanvasElement;
+    if (target instanceof HTMLCanvasElement) {
+      canvas = target;
+    } else {
+      canvas = document.createElement("canvas");
+      canvas.style.width = "100%";
+      canvas.style.height = "100%";
+      canvas.style.display = "block";
+      target.appendChild(canvas);
+    }
+
+    const app = new App(canvas);
+    try {
+      await app.init();
+    } catch (error) {
+      if (ownedCanvas) canvas.remove();
+      throw error;
+    }
+    const viewer = new SplatViewer(app, canvas, ownedCanvas, { ...DEFAULT_SPLAT_VIEWER_OPTIONS, ...options });
+    viewer.setRenderingConfig(viewer.options.rendering);
+    app.start();
+    return viewer;
+  }
+
+  /**
+   * 完整的应用接口
+   */
+  getApp(): App {
+    return this.app;
+  }
+
+  getCanvas(): HTMLCanvasElement {
+    return this.canvas;
+  }
+
+  /**
+   * 加载模型（.splat 按 splat 格式，其它按 PLY），替换当前模型
+   * @returns splat 数量
+   */
+  async loadUrl(url: string, onProgress?: ProgressCallback): Promise<number> {
+    const extension = new URL(url, location.href).pathname.split(".").pop()?.toLowerCase();
+    const splatCount = extension === "splat"
+      ? await this.app.addSplat(url, onProgress)
+      : await this.app.addPLY(url, onProgress);
+    // SH、可视化、混合、光栅化设置属于模型的渲染器，加载会重新创建渲染器并应用平台默认值，这里重新应用
+    this.setRenderingConfig(this.options.rendering);
+    if (this.options.frameOnLoad) {
+      this.app.frameCurrentModel(false);
+    }
+    const event = { url, splatCount };
+    for (const callback of [...this.loadedListeners]) {
+      callback(event);
+    }
+    return splatCount;
+  }
+
+  /**
+   * 监听加载完成
+   * @returns 取消监听函数
+   */
+  onLoaded(callback: SplatViewerLoadedCallback): () => void {
+    this.loadedListeners.add(callback);
+    return () => this.loadedListeners.delete(callback);
+  }
+
+  /**
+   * 修改显示配置（未提供的字段不变）
+   */
+  setRenderingConfig(config: SplatViewerRenderingConfig): void {
+    if (config.shDegree !== undefined) this.app.setSHMode(config.shDegree);
+    if (config.visMode !== undefined) this.app.setSplatVisMode(config.visMode);
+    if (config.blendMode !== undefined) this.app.setSplatBlendMode(config.blendMode);
+    if (config.rasterMode !== undefined) this.app.setSplatRasterMode(config.rasterMode);
+    if (config.background !== undefined) this.app.getRenderer().setClearColorHex(config.background);
+    if (config.grid !== undefined) this.app.setGridEnabled(config.grid);
+    this.options.rendering = { ...this.options.rendering, ...config };
+    // 背景颜色不经过 App，按需渲染时需要显式唤醒
+    this.app.requestRender();
+  }
+
+  /**
+   * 当前显示配置（通过查看器设置过的字段）
+   */
+  getRenderingConfig(): SplatViewerRenderingConfig {
+    return { ...this.options.rendering };
+  }
+
+  /**
+   * 拾取视口坐标（MouseEvent.clientX / clientY）下最近的 splat，不在画布上或没有命中时返回 null
+   */
+  async pick(clientX: number, clientY: number): Promise<SplatPickResult | null> {
+    const pixel = clientToCanvasPixels(this.canvas, clientX, clientY);
+    if (!pixel) return null;
+    return this.app.pickSplat(pixel[0], pixel[1]);
+  }
+
+  /**
+   * 设置相机位姿（轨道控制器以 target 为旋转中心）
+   */
+  setCameraPose(pose: SplatViewerCameraPose): void {
+    const camera = this.app.getCamera();
+    if (pose.up) camera.up.set(pose.up);
+    if (pose.fovY !== undefined) camera.fov = pose.fovY;
+    this.app.getControls().setPose(pose.position, pose.target);
+  }
+
+  getCameraPose(): Required<SplatViewerCameraPose> {
+    const { position, target, up, fov } = this.app.getCamera();
+    return {
+      position: [position[0], position[1], position[2]],
+      target: [target[0], target[1], target[2]],
+      up: [up[0], up[1], up[2]],
+      fovY: fov,
+    };
+  }
+
+  /**
+   * 销毁查看器（释放 GPU 资源，移除自动创建的画布）
+   */
+  destroy(): void {
+    this.loadedListeners.clear();
+    this.app.destroy();
+    if (this.ownedCanvas) {
+      this.canvas.remove();
+    }
+  }
+}
@@ -12,6 +12,13 @@ export default defineConfig({
   build: {
     outDir: "../dist-demo",
     target: "esnext",
+    rollupOptions: {
+      input: {
+        main: resolve(__dirname, "demo/index.html"),
+        hybrid: resolve(__dirname, "demo/hybrid.html"),
+        soak: resolve(__dirname, "demo/soak.html"),
+      },
+    },
   },
   resolve: {
     alias: {
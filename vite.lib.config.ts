@@ -1,10 +1,13 @@
 import { defineConfig } from "vite";
 import { resolve, dirname } from "path";
 import { fileURLToPath } from "url";
+import { getSplatShaderFeatureDefines, parseDisabledSplatShaderFeatures } from "./src/gs/SplatShaderFeatures";
 
 const __dirname = dirname(fileURLToPath(import.meta.url));
 
 export default defineConfig({
+  // 构建时裁剪 shader 功能，例如 GS_DISABLE_SHADER_FEATURES=editing,sway,appearance,customVisModes
+  define: getSplatShaderFeatureDefines(parseDisabledSplatShaderFeatures(process.env.GS_DISABLE_SHADER_FEATURES)),
   build: {
     lib: {
       entry: resolve(__dirname, "src/index.ts"),
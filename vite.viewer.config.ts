@@ -0,0 +1,15 @@
+import { defineConfig, mergeConfig } from "vite";
+import libConfig from "./vite.lib.config";
+import { getSplatShaderFeatureDefines } from "./src/gs/SplatShaderFeatures";
+
+// 只读查看器构建：去掉编辑预览、摆动、外观混合和自定义可视化模式，
+// 输出到单独的 dist-viewer，不覆盖完整库的 dist
+export default mergeConfig(
+  libConfig,
+  defineConfig({
+    define: getSplatShaderFeatureDefines({ editing: false, sway: false, appearance: false, customVisModes: false }),
+    build: {
+      outDir: "dist-viewer",
+    },
+  }),
+);